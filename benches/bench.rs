@@ -1,4 +1,4 @@
-use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use std::fs;
 
 fn readme(c: &mut Criterion) {
@@ -9,6 +9,97 @@ fn readme(c: &mut Criterion) {
     });
 }
 
+fn prose(c: &mut Criterion) {
+    let doc = "Venus is the second planet from the Sun, named after the Roman goddess of love and beauty.\nIt is the brightest natural object in the night sky after the Moon.\n\n".repeat(1000);
+
+    let mut group = c.benchmark_group("prose");
+    group.throughput(Throughput::Bytes(doc.len() as u64));
+    group.bench_with_input(BenchmarkId::new("paragraphs", "160 kb"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+    group.finish();
+}
+
+fn references(c: &mut Criterion) {
+    let mut doc = String::new();
+
+    for index in 0..1000 {
+        doc.push_str(&format!("* Fix [#{}] and [Issue {}][]\n", index, index));
+    }
+
+    doc.push('\n');
+
+    for index in 0..1000 {
+        doc.push_str(&format!(
+            "[#{}]: https://example.com/{}\n[issue {}]: https://example.com/{}\n",
+            index, index, index, index
+        ));
+    }
+
+    let mut group = c.benchmark_group("references");
+    group.throughput(Throughput::Bytes(doc.len() as u64));
+    group.bench_with_input(BenchmarkId::new("references", "2000"), &doc, |b, s| {
+        b.iter(|| markdown::to_html(s));
+    });
+    group.finish();
+}
+
+/// Inputs known to be slow in markdown parsers, by the name of their family.
+///
+/// Each is made with `size` repetitions of something, and should take time
+/// linear in `size` to parse.
+fn pathological_inputs(size: usize) -> Vec<(&'static str, String)> {
+    vec![
+        ("unclosed brackets", "[".repeat(size)),
+        ("unclosed images", "![".repeat(size)),
+        (
+            "nested brackets",
+            format!("{}a{}", "[".repeat(size), "]".repeat(size)),
+        ),
+        ("emphasis runs", "*a_ ".repeat(size)),
+        (
+            "nested emphasis",
+            format!("{}a{}", "*".repeat(size), "*".repeat(size)),
+        ),
+        (
+            "backtick runs",
+            (1..(size / 10)).map(|d| "`".repeat(d) + " a ").collect(),
+        ),
+        (
+            "code after lists",
+            format!("{}\n    code", "* a\n".repeat(size)),
+        ),
+        (
+            "nested lists",
+            (0..(size / 20))
+                .map(|d| format!("{}* a\n", "  ".repeat(d)))
+                .collect(),
+        ),
+        (
+            "table cells",
+            format!("{}\n{}\n", "a|".repeat(size), "-|".repeat(size)),
+        ),
+        (
+            "link destination parens",
+            format!("[a]({}b", "(".repeat(size)),
+        ),
+    ]
+}
+
+fn pathological(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pathological");
+    group.sample_size(10);
+
+    for (name, doc) in pathological_inputs(10_000) {
+        group.throughput(Throughput::Bytes(doc.len() as u64));
+        group.bench_with_input(BenchmarkId::new(name, doc.len()), &doc, |b, s| {
+            b.iter(|| markdown::to_html_with_options(s, &markdown::Options::gfm()));
+        });
+    }
+
+    group.finish();
+}
+
 // fn one_and_a_half_mb(c: &mut Criterion) {
 //     let doc = fs::read_to_string("../a-dump-of-markdown/markdown.md").unwrap();
 //     let mut group = c.benchmark_group("giant");
@@ -20,5 +111,5 @@ fn readme(c: &mut Criterion) {
 // }
 // , one_and_a_half_mb
 
-criterion_group!(benches, readme);
+criterion_group!(benches, readme, prose, references, pathological);
 criterion_main!(benches);
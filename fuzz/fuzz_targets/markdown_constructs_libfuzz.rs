@@ -0,0 +1,43 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use markdown::{to_html_with_options, to_mdast, CompileOptions, Constructs, Options, ParseOptions};
+
+/// All constructs that can be on together: either with HTML, autolinks, and
+/// indented code, or with MDX.
+fn options(mdx: bool) -> Options {
+    let base = if mdx {
+        Constructs::mdx()
+    } else {
+        Constructs::gfm()
+    };
+
+    Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                footnote_inline: true,
+                frontmatter: true,
+                gfm_autolink_literal: true,
+                gfm_footnote_definition: true,
+                gfm_label_start_footnote: true,
+                gfm_strikethrough: true,
+                gfm_table: true,
+                gfm_task_list_item: true,
+                math_flow: true,
+                math_text: true,
+                ..base
+            },
+            ..ParseOptions::default()
+        },
+        compile: CompileOptions::gfm(),
+    }
+}
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(s) = std::str::from_utf8(data) {
+        for mdx in [false, true] {
+            let options = options(mdx);
+            let _ = to_html_with_options(s, &options);
+            let _ = to_mdast(s, &options.parse);
+        }
+    }
+});
@@ -14,14 +14,19 @@ async fn main() {
 }
 
 async fn commonmark() {
-    let url = "https://raw.githubusercontent.com/commonmark/commonmark-spec/0.31.2/spec.txt";
+    // Keep in sync with `commonmark_version()` in `src/lib.rs`.
+    let version = "0.31.2";
+    let url = format!(
+        "https://raw.githubusercontent.com/commonmark/commonmark-spec/{}/spec.txt",
+        version
+    );
     let data_url = "commonmark-data.txt";
     let code_url = "tests/commonmark.rs";
 
     let value = if let Ok(value) = fs::read_to_string(data_url) {
         value
     } else {
-        let value = reqwest::get(url).await.unwrap().text().await.unwrap();
+        let value = reqwest::get(&url).await.unwrap().text().await.unwrap();
 
         fs::write(data_url, value.clone()).unwrap();
 
@@ -70,14 +75,20 @@ async fn commonmark() {
         "//! `CommonMark` test suite.
 
 // > 👉 **Important**: this module is generated by `generate/src/main.rs`.
-// > It is generate from the latest CommonMark website.
+// > It is generate from the CommonMark spec, version {}.
 
-use markdown::{{message, to_html_with_options, CompileOptions, Options}};
+use markdown::{{commonmark_version, message, to_html_with_options, CompileOptions, Options}};
 use pretty_assertions::assert_eq;
 
 #[rustfmt::skip]
 #[test]
 fn commonmark() -> Result<(), message::Message> {{
+    assert_eq!(
+        commonmark_version(),
+        \"{}\",
+        \"should be tested against the same version of the spec\"
+    );
+
     let danger = Options {{
         compile: CompileOptions {{
             allow_dangerous_html: true,
@@ -92,6 +103,8 @@ fn commonmark() -> Result<(), message::Message> {{
     Ok(())
 }}
 ",
+        version,
+        version,
         cases.join("\n\n")
     );
 
@@ -153,7 +166,7 @@ async fn punctuation() {
 /// ## References
 ///
 /// *   [*§ 2.1 Characters and lines* in `CommonMark`](https://spec.commonmark.org/0.31.2/#unicode-punctuation-character)
-pub const PUNCTUATION: [char; {}] = [
+pub static PUNCTUATION: [char; {}] = [
 {}
 ];
 ",
@@ -2,6 +2,10 @@
 //!
 //! JS equivalent: https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/types.js#L307.
 
+use crate::r#unsafe::UnsafePattern;
+use alloc::{boxed::Box, vec::Vec};
+use markdown::{mdast::Node, LineEnding};
+
 #[derive(Clone, Copy)]
 /// Configuration for indent of lists.
 pub enum IndentOptions {
@@ -14,6 +18,91 @@ pub enum IndentOptions {
     Tab,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Configuration for where to put definitions.
+pub enum DefinitionPlacement {
+    /// Where they are in the tree.
+    InPlace,
+    /// At the end of the document, after the other nodes.
+    ///
+    /// Only definitions that are direct children of the root, in flow content,
+    /// are moved.
+    /// Definitions in containers (such as block quotes and list items) stay
+    /// where they are.
+    /// Footnote definitions are not moved either: GFM footnotes cannot be
+    /// serialized yet.
+    AtEnd {
+        /// Whether to sort the moved definitions by identifier, instead of
+        /// keeping their order.
+        alphabetize: bool,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Decision that a [`StyleResolver`] is asked about.
+///
+/// Each decision corresponds to an option, which is used when the resolver
+/// does not answer.
+pub enum StyleQuery {
+    /// Marker to use for bullets of items in an unordered list
+    /// (`options.bullet`), asked with the list.
+    /// Answer with `StyleAnswer::Marker`.
+    Bullet {
+        /// How many lists are open, including this one: `1` for a list that
+        /// is not in another list.
+        depth: usize,
+    },
+    /// Marker to use for bullets of items in an ordered list
+    /// (`options.bullet_ordered`), asked with the list.
+    /// Answer with `StyleAnswer::Marker`.
+    BulletOrdered {
+        /// How many lists are open, including this one: `1` for a list that
+        /// is not in another list.
+        depth: usize,
+    },
+    /// Marker to use for emphasis (`options.emphasis`), asked with the
+    /// emphasis.
+    /// Answer with `StyleAnswer::Marker`.
+    Emphasis,
+    /// Marker to use for fenced code (`options.fence`), asked with the code.
+    /// Answer with `StyleAnswer::Marker`.
+    Fence,
+    /// Whether to use fenced code (`options.fences`), asked with the code.
+    /// Answer with `StyleAnswer::Flag`.
+    Fences,
+    /// Marker to use for a thematic break (`options.rule`), asked with the
+    /// thematic break.
+    /// Answer with `StyleAnswer::Marker`.
+    Rule,
+    /// Number of markers to use for a thematic break
+    /// (`options.rule_repetition`), asked with the thematic break.
+    /// Answer with `StyleAnswer::Repetition`.
+    RuleRepetition,
+    /// Marker to use for strong (`options.strong`), asked with the strong.
+    /// Answer with `StyleAnswer::Marker`.
+    Strong,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+/// Answer of a [`StyleResolver`].
+///
+/// Answers are checked like the options they replace.
+/// An answer of the wrong kind for a query is ignored, as if the resolver
+/// did not answer.
+pub enum StyleAnswer {
+    /// Use this marker.
+    Marker(char),
+    /// Use this number of markers.
+    Repetition(u32),
+    /// Turn this on or off.
+    Flag(bool),
+}
+
+/// Function that decides how to serialize a node, instead of an option.
+///
+/// Return `None` to use the option.
+pub type StyleResolver = dyn Fn(&Node, &StyleQuery) -> Option<StyleAnswer>;
+
 /// Configuration.
 pub struct Options {
     /// Marker to use for bullets of items in unordered lists (`'*'`, `'+'`, or
@@ -29,8 +118,18 @@ pub struct Options {
     /// Whether to add the same number of number signs (`#`) at the end of an
     /// ATX heading as the opening sequence (`bool`, default: `false`).
     pub close_atx: bool,
+    /// Where to put definitions (`DefinitionPlacement`, default:
+    /// `DefinitionPlacement::InPlace`).
+    /// Moving definitions to the end of the document does not change what
+    /// references resolve to, as definitions apply to the whole document.
+    pub definition_placement: DefinitionPlacement,
     /// Marker to use for emphasis (`'*'` or `'_'`, default: `'*'`).
     pub emphasis: char,
+    /// Extra patterns that cause characters to be escaped (`Vec<UnsafePattern>`,
+    /// default: `vec![]`).
+    /// They are checked in addition to the built-in patterns.
+    /// Useful when markdown is shown somewhere that supports more syntax.
+    pub extra_unsafe: Vec<UnsafePattern>,
     /// Marker to use for fenced code (``'`'`` or `'~'`, default: ``'`'``).
     pub fence: char,
     /// Whether to use fenced code always (`bool`, default: `true`).
@@ -41,10 +140,33 @@ pub struct Options {
     /// Whether to increment the counter of ordered lists items (`bool`,
     /// default: `true`).
     pub increment_list_marker: bool,
+    /// Line ending to use (`LineEnding`, default: `LineEnding::LineFeed`).
+    /// Line feeds in values, such as in the value of code, are turned into
+    /// this line ending too, as they become line endings of the document.
+    pub line_ending: LineEnding,
     /// How to indent the content of list items (default: `IndentOptions::One`).
     pub list_item_indent: IndentOptions,
-    /// Marker to use for titles (`'"'` or `"'"`, default: `'"'`).
+    /// Whether the markdown is MDX (`bool`, default: `false`).
+    /// When on, `<` and `{` in text are escaped, as they start JSX and
+    /// expressions in MDX.
+    pub mdx: bool,
+    /// Whether to also turn carriage returns (`\r\n`, `\r`) in values into
+    /// `line_ending` (`bool`, default: `false`).
+    /// The default is to keep them as they are.
+    /// Useful for trees made from documents with mixed line endings.
+    pub normalize_line_endings: bool,
+    /// Width of MDX JSX (flow) tags, in characters, above which each
+    /// attribute is put on its own line (`usize`, default: `usize::MAX`).
+    /// Attributes that include line endings are always put on their own
+    /// lines.
+    pub print_width: usize,
+    /// Marker to use for titles and MDX JSX attribute values (`'"'` or
+    /// `"'"`, default: `'"'`).
     pub quote: char,
+    /// Whether to use the other quote for an MDX JSX attribute value if it
+    /// occurs less often in that value than `quote` (`bool`, default:
+    /// `false`).
+    pub quote_smart: bool,
     /// Whether to always use resource links (`bool`, default: `false`).
     /// The default is to use autolinks (`<https://example.com>`) when possible
     /// and resource links (`[text](url)`) otherwise.
@@ -62,8 +184,10 @@ pub struct Options {
     /// `false`).
     /// The default is to always use ATX headings (`# heading`) instead of
     /// setext headings (`heading\n=======`).
-    /// Setext headings cannot be used for empty headings or headings with a
-    /// rank of three or more.
+    /// Setext headings cannot be used for empty (or whitespace only) headings
+    /// or headings with a rank of three or more.
+    /// Headings with line endings in them always use setext, as ATX headings
+    /// cannot contain them.
     pub setext: bool,
     /// Whether to support math (text) with a single dollar (`bool`, default: `true`).
     /// Single dollars work in Pandoc and many other places, but often interfere with “normal”
@@ -72,9 +196,27 @@ pub struct Options {
     pub single_dollar_text_math: bool,
     /// Marker to use for strong (`'*'` or `'_'`, default: `'*'`).
     pub strong: char,
+    /// Function to decide some styles per node (`Option<Box<StyleResolver>>`,
+    /// default: `None`).
+    /// It is asked about bullets, emphasis, fences, rules, and strong, before
+    /// their options are used.
+    /// Useful to keep the style of a document, or to pick a style based on
+    /// the content of a node.
+    pub style_resolver: Option<Box<StyleResolver>>,
     /// Whether to join definitions without a blank line (`bool`, default:
     /// `false`).
     pub tight_definitions: bool,
+    /// Whether to not add a space before the slash of self-closing MDX JSX
+    /// tags (`bool`, default: `false`).
+    /// The default is `<a />`, this makes `<a/>`.
+    pub tight_self_closing: bool,
+    /// Whether to use the source kept in `raw` fields when present (`bool`,
+    /// default: `false`).
+    /// Pass `true`, and `preserve: true` when parsing, to get the exact same
+    /// markdown back.
+    /// Nodes whose `raw` is cleared are serialized normally; the blank lines
+    /// between two sibling nodes that both have positions are kept.
+    pub use_raw: bool,
 }
 
 impl Default for Options {
@@ -84,12 +226,19 @@ impl Default for Options {
             bullet_ordered: '.',
             bullet_other: '-',
             close_atx: false,
+            definition_placement: DefinitionPlacement::InPlace,
             emphasis: '*',
+            extra_unsafe: Vec::new(),
             fence: '`',
             fences: true,
             increment_list_marker: true,
+            line_ending: LineEnding::LineFeed,
             list_item_indent: IndentOptions::One,
+            mdx: false,
+            normalize_line_endings: false,
+            print_width: usize::MAX,
             quote: '"',
+            quote_smart: false,
             resource_link: false,
             rule: '*',
             rule_repetition: 3,
@@ -97,7 +246,10 @@ impl Default for Options {
             setext: false,
             single_dollar_text_math: true,
             strong: '*',
+            style_resolver: None,
             tight_definitions: false,
+            tight_self_closing: false,
+            use_raw: false,
         }
     }
 }
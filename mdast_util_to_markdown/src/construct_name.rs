@@ -2,7 +2,8 @@
 //!
 //! JS equivalent: https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/index.d.ts#L18.
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
+/// Name of a construct, used to define where [unsafe patterns][crate::UnsafePattern] apply.
 pub enum ConstructName {
     /// Whole autolink.
     ///
@@ -206,6 +207,24 @@ pub enum ConstructName {
     ///   | $$
     /// ```
     MathFlowMeta,
+    /// MDX JSX (flow).
+    ///
+    /// ```markdown
+    /// > | <a b="c">
+    ///     ^^^^^^^^^
+    /// > |   d
+    ///     ^^^
+    /// > | </a>
+    ///     ^^^^
+    /// ```
+    MdxJsxFlowElement,
+    /// MDX JSX (text).
+    ///
+    /// ```markdown
+    /// > | a <b c="d">e</b> f
+    ///       ^^^^^^^^^^^^^^
+    /// ```
+    MdxJsxTextElement,
     /// Paragraph.
     ///
     /// ```markdown
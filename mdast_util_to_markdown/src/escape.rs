@@ -0,0 +1,183 @@
+//! Escape text so that it renders literally.
+//!
+//! No JS equivalent.
+
+use crate::{
+    construct_name::ConstructName,
+    state::State,
+    util::{check_extra_unsafe::check_extra_unsafe, safe::SafeConfig},
+    Options,
+};
+use alloc::{string::String, vec};
+use markdown::message::Message;
+
+/// Place where escaped text ends up, which defines what must be escaped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscapeContext {
+    /// Whole paragraph, at the start of a line.
+    ///
+    /// Escapes constructs that form at the start of a line (such as headings,
+    /// lists, and block quotes), and encodes whitespace at the start and end
+    /// of lines.
+    ///
+    /// ```markdown
+    /// > | # a
+    ///     ^^^
+    /// ```
+    Flow,
+    /// Phrasing between other phrasing on the same line.
+    ///
+    /// Like [`Flow`][EscapeContext::Flow], but the value does not start or
+    /// end a line.
+    ///
+    /// ```markdown
+    /// > | a *b* c
+    ///         ^^^
+    /// ```
+    Text,
+    /// Label of a link, image, or definition.
+    ///
+    /// ```markdown
+    /// > | [a](b)
+    ///      ^
+    /// ```
+    LabelText,
+    /// Title in double quotes.
+    ///
+    /// ```markdown
+    /// > | [a](b "c")
+    ///             ^
+    /// ```
+    TitleQuote,
+    /// Title in single quotes.
+    ///
+    /// ```markdown
+    /// > | [a](b 'c')
+    ///             ^
+    /// ```
+    TitleApostrophe,
+    /// Destination without angle brackets.
+    ///
+    /// Cannot contain whitespace or control characters: use
+    /// [`DestinationLiteral`][EscapeContext::DestinationLiteral] for those.
+    ///
+    /// ```markdown
+    /// > | [a](b)
+    ///         ^
+    /// ```
+    DestinationRaw,
+    /// Destination in angle brackets.
+    ///
+    /// ```markdown
+    /// > | [a](<b>)
+    ///          ^
+    /// ```
+    DestinationLiteral,
+}
+
+/// Escape `value` so that it renders literally in `context`.
+///
+/// Parsing the result in a paragraph ([`EscapeContext::Flow`]) yields a
+/// single text with `value` as its value, as long as `value` is not empty
+/// and does not contain blank lines (two line endings in a row, or line
+/// endings at its start or end), which cannot occur in text.
+/// For the other contexts, the construct that the result is placed in gets
+/// `value` back.
+pub fn escape_text(value: &str, context: EscapeContext) -> String {
+    escape(value, context, &Options::default())
+}
+
+/// Escape `value`, with options, so that it renders literally in `context`.
+///
+/// Options that change what is unsafe, such as `mdx`,
+/// `single_dollar_text_math`, and `extra_unsafe`, are used.
+///
+/// ## Errors
+///
+/// Errors when the `before` or `after` of a pattern in
+/// [`extra_unsafe`][Options::extra_unsafe] is not a valid regex.
+pub fn escape_text_with_options(
+    value: &str,
+    context: EscapeContext,
+    options: &Options,
+) -> Result<String, Message> {
+    check_extra_unsafe(options)?;
+    Ok(escape(value, context, options))
+}
+
+/// Escape `value`, with valid options.
+fn escape(value: &str, context: EscapeContext, options: &Options) -> String {
+    let (stack, before, after) = match context {
+        EscapeContext::Flow => (
+            vec![ConstructName::Paragraph, ConstructName::Phrasing],
+            "\n",
+            "\n",
+        ),
+        EscapeContext::Text => (
+            vec![ConstructName::Paragraph, ConstructName::Phrasing],
+            "",
+            "",
+        ),
+        EscapeContext::LabelText => (
+            vec![
+                ConstructName::Paragraph,
+                ConstructName::Phrasing,
+                ConstructName::Link,
+                ConstructName::Label,
+            ],
+            "[",
+            "]",
+        ),
+        EscapeContext::TitleQuote => (
+            vec![
+                ConstructName::Paragraph,
+                ConstructName::Phrasing,
+                ConstructName::Link,
+                ConstructName::TitleQuote,
+            ],
+            "\"",
+            "\"",
+        ),
+        EscapeContext::TitleApostrophe => (
+            vec![
+                ConstructName::Paragraph,
+                ConstructName::Phrasing,
+                ConstructName::Link,
+                ConstructName::TitleApostrophe,
+            ],
+            "'",
+            "'",
+        ),
+        EscapeContext::DestinationRaw => (
+            vec![
+                ConstructName::Paragraph,
+                ConstructName::Phrasing,
+                ConstructName::Link,
+                ConstructName::DestinationRaw,
+            ],
+            "(",
+            ")",
+        ),
+        EscapeContext::DestinationLiteral => (
+            vec![
+                ConstructName::Paragraph,
+                ConstructName::Phrasing,
+                ConstructName::Link,
+                ConstructName::DestinationLiteral,
+            ],
+            "<",
+            ">",
+        ),
+    };
+
+    let mut state = State::new(options);
+    state.stack = stack;
+    let mut result = state.safe(value, &SafeConfig::new(before, after, None));
+
+    // A raw destination cannot start with `<`, as that starts a literal one.
+    if context == EscapeContext::DestinationRaw && result.starts_with('<') {
+        result.insert(0, '\\');
+    }
+
+    result
+}
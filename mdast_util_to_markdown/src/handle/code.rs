@@ -24,11 +24,11 @@ impl Handle for Code {
         state: &mut State,
         _info: &Info,
         _parent: Option<&Node>,
-        _node: &Node,
+        node: &Node,
     ) -> Result<alloc::string::String, Message> {
-        let marker = check_fence(state)?;
+        let marker = check_fence(state, node)?;
 
-        if format_code_as_indented(self, state) {
+        if format_code_as_indented(self, node, state) {
             state.enter(ConstructName::CodeIndented);
             let value = state.indent_lines(&self.value, map);
             state.exit();
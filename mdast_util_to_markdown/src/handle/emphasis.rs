@@ -5,6 +5,7 @@ use crate::{
     construct_name::ConstructName,
     state::{Info, State},
     util::check_emphasis::check_emphasis,
+    StyleAnswer, StyleQuery,
 };
 use alloc::format;
 use markdown::{
@@ -20,7 +21,7 @@ impl Handle for Emphasis {
         _parent: Option<&Node>,
         node: &Node,
     ) -> Result<alloc::string::String, Message> {
-        let marker = check_emphasis(state)?;
+        let marker = check_emphasis(state, node)?;
 
         state.enter(ConstructName::Emphasis);
 
@@ -33,6 +34,9 @@ impl Handle for Emphasis {
     }
 }
 
-pub fn peek_emphasis(state: &State) -> char {
-    state.options.emphasis
+pub fn peek_emphasis(state: &State, node: &Node) -> char {
+    match state.style(node, &StyleQuery::Emphasis) {
+        Some(StyleAnswer::Marker(marker)) => marker,
+        _ => state.options.emphasis,
+    }
 }
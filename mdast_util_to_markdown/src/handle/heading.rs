@@ -30,19 +30,13 @@ impl Handle for Heading {
             state.exit();
             state.exit();
 
-            let underline_char = if rank == 1 { "=" } else { "-" };
-            let last_line_rank = value
-                .rfind('\n')
-                .unwrap_or(0)
-                .max(value.rfind('\r').unwrap_or(0));
-
-            let last_line_rank = if last_line_rank > 0 {
-                last_line_rank + 1
-            } else {
-                0
-            };
+            // Trailing whitespace is not part of the heading, and a blank last
+            // line would separate the underline from the content.
+            value.truncate(value.trim_end_matches([' ', '\t', '\r', '\n']).len());
 
-            let setext_underline = underline_char.repeat(value.len() - last_line_rank);
+            let underline_char = if rank == 1 { "=" } else { "-" };
+            let last_line_start = value.rfind(['\r', '\n']).map_or(0, |index| index + 1);
+            let setext_underline = underline_char.repeat(value[last_line_start..].chars().count());
             value.push('\n');
             value.push_str(&setext_underline);
 
@@ -26,9 +26,9 @@ impl Handle for List {
         let bullet_current = state.bullet_current;
 
         let mut bullet = if self.ordered {
-            check_bullet_ordered(state)?
+            check_bullet_ordered(state, node)?
         } else {
-            check_bullet(state)?
+            check_bullet(state, node)?
         };
 
         let bullet_other = if self.ordered {
@@ -38,7 +38,7 @@ impl Handle for List {
                 '.'
             }
         } else {
-            check_bullet_other(state)?
+            check_bullet_other(state, node)?
         };
 
         let mut use_different_marker = false;
@@ -70,16 +70,15 @@ impl Handle for List {
                 use_different_marker = true;
             }
 
-            if check_rule(state)? == bullet {
-                for child in self.children.iter() {
-                    if let Some(child_children) = child.children() {
-                        if !child_children.is_empty()
-                            && matches!(child, Node::ListItem(_))
-                            && matches!(child_children[0], Node::ThematicBreak(_))
-                        {
-                            use_different_marker = true;
-                            break;
-                        }
+            for child in self.children.iter() {
+                if let Some(child_children) = child.children() {
+                    if !child_children.is_empty()
+                        && matches!(child, Node::ListItem(_))
+                        && matches!(child_children[0], Node::ThematicBreak(_))
+                        && check_rule(state, &child_children[0])? == bullet
+                    {
+                        use_different_marker = true;
+                        break;
                     }
                 }
             }
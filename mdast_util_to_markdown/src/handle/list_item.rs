@@ -25,10 +25,12 @@ impl Handle for ListItem {
         node: &Node,
     ) -> Result<alloc::string::String, Message> {
         let list_item_indent = state.options.list_item_indent;
-        let mut bullet = state
-            .bullet_current
-            .unwrap_or(check_bullet(state)?)
-            .to_string();
+        let mut bullet = match state.bullet_current {
+            Some(bullet) => bullet,
+            // Ask with the list, like `list` does, if there is one.
+            None => check_bullet(state, parent.unwrap_or(node))?,
+        }
+        .to_string();
 
         if let Some(Node::List(list)) = parent {
             if list.ordered {
@@ -0,0 +1,37 @@
+//! JS equivalent: https://github.com/syntax-tree/mdast-util-mdx-expression/blob/main/lib/index.js#L80
+
+use super::Handle;
+use crate::state::{Info, State};
+use alloc::{format, string::String};
+use markdown::{
+    mdast::{MdxFlowExpression, Node},
+    message::Message,
+};
+
+impl Handle for MdxFlowExpression {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        _node: &Node,
+    ) -> Result<alloc::string::String, Message> {
+        Ok(expression(state, &self.value))
+    }
+}
+
+/// Serialize the value of an expression between braces.
+///
+/// Lines after the first are indented, as that much indent is removed from
+/// them when parsing.
+pub fn expression(state: &State, value: &str) -> String {
+    let value = state.indent_lines(value, |line, index, blank| {
+        if index == 0 || blank {
+            line.into()
+        } else {
+            format!("  {}", line)
+        }
+    });
+
+    format!("{{{}}}", value)
+}
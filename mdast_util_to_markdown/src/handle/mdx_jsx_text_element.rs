@@ -0,0 +1,27 @@
+//! JS equivalent: https://github.com/syntax-tree/mdast-util-mdx-jsx/blob/main/lib/index.js#L505
+
+use super::Handle;
+use crate::{
+    state::{Info, State},
+    util::mdx_jsx_element::mdx_jsx_element,
+};
+use markdown::{
+    mdast::{MdxJsxTextElement, Node},
+    message::Message,
+};
+
+impl Handle for MdxJsxTextElement {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        node: &Node,
+    ) -> Result<alloc::string::String, Message> {
+        mdx_jsx_element(state, node, self.name.as_deref(), &self.attributes)
+    }
+}
+
+pub fn peek_mdx_jsx_text_element() -> char {
+    '<'
+}
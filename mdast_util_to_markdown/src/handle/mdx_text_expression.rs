@@ -0,0 +1,24 @@
+//! JS equivalent: https://github.com/syntax-tree/mdast-util-mdx-expression/blob/main/lib/index.js#L80
+
+use super::{mdx_flow_expression::expression, Handle};
+use crate::state::{Info, State};
+use markdown::{
+    mdast::{MdxTextExpression, Node},
+    message::Message,
+};
+
+impl Handle for MdxTextExpression {
+    fn handle(
+        &self,
+        state: &mut State,
+        _info: &Info,
+        _parent: Option<&Node>,
+        _node: &Node,
+    ) -> Result<alloc::string::String, Message> {
+        Ok(expression(state, &self.value))
+    }
+}
+
+pub fn peek_mdx_text_expression() -> char {
+    '{'
+}
@@ -18,6 +18,11 @@ pub mod link_reference;
 mod list;
 mod list_item;
 mod math;
+mod mdx_flow_expression;
+mod mdx_jsx_flow_element;
+pub mod mdx_jsx_text_element;
+pub mod mdx_text_expression;
+mod mdxjs_esm;
 mod paragraph;
 mod root;
 pub mod strong;
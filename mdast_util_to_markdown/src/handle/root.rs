@@ -1,8 +1,11 @@
 //! JS equivalent: https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/handle/root.js
 
 use super::Handle;
-use crate::state::{Info, State};
-use alloc::string::String;
+use crate::{
+    configure::DefinitionPlacement,
+    state::{Info, State},
+};
+use alloc::{string::String, vec::Vec};
 use markdown::{
     mdast::{Node, Root},
     message::Message,
@@ -20,6 +23,24 @@ impl Handle for Root {
 
         if has_phrasing {
             state.container_phrasing(node, info)
+        } else if let DefinitionPlacement::AtEnd { alphabetize } =
+            state.options.definition_placement
+        {
+            // Move definitions after the other nodes.
+            let (mut definitions, mut children): (Vec<_>, Vec<_>) = self
+                .children
+                .iter()
+                .partition(|d| matches!(d, Node::Definition(_)));
+
+            if alphabetize {
+                definitions.sort_by(|a, b| match (a, b) {
+                    (Node::Definition(a), Node::Definition(b)) => a.identifier.cmp(&b.identifier),
+                    _ => unreachable!("expected definitions"),
+                });
+            }
+
+            children.append(&mut definitions);
+            state.container_flow_nodes(node, &children)
         } else {
             state.container_flow(node)
         }
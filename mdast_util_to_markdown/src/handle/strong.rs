@@ -5,6 +5,7 @@ use crate::{
     construct_name::ConstructName,
     state::{Info, State},
     util::check_strong::check_strong,
+    StyleAnswer, StyleQuery,
 };
 use alloc::format;
 use markdown::{
@@ -20,7 +21,7 @@ impl Handle for Strong {
         _parent: Option<&Node>,
         node: &Node,
     ) -> Result<alloc::string::String, Message> {
-        let marker = check_strong(state)?;
+        let marker = check_strong(state, node)?;
 
         state.enter(ConstructName::Strong);
 
@@ -39,6 +40,9 @@ impl Handle for Strong {
     }
 }
 
-pub fn peek_strong(state: &State) -> char {
-    state.options.strong
+pub fn peek_strong(state: &State, node: &Node) -> char {
+    match state.style(node, &StyleQuery::Strong) {
+        Some(StyleAnswer::Marker(marker)) => marker,
+        _ => state.options.strong,
+    }
 }
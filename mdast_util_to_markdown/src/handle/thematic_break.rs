@@ -17,12 +17,12 @@ impl Handle for ThematicBreak {
         state: &mut State,
         _info: &Info,
         _parent: Option<&Node>,
-        _node: &Node,
+        node: &Node,
     ) -> Result<alloc::string::String, Message> {
-        let marker = check_rule(state)?;
+        let marker = check_rule(state, node)?;
         let space = if state.options.rule_spaces { " " } else { "" };
         let mut value =
-            format!("{}{}", marker, space).repeat(check_rule_repetition(state)? as usize);
+            format!("{}{}", marker, space).repeat(check_rule_repetition(state, node)? as usize);
 
         if state.options.rule_spaces {
             // Remove the last space.
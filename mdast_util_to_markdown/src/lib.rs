@@ -5,14 +5,21 @@
 #![no_std]
 
 use alloc::string::String;
-pub use configure::{IndentOptions, Options};
-use markdown::{mdast::Node, message::Message};
+pub use configure::{
+    DefinitionPlacement, IndentOptions, Options, StyleAnswer, StyleQuery, StyleResolver,
+};
+pub use construct_name::ConstructName;
+pub use escape::{escape_text, escape_text_with_options, EscapeContext};
+use markdown::{mdast::Node, message::Message, LineEnding};
+pub use r#unsafe::UnsafePattern;
 use state::{Info, State};
+use util::{check_extra_unsafe::check_extra_unsafe, line_ending::convert_line_endings};
 
 extern crate alloc;
 mod association;
 mod configure;
 mod construct_name;
+mod escape;
 mod handle;
 mod state;
 mod r#unsafe;
@@ -24,7 +31,22 @@ pub fn to_markdown(tree: &Node) -> Result<String, Message> {
 }
 
 /// Turn an mdast syntax tree, with options, into markdown.
+///
+/// ## Errors
+///
+/// Errors when nodes cannot be serialized, when options are invalid (such as
+/// a marker that cannot be used), or when the `before` or `after` of a
+/// pattern in [`extra_unsafe`][Options::extra_unsafe] is not a valid regex.
 pub fn to_markdown_with_options(tree: &Node, options: &Options) -> Result<String, Message> {
+    check_extra_unsafe(options)?;
+
+    // The exact source, which does not get a final line ending.
+    if options.use_raw {
+        if let Some(raw) = tree.raw() {
+            return Ok(finish(raw, options));
+        }
+    }
+
     let mut state = State::new(options);
     let mut result = state.handle(tree, &Info::new("\n", "\n"), None)?;
 
@@ -35,5 +57,14 @@ pub fn to_markdown_with_options(tree: &Node, options: &Options) -> Result<String
         }
     }
 
-    Ok(result)
+    Ok(finish(&result, options))
+}
+
+/// Use the configured line ending.
+fn finish(value: &str, options: &Options) -> String {
+    if options.line_ending == LineEnding::LineFeed && !options.normalize_line_endings {
+        value.into()
+    } else {
+        convert_line_endings(value, &options.line_ending, options.normalize_line_endings)
+    }
 }
@@ -9,7 +9,8 @@ use crate::{
         emphasis::peek_emphasis, html::peek_html, image::peek_image,
         image_reference::peek_image_reference, inline_code::peek_inline_code,
         inline_math::peek_inline_math, link::peek_link, link_reference::peek_link_reference,
-        strong::peek_strong, Handle,
+        mdx_jsx_text_element::peek_mdx_jsx_text_element,
+        mdx_text_expression::peek_mdx_text_expression, strong::peek_strong, Handle,
     },
     r#unsafe::Unsafe,
     util::{
@@ -18,7 +19,7 @@ use crate::{
         pattern_in_scope::pattern_in_scope,
         safe::{escape_backslashes, EscapeInfos, SafeConfig},
     },
-    Options,
+    Options, StyleAnswer, StyleQuery,
 };
 use alloc::{
     boxed::Box,
@@ -27,6 +28,7 @@ use alloc::{
     string::{String, ToString},
     vec::Vec,
 };
+use core::ptr;
 use markdown::{mdast::Node, message::Message};
 use regex::{Captures, Regex, RegexBuilder};
 
@@ -80,8 +82,17 @@ impl<'a> State<'a> {
     }
 
     /// JS equivalent: <https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/util/container-flow.js#L66>.
-    fn between(&self, left: &Node, right: &Node, parent: &Node, results: &mut String) {
-        if self.options.tight_definitions {
+    fn between(
+        &self,
+        left: &Node,
+        right: &Node,
+        parent: &Node,
+        in_order: bool,
+        results: &mut String,
+    ) {
+        if let Some(join) = self.raw_between(left, right, in_order) {
+            Self::set_between(&join, results)
+        } else if self.options.tight_definitions {
             Self::set_between(&self.tight_definition(left, right), results)
         } else {
             Self::set_between(&self.join_defaults(left, right, parent), results)
@@ -91,61 +102,79 @@ impl<'a> State<'a> {
     /// JS equivalent: <https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/util/compile-pattern.js>.
     pub fn compile_pattern(pattern: &mut Unsafe) {
         if pattern.compiled.is_none() {
-            let mut pattern_to_compile = String::new();
+            // Extra patterns are checked before serializing.
+            pattern.set_compiled(
+                Regex::new(&Self::pattern_source(pattern)).expect("A valid unsafe regex pattern"),
+            );
+        }
+    }
 
-            if let Some(pattern_before) = pattern.before {
-                pattern_to_compile.push('(');
-                if pattern.at_break {
-                    pattern_to_compile.push_str("[\\r\\n][\\t ]*");
-                }
-                pattern_to_compile.push_str("(?:");
-                pattern_to_compile.push_str(pattern_before);
-                pattern_to_compile.push(')');
-                pattern_to_compile.push(')');
-            } else if pattern.at_break {
-                pattern_to_compile.push('(');
-                pattern_to_compile.push_str("[\\r\\n][\\t ]*");
-                pattern_to_compile.push(')');
-            }
+    /// Get the regex source of an unsafe pattern.
+    ///
+    /// No JS equivalent: part of `compile_pattern`.
+    pub fn pattern_source(pattern: &Unsafe) -> String {
+        let mut pattern_to_compile = String::new();
 
-            if matches!(
-                pattern.character,
-                '|' | '\\'
-                    | '{'
-                    | '}'
-                    | '('
-                    | ')'
-                    | '['
-                    | ']'
-                    | '^'
-                    | '$'
-                    | '+'
-                    | '*'
-                    | '?'
-                    | '.'
-                    | '-'
-            ) {
-                pattern_to_compile.push('\\');
+        if let Some(pattern_before) = pattern.before {
+            pattern_to_compile.push('(');
+            if pattern.at_break {
+                pattern_to_compile.push_str("[\\r\\n][\\t ]*");
             }
+            pattern_to_compile.push_str("(?:");
+            pattern_to_compile.push_str(pattern_before);
+            pattern_to_compile.push(')');
+            pattern_to_compile.push(')');
+        } else if pattern.at_break {
+            pattern_to_compile.push('(');
+            pattern_to_compile.push_str("[\\r\\n][\\t ]*");
+            pattern_to_compile.push(')');
+        }
 
-            pattern_to_compile.push(pattern.character);
+        if matches!(
+            pattern.character,
+            '|' | '\\'
+                | '{'
+                | '}'
+                | '('
+                | ')'
+                | '['
+                | ']'
+                | '^'
+                | '$'
+                | '+'
+                | '*'
+                | '?'
+                | '.'
+                | '-'
+        ) {
+            pattern_to_compile.push('\\');
+        }
 
-            if let Some(pattern_after) = pattern.after {
-                pattern_to_compile.push_str("(?:");
-                pattern_to_compile.push_str(pattern_after);
-                pattern_to_compile.push(')');
-            }
+        pattern_to_compile.push(pattern.character);
 
-            pattern.set_compiled(
-                Regex::new(&pattern_to_compile).expect("A valid unsafe regex pattern"),
-            );
+        if let Some(pattern_after) = pattern.after {
+            pattern_to_compile.push_str("(?:");
+            pattern_to_compile.push_str(pattern_after);
+            pattern_to_compile.push(')');
         }
+
+        pattern_to_compile
     }
 
     /// JS equivalent: <https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/container-flow.js>.
     pub fn container_flow(&mut self, parent: &Node) -> Result<String, Message> {
         let children = parent.children().expect("The node to be a flow parent.");
+        self.container_flow_nodes(parent, &children.iter().collect::<Vec<_>>())
+    }
 
+    /// Like `container_flow`, but with the children to serialize given.
+    ///
+    /// No JS equivalent.
+    pub fn container_flow_nodes(
+        &mut self,
+        parent: &Node,
+        children: &[&Node],
+    ) -> Result<String, Message> {
         if children.is_empty() {
             return Ok(String::new());
         }
@@ -153,6 +182,17 @@ impl<'a> State<'a> {
         let mut results: String = String::new();
         let mut children_iter = children.iter().peekable();
         let mut index = 0;
+        // Siblings are only next to each other in the source when they are
+        // not reordered (such as when moving definitions to the end).
+        let in_order = if let Some(siblings) = parent.children() {
+            siblings.len() == children.len()
+                && siblings
+                    .iter()
+                    .zip(children)
+                    .all(|(sibling, child)| ptr::eq(sibling, *child))
+        } else {
+            false
+        };
 
         self.index_stack.push(0);
 
@@ -172,7 +212,7 @@ impl<'a> State<'a> {
             results.push_str(&self.handle(child, &Info::new("\n", "\n"), Some(parent))?);
 
             if let Some(next_child) = children_iter.peek() {
-                self.between(child, next_child, parent, &mut results);
+                self.between(child, next_child, parent, in_order, &mut results);
             }
 
             index += 1;
@@ -301,6 +341,12 @@ impl<'a> State<'a> {
         info: &Info,
         parent: Option<&Node>,
     ) -> Result<String, Message> {
+        if self.options.use_raw {
+            if let Some(raw) = node.raw() {
+                return Ok(raw.clone());
+            }
+        }
+
         match node {
             Node::Break(r#break) => r#break.handle(self, info, parent, node),
             Node::Blockquote(block_quote) => block_quote.handle(self, info, parent, node),
@@ -325,6 +371,11 @@ impl<'a> State<'a> {
             Node::ThematicBreak(thematic_break) => thematic_break.handle(self, info, parent, node),
             Node::Math(math) => math.handle(self, info, parent, node),
             Node::InlineMath(inline_math) => inline_math.handle(self, info, parent, node),
+            Node::MdxJsxFlowElement(element) => element.handle(self, info, parent, node),
+            Node::MdxJsxTextElement(element) => element.handle(self, info, parent, node),
+            Node::MdxFlowExpression(expression) => expression.handle(self, info, parent, node),
+            Node::MdxTextExpression(expression) => expression.handle(self, info, parent, node),
+            Node::MdxjsEsm(esm) => esm.handle(self, info, parent, node),
             _ => Err(Message {
                 place: None,
                 reason: format!("Unexpected node type `{:?}`", node),
@@ -358,12 +409,12 @@ impl<'a> State<'a> {
     /// <https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/join.js>.
     fn join_defaults(&self, left: &Node, right: &Node, parent: &Node) -> Join {
         if let Node::Code(code) = right {
-            if format_code_as_indented(code, self) && matches!(left, Node::List(_)) {
+            if format_code_as_indented(code, right, self) && matches!(left, Node::List(_)) {
                 return Join::HtmlComment;
             }
 
             if let Node::Code(code) = left {
-                if format_code_as_indented(code, self) {
+                if format_code_as_indented(code, left, self) {
                     return Join::HtmlComment;
                 }
             }
@@ -412,22 +463,29 @@ impl<'a> State<'a> {
             index_stack: Vec::new(),
             options,
             stack: Vec::new(),
-            r#unsafe: Unsafe::get_default_unsafe(options),
+            r#unsafe: {
+                let mut r#unsafe = Unsafe::get_default_unsafe(options);
+                r#unsafe.append(&mut Unsafe::get_mdx_unsafe(options));
+                r#unsafe.append(&mut Unsafe::get_extra_unsafe(options));
+                r#unsafe
+            },
         }
     }
 
     /// No JS equivalent.
     fn peek_node(&self, node: &Node) -> Option<char> {
         match node {
-            Node::Emphasis(_) => Some(peek_emphasis(self)),
+            Node::Emphasis(_) => Some(peek_emphasis(self, node)),
             Node::Html(_) => Some(peek_html()),
             Node::ImageReference(_) => Some(peek_image_reference()),
             Node::Image(_) => Some(peek_image()),
             Node::InlineCode(_) => Some(peek_inline_code()),
             Node::LinkReference(_) => Some(peek_link_reference()),
             Node::Link(link) => Some(peek_link(link, node, self)),
-            Node::Strong(_) => Some(peek_strong(self)),
+            Node::Strong(_) => Some(peek_strong(self, node)),
             Node::InlineMath(_) => Some(peek_inline_math()),
+            Node::MdxJsxTextElement(_) => Some(peek_mdx_jsx_text_element()),
+            Node::MdxTextExpression(_) => Some(peek_mdx_text_expression()),
             _ => None,
         }
     }
@@ -556,6 +614,30 @@ impl<'a> State<'a> {
         }
     }
 
+    /// No JS equivalent.
+    ///
+    /// Ask `options.style_resolver` how to serialize `node`, if there is one.
+    pub fn style(&self, node: &Node, query: &StyleQuery) -> Option<StyleAnswer> {
+        self.options
+            .style_resolver
+            .as_ref()
+            .and_then(|style_resolver| style_resolver(node, query))
+    }
+
+    /// No JS equivalent.
+    ///
+    /// Keep the blank lines between two nodes from the source, whether they
+    /// are serialized from their source or not, unless they were reordered.
+    fn raw_between(&self, left: &Node, right: &Node, in_order: bool) -> Option<Join> {
+        if !self.options.use_raw || !in_order {
+            return None;
+        }
+
+        let end = left.position()?.end.line;
+        let start = right.position()?.start.line;
+        Some(Join::Lines(start.saturating_sub(end + 1)))
+    }
+
     /// No real JS equivalent, but see:
     /// <https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/util/container-flow.js#L66>.
     fn tight_definition(&self, left: &Node, right: &Node) -> Join {
@@ -4,9 +4,33 @@
 //! Also: <https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/types.js#L287-L305>.
 
 use crate::{construct_name::ConstructName, Options};
-use alloc::{vec, vec::Vec};
+use alloc::{string::String, vec, vec::Vec};
 use regex::Regex;
 
+/// Extra pattern that, when matched, causes a character to be escaped.
+///
+/// Passed in [`Options::extra_unsafe`][crate::Options::extra_unsafe], to
+/// escape syntax that is active in the place the markdown ends up, such as
+/// `||spoiler||` in a chat system.
+///
+/// JS equivalent: <https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/types.js#L287-L305>.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct UnsafePattern {
+    /// Regex (as a string) that must match after `character`, if any.
+    pub after: Option<String>,
+    /// Whether `character` is only unsafe at the start of a line, after
+    /// optional whitespace.
+    pub at_break: bool,
+    /// Regex (as a string) that must match before `character`, if any.
+    pub before: Option<String>,
+    /// Single unsafe character.
+    pub character: char,
+    /// Constructs where this is unsafe; unsafe everywhere when empty.
+    pub in_construct: Vec<ConstructName>,
+    /// Constructs where this is safe, even when in `in_construct`.
+    pub not_in_construct: Vec<ConstructName>,
+}
+
 #[derive(Default)]
 pub struct Unsafe<'a> {
     pub after: Option<&'a str>,
@@ -334,6 +358,54 @@ impl<'a> Unsafe<'a> {
         ]
     }
 
+    /// JS equivalent: the `unsafe` fields of the extensions in
+    /// <https://github.com/syntax-tree/mdast-util-mdx-jsx/blob/main/lib/index.js>
+    /// and <https://github.com/syntax-tree/mdast-util-mdx-expression/blob/main/lib/index.js>.
+    pub fn get_mdx_unsafe(options: &Options) -> Vec<Self> {
+        if !options.mdx {
+            return vec![];
+        }
+
+        vec![
+            Self::new(
+                '<',
+                None,
+                None,
+                vec![ConstructName::Phrasing],
+                vec![],
+                false,
+            ),
+            Self::new('<', None, None, vec![], vec![], true),
+            Self::new(
+                '{',
+                None,
+                None,
+                vec![ConstructName::Phrasing],
+                vec![],
+                false,
+            ),
+            Self::new('{', None, None, vec![], vec![], true),
+        ]
+    }
+
+    /// No JS equivalent: the JS API gets extra patterns from extensions.
+    pub fn get_extra_unsafe(options: &'a Options) -> Vec<Self> {
+        options
+            .extra_unsafe
+            .iter()
+            .map(|pattern| {
+                Self::new(
+                    pattern.character,
+                    pattern.before.as_deref(),
+                    pattern.after.as_deref(),
+                    pattern.in_construct.clone(),
+                    pattern.not_in_construct.clone(),
+                    pattern.at_break,
+                )
+            })
+            .collect()
+    }
+
     pub(crate) fn set_compiled(&mut self, regex_pattern: Regex) {
         self.compiled = Some(regex_pattern);
     }
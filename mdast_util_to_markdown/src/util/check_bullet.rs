@@ -1,11 +1,19 @@
 //! JS equivalent https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/check-bullet.js
 
-use crate::state::State;
+use crate::{construct_name::ConstructName, state::State, StyleAnswer, StyleQuery};
 use alloc::{boxed::Box, format};
-use markdown::message::Message;
+use markdown::{mdast::Node, message::Message};
 
-pub fn check_bullet(state: &mut State) -> Result<char, Message> {
-    let marker = state.options.bullet;
+pub fn check_bullet(state: &mut State, node: &Node) -> Result<char, Message> {
+    let depth = state
+        .stack
+        .iter()
+        .filter(|name| **name == ConstructName::List)
+        .count();
+    let marker = match state.style(node, &StyleQuery::Bullet { depth }) {
+        Some(StyleAnswer::Marker(marker)) => marker,
+        _ => state.options.bullet,
+    };
 
     if marker != '*' && marker != '+' && marker != '-' {
         return Err(Message {
@@ -3,10 +3,10 @@
 use super::check_bullet::check_bullet;
 use crate::state::State;
 use alloc::{boxed::Box, format};
-use markdown::message::Message;
+use markdown::{mdast::Node, message::Message};
 
-pub fn check_bullet_other(state: &mut State) -> Result<char, Message> {
-    let bullet = check_bullet(state)?;
+pub fn check_bullet_other(state: &mut State, node: &Node) -> Result<char, Message> {
+    let bullet = check_bullet(state, node)?;
     let mut bullet_other = state.options.bullet_other;
 
     if bullet != '*' {
@@ -1,11 +1,14 @@
 //! JS equivalent https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/check-emphasis.js
 
-use crate::state::State;
+use crate::{state::State, StyleAnswer, StyleQuery};
 use alloc::{boxed::Box, format};
-use markdown::message::Message;
+use markdown::{mdast::Node, message::Message};
 
-pub fn check_emphasis(state: &State) -> Result<char, Message> {
-    let marker = state.options.emphasis;
+pub fn check_emphasis(state: &State, node: &Node) -> Result<char, Message> {
+    let marker = match state.style(node, &StyleQuery::Emphasis) {
+        Some(StyleAnswer::Marker(marker)) => marker,
+        _ => state.options.emphasis,
+    };
 
     if marker != '*' && marker != '_' {
         return Err(Message {
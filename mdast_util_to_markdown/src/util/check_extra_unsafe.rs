@@ -0,0 +1,25 @@
+//! No JS equivalent: the JS API gets extra patterns from extensions, which
+//! are compiled as they are used.
+
+use crate::{r#unsafe::Unsafe, state::State, Options};
+use alloc::{boxed::Box, format};
+use markdown::message::Message;
+use regex::Regex;
+
+pub fn check_extra_unsafe(options: &Options) -> Result<(), Message> {
+    for pattern in Unsafe::get_extra_unsafe(options) {
+        if let Err(error) = Regex::new(&State::pattern_source(&pattern)) {
+            return Err(Message {
+                place: None,
+                reason: format!(
+                    "Cannot use pattern for `{}` in `options.extra_unsafe`, expected valid `before` and `after` regexes: {}",
+                    pattern.character, error
+                ),
+                rule_id: Box::new("unexpected-pattern".into()),
+                source: Box::new("mdast-util-to-markdown".into()),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -1,11 +1,14 @@
 //! JS equivalent https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/check-fence.js
 
-use crate::state::State;
+use crate::{state::State, StyleAnswer, StyleQuery};
 use alloc::{boxed::Box, format};
-use markdown::message::Message;
+use markdown::{mdast::Node, message::Message};
 
-pub fn check_fence(state: &mut State) -> Result<char, Message> {
-    let marker = state.options.fence;
+pub fn check_fence(state: &mut State, node: &Node) -> Result<char, Message> {
+    let marker = match state.style(node, &StyleQuery::Fence) {
+        Some(StyleAnswer::Marker(marker)) => marker,
+        _ => state.options.fence,
+    };
 
     if marker != '`' && marker != '~' {
         return Err(Message {
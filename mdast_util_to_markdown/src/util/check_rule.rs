@@ -1,11 +1,14 @@
 //! JS equivalent https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/check-rule.js
 
-use crate::state::State;
+use crate::{state::State, StyleAnswer, StyleQuery};
 use alloc::{boxed::Box, format};
-use markdown::message::Message;
+use markdown::{mdast::Node, message::Message};
 
-pub fn check_rule(state: &State) -> Result<char, Message> {
-    let marker = state.options.rule;
+pub fn check_rule(state: &State, node: &Node) -> Result<char, Message> {
+    let marker = match state.style(node, &StyleQuery::Rule) {
+        Some(StyleAnswer::Marker(marker)) => marker,
+        _ => state.options.rule,
+    };
 
     if marker != '*' && marker != '-' && marker != '_' {
         return Err(Message {
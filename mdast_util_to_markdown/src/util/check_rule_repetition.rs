@@ -1,11 +1,14 @@
 //! JS equivalent https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/check-rule-repetition.js
 
-use crate::state::State;
+use crate::{state::State, StyleAnswer, StyleQuery};
 use alloc::{boxed::Box, format};
-use markdown::message::Message;
+use markdown::{mdast::Node, message::Message};
 
-pub fn check_rule_repetition(state: &State) -> Result<u32, Message> {
-    let repetition = state.options.rule_repetition;
+pub fn check_rule_repetition(state: &State, node: &Node) -> Result<u32, Message> {
+    let repetition = match state.style(node, &StyleQuery::RuleRepetition) {
+        Some(StyleAnswer::Repetition(repetition)) => repetition,
+        _ => state.options.rule_repetition,
+    };
 
     if repetition < 3 {
         return Err(Message {
@@ -1,14 +1,18 @@
 //! JS equivalent https://github.com/syntax-tree/mdast-util-to-markdown/blob/main/lib/util/format-code-as-indented.js
 
-use crate::state::State;
-use markdown::mdast::Code;
+use crate::{state::State, StyleAnswer, StyleQuery};
+use markdown::mdast::{Code, Node};
 use regex::Regex;
 
-pub fn format_code_as_indented(code: &Code, state: &State) -> bool {
+pub fn format_code_as_indented(code: &Code, node: &Node, state: &State) -> bool {
+    let fences = match state.style(node, &StyleQuery::Fences) {
+        Some(StyleAnswer::Flag(fences)) => fences,
+        _ => state.options.fences,
+    };
     let non_whitespace = code.value.chars().any(|c| !c.is_whitespace());
     let blank = Regex::new(r"^[\t ]*(?:[\r\n]|$)|(?:^|[\r\n])[\t ]*$").unwrap();
 
-    !state.options.fences
+    !fences
         && !code.value.is_empty()
         && code.lang.is_none()
         && non_whitespace
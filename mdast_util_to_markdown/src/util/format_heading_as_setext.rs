@@ -18,7 +18,9 @@ pub fn format_heading_as_setext(heading: &Heading, state: &State) -> bool {
     }
 
     heading.depth < 3
-        && !to_string(&heading.children).is_empty()
+        && !to_string(&heading.children)
+            .trim_matches([' ', '\t', '\r', '\n'])
+            .is_empty()
         && (state.options.setext || literal_with_line_break)
 }
 
@@ -0,0 +1,76 @@
+//! Change the line endings of generated markdown.
+
+use alloc::string::String;
+use markdown::LineEnding;
+
+/// Turn line feeds (`\n`) in `value` into `line_ending`.
+///
+/// Carriage returns (`\r`, `\r\n`) are kept as they are, unless `normalize`
+/// is on, in which case they are turned into `line_ending` too.
+pub fn convert_line_endings(value: &str, line_ending: &LineEnding, normalize: bool) -> String {
+    let ending = line_ending.as_str();
+    let bytes = value.as_bytes();
+    let mut result = String::with_capacity(value.len());
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        if byte == b'\n' || byte == b'\r' {
+            let size = if byte == b'\r' && bytes.get(index + 1) == Some(&b'\n') {
+                2
+            } else {
+                1
+            };
+
+            result.push_str(&value[start..index]);
+
+            if byte == b'\n' || normalize {
+                result.push_str(ending);
+            } else {
+                result.push_str(&value[index..index + size]);
+            }
+
+            index += size;
+            start = index;
+        } else {
+            index += 1;
+        }
+    }
+
+    result.push_str(&value[start..]);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert() {
+        assert_eq!(
+            convert_line_endings("a\nb\n", &LineEnding::CarriageReturnLineFeed, false),
+            "a\r\nb\r\n",
+            "should turn line feeds into the line ending"
+        );
+
+        assert_eq!(
+            convert_line_endings("a\r\nb\rc\n", &LineEnding::CarriageReturnLineFeed, false),
+            "a\r\nb\rc\r\n",
+            "should keep carriage returns by default"
+        );
+
+        assert_eq!(
+            convert_line_endings("a\r\nb\rc\n", &LineEnding::LineFeed, true),
+            "a\nb\nc\n",
+            "should normalize carriage returns if `normalize` is on"
+        );
+
+        assert_eq!(
+            convert_line_endings("a\r\nb\rc\n", &LineEnding::CarriageReturn, true),
+            "a\rb\rc\r",
+            "should support carriage returns as the line ending"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+//! JS equivalent: https://github.com/syntax-tree/mdast-util-mdx-jsx/blob/main/lib/index.js#L505
+
+use crate::{
+    construct_name::ConstructName,
+    state::{Info, State},
+    util::check_quote::check_quote,
+};
+use alloc::{
+    boxed::Box,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use markdown::{
+    mdast::{AttributeContent, AttributeValue, Node},
+    message::Message,
+};
+
+/// Serialize an MDX JSX element, flow or text.
+///
+/// Attributes are kept in order, and their values as they are: boolean
+/// attributes stay shorthand (`b`), and expressions are put between braces
+/// as is (`{...c}`, `d={e}`).
+pub fn mdx_jsx_element(
+    state: &mut State,
+    node: &Node,
+    name: Option<&str>,
+    attributes: &[AttributeContent],
+) -> Result<String, Message> {
+    let flow = matches!(node, Node::MdxJsxFlowElement(_));
+    let children = node.children().expect("The node to be a JSX element.");
+    let self_closing = name.is_some() && children.is_empty();
+    let name = name.unwrap_or_default();
+
+    if name.is_empty() && !attributes.is_empty() {
+        return Err(unexpected_attribute(
+            "Cannot serialize fragment w/ attributes",
+        ));
+    }
+
+    let mut serialized_attributes = Vec::with_capacity(attributes.len());
+
+    for attribute in attributes {
+        serialized_attributes.push(match attribute {
+            AttributeContent::Expression(expression) => format!("{{{}}}", expression.value),
+            AttributeContent::Property(property) => {
+                if property.name.is_empty() {
+                    return Err(unexpected_attribute("Cannot serialize attribute w/o name"));
+                }
+
+                match &property.value {
+                    None => property.name.clone(),
+                    Some(AttributeValue::Expression(expression)) => {
+                        format!("{}={{{}}}", property.name, expression.value)
+                    }
+                    Some(AttributeValue::Literal(value)) => {
+                        format!("{}={}", property.name, literal(state, value)?)
+                    }
+                }
+            }
+        });
+    }
+
+    let attributes_on_one_line = serialized_attributes.join(" ");
+    let close_size = if !self_closing {
+        1
+    } else if state.options.tight_self_closing {
+        2
+    } else {
+        3
+    };
+    let attributes_on_their_own_line = flow
+        && !attributes_on_one_line.is_empty()
+        && (attributes_on_one_line.contains(['\r', '\n'])
+            // `<`, the name, a space, the attributes, and the end of the tag.
+            || 2 + name.chars().count() + attributes_on_one_line.chars().count() + close_size
+                > state.options.print_width);
+
+    let mut value = format!("<{}", name);
+
+    if attributes_on_their_own_line {
+        value.push('\n');
+        value.push_str(&state.indent_lines(&serialized_attributes.join("\n"), indent));
+        value.push('\n');
+    } else if !attributes_on_one_line.is_empty() {
+        value.push(' ');
+        value.push_str(&attributes_on_one_line);
+    }
+
+    if self_closing {
+        if !state.options.tight_self_closing && !attributes_on_their_own_line {
+            value.push(' ');
+        }
+
+        value.push('/');
+    }
+
+    value.push('>');
+
+    if !children.is_empty() {
+        if flow {
+            state.enter(ConstructName::MdxJsxFlowElement);
+            let content = state.container_flow(node)?;
+            state.exit();
+            value.push('\n');
+            value.push_str(&state.indent_lines(&content, indent));
+            value.push('\n');
+        } else {
+            state.enter(ConstructName::MdxJsxTextElement);
+            value.push_str(&state.container_phrasing(node, &Info::new(">", "<"))?);
+            state.exit();
+        }
+    }
+
+    if !self_closing {
+        value.push_str("</");
+        value.push_str(name);
+        value.push('>');
+    }
+
+    Ok(value)
+}
+
+/// Serialize a literal attribute value, with quotes.
+fn literal(state: &State, value: &str) -> Result<String, Message> {
+    let mut quote = check_quote(state)?;
+    let alternative = if quote == '"' { '\'' } else { '"' };
+
+    if state.options.quote_smart
+        && value.matches(quote).count() > value.matches(alternative).count()
+    {
+        quote = alternative;
+    }
+
+    // Encode ampersands first, as character references in values are decoded
+    // when parsing.
+    Ok(format!(
+        "{}{}{}",
+        quote,
+        value
+            .replace('&', "&#x26;")
+            .replace(quote, &format!("&#x{:X};", u32::from(quote))),
+        quote
+    ))
+}
+
+/// Indent a line of attributes or children.
+fn indent(line: &str, _index: usize, blank: bool) -> String {
+    if blank {
+        String::new()
+    } else {
+        format!("  {}", line)
+    }
+}
+
+fn unexpected_attribute(reason: &str) -> Message {
+    Message {
+        place: None,
+        reason: reason.to_string(),
+        rule_id: Box::new("unexpected-attribute".into()),
+        source: Box::new("mdast-util-to-markdown".into()),
+    }
+}
@@ -2,6 +2,7 @@ pub mod check_bullet;
 pub mod check_bullet_ordered;
 pub mod check_bullet_other;
 pub mod check_emphasis;
+pub mod check_extra_unsafe;
 pub mod check_fence;
 pub mod check_quote;
 pub mod check_rule;
@@ -11,6 +12,8 @@ pub mod contains_control_or_whitespace;
 pub mod format_code_as_indented;
 pub mod format_heading_as_setext;
 pub mod format_link_as_auto_link;
+pub mod line_ending;
 pub mod longest_char_streak;
+pub mod mdx_jsx_element;
 pub mod pattern_in_scope;
 pub mod safe;
@@ -1,7 +1,6 @@
 //! JS equivalent https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/util/safe.js
 
-use alloc::{format, string::String, vec::Vec};
-use regex::Regex;
+use alloc::{format, string::String};
 
 pub struct EscapeInfos {
     pub after: bool,
@@ -26,21 +25,28 @@ impl<'a> SafeConfig<'a> {
 
 /// JS: <https://github.com/syntax-tree/mdast-util-to-markdown/blob/fd6a508/lib/util/safe.js#L148>.
 pub fn escape_backslashes(value: &str, after: &str) -> String {
-    let expression = Regex::new(r"\\[!-/:-@\[-`{-~]").unwrap();
-    let mut results: String = String::new();
     let whole = format!("{}{}", value, after);
-
-    let positions: Vec<usize> = expression.find_iter(&whole).map(|m| m.start()).collect();
+    let bytes = whole.as_bytes();
+    let mut results: String = String::new();
     let mut start = 0;
+    let mut index = 0;
 
-    for position in &positions {
-        if start != *position {
-            results.push_str(&value[start..*position]);
-        }
+    while index < value.len() {
+        // Look at the next byte instead of matching pairs, like the lookahead
+        // in JS, so that both backslashes in `\\(` are escaped.
+        if bytes[index] == b'\\'
+            && matches!(bytes.get(index + 1), Some(byte) if byte.is_ascii_punctuation())
+        {
+            if start != index {
+                results.push_str(&value[start..index]);
+            }
+
+            results.push('\\');
 
-        results.push('\\');
+            start = index;
+        }
 
-        start = *position;
+        index += 1;
     }
 
     results.push_str(&value[start..]);
@@ -14,6 +14,7 @@ fn block_quote() {
         to(&Node::Blockquote(Blockquote {
             children: vec![],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         ">\n",
@@ -24,9 +25,11 @@ fn block_quote() {
         to(&Node::Blockquote(Blockquote {
             children: vec![Node::Text(Text {
                 value: String::from("a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n",
@@ -39,20 +42,29 @@ fn block_quote() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::ThematicBreak(ThematicBreak {
+                    position: None,
+                    markers: None,
+                    raw: None,
                 }),
-                Node::ThematicBreak(ThematicBreak { position: None }),
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("b"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n>\n> ***\n>\n> b\n",
@@ -64,11 +76,14 @@ fn block_quote() {
             children: vec![Node::Paragraph(Paragraph {
                 children: vec![Node::Text(Text {
                     value: String::from("a\nb"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
-                position: None
+                position: None,
+                raw: None,
             }),],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> b\n",
@@ -81,16 +96,20 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("b"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })
                 ],
-                position: None
+                position: None,
+                raw: None,
             }),],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> ab\n",
@@ -103,9 +122,11 @@ fn block_quote() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a\nb"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Blockquote(Blockquote {
                     children: vec![
@@ -113,32 +134,41 @@ fn block_quote() {
                             children: vec![
                                 Node::Text(Text {
                                     value: String::from("a\n"),
-                                    position: None
+                                    position: None,
+                                    raw: None,
                                 }),
                                 Node::InlineCode(InlineCode {
                                     value: String::from("b\nc"),
-                                    position: None
+                                    position: None,
+                                    raw: None,
                                 }),
                                 Node::Text(Text {
                                     value: String::from("\nd"),
-                                    position: None
+                                    position: None,
+                                    raw: None,
                                 }),
                             ],
-                            position: None
+                            position: None,
+                            raw: None,
                         }),
                         Node::Heading(Heading {
                             children: vec![Node::Text(Text {
                                 value: String::from("a b"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
                             position: None,
-                            depth: 1
+                            depth: 1,
+                            markers: None,
+                            raw: None,
                         })
                     ],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> b\n>\n> > a\n> > `b\n> > c`\n> > d\n> >\n> > # a b\n",
@@ -151,17 +181,24 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
+                    }),
+                    Node::Break(Break {
+                        position: None,
+                        raw: None
                     }),
-                    Node::Break(Break { position: None }),
                     Node::Text(Text {
                         value: String::from("b"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })
                 ],
-                position: None
+                position: None,
+                raw: None,
             }),],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\\\n> b\n",
@@ -175,9 +212,12 @@ fn block_quote() {
                     value: String::from("a\nb\n\nc"),
                     position: None,
                     lang: None,
-                    meta: None
+                    meta: None,
+                    markers: None,
+                    raw: None,
                 })],
                 position: None,
+                raw: None,
             }),
             &Options {
                 fences: false,
@@ -195,9 +235,12 @@ fn block_quote() {
                 value: String::from("c\nd\n\ne"),
                 position: None,
                 lang: String::from("a\nb").into(),
-                meta: None
+                meta: None,
+                markers: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> ```a&#xA;b\n> c\n> d\n>\n> e\n> ```\n",
@@ -210,20 +253,25 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a\n"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::InlineCode(InlineCode {
                         value: String::from("b\nc"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\nd"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> `b\n> c`\n> d\n",
@@ -238,17 +286,21 @@ fn block_quote() {
                     title: Some("e\nf".into()),
                     url: "c\nd".into(),
                     identifier: "a\nb".into(),
-                    label: None
+                    label: None,
+                    raw: None,
                 }),
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a\nb"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 })
             ],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> [a\n> b]: <c&#xA;d> \"e\n> f\"\n>\n> a\n> b\n",
@@ -261,23 +313,30 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a\n"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Emphasis(Emphasis {
                         children: vec![Node::Text(Text {
                             value: String::from("c\nd"),
-                            position: None
+                            position: None,
+                            raw: None,
                         }),],
-                        position: None
+                        position: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\nd"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> *c\n> d*\n> d\n",
@@ -289,12 +348,16 @@ fn block_quote() {
             children: vec![Node::Heading(Heading {
                 children: vec![Node::Text(Text {
                     value: String::from("a\nb"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),],
                 position: None,
-                depth: 3
+                depth: 3,
+                markers: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> ### a&#xA;b\n",
@@ -307,12 +370,16 @@ fn block_quote() {
                 children: vec![Node::Heading(Heading {
                     children: vec![Node::Text(Text {
                         value: String::from("a\nb"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),],
                     position: None,
-                    depth: 1
+                    depth: 1,
+                    markers: None,
+                    raw: None,
                 })],
                 position: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -328,9 +395,12 @@ fn block_quote() {
         to(&Node::Blockquote(Blockquote {
             children: vec![Node::Html(Html {
                 value: String::from("<div\nhidden>"),
-                position: None
+                position: None,
+                kind: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> <div\n> hidden>\n",
@@ -343,20 +413,26 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Html(Html {
                         value: String::from("<span\nhidden>"),
-                        position: None
+                        position: None,
+                        kind: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\nb"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a <span\n> hidden>\n> b\n",
@@ -369,22 +445,27 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a\n"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Image(Image {
                         position: None,
                         alt: String::from("d\ne"),
                         url: String::from("b\nc"),
-                        title: Some(String::from("f\ng"))
+                        title: Some(String::from("f\ng")),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\nh"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> ![d\n> e](<b&#xA;c> \"f\n> g\")\n> h\n",
@@ -397,7 +478,8 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a\n"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::ImageReference(ImageReference {
                         position: None,
@@ -405,15 +487,19 @@ fn block_quote() {
                         label: Some(String::from("d\ne")),
                         reference_kind: ReferenceKind::Collapsed,
                         identifier: String::from("f"),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\ng"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> ![b\n> c][d\n> e]\n> g\n",
@@ -426,25 +512,31 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a\n"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Link(Link {
                         children: vec![Node::Text(Text {
                             value: String::from("d\ne"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         url: String::from("b\nc"),
-                        title: Some(String::from("f\ng"))
+                        title: Some(String::from("f\ng")),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\nh"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> [d\n> e](<b&#xA;c> \"f\n> g\")\n> h\n",
@@ -457,26 +549,32 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a\n"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::LinkReference(LinkReference {
                         children: vec![Node::Text(Text {
                             value: String::from("b\nc"),
-                            position: None
+                            position: None,
+                            raw: None,
                         }),],
                         position: None,
                         reference_kind: ReferenceKind::Collapsed,
                         identifier: String::from("f"),
-                        label: Some(String::from("d\ne"))
+                        label: Some(String::from("d\ne")),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\ng"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> [b\n> c][d\n> e]\n> g\n",
@@ -489,9 +587,11 @@ fn block_quote() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a\nb"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::List(List {
                     children: vec![
@@ -499,40 +599,58 @@ fn block_quote() {
                             children: vec![Node::Paragraph(Paragraph {
                                 children: vec![Node::Text(Text {
                                     value: String::from("c\nd"),
-                                    position: None
+                                    position: None,
+                                    raw: None,
                                 })],
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
                             position: None,
                             spread: false,
-                            checked: None
+                            checked: None,
+                            markers: None,
+                            raw: None,
                         }),
                         Node::ListItem(ListItem {
-                            children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                            children: vec![Node::ThematicBreak(ThematicBreak {
+                                position: None,
+                                markers: None,
+                                raw: None,
+                            })],
                             position: None,
                             spread: false,
-                            checked: None
+                            checked: None,
+                            markers: None,
+                            raw: None,
                         }),
                         Node::ListItem(ListItem {
                             children: vec![Node::Paragraph(Paragraph {
                                 children: vec![Node::Text(Text {
                                     value: String::from("e\nf"),
-                                    position: None
+                                    position: None,
+                                    raw: None,
                                 })],
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
                             position: None,
                             spread: false,
-                            checked: None
+                            checked: None,
+                            markers: None,
+                            raw: None,
                         }),
                     ],
                     position: None,
                     ordered: false,
                     start: None,
-                    spread: false
+                    kind: None,
+                    spread: false,
+                    markers: None,
+                    raw: None,
                 })
             ],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> b\n>\n> - c\n>   d\n> - ***\n> - e\n>   f\n",
@@ -545,23 +663,30 @@ fn block_quote() {
                 children: vec![
                     Node::Text(Text {
                         value: String::from("a\n"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Strong(Strong {
                         children: vec![Node::Text(Text {
                             value: String::from("c\nd"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: String::from("\nd"),
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> a\n> **c\n> d**\n> d\n",
@@ -571,10 +696,19 @@ fn block_quote() {
     assert_eq!(
         to(&Node::Blockquote(Blockquote {
             children: vec![
-                Node::ThematicBreak(ThematicBreak { position: None }),
-                Node::ThematicBreak(ThematicBreak { position: None })
+                Node::ThematicBreak(ThematicBreak {
+                    position: None,
+                    markers: None,
+                    raw: None,
+                }),
+                Node::ThematicBreak(ThematicBreak {
+                    position: None,
+                    markers: None,
+                    raw: None,
+                })
             ],
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "> ***\n>\n> ***\n",
@@ -10,7 +10,11 @@ use pretty_assertions::assert_eq;
 #[test]
 fn r#break() {
     assert_eq!(
-        to(&Node::Break(Break { position: None })).unwrap(),
+        to(&Node::Break(Break {
+            position: None,
+            raw: None
+        }))
+        .unwrap(),
         "\\\n",
         "should support a break"
     );
@@ -20,16 +24,23 @@ fn r#break() {
             children: vec![
                 Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::Break(Break {
+                    position: None,
+                    raw: None
                 }),
-                Node::Break(Break { position: None }),
                 Node::Text(Text {
                     value: String::from("b"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
             position: None,
-            depth: 3
+            depth: 3,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "### a b\n",
@@ -41,16 +52,23 @@ fn r#break() {
             children: vec![
                 Node::Text(Text {
                     value: String::from("a "),
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::Break(Break {
+                    position: None,
+                    raw: None
                 }),
-                Node::Break(Break { position: None }),
                 Node::Text(Text {
                     value: String::from("b"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
             position: None,
-            depth: 3
+            depth: 3,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "### a b\n",
@@ -12,7 +12,9 @@ fn text() {
                 value: String::from("a"),
                 position: None,
                 lang: None,
-                meta: None
+                meta: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 fences: false,
@@ -29,7 +31,9 @@ fn text() {
             value: String::from("a"),
             position: None,
             lang: None,
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```\na\n```\n",
@@ -41,7 +45,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("a".to_string()),
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```a\n```\n",
@@ -53,7 +59,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: None,
-            meta: Some("a".to_string())
+            meta: Some("a".to_string()),
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```\n```\n",
@@ -65,7 +73,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("a".to_string()),
-            meta: Some("b".to_string())
+            meta: Some("b".to_string()),
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```a b\n```\n",
@@ -77,7 +87,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("a b".to_string()),
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```a&#x20;b\n```\n",
@@ -89,7 +101,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("a\nb".to_string()),
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```a&#xA;b\n```\n",
@@ -101,7 +115,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("a`b".to_string()),
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```a&#x60;b\n```\n",
@@ -113,7 +129,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("a\\-b".to_string()),
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```a\\\\-b\n```\n",
@@ -125,7 +143,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("x".to_string()),
-            meta: Some("a b".to_string())
+            meta: Some("a b".to_string()),
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```x a b\n```\n",
@@ -137,7 +157,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("x".to_string()),
-            meta: Some("a\nb".to_string())
+            meta: Some("a\nb".to_string()),
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```x a&#xA;b\n```\n",
@@ -149,7 +171,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("x".to_string()),
-            meta: Some("a`b".to_string())
+            meta: Some("a`b".to_string()),
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```x a&#x60;b\n```\n",
@@ -161,7 +185,9 @@ fn text() {
             value: String::new(),
             position: None,
             lang: Some("x".to_string()),
-            meta: Some("a\\-b".to_string())
+            meta: Some("a\\-b".to_string()),
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```x a\\\\-b\n```\n",
@@ -174,7 +200,9 @@ fn text() {
                 value: String::new(),
                 position: None,
                 lang: None,
-                meta: None
+                meta: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 fence: '~',
@@ -192,7 +220,9 @@ fn text() {
                 value: String::new(),
                 position: None,
                 lang: Some("a`b".to_string()),
-                meta: None
+                meta: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 fence: '~',
@@ -209,7 +239,9 @@ fn text() {
             value: String::from("```\nasd\n```"),
             position: None,
             lang: None,
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "````\n```\nasd\n```\n````\n",
@@ -222,7 +254,9 @@ fn text() {
                 value: String::from("~~~\nasd\n~~~"),
                 position: None,
                 lang: None,
-                meta: None
+                meta: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 fence: '~',
@@ -239,7 +273,9 @@ fn text() {
             value: String::from("b"),
             position: None,
             lang: Some("a".to_string()),
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```a\nb\n```\n",
@@ -251,7 +287,9 @@ fn text() {
             value: String::from(" "),
             position: None,
             lang: None,
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```\n \n```\n",
@@ -263,7 +301,9 @@ fn text() {
             value: String::from("\na"),
             position: None,
             lang: None,
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```\n\na\n```\n",
@@ -275,7 +315,9 @@ fn text() {
             value: String::from(" \na"),
             position: None,
             lang: None,
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```\n \na\n```\n",
@@ -287,7 +329,9 @@ fn text() {
             value: String::from("a\n"),
             position: None,
             lang: None,
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```\na\n\n```\n",
@@ -299,7 +343,9 @@ fn text() {
             value: String::from("a\n "),
             position: None,
             lang: None,
-            meta: None
+            meta: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "```\na\n \n```\n",
@@ -312,7 +358,9 @@ fn text() {
                 value: String::from("  a\n\n b"),
                 position: None,
                 lang: None,
-                meta: None
+                meta: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 fences: false,
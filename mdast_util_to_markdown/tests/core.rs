@@ -1,8 +1,9 @@
 use markdown::mdast::{
     Break, Code, Definition, Heading, List, ListItem, Node, Paragraph, Root, Text, ThematicBreak,
 };
+use markdown::{to_html, to_mdast, ParseOptions};
 use mdast_util_to_markdown::{
-    to_markdown as to, to_markdown_with_options as to_md_with_opts, Options,
+    to_markdown as to, to_markdown_with_options as to_md_with_opts, DefinitionPlacement, Options,
 };
 use pretty_assertions::assert_eq;
 
@@ -14,20 +15,29 @@ fn core() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::ThematicBreak(ThematicBreak {
+                    position: None,
+                    markers: None,
+                    raw: None,
                 }),
-                Node::ThematicBreak(ThematicBreak { position: None }),
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("b"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\n\n***\n\nb\n",
@@ -39,15 +49,21 @@ fn core() {
             children: vec![
                 Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::Break(Break {
+                    position: None,
+                    raw: None
                 }),
-                Node::Break(Break { position: None }),
                 Node::Text(Text {
                     value: String::from("b"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\\\nb\n",
@@ -60,33 +76,40 @@ fn core() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Definition(Definition {
                     position: None,
                     url: String::new(),
                     title: None,
                     identifier: String::from("b"),
-                    label: None
+                    label: None,
+                    raw: None,
                 }),
                 Node::Definition(Definition {
                     position: None,
                     url: String::new(),
                     title: None,
                     identifier: String::from("c"),
-                    label: None
+                    label: None,
+                    raw: None,
                 }),
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("d"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\n\n[b]: <>\n\n[c]: <>\n\nd\n",
@@ -100,33 +123,40 @@ fn core() {
                     Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Definition(Definition {
                         position: None,
                         url: String::new(),
                         title: None,
                         identifier: String::from("b"),
-                        label: None
+                        label: None,
+                        raw: None,
                     }),
                     Node::Definition(Definition {
                         position: None,
                         url: String::new(),
                         title: None,
                         identifier: String::from("c"),
-                        label: None
+                        label: None,
+                        raw: None,
                     }),
                     Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("d"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             }),
             &Options {
                 tight_definitions: true,
@@ -144,67 +174,92 @@ fn core() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::List(List {
                     children: vec![Node::ListItem(ListItem {
                         children: vec![],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     })],
                     position: None,
                     ordered: false,
                     start: None,
-                    spread: false
+                    kind: None,
+                    spread: false,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::List(List {
                     children: vec![Node::ListItem(ListItem {
                         children: vec![],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     })],
                     position: None,
                     ordered: false,
                     start: None,
-                    spread: false
+                    kind: None,
+                    spread: false,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::List(List {
                     children: vec![Node::ListItem(ListItem {
                         children: vec![],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     })],
                     position: None,
                     ordered: true,
                     start: None,
-                    spread: false
+                    kind: None,
+                    spread: false,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::List(List {
                     children: vec![Node::ListItem(ListItem {
                         children: vec![],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     })],
                     position: None,
                     ordered: true,
                     start: None,
-                    spread: false
+                    kind: None,
+                    spread: false,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("d"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\n\n*\n\n-\n\n1.\n\n1)\n\nd\n",
@@ -219,28 +274,38 @@ fn core() {
                         value: String::from("a"),
                         position: None,
                         lang: None,
-                        meta: None
+                        meta: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::List(List {
                         children: vec![Node::ListItem(ListItem {
                             children: vec![],
                             position: None,
                             spread: false,
-                            checked: None
+                            checked: None,
+                            markers: None,
+                            raw: None,
                         })],
                         position: None,
                         ordered: false,
                         start: None,
-                        spread: false
+                        kind: None,
+                        spread: false,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::Code(Code {
                         value: String::from("b"),
                         position: None,
                         lang: None,
-                        meta: None
+                        meta: None,
+                        markers: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             }),
             &Options {
                 fences: false,
@@ -260,16 +325,21 @@ fn core() {
                         value: String::from("a"),
                         position: None,
                         lang: None,
-                        meta: None
+                        meta: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::Code(Code {
                         value: String::from("b"),
                         position: None,
                         lang: None,
-                        meta: None
+                        meta: None,
+                        markers: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             }),
             &Options {
                 fences: false,
@@ -287,21 +357,27 @@ fn core() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("b"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
             position: None,
             spread: false,
-            checked: None
+            checked: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "* a\n\n  b\n",
@@ -314,21 +390,26 @@ fn core() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Definition(Definition {
                     position: None,
                     url: String::from("d"),
                     title: None,
                     identifier: String::from("b"),
-                    label: Some(String::from("c"))
+                    label: Some(String::from("c")),
+                    raw: None,
                 }),
             ],
             position: None,
             spread: false,
-            checked: None
+            checked: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "* a\n\n  [c]: d\n",
@@ -341,22 +422,29 @@ fn core() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Heading(Heading {
                     children: vec![Node::Text(Text {
                         value: String::from("b"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
-                    depth: 1
+                    depth: 1,
+                    markers: None,
+                    raw: None,
                 })
             ],
             position: None,
             spread: false,
-            checked: None
+            checked: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "* a\n  # b\n",
@@ -370,22 +458,29 @@ fn core() {
                     Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
                     Node::Heading(Heading {
                         children: vec![Node::Text(Text {
                             value: String::from("b"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
-                        depth: 1
+                        depth: 1,
+                        markers: None,
+                        raw: None,
                     })
                 ],
                 position: None,
                 spread: false,
-                checked: None
+                checked: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -397,3 +492,57 @@ fn core() {
         "should not honour `spread: false` for a paragraph and a setext heading"
     );
 }
+
+#[test]
+fn definition_placement() {
+    let value = "[b]: B\n\n# h\n\n[a] [b] [c]\n\n[a]: A\n\n> [c]: C\n>\n> [c]\n";
+    let tree = to_mdast(value, &ParseOptions::default()).unwrap();
+
+    assert_eq!(
+        to(&tree).unwrap(),
+        value,
+        "should keep definitions in place by default"
+    );
+
+    let at_end = to_md_with_opts(
+        &tree,
+        &Options {
+            definition_placement: DefinitionPlacement::AtEnd { alphabetize: false },
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        at_end, "# h\n\n[a] [b] [c]\n\n> [c]: C\n>\n> [c]\n\n[b]: B\n\n[a]: A\n",
+        "should move definitions in the root to the end w/ `AtEnd`"
+    );
+
+    assert_eq!(
+        to_html(&at_end),
+        to_html(value),
+        "should resolve the same references after moving definitions"
+    );
+
+    let alphabetized = to_md_with_opts(
+        &tree,
+        &Options {
+            definition_placement: DefinitionPlacement::AtEnd { alphabetize: true },
+            tight_definitions: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        alphabetized,
+        "# h\n\n[a] [b] [c]\n\n> [c]: C\n>\n> [c]\n\n[a]: A\n[b]: B\n",
+        "should sort moved definitions w/ `alphabetize: true`, and join them w/ `tight_definitions`"
+    );
+
+    assert_eq!(
+        to_html(&alphabetized),
+        to_html(value),
+        "should resolve the same references after sorting definitions"
+    );
+}
@@ -12,7 +12,8 @@ fn defintion() {
             title: None,
             identifier: String::new(),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[]: <>\n",
@@ -25,7 +26,8 @@ fn defintion() {
             title: None,
             identifier: String::new(),
             position: None,
-            label: Some(String::from("a"))
+            label: Some(String::from("a")),
+            raw: None,
         }))
         .unwrap(),
         "[a]: <>\n",
@@ -38,7 +40,8 @@ fn defintion() {
             title: None,
             identifier: String::new(),
             position: None,
-            label: Some(String::from("\\"))
+            label: Some(String::from("\\")),
+            raw: None,
         }))
         .unwrap(),
         "[\\\\]: <>\n",
@@ -51,7 +54,8 @@ fn defintion() {
             title: None,
             identifier: String::new(),
             position: None,
-            label: Some(String::from("["))
+            label: Some(String::from("[")),
+            raw: None,
         }))
         .unwrap(),
         "[\\[]: <>\n",
@@ -64,7 +68,8 @@ fn defintion() {
             title: None,
             identifier: String::new(),
             position: None,
-            label: Some(String::from("]"))
+            label: Some(String::from("]")),
+            raw: None,
         }))
         .unwrap(),
         "[\\]]: <>\n",
@@ -77,7 +82,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <>\n",
@@ -90,7 +96,8 @@ fn defintion() {
             title: None,
             identifier: String::from(r"\\"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[\\\\]: <>\n",
@@ -103,7 +110,8 @@ fn defintion() {
             title: None,
             identifier: String::from("["),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[\\[]: <>\n",
@@ -116,7 +124,8 @@ fn defintion() {
             title: None,
             identifier: String::from("]"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[\\]]: <>\n",
@@ -129,7 +138,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: b\n",
@@ -142,7 +152,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <b c>\n",
@@ -155,7 +166,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <b \\<c>\n",
@@ -168,7 +180,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <b \\>c>\n",
@@ -181,7 +194,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <b \\\\.c>\n",
@@ -194,7 +208,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <b&#xA;c>\n",
@@ -207,7 +222,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <\x0C>\n",
@@ -220,7 +236,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: b\\(c\n",
@@ -233,7 +250,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: b\\)c\n",
@@ -246,7 +264,8 @@ fn defintion() {
             title: None,
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: b\\\\?c\n",
@@ -259,7 +278,8 @@ fn defintion() {
             title: String::from("b").into(),
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <> \"b\"\n",
@@ -272,7 +292,8 @@ fn defintion() {
             title: String::from("c").into(),
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: b \"c\"\n",
@@ -285,7 +306,8 @@ fn defintion() {
             title: String::from("\"").into(),
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <> \"\\\"\"\n",
@@ -298,7 +320,8 @@ fn defintion() {
             title: String::from("\\").into(),
             identifier: String::from("a"),
             position: None,
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]: <> \"\\\\\"\n",
@@ -312,7 +335,8 @@ fn defintion() {
                 title: String::from("b").into(),
                 identifier: String::from("a"),
                 position: None,
-                label: None
+                label: None,
+                raw: None,
             }),
             &Options {
                 quote: '\'',
@@ -331,7 +355,8 @@ fn defintion() {
                 title: String::from("'").into(),
                 identifier: String::from("a"),
                 position: None,
-                label: None
+                label: None,
+                raw: None,
             }),
             &Options {
                 quote: '\'',
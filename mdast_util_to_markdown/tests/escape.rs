@@ -0,0 +1,329 @@
+use markdown::{
+    mdast::{Link, Node, Paragraph, Root, Text},
+    to_mdast as from,
+};
+use mdast_util_to_markdown::{
+    escape_text, escape_text_with_options, to_markdown as to, EscapeContext, Options, UnsafePattern,
+};
+use pretty_assertions::assert_eq;
+
+const HOSTILE: &[&str] = &[
+    "a",
+    "# a",
+    "#a",
+    "###### a",
+    "a #",
+    "1. a",
+    "1) a",
+    "123456789. a",
+    "1.",
+    "- a",
+    "+ a",
+    "* a",
+    "-",
+    "> a",
+    ">",
+    "a > b",
+    "***",
+    "---",
+    "___",
+    "- - -",
+    "=",
+    "a\n=",
+    "a\n---",
+    "a\n- b",
+    "a\n# b",
+    "a\n> b",
+    "a\n1. b",
+    "```",
+    "```js",
+    "~~~",
+    "~~~a~~~",
+    "`a`",
+    "``a``",
+    "a ` b",
+    "$a$",
+    "$$",
+    "|",
+    "a | b",
+    "| a | b |\n| - | - |",
+    "*a*",
+    "**a**",
+    "_a_",
+    "__a__",
+    "a*b*c",
+    "a_b_c",
+    "*",
+    "_",
+    "[a]",
+    "[a](b)",
+    "[a]: b",
+    "![a](b)",
+    "!",
+    "]",
+    "a]b",
+    "<a>",
+    "<div>",
+    "<!-- a -->",
+    "<https://example.com>",
+    "<a@b.c>",
+    "&",
+    "&amp;",
+    "&#123;",
+    "&#x20;",
+    "\\",
+    "a\\",
+    "\\a",
+    "\\*",
+    "\\\\(",
+    "\\\\!",
+    "a\\\nb",
+    "a  \nb",
+    "a \nb",
+    "a\n b",
+    "a\n    b",
+    " a",
+    "a ",
+    "  a  ",
+    "    a",
+    "\ta",
+    "a\t",
+    "a\tb",
+    "#\t",
+    "é # ü",
+    "a\rb",
+    "a\r\nb",
+];
+
+#[test]
+fn escape() {
+    assert_eq!(
+        escape_text("# a", EscapeContext::Flow),
+        "\\# a",
+        "should escape headings in flow"
+    );
+
+    assert_eq!(
+        escape_text("# a", EscapeContext::Text),
+        "# a",
+        "should not escape headings in text"
+    );
+
+    assert_eq!(
+        escape_text("*a*", EscapeContext::Text),
+        "\\*a\\*",
+        "should escape attention in text"
+    );
+
+    assert_eq!(
+        escape_text(" a ", EscapeContext::Flow),
+        "&#x20;a&#x20;",
+        "should encode initial and final whitespace in flow"
+    );
+
+    assert_eq!(
+        escape_text("a]b", EscapeContext::LabelText),
+        "a\\]b",
+        "should escape brackets in labels"
+    );
+
+    assert_eq!(
+        escape_text("a]b", EscapeContext::Flow),
+        "a]b",
+        "should not escape closing brackets in flow"
+    );
+
+    assert_eq!(
+        escape_text("a\"b'c", EscapeContext::TitleQuote),
+        "a\\\"b'c",
+        "should escape quotes in titles in quotes"
+    );
+
+    assert_eq!(
+        escape_text("a\"b'c", EscapeContext::TitleApostrophe),
+        "a\"b\\'c",
+        "should escape apostrophes in titles in apostrophes"
+    );
+
+    assert_eq!(
+        escape_text("a(b)", EscapeContext::DestinationRaw),
+        "a\\(b\\)",
+        "should escape parens in raw destinations"
+    );
+
+    assert_eq!(
+        escape_text("<a>", EscapeContext::DestinationLiteral),
+        "\\<a\\>",
+        "should escape angle brackets in literal destinations"
+    );
+
+    assert_eq!(
+        escape_text("\\\\(", EscapeContext::Flow),
+        "\\\\\\\\(",
+        "should escape consecutive backslashes before punctuation"
+    );
+
+    assert_eq!(
+        escape_text_with_options(
+            "{a}",
+            EscapeContext::Flow,
+            &Options {
+                mdx: true,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "\\{a}",
+        "should support options"
+    );
+
+    assert_eq!(
+        escape_text_with_options(
+            "a",
+            EscapeContext::Text,
+            &Options {
+                extra_unsafe: vec![UnsafePattern {
+                    character: '|',
+                    after: Some(")".into()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }
+        )
+        .unwrap_err()
+        .rule_id,
+        Box::new("unexpected-pattern".into()),
+        "should error on invalid regexes in `extra_unsafe`"
+    );
+
+    for value in HOSTILE {
+        let flow = escape_text(value, EscapeContext::Flow);
+
+        assert!(
+            from(&flow, &Default::default())
+                .unwrap()
+                .eq_ignore_position(&root(vec![paragraph(vec![text(value)])])),
+            "should roundtrip `{:?}` as flow (escaped as `{:?}`)",
+            value,
+            flow
+        );
+
+        assert_eq!(
+            to(&paragraph(vec![text(value)])).unwrap(),
+            format!("{}\n", flow),
+            "should escape `{:?}` as flow like a paragraph",
+            value
+        );
+
+        let source = format!("a {} b", escape_text(value, EscapeContext::Text));
+        assert!(
+            from(&source, &Default::default())
+                .unwrap()
+                .eq_ignore_position(&root(vec![paragraph(vec![text(&format!(
+                    "a {} b",
+                    value
+                ))])])),
+            "should roundtrip `{:?}` as text (escaped as `{:?}`)",
+            value,
+            source
+        );
+
+        let source = format!("[{}](b)", escape_text(value, EscapeContext::LabelText));
+        assert_eq!(
+            link(&source).children.len(),
+            1,
+            "should roundtrip `{:?}` as a label (escaped as `{:?}`)",
+            value,
+            source
+        );
+        assert!(
+            link(&source).children[0].eq_ignore_position(&text(value)),
+            "should roundtrip `{:?}` as a label (escaped as `{:?}`)",
+            value,
+            source
+        );
+
+        let source = format!(
+            "[a](b \"{}\")",
+            escape_text(value, EscapeContext::TitleQuote)
+        );
+        assert_eq!(
+            link(&source).title,
+            Some(value.to_string()),
+            "should roundtrip `{:?}` as a title in quotes",
+            value
+        );
+
+        let source = format!(
+            "[a](b '{}')",
+            escape_text(value, EscapeContext::TitleApostrophe)
+        );
+        assert_eq!(
+            link(&source).title,
+            Some(value.to_string()),
+            "should roundtrip `{:?}` as a title in apostrophes",
+            value
+        );
+
+        // Line endings cannot occur in destinations.
+        if !value.contains(['\n', '\r']) {
+            let source = format!(
+                "[a](<{}>)",
+                escape_text(value, EscapeContext::DestinationLiteral)
+            );
+            assert_eq!(
+                link(&source).url,
+                *value,
+                "should roundtrip `{:?}` as a literal destination",
+                value
+            );
+        }
+
+        // Whitespace cannot occur in raw destinations.
+        if !value.contains([' ', '\t', '\n', '\r']) {
+            let source = format!("[a]({})", escape_text(value, EscapeContext::DestinationRaw));
+            assert_eq!(
+                link(&source).url,
+                *value,
+                "should roundtrip `{:?}` as a raw destination",
+                value
+            );
+        }
+    }
+}
+
+fn root(children: Vec<Node>) -> Node {
+    Node::Root(Root {
+        children,
+        position: None,
+        raw: None,
+    })
+}
+
+fn paragraph(children: Vec<Node>) -> Node {
+    Node::Paragraph(Paragraph {
+        children,
+        position: None,
+        raw: None,
+    })
+}
+
+fn text(value: &str) -> Node {
+    Node::Text(Text {
+        value: value.into(),
+        position: None,
+        raw: None,
+    })
+}
+
+fn link(source: &str) -> Link {
+    let tree = from(source, &Default::default()).unwrap();
+
+    match tree.children().map(|children| children.as_slice()) {
+        Some([Node::Paragraph(paragraph)]) => match paragraph.children.as_slice() {
+            [Node::Link(link)] => link.clone(),
+            _ => panic!("expected a single link in `{:?}`", source),
+        },
+        _ => panic!("expected a single paragraph in `{:?}`", source),
+    }
+}
@@ -10,7 +10,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "#\n",
@@ -21,7 +23,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 6
+            depth: 6,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "######\n",
@@ -32,7 +36,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 7
+            depth: 7,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "######\n",
@@ -43,7 +49,9 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![],
             position: None,
-            depth: 0
+            depth: 0,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "#\n",
@@ -54,10 +62,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# a\n",
@@ -69,10 +80,13 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 position: None,
-                depth: 1
+                depth: 1,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -89,10 +103,13 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 position: None,
-                depth: 2
+                depth: 2,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -109,10 +126,13 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 position: None,
-                depth: 3
+                depth: 3,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -129,10 +149,13 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![Node::Text(Text {
                     value: String::from("aa\rb"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 position: None,
-                depth: 2
+                depth: 2,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -149,10 +172,13 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![Node::Text(Text {
                     value: String::from("a\r\nbbb"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 position: None,
-                depth: 1
+                depth: 1,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -169,7 +195,9 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![],
                 position: None,
-                depth: 1
+                depth: 1,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -186,7 +214,9 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![],
                 position: None,
-                depth: 2
+                depth: 2,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 setext: true,
@@ -198,6 +228,75 @@ fn heading() {
         "should serialize an empty heading w/ rank 1 as atx when `setext: true`"
     );
 
+    assert_eq!(
+        to_md_with_opts(
+            &Node::Heading(Heading {
+                children: vec![Node::Text(Text {
+                    value: String::from(" \n"),
+                    position: None,
+                    raw: None,
+                })],
+                position: None,
+                depth: 1,
+                markers: None,
+                raw: None,
+            }),
+            &Options {
+                setext: true,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "# &#x20;&#xA;\n",
+        "should serialize a whitespace only heading as atx when `setext: true`"
+    );
+
+    assert_eq!(
+        to_md_with_opts(
+            &Node::Heading(Heading {
+                children: vec![Node::Text(Text {
+                    value: String::from("a\n"),
+                    position: None,
+                    raw: None,
+                })],
+                position: None,
+                depth: 2,
+                markers: None,
+                raw: None,
+            }),
+            &Options {
+                setext: true,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "a\n-\n",
+        "should not serialize a blank last line in setext headings"
+    );
+
+    assert_eq!(
+        to_md_with_opts(
+            &Node::Heading(Heading {
+                children: vec![Node::Text(Text {
+                    value: String::from("¡olé!"),
+                    position: None,
+                    raw: None,
+                })],
+                position: None,
+                depth: 1,
+                markers: None,
+                raw: None,
+            }),
+            &Options {
+                setext: true,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "¡olé!\n=====\n",
+        "should serialize a setext underline as long as the characters of the last line"
+    );
+
     //assert_eq!(
     //    to(&Node::Heading(Heading {
     //        children: vec![],
@@ -213,10 +312,14 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Html(Html {
                 value: "<a\n/>".to_string(),
-                position: None
+                position: None,
+                kind: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }),)
         .unwrap(),
         "<a\n/>\n==\n",
@@ -227,10 +330,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a\nb"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "a\nb\n=\n",
@@ -242,16 +348,23 @@ fn heading() {
             children: vec![
                 Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::Break(Break {
+                    position: None,
+                    raw: None
                 }),
-                Node::Break(Break { position: None }),
                 Node::Text(Text {
                     value: String::from("b"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "a\\\nb\n=\n",
@@ -263,7 +376,9 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![],
                 position: None,
-                depth: 1
+                depth: 1,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 close_atx: true,
@@ -280,10 +395,13 @@ fn heading() {
             &Node::Heading(Heading {
                 children: vec![Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 position: None,
-                depth: 3
+                depth: 3,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 close_atx: true,
@@ -299,10 +417,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("# a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "## # a\n",
@@ -313,10 +434,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("1) a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "## 1) a\n",
@@ -327,10 +451,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("+ a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "## + a\n",
@@ -341,10 +468,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("- a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "## - a\n",
@@ -355,10 +485,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("= a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "## = a\n",
@@ -369,10 +502,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("> a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 2
+            depth: 2,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "## > a\n",
@@ -383,10 +519,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a #"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# a \\#\n",
@@ -397,10 +536,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a ##"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# a #\\#\n",
@@ -411,10 +553,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a # b"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# a # b\n",
@@ -425,10 +570,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("  a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# &#x20; a\n",
@@ -439,10 +587,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("\t\ta"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# &#x9;\ta\n",
@@ -453,10 +604,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a  "),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# a &#x20;\n",
@@ -467,10 +621,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a\t\t"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "# a\t&#x9;\n",
@@ -481,10 +638,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a \n b"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 1
+            depth: 1,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "a&#x20;\n&#x20;b\n=======\n",
@@ -495,10 +655,13 @@ fn heading() {
         to(&Node::Heading(Heading {
             children: vec![Node::Text(Text {
                 value: String::from("a \n b"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
-            depth: 3
+            depth: 3,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "### a &#xA; b\n",
@@ -7,7 +7,9 @@ fn html() {
     assert_eq!(
         to(&Node::Html(Html {
             value: String::new(),
-            position: None
+            position: None,
+            kind: None,
+            raw: None,
         }))
         .unwrap(),
         "",
@@ -17,7 +19,9 @@ fn html() {
     assert_eq!(
         to(&Node::Html(Html {
             value: String::from("a\nb"),
-            position: None
+            position: None,
+            kind: None,
+            raw: None,
         }))
         .unwrap(),
         "a\nb\n",
@@ -29,14 +33,18 @@ fn html() {
             children: vec![
                 Node::Text(Text {
                     value: "a\n".to_string(),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Html(Html {
                     value: "<div>".to_string(),
-                    position: None
+                    position: None,
+                    kind: None,
+                    raw: None,
                 })
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a <div>\n",
@@ -48,14 +56,18 @@ fn html() {
             children: vec![
                 Node::Text(Text {
                     value: "a\r".to_string(),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Html(Html {
                     value: "<div>".to_string(),
-                    position: None
+                    position: None,
+                    kind: None,
+                    raw: None,
                 })
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a <div>\n",
@@ -67,14 +79,18 @@ fn html() {
             children: vec![
                 Node::Text(Text {
                     value: "a\r\n".to_string(),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Html(Html {
                     value: "<div>".to_string(),
-                    position: None
+                    position: None,
+                    kind: None,
+                    raw: None,
                 })
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a <div>\n",
@@ -86,14 +102,18 @@ fn html() {
             children: vec![
                 Node::Html(Html {
                     value: "<x>".to_string(),
-                    position: None
+                    position: None,
+                    kind: None,
+                    raw: None,
                 }),
                 Node::Text(Text {
                     value: "a".to_string(),
-                    position: None
+                    position: None,
+                    raw: None,
                 })
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "<x>a\n",
@@ -11,7 +11,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::new(),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![]()\n",
@@ -23,7 +24,8 @@ fn image() {
             position: None,
             alt: String::from("a"),
             url: String::new(),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![a]()\n",
@@ -35,7 +37,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("a"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](a)\n",
@@ -47,7 +50,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::new(),
-            title: Some(String::from("a"))
+            title: Some(String::from("a")),
+            raw: None,
         }))
         .unwrap(),
         "![](<> \"a\")\n",
@@ -59,7 +63,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("a"),
-            title: Some(String::from("b"))
+            title: Some(String::from("b")),
+            raw: None,
         }))
         .unwrap(),
         "![](a \"b\")\n",
@@ -71,7 +76,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](<b c>)\n",
@@ -83,7 +89,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b <c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](<b \\<c>)\n",
@@ -95,7 +102,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b >c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](<b \\>c>)\n",
@@ -107,7 +115,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b \\+c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](<b \\\\+c>)\n",
@@ -119,7 +128,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b\nc"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](<b&#xA;c>)\n",
@@ -131,7 +141,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b(c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](b\\(c)\n",
@@ -143,7 +154,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b)c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](b\\)c)\n",
@@ -155,7 +167,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("b\\+c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](b\\\\+c)\n",
@@ -167,7 +180,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::from("\x0C"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "![](<\x0C>)\n",
@@ -179,7 +193,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::new(),
-            title: Some(String::from("b\"c"))
+            title: Some(String::from("b\"c")),
+            raw: None,
         }))
         .unwrap(),
         "![](<> \"b\\\"c\")\n",
@@ -191,7 +206,8 @@ fn image() {
             position: None,
             alt: String::new(),
             url: String::new(),
-            title: Some(String::from("b\\.c"))
+            title: Some(String::from("b\\.c")),
+            raw: None,
         }))
         .unwrap(),
         "![](<> \"b\\\\.c\")\n",
@@ -204,7 +220,8 @@ fn image() {
                 position: None,
                 alt: String::new(),
                 url: String::new(),
-                title: Some(String::from("b"))
+                title: Some(String::from("b")),
+                raw: None,
             }),
             &Options {
                 quote: '\'',
@@ -222,7 +239,8 @@ fn image() {
                 position: None,
                 alt: String::new(),
                 url: String::new(),
-                title: Some(String::from("'"))
+                title: Some(String::from("'")),
+                raw: None,
             }),
             &Options {
                 quote: '\'',
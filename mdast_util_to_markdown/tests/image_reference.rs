@@ -10,7 +10,8 @@ fn image_reference() {
             alt: String::new(),
             reference_kind: ReferenceKind::Full,
             identifier: String::new(),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "![][]\n",
@@ -23,7 +24,8 @@ fn image_reference() {
             alt: String::from("a"),
             reference_kind: ReferenceKind::Full,
             identifier: String::new(),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "![a][]\n",
@@ -36,7 +38,8 @@ fn image_reference() {
             alt: String::new(),
             reference_kind: ReferenceKind::Full,
             identifier: String::from("a"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "![][a]\n",
@@ -49,7 +52,8 @@ fn image_reference() {
             alt: String::new(),
             reference_kind: ReferenceKind::Full,
             identifier: String::new(),
-            label: String::from("a").into()
+            label: String::from("a").into(),
+            raw: None,
         }))
         .unwrap(),
         "![][a]\n",
@@ -62,7 +66,8 @@ fn image_reference() {
             alt: String::from("A"),
             reference_kind: ReferenceKind::Shortcut,
             identifier: String::from("A"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "![A]\n",
@@ -75,7 +80,8 @@ fn image_reference() {
             alt: String::from("A"),
             reference_kind: ReferenceKind::Collapsed,
             identifier: String::from("A"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "![A][]\n",
@@ -88,7 +94,8 @@ fn image_reference() {
             alt: String::from("A"),
             reference_kind: ReferenceKind::Full,
             identifier: String::from("A"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "![A][A]\n",
@@ -102,6 +109,7 @@ fn image_reference() {
             label: String::from("&").into(),
             reference_kind: ReferenceKind::Full,
             identifier: String::from("&amp;"),
+            raw: None,
         }))
         .unwrap(),
         "![&][&]\n",
@@ -115,6 +123,7 @@ fn image_reference() {
             alt: String::from("&"),
             reference_kind: ReferenceKind::Full,
             identifier: String::from("&amp;"),
+            raw: None,
         }))
         .unwrap(),
         "![&][&]\n",
@@ -129,8 +138,10 @@ fn image_reference() {
                 alt: String::from("&a;"),
                 reference_kind: ReferenceKind::Full,
                 identifier: String::from("&b;"),
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "![\\&a;][&b;]\n",
@@ -144,6 +155,7 @@ fn image_reference() {
             alt: String::from("+"),
             reference_kind: ReferenceKind::Full,
             identifier: String::from("\\+"),
+            raw: None,
         }))
         .unwrap(),
         "![+][+]\n",
@@ -157,6 +169,7 @@ fn image_reference() {
             alt: String::from("a"),
             reference_kind: ReferenceKind::Collapsed,
             identifier: String::from("b"),
+            raw: None,
         }))
         .unwrap(),
         "![a][b]\n",
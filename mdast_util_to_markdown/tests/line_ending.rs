@@ -0,0 +1,99 @@
+use markdown::{
+    mdast::{Code, Node, Root},
+    to_mdast as from, LineEnding, ParseOptions,
+};
+use mdast_util_to_markdown::{to_markdown_with_options as to, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn line_ending() {
+    let crlf = Options {
+        line_ending: LineEnding::CarriageReturnLineFeed,
+        ..Default::default()
+    };
+
+    let tree = from(
+        "# a\n\n* b\n  c\n\n  * d\n\n> e\n> f\n\n```js\ng\n\nh\n```\n\n    i\n",
+        &ParseOptions::default(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        to(&tree, &crlf).unwrap(),
+        "# a\r\n\r\n* b\r\n  c\r\n\r\n  * d\r\n\r\n> e\r\n> f\r\n\r\n```js\r\ng\r\n\r\nh\r\n```\r\n\r\n```\r\ni\r\n```\r\n",
+        "should use CRLF for headings, lists, block quotes, and code"
+    );
+
+    assert_eq!(
+        to(
+            &tree,
+            &Options {
+                line_ending: LineEnding::CarriageReturn,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "# a\r\r* b\r  c\r\r  * d\r\r> e\r> f\r\r```js\rg\r\rh\r```\r\r```\ri\r```\r",
+        "should use CR"
+    );
+
+    let table = from(
+        "| a | b |\n| - | - |\n| c | d |\n\n* e\n",
+        &ParseOptions {
+            preserve: true,
+            ..ParseOptions::gfm()
+        },
+    )
+    .unwrap();
+    let result = to(
+        &table,
+        &Options {
+            line_ending: LineEnding::CarriageReturnLineFeed,
+            use_raw: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        result, "| a | b |\r\n| - | - |\r\n| c | d |\r\n\r\n* e\r\n",
+        "should use CRLF for raw tables"
+    );
+
+    assert!(
+        !result.replace("\r\n", "").contains('\n'),
+        "should not leave lone line feeds"
+    );
+
+    let code = Node::Root(Root {
+        children: vec![Node::Code(Code {
+            value: String::from("a\r\nb\rc\nd"),
+            position: None,
+            lang: None,
+            meta: None,
+            markers: None,
+            raw: None,
+        })],
+        position: None,
+        raw: None,
+    });
+
+    assert_eq!(
+        to(&code, &crlf).unwrap(),
+        "```\r\na\r\nb\rc\r\nd\r\n```\r\n",
+        "should keep carriage returns in values by default"
+    );
+
+    assert_eq!(
+        to(
+            &code,
+            &Options {
+                normalize_line_endings: true,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "```\na\nb\nc\nd\n```\n",
+        "should turn carriage returns in values into the line ending w/ `normalize_line_endings`"
+    );
+}
@@ -11,7 +11,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::new(),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[]()\n",
@@ -22,11 +23,13 @@ fn text() {
         to(&Node::Link(Link {
             children: vec![Node::Text(Text {
                 value: String::from("a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             url: String::new(),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[a]()\n",
@@ -38,7 +41,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("a"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](a)\n",
@@ -50,7 +54,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::new(),
-            title: Some(String::from("a"))
+            title: Some(String::from("a")),
+            raw: None,
         }))
         .unwrap(),
         "[](<> \"a\")\n",
@@ -62,7 +67,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("a"),
-            title: Some(String::from("b"))
+            title: Some(String::from("b")),
+            raw: None,
         }))
         .unwrap(),
         "[](a \"b\")\n",
@@ -74,7 +80,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](<b c>)\n",
@@ -86,7 +93,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b <c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](<b \\<c>)\n",
@@ -98,7 +106,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b >c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](<b \\>c>)\n",
@@ -110,7 +119,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b \\+c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](<b \\\\+c>)\n",
@@ -122,7 +132,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b\nc"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](<b&#xA;c>)\n",
@@ -134,7 +145,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b(c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](b\\(c)\n",
@@ -146,7 +158,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b)c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](b\\)c)\n",
@@ -158,7 +171,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("b\\.c"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](b\\\\.c)\n",
@@ -170,7 +184,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("\x0C"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](<\x0C>)\n",
@@ -182,7 +197,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::new(),
-            title: Some(String::from("b\\-c"))
+            title: Some(String::from("b\\-c")),
+            raw: None,
         }))
         .unwrap(),
         "[](<> \"b\\\\-c\")\n",
@@ -193,11 +209,13 @@ fn text() {
         to(&Node::Link(Link {
             children: vec![Node::Text(Text {
                 value: String::from("tel:123"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             url: String::from("tel:123"),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "<tel:123>\n",
@@ -209,11 +227,13 @@ fn text() {
             &Node::Link(Link {
                 children: vec![Node::Text(Text {
                     value: String::from("tel:123"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 position: None,
                 url: String::from("tel:123"),
-                title: None
+                title: None,
+                raw: None,
             }),
             &Options {
                 resource_link: true,
@@ -229,11 +249,13 @@ fn text() {
         to(&Node::Link(Link {
             children: vec![Node::Text(Text {
                 value: String::from("a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             url: String::from("a"),
-            title: None
+            title: None,
+            raw: None,
         }),)
         .unwrap(),
         "[a](a)\n",
@@ -244,11 +266,13 @@ fn text() {
         to(&Node::Link(Link {
             children: vec![Node::Text(Text {
                 value: String::from("tel:123"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             url: String::from("tel:123"),
-            title: None
+            title: None,
+            raw: None,
         }),)
         .unwrap(),
         "<tel:123>\n",
@@ -259,11 +283,13 @@ fn text() {
         to(&Node::Link(Link {
             children: vec![Node::Text(Text {
                 value: String::from("tel:123"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             url: String::from("tel:123"),
-            title: Some(String::from("a"))
+            title: Some(String::from("a")),
+            raw: None,
         }),)
         .unwrap(),
         "[tel:123](tel:123 \"a\")\n",
@@ -274,11 +300,13 @@ fn text() {
         to(&Node::Link(Link {
             children: vec![Node::Text(Text {
                 value: String::from("a@b.c"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             url: String::from("mailto:a@b.c"),
-            title: None
+            title: None,
+            raw: None,
         }),)
         .unwrap(),
         "<a@b.c>\n",
@@ -289,11 +317,13 @@ fn text() {
         to(&Node::Link(Link {
             children: vec![Node::Text(Text {
                 value: String::from("a.b-c_d@a.b"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             url: String::from("mailto:a.b-c_d@a.b"),
-            title: None
+            title: None,
+            raw: None,
         }),)
         .unwrap(),
         "<a.b-c_d@a.b>\n",
@@ -306,7 +336,8 @@ fn text() {
                 children: Vec::new(),
                 position: None,
                 url: String::new(),
-                title: Some("b".to_string())
+                title: Some("b".to_string()),
+                raw: None,
             }),
             &Options {
                 quote: '\'',
@@ -324,7 +355,8 @@ fn text() {
                 children: Vec::new(),
                 position: None,
                 url: String::new(),
-                title: Some("'".to_string())
+                title: Some("'".to_string()),
+                raw: None,
             }),
             &Options {
                 quote: '\'',
@@ -341,7 +373,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: "a b![c](d*e_f[g_h`i".to_string(),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](<a b![c](d*e_f[g_h`i>)\n",
@@ -353,7 +386,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: "a![b](c*d_e[f_g`h<i</j".to_string(),
-            title: None
+            title: None,
+            raw: None,
         }))
         .unwrap(),
         "[](a![b]\\(c*d_e[f_g`h<i</j)\n",
@@ -365,7 +399,8 @@ fn text() {
             children: Vec::new(),
             position: None,
             url: String::from("#"),
-            title: Some("a![b](c*d_e[f_g`h<i</j".to_string())
+            title: Some("a![b](c*d_e[f_g`h<i</j".to_string()),
+            raw: None,
         }))
         .unwrap(),
         "[](# \"a![b](c*d_e[f_g`h<i</j\")\n",
@@ -378,7 +413,8 @@ fn text() {
                 children: Vec::new(),
                 position: None,
                 url: String::from("#"),
-                title: Some("a![b](c*d_e[f_g`h<i</j".to_string())
+                title: Some("a![b](c*d_e[f_g`h<i</j".to_string()),
+                raw: None,
             }),
             &Options {
                 quote: '\'',
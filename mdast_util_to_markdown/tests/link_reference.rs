@@ -10,7 +10,8 @@ fn link_reference() {
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::new(),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[][]\n",
@@ -21,12 +22,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::new(),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[a][]\n",
@@ -39,7 +42,8 @@ fn link_reference() {
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::from("a"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[][a]\n",
@@ -53,6 +57,7 @@ fn link_reference() {
             reference_kind: ReferenceKind::Full,
             identifier: String::new(),
             label: Some(String::from("a")),
+            raw: None,
         }))
         .unwrap(),
         "[][a]\n",
@@ -63,12 +68,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("A"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Shortcut,
             identifier: String::from("A"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[A]\n",
@@ -79,12 +86,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("A"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Collapsed,
             identifier: String::from("A"),
-            label: Some("A".into())
+            label: Some("A".into()),
+            raw: None,
         }))
         .unwrap(),
         "[A][]\n",
@@ -95,12 +104,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("A"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::from("A"),
-            label: Some("A".into())
+            label: Some("A".into()),
+            raw: None,
         }))
         .unwrap(),
         "[A][A]\n",
@@ -111,12 +122,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("&"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::from("&amp;"),
-            label: Some("&".into())
+            label: Some("&".into()),
+            raw: None,
         }))
         .unwrap(),
         "[&][&]\n",
@@ -127,12 +140,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("&"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::from("&amp;"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[&][&]\n",
@@ -146,12 +161,15 @@ fn link_reference() {
                 label: None,
                 children: vec![Node::Text(Text {
                     value: String::from("&a;"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
                 reference_kind: ReferenceKind::Full,
                 identifier: String::from("&b;"),
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "[\\&a;][&b;]\n",
@@ -164,7 +182,8 @@ fn link_reference() {
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::from("a![b](c*d_e[f_g`h<i</j"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[][a!\\[b\\](c*d_e\\[f_g`h<i</j]\n",
@@ -175,12 +194,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("+"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Full,
             identifier: String::from("\\+"),
-            label: None
+            label: None,
+            raw: None,
         }))
         .unwrap(),
         "[+][+]\n",
@@ -191,12 +212,14 @@ fn link_reference() {
         to(&Node::LinkReference(LinkReference {
             children: vec![Node::Text(Text {
                 value: String::from("a"),
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             reference_kind: ReferenceKind::Collapsed,
             identifier: String::from("a"),
-            label: Some("b".to_string())
+            label: Some("b".to_string()),
+            raw: None,
         }))
         .unwrap(),
         "[a][b]\n",
@@ -12,7 +12,10 @@ fn list() {
             position: None,
             ordered: false,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "",
@@ -25,12 +28,17 @@ fn list() {
                 children: Vec::new(),
                 position: None,
                 spread: false,
-                checked: None
+                checked: None,
+                markers: None,
+                raw: None,
             })],
             position: None,
             ordered: false,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "*\n",
@@ -44,37 +52,54 @@ fn list() {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
-                    children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                    children: vec![Node::ThematicBreak(ThematicBreak {
+                        position: None,
+                        markers: None,
+                        raw: None,
+                    })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("b"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 })
             ],
             position: None,
             ordered: false,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "- a\n- ***\n- b\n",
@@ -88,25 +113,38 @@ fn list() {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
-                    children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                    children: vec![Node::ThematicBreak(ThematicBreak {
+                        position: None,
+                        markers: None,
+                        raw: None,
+                    })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
             ],
             position: None,
             ordered: false,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "- a\n- ***\n",
@@ -121,33 +159,48 @@ fn list() {
                         Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("a"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         }),
                         Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("b"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         })
                     ],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
-                    children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                    children: vec![Node::ThematicBreak(ThematicBreak {
+                        position: None,
+                        markers: None,
+                        raw: None,
+                    })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
             ],
             position: None,
             ordered: false,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "- a\n\n  b\n- ***\n",
@@ -160,12 +213,17 @@ fn list() {
                 children: Vec::new(),
                 position: None,
                 spread: false,
-                checked: None
+                checked: None,
+                markers: None,
+                raw: None,
             })],
             position: None,
             ordered: true,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "1.\n",
@@ -179,37 +237,54 @@ fn list() {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
-                    children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                    children: vec![Node::ThematicBreak(ThematicBreak {
+                        position: None,
+                        markers: None,
+                        raw: None,
+                    })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("b"),
-                            position: None
+                            position: None,
+                            raw: None,
                         }),],
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 })
             ],
             position: None,
             ordered: true,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "1. a\n2. ***\n3. b\n",
@@ -223,37 +298,54 @@ fn list() {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
-                    children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                    children: vec![Node::ThematicBreak(ThematicBreak {
+                        position: None,
+                        markers: None,
+                        raw: None,
+                    })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("b"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 })
             ],
             position: None,
             ordered: true,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "1. a\n2. ***\n3. b\n",
@@ -268,37 +360,54 @@ fn list() {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("a"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
-                        children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                        children: vec![Node::ThematicBreak(ThematicBreak {
+                            position: None,
+                            markers: None,
+                            raw: None,
+                        })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("b"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     })
                 ],
                 position: None,
                 ordered: true,
                 start: None,
-                spread: false
+                kind: None,
+                spread: false,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 increment_list_marker: false,
@@ -318,25 +427,38 @@ fn list() {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("a"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
-                        children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                        children: vec![Node::ThematicBreak(ThematicBreak {
+                            position: None,
+                            markers: None,
+                            raw: None,
+                        })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     })
                 ],
                 position: None,
                 ordered: true,
                 start: Some(0),
-                spread: false
+                kind: None,
+                spread: false,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 list_item_indent: IndentOptions::One,
@@ -356,31 +478,42 @@ fn list() {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("a\nb"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("c\nd"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     }),
                 ],
                 position: None,
                 ordered: false,
                 start: None,
-                spread: false
+                kind: None,
+                spread: false,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 list_item_indent: IndentOptions::Mixed,
@@ -400,31 +533,42 @@ fn list() {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("a\nb"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                }),],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                        Node::ListItem(ListItem {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("c\nd"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                })],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                    ],
                    position: None,
                    ordered: false,
                    start: None,
-                   spread:true
+                   kind: None,
+                   spread:true,
+                   markers: None,
+                   raw: None,
                }),
                &Options {
                    list_item_indent: IndentOptions::Mixed,
@@ -444,31 +588,42 @@ fn list() {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("a\nb"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                }),],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                        Node::ListItem(ListItem {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("c\nd"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                })],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                    ],
                    position: None,
                    ordered: true,
                    start: Some(9),
-                   spread: false
+                   kind: None,
+                   spread: false,
+                   markers: None,
+                   raw: None,
                }),
                &Options {
                    list_item_indent: IndentOptions::One,
@@ -488,31 +643,42 @@ fn list() {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("a\nb"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                }),],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                        Node::ListItem(ListItem {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("c\nd"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                })],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                    ],
                    position: None,
                    ordered: true,
                    start: Some(99),
-                   spread: false
+                   kind: None,
+                   spread: false,
+                   markers: None,
+                   raw: None,
                }),
                &Options {
                    list_item_indent: IndentOptions::One,
@@ -532,31 +698,42 @@ fn list() {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("a\nb"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                }),],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                        Node::ListItem(ListItem {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("c\nd"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                })],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                    ],
                    position: None,
                    ordered: true,
                    start: Some(999),
-                   spread: false
+                   kind: None,
+                   spread: false,
+                   markers: None,
+                   raw: None,
                }),
                &Options {
                    list_item_indent: IndentOptions::One,
@@ -576,31 +753,42 @@ fn list() {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("a\nb"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                }),],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                        Node::ListItem(ListItem {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("c\nd"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                })],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                    ],
                    position: None,
                    ordered: true,
                    start: Some(9),
-                   spread: false
+                   kind: None,
+                   spread: false,
+                   markers: None,
+                   raw: None,
                }),
                &Options {
                    list_item_indent: IndentOptions::Tab,
@@ -620,31 +808,42 @@ fn list() {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("a\nb"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                }),],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                        Node::ListItem(ListItem {
                            children: vec![Node::Paragraph(Paragraph {
                                children: vec![Node::Text(Text {
                                    value: String::from("c\nd"),
-                                   position: None
+                                   position: None,
+                                   raw: None,
                                })],
-                               position: None
+                               position: None,
+                               raw: None,
                            })],
                            position: None,
                            spread: false,
-                           checked: None
+                           checked: None,
+                           markers: None,
+                           raw: None,
                        }),
                    ],
                    position: None,
                    ordered: true,
                    start: Some(99),
-                   spread: false
+                   kind: None,
+                   spread: false,
+                   markers: None,
+                   raw: None,
                }),
                &Options {
                    list_item_indent: IndentOptions::Tab,
@@ -664,31 +863,42 @@ fn list() {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("a\nb"),
-                                position: None
+                                position: None,
+                                raw: None,
                             }),],
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: String::from("c\nd"),
-                                position: None
+                                position: None,
+                                raw: None,
                             })],
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
                         position: None,
                         spread: false,
-                        checked: None
+                        checked: None,
+                        markers: None,
+                        raw: None,
                     }),
                 ],
                 position: None,
                 ordered: true,
                 start: Some(999),
-                spread: false
+                kind: None,
+                spread: false,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 list_item_indent: IndentOptions::Tab,
@@ -11,7 +11,9 @@ fn list_item() {
             children: vec![],
             position: None,
             spread: false,
-            checked: None
+            checked: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "*\n",
@@ -24,7 +26,9 @@ fn list_item() {
                 children: Vec::new(),
                 position: None,
                 spread: false,
-                checked: None
+                checked: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 bullet: '+',
@@ -41,13 +45,17 @@ fn list_item() {
             children: vec![Node::Paragraph(Paragraph {
                 children: vec![Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
-                position: None
+                position: None,
+                raw: None,
             })],
             position: None,
             spread: false,
-            checked: None
+            checked: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "* a\n",
@@ -60,22 +68,32 @@ fn list_item() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::ThematicBreak(ThematicBreak {
+                    position: None,
+                    markers: None,
+                    raw: None,
                 }),
-                Node::ThematicBreak(ThematicBreak { position: None }),
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("b"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
             position: None,
             spread: false,
-            checked: None
+            checked: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "* a\n  ***\n  b\n",
@@ -89,15 +107,23 @@ fn list_item() {
                     Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     }),
-                    Node::ThematicBreak(ThematicBreak { position: None })
+                    Node::ThematicBreak(ThematicBreak {
+                        position: None,
+                        markers: None,
+                        raw: None,
+                    })
                 ],
                 position: None,
                 spread: false,
-                checked: None
+                checked: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 list_item_indent: IndentOptions::One,
@@ -115,13 +141,17 @@ fn list_item() {
                 children: vec![Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),],
                 position: None,
                 spread: false,
-                checked: None
+                checked: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 list_item_indent: IndentOptions::Mixed,
@@ -139,14 +169,18 @@ fn list_item() {
                 children: vec![Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
-                Node::ThematicBreak(ThematicBreak { position: None })],
+                Node::ThematicBreak(ThematicBreak { position: None, markers: None, raw: None   })],
                 position: None,
                 spread: true,
-                checked: None
+                checked: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 list_item_indent: IndentOptions::Mixed,
@@ -164,15 +198,23 @@ fn list_item() {
                 Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: String::from("a"),
-                        position: None
+                        position: None,
+                        raw: None,
                     })],
-                    position: None
+                    position: None,
+                    raw: None,
+                }),
+                Node::ThematicBreak(ThematicBreak {
+                    position: None,
+                    markers: None,
+                    raw: None,
                 }),
-                Node::ThematicBreak(ThematicBreak { position: None }),
             ],
             position: None,
             spread: false,
-            checked: None
+            checked: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "* a\n  ***\n",
@@ -225,25 +267,34 @@ fn list_item() {
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: String::from("a"),
-                            position: None
+                            position: None,
+                            raw: None,
                         })],
-                        position: None
+                        position: None,
+                        raw: None,
                     }),],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
-                    children: vec![Node::ThematicBreak(ThematicBreak { position: None })],
+                    children: vec![Node::ThematicBreak(ThematicBreak { position: None, markers: None, raw: None   })],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 })
             ],
             position: None,
             ordered: false,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "- a\n- ***\n",
@@ -269,19 +320,26 @@ fn list_item() {
                     children: vec![],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 }),
                 Node::ListItem(ListItem {
                     children: vec![create_list(create_list::<Option<Node>>(None))],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 })
             ],
             position: None,
             ordered: false,
             start: None,
-            spread: false
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "*\n* * -\n",
@@ -324,9 +382,11 @@ fn list_item() {
             create_list(Node::Paragraph(Paragraph {
                 children: vec![Node::Text(Text {
                     value: String::from("a"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
-                position: None
+                position: None,
+                raw: None,
             })),
             create_list::<Option<Node>>(None)
         ])))
@@ -342,12 +402,17 @@ fn list_item() {
                     children: vec![],
                     position: None,
                     spread: false,
-                    checked: None
+                    checked: None,
+                    markers: None,
+                    raw: None,
                 })],
                 position: None,
                 ordered: true,
                 start: None,
-                spread: false
+                kind: None,
+                spread: false,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 bullet_ordered: ')',
@@ -368,27 +433,38 @@ fn list_item() {
                             children: vec![],
                             position: None,
                             spread: false,
-                            checked: None
+                            checked: None,
+                            markers: None,
+                            raw: None,
                         })],
                         position: None,
                         ordered: true,
                         start: None,
-                        spread: false
+                        kind: None,
+                        spread: false,
+                        markers: None,
+                        raw: None,
                     }),
                     Node::List(List {
                         children: vec![Node::ListItem(ListItem {
                             children: vec![],
                             position: None,
                             spread: false,
-                            checked: None
+                            checked: None,
+                            markers: None,
+                            raw: None,
                         })],
                         position: None,
                         ordered: true,
                         start: None,
-                        spread: false
+                        kind: None,
+                        spread: false,
+                        markers: None,
+                        raw: None,
                     }),
                 ],
-                position: None
+                position: None,
+                raw: None,
             }),
             &Options {
                 bullet_ordered: ')',
@@ -433,10 +509,15 @@ where
             position: None,
             spread: false,
             checked: None,
+            markers: None,
+            raw: None,
         })],
         position: None,
         ordered: false,
         start: None,
+        kind: None,
         spread: false,
+        markers: None,
+        raw: None,
     })
 }
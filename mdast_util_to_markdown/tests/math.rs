@@ -9,7 +9,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$a$\n",
@@ -20,7 +21,8 @@ fn math() {
         to_md_with_opts(
             &Node::InlineMath(InlineMath {
                 value: String::from("a"),
-                position: None
+                position: None,
+                raw: None,
             }),
             &Options {
                 single_dollar_text_math: false,
@@ -35,7 +37,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::new(),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$$\n",
@@ -45,7 +48,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a \\$ b"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$$a \\$ b$$\n",
@@ -55,7 +59,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a \\$"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$$ a \\$ $$\n",
@@ -65,7 +70,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("$ a"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$$ $ a $$\n",
@@ -75,7 +81,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from(" a "),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$  a  $\n",
@@ -85,7 +92,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from(" a"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$ a$\n",
@@ -95,7 +103,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a "),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$a $\n",
@@ -106,7 +115,8 @@ fn math() {
         to(&Node::Math(Math {
             value: String::from("a"),
             position: None,
-            meta: None
+            meta: None,
+            raw: None,
         }))
         .unwrap(),
         "$$\na\n$$\n",
@@ -117,7 +127,8 @@ fn math() {
         to(&Node::Math(Math {
             value: String::new(),
             position: None,
-            meta: None
+            meta: None,
+            raw: None,
         }))
         .unwrap(),
         "$$\n$$\n",
@@ -128,7 +139,8 @@ fn math() {
         to(&Node::Math(Math {
             value: String::new(),
             position: None,
-            meta: String::from("a").into()
+            meta: String::from("a").into(),
+            raw: None,
         }))
         .unwrap(),
         "$$a\n$$\n",
@@ -139,7 +151,8 @@ fn math() {
         to(&Node::Math(Math {
             value: String::from("$$"),
             position: None,
-            meta: None
+            meta: None,
+            raw: None,
         }))
         .unwrap(),
         "$$$\n$$\n$$$\n",
@@ -150,9 +163,11 @@ fn math() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("a $ b"),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a \\$ b\n",
@@ -164,9 +179,11 @@ fn math() {
             &Node::Paragraph(Paragraph {
                 children: vec![Node::Text(Text {
                     value: String::from("a $ b"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
-                position: None
+                position: None,
+                raw: None,
             }),
             &Options {
                 single_dollar_text_math: false,
@@ -183,9 +200,11 @@ fn math() {
             &Node::Paragraph(Paragraph {
                 children: vec![Node::Text(Text {
                     value: String::from("a $$ b"),
-                    position: None
+                    position: None,
+                    raw: None,
                 })],
-                position: None
+                position: None,
+                raw: None,
             }),
             &Options {
                 single_dollar_text_math: false,
@@ -202,18 +221,22 @@ fn math() {
             children: vec![
                 Node::Text(Text {
                     value: String::from("a $"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::InlineMath(InlineMath {
                     value: String::from("b"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
                 Node::Text(Text {
                     value: String::from("$ c"),
-                    position: None
+                    position: None,
+                    raw: None,
                 }),
             ],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a \\$$b$\\$ c\n",
@@ -227,6 +250,7 @@ fn math() {
             title: String::from("a\n$\nb").into(),
             identifier: String::from("a"),
             label: String::from("a").into(),
+            raw: None,
         }))
         .unwrap(),
         "[a]: b \"a\n$\nb\"\n",
@@ -237,7 +261,8 @@ fn math() {
         to(&Node::Math(Math {
             value: String::new(),
             position: None,
-            meta: String::from("a\rb\nc").into()
+            meta: String::from("a\rb\nc").into(),
+            raw: None,
         }))
         .unwrap(),
         "$$a&#xD;b&#xA;c\n$$\n",
@@ -248,7 +273,8 @@ fn math() {
         to(&Node::Math(Math {
             value: String::new(),
             position: None,
-            meta: String::from("a$b").into()
+            meta: String::from("a$b").into(),
+            raw: None,
         }))
         .unwrap(),
         "$$a&#x24;b\n$$\n",
@@ -258,7 +284,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a\n- b"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$a - b$\n",
@@ -268,7 +295,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a\n#"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$a #$\n",
@@ -278,7 +306,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a\n1. "),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$a 1. $\n",
@@ -288,7 +317,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a\r- b"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$a - b$\n",
@@ -298,7 +328,8 @@ fn math() {
     assert_eq!(
         to(&Node::InlineMath(InlineMath {
             value: String::from("a\n- b"),
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "$a - b$\n",
@@ -0,0 +1,248 @@
+use markdown::{
+    mdast::{
+        AttributeContent, AttributeValue, MdxJsxAttribute, MdxJsxFlowElement, Node, Paragraph, Text,
+    },
+    to_mdast, ParseOptions,
+};
+use mdast_util_to_markdown::{to_markdown_with_options, Options};
+use pretty_assertions::assert_eq;
+
+/// Parse MDX, and serialize it again.
+fn mdx(value: &str, options: &Options) -> String {
+    to_markdown_with_options(&to_mdast(value, &ParseOptions::mdx()).unwrap(), options).unwrap()
+}
+
+fn element(name: Option<&str>, attributes: Vec<AttributeContent>) -> Node {
+    Node::MdxJsxFlowElement(MdxJsxFlowElement {
+        children: vec![],
+        position: None,
+        name: name.map(Into::into),
+        attributes,
+        raw: None,
+    })
+}
+
+fn literal(name: &str, value: &str) -> AttributeContent {
+    AttributeContent::Property(MdxJsxAttribute {
+        position: None,
+        name: name.into(),
+        value: Some(AttributeValue::Literal(value.into())),
+    })
+}
+
+#[test]
+fn mdx_jsx() {
+    let options = Options {
+        mdx: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mdx("<a />", &options),
+        "<a />\n",
+        "should support self-closing elements"
+    );
+
+    assert_eq!(
+        mdx("<></>", &options),
+        "<></>\n",
+        "should support fragments"
+    );
+
+    assert_eq!(
+        mdx("<a b c=\"d\" {...e} f={g} h={{i: 1}} />", &options),
+        "<a b c=\"d\" {...e} f={g} h={{i: 1}} />\n",
+        "should keep the order and form of attributes"
+    );
+
+    assert_eq!(
+        mdx("<a b='c\"d' />", &options),
+        "<a b=\"c&#x22;d\" />\n",
+        "should encode quotes in attribute values"
+    );
+
+    assert_eq!(
+        mdx("<a b=\"&amp;amp;\" />", &options),
+        "<a b=\"&#x26;amp;\" />\n",
+        "should encode ampersands in attribute values"
+    );
+
+    assert_eq!(
+        mdx(
+            "<a b='c\"d' />",
+            &Options {
+                quote_smart: true,
+                mdx: true,
+                ..Default::default()
+            }
+        ),
+        "<a b='c\"d' />\n",
+        "should support `quote_smart`"
+    );
+
+    assert_eq!(
+        mdx(
+            "<a b=\"c\" />",
+            &Options {
+                quote: '\'',
+                mdx: true,
+                ..Default::default()
+            }
+        ),
+        "<a b='c' />\n",
+        "should support `quote`"
+    );
+
+    assert_eq!(
+        mdx(
+            "<a b />",
+            &Options {
+                tight_self_closing: true,
+                mdx: true,
+                ..Default::default()
+            }
+        ),
+        "<a b/>\n",
+        "should support `tight_self_closing`"
+    );
+
+    assert_eq!(
+        mdx(
+            "<abc def=\"ghi\" jkl={mno} />",
+            &Options {
+                print_width: 20,
+                mdx: true,
+                ..Default::default()
+            }
+        ),
+        "<abc\n  def=\"ghi\"\n  jkl={mno}\n/>\n",
+        "should put attributes on their own lines if the tag is wider than `print_width`"
+    );
+
+    assert_eq!(
+        mdx(
+            "<abc def=\"ghi\" />",
+            &Options {
+                print_width: 17,
+                mdx: true,
+                ..Default::default()
+            }
+        ),
+        "<abc def=\"ghi\" />\n",
+        "should keep attributes on one line if the tag fits `print_width`"
+    );
+
+    assert_eq!(
+        mdx("<a b={c\n  d} e />", &options),
+        "<a\n  b={c\n  d}\n  e\n/>\n",
+        "should put attributes on their own lines if they include line endings"
+    );
+
+    assert_eq!(
+        mdx("<a>\n  b\n\n  <c>\n    * d\n  </c>\n</a>", &options),
+        "<a>\n  b\n\n  <c>\n    * d\n  </c>\n</a>\n",
+        "should indent children of flow elements"
+    );
+
+    assert_eq!(
+        mdx("a <b c>*d*</b> e", &options),
+        "a <b c>*d*</b> e\n",
+        "should support text elements"
+    );
+
+    assert_eq!(
+        mdx("a \\<b \\{c}", &options),
+        "a \\<b \\{c}\n",
+        "should escape `<` and `{{` in text w/ `mdx`"
+    );
+
+    assert_eq!(
+        to_markdown_with_options(
+            &Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a < b {c}".into(),
+                    position: None,
+                    raw: None,
+                })],
+                position: None,
+                raw: None,
+            }),
+            &Options::default()
+        )
+        .unwrap(),
+        "a < b {c}\n",
+        "should not escape `<` and `{{` in text w/o `mdx`"
+    );
+
+    assert_eq!(
+        to_markdown_with_options(&element(None, vec![literal("a", "b")]), &options)
+            .unwrap_err()
+            .reason,
+        "Cannot serialize fragment w/ attributes",
+        "should crash on fragments w/ attributes"
+    );
+
+    assert_eq!(
+        to_markdown_with_options(&element(Some("a"), vec![literal("", "b")]), &options)
+            .unwrap_err()
+            .reason,
+        "Cannot serialize attribute w/o name",
+        "should crash on attributes w/o name"
+    );
+}
+
+#[test]
+fn mdx_expression() {
+    let options = Options {
+        mdx: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        mdx("{a}", &options),
+        "{a}\n",
+        "should support expressions (flow)"
+    );
+
+    assert_eq!(
+        mdx("{a\n    b\n\n  c}", &options),
+        "{a\n    b\n\n  c}\n",
+        "should indent lines in expressions (flow)"
+    );
+
+    assert_eq!(
+        mdx("a {b} c", &options),
+        "a {b} c\n",
+        "should support expressions (text)"
+    );
+
+    assert_eq!(
+        mdx("import a from 'b'\nexport const c = {d: 1}", &options),
+        "import a from 'b'\nexport const c = {d: 1}\n",
+        "should support ESM"
+    );
+}
+
+#[test]
+fn mdx_roundtrip() {
+    let options = Options {
+        mdx: true,
+        print_width: 60,
+        ..Default::default()
+    };
+    let source = "import {Chart} from './chart.js'\n\n<Chart\n  data={[1, 2]}\n  {...props}\n  title='Sales \"2024\" &amp;amp; more'\n  legend\n>\n  Some *text* with <Tooltip tip={tip}>a tip</Tooltip> and {value}.\n\n  <Footer />\n</Chart>\n\n{/* comment */}";
+    let doc = mdx(source, &options);
+
+    assert_eq!(
+        doc,
+        "import {Chart} from './chart.js'\n\n<Chart\n  data={[1, 2]}\n  {...props}\n  title=\"Sales &#x22;2024&#x22; &#x26;amp; more\"\n  legend\n>\n  Some *text* with <Tooltip tip={tip}>a tip</Tooltip> and {value}.\n\n  <Footer />\n</Chart>\n\n{/* comment */}\n",
+        "should serialize components"
+    );
+
+    assert!(
+        to_mdast(&doc, &ParseOptions::mdx())
+            .unwrap()
+            .eq_ignore_position(&to_mdast(source, &ParseOptions::mdx()).unwrap()),
+        "should round-trip components"
+    );
+}
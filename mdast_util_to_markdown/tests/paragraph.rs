@@ -7,7 +7,8 @@ fn paragraph() {
     assert_eq!(
         to(&Node::Paragraph(Paragraph {
             children: vec![],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "",
@@ -18,9 +19,11 @@ fn paragraph() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("a\nb"),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\nb\n",
@@ -31,9 +34,11 @@ fn paragraph() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("  a"),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "&#x20; a\n",
@@ -44,9 +49,11 @@ fn paragraph() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("a  "),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a &#x20;\n",
@@ -57,9 +64,11 @@ fn paragraph() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("\t\ta"),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "&#x9;\ta\n",
@@ -70,9 +79,11 @@ fn paragraph() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("a\t\t"),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\t&#x9;\n",
@@ -83,9 +94,11 @@ fn paragraph() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("a  \n  b"),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a &#x20;\n&#x20; b\n",
@@ -96,9 +109,11 @@ fn paragraph() {
         to(&Node::Paragraph(Paragraph {
             children: vec![Node::Text(Text {
                 value: String::from("a\t\t\n\t\tb"),
-                position: None
+                position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\t&#x9;\n&#x9;\tb\n",
@@ -0,0 +1,167 @@
+use markdown::{
+    mdast::{Node, Paragraph, Text},
+    to_mdast as from, ParseOptions,
+};
+use mdast_util_to_markdown::{to_markdown_with_options as to, DefinitionPlacement, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn raw() {
+    let parse = ParseOptions {
+        preserve: true,
+        ..ParseOptions::gfm()
+    };
+    let use_raw = Options {
+        use_raw: true,
+        ..Default::default()
+    };
+
+    // Documents that include constructs `mdast_util_to_markdown` cannot
+    // serialize, such as tables, which are kept as-is.
+    let corpus = [
+        include_str!("../../readme.md"),
+        include_str!("../../.github/contribute.md"),
+        include_str!("../../.github/support.md"),
+        include_str!("../../.github/code-of-conduct.md"),
+    ];
+
+    for doc in corpus {
+        let tree = from(doc, &parse).unwrap();
+
+        assert_eq!(
+            to(&tree, &use_raw).unwrap(),
+            doc,
+            "should round trip byte for byte w/ `use_raw`"
+        );
+
+        let mut tree = tree;
+        tree.raw_set(None);
+
+        assert_eq!(
+            to(&tree, &use_raw).unwrap(),
+            doc,
+            "should round trip byte for byte from the children w/ `use_raw`"
+        );
+    }
+
+    let doc =
+        "# a\nb\n\n\n* c\n  - d\n\n> e\nf\n\n***\n\n```g\nh\n```\n\n[i]: j\n\nk  &amp;  \\*l\\*";
+    let mut tree = from(doc, &parse).unwrap();
+
+    assert_eq!(
+        to(&tree, &use_raw).unwrap(),
+        doc,
+        "should support a document w/o final line ending"
+    );
+
+    tree.raw_set(None);
+
+    assert_eq!(
+        to(&tree, &use_raw).unwrap(),
+        format!("{}\n", doc),
+        "should keep the blank lines between children"
+    );
+
+    let children = tree.children_mut().unwrap();
+    let last = children.len() - 1;
+    children[last] = Node::Paragraph(Paragraph {
+        children: vec![Node::Text(Text {
+            value: "m*".into(),
+            position: None,
+            raw: None,
+        })],
+        position: None,
+        raw: None,
+    });
+
+    assert_eq!(
+        to(&tree, &use_raw).unwrap(),
+        "# a\nb\n\n\n* c\n  - d\n\n> e\nf\n\n***\n\n```g\nh\n```\n\n[i]: j\n\nm\\*\n",
+        "should serialize nodes w/o `raw` normally"
+    );
+
+    let mut tree = from("<div>\n\n\n\n# a", &parse).unwrap();
+    tree.raw_set(None);
+
+    assert_eq!(
+        to(&tree, &use_raw).unwrap(),
+        "<div>\n\n\n\n# a\n",
+        "should keep the blank lines after html"
+    );
+
+    let mut tree = from("a\n\n\n\nb", &parse).unwrap();
+    tree.raw_set(None);
+    tree.children_mut().unwrap()[0] = Node::Paragraph(Paragraph {
+        children: vec![Node::Text(Text {
+            value: "c".into(),
+            position: None,
+            raw: None,
+        })],
+        position: tree.children().unwrap()[0].position().cloned(),
+        raw: None,
+    });
+
+    assert_eq!(
+        to(&tree, &use_raw).unwrap(),
+        "c\n\n\n\nb\n",
+        "should keep the blank lines around nodes w/o `raw`"
+    );
+
+    let mut tree = from("> a  &amp;  *b*\n> c\n\n- d  *e*\n  f", &parse).unwrap();
+
+    if let Node::Blockquote(block_quote) = &tree.children().unwrap()[0] {
+        assert_eq!(
+            block_quote.children[0].raw(),
+            None,
+            "should not set `raw` on nodes in containers that span several lines"
+        );
+
+        assert_eq!(
+            block_quote.children[0].children().unwrap()[1].raw(),
+            Some(&"*b*".into()),
+            "should set `raw` on phrasing in containers on one line"
+        );
+    } else {
+        unreachable!("expected block quote");
+    }
+
+    tree.raw_set(None);
+    tree.children_mut().unwrap()[0].raw_set(None);
+
+    assert_eq!(
+        to(&tree, &use_raw).unwrap(),
+        "> a  &amp;  *b*\n> c\n\n- d  *e*\n  f\n",
+        "should serialize nodes in containers from their `raw`"
+    );
+
+    tree.children_mut().unwrap()[1].raw_set(None);
+
+    assert_eq!(
+        to(&tree, &use_raw).unwrap(),
+        "> a  &amp;  *b*\n> c\n\n* d  *e*\n  f\n",
+        "should serialize lists from the `raw` of their descendants"
+    );
+
+    let mut tree = from("[a]: b\n\n[x][a]\n\n\nc\n", &parse).unwrap();
+    tree.raw_set(None);
+
+    assert_eq!(
+        to(
+            &tree,
+            &Options {
+                definition_placement: DefinitionPlacement::AtEnd { alphabetize: false },
+                use_raw: true,
+                ..Default::default()
+            }
+        )
+        .unwrap(),
+        "[x][a]\n\nc\n\n[a]: b\n",
+        "should not keep the blank lines between reordered nodes"
+    );
+
+    assert_eq!(
+        to(&from(doc, &parse).unwrap(), &Default::default()).unwrap(),
+        "# a\n\nb\n\n* c\n  * d\n\n> e\n> f\n\n***\n\n```g\nh\n```\n\n[i]: j\n\nk  &  \\*l\\*\n",
+        "should ignore `raw` by default"
+    );
+}
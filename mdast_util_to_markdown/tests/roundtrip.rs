@@ -388,6 +388,30 @@ a _\\__ is this emphasis? _\\__"
         remove_pos(&mut tree),
     );
 
+    let setext = Options {
+        setext: true,
+        ..Default::default()
+    };
+
+    let doc: String = document(vec![
+        "a", "=", "", "b *c*", "-----", "", "### d", "", "e\\", "f", "=", "",
+    ]);
+
+    assert_eq!(
+        to_md_with_opts(&from(&doc, &Default::default()).unwrap(), &setext).unwrap(),
+        doc
+    );
+
+    let mut tree = from("# a\n\n## b\n\n### c", &Default::default()).unwrap();
+    let mut result = from(
+        &to_md_with_opts(&tree, &setext).unwrap(),
+        &Default::default(),
+    )
+    .unwrap();
+    remove_pos(&mut tree);
+    remove_pos(&mut result);
+    assert_eq!(result, tree);
+
     let doc: String = to(&from("(____", &Default::default()).unwrap()).unwrap();
     assert_eq!(to(&from(&doc, &Default::default()).unwrap()).unwrap(), doc);
 
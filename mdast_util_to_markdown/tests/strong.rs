@@ -9,7 +9,9 @@ fn strong() {
     assert_eq!(
         to(&Node::Strong(Strong {
             children: Vec::new(),
-            position: None
+            position: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "****\n",
@@ -21,8 +23,11 @@ fn strong() {
             children: vec![Node::Text(Text {
                 value: String::from("a"),
                 position: None,
+                raw: None,
             })],
-            position: None
+            position: None,
+            markers: None,
+            raw: None,
         }))
         .unwrap(),
         "**a**\n",
@@ -35,8 +40,11 @@ fn strong() {
                 children: vec![Node::Text(Text {
                     value: String::from("a"),
                     position: None,
+                    raw: None,
                 })],
-                position: None
+                position: None,
+                markers: None,
+                raw: None,
             }),
             &Options {
                 strong: '_',
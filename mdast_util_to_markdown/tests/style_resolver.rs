@@ -0,0 +1,193 @@
+use markdown::{mdast::Node, message::Message, to_mdast as from, ParseOptions};
+use mdast_util_to_markdown::{to_markdown_with_options as to, Options, StyleAnswer, StyleQuery};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn style_resolver() -> Result<(), Message> {
+    let alternate_bullets = Options {
+        style_resolver: Some(Box::new(|_: &Node, query: &StyleQuery| match query {
+            StyleQuery::Bullet { depth } => {
+                Some(StyleAnswer::Marker(if depth % 2 == 1 { '-' } else { '+' }))
+            }
+            _ => None,
+        })),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to(
+            &from("* a\n  * b\n    * c\n* d", &ParseOptions::default())?,
+            &alternate_bullets
+        )?,
+        "- a\n  + b\n    - c\n- d\n",
+        "should support a resolver picking bullets per list depth"
+    );
+
+    assert_eq!(
+        to(
+            &from("1. a\n\n   * b", &ParseOptions::default())?,
+            &alternate_bullets
+        )?,
+        "1. a\n\n   + b\n",
+        "should count ordered lists in the depth"
+    );
+
+    assert_eq!(
+        to(
+            &from("* a\n\n- b", &ParseOptions::default())?,
+            &alternate_bullets
+        )?,
+        "- a\n\n* b\n",
+        "should still use `bullet_other` for adjacent lists"
+    );
+
+    let invalid_for_items = Options {
+        style_resolver: Some(Box::new(|node: &Node, query: &StyleQuery| {
+            match (node, query) {
+                (Node::ListItem(_), StyleQuery::Bullet { .. }) => Some(StyleAnswer::Marker('x')),
+                (Node::List(_), StyleQuery::Bullet { .. }) => Some(StyleAnswer::Marker('+')),
+                _ => None,
+            }
+        })),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to(
+            &from("* a\n* b", &ParseOptions::default())?,
+            &invalid_for_items
+        )?,
+        "+ a\n+ b\n",
+        "should ask for bullets with the list, not with its items"
+    );
+
+    assert_eq!(
+        to(
+            &from("* a", &ParseOptions::default())?
+                .children()
+                .unwrap()[0]
+                .children()
+                .unwrap()[0],
+            &invalid_for_items
+        )
+        .unwrap_err()
+        .to_string(),
+        "Cannot serialize items with `x` for `options.bullet`, expected `*`, `+`, or `-` (mdast-util-to-markdown:unexpected-marker)",
+        "should ask for bullets with an item that is not in a list"
+    );
+
+    let tilde_for_backticks = Options {
+        style_resolver: Some(Box::new(|node: &Node, query: &StyleQuery| {
+            match (node, query) {
+                (Node::Code(code), StyleQuery::Fence) if code.value.contains('`') => {
+                    Some(StyleAnswer::Marker('~'))
+                }
+                _ => None,
+            }
+        })),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to(
+            &from(
+                "```js\na\n```\n\n````md\n```\nb\n```\n````",
+                &ParseOptions::default()
+            )?,
+            &tilde_for_backticks
+        )?,
+        "```js\na\n```\n\n~~~md\n```\nb\n```\n~~~\n",
+        "should support a resolver picking fences for code containing backticks"
+    );
+
+    assert_eq!(
+        to(
+            &from("    a\n\n```\nb\n```", &ParseOptions::default())?,
+            &Options {
+                fences: false,
+                style_resolver: Some(Box::new(|node: &Node, query: &StyleQuery| {
+                    match (node, query) {
+                        (Node::Code(code), StyleQuery::Fences) => {
+                            Some(StyleAnswer::Flag(code.value == "b"))
+                        }
+                        _ => None,
+                    }
+                })),
+                ..Default::default()
+            }
+        )?,
+        "    a\n\n<!---->\n\n```\nb\n```\n",
+        "should support a resolver deciding whether to use fences per node"
+    );
+
+    assert_eq!(
+        to(
+            &from("*a* **b** *c*\n\n***", &ParseOptions::default())?,
+            &Options {
+                style_resolver: Some(Box::new(|node: &Node, query: &StyleQuery| {
+                    match (node, query) {
+                        (Node::Emphasis(_), StyleQuery::Emphasis) if node.to_string() == "c" => {
+                            Some(StyleAnswer::Marker('_'))
+                        }
+                        (_, StyleQuery::Strong) => Some(StyleAnswer::Marker('_')),
+                        (_, StyleQuery::Rule) => Some(StyleAnswer::Marker('-')),
+                        (_, StyleQuery::RuleRepetition) => Some(StyleAnswer::Repetition(5)),
+                        _ => None,
+                    }
+                })),
+                ..Default::default()
+            }
+        )?,
+        "*a* __b__ _c_\n\n-----\n",
+        "should support a resolver picking emphasis, strong, and rules"
+    );
+
+    assert_eq!(
+        to(
+            &from("*a*\n\n***", &ParseOptions::default())?,
+            &Options {
+                style_resolver: Some(Box::new(|_: &Node, query: &StyleQuery| match query {
+                    StyleQuery::Emphasis => Some(StyleAnswer::Repetition(2)),
+                    StyleQuery::RuleRepetition => Some(StyleAnswer::Flag(true)),
+                    _ => None,
+                })),
+                ..Default::default()
+            }
+        )?,
+        "*a*\n\n***\n",
+        "should ignore answers of the wrong kind"
+    );
+
+    assert_eq!(
+        to(
+            &from("*a*", &ParseOptions::default())?,
+            &Options {
+                style_resolver: Some(Box::new(|_: &Node, _: &StyleQuery| {
+                    Some(StyleAnswer::Marker('x'))
+                })),
+                ..Default::default()
+            }
+        )
+        .unwrap_err()
+        .reason,
+        "Cannot serialize emphasis with `x` for `options.emphasis`, expected `*`, or `_`",
+        "should check answers like options"
+    );
+
+    assert_eq!(
+        to(
+            &from("* a\n* - - -", &ParseOptions::default())?,
+            &Options {
+                style_resolver: Some(Box::new(|_: &Node, query: &StyleQuery| match query {
+                    StyleQuery::Rule => Some(StyleAnswer::Marker('-')),
+                    _ => None,
+                })),
+                ..Default::default()
+            }
+        )?,
+        "* a\n* ---\n",
+        "should ask about thematic breaks in list items when checking bullets"
+    );
+
+    Ok(())
+}
@@ -1,5 +1,11 @@
-use markdown::mdast::{Node, Text};
-use mdast_util_to_markdown::to_markdown as to;
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    to_mdast as from,
+};
+use mdast_util_to_markdown::{
+    to_markdown as to, to_markdown_with_options as to_md_with_opts, ConstructName, Options,
+    UnsafePattern,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -8,6 +14,7 @@ fn text() {
         to(&Node::Text(Text {
             value: String::new(),
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "",
@@ -18,9 +25,119 @@ fn text() {
         to(&Node::Text(Text {
             value: String::from("a\nb"),
             position: None,
+            raw: None,
         }))
         .unwrap(),
         "a\nb\n",
         "should support text"
     );
+
+    let spoiler = Options {
+        extra_unsafe: vec![
+            UnsafePattern {
+                character: '|',
+                after: Some("\\|".into()),
+                in_construct: vec![ConstructName::Phrasing],
+                ..Default::default()
+            },
+            UnsafePattern {
+                character: '@',
+                after: Some("here".into()),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to(&Node::Text(Text {
+            value: String::from("a ||b|| c"),
+            position: None,
+            raw: None,
+        }))
+        .unwrap(),
+        "a ||b|| c\n",
+        "should not escape a custom pattern by default"
+    );
+
+    assert_eq!(
+        to_md_with_opts(
+            &Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: String::from("a ||b|| c"),
+                    position: None,
+                    raw: None,
+                })],
+                position: None,
+                raw: None,
+            }),
+            &spoiler
+        )
+        .unwrap(),
+        "a \\||b\\|| c\n",
+        "should escape a custom pattern in phrasing"
+    );
+
+    assert_eq!(
+        to_md_with_opts(
+            &Node::Text(Text {
+                value: String::from("@here, @there"),
+                position: None,
+                raw: None,
+            }),
+            &spoiler
+        )
+        .unwrap(),
+        "\\@here, @there\n",
+        "should escape a custom pattern with an `after`"
+    );
+
+    let tree = Node::Root(Root {
+        children: vec![Node::Paragraph(Paragraph {
+            children: vec![Node::Text(Text {
+                value: String::from("a ||b|| @here"),
+                position: None,
+                raw: None,
+            })],
+            position: None,
+            raw: None,
+        })],
+        position: None,
+        raw: None,
+    });
+
+    assert_eq!(
+        from(
+            &to_md_with_opts(&tree, &spoiler).unwrap(),
+            &Default::default()
+        )
+        .unwrap()
+        .to_string(),
+        tree.to_string(),
+        "should roundtrip escaped custom patterns"
+    );
+
+    let invalid = Options {
+        extra_unsafe: vec![UnsafePattern {
+            character: '|',
+            before: Some("(".into()),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_md_with_opts(
+            &Node::Text(Text {
+                value: String::from("a"),
+                position: None,
+                raw: None,
+            }),
+            &invalid
+        )
+        .unwrap_err()
+        .rule_id,
+        Box::new("unexpected-pattern".into()),
+        "should error on invalid regexes in `extra_unsafe`"
+    );
 }
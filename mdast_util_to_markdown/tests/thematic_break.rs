@@ -7,14 +7,23 @@ use pretty_assertions::assert_eq;
 #[test]
 fn thematic_break() {
     assert_eq!(
-        to(&Node::ThematicBreak(ThematicBreak { position: None })).unwrap(),
+        to(&Node::ThematicBreak(ThematicBreak {
+            position: None,
+            markers: None,
+            raw: None,
+        }))
+        .unwrap(),
         "***\n",
         "should support a thematic break"
     );
 
     assert_eq!(
         to_md_with_opts(
-            &Node::ThematicBreak(ThematicBreak { position: None }),
+            &Node::ThematicBreak(ThematicBreak {
+                position: None,
+                markers: None,
+                raw: None,
+            }),
             &Options {
                 rule: '-',
                 ..Default::default()
@@ -27,7 +36,11 @@ fn thematic_break() {
 
     assert_eq!(
         to_md_with_opts(
-            &Node::ThematicBreak(ThematicBreak { position: None }),
+            &Node::ThematicBreak(ThematicBreak {
+                position: None,
+                markers: None,
+                raw: None,
+            }),
             &Options {
                 rule: '_',
                 ..Default::default()
@@ -40,7 +53,11 @@ fn thematic_break() {
 
     assert_eq!(
         to_md_with_opts(
-            &Node::ThematicBreak(ThematicBreak { position: None }),
+            &Node::ThematicBreak(ThematicBreak {
+                position: None,
+                markers: None,
+                raw: None,
+            }),
             &Options {
                 rule_repetition: 5,
                 ..Default::default()
@@ -53,7 +70,11 @@ fn thematic_break() {
 
     assert_eq!(
         to_md_with_opts(
-            &Node::ThematicBreak(ThematicBreak { position: None }),
+            &Node::ThematicBreak(ThematicBreak {
+                position: None,
+                markers: None,
+                raw: None,
+            }),
             &Options {
                 rule_spaces: true,
                 ..Default::default()
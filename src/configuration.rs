@@ -1,8 +1,14 @@
+use crate::message;
 use crate::util::{
+    constant::HEADING_ATX_OPENING_FENCE_SIZE_MAX,
+    encode::encode,
+    extension::TextExtension,
     line_ending::LineEnding,
     mdx::{EsmParse as MdxEsmParse, ExpressionParse as MdxExpressionParse},
 };
-use alloc::{boxed::Box, fmt, string::String};
+use alloc::{
+    boxed::Box, collections::BTreeMap, fmt, format, string::String, sync::Arc, vec, vec::Vec,
+};
 
 /// Control which constructs are enabled.
 ///
@@ -31,6 +37,11 @@ use alloc::{boxed::Box, fmt, string::String};
 /// ```
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
 pub struct Constructs {
     /// Attention.
     ///
@@ -99,6 +110,18 @@ pub struct Constructs {
     ///     ^^^^^^^^^^
     /// ```
     pub definition: bool,
+    /// Footnote (inline).
+    ///
+    /// ```markdown
+    /// > | a^[b]
+    ///      ^^^^
+    /// ```
+    ///
+    /// Inline footnotes are numbered, skipping the identifiers of GFM footnote
+    /// definitions.
+    /// In mdast, they turn into a `FootnoteReference`, and a
+    /// `FootnoteDefinition` that is added at the end of the root.
+    pub footnote_inline: bool,
     /// Frontmatter.
     ///
     /// ````markdown
@@ -349,6 +372,7 @@ impl Default for Constructs {
             code_fenced: true,
             code_text: true,
             definition: true,
+            footnote_inline: false,
             frontmatter: false,
             gfm_autolink_literal: false,
             gfm_label_start_footnote: false,
@@ -432,6 +456,364 @@ impl Constructs {
             ..Self::default()
         }
     }
+
+    /// Inline only.
+    ///
+    /// This turns on emphasis and strong (attention), code (text), links,
+    /// images, character escapes, and character references, and turns
+    /// everything else off.
+    /// Block syntax, such as `# a` or `- a`, is kept as text in a paragraph.
+    /// HTML, autolinks, and definitions are also kept as text.
+    ///
+    /// Images are turned on so that `![a](b)` is not seen as a `!` followed
+    /// by a link; to show them as their alt text, see
+    /// [`Options::safe_inline`][].
+    ///
+    /// This is useful for short, untrusted content, such as user bios and
+    /// chat messages.
+    ///
+    /// > 👉 **Note**: new constructs are off in this preset.
+    pub fn inline_only() -> Self {
+        // Listing every field makes adding a construct fail to compile here.
+        Self {
+            attention: true,
+            autolink: false,
+            block_quote: false,
+            character_escape: true,
+            character_reference: true,
+            code_indented: false,
+            code_fenced: false,
+            code_text: true,
+            definition: false,
+            footnote_inline: false,
+            frontmatter: false,
+            gfm_autolink_literal: false,
+            gfm_label_start_footnote: false,
+            gfm_footnote_definition: false,
+            gfm_strikethrough: false,
+            gfm_table: false,
+            gfm_task_list_item: false,
+            hard_break_escape: false,
+            hard_break_trailing: false,
+            heading_atx: false,
+            heading_setext: false,
+            html_flow: false,
+            html_text: false,
+            label_start_image: true,
+            label_start_link: true,
+            label_end: true,
+            list_item: false,
+            math_flow: false,
+            math_text: false,
+            mdx_esm: false,
+            mdx_expression_flow: false,
+            mdx_expression_text: false,
+            mdx_jsx_flow: false,
+            mdx_jsx_text: false,
+            thematic_break: false,
+        }
+    }
+}
+
+/// Quote to use around attribute values in HTML.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::QuoteKind;
+/// # fn main() {
+///
+/// // Use single quotes:
+/// let single = QuoteKind::Single;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum QuoteKind {
+    /// Double quote (`"`).
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <a href="b">
+    /// ```
+    #[default]
+    Double,
+    /// Single quote (`'`).
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <a href='b'>
+    /// ```
+    Single,
+}
+
+/// Style of boolean attributes (such as `checked`) in HTML.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::BooleanAttributeStyle;
+/// # fn main() {
+///
+/// // Use the attribute name as its value:
+/// let repeat = BooleanAttributeStyle::Repeat;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum BooleanAttributeStyle {
+    /// Empty value.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <input checked="" />
+    /// ```
+    #[default]
+    Empty,
+    /// Attribute name as value, which is also valid XML.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <input checked="checked" />
+    /// ```
+    Repeat,
+    /// No value.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <input checked />
+    /// ```
+    Omit,
+}
+
+/// Style of void elements (such as `<br>`) in HTML.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::SelfClosingStyle;
+/// # fn main() {
+///
+/// // Use HTML5 style, without slashes:
+/// let html5 = SelfClosingStyle::Html5;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum SelfClosingStyle {
+    /// Space and slash, which is also valid XML.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <br />
+    /// ```
+    #[default]
+    Xhtml,
+    /// Nothing.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <br>
+    /// ```
+    Html5,
+}
+
+/// How frontmatter is compiled to HTML.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::FrontmatterRendering;
+/// # fn main() {
+///
+/// // Show frontmatter as code:
+/// let code_block = FrontmatterRendering::CodeBlock;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum FrontmatterRendering {
+    /// Nothing.
+    #[default]
+    Ignore,
+    /// Code, with a `language-yaml` or `language-toml` class.
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <pre><code class="language-yaml">title: Venus
+    /// </code></pre>
+    /// ```
+    CodeBlock,
+    /// An HTML comment, with the fences.
+    ///
+    /// `>` in frontmatter is turned into `&gt;`, so that it cannot end the
+    /// comment (such as with `-->` or `--!>`).
+    ///
+    /// ## Example
+    ///
+    /// ```html
+    /// <!--
+    /// ---
+    /// title: Venus
+    /// ---
+    /// -->
+    /// ```
+    Comment,
+}
+
+/// Order in which GFM footnotes are numbered in HTML.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::FootnoteNumbering;
+/// # fn main() {
+///
+/// // Number footnotes in the order they are defined:
+/// let definition_order = FootnoteNumbering::DefinitionOrder;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum FootnoteNumbering {
+    /// In the order footnotes are first called, like GitHub.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// a[^y] b[^x]
+    ///
+    /// [^x]: 2
+    /// [^y]: 1
+    /// ```
+    #[default]
+    CallOrder,
+    /// In the order footnotes are defined.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// a[^y] b[^x]
+    ///
+    /// [^x]: 1
+    /// [^y]: 2
+    /// ```
+    DefinitionOrder,
+}
+
+/// Info on an image, passed to
+/// [`image_renderer`][CompileOptions::image_renderer].
+///
+/// The values are not HTML-escaped: character references are decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageInfo {
+    /// URL of the image, as used in `src`.
+    ///
+    /// It is resolved against [`url_base`][CompileOptions::url_base] and
+    /// sanitized: dangerous protocols are removed (unless
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// is on) and characters are percent-encoded.
+    pub src: String,
+    /// Plain text of the image description, as used in `alt`.
+    pub alt: String,
+    /// Advisory info, as used in `title`.
+    pub title: Option<String>,
+}
+
+/// Signature of a function that turns an image into HTML.
+///
+/// Return `None` to compile the image as normal.
+pub type ImageRenderer = dyn Fn(&ImageInfo) -> Option<String> + Send + Sync;
+
+/// Tag name and attributes to use for an element generated from markdown,
+/// used in [`ElementOverrides`].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::ElementOverride;
+/// # fn main() {
+///
+/// // Use `<s class="strike">`:
+/// let strike = ElementOverride {
+///     tag_name: Some("s".into()),
+///     attributes: vec![("class".into(), "strike".into())],
+/// };
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
+pub struct ElementOverride {
+    /// Tag name to use instead of the default one.
+    ///
+    /// Must be ASCII letters.
+    /// `None` keeps the default tag name.
+    pub tag_name: Option<String>,
+    /// Attributes to add, as pairs of names and values.
+    ///
+    /// Names must be ASCII alphanumerics, `-`, `_`, or `:`.
+    /// Values are encoded.
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Elements generated from markdown to compile with other tag names or
+/// attributes, used in
+/// [`element_overrides`][CompileOptions::element_overrides].
+///
+/// Elements from HTML in markdown are never changed.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
+pub struct ElementOverrides {
+    /// Override for `<del>`, from GFM strikethrough.
+    pub delete: Option<ElementOverride>,
+    /// Override for `<em>`, from emphasis.
+    pub emphasis: Option<ElementOverride>,
+    /// Override for `<code>`, from code (text).
+    ///
+    /// Math (text) is not affected.
+    pub inline_code: Option<ElementOverride>,
+    /// Override for `<p>`, from paragraphs.
+    ///
+    /// Paragraphs in tight lists have no element.
+    pub paragraph: Option<ElementOverride>,
+    /// Override for `<strong>`, from strong.
+    pub strong: Option<ElementOverride>,
 }
 
 /// Configuration that describes how to compile to HTML.
@@ -466,7 +848,12 @@ impl Constructs {
 /// # }
 /// ```
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
 pub struct CompileOptions {
     /// Whether to allow (dangerous) HTML.
     ///
@@ -521,6 +908,9 @@ pub struct CompileOptions {
     /// (for images: `http`, `https`; for links: `http`, `https`, `irc`,
     /// `ircs`, `mailto`, `xmpp`), are safe.
     /// All other URLs are dangerous and dropped.
+    /// Use [`protocol_href`][CompileOptions::protocol_href] and
+    /// [`protocol_src`][CompileOptions::protocol_src] to change which
+    /// protocols are safe.
     ///
     /// ## Examples
     ///
@@ -553,376 +943,1488 @@ pub struct CompileOptions {
     /// ```
     pub allow_dangerous_protocol: bool,
 
-    // To do: `doc_markdown` is broken.
-    #[allow(clippy::doc_markdown)]
-    /// Default line ending to use when compiling to HTML, for line endings not
-    /// in `value`.
+    /// Whether to allow HTML comments, when other HTML is not allowed.
     ///
-    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
-    /// the markdown document over to the compiled HTML.
-    /// In some cases, such as `> a`, CommonMark requires that extra line
-    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
+    /// The default is `false`, which shows comments as text, like other HTML,
+    /// unless `allow_dangerous_html` is on.
     ///
-    /// To create that line ending, the document is checked for the first line
-    /// ending that is used.
-    /// If there is no line ending, `default_line_ending` is used.
-    /// If that isn’t configured, `\n` is used.
+    /// Pass `true` to pass comments through, such as for directives like
+    /// `<!-- toc -->`, while still showing all other HTML as text.
+    /// HTML (flow) is only passed through when it is one comment, optionally
+    /// followed by whitespace.
+    /// HTML (text) is always one construct, so each comment is passed through
+    /// on its own.
+    /// Comments that are not closed, or that browsers would close early (with
+    /// `--!>`), are shown as text.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `markdown-rs` uses `\n` by default:
+    /// // `markdown-rs` shows comments as text by default:
     /// assert_eq!(
-    ///     to_html("> a"),
-    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    ///     to_html("<!-- toc -->"),
+    ///     "&lt;!-- toc --&gt;"
     /// );
     ///
-    /// // Define `default_line_ending` to configure the default:
+    /// // Turn `allow_html_comments` on to allow comments, but no other HTML:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "> a",
+    ///         "<!-- toc -->\n\nHi, <!--a--><i>venus</i>!",
     ///         &Options {
     ///             compile: CompileOptions {
-    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               allow_html_comments: true,
     ///               ..CompileOptions::default()
     ///             },
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    ///     "<!-- toc -->\n<p>Hi, <!--a-->&lt;i&gt;venus&lt;/i&gt;!</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub default_line_ending: LineEnding,
+    pub allow_html_comments: bool,
 
-    /// Textual label to use for the footnotes section.
+    /// Quote to use around attribute values.
     ///
-    /// The default value is `"Footnotes"`.
-    /// Change it when the markdown is not in English.
+    /// The default is [`QuoteKind::Double`][].
+    /// Pass [`QuoteKind::Single`][] to use single quotes, for example when
+    /// embedding the HTML in something that uses double quotes.
+    /// Single quotes in values are then encoded.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// This does not affect the raw `gfm_footnote_label_attributes` or HTML
+    /// in the markdown.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options, QuoteKind};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"Footnotes"` is used by default:
+    /// // `markdown-rs` uses double quotes by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html("[a](b 'c')"),
+    ///     "<p><a href=\"b\" title=\"c\">a</a></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label` to use something else:
+    /// // Pass `attribute_quote` to use single quotes:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "[a](b \"c'd\")",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               attribute_quote: QuoteKind::Single,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><a href='b' title='c&#x27;d'>a</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label: Option<String>,
+    pub attribute_quote: QuoteKind,
 
-    /// HTML tag name to use for the footnote label element.
+    /// Style of boolean attributes.
     ///
-    /// The default value is `"h2"`.
-    /// Change it to match your document structure.
+    /// The default is [`BooleanAttributeStyle::Empty`][], which generates
+    /// `checked=""`.
+    /// Pass [`BooleanAttributeStyle::Repeat`][] for `checked="checked"`,
+    /// for example for XML, or [`BooleanAttributeStyle::Omit`][] for
+    /// `checked`.
     ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass different attributes with the `gfm_footnote_label_attributes`
-    /// option.
+    /// Only the boolean attributes on GFM task list item checks (`checked`,
+    /// `disabled`) are affected: other attributes with empty values, such as
+    /// `data-footnote-ref=""`, are not boolean attributes.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, BooleanAttributeStyle, CompileOptions, Options, ParseOptions};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"h2"` is used by default:
+    /// // `markdown-rs` uses empty values by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html_with_options("* [x] y.", &Options::gfm())?,
+    ///     "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> y.</li>\n</ul>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// // Pass `boolean_attribute_style` to use something else:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "* [x] y.",
     ///         &Options {
     ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_tag_name: Some("h1".into()),
+    ///               boolean_attribute_style: BooleanAttributeStyle::Repeat,
     ///               ..CompileOptions::gfm()
     ///             }
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<ul>\n<li><input type=\"checkbox\" disabled=\"disabled\" checked=\"checked\" /> y.</li>\n</ul>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_tag_name: Option<String>,
+    pub boolean_attribute_style: BooleanAttributeStyle,
 
-    /// Attributes to use on the footnote label.
+    /// Whether to decode character references and escapes in titles.
     ///
-    /// The default value is `"class=\"sr-only\""`.
-    /// Change it to show the label and add other attributes.
-    ///
-    /// This label is typically hidden visually (assuming a `sr-only` CSS class
-    /// is defined that does that), and thus affects screen readers only.
-    /// If you do have such a class, but want to show this section to everyone,
-    /// pass an empty string.
-    /// You can also add different attributes.
+    /// The default is `true`, so that `&copy;` in the title of a link,
+    /// image, or definition becomes `©`.
+    /// Pass `false` to keep them as they are written instead, such as to
+    /// match other renderers that do not decode titles.
+    /// The result is still encoded, so `&copy;` becomes `&amp;copy;`.
     ///
-    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
-    /// > calls use it with `aria-describedby` to provide an accessible label.
+    /// It only applies when compiling markdown: titles in syntax trees are
+    /// already decoded.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"class=\"sr-only\""` is used by default:
+    /// // `markdown-rs` decodes titles by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html_with_options("[a](b \"c &copy; d\")", &Options::default())?,
+    ///     "<p><a href=\"b\" title=\"c © d\">a</a></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// // Turn `decode_titles` off to keep character references as they are:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "[a](b \"c &copy; d\")",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               decode_titles: false,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><a href=\"b\" title=\"c &amp;copy; d\">a</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_label_attributes: Option<String>,
+    pub decode_titles: bool,
 
-    /// Textual label to describe the backreference back to footnote calls.
-    ///
-    /// The default value is `"Back to content"`.
-    /// Change it when the markdown is not in English.
+    // To do: `doc_markdown` is broken.
+    #[allow(clippy::doc_markdown)]
+    /// Default line ending to use when compiling to HTML, for line endings not
+    /// in `value`.
+    ///
+    /// Generally, `markdown-rs` copies line endings (`\r`, `\n`, `\r\n`) in
+    /// the markdown document over to the compiled HTML.
+    /// In some cases, such as `> a`, CommonMark requires that extra line
+    /// endings are added: `<blockquote>\n<p>a</p>\n</blockquote>`.
+    ///
+    /// To create that line ending, the document is checked for the first line
+    /// ending that is used.
+    /// If there is no line ending, `default_line_ending` is used.
+    /// If that isn’t configured, `\n` is used.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, LineEnding, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses `\n` by default:
+    /// assert_eq!(
+    ///     to_html("> a"),
+    ///     "<blockquote>\n<p>a</p>\n</blockquote>"
+    /// );
+    ///
+    /// // Define `default_line_ending` to configure the default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "> a",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               default_line_ending: LineEnding::CarriageReturnLineFeed,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<blockquote>\r\n<p>a</p>\r\n</blockquote>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub default_line_ending: LineEnding,
+
+    /// Tag names and attributes to use for certain elements generated from
+    /// markdown.
+    ///
+    /// The default is to use the normal elements without attributes.
+    /// Elements from HTML in markdown, such as a `<del>` written by the
+    /// author, are never changed.
+    ///
+    /// Tag names must be ASCII letters and attribute names ASCII
+    /// alphanumerics, `-`, `_`, or `:`; compiling fails otherwise.
+    /// Attribute values are encoded.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, ElementOverride, ElementOverrides, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` uses `<del>` by default:
+    /// assert_eq!(
+    ///     to_html_with_options("~a~", &Options::gfm())?,
+    ///     "<p><del>a</del></p>"
+    /// );
+    ///
+    /// // Pass `element_overrides` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               element_overrides: ElementOverrides {
+    ///                   delete: Some(ElementOverride {
+    ///                       tag_name: Some("s".into()),
+    ///                       attributes: vec![("class".into(), "strike".into())],
+    ///                   }),
+    ///                   ..ElementOverrides::default()
+    ///               },
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><s class=\"strike\">a</s></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub element_overrides: ElementOverrides,
+
+    /// Tags to use for the events of text extensions.
+    ///
+    /// The default is empty.
+    /// Keys are the names passed to `enter` and `exit` in
+    /// [`text_extensions`][ParseOptions::text_extensions], values are the
+    /// names of the HTML elements to generate for them.
+    /// Events without tag generate nothing, but the data in them is still
+    /// compiled.
+    ///
+    /// > 👉 **Note**: this is experimental.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{ExtensionState, ExtensionTokenizer, TextExtension};
+    ///
+    /// // Highlight (`==a==`).
+    /// fn highlight(tokenizer: &mut ExtensionTokenizer) -> ExtensionState {
+    ///     match (tokenizer.state(), tokenizer.current()) {
+    ///         // Opening sequence.
+    ///         (0, Some(b'=')) => {
+    ///             tokenizer.enter("highlight");
+    ///             tokenizer.enter("highlightSequence");
+    ///             tokenizer.consume();
+    ///             tokenizer.set_state(1);
+    ///             ExtensionState::Next
+    ///         }
+    ///         (1, Some(b'=')) => {
+    ///             tokenizer.consume();
+    ///             tokenizer.exit("highlightSequence");
+    ///             tokenizer.set_state(2);
+    ///             ExtensionState::Next
+    ///         }
+    ///         // Content.
+    ///         (2, Some(byte)) if byte != b'=' && !byte.is_ascii_whitespace() => {
+    ///             tokenizer.enter_data();
+    ///             tokenizer.consume();
+    ///             tokenizer.set_state(3);
+    ///             ExtensionState::Next
+    ///         }
+    ///         (3, Some(b'=')) => {
+    ///             tokenizer.exit_data();
+    ///             tokenizer.enter("highlightSequence");
+    ///             tokenizer.consume();
+    ///             tokenizer.set_state(4);
+    ///             ExtensionState::Next
+    ///         }
+    ///         (3, Some(byte)) if byte != b'\n' => {
+    ///             tokenizer.consume();
+    ///             ExtensionState::Next
+    ///         }
+    ///         // Closing sequence.
+    ///         (4, Some(b'=')) => {
+    ///             tokenizer.consume();
+    ///             tokenizer.exit("highlightSequence");
+    ///             tokenizer.exit("highlight");
+    ///             ExtensionState::Ok
+    ///         }
+    ///         _ => ExtensionState::Nok,
+    ///     }
+    /// }
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// let parse = ParseOptions {
+    ///     text_extensions: vec![TextExtension {
+    ///         name: "highlight",
+    ///         markers: vec![b'='],
+    ///         tokenize: Box::new(highlight),
+    ///     }],
+    ///     ..ParseOptions::default()
+    /// };
+    ///
+    /// // Pass `extension_tags` to turn `highlight` events into `<mark>`:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a ==b== c",
+    ///         &Options {
+    ///             parse,
+    ///             compile: CompileOptions {
+    ///               extension_tags: [("highlight".into(), "mark".into())].into(),
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p>a <mark>b</mark> c</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub extension_tags: BTreeMap<String, String>,
+
+    /// GFM footnote definitions that are defined elsewhere, as pairs of
+    /// identifiers and markdown.
+    ///
+    /// Useful when footnotes are defined in another file, such as a shared
+    /// glossary.
+    /// When a footnote is called but not defined in the document, its
+    /// markdown here is parsed as a separate document, with the same parse
+    /// options, and its HTML is used as if it was defined in the document.
+    /// Footnotes defined in the document take precedence.
+    /// The markdown of these footnotes cannot call or define footnotes
+    /// itself: that is an error.
+    ///
+    /// Only used by [`to_html_with_options()`][crate::to_html_with_options],
+    /// [`to_html_to_writer()`][crate::to_html_to_writer], and
+    /// [`to_html_multi()`][crate::to_html_multi]: calls must be known while
+    /// parsing.
+    /// So it is not used by [`Parsed::to_html()`][crate::Parsed::to_html],
+    /// as [`parse()`][crate::parse] does not get compile options.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not know about footnotes defined elsewhere by default:
+    /// assert_eq!(
+    ///     to_html_with_options("a[^b]", &Options::gfm())?,
+    ///     "<p>a[^b]</p>"
+    /// );
+    ///
+    /// // Pass `extra_footnote_definitions` to define them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a[^b]",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               extra_footnote_definitions: vec![("b".into(), "*c*".into())],
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-b\">\n<p><em>c</em> <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub extra_footnote_definitions: Vec<(String, String)>,
+
+    /// How to compile frontmatter.
+    ///
+    /// The default is [`FrontmatterRendering::Ignore`][], which drops
+    /// frontmatter: it is data about the document, not part of it.
+    /// Pass [`FrontmatterRendering::CodeBlock`][] to show it as code, or
+    /// [`FrontmatterRendering::Comment`][] to keep it in an HTML comment,
+    /// which can be useful when previewing documents.
+    ///
+    /// Frontmatter must be turned on with
+    /// [`Constructs::frontmatter`][crate::Constructs::frontmatter].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Constructs, FrontmatterRendering, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` drops frontmatter by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "---\ntitle: Venus\n---\n\n# Venus",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h1>Venus</h1>"
+    /// );
+    ///
+    /// // Pass `frontmatter` to show it:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "---\ntitle: Venus\n---\n\n# Venus",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs {
+    ///                 frontmatter: true,
+    ///                 ..Constructs::default()
+    ///               },
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             compile: CompileOptions {
+    ///               frontmatter: FrontmatterRendering::CodeBlock,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<pre><code class=\"language-yaml\">title: Venus\n</code></pre>\n<h1>Venus</h1>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub frontmatter: FrontmatterRendering,
+
+    /// Textual label to use for the footnotes section.
+    ///
+    /// The default value is `"Footnotes"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"Footnotes"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label: Some("Notes de bas de page".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Notes de bas de page</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label: Option<String>,
+
+    /// HTML tag name to use for the footnote label element.
+    ///
+    /// The default value is `"h2"`.
+    /// Change it to match your document structure.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass different attributes with the `gfm_footnote_label_attributes`
+    /// option.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"h2"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_tag_name` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_tag_name: Some("h1".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h1 id=\"footnote-label\" class=\"sr-only\">Footnotes</h1>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_tag_name: Option<String>,
+
+    /// Attributes to use on the footnote label.
+    ///
+    /// The default value is `"class=\"sr-only\""`.
+    /// Change it to show the label and add other attributes.
+    ///
+    /// This label is typically hidden visually (assuming a `sr-only` CSS class
+    /// is defined that does that), and thus affects screen readers only.
+    /// If you do have such a class, but want to show this section to everyone,
+    /// pass an empty string.
+    /// You can also add different attributes.
+    ///
+    /// > 👉 **Note**: `id="footnote-label"` is always added, because footnote
+    /// > calls use it with `aria-describedby` to provide an accessible label.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"class=\"sr-only\""` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_label_attributes` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_label_attributes: Some("class=\"footnote-heading\"".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"footnote-heading\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_label_attributes: Option<String>,
+
+    /// Textual label to describe the backreference back to footnote calls.
+    ///
+    /// The default value is `"Back to content"`.
+    /// Change it when the markdown is not in English.
+    ///
+    /// This label is used in the `aria-label` attribute on each backreference
+    /// (the `↩` links).
+    /// It affects users of assistive technology.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"Back to content"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_back_label: Some("Arrière".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_back_label: Option<String>,
+
+    /// Prefix to use before the `id` attribute on footnotes to prevent them
+    /// from *clobbering*.
+    ///
+    /// The default is `"user-content-"`.
+    /// Pass `Some("".into())` for trusted markdown and when you are careful
+    /// with polyfilling: an empty prefix turns this protection off.
+    /// You could pass a different prefix.
+    /// The prefix is sanitized like footnote labels are: characters that are
+    /// not allowed in URLs (such as spaces, quotes, and non-ASCII characters)
+    /// are percent-encoded, so that each `id` is exactly the fragment of the
+    /// `href` that links to it.
+    ///
+    /// DOM clobbering is this:
+    ///
+    /// ```html
+    /// <p id="x"></p>
+    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
+    /// ```
+    ///
+    /// The above example shows that elements are made available by browsers,
+    /// by their ID, on the `window` object.
+    /// This is a security risk because you might be expecting some other
+    /// variable at that place.
+    /// It can also break polyfills.
+    /// Using a prefix solves these problems.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `"user-content-"` is used by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_clobber_prefix: Some("".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_clobber_prefix: Option<String>,
+
+    /// Namespace to use in the `id` attributes of footnotes, and in the
+    /// `href` attributes that link to them.
+    ///
+    /// The default is `None`, which uses no namespace.
+    /// Pass a different string for each document (such as the ID of a
+    /// comment) when several documents are rendered into one page, so that
+    /// their footnotes do not collide.
+    /// The namespace goes after the clobber prefix (see
+    /// [`gfm_footnote_clobber_prefix`][Self::gfm_footnote_clobber_prefix]),
+    /// and before `fn-` and `fnref-`.
+    /// It is also used before the `footnote-label` ID of the label and the
+    /// `aria-describedby` attributes that point to it.
+    /// It is sanitized like the clobber prefix.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // Pass `gfm_footnote_id_prefix` to namespace IDs:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "[^a]\n\n[^a]: b",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_id_prefix: Some("comment-1-".into()),
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p><sup><a href=\"#user-content-comment-1-fn-a\" id=\"user-content-comment-1-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"comment-1-footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"comment-1-footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-comment-1-fn-a\">\n<p>b <a href=\"#user-content-comment-1-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_id_prefix: Option<String>,
+
+    /// Order in which GFM footnotes are numbered.
+    ///
+    /// The default is [`FootnoteNumbering::CallOrder`][], which numbers
+    /// footnotes in the order they are first called, like GitHub does.
+    /// Pass [`FootnoteNumbering::DefinitionOrder`][] to number them in the
+    /// order they are defined, for example so that the numbers match those of
+    /// a printed manuscript.
+    ///
+    /// This affects the numbers of calls and the order of items in the
+    /// footnote section.
+    /// In both cases, footnotes that are defined but never called are not
+    /// included.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, FootnoteNumbering, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // Footnotes are numbered in call order by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a[^y] b[^x]\n\n[^x]: c\n[^y]: d",
+    ///         &Options::gfm()
+    ///     )?,
+    ///     "<p>a<sup><a href=\"#user-content-fn-y\" id=\"user-content-fnref-y\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> b<sup><a href=\"#user-content-fn-x\" id=\"user-content-fnref-x\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-y\">\n<p>d <a href=\"#user-content-fnref-y\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-x\">\n<p>c <a href=\"#user-content-fnref-x\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    ///
+    /// // Pass `gfm_footnote_numbering` to number them in definition order:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a[^y] b[^x]\n\n[^x]: c\n[^y]: d",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               gfm_footnote_numbering: FootnoteNumbering::DefinitionOrder,
+    ///               ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<p>a<sup><a href=\"#user-content-fn-y\" id=\"user-content-fnref-y\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup> b<sup><a href=\"#user-content-fn-x\" id=\"user-content-fnref-x\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-x\">\n<p>c <a href=\"#user-content-fnref-x\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-y\">\n<p>d <a href=\"#user-content-fnref-y\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_footnote_numbering: FootnoteNumbering,
+
+    /// Whether or not GFM task list html `<input>` items are enabled.
+    ///
+    /// This determines whether or not the user of the browser is able
+    /// to click and toggle generated checkbox items. The default is false.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // With `gfm_task_list_item_checkable`, generated `<input type="checkbox" />`
+    /// // tags do not contain the attribute `disabled=""` and are thus toggleable by
+    /// // browser users.
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "* [x] y.",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///                 gfm_task_list_item_checkable: true,
+    ///                 ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li><input type=\"checkbox\" checked=\"\" /> y.</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_task_list_item_checkable: bool,
+
+    /// Whether to add the place of GFM task list item checks to their
+    /// generated `<input>` elements.
+    ///
+    /// The default is `false`.
+    /// Pass `true` to add a `data-offset` attribute, with the byte offset of
+    /// the `[` of the check in the document, to each `<input>`.
+    /// This can be used to map a checkbox back to the source, for example to
+    /// toggle it there when a browser user clicks it.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // With `gfm_task_list_item_data_offset`, generated `<input>` tags get
+    /// // a `data-offset` attribute:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "* [x] y.",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///                 gfm_task_list_item_data_offset: true,
+    ///                 ..CompileOptions::gfm()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<ul>\n<li><input type=\"checkbox\" disabled=\"\" data-offset=\"2\" checked=\"\" /> y.</li>\n</ul>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_task_list_item_data_offset: bool,
+
+    /// Whether to support the GFM tagfilter.
+    ///
+    /// This option does nothing if `allow_dangerous_html` is not turned on.
+    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
+    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    ///
+    /// The tagfilter is kinda weird and kinda useless.
+    /// The tag filter is a naïve attempt at XSS protection.
+    /// You should use a proper HTML sanitizing algorithm instead.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "<iframe>"
+    /// );
+    ///
+    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<iframe>",
+    ///         &Options {
+    ///             parse: ParseOptions::gfm(),
+    ///             compile: CompileOptions {
+    ///               allow_dangerous_html: true,
+    ///               gfm_tagfilter: true,
+    ///               ..CompileOptions::default()
+    ///             }
+    ///         }
+    ///     )?,
+    ///     "&lt;iframe>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ## References
+    ///
+    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
+    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
+    pub gfm_tagfilter: bool,
+
+    /// Whether to turn line endings in paragraphs and headings into breaks.
+    ///
+    /// The default is `false`, which keeps line endings in text as line
+    /// endings, which browsers show as spaces.
+    /// Pass `true` to turn them into `<br />` followed by the line ending,
+    /// like GitHub does in comments.
+    /// Line endings in code, HTML, and titles are not affected, and neither
+    /// are line endings that already are hard breaks.
+    ///
+    /// It only changes the HTML: syntax trees have no breaks for these line
+    /// endings.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` keeps line endings by default:
+    /// assert_eq!(to_html("a\nb"), "<p>a\nb</p>");
+    ///
+    /// // Pass `hard_wrap` to turn them into breaks:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a\nb",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               hard_wrap: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a<br />\nb</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub hard_wrap: bool,
+
+    /// Number of levels to shift headings by.
+    ///
+    /// The default is `0`, which keeps heading ranks as they are in the
+    /// markdown.
+    /// Pass a positive number to make headings less important (such as `2`
+    /// to turn `# a` into `<h3>`), or a negative number to make them more
+    /// important.
+    /// The resulting rank is clamped between `1` and `6`.
+    ///
+    /// This is useful when embedding markdown in a page that already has
+    /// headings of its own.
+    /// It applies to ATX and setext headings only, not to raw HTML.
+    /// The syntax tree (mdast) is not affected.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` keeps heading ranks by default:
+    /// assert_eq!(
+    ///     to_html("# a\n\nb\n-"),
+    ///     "<h1>a</h1>\n<h2>b</h2>"
+    /// );
+    ///
+    /// // Pass `heading_shift` to shift them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "# a\n\nb\n-\n\n###### c",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               heading_shift: 2,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h3>a</h3>\n<h4>b</h4>\n<h6>c</h6>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_shift: i8,
+
+    /// Function to turn images into HTML.
+    ///
+    /// The default is `None`, which compiles images to `<img>`.
+    /// Pass a function to compile images differently, for example into a
+    /// `<picture>`.
+    /// It is called with [`ImageInfo`][] for each image (also for images
+    /// from references and in syntax trees), except for images in the
+    /// description of other images, which are only text.
+    /// Return `Some` with HTML to use instead of `<img>`, or `None` to use
+    /// `<img>`.
+    ///
+    /// > 👉 **Important**: the returned HTML is used as-is.
+    /// > The values in [`ImageInfo`][] are not HTML-escaped, so make sure to
+    /// > escape them when making HTML.
+    ///
+    /// Functions cannot be serialized: with the `serde` feature, this field
+    /// is skipped, and `None` when deserializing.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// use std::sync::Arc;
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` compiles images to `<img>` by default:
+    /// assert_eq!(
+    ///     to_html_with_options("![Venus](venus.png)", &Options::default())?,
+    ///     "<p><img src=\"venus.png\" alt=\"Venus\" /></p>"
+    /// );
+    ///
+    /// // Pass `image_renderer` to compile them differently:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "![Venus](venus.png)",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               image_renderer: Some(Arc::new(|image| {
+    ///                   Some(format!("<figure><img src=\"{}\" /></figure>", image.src))
+    ///               })),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><figure><img src=\"venus.png\" /></figure></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub image_renderer: Option<Arc<ImageRenderer>>,
+
+    /// Maximum size of the HTML, in bytes.
+    ///
+    /// The default is `None`, which does not limit the size.
+    /// Pass a number to stop compiling and error when the HTML would become
+    /// bigger than that.
+    ///
+    /// Small documents can make big HTML, such as with many references to a
+    /// definition with a long URL, or images with long alt text.
+    /// The size is checked while the HTML is generated, so that no more than
+    /// the maximum is kept in memory.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// let options = Options {
+    ///     compile: CompileOptions {
+    ///       max_output_size: Some(17),
+    ///       ..CompileOptions::default()
+    ///     },
+    ///     ..Options::default()
+    /// };
+    ///
+    /// // Small enough:
+    /// assert_eq!(to_html_with_options("*a*", &options)?, "<p><em>a</em></p>");
+    ///
+    /// // Too big:
+    /// let error = to_html_with_options("*a* *b*", &options).unwrap_err();
+    /// assert_eq!(error.rule_id.as_str(), "max-output-size");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub max_output_size: Option<usize>,
+
+    /// Protocols to allow in links.
+    ///
+    /// The default is `None`, which allows `http`, `https`, `irc`, `ircs`,
+    /// `mailto`, and `xmpp`.
+    /// Pass a list of protocols to allow those instead.
+    /// Protocols are compared case-insensitively.
+    /// URLs without a protocol (relative URLs) are always allowed.
+    ///
+    /// Applies to links, autolinks, GFM autolink literals, and references
+    /// to definitions.
+    /// Has no effect when
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// is on.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` drops `tel:` URLs by default:
+    /// assert_eq!(
+    ///     to_html_with_options("<tel:123>", &Options::default())?,
+    ///     "<p><a href=\"\">tel:123</a></p>"
+    /// );
+    ///
+    /// // Pass `protocol_href` to allow them:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "<tel:123>",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               protocol_href: Some(vec!["https".into(), "tel".into()]),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><a href=\"tel:123\">tel:123</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub protocol_href: Option<Vec<String>>,
+
+    /// Protocols to allow in images.
     ///
-    /// This label is used in the `aria-label` attribute on each backreference
-    /// (the `↩` links).
-    /// It affects users of assistive technology.
+    /// The default is `None`, which allows `http` and `https`.
+    /// Pass a list of protocols to allow those instead.
+    /// Protocols are compared case-insensitively.
+    /// URLs without a protocol (relative URLs) are always allowed.
+    ///
+    /// Has no effect when
+    /// [`allow_dangerous_protocol`][CompileOptions::allow_dangerous_protocol]
+    /// is on.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"Back to content"` is used by default:
+    /// // `markdown-rs` allows `http:` images by default:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
-    ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     to_html_with_options("![a](http://b.com/c.png)", &Options::default())?,
+    ///     "<p><img src=\"http://b.com/c.png\" alt=\"a\" /></p>"
     /// );
     ///
-    /// // Pass `gfm_footnote_back_label` to use something else:
+    /// // Pass `protocol_src` to allow only `https:`:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
+    ///         "![a](http://b.com/c.png)",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               gfm_footnote_back_label: Some("Arrière".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///               protocol_src: Some(vec!["https".into()]),
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Arrière\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><img src=\"\" alt=\"a\" /></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_back_label: Option<String>,
+    pub protocol_src: Option<Vec<String>>,
 
-    /// Prefix to use before the `id` attribute on footnotes to prevent them
-    /// from *clobbering*.
-    ///
-    /// The default is `"user-content-"`.
-    /// Pass `Some("".into())` for trusted markdown and when you are careful
-    /// with polyfilling.
-    /// You could pass a different prefix.
-    ///
-    /// DOM clobbering is this:
+    /// Style of void elements that are generated from markdown, such as
+    /// `<br />`, `<hr />`, `<img />`, and `<input />`.
     ///
-    /// ```html
-    /// <p id="x"></p>
-    /// <script>alert(x) // `x` now refers to the `p#x` DOM element</script>
-    /// ```
+    /// The default is [`SelfClosingStyle::Xhtml`][], which generates a space
+    /// and a slash before the `>`.
+    /// Pass [`SelfClosingStyle::Html5`][] to generate `<br>` and such, which
+    /// some HTML linters prefer, and which other markdown parsers generate.
     ///
-    /// The above example shows that elements are made available by browsers,
-    /// by their ID, on the `window` object.
-    /// This is a security risk because you might be expecting some other
-    /// variable at that place.
-    /// It can also break polyfills.
-    /// Using a prefix solves these problems.
+    /// HTML in markdown is not affected.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options, SelfClosingStyle};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // `"user-content-"` is used by default:
+    /// // `markdown-rs` uses XHTML style by default:
+    /// assert_eq!(to_html("***"), "<hr />");
+    ///
+    /// // Pass `self_closing` to use HTML5 style:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options::gfm()
+    ///         "***",
+    ///         &Options {
+    ///             compile: CompileOptions {
+    ///               self_closing: SelfClosingStyle::Html5,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<hr>"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub self_closing: SelfClosingStyle,
+
+    /// Whether to skip MDX nodes when compiling a syntax tree with
+    /// [`mdast_to_html()`][crate::mdast_to_html].
     ///
-    /// // Pass `gfm_footnote_clobber_prefix` to use something else:
+    /// The default is `false`, which errors when there are MDX nodes, as
+    /// they cannot be turned into HTML.
+    /// Pass `true` to drop ESM and expressions, and to compile the children
+    /// of JSX elements without the elements themselves.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{mdast_to_html, to_mdast, CompileOptions, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// let tree = to_mdast("<Venus>*a*</Venus> {b}", &ParseOptions::mdx())?;
+    ///
+    /// // MDX nodes are an error by default:
+    /// let error = mdast_to_html(&tree, &CompileOptions::default()).unwrap_err();
+    /// assert_eq!(error.rule_id.as_str(), "unexpected-mdx-node");
+    ///
+    /// // Pass `skip_mdx_nodes` to skip them:
     /// assert_eq!(
-    ///     to_html_with_options(
-    ///         "[^a]\n\n[^a]: b",
-    ///         &Options {
-    ///             parse: ParseOptions::gfm(),
-    ///             compile: CompileOptions {
-    ///               gfm_footnote_clobber_prefix: Some("".into()),
-    ///               ..CompileOptions::gfm()
-    ///             }
+    ///     mdast_to_html(
+    ///         &tree,
+    ///         &CompileOptions {
+    ///           skip_mdx_nodes: true,
+    ///           ..CompileOptions::default()
     ///         }
     ///     )?,
-    ///     "<p><sup><a href=\"#fn-a\" id=\"fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"fn-a\">\n<p>b <a href=\"#fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n"
+    ///     "<p><em>a</em> </p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_footnote_clobber_prefix: Option<String>,
+    pub skip_mdx_nodes: bool,
 
-    /// Whether or not GFM task list html `<input>` items are enabled.
+    /// Whether to turn ASCII punctuation into typographic punctuation.
     ///
-    /// This determines whether or not the user of the browser is able
-    /// to click and toggle generated checkbox items. The default is false.
+    /// The default is `false`, which keeps punctuation as it is.
+    /// Pass `true` to turn straight quotes (`"` and `'`) into curly quotes
+    /// (`“”` and `‘’`, with `’` as an apostrophe), `--` into an en dash (`–`),
+    /// `---` into an em dash (`—`), and `...` into an ellipsis (`…`).
+    /// Whether a quote opens or closes depends on the characters around it.
+    ///
+    /// This only affects text.
+    /// Code, math, HTML, autolinks, destinations, titles, and escaped
+    /// characters (such as `\"`) are kept.
+    /// The syntax tree is not affected either: it keeps the source text.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // With `gfm_task_list_item_checkable`, generated `<input type="checkbox" />`
-    /// // tags do not contain the attribute `disabled=""` and are thus toggleable by
-    /// // browser users.
+    /// // `markdown-rs` keeps punctuation by default:
+    /// assert_eq!(
+    ///     to_html("\"It's 1--2...\""),
+    ///     "<p>&quot;It's 1--2...&quot;</p>"
+    /// );
+    ///
+    /// // Pass `smart_punctuation: true` to make it typographic:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "* [x] y.",
+    ///         "\"It's 1--2...\"",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///                 gfm_task_list_item_checkable: true,
-    ///                 ..CompileOptions::gfm()
-    ///             }
+    ///               smart_punctuation: true,
+    ///               ..CompileOptions::default()
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<ul>\n<li><input type=\"checkbox\" checked=\"\" /> y.</li>\n</ul>"
+    ///     "<p>“It’s 1–2…”</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub gfm_task_list_item_checkable: bool,
+    pub smart_punctuation: bool,
 
-    /// Whether to support the GFM tagfilter.
+    /// Whether to remove control characters from the HTML.
     ///
-    /// This option does nothing if `allow_dangerous_html` is not turned on.
-    /// The default is `false`, which does not apply the GFM tagfilter to HTML.
-    /// Pass `true` for output that is a bit closer to GitHub’s actual output.
+    /// The default is `false`, which keeps control characters, as they are
+    /// allowed by `CommonMark`.
+    /// NUL is always replaced with a replacement character (`�`).
+    /// Pass `true` to remove the other C0 control characters, `DEL`, and the
+    /// C1 control characters, except for tabs and line endings.
+    /// This is useful when the HTML is processed by tools that reject them,
+    /// such as XML parsers.
     ///
-    /// The tagfilter is kinda weird and kinda useless.
-    /// The tag filter is a naïve attempt at XSS protection.
-    /// You should use a proper HTML sanitizing algorithm instead.
+    /// This only affects HTML, not the syntax tree.
     ///
     /// ## Examples
     ///
     /// ```
-    /// use markdown::{to_html_with_options, CompileOptions, Options, ParseOptions};
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
     /// # fn main() -> Result<(), markdown::message::Message> {
     ///
-    /// // With `allow_dangerous_html`, `markdown-rs` passes HTML through untouched:
+    /// // `markdown-rs` keeps control characters by default:
+    /// assert_eq!(to_html("a\u{1}b\0c"), "<p>a\u{1}b�c</p>");
+    ///
+    /// // Pass `strip_control_characters: true` to remove them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "<iframe>",
+    ///         "a\u{1}b\0c",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
+    ///               strip_control_characters: true,
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<iframe>"
+    ///     "<p>ab�c</p>"
     /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub strip_control_characters: bool,
+
+    /// Base URL to resolve relative URLs against.
     ///
-    /// // Pass `gfm_tagfilter: true` to make some of that safe:
+    /// The default is `None`, which keeps URLs as they are.
+    /// Pass an absolute URL to resolve the destinations of links, images,
+    /// and definitions that are relative to a path (such as `./a.md`,
+    /// `../b.png`, `c`, `/d`, or `?e`) against it, following
+    /// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2).
+    /// This is useful when HTML is shown somewhere else than where the
+    /// markdown lives, such as a readme on a different host.
+    ///
+    /// URLs with a protocol (such as `https:`, `mailto:`, or `data:`),
+    /// protocol-relative URLs (`//`), and fragments (`#`) are kept.
+    /// Autolinks always have a protocol.
+    /// The result is sanitized like other URLs.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, CompileOptions, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` keeps URLs as they are by default:
+    /// assert_eq!(
+    ///     to_html("[a](../b.md)"),
+    ///     "<p><a href=\"../b.md\">a</a></p>"
+    /// );
+    ///
+    /// // Pass `url_base` to resolve them:
     /// assert_eq!(
     ///     to_html_with_options(
-    ///         "<iframe>",
+    ///         "[a](../b.md)",
     ///         &Options {
-    ///             parse: ParseOptions::gfm(),
     ///             compile: CompileOptions {
-    ///               allow_dangerous_html: true,
-    ///               gfm_tagfilter: true,
+    ///               url_base: Some("https://example.com/c/d/".into()),
     ///               ..CompileOptions::default()
-    ///             }
+    ///             },
+    ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "&lt;iframe>"
+    ///     "<p><a href=\"https://example.com/c/b.md\">a</a></p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    ///
-    /// ## References
-    ///
-    /// *   [*§ 6.1 Disallowed Raw HTML (extension)* in GFM](https://github.github.com/gfm/#disallowed-raw-html-extension-)
-    /// *   [`cmark-gfm#extensions/tagfilter.c`](https://github.com/github/cmark-gfm/blob/master/extensions/tagfilter.c)
-    pub gfm_tagfilter: bool,
+    pub url_base: Option<String>,
+    // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
+}
+
+impl fmt::Debug for CompileOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompileOptions")
+            .field("allow_dangerous_html", &self.allow_dangerous_html)
+            .field("allow_dangerous_protocol", &self.allow_dangerous_protocol)
+            .field("allow_html_comments", &self.allow_html_comments)
+            .field("attribute_quote", &self.attribute_quote)
+            .field("boolean_attribute_style", &self.boolean_attribute_style)
+            .field("decode_titles", &self.decode_titles)
+            .field("default_line_ending", &self.default_line_ending)
+            .field("element_overrides", &self.element_overrides)
+            .field("extension_tags", &self.extension_tags)
+            .field(
+                "extra_footnote_definitions",
+                &self.extra_footnote_definitions,
+            )
+            .field("frontmatter", &self.frontmatter)
+            .field("gfm_footnote_label", &self.gfm_footnote_label)
+            .field(
+                "gfm_footnote_label_tag_name",
+                &self.gfm_footnote_label_tag_name,
+            )
+            .field(
+                "gfm_footnote_label_attributes",
+                &self.gfm_footnote_label_attributes,
+            )
+            .field("gfm_footnote_back_label", &self.gfm_footnote_back_label)
+            .field(
+                "gfm_footnote_clobber_prefix",
+                &self.gfm_footnote_clobber_prefix,
+            )
+            .field("gfm_footnote_id_prefix", &self.gfm_footnote_id_prefix)
+            .field("gfm_footnote_numbering", &self.gfm_footnote_numbering)
+            .field(
+                "gfm_task_list_item_checkable",
+                &self.gfm_task_list_item_checkable,
+            )
+            .field(
+                "gfm_task_list_item_data_offset",
+                &self.gfm_task_list_item_data_offset,
+            )
+            .field("gfm_tagfilter", &self.gfm_tagfilter)
+            .field("hard_wrap", &self.hard_wrap)
+            .field("heading_shift", &self.heading_shift)
+            .field(
+                "image_renderer",
+                &self.image_renderer.as_ref().map(|_d| "[Function]"),
+            )
+            .field("max_output_size", &self.max_output_size)
+            .field("protocol_href", &self.protocol_href)
+            .field("protocol_src", &self.protocol_src)
+            .field("self_closing", &self.self_closing)
+            .field("skip_mdx_nodes", &self.skip_mdx_nodes)
+            .field("smart_punctuation", &self.smart_punctuation)
+            .field("strip_control_characters", &self.strip_control_characters)
+            .field("url_base", &self.url_base)
+            .finish()
+    }
+}
+
+impl Default for CompileOptions {
+    /// `CommonMark` defaults.
+    fn default() -> Self {
+        Self {
+            allow_dangerous_html: false,
+            allow_dangerous_protocol: false,
+            allow_html_comments: false,
+            attribute_quote: QuoteKind::default(),
+            boolean_attribute_style: BooleanAttributeStyle::default(),
+            decode_titles: true,
+            default_line_ending: LineEnding::default(),
+            element_overrides: ElementOverrides::default(),
+            extension_tags: BTreeMap::new(),
+            extra_footnote_definitions: vec![],
+            frontmatter: FrontmatterRendering::default(),
+            gfm_footnote_label: None,
+            gfm_footnote_label_tag_name: None,
+            gfm_footnote_label_attributes: None,
+            gfm_footnote_back_label: None,
+            gfm_footnote_clobber_prefix: None,
+            gfm_footnote_id_prefix: None,
+            gfm_footnote_numbering: FootnoteNumbering::default(),
+            gfm_task_list_item_checkable: false,
+            gfm_task_list_item_data_offset: false,
+            gfm_tagfilter: false,
+            hard_wrap: false,
+            heading_shift: 0,
+            image_renderer: None,
+            max_output_size: None,
+            protocol_href: None,
+            protocol_src: None,
+            self_closing: SelfClosingStyle::default(),
+            skip_mdx_nodes: false,
+            smart_punctuation: false,
+            strip_control_characters: false,
+            url_base: None,
+        }
+    }
 }
 
 impl CompileOptions {
@@ -943,6 +2445,52 @@ impl CompileOptions {
     }
 }
 
+/// GFM strikethrough sequences to support.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::GfmStrikethroughAllowed;
+/// # fn main() {
+///
+/// // Support strikethrough with two tildes, but not one:
+/// let double = GfmStrikethroughAllowed::DoubleOnly;
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum GfmStrikethroughAllowed {
+    /// One or two tildes.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// ~a~ ~~b~~
+    /// ```
+    #[default]
+    Both,
+    /// One tilde.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// ~a~
+    /// ```
+    SingleOnly,
+    /// Two tildes.
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// ~~a~~
+    /// ```
+    DoubleOnly,
+}
+
 /// Configuration that describes how to parse from markdown.
 ///
 /// You can use this:
@@ -967,6 +2515,11 @@ impl CompileOptions {
 /// # }
 /// ```
 #[allow(clippy::struct_excessive_bools)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
 pub struct ParseOptions {
     // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
     /// Which constructs to enable and disable.
@@ -1000,12 +2553,109 @@ pub struct ParseOptions {
     ///             ..Options::default()
     ///         }
     ///     )?,
-    ///     "<p>indented code?</p>"
+    ///     "<p>indented code?</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub constructs: Constructs,
+
+    /// Whether character escapes are inert when preceded by an ASCII
+    /// alphanumeric.
+    ///
+    /// The default is `false`, which follows `CommonMark`: a backslash before
+    /// ASCII punctuation is always a character escape.
+    /// Pass `true` to keep backslashes in words, such as in Windows paths
+    /// (`C:\Users\name\(x).md`), as they are.
+    /// Escapes elsewhere (`\*a*`, `a \*`) still work, and so do hard breaks
+    /// (escape).
+    /// Use a character reference to escape punctuation in words (`a&#42;`).
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html("C:\\Users\\name\\(x).md"),
+    ///     "<p>C:\\Users\\name(x).md</p>"
+    /// );
+    ///
+    /// // Pass `character_escape_requires_punctuation_context: true` to keep
+    /// // backslashes in words:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "C:\\Users\\name\\(x).md",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               character_escape_requires_punctuation_context: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>C:\\Users\\name\\(x).md</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub character_escape_requires_punctuation_context: bool,
+
+    /// Which GFM strikethrough sequences to support: one tilde, two tildes,
+    /// or both.
+    ///
+    /// This option does nothing if `gfm_strikethrough` is not turned on in
+    /// `constructs`.
+    ///
+    /// The default is `GfmStrikethroughAllowed::Both`, which follows how
+    /// markdown on `github.com` works.
+    /// Pass `GfmStrikethroughAllowed::DoubleOnly` to follow the GFM spec more
+    /// strictly, or `GfmStrikethroughAllowed::SingleOnly` to keep `~~` as it
+    /// is, for example to use it for other syntax.
+    /// Sequences of three or more tildes never form strikethrough.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Constructs, GfmStrikethroughAllowed, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` supports one or two tildes by default:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~ ~~b~~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><del>a</del> <del>b</del></p>"
+    /// );
+    ///
+    /// // Pass `GfmStrikethroughAllowed::SingleOnly` to turn two tildes off:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "~a~ ~~b~~",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               constructs: Constructs::gfm(),
+    ///               gfm_strikethrough_allowed: GfmStrikethroughAllowed::SingleOnly,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p><del>a</del> ~~b~~</p>"
     /// );
     /// # Ok(())
     /// # }
     /// ```
-    pub constructs: Constructs,
+    pub gfm_strikethrough_allowed: GfmStrikethroughAllowed,
 
     /// Whether to support GFM strikethrough with a single tilde
     ///
@@ -1013,6 +2663,11 @@ pub struct ParseOptions {
     /// `constructs`.
     /// This option does not affect strikethrough with double tildes.
     ///
+    /// This option is kept for compatibility: prefer
+    /// [`gfm_strikethrough_allowed`][Self::gfm_strikethrough_allowed].
+    /// Passing `false` is the same as passing
+    /// `GfmStrikethroughAllowed::DoubleOnly` there.
+    ///
     /// The default is `true`, which follows how markdown on `github.com`
     /// works, as strikethrough with single tildes is supported.
     /// Pass `false`, to follow the GFM spec more strictly, by not allowing
@@ -1059,6 +2714,94 @@ pub struct ParseOptions {
     /// ```
     pub gfm_strikethrough_single_tilde: bool,
 
+    /// Whether to normalize the cells of GFM table rows in the syntax tree.
+    ///
+    /// The default is `false`, which keeps the cells that are in the
+    /// markdown: rows can have fewer or more cells than the head row, as in
+    /// `mdast-util-gfm-table`.
+    /// Pass `true` to pad rows with empty cells (without position) and drop
+    /// excess cells, so that each row has as many cells as the head row.
+    /// That matches how tables are compiled to HTML, like GitHub does.
+    ///
+    /// This only affects [`to_mdast()`][crate::to_mdast].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_mdast, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// let cells = |options: &ParseOptions| -> Result<Vec<usize>, markdown::message::Message> {
+    ///     let tree = to_mdast("| a | b |\n| - | - |\n| c |\n| d | e | f |", options)?;
+    ///     let table = &tree.children().unwrap()[0];
+    ///     Ok(table
+    ///         .children()
+    ///         .unwrap()
+    ///         .iter()
+    ///         .map(|row| row.children().unwrap().len())
+    ///         .collect())
+    /// };
+    ///
+    /// // `markdown-rs` keeps cells as they are by default:
+    /// assert_eq!(cells(&ParseOptions::gfm())?, vec![2, 1, 3]);
+    ///
+    /// // Pass `gfm_table_normalize_cells: true` to match the head row:
+    /// assert_eq!(
+    ///     cells(&ParseOptions {
+    ///       gfm_table_normalize_cells: true,
+    ///       ..ParseOptions::gfm()
+    ///     })?,
+    ///     vec![2, 2, 2]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub gfm_table_normalize_cells: bool,
+
+    /// Max number of markers in the opening sequence of headings (ATX).
+    ///
+    /// The default is `6`, which is the max in `CommonMark`, as HTML has
+    /// six heading ranks: `####### a` is a paragraph.
+    /// Pass a bigger number to allow deeper headings, such as for documents
+    /// that have more levels.
+    /// The closing sequence can be of any size, as always.
+    ///
+    /// In the syntax tree (mdast), the heading `depth` is the number of
+    /// markers.
+    /// In HTML, headings deeper than `6` are compiled to `<h6>` with their
+    /// depth in `aria-level`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows CommonMark by default:
+    /// assert_eq!(
+    ///     to_html("####### a"),
+    ///     "<p>####### a</p>"
+    /// );
+    ///
+    /// // Pass `heading_atx_max_depth` to allow deeper headings:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "####### a",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               heading_atx_max_depth: 8,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<h6 aria-level=\"7\">a</h6>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub heading_atx_max_depth: u8,
+
     /// Whether to support math (text) with a single dollar
     ///
     /// This option does nothing if `math_text` is not turned on in
@@ -1120,6 +2863,183 @@ pub struct ParseOptions {
     /// ```
     pub math_text_single_dollar: bool,
 
+    /// Whether to add the kind of HTML to nodes in the syntax tree.
+    ///
+    /// The default is `false`, which does not add this info.
+    /// Pass `true` to add a [`kind`][crate::mdast::HtmlKind] to HTML nodes,
+    /// which tells comments, CDATA, instructions, and declarations apart
+    /// from elements.
+    /// This is useful to find comments such as `<!-- prettier-ignore -->`.
+    ///
+    /// HTML (flow) that has more HTML after a comment, CDATA, instruction,
+    /// or declaration, such as `<!-- a --><div>`, is an element.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{mdast::{HtmlKind, Node}, to_mdast, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not add the kind by default:
+    /// let tree = to_mdast("<!-- a -->", &ParseOptions::default())?;
+    /// if let Node::Html(node) = &tree.children().unwrap()[0] {
+    ///     assert_eq!(node.kind, None);
+    /// }
+    ///
+    /// // Pass `mdast_html_kind: true` to add it:
+    /// let tree = to_mdast(
+    ///     "<!-- a -->",
+    ///     &ParseOptions {
+    ///         mdast_html_kind: true,
+    ///         ..ParseOptions::default()
+    ///     },
+    /// )?;
+    /// if let Node::Html(node) = &tree.children().unwrap()[0] {
+    ///     assert_eq!(node.kind, Some(HtmlKind::Comment));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mdast_html_kind: bool,
+
+    /// Whether to add info on the markers used to nodes in the syntax tree.
+    ///
+    /// The default is `false`, which does not add this info.
+    /// Pass `true` to add [`markers`][crate::mdast::Markers] to emphasis,
+    /// strong, lists, list items, fenced code, headings, and thematic breaks,
+    /// with which character was used (such as `*` or `_` for emphasis) and
+    /// how many.
+    /// This is useful to check the style of a document, such as when
+    /// linting.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{mdast::{Markers, Node}, to_mdast, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not add markers by default:
+    /// let tree = to_mdast("***", &ParseOptions::default())?;
+    /// if let Node::ThematicBreak(node) = &tree.children().unwrap()[0] {
+    ///     assert_eq!(node.markers, None);
+    /// }
+    ///
+    /// // Pass `mdast_markers: true` to add them:
+    /// let tree = to_mdast(
+    ///     "***",
+    ///     &ParseOptions {
+    ///         mdast_markers: true,
+    ///         ..ParseOptions::default()
+    ///     },
+    /// )?;
+    /// if let Node::ThematicBreak(node) = &tree.children().unwrap()[0] {
+    ///     assert_eq!(node.markers, Some(Markers { character: '*', size: 3 }));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mdast_markers: bool,
+
+    /// Whether to keep each run of data as its own text node in the syntax
+    /// tree.
+    ///
+    /// The default is `false`, which merges adjacent text into one node.
+    /// Pass `true` to start a new [`Text`][crate::mdast::Text] node wherever
+    /// the tokenizer started a new run of data.
+    /// That happens at characters that could start a construct (such as `<`
+    /// for autolinks and HTML (text), `[` for links, or `h` and `w` for GFM
+    /// autolink literals), when no construct was found there: either because
+    /// the syntax is not valid, or because the construct is turned off in
+    /// `constructs`.
+    /// This is useful to find where text looks like disabled syntax, such as
+    /// when linting.
+    ///
+    /// Line endings, character escapes, and character references are still
+    /// merged with the text around them.
+    /// HTML output is not affected.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_mdast, Constructs, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    /// let constructs = Constructs {
+    ///     html_text: false,
+    ///     ..Constructs::default()
+    /// };
+    ///
+    /// // `markdown-rs` merges text by default:
+    /// let tree = to_mdast(
+    ///     "a <b> c",
+    ///     &ParseOptions {
+    ///         constructs: constructs.clone(),
+    ///         ..ParseOptions::default()
+    ///     },
+    /// )?;
+    /// assert_eq!(tree.children().unwrap()[0].children().unwrap().len(), 1);
+    ///
+    /// // Pass `mdast_split_text: true` to keep runs of data apart:
+    /// let tree = to_mdast(
+    ///     "a <b> c",
+    ///     &ParseOptions {
+    ///         constructs,
+    ///         mdast_split_text: true,
+    ///         ..ParseOptions::default()
+    ///     },
+    /// )?;
+    /// assert_eq!(tree.children().unwrap()[0].children().unwrap().len(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mdast_split_text: bool,
+
+    /// Whether to allow HTML void elements in MDX JSX without closing tag.
+    ///
+    /// This option does nothing if `mdx_jsx_flow` or `mdx_jsx_text` are not
+    /// turned on in `constructs`.
+    ///
+    /// The default is `false`, which follows how MDX works: every opening
+    /// tag has to be closed, so `<br>` is a syntax error when compiling to a
+    /// syntax tree.
+    /// Pass `true` to treat lowercase void elements (such as `<br>`, `<hr>`,
+    /// and `<img>`) without a corresponding closing tag as if they were
+    /// self-closing.
+    /// Other tags that are not closed are still an error.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_mdast, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // MDX crashes on unclosed tags by default:
+    /// assert!(to_mdast("a <br> b", &ParseOptions::mdx()).is_err());
+    ///
+    /// // Pass `mdx_allow_unclosed_void_elements: true` to allow void elements:
+    /// assert!(to_mdast(
+    ///     "a <br> b",
+    ///     &ParseOptions {
+    ///         mdx_allow_unclosed_void_elements: true,
+    ///         ..ParseOptions::mdx()
+    ///     }
+    /// ).is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub mdx_allow_unclosed_void_elements: bool,
+
     /// Function to parse expressions with.
     ///
     /// This function can be used to add support for arbitrary programming
@@ -1130,6 +3050,10 @@ pub struct ParseOptions {
     ///
     /// For an example that adds support for JavaScript with SWC, see
     /// `tests/test_utils/mod.rs`.
+    ///
+    /// Functions cannot be serialized: with the `serde` feature, this field
+    /// is skipped, and `None` when deserializing.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mdx_expression_parse: Option<Box<MdxExpressionParse>>,
 
     /// Function to parse ESM with.
@@ -1146,7 +3070,246 @@ pub struct ParseOptions {
     ///
     /// For an example that adds support for JavaScript with SWC, see
     /// `tests/test_utils/mod.rs`.
+    ///
+    /// Functions cannot be serialized: with the `serde` feature, this field
+    /// is skipped, and `None` when deserializing.
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub mdx_esm_parse: Option<Box<MdxEsmParse>>,
+
+    /// Whether to support letters and roman numerals as the values of
+    /// ordered list items.
+    ///
+    /// The default is `false`, which is how `CommonMark` works: only digits
+    /// can be used (`1.`).
+    /// Pass `true` to also allow a single letter (`a.`, `B)`) or a roman
+    /// numeral (`iv.`, `IV)`), like Pandoc.
+    /// A single uppercase letter followed by `.` must be followed by at least
+    /// two spaces (`A.  b`), so that initials (`B. Russell`) do not start
+    /// lists.
+    /// A single lowercase letter followed by `.` and one space must be next
+    /// to another item (`a. b` and `b. c` on adjacent lines), so that
+    /// abbreviations (`i. e.`) do not start lists.
+    ///
+    /// A list starting with `i` or `I` uses roman numerals, other single
+    /// letters start a list of letters, unless the second item is a roman
+    /// numeral (`v.` and `vi.`).
+    /// Items that use another kind of value, or another case, start a new
+    /// list.
+    /// In HTML, such lists get a `type` attribute (such as `<ol type="a">`),
+    /// and in mdast, lists get a [`kind`][crate::mdast::List::kind].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` only supports digits by default:
+    /// assert_eq!(to_html("c) d"), "<p>c) d</p>");
+    ///
+    /// // Pass `ordered_list_letters: true` to support letters too:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "c) d",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               ordered_list_letters: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<ol start=\"3\" type=\"a\">\n<li>d</li>\n</ol>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub ordered_list_letters: bool,
+
+    /// Whether to keep the source of nodes in the syntax tree.
+    ///
+    /// The default is `false`.
+    /// Pass `true` to set `raw` on nodes (such as paragraphs, text, and
+    /// html) to the markdown they were parsed from.
+    /// Nodes in containers (such as block quotes) that span several lines
+    /// do not get `raw`, as their source includes the prefixes of containers.
+    /// This is useful to turn the tree back into the exact same markdown
+    /// with `mdast_util_to_markdown` and its `use_raw` option.
+    ///
+    /// When changing a node, clear its `raw` and that of its ancestors, so
+    /// that they are serialized normally.
+    ///
+    /// It only makes sense to pass this when compiling to a syntax tree
+    /// with [`to_mdast()`][crate::to_mdast()].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_mdast, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` does not keep the source by default:
+    /// let tree = to_mdast("a  *b*", &ParseOptions::default())?;
+    /// assert_eq!(tree.raw(), None);
+    ///
+    /// // Pass `preserve: true` to keep it:
+    /// let tree = to_mdast("a  *b*", &ParseOptions {
+    ///     preserve: true,
+    ///     ..ParseOptions::default()
+    /// })?;
+    /// assert_eq!(tree.raw(), Some(&"a  *b*".into()));
+    /// assert_eq!(tree.children().unwrap()[0].raw(), Some(&"a  *b*".into()));
+    /// assert_eq!(
+    ///     tree.children().unwrap()[0].children().unwrap()[1].raw(),
+    ///     Some(&"*b*".into())
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub preserve: bool,
+
+    /// Whether underscores need whitespace on the outside to form attention.
+    ///
+    /// The default is `false`, which follows `CommonMark`: an underscore
+    /// sequence can open when followed by a word character and preceded by
+    /// whitespace or punctuation (such as in `(_a_)`), and close the other way
+    /// around.
+    /// Pass `true` to also require whitespace (or the start or end of a line)
+    /// *before* an opening and *after* a closing underscore sequence.
+    /// This is useful for prose with identifiers such as `_name()` or
+    /// `*_internal`, which would otherwise form emphasis.
+    ///
+    /// > 👉 **Note**: this deviates from `CommonMark`: `(_a_)` and `_a_.`
+    /// > do not form emphasis when this is on.
+    /// > Asterisks are not affected.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html, to_html_with_options, Options, ParseOptions};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // `markdown-rs` follows `CommonMark` by default:
+    /// assert_eq!(
+    ///     to_html("Use __init__() or _this_ one."),
+    ///     "<p>Use <strong>init</strong>() or <em>this</em> one.</p>"
+    /// );
+    ///
+    /// // Pass `relaxed_underscore: true` to need whitespace around underscores:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "Use __init__() or _this_ one.",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///               relaxed_underscore: true,
+    ///               ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>Use __init__() or <em>this</em> one.</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub relaxed_underscore: bool,
+
+    /// Custom constructs to support in text.
+    ///
+    /// The default is empty.
+    /// Each [`TextExtension`][] is tried, in order, before the built in
+    /// constructs, at the bytes in its `markers`.
+    /// Its `tokenize` function is then called for each byte with a limited
+    /// tokenizer, until it returns [`Ok`][crate::ExtensionState::Ok] or
+    /// [`Nok`][crate::ExtensionState::Nok].
+    ///
+    /// Text extensions cannot span lines and what they consume is not parsed
+    /// further.
+    /// The events they emit are compiled to HTML with
+    /// [`extension_tags`][CompileOptions::extension_tags].
+    /// In the syntax tree (mdast), only their data is kept, as text.
+    ///
+    /// Functions cannot be serialized: with the `serde` feature, this field
+    /// is skipped, and empty when deserializing.
+    ///
+    /// > 👉 **Note**: this is experimental.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options, ParseOptions};
+    /// use markdown::{ExtensionState, ExtensionTokenizer, TextExtension};
+    ///
+    /// // Highlight (`==a==`).
+    /// fn highlight(tokenizer: &mut ExtensionTokenizer) -> ExtensionState {
+    ///     match (tokenizer.state(), tokenizer.current()) {
+    ///         // Opening sequence.
+    ///         (0, Some(b'=')) => {
+    ///             tokenizer.enter("highlight");
+    ///             tokenizer.enter("highlightSequence");
+    ///             tokenizer.consume();
+    ///             tokenizer.set_state(1);
+    ///             ExtensionState::Next
+    ///         }
+    ///         (1, Some(b'=')) => {
+    ///             tokenizer.consume();
+    ///             tokenizer.exit("highlightSequence");
+    ///             tokenizer.set_state(2);
+    ///             ExtensionState::Next
+    ///         }
+    ///         // Content.
+    ///         (2, Some(byte)) if byte != b'=' && !byte.is_ascii_whitespace() => {
+    ///             tokenizer.enter_data();
+    ///             tokenizer.consume();
+    ///             tokenizer.set_state(3);
+    ///             ExtensionState::Next
+    ///         }
+    ///         (3, Some(b'=')) => {
+    ///             tokenizer.exit_data();
+    ///             tokenizer.enter("highlightSequence");
+    ///             tokenizer.consume();
+    ///             tokenizer.set_state(4);
+    ///             ExtensionState::Next
+    ///         }
+    ///         (3, Some(byte)) if byte != b'\n' => {
+    ///             tokenizer.consume();
+    ///             ExtensionState::Next
+    ///         }
+    ///         // Closing sequence.
+    ///         (4, Some(b'=')) => {
+    ///             tokenizer.consume();
+    ///             tokenizer.exit("highlightSequence");
+    ///             tokenizer.exit("highlight");
+    ///             ExtensionState::Ok
+    ///         }
+    ///         _ => ExtensionState::Nok,
+    ///     }
+    /// }
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// // Pass `text_extensions` to support highlight:
+    /// assert_eq!(
+    ///     to_html_with_options(
+    ///         "a ==b== c",
+    ///         &Options {
+    ///             parse: ParseOptions {
+    ///                 text_extensions: vec![TextExtension {
+    ///                     name: "highlight",
+    ///                     markers: vec![b'='],
+    ///                     tokenize: Box::new(highlight),
+    ///                 }],
+    ///                 ..ParseOptions::default()
+    ///             },
+    ///             ..Options::default()
+    ///         }
+    ///     )?,
+    ///     "<p>a b c</p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub text_extensions: Vec<TextExtension>,
     // Note: when adding fields, don’t forget to add them to `fmt::Debug` below.
 }
 
@@ -1154,11 +3317,25 @@ impl fmt::Debug for ParseOptions {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ParseOptions")
             .field("constructs", &self.constructs)
+            .field(
+                "character_escape_requires_punctuation_context",
+                &self.character_escape_requires_punctuation_context,
+            )
+            .field("gfm_strikethrough_allowed", &self.gfm_strikethrough_allowed)
             .field(
                 "gfm_strikethrough_single_tilde",
                 &self.gfm_strikethrough_single_tilde,
             )
+            .field("gfm_table_normalize_cells", &self.gfm_table_normalize_cells)
+            .field("heading_atx_max_depth", &self.heading_atx_max_depth)
             .field("math_text_single_dollar", &self.math_text_single_dollar)
+            .field("mdast_html_kind", &self.mdast_html_kind)
+            .field("mdast_markers", &self.mdast_markers)
+            .field("mdast_split_text", &self.mdast_split_text)
+            .field(
+                "mdx_allow_unclosed_void_elements",
+                &self.mdx_allow_unclosed_void_elements,
+            )
             .field(
                 "mdx_expression_parse",
                 &self.mdx_expression_parse.as_ref().map(|_d| "[Function]"),
@@ -1167,6 +3344,10 @@ impl fmt::Debug for ParseOptions {
                 "mdx_esm_parse",
                 &self.mdx_esm_parse.as_ref().map(|_d| "[Function]"),
             )
+            .field("ordered_list_letters", &self.ordered_list_letters)
+            .field("preserve", &self.preserve)
+            .field("relaxed_underscore", &self.relaxed_underscore)
+            .field("text_extensions", &self.text_extensions)
             .finish()
     }
 }
@@ -1176,10 +3357,22 @@ impl Default for ParseOptions {
     fn default() -> Self {
         Self {
             constructs: Constructs::default(),
+            character_escape_requires_punctuation_context: false,
+            gfm_strikethrough_allowed: GfmStrikethroughAllowed::default(),
             gfm_strikethrough_single_tilde: true,
+            gfm_table_normalize_cells: false,
+            heading_atx_max_depth: HEADING_ATX_OPENING_FENCE_SIZE_MAX,
             math_text_single_dollar: true,
+            mdast_html_kind: false,
+            mdast_markers: false,
+            mdast_split_text: false,
+            mdx_allow_unclosed_void_elements: false,
             mdx_expression_parse: None,
             mdx_esm_parse: None,
+            ordered_list_letters: false,
+            preserve: false,
+            relaxed_underscore: false,
+            text_extensions: vec![],
         }
     }
 }
@@ -1225,6 +3418,58 @@ impl ParseOptions {
             ..Self::default()
         }
     }
+
+    /// Check that the options can be used together.
+    ///
+    /// This is done when parsing, so there is no need to call it before
+    /// that, but it can be used to check options when loading them.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if MDX JSX (flow) is on together with HTML (flow), or MDX JSX
+    /// (text) together with HTML (text).
+    /// HTML is tried first, so most JSX would silently turn into HTML.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{Constructs, ParseOptions};
+    ///
+    /// assert!(ParseOptions::mdx().validate().is_ok());
+    ///
+    /// let options = ParseOptions {
+    ///     constructs: Constructs {
+    ///         html_flow: true,
+    ///         ..Constructs::mdx()
+    ///     },
+    ///     ..ParseOptions::mdx()
+    /// };
+    /// assert!(options.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<(), message::Message> {
+        let constructs = &self.constructs;
+        let conflict = if constructs.mdx_jsx_flow && constructs.html_flow {
+            Some(("mdx_jsx_flow", "html_flow"))
+        } else if constructs.mdx_jsx_text && constructs.html_text {
+            Some(("mdx_jsx_text", "html_text"))
+        } else {
+            None
+        };
+
+        if let Some((jsx, html)) = conflict {
+            return Err(message::Message {
+                place: None,
+                reason: format!(
+                    "Unexpected `{}` together with `{}`, expected only one of them, as HTML takes precedence over JSX; turn `{}` off",
+                    jsx, html, html
+                ),
+                rule_id: Box::new(message::rules::INVALID_OPTIONS.into()),
+                source: Box::new(message::rules::SOURCE.into()),
+            });
+        }
+
+        Ok(())
+    }
 }
 
 /// Configuration that describes how to parse from markdown and compile to
@@ -1247,6 +3492,11 @@ impl ParseOptions {
 /// ```
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase", default)
+)]
 pub struct Options {
     /// Configuration that describes how to parse from markdown.
     pub parse: ParseOptions,
@@ -1271,6 +3521,59 @@ impl Options {
             compile: CompileOptions::gfm(),
         }
     }
+
+    /// Safe inline.
+    ///
+    /// This parses with [`Constructs::inline_only`][], so only emphasis,
+    /// strong, code (text), links, and images form.
+    /// On the compilation side, links can only use `http:` and `https:` (or
+    /// relative URLs), images are shown as their alt text, and control
+    /// characters are removed.
+    /// HTML is never allowed, as dangerous HTML and protocols stay off.
+    ///
+    /// This is useful for short, untrusted content, such as user bios and
+    /// chat messages.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::{to_html_with_options, Options};
+    /// # fn main() -> Result<(), markdown::message::Message> {
+    ///
+    /// assert_eq!(
+    ///     to_html_with_options("# *a* ![b](c.png) [d](https://e.com)", &Options::safe_inline())?,
+    ///     "<p># <em>a</em> b <a href=\"https://e.com\">d</a></p>"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn safe_inline() -> Self {
+        Self {
+            parse: ParseOptions {
+                constructs: Constructs::inline_only(),
+                ..ParseOptions::default()
+            },
+            compile: CompileOptions {
+                image_renderer: Some(Arc::new(|image| {
+                    Some(encode(&image.alt, true).into_owned())
+                })),
+                protocol_href: Some(vec!["http".into(), "https".into()]),
+                strip_control_characters: true,
+                ..CompileOptions::default()
+            },
+        }
+    }
+
+    /// Check that the options can be used together.
+    ///
+    /// See [`ParseOptions::validate`][] for the checks.
+    ///
+    /// ## Errors
+    ///
+    /// Errors if the parse options cannot be used together.
+    pub fn validate(&self) -> Result<(), message::Message> {
+        self.parse.validate()
+    }
 }
 
 #[cfg(test)]
@@ -1320,6 +3623,51 @@ mod tests {
         );
         assert!(constructs.mdx_jsx_flow, "should support `mdx` shortcut (3)");
         assert!(!constructs.frontmatter, "should support `mdx` shortcut (4)");
+
+        let constructs = Constructs::inline_only();
+        assert!(
+            constructs.attention,
+            "should support `inline_only` shortcut (1)"
+        );
+        assert!(
+            constructs.label_start_link,
+            "should support `inline_only` shortcut (2)"
+        );
+        assert!(
+            !constructs.heading_atx,
+            "should support `inline_only` shortcut (3)"
+        );
+        assert!(
+            !constructs.html_text,
+            "should support `inline_only` shortcut (4)"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_constructs_inline_only() {
+        let value = serde_json::to_value(Constructs::inline_only()).unwrap();
+        let on = value
+            .as_object()
+            .unwrap()
+            .iter()
+            .filter(|(_, d)| d.as_bool().unwrap())
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            on,
+            vec![
+                "attention",
+                "characterEscape",
+                "characterReference",
+                "codeText",
+                "labelEnd",
+                "labelStartImage",
+                "labelStartLink"
+            ],
+            "should turn on exactly the inline constructs in `inline_only`"
+        );
     }
 
     #[test]
@@ -1372,7 +3720,7 @@ mod tests {
 
         assert_eq!(
             format!("{:?}", ParseOptions::default()),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: None, mdx_esm_parse: None }",
+            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, footnote_inline: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, character_escape_requires_punctuation_context: false, gfm_strikethrough_allowed: Both, gfm_strikethrough_single_tilde: true, gfm_table_normalize_cells: false, heading_atx_max_depth: 6, math_text_single_dollar: true, mdast_html_kind: false, mdast_markers: false, mdast_split_text: false, mdx_allow_unclosed_void_elements: false, mdx_expression_parse: None, mdx_esm_parse: None, ordered_list_letters: false, preserve: false, relaxed_underscore: false, text_extensions: [] }",
             "should support `Debug` trait"
         );
         assert_eq!(
@@ -1385,7 +3733,7 @@ mod tests {
                 })),
                 ..Default::default()
             }),
-            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, gfm_strikethrough_single_tilde: true, math_text_single_dollar: true, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\") }",
+            "ParseOptions { constructs: Constructs { attention: true, autolink: true, block_quote: true, character_escape: true, character_reference: true, code_indented: true, code_fenced: true, code_text: true, definition: true, footnote_inline: false, frontmatter: false, gfm_autolink_literal: false, gfm_footnote_definition: false, gfm_label_start_footnote: false, gfm_strikethrough: false, gfm_table: false, gfm_task_list_item: false, hard_break_escape: true, hard_break_trailing: true, heading_atx: true, heading_setext: true, html_flow: true, html_text: true, label_start_image: true, label_start_link: true, label_end: true, list_item: true, math_flow: false, math_text: false, mdx_esm: false, mdx_expression_flow: false, mdx_expression_text: false, mdx_jsx_flow: false, mdx_jsx_text: false, thematic_break: true }, character_escape_requires_punctuation_context: false, gfm_strikethrough_allowed: Both, gfm_strikethrough_single_tilde: true, gfm_table_normalize_cells: false, heading_atx_max_depth: 6, math_text_single_dollar: true, mdast_html_kind: false, mdast_markers: false, mdast_split_text: false, mdx_allow_unclosed_void_elements: false, mdx_expression_parse: Some(\"[Function]\"), mdx_esm_parse: Some(\"[Function]\"), ordered_list_letters: false, preserve: false, relaxed_underscore: false, text_extensions: [] }",
             "should support `Debug` trait on mdx functions"
         );
     }
@@ -1414,6 +3762,18 @@ mod tests {
             options.gfm_tagfilter,
             "should support safe `gfm` shortcut (1)"
         );
+
+        assert_eq!(
+            format!(
+                "{:?}",
+                CompileOptions {
+                    image_renderer: Some(Arc::new(|_image| None)),
+                    ..Default::default()
+                }
+            ),
+            "CompileOptions { allow_dangerous_html: false, allow_dangerous_protocol: false, allow_html_comments: false, attribute_quote: Double, boolean_attribute_style: Empty, decode_titles: true, default_line_ending: LineFeed, element_overrides: ElementOverrides { delete: None, emphasis: None, inline_code: None, paragraph: None, strong: None }, extension_tags: {}, extra_footnote_definitions: [], frontmatter: Ignore, gfm_footnote_label: None, gfm_footnote_label_tag_name: None, gfm_footnote_label_attributes: None, gfm_footnote_back_label: None, gfm_footnote_clobber_prefix: None, gfm_footnote_id_prefix: None, gfm_footnote_numbering: CallOrder, gfm_task_list_item_checkable: false, gfm_task_list_item_data_offset: false, gfm_tagfilter: false, hard_wrap: false, heading_shift: 0, image_renderer: Some(\"[Function]\"), max_output_size: None, protocol_href: None, protocol_src: None, self_closing: Xhtml, skip_mdx_nodes: false, smart_punctuation: false, strip_control_characters: false, url_base: None }",
+            "should support `Debug` trait on image renderers"
+        );
     }
 
     #[test]
@@ -16,6 +16,13 @@
 //! each sequence.
 //! Otherwise they are turned into data.
 //!
+//! Sequences with different characters can be interleaved, but the
+//! constructs they form cannot cross: the first sequence that can close is
+//! matched with the nearest opening sequence of the same character, and
+//! sequences between them can no longer open.
+//! So, `**a ~~b** c~~` is strong containing `a ~~b`, followed by ` c~~`, as
+//! in `cmark-gfm`.
+//!
 //! ## HTML
 //!
 //! When asterisk/underscore sequences match, and two markers can be “taken”
@@ -39,6 +46,9 @@
 //! Because underscores also frequently occur in natural language inside words,
 //! while asterisks typically never do, `CommonMark` prohibits underscore
 //! sequences from opening or closing when *inside* a word.
+//! With [`relaxed_underscore`][crate::ParseOptions::relaxed_underscore],
+//! underscore sequences additionally need whitespace on the outside, so that
+//! identifiers such as `_name()` do not form attention either.
 //!
 //! Because asterisks can be used to form the most markdown constructs, using
 //! them has the added benefit of making it easier to gloss over markdown: you
@@ -48,6 +58,8 @@
 //! For strikethrough attention, it is recommended to use two markers.
 //! While `github.com` allows single tildes too, it technically prohibits it in
 //! their spec.
+//! Which of the two are allowed can be configured with
+//! `gfm_strikethrough_allowed`.
 //!
 //! ## Tokens
 //!
@@ -76,6 +88,7 @@
 //! [html-strong]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-strong-element
 //! [html-del]: https://html.spec.whatwg.org/multipage/edits.html#the-del-element
 
+use crate::configuration::GfmStrikethroughAllowed;
 use crate::event::{Event, Kind, Name, Point};
 use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
@@ -191,11 +204,17 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                     // * both sequences must have the same size
                     // * more than 2 markers don’t work
                     // * one marker is prohibited by the spec, but supported by GH
+                    // * one or two markers can be turned off
                     if sequence_close.marker == b'~'
                         && (sequence_close.size != sequence_open.size
                             || sequence_close.size > 2
                             || sequence_close.size == 1
-                                && !tokenizer.parse_state.options.gfm_strikethrough_single_tilde)
+                                && (!tokenizer.parse_state.options.gfm_strikethrough_single_tilde
+                                    || tokenizer.parse_state.options.gfm_strikethrough_allowed
+                                        == GfmStrikethroughAllowed::DoubleOnly)
+                            || sequence_close.size == 2
+                                && tokenizer.parse_state.options.gfm_strikethrough_allowed
+                                    == GfmStrikethroughAllowed::SingleOnly)
                     {
                         continue;
                     }
@@ -256,6 +275,11 @@ fn get_sequences(tokenizer: &mut Tokenizer) -> Vec<Sequence> {
                         && tokenizer.parse_state.options.constructs.gfm_strikethrough
                         && matches!(before_char, Some('~')));
 
+                // Optionally, underscores need whitespace on the outside.
+                let relaxed = marker == b'_' && tokenizer.parse_state.options.relaxed_underscore;
+                let open = open && (!relaxed || before == CharacterKind::Whitespace);
+                let close = close && (!relaxed || after == CharacterKind::Whitespace);
+
                 sequences.push(Sequence {
                     index,
                     stack: stack.clone(),
@@ -20,6 +20,10 @@
 //! construct: a [hard break (escape)][hard_break_escape] is a backslash followed
 //! by a line ending (that is part of the construct instead of ending it).
 //!
+//! When `character_escape_requires_punctuation_context` is on, a backslash
+//! preceded by an ASCII alphanumeric is also just a backslash, which keeps
+//! paths such as `C:\Users\name\(x).md` intact.
+//!
 //! ## Recommendation
 //!
 //! If possible, use a character escape.
@@ -52,7 +56,16 @@ use crate::tokenizer::Tokenizer;
 ///      ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.parse_state.options.constructs.character_escape && tokenizer.current == Some(b'\\')
+    if tokenizer.parse_state.options.constructs.character_escape
+        && tokenizer.current == Some(b'\\')
+        && !(tokenizer
+            .parse_state
+            .options
+            .character_escape_requires_punctuation_context
+            && matches!(
+                tokenizer.previous,
+                Some(b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z')
+            ))
     {
         tokenizer.enter(Name::CharacterEscape);
         tokenizer.enter(Name::CharacterEscapeMarker);
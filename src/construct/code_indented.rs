@@ -146,21 +146,36 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
 ///   |     bbb
 /// ```
 pub fn further_start(tokenizer: &mut Tokenizer) -> State {
-    if tokenizer.lazy || tokenizer.pierce {
-        return State::Nok;
-    }
-
     if tokenizer.current == Some(b'\n') {
         tokenizer.enter(Name::LineEnding);
         tokenizer.consume();
         tokenizer.exit(Name::LineEnding);
-        State::Next(StateName::CodeIndentedFurtherStart)
+        State::Next(StateName::CodeIndentedFurtherLineStart)
     } else {
         tokenizer.attempt(State::Ok, State::Next(StateName::CodeIndentedFurtherBegin));
         State::Retry(space_or_tab_min_max(tokenizer, TAB_SIZE, TAB_SIZE))
     }
 }
 
+/// At the start of a line after an eol, trying to parse another indent.
+///
+/// ```markdown
+///   |     aaa
+/// > |     bbb
+///     ^
+/// ```
+pub fn further_line_start(tokenizer: &mut Tokenizer) -> State {
+    // Check laziness and piercing at the start of each line (blank lines
+    // included), as the flags describe the line that is being parsed.
+    // The line that opened a container is not checked, as code can start on
+    // it.
+    if tokenizer.lazy || tokenizer.pierce {
+        State::Nok
+    } else {
+        State::Retry(StateName::CodeIndentedFurtherStart)
+    }
+}
+
 /// At the beginning of a line that is not indented enough.
 ///
 /// ```markdown
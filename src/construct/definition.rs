@@ -318,7 +318,8 @@ pub fn after_whitespace(tokenizer: &mut Tokenizer) -> State {
                         &Position::from_exit_event(&tokenizer.events, tokenizer.tokenize_state.end),
                     )
                     .as_str(),
-                ),
+                )
+                .into_owned(),
             );
 
             tokenizer.tokenize_state.end = 0;
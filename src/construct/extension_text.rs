@@ -0,0 +1,92 @@
+//! Text extensions are custom constructs that occur in the [text][]
+//! content type.
+//!
+//! ## Grammar
+//!
+//! Text extensions form with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! extension_text ::= marker *byte
+//! ```
+//!
+//! Where `marker` is one of the markers of a
+//! [`TextExtension`][crate::TextExtension], and what follows is decided by
+//! its `tokenize` function.
+//!
+//! Extensions are tried, in the order they are given, before the built in
+//! constructs.
+//! What they consume is not parsed further, and they cannot span line
+//! endings.
+//!
+//! ## HTML
+//!
+//! Each `Extension(name)` event is compiled to the tag set for `name` in
+//! [`extension_tags`][crate::CompileOptions::extension_tags], and ignored
+//! when there is no such tag.
+//! Data in extensions is compiled as text.
+//!
+//! ## Tokens
+//!
+//! *   [`Data`][Name::Data]
+//! *   [`Extension`][Name::Extension]
+//!
+//! [text]: crate::construct::text
+//! [Name::Data]: crate::event::Name::Data
+//! [Name::Extension]: crate::event::Name::Extension
+
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::extension::{ExtensionState, ExtensionTokenizer};
+
+/// Start of a text extension.
+///
+/// ```markdown
+/// > | a ==b== c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    tokenizer.tokenize_state.seen = false;
+    tokenizer.tokenize_state.size = 0;
+    tokenizer.tokenize_state.size_b = tokenizer.stack.len();
+    State::Retry(StateName::ExtensionTextInside)
+}
+
+/// In a text extension.
+///
+/// ```markdown
+/// > | a ==b== c
+///       ^^^^^
+/// ```
+pub fn inside(tokenizer: &mut Tokenizer) -> State {
+    let options = tokenizer.parse_state.options;
+    let extension = &options.text_extensions[tokenizer.tokenize_state.extension_index - 1];
+    let mut facade = ExtensionTokenizer::new(tokenizer);
+    let result = (extension.tokenize)(&mut facade);
+    let consumed = facade.consumed();
+
+    if consumed {
+        tokenizer.tokenize_state.seen = true;
+    }
+
+    match result {
+        ExtensionState::Next if consumed => State::Next(StateName::ExtensionTextInside),
+        // Empty extensions would be tried again at the same place, forever.
+        ExtensionState::Ok
+            if tokenizer.tokenize_state.seen
+                && tokenizer.stack.len() == tokenizer.tokenize_state.size_b =>
+        {
+            tokenizer.tokenize_state.extension_index = 0;
+            tokenizer.tokenize_state.seen = false;
+            tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.size_b = 0;
+            State::Ok
+        }
+        _ => {
+            tokenizer.tokenize_state.seen = false;
+            tokenizer.tokenize_state.size = 0;
+            tokenizer.tokenize_state.size_b = 0;
+            State::Nok
+        }
+    }
+}
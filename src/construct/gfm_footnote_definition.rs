@@ -347,7 +347,10 @@ pub fn label_after(tokenizer: &mut Tokenizer) -> State {
             // Note: we don’t care about uniqueness.
             // It’s likely that that doesn’t happen very frequently.
             // It is more likely that it wastes precious time.
-            tokenizer.tokenize_state.gfm_footnote_definitions.push(id);
+            tokenizer
+                .tokenize_state
+                .gfm_footnote_definitions
+                .push(id.into_owned());
 
             tokenizer.enter(Name::DefinitionMarker);
             tokenizer.consume();
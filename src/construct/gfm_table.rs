@@ -132,6 +132,10 @@
 //! > 👉 **Note**: no other character can be escaped like this.
 //! > Escaping pipes in code does not work when not inside a table, either.
 //!
+//! Rows are split into cells before the text in them is parsed, so an
+//! unescaped pipe always splits, also when it looks like it is in code or
+//! math: `| $a|b$ |` is two cells, `$a` and `b$`.
+//!
 //! ## HTML
 //!
 //! GFM tables relate to several HTML elements: `<table>`, `<tbody>`, `<td>`,
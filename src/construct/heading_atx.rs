@@ -9,6 +9,9 @@
 //! heading_atx ::= 1*6'#' [ 1*space_or_tab line [ 1*space_or_tab 1*'#' ] ] *space_or_tab
 //! ```
 //!
+//! The max size of the opening sequence (`6`) can be changed with
+//! [`heading_atx_max_depth`][crate::ParseOptions::heading_atx_max_depth].
+//!
 //! As this construct occurs in flow, like all flow constructs, it must be
 //! followed by an eol (line ending) or eof (end of file).
 //!
@@ -68,7 +71,7 @@ use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
-use crate::util::constant::{HEADING_ATX_OPENING_FENCE_SIZE_MAX, TAB_SIZE};
+use crate::util::constant::TAB_SIZE;
 use alloc::vec;
 
 /// Start of a heading (atx).
@@ -122,7 +125,8 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
 /// ```
 pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
     if tokenizer.current == Some(b'#')
-        && tokenizer.tokenize_state.size < HEADING_ATX_OPENING_FENCE_SIZE_MAX
+        && tokenizer.tokenize_state.size
+            < usize::from(tokenizer.parse_state.options.heading_atx_max_depth)
     {
         tokenizer.tokenize_state.size += 1;
         tokenizer.consume();
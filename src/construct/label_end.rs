@@ -46,8 +46,9 @@
 //! attribute in case of a [label start (link)][label_start_link], and an
 //! `src` attribute in case of a [label start (image)][label_start_image].
 //! The title is formed, optionally, on either `<a>` or `<img>`.
-//! When matched with a [gfm label start (footnote)][gfm_label_start_footnote],
-//! no reference or resource can follow the label end.
+//! When matched with a [gfm label start (footnote)][gfm_label_start_footnote]
+//! or a [label start (footnote inline)][label_start_footnote_inline], no
+//! reference or resource can follow the label end.
 //!
 //! For info on how to encode characters in URLs, see
 //! [`destination`][destination].
@@ -57,6 +58,9 @@
 //!
 //! In case of a matched [gfm label start (footnote)][gfm_label_start_footnote],
 //! a counter is injected.
+//! In case of a matched [label start (footnote inline)][label_start_footnote_inline],
+//! a counter is injected too, and the interpreted content between it and the
+//! label end is used as the content of the footnote.
 //! In case of a matched [label start (link)][label_start_link], the interpreted
 //! content between it and the label end, is placed between the opening and
 //! closing tags.
@@ -128,6 +132,7 @@
 //! ## Tokens
 //!
 //! *   [`Data`][Name::Data]
+//! *   [`FootnoteInline`][Name::FootnoteInline]
 //! *   [`GfmFootnoteCall`][Name::GfmFootnoteCall]
 //! *   [`Image`][Name::Image]
 //! *   [`Label`][Name::Label]
@@ -171,6 +176,7 @@
 //! [label_start_image]: crate::construct::label_start_image
 //! [label_start_link]: crate::construct::label_start_link
 //! [gfm_label_start_footnote]: crate::construct::gfm_label_start_footnote
+//! [label_start_footnote_inline]: crate::construct::label_start_footnote_inline
 //! [definition]: crate::construct::definition
 //! [autolink]: crate::construct::autolink
 //! [sanitize_uri]: crate::util::sanitize_uri::sanitize
@@ -186,12 +192,12 @@ use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::{Label, LabelKind, LabelStart, Tokenizer};
 use crate::util::{
-    constant::RESOURCE_DESTINATION_BALANCE_MAX,
+    constant::{LINK_REFERENCE_SIZE_MAX, RESOURCE_DESTINATION_BALANCE_MAX},
     normalize_identifier::normalize_identifier,
     skip,
     slice::{Position, Slice},
 };
-use alloc::{string::String, vec};
+use alloc::{borrow::Cow, string::String, vec};
 
 /// Start of label end.
 ///
@@ -253,19 +259,34 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
     let start_index = tokenizer.tokenize_state.label_starts.len() - 1;
     let start = &tokenizer.tokenize_state.label_starts[start_index];
 
+    // Inline footnotes are done at `]`: no resource or reference can follow.
+    if start.kind == LabelKind::FootnoteInline {
+        return State::Retry(StateName::LabelEndOk);
+    }
+
     let indices = (
         tokenizer.events[start.start.1].point.index,
         tokenizer.events[tokenizer.tokenize_state.end].point.index,
     );
 
+    let bytes = &tokenizer.parse_state.bytes[indices.0..indices.1];
+    let fits = label_fits(bytes, identifier_size_max(tokenizer));
     // We don’t care about virtual spaces, so `indices` and `as_str` are fine.
-    let mut id = normalize_identifier(
-        Slice::from_indices(tokenizer.parse_state.bytes, indices.0, indices.1).as_str(),
-    );
+    let mut id = if fits {
+        normalize_identifier(core::str::from_utf8(bytes).unwrap())
+    } else {
+        Cow::Borrowed("")
+    };
 
     // See if this matches a footnote definition.
     if start.kind == LabelKind::GfmFootnote {
-        if tokenizer.parse_state.gfm_footnote_definitions.contains(&id) {
+        if fits
+            && tokenizer
+                .parse_state
+                .gfm_footnote_definitions
+                .iter()
+                .any(|d| *d == id)
+        {
             return State::Retry(StateName::LabelEndOk);
         }
 
@@ -274,10 +295,10 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
         let mut new_id = String::new();
         new_id.push('^');
         new_id.push_str(&id);
-        id = new_id;
+        id = new_id.into();
     }
 
-    let defined = tokenizer.parse_state.definitions.contains(&id);
+    let defined = fits && tokenizer.parse_state.definitions.iter().any(|d| *d == id);
 
     match tokenizer.current {
         // Resource (`[asd](fgh)`)?
@@ -351,12 +372,16 @@ pub fn ok(tokenizer: &mut Tokenizer) -> State {
     // longer viable for use (as they would otherwise contain a link).
     // These link starts are still looking for balanced closing brackets, so
     // we can’t remove them, but we can mark them.
+    // Inline footnotes can contain links, but not other inline footnotes.
     if label_start.kind != LabelKind::Image {
         let mut index = 0;
         while index < tokenizer.tokenize_state.label_starts.len() {
-            let label_start = &mut tokenizer.tokenize_state.label_starts[index];
-            if label_start.kind != LabelKind::Image {
-                label_start.inactive = true;
+            let start = &mut tokenizer.tokenize_state.label_starts[index];
+            if start.kind != LabelKind::Image
+                && (start.kind != LabelKind::FootnoteInline
+                    || label_start.kind == LabelKind::FootnoteInline)
+            {
+                start.inactive = true;
             }
             index += 1;
         }
@@ -590,31 +615,78 @@ pub fn reference_full_after(tokenizer: &mut Tokenizer) -> State {
     tokenizer.tokenize_state.token_2 = Name::Data;
     tokenizer.tokenize_state.token_3 = Name::Data;
 
-    if tokenizer
-        .parse_state
-        .definitions
-        // We don’t care about virtual spaces, so `as_str` is fine.
-        .contains(&normalize_identifier(
-            Slice::from_position(
-                tokenizer.parse_state.bytes,
-                &Position::from_exit_event(
+    // We don’t care about virtual spaces, so `as_str` is fine.
+    let id = normalize_identifier(
+        Slice::from_position(
+            tokenizer.parse_state.bytes,
+            &Position::from_exit_event(
+                &tokenizer.events,
+                skip::to_back(
                     &tokenizer.events,
-                    skip::to_back(
-                        &tokenizer.events,
-                        tokenizer.events.len() - 1,
-                        &[Name::ReferenceString],
-                    ),
+                    tokenizer.events.len() - 1,
+                    &[Name::ReferenceString],
                 ),
-            )
-            .as_str(),
-        ))
-    {
+            ),
+        )
+        .as_str(),
+    );
+
+    if tokenizer.parse_state.definitions.iter().any(|d| *d == id) {
         State::Ok
     } else {
         State::Nok
     }
 }
 
+/// Get the size of the longest identifier of a definition or GFM footnote
+/// definition (including a `^` for the latter).
+fn identifier_size_max(tokenizer: &Tokenizer) -> usize {
+    let definitions = tokenizer.parse_state.definitions.iter().map(String::len);
+    let footnotes = (tokenizer.parse_state.gfm_footnote_definitions.iter()).map(|d| d.len() + 1);
+    definitions.chain(footnotes).max().unwrap_or(0)
+}
+
+/// Check whether the label in `bytes` is short enough that its identifier
+/// can be at most `max` bytes.
+///
+/// Normalizing drops whitespace, but case folding never results in fewer
+/// characters, so labels with more other characters than that cannot match.
+/// Checking this first prevents normalizing long labels again and again,
+/// such as in `[[[[a]]]]` with thousands of brackets, which is quadratic.
+///
+/// The label itself must also be at most [`LINK_REFERENCE_SIZE_MAX`][], as
+/// counted by [`partial_label`][crate::construct::partial_label]: line
+/// endings, and whitespace after them, are not counted.
+fn label_fits(bytes: &[u8], max: usize) -> bool {
+    let mut size = 0;
+    let mut label_size = 0;
+    let mut after_eol = false;
+
+    for byte in bytes {
+        if matches!(byte, b'\n' | b'\r') {
+            after_eol = true;
+        } else if !(after_eol && matches!(byte, b'\t' | b' ')) {
+            after_eol = false;
+            label_size += 1;
+
+            if label_size > LINK_REFERENCE_SIZE_MAX {
+                return false;
+            }
+        }
+
+        // Count characters, so skip whitespace and UTF-8 continuation bytes.
+        if !matches!(byte, b'\t' | b'\n' | b'\r' | b' ') && byte & 0b1100_0000 != 0b1000_0000 {
+            size += 1;
+
+            if size > max {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
 /// In reference (full) that was missing.
 ///
 /// ```markdown
@@ -693,6 +765,8 @@ fn inject_labels(tokenizer: &mut Tokenizer, labels: &[Label]) {
         let label = &labels[index];
         let group_name = if label.kind == LabelKind::GfmFootnote {
             Name::GfmFootnoteCall
+        } else if label.kind == LabelKind::FootnoteInline {
+            Name::FootnoteInline
         } else if label.kind == LabelKind::Image {
             Name::Image
         } else {
@@ -0,0 +1,90 @@
+//! Label start (footnote inline) occurs in the [text][] content type.
+//!
+//! ## Grammar
+//!
+//! Label start (footnote inline) forms with the following BNF
+//! (<small>see [construct][crate::construct] for character groups</small>):
+//!
+//! ```bnf
+//! label_start_footnote_inline ::= '^' '['
+//! ```
+//!
+//! ## HTML
+//!
+//! Label start (footnote inline) does not, on its own, relate to anything in
+//! HTML.
+//! When matched with a [label end][label_end], they together relate to `<sup>`
+//! and `<a>` elements in HTML, like a
+//! [gfm label start (footnote)][gfm_label_start_footnote].
+//! The text between the brackets is used as the content of the footnote,
+//! which is generated in the footnote section along with the footnotes of
+//! GFM footnote definitions.
+//! See [*§ 4.5.19 The `sub` and `sup` elements*][html_sup] and
+//! [*§ 4.5.1 The `a` element*][html_a] in the HTML spec for more info.
+//! Without an end, the characters (`^[`) are output.
+//!
+//! ## Tokens
+//!
+//! *   [`LabelFootnoteInline`][Name::LabelFootnoteInline]
+//! *   [`LabelFootnoteInlineMarker`][Name::LabelFootnoteInlineMarker]
+//! *   [`LabelMarker`][Name::LabelMarker]
+//!
+//! ## References
+//!
+//! *   [*Inline notes* in `pandoc`](https://pandoc.org/MANUAL.html#extension-inline_notes)
+//!
+//! > 👉 **Note**: Inline footnotes are not specified in `CommonMark` or GFM.
+//!
+//! [text]: crate::construct::text
+//! [label_end]: crate::construct::label_end
+//! [gfm_label_start_footnote]: crate::construct::gfm_label_start_footnote
+//! [html_a]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-a-element
+//! [html_sup]: https://html.spec.whatwg.org/multipage/text-level-semantics.html#the-sub-and-sup-elements
+
+use crate::event::Name;
+use crate::resolve::Name as ResolveName;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::{LabelKind, LabelStart, Tokenizer};
+
+/// Start of label (footnote inline) start.
+///
+/// ```markdown
+/// > | a ^[b] c
+///       ^
+/// ```
+pub fn start(tokenizer: &mut Tokenizer) -> State {
+    if tokenizer.parse_state.options.constructs.footnote_inline && tokenizer.current == Some(b'^') {
+        tokenizer.enter(Name::LabelFootnoteInline);
+        tokenizer.enter(Name::LabelFootnoteInlineMarker);
+        tokenizer.consume();
+        tokenizer.exit(Name::LabelFootnoteInlineMarker);
+        State::Next(StateName::LabelStartFootnoteInlineOpen)
+    } else {
+        State::Nok
+    }
+}
+
+/// After `^`, at `[`.
+///
+/// ```markdown
+/// > | a ^[b] c
+///        ^
+/// ```
+pub fn open(tokenizer: &mut Tokenizer) -> State {
+    match tokenizer.current {
+        Some(b'[') => {
+            tokenizer.enter(Name::LabelMarker);
+            tokenizer.consume();
+            tokenizer.exit(Name::LabelMarker);
+            tokenizer.exit(Name::LabelFootnoteInline);
+            tokenizer.tokenize_state.label_starts.push(LabelStart {
+                kind: LabelKind::FootnoteInline,
+                start: (tokenizer.events.len() - 6, tokenizer.events.len() - 1),
+                inactive: false,
+            });
+            tokenizer.register_resolver_before(ResolveName::Label);
+            State::Ok
+        }
+        _ => State::Nok,
+    }
+}
@@ -9,7 +9,13 @@
 //! ; Restriction: if there is no space after the marker, the start must be followed by an `eol`.
 //! ; Restriction: if the first line after the marker is not blank and starts with `5(space_or_tab)`,
 //! ; only the first `space_or_tab` is part of the start.
-//! list_item_start ::= '*' | '+' | '-' | 1*9(ascii_decimal) ('.' | ')') [1*4 space_or_tab]
+//! list_item_start ::= '*' | '+' | '-' | value ('.' | ')') [1*4 space_or_tab]
+//!
+//! ; Restriction: letters are only allowed if `ordered_list_letters` is on.
+//! ; Restriction: several letters must form a roman numeral (such as `iv`).
+//! ; Restriction: a single uppercase letter followed by `.` must be followed by
+//! ; at least two spaces, or a tab.
+//! value ::= 1*9(ascii_decimal) | ascii_alpha | 2*9(ascii_alpha)
 //!
 //! ; Restriction: blank line allowed, except when this is the first continuation after a blank start.
 //! ; Restriction: if not blank, the line must be indented, exactly `n` times.
@@ -22,6 +28,19 @@
 //! See [*§ Phase 1: block structure* in `CommonMark`][commonmark_block] for
 //! more on parsing details.
 //!
+//! Values with letters are not in `CommonMark`.
+//! They are like in Pandoc: a single letter (`a.`, `B)`), or a roman numeral
+//! (`iv.`, `IV)`).
+//! A list starting with `i` or `I` uses roman numerals, other single letters
+//! start a list of letters, unless the second item is a roman numeral (`v.`
+//! followed by `vi.`).
+//! Adjacent items that use a different kind of value, or a different case,
+//! form separate lists, like items with different markers.
+//! The two spaces after a single uppercase letter followed by `.` prevent
+//! initials, as in `B. Russell wrote…`, from being seen as a list item.
+//! A single lowercase letter followed by `.` and one space is only a list
+//! item next to another item, which prevents abbreviations, as in `i. e.`.
+//!
 //! As list item is a container, it takes several bytes from the start of the
 //! line, while the rest of the line includes more containers or flow.
 //!
@@ -59,14 +78,15 @@
 //! [commonmark_block]: https://spec.commonmark.org/0.31/#phase-1-block-structure
 
 use crate::construct::partial_space_or_tab::space_or_tab_min_max;
-use crate::event::{Kind, Name};
+use crate::event::{Event, Kind, Name};
+use crate::mdast::OrderedListKind;
 use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
-use crate::tokenizer::Tokenizer;
+use crate::tokenizer::{Container, Tokenizer};
 use crate::util::{
     constant::{LIST_ITEM_VALUE_SIZE_MAX, TAB_SIZE},
-    skip,
+    list_value, skip,
     slice::{Position, Slice},
 };
 use alloc::{vec, vec::Vec};
@@ -114,9 +134,13 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
     } else if tokenizer.current == Some(b'+') {
         State::Retry(StateName::ListItemBeforeUnordered)
     }
-    // Ordered.
+    // Ordered, with digits or, if enabled, letters.
     else if tokenizer.current == Some(b'1')
         || (matches!(tokenizer.current, Some(b'0'..=b'9')) && !tokenizer.interrupt)
+        || (tokenizer.parse_state.options.ordered_list_letters
+            && (matches!(tokenizer.current, Some(b'a' | b'A' | b'i' | b'I'))
+                || (tokenizer.current.map_or(false, |b| b.is_ascii_alphabetic())
+                    && !tokenizer.interrupt)))
     {
         State::Retry(StateName::ListItemBeforeOrdered)
     } else {
@@ -156,13 +180,20 @@ pub fn before_ordered(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn value(tokenizer: &mut Tokenizer) -> State {
+    let start = tokenizer.events[tokenizer.events.len() - 1].point.index;
+    let letters = tokenizer.parse_state.bytes[start].is_ascii_alphabetic();
+
     if matches!(tokenizer.current, Some(b'.' | b')'))
         && (!tokenizer.interrupt || tokenizer.tokenize_state.size < 2)
+        && (!letters || value_letters_valid(tokenizer, start))
     {
         tokenizer.exit(Name::ListItemValue);
         State::Retry(StateName::ListItemMarker)
-    } else if matches!(tokenizer.current, Some(b'0'..=b'9'))
-        && tokenizer.tokenize_state.size + 1 < LIST_ITEM_VALUE_SIZE_MAX
+    } else if (if letters {
+        matches!(tokenizer.current, Some(b'a'..=b'z' | b'A'..=b'Z'))
+    } else {
+        matches!(tokenizer.current, Some(b'0'..=b'9'))
+    }) && tokenizer.tokenize_state.size + 1 < LIST_ITEM_VALUE_SIZE_MAX
     {
         tokenizer.tokenize_state.size += 1;
         tokenizer.consume();
@@ -173,6 +204,120 @@ pub fn value(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
+/// Check whether a value of letters, before `.` or `)`, is valid.
+///
+/// Several letters must form a roman numeral.
+/// A single letter before `.` must be followed by two spaces or a tab.
+/// For lowercase letters, one space is also fine when the item follows
+/// another list item, or when the next line that is not blank starts with
+/// another item of letters, so that abbreviations such as `i. e.` are not
+/// seen as list items.
+fn value_letters_valid(tokenizer: &Tokenizer, start: usize) -> bool {
+    let bytes = tokenizer.parse_state.bytes;
+    let index = tokenizer.point.index;
+    let value = &bytes[start..index];
+
+    if value.len() > 1 {
+        // Guaranteed to be ASCII.
+        list_value::roman(core::str::from_utf8(value).unwrap()).is_some()
+    } else if bytes[index] == b'.' {
+        matches!(bytes.get(index + 1), Some(b'\t'))
+            || (bytes.get(index + 1) == Some(&b' ')
+                && matches!(bytes.get(index + 2), Some(b'\t' | b' ')))
+            || (value[0].is_ascii_lowercase()
+                && (after_list_item(tokenizer) || before_list_item(bytes, start, index)))
+    } else {
+        true
+    }
+}
+
+/// Check whether a new list item would follow an existing list item, at the
+/// same level.
+fn after_list_item(tokenizer: &Tokenizer) -> bool {
+    let stack = &tokenizer.tokenize_state.document_container_stack;
+    // The container that was at this level, if any, is moved to the end.
+    stack.len() > tokenizer.tokenize_state.document_continued + 1
+        && stack
+            .last()
+            .map_or(false, |container| container.kind == Container::ListItem)
+}
+
+/// Check whether the next line that is not blank or more indented than the
+/// value at `start`, after `index`, starts with a value of letters followed
+/// by `.` or `)`.
+///
+/// Lines must be in as many block quotes as the line of the value.
+fn before_list_item(bytes: &[u8], start: usize, mut index: usize) -> bool {
+    let mut line_start = start;
+
+    while line_start > 0 && !matches!(bytes[line_start - 1], b'\n' | b'\r') {
+        line_start -= 1;
+    }
+
+    let prefix = line_prefix(&bytes[line_start..start]);
+
+    loop {
+        // Move to the next line.
+        while index < bytes.len() && !matches!(bytes[index], b'\n' | b'\r') {
+            index += 1;
+        }
+
+        if index == bytes.len() {
+            return false;
+        }
+
+        index += 1;
+        let line_start = index;
+
+        while index < bytes.len() && matches!(bytes[index], b'\t' | b' ' | b'>') {
+            index += 1;
+        }
+
+        // Blank.
+        if index == bytes.len() || matches!(bytes[index], b'\n' | b'\r') {
+            continue;
+        }
+
+        let (quotes, indent) = line_prefix(&bytes[line_start..index]);
+
+        if quotes != prefix.0 {
+            return false;
+        }
+
+        // Not in the item.
+        if indent <= prefix.1 {
+            let value_start = index;
+
+            while index < bytes.len()
+                && bytes[index].is_ascii_alphabetic()
+                && index - value_start < LIST_ITEM_VALUE_SIZE_MAX
+            {
+                index += 1;
+            }
+
+            return index > value_start && matches!(bytes.get(index), Some(b'.' | b')'));
+        }
+    }
+}
+
+/// Count the block quote markers and the whitespace after the last one in
+/// the prefix of a line.
+fn line_prefix(bytes: &[u8]) -> (usize, usize) {
+    let mut quotes = 0;
+    let mut indent = 0;
+
+    for byte in bytes {
+        if *byte == b'>' {
+            quotes += 1;
+            indent = 0;
+        } else {
+            indent += 1;
+        }
+    }
+
+    (quotes, indent)
+}
+
 /// At list item marker.
 ///
 /// ```markdown
@@ -318,11 +463,11 @@ pub fn after(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn cont_start(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.check(
-        State::Next(StateName::ListItemContBlank),
-        State::Next(StateName::ListItemContFilled),
-    );
-    State::Retry(StateName::BlankLineStart)
+    if blank(tokenizer) {
+        State::Retry(StateName::ListItemContBlank)
+    } else {
+        State::Retry(StateName::ListItemContFilled)
+    }
 }
 
 /// Start of blank list item continuation.
@@ -370,10 +515,49 @@ pub fn cont_filled(tokenizer: &mut Tokenizer) -> State {
     }
 }
 
-/// Find adjacent list items with the same marker.
+/// Check whether the rest of the line is blank, like
+/// [`blank_line`][crate::construct::blank_line] does.
+///
+/// The whitespace that was found is kept, as deeply nested list items would
+/// otherwise look at the same whitespace for each item.
+fn blank(tokenizer: &mut Tokenizer) -> bool {
+    let bytes = tokenizer.parse_state.bytes;
+    let index = tokenizer.point.index;
+
+    match tokenizer.tokenize_state.document_whitespace {
+        Some((start, end, blank)) if start <= index && index <= end => blank,
+        _ => {
+            let mut end = index;
+
+            while end < bytes.len() && matches!(bytes[end], b'\t' | b' ') {
+                end += 1;
+            }
+
+            let blank = end == bytes.len() || matches!(bytes[end], b'\n' | b'\r');
+            tokenizer.tokenize_state.document_whitespace = Some((index, end, blank));
+            blank
+        }
+    }
+}
+
+/// List: marker, balance, index of its start, index of its end, kind,
+/// and the value of the first item while it is the only item.
+type List<'a> = (
+    u8,
+    usize,
+    usize,
+    usize,
+    Option<OrderedListKind>,
+    Option<&'a str>,
+);
+
+/// Find adjacent list items with the same marker, and, when ordered, the same
+/// kind of value.
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
-    let mut lists_wip: Vec<(u8, usize, usize, usize)> = vec![];
-    let mut lists: Vec<(u8, usize, usize, usize)> = vec![];
+    let mut lists_wip: Vec<List> = vec![];
+    let mut lists: Vec<List> = vec![];
+    let ends = list_item_ends(&tokenizer.events);
+    let mut item = 0;
     let mut index = 0;
     let mut balance = 0;
 
@@ -383,11 +567,30 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
 
         if event.name == Name::ListItem {
             if event.kind == Kind::Enter {
-                let end = skip::opt(&tokenizer.events, index, &[Name::ListItem]) - 1;
-                let marker = skip::to(&tokenizer.events, index, &[Name::ListItemMarker]);
+                let end = ends[item];
+                item += 1;
+                let marker_index = skip::to(&tokenizer.events, index, &[Name::ListItemMarker]);
                 // Guaranteed to be a valid ASCII byte.
-                let marker = tokenizer.parse_state.bytes[tokenizer.events[marker].point.index];
-                let current = (marker, balance, index, end);
+                let marker =
+                    tokenizer.parse_state.bytes[tokenizer.events[marker_index].point.index];
+                // Value is empty when unordered.
+                let value = if matches!(marker, b'.' | b')') {
+                    let bytes = tokenizer.parse_state.bytes;
+                    let start = tokenizer.events[marker_index - 2].point.index;
+                    let end = tokenizer.events[marker_index].point.index;
+                    // Guaranteed to be ASCII.
+                    core::str::from_utf8(&bytes[start..end]).unwrap()
+                } else {
+                    ""
+                };
+                let current = (
+                    marker,
+                    balance,
+                    index,
+                    end,
+                    list_value::kind(value),
+                    Some(value),
+                );
 
                 let mut list_index = lists_wip.len();
                 let mut matched = false;
@@ -406,9 +609,22 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
                         ],
                     );
 
-                    if previous.0 == current.0 && previous.1 == current.1 && before == current.2 {
+                    // A single letter can turn out to be a roman numeral
+                    // when the second item is one (`v.` and `vi.`).
+                    let kind = match previous.5 {
+                        Some(first) => list_value::kind_with_next(first, Some(value)),
+                        None => previous.4,
+                    };
+
+                    if previous.0 == current.0
+                        && previous.1 == current.1
+                        && before == current.2
+                        && list_value::compatible(kind, value)
+                    {
                         let previous_mut = &mut lists_wip[list_index];
                         previous_mut.3 = current.3;
+                        previous_mut.4 = kind;
+                        previous_mut.5 = None;
                         lists.append(&mut lists_wip.split_off(list_index + 1));
                         matched = true;
                         break;
@@ -472,3 +688,30 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
+
+/// Get where each list item exits, in the order they enter.
+///
+/// Done upfront, as looking for the exit of each item when entering it would
+/// take quadratic time for deeply nested lists.
+fn list_item_ends(events: &[Event]) -> Vec<usize> {
+    let mut ends = vec![];
+    let mut stack = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.name == Name::ListItem {
+            if event.kind == Kind::Enter {
+                stack.push(ends.len());
+                ends.push(0);
+            } else {
+                ends[stack.pop().unwrap()] = index;
+            }
+        }
+
+        index += 1;
+    }
+
+    ends
+}
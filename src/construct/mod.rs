@@ -62,12 +62,14 @@
 //!
 //! The following constructs are extensions found in markdown:
 //!
+//! *   [extension (text)][extension_text]
 //! *   [frontmatter][]
 //! *   [gfm autolink literal][gfm_autolink_literal]
 //! *   [gfm footnote definition][gfm_footnote_definition]
 //! *   [gfm label start footnote][gfm_label_start_footnote]
 //! *   [gfm table][gfm_table]
 //! *   [gfm task list item check][gfm_task_list_item_check]
+//! *   [label start (footnote inline)][label_start_footnote_inline]
 //! *   [mdx esm][mdx_esm]
 //! *   [mdx expression (flow)][mdx_expression_flow]
 //! *   [mdx expression (text)][mdx_expression_text]
@@ -158,6 +160,7 @@ pub mod code_indented;
 pub mod content;
 pub mod definition;
 pub mod document;
+pub mod extension_text;
 pub mod flow;
 pub mod frontmatter;
 pub mod gfm_autolink_literal;
@@ -171,6 +174,7 @@ pub mod heading_setext;
 pub mod html_flow;
 pub mod html_text;
 pub mod label_end;
+pub mod label_start_footnote_inline;
 pub mod label_start_image;
 pub mod label_start_link;
 pub mod list_item;
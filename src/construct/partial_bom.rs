@@ -13,6 +13,13 @@
 //!
 //! Don’t use BOMs.
 //!
+//! ## Positional info
+//!
+//! The BOM does not end up in the syntax tree, but it is not removed from
+//! the document either: it counts as one character in the column, and as its
+//! three bytes in the offset, of everything after it.
+//! The root starts at `1:1 (0)`, the first child at `1:2 (3)`.
+//!
 //! ## Tokens
 //!
 //! *   [`ByteOrderMark`][Name::ByteOrderMark]
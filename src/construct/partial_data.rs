@@ -6,7 +6,7 @@
 //! [string]: crate::construct::string
 //! [text]: crate::construct::text
 
-use crate::event::{Kind, Name};
+use crate::event::{Event, Kind, Name};
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
@@ -74,6 +74,7 @@ pub fn inside(tokenizer: &mut Tokenizer) -> State {
 
 /// Merge adjacent data events.
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
+    let keep_starts = tokenizer.parse_state.options.mdast_split_text;
     let mut index = 0;
 
     // Loop through events and merge adjacent data events.
@@ -94,6 +95,15 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
             }
 
             if exit_index > index {
+                if keep_starts {
+                    let mut start_index = index + 1;
+                    while start_index < exit_index {
+                        let point = tokenizer.events[start_index].point.clone();
+                        tokenizer.tokenize_state.data_starts.push(point);
+                        start_index += 2;
+                    }
+                }
+
                 tokenizer.map.add(index, exit_index - index, vec![]);
                 // Change positional info.
                 tokenizer.events[index].point = tokenizer.events[exit_index].point.clone();
@@ -108,3 +118,50 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     tokenizer.map.consume(&mut tokenizer.events);
     None
 }
+
+/// Split merged data events again, where runs of data started.
+///
+/// Only does something when `mdast_split_text` is on, and must run after
+/// other resolvers, as they expect adjacent data to be merged.
+pub fn split(tokenizer: &mut Tokenizer) {
+    let starts = core::mem::take(&mut tokenizer.tokenize_state.data_starts);
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < tokenizer.events.len() && start < starts.len() {
+        let event = &tokenizer.events[index];
+
+        if event.kind == Kind::Exit && event.name == Name::Data {
+            let from = tokenizer.events[index - 1].point.index;
+            let mut add = vec![];
+
+            while start < starts.len() && starts[start].index < event.point.index {
+                // Starts can be gone, such as when in trimmed whitespace.
+                if starts[start].index > from {
+                    add.push(Event {
+                        kind: Kind::Exit,
+                        name: Name::Data,
+                        point: starts[start].clone(),
+                        link: None,
+                    });
+                    add.push(Event {
+                        kind: Kind::Enter,
+                        name: Name::Data,
+                        point: starts[start].clone(),
+                        link: None,
+                    });
+                }
+
+                start += 1;
+            }
+
+            if !add.is_empty() {
+                tokenizer.map.add(index, 0, add);
+            }
+        }
+
+        index += 1;
+    }
+
+    tokenizer.map.consume(&mut tokenizer.events);
+}
@@ -90,7 +90,7 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         None => {
             let problem = tokenizer.tokenize_state.mdx_last_parse_error.take()
-                        .unwrap_or_else(|| ("Unexpected end of file in expression, expected a corresponding closing brace for `{`".into(), "markdown-rs".into(), "unexpected-eof".into()));
+                        .unwrap_or_else(|| ("Unexpected end of file in expression, expected a corresponding closing brace for `{`".into(), message::rules::SOURCE.into(), message::rules::UNEXPECTED_EOF_IN_EXPRESSION.into()));
 
             State::Error(message::Message {
                 place: Some(Box::new(message::Place::Point(tokenizer.point.to_unist()))),
@@ -174,8 +174,8 @@ pub fn eol_after(tokenizer: &mut Tokenizer) -> State {
             message::Message {
                 place: Some(Box::new(message::Place::Point(tokenizer.point.to_unist()))),
                 reason: "Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc".into(),
-                source: Box::new("markdown-rs".into()),
-                rule_id: Box::new("unexpected-lazy".into()),
+                source: Box::new(message::rules::SOURCE.into()),
+                rule_id: Box::new(message::rules::UNEXPECTED_LAZY_IN_EXPRESSION.into()),
             }
         )
     } else if matches!(tokenizer.current, Some(b'\t' | b' ')) {
@@ -1097,8 +1097,8 @@ pub fn es_whitespace_eol_after(tokenizer: &mut Tokenizer) -> State {
             message::Message {
                 place: Some(Box::new(message::Place::Point(tokenizer.point.to_unist()))),
                 reason: "Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc".into(),
-                rule_id: Box::new("unexpected-lazy".into()),
-                source: Box::new("markdown-rs".into()),
+                rule_id: Box::new(message::rules::UNEXPECTED_LAZY_IN_JSX.into()),
+                source: Box::new(message::rules::SOURCE.into()),
             }
         )
     } else {
@@ -1132,13 +1132,14 @@ fn crash(tokenizer: &Tokenizer, at: &str, expect: &str) -> State {
             expect
         ),
         rule_id: Box::new(format!(
-            "unexpected-{}",
+            "unexpected-{}-{}",
             if tokenizer.current.is_none() {
                 "eof"
             } else {
                 "character"
-            }
+            },
+            at.replace(' ', "-")
         )),
-        source: Box::new("markdown-rs".into()),
+        source: Box::new(message::rules::SOURCE.into()),
     })
 }
@@ -144,7 +144,7 @@ use crate::state::{Name as StateName, State};
 use crate::tokenizer::Tokenizer;
 use crate::util::{
     constant::{CODE_FENCED_SEQUENCE_SIZE_MIN, MATH_FLOW_SEQUENCE_SIZE_MIN, TAB_SIZE},
-    slice::{Position, Slice},
+    slice::Position,
 };
 
 /// Start of raw.
@@ -197,11 +197,10 @@ pub fn before_sequence_open(tokenizer: &mut Tokenizer) -> State {
 
     if let Some(event) = tail {
         if event.name == Name::SpaceOrTab {
-            prefix = Slice::from_position(
-                tokenizer.parse_state.bytes,
-                &Position::from_exit_event(&tokenizer.events, tokenizer.events.len() - 1),
-            )
-            .len();
+            // Count columns, not bytes: a tab after a container marker (such
+            // as `>\t`) can be partially consumed by that container.
+            let position = Position::from_exit_event(&tokenizer.events, tokenizer.events.len() - 1);
+            prefix = position.end.column - position.start.column;
         }
     }
 
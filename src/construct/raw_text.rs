@@ -168,10 +168,12 @@ pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
         tokenizer.consume();
         State::Next(StateName::RawTextSequenceOpen)
     }
-    // Not enough markers in the sequence.
-    else if tokenizer.tokenize_state.marker == b'$'
+    // Not enough markers in the sequence, or no closing sequence of this size
+    // after it: don’t look for one again, which would take quadratic time.
+    else if (tokenizer.tokenize_state.marker == b'$'
         && tokenizer.tokenize_state.size == 1
-        && !tokenizer.parse_state.options.math_text_single_dollar
+        && !tokenizer.parse_state.options.math_text_single_dollar)
+        || !closable(tokenizer)
     {
         tokenizer.tokenize_state.marker = 0;
         tokenizer.tokenize_state.size = 0;
@@ -194,6 +196,8 @@ pub fn sequence_open(tokenizer: &mut Tokenizer) -> State {
 pub fn between(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         None => {
+            let index = end_index(tokenizer.tokenize_state.marker);
+            tokenizer.tokenize_state.raw_text_ends_complete[index] = true;
             tokenizer.tokenize_state.marker = 0;
             tokenizer.tokenize_state.size = 0;
             tokenizer.tokenize_state.token_1 = Name::Data;
@@ -250,6 +254,16 @@ pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
         State::Next(StateName::RawTextSequenceClose)
     } else {
         tokenizer.exit(tokenizer.tokenize_state.token_2.clone());
+        let ends =
+            &mut tokenizer.tokenize_state.raw_text_ends[end_index(tokenizer.tokenize_state.marker)];
+        let size = tokenizer.tokenize_state.size_b;
+        if ends.len() <= size {
+            ends.resize(size + 1, 0);
+        }
+        // Sequences can be seen again when retrying from an earlier opening
+        // sequence, so keep the last one.
+        ends[size] = ends[size].max(tokenizer.point.index);
+
         if tokenizer.tokenize_state.size == tokenizer.tokenize_state.size_b {
             tokenizer.exit(tokenizer.tokenize_state.token_1.clone());
             tokenizer.tokenize_state.marker = 0;
@@ -269,3 +283,21 @@ pub fn sequence_close(tokenizer: &mut Tokenizer) -> State {
         }
     }
 }
+
+/// Check whether a closing sequence as big as the opening sequence can occur
+/// after it.
+///
+/// That is not known before looking for one has reached the end once.
+fn closable(tokenizer: &Tokenizer) -> bool {
+    let index = end_index(tokenizer.tokenize_state.marker);
+
+    !tokenizer.tokenize_state.raw_text_ends_complete[index]
+        || tokenizer.tokenize_state.raw_text_ends[index]
+            .get(tokenizer.tokenize_state.size)
+            .map_or(false, |end| *end > tokenizer.point.index)
+}
+
+/// Get the index into `raw_text_ends` for `marker`.
+fn end_index(marker: u8) -> usize {
+    usize::from(marker != b'`')
+}
@@ -12,6 +12,7 @@
 //!
 //! [text]: crate::construct::text
 
+use crate::construct::partial_data::split as split_data;
 use crate::construct::partial_whitespace::resolve_whitespace;
 use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
@@ -77,5 +78,6 @@ pub fn before_data(tokenizer: &mut Tokenizer) -> State {
 /// Resolve whitespace in string.
 pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     resolve_whitespace(tokenizer, false, false);
+    split_data(tokenizer);
     None
 }
@@ -10,11 +10,13 @@
 //! *   [Autolink][crate::construct::autolink]
 //! *   [Character escape][crate::construct::character_escape]
 //! *   [Character reference][crate::construct::character_reference]
+//! *   [Extension (text)][crate::construct::extension_text]
 //! *   [Raw (text)][crate::construct::raw_text] (code (text), math (text))
 //! *   [GFM: Label start (footnote)][crate::construct::gfm_label_start_footnote]
 //! *   [GFM: Task list item check][crate::construct::gfm_task_list_item_check]
 //! *   [Hard break (escape)][crate::construct::hard_break_escape]
 //! *   [HTML (text)][crate::construct::html_text]
+//! *   [Label start (footnote inline)][crate::construct::label_start_footnote_inline]
 //! *   [Label start (image)][crate::construct::label_start_image]
 //! *   [Label start (link)][crate::construct::label_start_link]
 //! *   [Label end][crate::construct::label_end]
@@ -25,14 +27,17 @@
 //! > [whitespace][crate::construct::partial_whitespace].
 
 use crate::construct::gfm_autolink_literal::resolve as resolve_gfm_autolink_literal;
+use crate::construct::partial_data::split as split_data;
 use crate::construct::partial_whitespace::resolve_whitespace;
 use crate::resolve::Name as ResolveName;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::Subresult;
 use crate::tokenizer::Tokenizer;
+use crate::ParseOptions;
+use alloc::vec::Vec;
 
 /// Characters that can start something in text.
-const MARKERS: [u8; 16] = [
+const MARKERS: [u8; 17] = [
     b'!',  // `label_start_image`
     b'$',  // `raw_text` (math (text))
     b'&',  // `character_reference`
@@ -43,6 +48,7 @@ const MARKERS: [u8; 16] = [
     b'[',  // `label_start_link`
     b'\\', // `character_escape`, `hard_break_escape`
     b']',  // `label_end`, `gfm_label_start_footnote`
+    b'^',  // `label_start_footnote_inline`
     b'_',  // `attention` (emphasis, strong)
     b'`',  // `raw_text` (code (text))
     b'h',  // `gfm_autolink_literal` (`protocol` kind)
@@ -51,6 +57,21 @@ const MARKERS: [u8; 16] = [
     b'~',  // `attention` (gfm strikethrough)
 ];
 
+/// Characters that can start something in text, with text extensions.
+///
+/// Only used when there are extensions, otherwise [`MARKERS`][] is used.
+pub fn markers(options: &ParseOptions) -> Vec<u8> {
+    let mut markers = MARKERS.to_vec();
+
+    for extension in &options.text_extensions {
+        markers.extend_from_slice(&extension.markers);
+    }
+
+    markers.sort_unstable();
+    markers.dedup();
+    markers
+}
+
 /// Start of text.
 ///
 /// There is a slightly weird case where task list items have their check at
@@ -62,7 +83,12 @@ const MARKERS: [u8; 16] = [
 ///     ^
 /// ```
 pub fn start(tokenizer: &mut Tokenizer) -> State {
-    tokenizer.tokenize_state.markers = &MARKERS;
+    let parse_state = tokenizer.parse_state;
+    tokenizer.tokenize_state.markers = if parse_state.options.text_extensions.is_empty() {
+        &MARKERS
+    } else {
+        &parse_state.text_markers
+    };
     tokenizer.attempt(
         State::Next(StateName::TextBefore),
         State::Next(StateName::TextBefore),
@@ -77,6 +103,58 @@ pub fn start(tokenizer: &mut Tokenizer) -> State {
 ///     ^
 /// ```
 pub fn before(tokenizer: &mut Tokenizer) -> State {
+    if let Some(byte) = tokenizer.current {
+        if tokenizer
+            .parse_state
+            .options
+            .text_extensions
+            .iter()
+            .any(|extension| extension.markers.contains(&byte))
+        {
+            return State::Retry(StateName::TextBeforeExtension);
+        }
+    }
+
+    State::Retry(StateName::TextBeforeBuiltin)
+}
+
+/// Before a text extension.
+///
+/// At a marker of one or more extensions, which are tried in order.
+///
+/// ```markdown
+/// > | a ==b== c
+///       ^
+/// ```
+pub fn before_extension(tokenizer: &mut Tokenizer) -> State {
+    let extensions = &tokenizer.parse_state.options.text_extensions;
+    let byte = tokenizer.current.unwrap();
+    let mut index = tokenizer.tokenize_state.extension_index;
+
+    while index < extensions.len() {
+        if extensions[index].markers.contains(&byte) {
+            tokenizer.tokenize_state.extension_index = index + 1;
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeExtension),
+            );
+            return State::Retry(StateName::ExtensionTextStart);
+        }
+
+        index += 1;
+    }
+
+    tokenizer.tokenize_state.extension_index = 0;
+    State::Retry(StateName::TextBeforeBuiltin)
+}
+
+/// Before a built in construct.
+///
+/// ```markdown
+/// > | abc
+///     ^
+/// ```
+pub fn before_builtin(tokenizer: &mut Tokenizer) -> State {
     match tokenizer.current {
         None => {
             tokenizer.register_resolver(ResolveName::Data);
@@ -156,6 +234,13 @@ pub fn before(tokenizer: &mut Tokenizer) -> State {
             );
             State::Retry(StateName::LabelEndStart)
         }
+        Some(b'^') => {
+            tokenizer.attempt(
+                State::Next(StateName::TextBefore),
+                State::Next(StateName::TextBeforeData),
+            );
+            State::Retry(StateName::LabelStartFootnoteInlineStart)
+        }
         Some(b'{') => {
             tokenizer.attempt(
                 State::Next(StateName::TextBefore),
@@ -260,5 +345,6 @@ pub fn resolve(tokenizer: &mut Tokenizer) -> Option<Subresult> {
     }
 
     tokenizer.map.consume(&mut tokenizer.events);
+    split_data(tokenizer);
     None
 }
@@ -1,10 +1,18 @@
 //! Semantic labels of things happening.
+//!
+//! Events can be gotten with [`to_events()`][crate::to_events] and compiled
+//! with [`compile_events()`][crate::compile_events].
+//! New names of events can be added in minor releases.
+
+// Constructs are private, but links to them help contributors.
+#![allow(rustdoc::private_intra_doc_links)]
 
 use crate::unist;
-use crate::util::constant::TAB_SIZE;
+use crate::util::{char::column_width, constant::TAB_SIZE};
 
 /// Semantic label of a span.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
 pub enum Name {
     /// Attention sequence.
     ///
@@ -902,6 +910,46 @@ pub enum Name {
     ///      ^
     /// ```
     EmphasisText,
+    /// Span of a text extension.
+    ///
+    /// The name is the name given by the extension.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text],
+    ///     [`Extension`][Name::Extension]
+    /// *   **Content model**:
+    ///     [`Data`][Name::Data],
+    ///     [`Extension`][Name::Extension]
+    /// *   **Construct**:
+    ///     [`extension_text`][crate::construct::extension_text]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ==b== c
+    ///       ^^^^^
+    /// ```
+    Extension(&'static str),
+    /// Whole inline footnote.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [text content][crate::construct::text]
+    /// *   **Content model**:
+    ///     [`Label`][Name::Label]
+    /// *   **Construct**:
+    ///     [`label_end`][crate::construct::label_end]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ^[b] c
+    ///       ^^^^
+    /// ```
+    FootnoteInline,
     /// Whole frontmatter.
     ///
     /// ## Info
@@ -1912,9 +1960,11 @@ pub enum Name {
     /// ## Info
     ///
     /// *   **Context**:
+    ///     [`FootnoteInline`][Name::FootnoteInline],
     ///     [`Image`][Name::Image],
     ///     [`Link`][Name::Link]
     /// *   **Content model**:
+    ///     [`LabelFootnoteInline`][Name::LabelFootnoteInline],
     ///     [`LabelImage`][Name::LabelImage],
     ///     [`LabelLink`][Name::LabelLink],
     ///     [`LabelEnd`][Name::LabelEnd],
@@ -1953,6 +2003,43 @@ pub enum Name {
     ///         ^
     /// ```
     LabelEnd,
+    /// Label start (footnote inline).
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`Label`][Name::Label]
+    /// *   **Content model**:
+    ///     [`LabelFootnoteInlineMarker`][Name::LabelFootnoteInlineMarker],
+    ///     [`LabelMarker`][Name::LabelMarker]
+    /// *   **Construct**:
+    ///     [`label_start_footnote_inline`][crate::construct::label_start_footnote_inline]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ^[b] c
+    ///       ^^
+    /// ```
+    LabelFootnoteInline,
+    /// Label start (footnote inline) marker.
+    ///
+    /// ## Info
+    ///
+    /// *   **Context**:
+    ///     [`LabelFootnoteInline`][Name::LabelFootnoteInline]
+    /// *   **Content model**:
+    ///     void
+    /// *   **Construct**:
+    ///     [`label_start_footnote_inline`][crate::construct::label_start_footnote_inline]
+    ///
+    /// ## Example
+    ///
+    /// ```markdown
+    /// > | a ^[b] c
+    ///       ^
+    /// ```
+    LabelFootnoteInlineMarker,
     /// Label start (image).
     ///
     /// ## Info
@@ -2013,12 +2100,14 @@ pub enum Name {
     /// ## Info
     ///
     /// *   **Context**:
+    ///     [`LabelFootnoteInline`][Name::LabelFootnoteInline],
     ///     [`LabelImage`][Name::LabelImage],
     ///     [`LabelLink`][Name::LabelLink],
     ///     [`LabelEnd`][Name::LabelEnd]
     /// *   **Content model**:
     ///     void
     /// *   **Construct**:
+    ///     [`label_start_footnote_inline`][crate::construct::label_start_footnote_inline],
     ///     [`label_start_image`][crate::construct::label_start_image],
     ///     [`label_start_link`][crate::construct::label_start_link],
     ///     [`label_end`][crate::construct::label_end]
@@ -3495,7 +3584,8 @@ pub struct Point {
     pub line: usize,
     /// 1-indexed column number.
     ///
-    /// This is increased up to a tab stop for tabs.
+    /// This counts UTF-16 code units, and is increased up to a tab stop for
+    /// tabs.
     /// Some editors count tabs as 1 character, so this position is not the
     /// same as editors.
     pub column: usize,
@@ -3519,7 +3609,7 @@ impl Point {
 
     /// Create a new point, that is shifted from the close earlier current
     /// point, to `index`.
-    pub fn shift_to(&self, bytes: &[u8], index: usize) -> Point {
+    pub(crate) fn shift_to(&self, bytes: &[u8], index: usize) -> Point {
         let mut next = self.clone();
         debug_assert!(index > next.index, "expected to shift forward");
 
@@ -3536,9 +3626,9 @@ impl Point {
                     next.index += 1;
                     next.column += 1 + vs;
                 }
-                _ => {
+                byte => {
                     next.index += 1;
-                    next.column += 1;
+                    next.column += column_width(byte);
                 }
             }
         }
@@ -11,6 +11,31 @@
 //!     constructs (GFM, MDX, and the like)
 //! *   [`to_mdast()`][]
 //!     — turn markdown into a syntax tree
+//! *   [`to_mdast_multi()`][], [`to_html_multi()`][]
+//!     — turn several documents, separated by delimiter lines, into syntax
+//!     trees or HTML
+//! *   [`parse()`][]
+//!     — parse markdown once, to turn it into both HTML and a syntax tree
+//! *   [`mdast_to_html()`][]
+//!     — turn a syntax tree into HTML, to change the tree in between
+//! *   [`mdast_to_unified_json()`][]
+//!     — turn a syntax tree into JSON, in the shape that `unified` (JS) uses
+//! *   [`to_events()`][], [`compile_events()`][]
+//!     — turn markdown into events, and events into HTML, to change the
+//!     events in between
+//! *   [`EventTree`][]
+//!     — walk events as a tree, without tracking where they are exited
+//! *   [`frontmatter()`][]
+//!     — get just the frontmatter of a document, without parsing the rest
+//! *   [`linkify()`][]
+//!     — find links in plain text, like GFM autolink literals
+//! *   [`toc()`][]
+//!     — get the headings of a document, to make a table of contents
+//! *   [`normalize_line_endings()`][], [`LineEnding::detect()`][]
+//!     — turn line endings into one kind, and find which kind a document
+//!     uses
+//! *   [`commonmark_version()`][]
+//!     — get the version of `CommonMark` that is supported
 //!
 //! ## Features
 //!
@@ -21,6 +46,11 @@
 //! *   **`log`**
 //!     — enable logging (includes `dep:log`);
 //!     you can show logs with `RUST_LOG=debug`
+//! *   **`profile`**
+//!     — enable `to_html_with_profile()`, to find out which constructs
+//!     take time in a document (includes `std`)
+//! *   **`std`**
+//!     — implement `std::error::Error` for [`message::Message`][]
 
 #![no_std]
 #![deny(clippy::pedantic)]
@@ -34,9 +64,10 @@
 )]
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 mod configuration;
 mod construct;
-mod event;
 mod parser;
 mod resolve;
 mod state;
@@ -46,6 +77,8 @@ mod to_mdast;
 mod tokenizer;
 mod util;
 
+pub mod event;
+pub mod limits;
 pub mod mdast; // To do: externalize?
 pub mod message; // To do: externalize.
 pub mod unist; // To do: externalize.
@@ -62,16 +95,35 @@ pub use util::sanitize_uri::sanitize;
 #[doc(hidden)]
 pub use util::location::Location;
 
-pub use util::line_ending::LineEnding;
+#[cfg(feature = "profile")]
+pub use util::profile::{Profile, ProfileEntry};
+
+pub use util::event_tree::EventTree;
+
+pub use util::extension::{ExtensionState, ExtensionTokenize, ExtensionTokenizer, TextExtension};
+
+pub use util::frontmatter::FrontmatterKind;
+
+pub use util::line_ending::{normalize_line_endings, LineEnding};
+
+pub use util::linkify::{linkify, LinkSpan, LinkSpanKind};
+
+pub use util::toc::TocEntry;
 
 pub use util::mdx::{
     EsmParse as MdxEsmParse, ExpressionKind as MdxExpressionKind,
     ExpressionParse as MdxExpressionParse, Signal as MdxSignal,
 };
 
-pub use configuration::{CompileOptions, Constructs, Options, ParseOptions};
+pub use parser::Parsed;
+
+pub use configuration::{
+    BooleanAttributeStyle, CompileOptions, Constructs, ElementOverride, ElementOverrides,
+    FootnoteNumbering, FrontmatterRendering, GfmStrikethroughAllowed, ImageInfo, ImageRenderer,
+    Options, ParseOptions, QuoteKind, SelfClosingStyle,
+};
 
-use alloc::string::String;
+use alloc::{string::String, vec, vec::Vec};
 
 /// Turn markdown into HTML.
 ///
@@ -99,6 +151,10 @@ pub fn to_html(value: &str) -> String {
 /// However, MDX does have syntax errors.
 /// When MDX is turned on, there are several errors that can occur with how
 /// expressions, ESM, and JSX are written.
+/// It also errors when the HTML would become bigger than
+/// [`max_output_size`][CompileOptions::max_output_size], and when the
+/// options cannot be used together (see
+/// [`ParseOptions::validate`][]).
 ///
 /// ## Examples
 ///
@@ -126,12 +182,211 @@ pub fn to_html(value: &str) -> String {
 /// # }
 /// ```
 pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, message::Message> {
-    let (events, parse_state) = parser::parse(value, &options.parse)?;
-    Ok(to_html::compile(
+    let mut result = String::new();
+    to_html_to_writer(value, options, &mut result)?;
+    Ok(result)
+}
+
+/// Turn markdown into HTML, with configuration, written to `out`.
+///
+/// Like [`to_html_with_options()`][], but the HTML is written to `out` while
+/// it is generated, instead of collected into one string.
+/// This halves peak memory for big documents.
+///
+/// ## Errors
+///
+/// `to_html_to_writer()` errors with MDX syntax errors, like
+/// [`to_html_with_options()`][].
+/// It also errors when writing to `out` fails, or when the HTML would become
+/// bigger than [`max_output_size`][CompileOptions::max_output_size].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_to_writer, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut result = String::new();
+/// to_html_to_writer("# Hello, world!", &Options::default(), &mut result)?;
+///
+/// assert_eq!(result, "<h1>Hello, world!</h1>");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_to_writer(
+    value: &str,
+    options: &Options,
+    out: &mut impl core::fmt::Write,
+) -> Result<(), message::Message> {
+    let identifiers = to_html::extra_gfm_footnote_definition_identifiers(&options.compile);
+    let (events, parse_state) =
+        parser::parse_with_gfm_footnote_definitions(value, &options.parse, identifiers)?;
+    let definitions =
+        to_html::compile_extra_gfm_footnote_definitions(options, &events, parse_state.bytes)?;
+    to_html::compile_with_gfm_footnote_definitions(
         &events,
         parse_state.bytes,
         &options.compile,
-    ))
+        definitions,
+        out,
+    )
+}
+
+/// Turn markdown into HTML, with configuration, and get statistics about
+/// how it was parsed.
+///
+/// Like [`to_html_with_options()`][], but also returns a [`Profile`][]: how
+/// many steps were taken in which state, how many events of each kind there
+/// are, and how long each resolver took.
+/// Useful to find out which construct makes a slow document slow.
+///
+/// Only available with the `profile` feature.
+///
+/// ## Errors
+///
+/// `to_html_with_profile()` errors with MDX syntax errors and when the HTML
+/// would become too big, like [`to_html_with_options()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_with_profile, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let (result, profile) = to_html_with_profile("*Hello*, world!", &Options::default())?;
+///
+/// assert_eq!(result, "<p><em>Hello</em>, world!</p>");
+/// assert!(profile.resolvers.iter().any(|d| d.name == "Attention"));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "profile")]
+pub fn to_html_with_profile(
+    value: &str,
+    options: &Options,
+) -> Result<(String, Profile), message::Message> {
+    let start = std::time::Instant::now();
+    let identifiers = to_html::extra_gfm_footnote_definition_identifiers(&options.compile);
+    let (events, parse_state) =
+        parser::parse_with_gfm_footnote_definitions(value, &options.parse, identifiers)?;
+    let definitions =
+        to_html::compile_extra_gfm_footnote_definitions(options, &events, parse_state.bytes)?;
+    let parse_time = start.elapsed();
+    let start = std::time::Instant::now();
+    let mut result = String::new();
+    to_html::compile_with_gfm_footnote_definitions(
+        &events,
+        parse_state.bytes,
+        &options.compile,
+        definitions,
+        &mut result,
+    )?;
+    let compile_time = start.elapsed();
+    let profile = parse_state
+        .profiler
+        .into_inner()
+        .into_profile(&events, parse_time, compile_time);
+    Ok((result, profile))
+}
+
+/// Turn markdown into events.
+///
+/// Events are what markdown is parsed into, before it is compiled.
+/// Use [`compile_events()`][] to turn them into HTML, optionally after
+/// changing them.
+///
+/// ## Errors
+///
+/// `to_events()` errors with MDX syntax errors, like
+/// [`to_html_with_options()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::{Kind, Name}, to_events, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let events = to_events("*a*", &ParseOptions::default())?;
+///
+/// assert_eq!(events[1].kind, Kind::Enter);
+/// assert_eq!(events[1].name, Name::Emphasis);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_events(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<event::Event>, message::Message> {
+    let (events, _) = parser::parse(value, options)?;
+    Ok(events)
+}
+
+/// Turn events into HTML.
+///
+/// Events typically come from [`to_events()`][], for the same `value`, and
+/// can be changed in between.
+/// Changed events must be like the events that `markdown-rs` makes:
+///
+/// *   events must be balanced: each exit must close the last entered event
+/// *   [void events][event::VOID_EVENTS], such as
+///     [`Data`][event::Name::Data], must be empty: their exit must directly
+///     follow their enter
+/// *   points must refer to character boundaries in `value`, and exits must
+///     not be before their enters
+/// *   constructs must have the events the compiler expects in them, such as
+///     a [`Label`][event::Name::Label] and a
+///     [`Resource`][event::Name::Resource] in a
+///     [`Link`][event::Name::Link]
+/// *   references must match a definition, and footnote calls must match a
+///     footnote definition
+/// *   character reference and list item values must be valid
+///
+/// Values, such as the text of data and the URLs of links, are taken from
+/// `value` at the points of events.
+/// To inject values that are not in the document, such as a URL for a new
+/// link, append them to `value` and point events at them.
+/// [`links`][event::Event::link] between events are not used.
+///
+/// ## Errors
+///
+/// `compile_events()` errors when events are not balanced, when void events
+/// are not empty, when points are not valid, or when constructs do not have
+/// the structure described above.
+/// It also errors when the HTML would become bigger than
+/// [`max_output_size`][CompileOptions::max_output_size].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{compile_events, event::Name, to_events, CompileOptions, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let value = "*a*";
+/// let mut events = to_events(value, &ParseOptions::default())?;
+///
+/// // Turn emphasis into strong.
+/// for event in &mut events {
+///     if event.name == Name::Emphasis {
+///         event.name = Name::Strong;
+///     }
+/// }
+///
+/// assert_eq!(
+///     compile_events(&events, value, &CompileOptions::default())?,
+///     "<p><strong>a</strong></p>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn compile_events(
+    events: &[event::Event],
+    value: &str,
+    options: &CompileOptions,
+) -> Result<String, message::Message> {
+    util::validate::validate(events, value)?;
+    let mut result = String::new();
+    to_html::compile(events, value.as_bytes(), options, &mut result)?;
+    Ok(result)
 }
 
 /// Turn markdown into a syntax tree.
@@ -143,6 +398,8 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, me
 /// However, MDX does have syntax errors.
 /// When MDX is turned on, there are several errors that can occur with how
 /// JSX, expressions, or ESM are written.
+/// It also errors when the options cannot be used together (see
+/// [`ParseOptions::validate`][]).
 ///
 /// ## Examples
 ///
@@ -153,12 +410,430 @@ pub fn to_html_with_options(value: &str, options: &Options) -> Result<String, me
 /// let tree = to_mdast("# Hey, *you*!", &ParseOptions::default())?;
 ///
 /// println!("{:?}", tree);
-/// // => Root { children: [Heading { children: [Text { value: "Hey, ", position: Some(1:3-1:8 (2-7)) }, Emphasis { children: [Text { value: "you", position: Some(1:9-1:12 (8-11)) }], position: Some(1:8-1:13 (7-12)) }, Text { value: "!", position: Some(1:13-1:14 (12-13)) }], position: Some(1:1-1:14 (0-13)), depth: 1 }], position: Some(1:1-1:14 (0-13)) }
+/// // => Root { children: [Heading { children: [Text { value: "Hey, ", position: Some(1:3-1:8 (2-7)), raw: None }, Emphasis { children: [Text { value: "you", position: Some(1:9-1:12 (8-11)), raw: None }], position: Some(1:8-1:13 (7-12)), markers: None, raw: None }, Text { value: "!", position: Some(1:13-1:14 (12-13)), raw: None }], position: Some(1:1-1:14 (0-13)), depth: 1, markers: None, raw: None }], position: Some(1:1-1:14 (0-13)), raw: None }
 /// # Ok(())
 /// # }
 /// ```
 pub fn to_mdast(value: &str, options: &ParseOptions) -> Result<mdast::Node, message::Message> {
     let (events, parse_state) = parser::parse(value, options)?;
-    let node = to_mdast::compile(&events, parse_state.bytes)?;
+    let node = to_mdast::compile(&events, parse_state.bytes, options)?;
     Ok(node)
 }
+
+/// Turn several markdown documents, separated by delimiter lines, into
+/// syntax trees.
+///
+/// Lines that are exactly `delimiter` (such as `"\u{1e}"` or `"---"`)
+/// separate documents.
+/// Delimiters in fenced code, math (flow), and frontmatter do not separate
+/// documents, as they are content of those constructs.
+/// Each document is parsed on its own: definitions in one document are not
+/// used in another.
+/// Consecutive delimiters, and delimiters at the start or end of `value`,
+/// make empty documents.
+///
+/// Positions are relative to the whole `value`.
+///
+/// ## Errors
+///
+/// `to_mdast_multi()` errors like [`to_mdast()`][], and when `delimiter` is
+/// empty, as that would match every blank line.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::Node, to_mdast_multi, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let trees = to_mdast_multi("# Venus\n\u{1e}\n# Mars", "\u{1e}", &ParseOptions::default())?;
+///
+/// assert_eq!(trees.len(), 2);
+/// assert_eq!(trees[1].to_string(), "Mars");
+/// assert_eq!(trees[1].position().unwrap().start.line, 3);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_mdast_multi(
+    value: &str,
+    delimiter: &str,
+    options: &ParseOptions,
+) -> Result<Vec<mdast::Node>, message::Message> {
+    let mut nodes = vec![];
+
+    for (start, end) in util::multi::split(value, delimiter, options)? {
+        let point = start.to_unist();
+        let (events, parse_state) = parser::parse_range(value, options, start, end, vec![])?;
+        let mut node = to_mdast::compile(&events, parse_state.bytes, options)?;
+
+        // Empty documents are where their delimiter ends.
+        if events.is_empty() {
+            node.position_set(Some(unist::Position {
+                start: point.clone(),
+                end: point.clone(),
+            }));
+        }
+
+        if options.preserve {
+            node.raw_set(Some(value[point.offset..end].into()));
+        }
+
+        nodes.push(node);
+    }
+
+    Ok(nodes)
+}
+
+/// Turn several markdown documents, separated by delimiter lines, into
+/// HTML.
+///
+/// Documents are found like [`to_mdast_multi()`][] does.
+///
+/// ## Errors
+///
+/// `to_html_multi()` errors like [`to_html_with_options()`][], and when
+/// `delimiter` is empty, as that would match every blank line.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{to_html_multi, Options};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let results = to_html_multi("# Venus\n\u{1e}\n# Mars", "\u{1e}", &Options::default())?;
+///
+/// assert_eq!(results, vec!["<h1>Venus</h1>\n", "<h1>Mars</h1>"]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_html_multi(
+    value: &str,
+    delimiter: &str,
+    options: &Options,
+) -> Result<Vec<String>, message::Message> {
+    let mut results = vec![];
+    let identifiers = to_html::extra_gfm_footnote_definition_identifiers(&options.compile);
+
+    for (start, end) in util::multi::split(value, delimiter, &options.parse)? {
+        let (events, parse_state) =
+            parser::parse_range(value, &options.parse, start, end, identifiers.clone())?;
+        let definitions =
+            to_html::compile_extra_gfm_footnote_definitions(options, &events, parse_state.bytes)?;
+        let mut result = String::new();
+        to_html::compile_with_gfm_footnote_definitions(
+            &events,
+            parse_state.bytes,
+            &options.compile,
+            definitions,
+            &mut result,
+        )?;
+        results.push(result);
+    }
+
+    Ok(results)
+}
+
+/// Parse markdown, to compile it later.
+///
+/// Use this to get both HTML and a syntax tree without parsing twice: the
+/// result can be compiled with [`Parsed::to_html()`][] and
+/// [`Parsed::to_mdast()`][], as often as needed.
+///
+/// ## Errors
+///
+/// `parse()` errors with MDX syntax errors, and when the options cannot be
+/// used together, like [`to_mdast()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::Node, parse, CompileOptions, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let options = ParseOptions::default();
+/// let parsed = parse("# Hey, *you*!", &options)?;
+///
+/// assert_eq!(
+///     parsed.to_html(&CompileOptions::default())?,
+///     "<h1>Hey, <em>you</em>!</h1>"
+/// );
+/// assert!(matches!(parsed.to_mdast()?, Node::Root(_)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+) -> Result<Parsed<'a>, message::Message> {
+    Parsed::new(value, options)
+}
+
+/// Turn markdown text into phrasing nodes, without block constructs.
+///
+/// This parses `value` like the text of a paragraph: block constructs, such
+/// as headings and lists, are not tried, so `# a` stays text, and blank
+/// lines are line endings in text.
+/// Like in paragraphs, whitespace at the start of lines is dropped.
+/// Constructs in text, such as emphasis, links, and code (text), are
+/// supported if they are on in `options`.
+/// There are no definitions, so references are text.
+///
+/// Positions are relative to `value`.
+/// The nodes are not wrapped in a paragraph or root.
+/// Definitions of inline footnotes, if any, come after the phrasing nodes.
+///
+/// ## Errors
+///
+/// `parse_text_fragment()` never errors with normal markdown because markdown
+/// does not have syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::Node, parse_text_fragment, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let nodes = parse_text_fragment("# *Venus*", &ParseOptions::default())?;
+///
+/// assert_eq!(nodes.len(), 2);
+/// assert_eq!(nodes[0].to_string(), "# ");
+/// assert!(matches!(nodes[1], Node::Emphasis(_)));
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_text_fragment(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<mdast::Node>, message::Message> {
+    let (events, parse_state) = parser::parse_content(value, options, &event::Content::Text)?;
+    to_mdast::compile_fragment(&events, parse_state.bytes, options)
+}
+
+/// Turn a markdown string into text nodes.
+///
+/// This parses `value` like the title of a link or the info string of fenced
+/// code: only character escapes and character references are supported (if
+/// they are on in `options`).
+/// Everything else, including emphasis, stays text.
+///
+/// Positions are relative to `value`.
+///
+/// ## Errors
+///
+/// `parse_string_fragment()` only errors when the options cannot be used
+/// together (see [`ParseOptions::validate`][]).
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{parse_string_fragment, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let nodes = parse_string_fragment("*Venus* &amp; \\*Mars\\*", &ParseOptions::default())?;
+///
+/// assert_eq!(nodes.len(), 1);
+/// assert_eq!(nodes[0].to_string(), "*Venus* & *Mars*");
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_string_fragment(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<mdast::Node>, message::Message> {
+    let (events, parse_state) = parser::parse_content(value, options, &event::Content::String)?;
+    to_mdast::compile_fragment(&events, parse_state.bytes, options)
+}
+
+/// Turn a syntax tree into HTML.
+///
+/// The HTML is the same as what [`to_html_with_options()`][] makes for the
+/// markdown the tree came from, with the same escaping, URL sanitizing, and
+/// GFM features.
+/// Definitions in the tree are used by references; references that are not
+/// defined are turned into text.
+/// The line ending used is
+/// [`default_line_ending`][CompileOptions::default_line_ending], as the
+/// tree does not know which line endings were used in the markdown.
+/// Frontmatter is compiled as configured with
+/// [`frontmatter`][CompileOptions::frontmatter]: dropped by default.
+///
+/// ## Errors
+///
+/// `mdast_to_html()` errors when there are MDX nodes, which cannot be turned
+/// into HTML, unless [`skip_mdx_nodes`][CompileOptions::skip_mdx_nodes] is
+/// on.
+/// It also errors when the HTML would become bigger than
+/// [`max_output_size`][CompileOptions::max_output_size].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::Node, mdast_to_html, to_mdast, CompileOptions, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let mut tree = to_mdast("# Hey, *you*!", &ParseOptions::default())?;
+///
+/// // Make the heading smaller.
+/// if let Node::Heading(heading) = &mut tree.children_mut().unwrap()[0] {
+///     heading.depth = 2;
+/// }
+///
+/// assert_eq!(
+///     mdast_to_html(&tree, &CompileOptions::default())?,
+///     "<h2>Hey, <em>you</em>!</h2>"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn mdast_to_html(
+    tree: &mdast::Node,
+    options: &CompileOptions,
+) -> Result<String, message::Message> {
+    to_html::compile_mdast(tree, options)
+}
+
+/// Turn a syntax tree into JSON, in the shape that `unified` (JS) uses.
+///
+/// The `serde` serialization of [`mdast::Node`][] leaves out missing
+/// fields.
+/// This instead writes what `JSON.stringify` writes for a tree made by
+/// `mdast-util-from-markdown` (and its extensions for GFM, math,
+/// frontmatter, and MDX): the same fields, in the same order, with `null`
+/// for missing optional fields (such as `checked`, `start`, `lang`, `title`),
+/// `null` for table columns that are not aligned, and `offset` in points.
+/// It works without the `serde` feature.
+///
+/// Some things cannot match:
+///
+/// *   offsets are in bytes, whereas JS counts UTF-16 code units, so they
+///     differ after non-ASCII characters
+/// *   `data` is not written, so math nodes lack the `hName`, `hProperties`,
+///     and `hChildren` that `mdast-util-math` adds, and MDX nodes lack the
+///     `estree` that `micromark-extension-mdxjs` adds
+/// *   fields that only exist in markdown-rs (`raw`, `markers`, `kind`,
+///     stops) are not written
+///
+/// Compatibility is not verified against fixtures generated by
+/// `mdast-util-from-markdown` for the whole test corpus: the expected JSON in
+/// the tests is written by hand, for a sample of documents.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{mdast::{Code, Node}, mdast_to_unified_json};
+///
+/// let tree = Node::Code(Code {
+///     value: "a".into(),
+///     position: None,
+///     lang: None,
+///     meta: None,
+///     markers: None,
+///     raw: None,
+/// });
+///
+/// assert_eq!(
+///     mdast_to_unified_json(&tree),
+///     r#"{"type":"code","lang":null,"meta":null,"value":"a"}"#
+/// );
+/// ```
+pub fn mdast_to_unified_json(tree: &mdast::Node) -> String {
+    util::unified_json::serialize(tree)
+}
+
+/// Get the frontmatter of a document, without parsing the rest.
+///
+/// Only the start of the document is tokenized, so this is much faster than
+/// [`to_mdast()`][] for large documents.
+/// It agrees with [`to_mdast()`][] about whether there is frontmatter: the
+/// [`frontmatter`][Constructs::frontmatter] construct must be on, a BOM is
+/// allowed before it, and a closing fence is required.
+///
+/// Returns the value (the lines between the fences), the kind, and the
+/// position of the whole frontmatter, fences included.
+///
+/// ## Errors
+///
+/// `frontmatter()` never errors: frontmatter does not have syntax errors.
+/// It returns a result for parity with [`to_mdast()`][].
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{frontmatter, Constructs, FrontmatterKind, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let options = ParseOptions {
+///     constructs: Constructs {
+///         frontmatter: true,
+///         ..Constructs::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let (value, kind, _) = frontmatter("---\ntitle: Venus\n---\n# Venus", &options)?.unwrap();
+///
+/// assert_eq!(value, "title: Venus");
+/// assert_eq!(kind, FrontmatterKind::Yaml);
+///
+/// assert_eq!(frontmatter("# Venus", &options)?, None);
+/// # Ok(())
+/// # }
+/// ```
+pub fn frontmatter(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Option<(String, FrontmatterKind, unist::Position)>, message::Message> {
+    let (events, parse_state) = parser::parse_frontmatter(value, options)?;
+    Ok(util::frontmatter::from_events(&events, parse_state.bytes))
+}
+
+/// Get the headings of a document, to make a table of contents.
+///
+/// This is faster than going through [`to_mdast()`][] when only headings
+/// are needed, as no syntax tree is made.
+/// ATX and setext headings are included, also when they are in containers
+/// (block quotes, lists, footnote definitions).
+/// The text of each heading is plain text, made the same way as the `alt`
+/// of images in HTML.
+///
+/// ## Errors
+///
+/// `toc()` never errors with normal markdown because markdown does not have
+/// syntax errors, so feel free to `unwrap()`.
+/// However, MDX does have syntax errors.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{toc, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let entries = toc("# Venus\n\n> Mars\n> ----", &ParseOptions::default())?;
+///
+/// assert_eq!(entries.len(), 2);
+/// assert_eq!((entries[0].depth, entries[0].text.as_str()), (1, "Venus"));
+/// assert_eq!((entries[1].depth, entries[1].text.as_str()), (2, "Mars"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn toc(value: &str, options: &ParseOptions) -> Result<Vec<TocEntry>, message::Message> {
+    let (events, parse_state) = parser::parse(value, options)?;
+    Ok(util::toc::from_events(&events, parse_state.bytes))
+}
+
+/// Get the version of `CommonMark` that `markdown-rs` is compliant with.
+///
+/// All examples in that version of the spec pass (see `tests/commonmark.rs`,
+/// which is generated from it), without known deviations.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::commonmark_version;
+///
+/// assert_eq!(commonmark_version(), "0.31.2");
+/// ```
+#[must_use]
+pub fn commonmark_version() -> &'static str {
+    "0.31.2"
+}
@@ -0,0 +1,66 @@
+//! Limits that affect how markdown parses.
+//!
+//! Markdown never fails, but some constructs only form when parts of them
+//! are not too long.
+//! Inputs near these limits parse differently on either side of them,
+//! which is useful to know when fuzzing or validating.
+//! Sizes count bytes.
+
+use crate::util::constant;
+
+/// Max size of the scheme of an autolink (the `xxx` in
+/// `<xxx://example.com>`).
+///
+/// 32 is fine, 33 is not an autolink.
+pub const AUTOLINK_SCHEME_SIZE_MAX: usize = constant::AUTOLINK_SCHEME_SIZE_MAX;
+
+/// Max size of each domain part of an email autolink (each `xxx` in
+/// `<example@xxx.xxx>`).
+///
+/// 63 is fine, 64 is not an autolink.
+pub const AUTOLINK_DOMAIN_SIZE_MAX: usize = constant::AUTOLINK_DOMAIN_SIZE_MAX;
+
+/// Max number of digits in a decimal character reference (the `xxx` in
+/// `&#xxx;`).
+///
+/// `&#9999999;` is fine, `&#99999990;` is not a character reference.
+pub const CHARACTER_REFERENCE_DECIMAL_SIZE_MAX: usize =
+    constant::CHARACTER_REFERENCE_DECIMAL_SIZE_MAX;
+
+/// Max number of digits in a hexadecimal character reference (the `xxx` in
+/// `&#xxxx;`).
+///
+/// `&#xff9999;` is fine, `&#xff99990;` is not a character reference.
+pub const CHARACTER_REFERENCE_HEXADECIMAL_SIZE_MAX: usize =
+    constant::CHARACTER_REFERENCE_HEXADECIMAL_SIZE_MAX;
+
+/// Max size of the name in a named character reference (the `xxx` in
+/// `&xxx;`).
+///
+/// This is the size of the longest name, `CounterClockwiseContourIntegral`.
+pub const CHARACTER_REFERENCE_NAMED_SIZE_MAX: usize = constant::CHARACTER_REFERENCE_NAMED_SIZE_MAX;
+
+/// Max number of `#` in a heading (atx), by default.
+///
+/// `######` is fine, `#######` is not a heading.
+/// It can be changed with
+/// [`heading_atx_max_depth`][crate::ParseOptions::heading_atx_max_depth].
+pub const HEADING_ATX_OPENING_FENCE_SIZE_MAX: u8 = constant::HEADING_ATX_OPENING_FENCE_SIZE_MAX;
+
+/// Max size of a label (the `xxx` in `[xxx]: a`, `[a][xxx]`, `[xxx][]`,
+/// `[xxx]`, and `[^xxx]`).
+///
+/// Line endings, and whitespace after them, are not counted.
+/// 999 is fine, 1000 is not a definition, reference, or footnote.
+pub const LINK_REFERENCE_SIZE_MAX: usize = constant::LINK_REFERENCE_SIZE_MAX;
+
+/// Number of digits that is too many for the value of an ordered list item
+/// (the `xxx` in `xxx.`).
+///
+/// 9 is fine, 10 is not a list item.
+pub const LIST_ITEM_VALUE_SIZE_MAX: usize = constant::LIST_ITEM_VALUE_SIZE_MAX;
+
+/// Max number of unclosed parens in a destination (such as `[a](b(c)`).
+///
+/// 32 is fine, 33 is not a link.
+pub const RESOURCE_DESTINATION_BALANCE_MAX: usize = constant::RESOURCE_DESTINATION_BALANCE_MAX;
@@ -158,6 +158,135 @@ impl<'de> serde::de::Deserialize<'de> for AlignKind {
     }
 }
 
+/// Markers used to form a node.
+///
+/// Only added when [`mdast_markers`][crate::ParseOptions::mdast_markers] is
+/// on.
+/// Useful to check the style of a document, such as when linting.
+///
+/// ```markdown
+/// > | ~~~js
+///     ^^^
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Markers {
+    /// Marker character.
+    ///
+    /// *   emphasis and strong: `*` or `_`
+    /// *   code (fenced): `` ` `` or `~`
+    /// *   heading: `#` for ATX headings, `=` or `-` for setext headings
+    /// *   list and list item: `*`, `+`, or `-` when unordered, `.` or `)`
+    ///     when ordered
+    /// *   thematic break: `*`, `-`, or `_`
+    pub character: char,
+    /// Number of marker characters.
+    ///
+    /// *   emphasis: `1`
+    /// *   strong: `2`
+    /// *   code (fenced): size of the opening fence
+    /// *   heading: size of the opening sequence (ATX) or underline (setext)
+    /// *   list and list item: `1`
+    /// *   thematic break: total, not counting whitespace
+    pub size: usize,
+}
+
+/// Kind of numbering of an ordered list that does not use digits.
+///
+/// Only used when
+/// [`ordered_list_letters`][crate::ParseOptions::ordered_list_letters] is
+/// on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum OrderedListKind {
+    /// Lowercase letters.
+    ///
+    /// ```markdown
+    /// > | a. b
+    ///     ^
+    /// ```
+    LowerAlpha,
+    /// Uppercase letters.
+    ///
+    /// ```markdown
+    /// > | A.  b
+    ///     ^
+    /// ```
+    UpperAlpha,
+    /// Lowercase roman numerals.
+    ///
+    /// ```markdown
+    /// > | iv. b
+    ///     ^^
+    /// ```
+    LowerRoman,
+    /// Uppercase roman numerals.
+    ///
+    /// ```markdown
+    /// > | IV. b
+    ///     ^^
+    /// ```
+    UpperRoman,
+}
+
+/// Kind of HTML.
+///
+/// Only added when [`mdast_html_kind`][crate::ParseOptions::mdast_html_kind]
+/// is on.
+/// Useful to tell comments apart from other HTML, such as when looking for
+/// `<!-- prettier-ignore -->`.
+///
+/// For HTML (flow), the kind is that of how the block starts: it can
+/// include other HTML after it, such as in `<!-- a --><div>`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum HtmlKind {
+    /// Comment.
+    ///
+    /// ```markdown
+    /// > | <!-- a -->
+    ///     ^^^^^^^^^^
+    /// ```
+    Comment,
+    /// CDATA.
+    ///
+    /// ```markdown
+    /// > | <![CDATA[a]]>
+    ///     ^^^^^^^^^^^^^
+    /// ```
+    Cdata,
+    /// Processing instruction.
+    ///
+    /// ```markdown
+    /// > | <?a?>
+    ///     ^^^^^
+    /// ```
+    Instruction,
+    /// Declaration.
+    ///
+    /// ```markdown
+    /// > | <!doctype html>
+    ///     ^^^^^^^^^^^^^^^
+    /// ```
+    Declaration,
+    /// Element: an opening or closing tag, or other HTML followed by more
+    /// HTML (such as `<!-- a --><div>`).
+    ///
+    /// ```markdown
+    /// > | <div>
+    ///     ^^^^^
+    /// ```
+    Element,
+}
+
 /// Nodes.
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(
@@ -301,6 +430,25 @@ fn children_to_string(children: &[Node]) -> String {
     children.iter().map(ToString::to_string).collect()
 }
 
+impl Drop for Node {
+    // Drop descendants with a work list instead of recursion, so that deep
+    // trees (such as thousands of nested block quotes) do not overflow the
+    // stack.
+    fn drop(&mut self) {
+        let mut stack = match self.children_mut() {
+            Some(children) if !children.is_empty() => core::mem::take(children),
+            _ => return,
+        };
+
+        while let Some(mut node) = stack.pop() {
+            if let Some(children) = node.children_mut() {
+                stack.append(children);
+            }
+            // `node` is dropped here, without children.
+        }
+    }
+}
+
 // To do: clippy may be right but that’s a breaking change.
 #[allow(clippy::to_string_trait_impl)]
 impl ToString for Node {
@@ -518,6 +666,301 @@ impl Node {
             Node::Paragraph(x) => x.position = position,
         }
     }
+
+    #[must_use]
+    pub fn raw(&self) -> Option<&String> {
+        match self {
+            Node::Root(x) => x.raw.as_ref(),
+            Node::Blockquote(x) => x.raw.as_ref(),
+            Node::FootnoteDefinition(x) => x.raw.as_ref(),
+            Node::MdxJsxFlowElement(x) => x.raw.as_ref(),
+            Node::List(x) => x.raw.as_ref(),
+            Node::MdxjsEsm(x) => x.raw.as_ref(),
+            Node::Toml(x) => x.raw.as_ref(),
+            Node::Yaml(x) => x.raw.as_ref(),
+            Node::Break(x) => x.raw.as_ref(),
+            Node::InlineCode(x) => x.raw.as_ref(),
+            Node::InlineMath(x) => x.raw.as_ref(),
+            Node::Delete(x) => x.raw.as_ref(),
+            Node::Emphasis(x) => x.raw.as_ref(),
+            Node::MdxTextExpression(x) => x.raw.as_ref(),
+            Node::FootnoteReference(x) => x.raw.as_ref(),
+            Node::Html(x) => x.raw.as_ref(),
+            Node::Image(x) => x.raw.as_ref(),
+            Node::ImageReference(x) => x.raw.as_ref(),
+            Node::MdxJsxTextElement(x) => x.raw.as_ref(),
+            Node::Link(x) => x.raw.as_ref(),
+            Node::LinkReference(x) => x.raw.as_ref(),
+            Node::Strong(x) => x.raw.as_ref(),
+            Node::Text(x) => x.raw.as_ref(),
+            Node::Code(x) => x.raw.as_ref(),
+            Node::Math(x) => x.raw.as_ref(),
+            Node::MdxFlowExpression(x) => x.raw.as_ref(),
+            Node::Heading(x) => x.raw.as_ref(),
+            Node::Table(x) => x.raw.as_ref(),
+            Node::ThematicBreak(x) => x.raw.as_ref(),
+            Node::TableRow(x) => x.raw.as_ref(),
+            Node::TableCell(x) => x.raw.as_ref(),
+            Node::ListItem(x) => x.raw.as_ref(),
+            Node::Definition(x) => x.raw.as_ref(),
+            Node::Paragraph(x) => x.raw.as_ref(),
+        }
+    }
+
+    pub fn raw_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Node::Root(x) => x.raw.as_mut(),
+            Node::Blockquote(x) => x.raw.as_mut(),
+            Node::FootnoteDefinition(x) => x.raw.as_mut(),
+            Node::MdxJsxFlowElement(x) => x.raw.as_mut(),
+            Node::List(x) => x.raw.as_mut(),
+            Node::MdxjsEsm(x) => x.raw.as_mut(),
+            Node::Toml(x) => x.raw.as_mut(),
+            Node::Yaml(x) => x.raw.as_mut(),
+            Node::Break(x) => x.raw.as_mut(),
+            Node::InlineCode(x) => x.raw.as_mut(),
+            Node::InlineMath(x) => x.raw.as_mut(),
+            Node::Delete(x) => x.raw.as_mut(),
+            Node::Emphasis(x) => x.raw.as_mut(),
+            Node::MdxTextExpression(x) => x.raw.as_mut(),
+            Node::FootnoteReference(x) => x.raw.as_mut(),
+            Node::Html(x) => x.raw.as_mut(),
+            Node::Image(x) => x.raw.as_mut(),
+            Node::ImageReference(x) => x.raw.as_mut(),
+            Node::MdxJsxTextElement(x) => x.raw.as_mut(),
+            Node::Link(x) => x.raw.as_mut(),
+            Node::LinkReference(x) => x.raw.as_mut(),
+            Node::Strong(x) => x.raw.as_mut(),
+            Node::Text(x) => x.raw.as_mut(),
+            Node::Code(x) => x.raw.as_mut(),
+            Node::Math(x) => x.raw.as_mut(),
+            Node::MdxFlowExpression(x) => x.raw.as_mut(),
+            Node::Heading(x) => x.raw.as_mut(),
+            Node::Table(x) => x.raw.as_mut(),
+            Node::ThematicBreak(x) => x.raw.as_mut(),
+            Node::TableRow(x) => x.raw.as_mut(),
+            Node::TableCell(x) => x.raw.as_mut(),
+            Node::ListItem(x) => x.raw.as_mut(),
+            Node::Definition(x) => x.raw.as_mut(),
+            Node::Paragraph(x) => x.raw.as_mut(),
+        }
+    }
+
+    pub fn raw_set(&mut self, raw: Option<String>) {
+        match self {
+            Node::Root(x) => x.raw = raw,
+            Node::Blockquote(x) => x.raw = raw,
+            Node::FootnoteDefinition(x) => x.raw = raw,
+            Node::MdxJsxFlowElement(x) => x.raw = raw,
+            Node::List(x) => x.raw = raw,
+            Node::MdxjsEsm(x) => x.raw = raw,
+            Node::Toml(x) => x.raw = raw,
+            Node::Yaml(x) => x.raw = raw,
+            Node::Break(x) => x.raw = raw,
+            Node::InlineCode(x) => x.raw = raw,
+            Node::InlineMath(x) => x.raw = raw,
+            Node::Delete(x) => x.raw = raw,
+            Node::Emphasis(x) => x.raw = raw,
+            Node::MdxTextExpression(x) => x.raw = raw,
+            Node::FootnoteReference(x) => x.raw = raw,
+            Node::Html(x) => x.raw = raw,
+            Node::Image(x) => x.raw = raw,
+            Node::ImageReference(x) => x.raw = raw,
+            Node::MdxJsxTextElement(x) => x.raw = raw,
+            Node::Link(x) => x.raw = raw,
+            Node::LinkReference(x) => x.raw = raw,
+            Node::Strong(x) => x.raw = raw,
+            Node::Text(x) => x.raw = raw,
+            Node::Code(x) => x.raw = raw,
+            Node::Math(x) => x.raw = raw,
+            Node::MdxFlowExpression(x) => x.raw = raw,
+            Node::Heading(x) => x.raw = raw,
+            Node::Table(x) => x.raw = raw,
+            Node::ThematicBreak(x) => x.raw = raw,
+            Node::TableRow(x) => x.raw = raw,
+            Node::TableCell(x) => x.raw = raw,
+            Node::ListItem(x) => x.raw = raw,
+            Node::Definition(x) => x.raw = raw,
+            Node::Paragraph(x) => x.raw = raw,
+        }
+    }
+
+    /// Check if two nodes are equal, ignoring positional info.
+    ///
+    /// This compares everything (such as values, URLs, the kinds of lists
+    /// and HTML, and children) except `position` fields and the `stops` of
+    /// MDX expressions and ESM, which also refer to places in the source, and
+    /// `markers` and `raw`, which refer to how the source is formatted.
+    /// It is useful to compare trees from differently formatted sources.
+    #[must_use]
+    pub fn eq_ignore_position(&self, other: &Node) -> bool {
+        match (self, other) {
+            (Node::Root(a), Node::Root(b)) => children_eq_ignore_position(&a.children, &b.children),
+            (Node::Blockquote(a), Node::Blockquote(b)) => {
+                children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::FootnoteDefinition(a), Node::FootnoteDefinition(b)) => {
+                a.identifier == b.identifier
+                    && a.label == b.label
+                    && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::MdxJsxFlowElement(a), Node::MdxJsxFlowElement(b)) => {
+                a.name == b.name
+                    && attributes_eq_ignore_position(&a.attributes, &b.attributes)
+                    && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::List(a), Node::List(b)) => {
+                a.ordered == b.ordered
+                    && a.start == b.start
+                    && a.kind == b.kind
+                    && a.spread == b.spread
+                    && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::MdxjsEsm(a), Node::MdxjsEsm(b)) => a.value == b.value,
+            (Node::Toml(a), Node::Toml(b)) => a.value == b.value,
+            (Node::Yaml(a), Node::Yaml(b)) => a.value == b.value,
+            (Node::Break(_), Node::Break(_)) | (Node::ThematicBreak(_), Node::ThematicBreak(_)) => {
+                true
+            }
+            (Node::InlineCode(a), Node::InlineCode(b)) => a.value == b.value,
+            (Node::InlineMath(a), Node::InlineMath(b)) => a.value == b.value,
+            (Node::Delete(a), Node::Delete(b)) => {
+                children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::Emphasis(a), Node::Emphasis(b)) => {
+                children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::MdxTextExpression(a), Node::MdxTextExpression(b)) => a.value == b.value,
+            (Node::FootnoteReference(a), Node::FootnoteReference(b)) => {
+                a.identifier == b.identifier && a.label == b.label
+            }
+            (Node::Html(a), Node::Html(b)) => a.value == b.value && a.kind == b.kind,
+            (Node::Image(a), Node::Image(b)) => {
+                a.alt == b.alt && a.url == b.url && a.title == b.title
+            }
+            (Node::ImageReference(a), Node::ImageReference(b)) => {
+                a.alt == b.alt
+                    && a.reference_kind == b.reference_kind
+                    && a.identifier == b.identifier
+                    && a.label == b.label
+            }
+            (Node::MdxJsxTextElement(a), Node::MdxJsxTextElement(b)) => {
+                a.name == b.name
+                    && attributes_eq_ignore_position(&a.attributes, &b.attributes)
+                    && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::Link(a), Node::Link(b)) => {
+                a.url == b.url
+                    && a.title == b.title
+                    && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::LinkReference(a), Node::LinkReference(b)) => {
+                a.reference_kind == b.reference_kind
+                    && a.identifier == b.identifier
+                    && a.label == b.label
+                    && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::Strong(a), Node::Strong(b)) => {
+                children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::Text(a), Node::Text(b)) => a.value == b.value,
+            (Node::Code(a), Node::Code(b)) => {
+                a.value == b.value && a.lang == b.lang && a.meta == b.meta
+            }
+            (Node::Math(a), Node::Math(b)) => a.value == b.value && a.meta == b.meta,
+            (Node::MdxFlowExpression(a), Node::MdxFlowExpression(b)) => a.value == b.value,
+            (Node::Heading(a), Node::Heading(b)) => {
+                a.depth == b.depth && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::Table(a), Node::Table(b)) => {
+                a.align == b.align && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::TableRow(a), Node::TableRow(b)) => {
+                children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::TableCell(a), Node::TableCell(b)) => {
+                children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::ListItem(a), Node::ListItem(b)) => {
+                a.spread == b.spread
+                    && a.checked == b.checked
+                    && children_eq_ignore_position(&a.children, &b.children)
+            }
+            (Node::Definition(a), Node::Definition(b)) => {
+                a.url == b.url
+                    && a.title == b.title
+                    && a.identifier == b.identifier
+                    && a.label == b.label
+            }
+            (Node::Paragraph(a), Node::Paragraph(b)) => {
+                children_eq_ignore_position(&a.children, &b.children)
+            }
+            // Different kinds of nodes.
+            (
+                Node::Root(_)
+                | Node::Blockquote(_)
+                | Node::FootnoteDefinition(_)
+                | Node::MdxJsxFlowElement(_)
+                | Node::List(_)
+                | Node::MdxjsEsm(_)
+                | Node::Toml(_)
+                | Node::Yaml(_)
+                | Node::Break(_)
+                | Node::InlineCode(_)
+                | Node::InlineMath(_)
+                | Node::Delete(_)
+                | Node::Emphasis(_)
+                | Node::MdxTextExpression(_)
+                | Node::FootnoteReference(_)
+                | Node::Html(_)
+                | Node::Image(_)
+                | Node::ImageReference(_)
+                | Node::MdxJsxTextElement(_)
+                | Node::Link(_)
+                | Node::LinkReference(_)
+                | Node::Strong(_)
+                | Node::Text(_)
+                | Node::Code(_)
+                | Node::Math(_)
+                | Node::MdxFlowExpression(_)
+                | Node::Heading(_)
+                | Node::Table(_)
+                | Node::ThematicBreak(_)
+                | Node::TableRow(_)
+                | Node::TableCell(_)
+                | Node::ListItem(_)
+                | Node::Definition(_)
+                | Node::Paragraph(_),
+                _,
+            ) => false,
+        }
+    }
+}
+
+/// Check if two lists of nodes are equal, ignoring positional info.
+fn children_eq_ignore_position(a: &[Node], b: &[Node]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| a.eq_ignore_position(b))
+}
+
+/// Check if two lists of JSX attributes are equal, ignoring positional info.
+fn attributes_eq_ignore_position(a: &[AttributeContent], b: &[AttributeContent]) -> bool {
+    a.len() == b.len()
+        && a.iter().zip(b).all(|pair| match pair {
+            (AttributeContent::Expression(a), AttributeContent::Expression(b)) => {
+                a.value == b.value
+            }
+            (AttributeContent::Property(a), AttributeContent::Property(b)) => {
+                a.name == b.name
+                    && match (&a.value, &b.value) {
+                        (
+                            Some(AttributeValue::Expression(a)),
+                            Some(AttributeValue::Expression(b)),
+                        ) => a.value == b.value,
+                        (a, b) => a == b,
+                    }
+            }
+            _ => false,
+        })
 }
 
 /// MDX: attribute content.
@@ -595,6 +1038,14 @@ pub struct Root {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Paragraph.
@@ -612,6 +1063,14 @@ pub struct Paragraph {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Heading.
@@ -630,8 +1089,22 @@ pub struct Heading {
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
     // Extra.
-    /// Rank (between `1` and `6`, both including).
+    /// Rank (between `1` and `6`, both including, unless
+    /// `heading_atx_max_depth` allows deeper headings).
     pub depth: u8,
+    /// Markers used.
+    ///
+    /// Only set when [`mdast_markers`][crate::ParseOptions::mdast_markers]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub markers: Option<Markers>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Thematic break.
@@ -647,6 +1120,20 @@ pub struct ThematicBreak {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Markers used.
+    ///
+    /// Only set when [`mdast_markers`][crate::ParseOptions::mdast_markers]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub markers: Option<Markers>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Block quote.
@@ -664,6 +1151,14 @@ pub struct Blockquote {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// List.
@@ -688,9 +1183,30 @@ pub struct List {
     /// `None` when unordered.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub start: Option<u32>,
+    /// Kind of numbering, if letters or roman numerals are used.
+    /// `None` when unordered, or when digits are used.
+    ///
+    /// Only set when
+    /// [`ordered_list_letters`][crate::ParseOptions::ordered_list_letters] is
+    /// on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub kind: Option<OrderedListKind>,
     /// One or more of its children are separated with a blank line from its
     /// siblings (when `true`), or not (when `false`).
     pub spread: bool,
+    /// Markers used.
+    ///
+    /// Only set when [`mdast_markers`][crate::ParseOptions::mdast_markers]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub markers: Option<Markers>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// List item.
@@ -716,6 +1232,19 @@ pub struct ListItem {
     /// or indeterminate or not applicable (`None`).
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub checked: Option<bool>,
+    /// Markers used.
+    ///
+    /// Only set when [`mdast_markers`][crate::ParseOptions::mdast_markers]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub markers: Option<Markers>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Html (flow or phrasing).
@@ -733,6 +1262,20 @@ pub struct Html {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Kind of HTML.
+    ///
+    /// Only set when [`mdast_html_kind`][crate::ParseOptions::mdast_html_kind]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub kind: Option<HtmlKind>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Code (flow).
@@ -761,6 +1304,19 @@ pub struct Code {
     /// Custom info relating to the node.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub meta: Option<String>,
+    /// Markers used.
+    ///
+    /// Only set when [`mdast_markers`][crate::ParseOptions::mdast_markers]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub markers: Option<Markers>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Math (flow).
@@ -786,6 +1342,13 @@ pub struct Math {
     /// Custom info relating to the node.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub meta: Option<String>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Definition.
@@ -822,6 +1385,14 @@ pub struct Definition {
     /// case-folding.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub label: Option<String>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Text.
@@ -839,6 +1410,14 @@ pub struct Text {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Emphasis.
@@ -856,6 +1435,20 @@ pub struct Emphasis {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Markers used.
+    ///
+    /// Only set when [`mdast_markers`][crate::ParseOptions::mdast_markers]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub markers: Option<Markers>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Strong.
@@ -873,6 +1466,20 @@ pub struct Strong {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Markers used.
+    ///
+    /// Only set when [`mdast_markers`][crate::ParseOptions::mdast_markers]
+    /// is on.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub markers: Option<Markers>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Code (phrasing).
@@ -890,6 +1497,14 @@ pub struct InlineCode {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Math (phrasing).
@@ -907,6 +1522,14 @@ pub struct InlineMath {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Break.
@@ -923,6 +1546,14 @@ pub struct Break {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Link.
@@ -947,6 +1578,14 @@ pub struct Link {
     /// appropriate for a tooltip.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub title: Option<String>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Image.
@@ -973,6 +1612,14 @@ pub struct Image {
     /// appropriate for a tooltip.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub title: Option<String>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Link reference.
@@ -1008,6 +1655,14 @@ pub struct LinkReference {
     /// case-folding.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub label: Option<String>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Image reference.
@@ -1045,6 +1700,14 @@ pub struct ImageReference {
     /// case-folding.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub label: Option<String>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// GFM: footnote definition.
@@ -1076,6 +1739,14 @@ pub struct FootnoteDefinition {
     /// case-folding.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub label: Option<String>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// GFM: footnote reference.
@@ -1105,6 +1776,14 @@ pub struct FootnoteReference {
     /// case-folding.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub label: Option<String>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// GFM: table.
@@ -1127,6 +1806,13 @@ pub struct Table {
     // Extra.
     /// Represents how cells in columns are aligned.
     pub align: Vec<AlignKind>,
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// GFM: table row.
@@ -1144,6 +1830,14 @@ pub struct TableRow {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// GFM: table cell.
@@ -1161,6 +1855,14 @@ pub struct TableCell {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// GFM: delete.
@@ -1178,6 +1880,14 @@ pub struct Delete {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Frontmatter: yaml.
@@ -1199,6 +1909,14 @@ pub struct Yaml {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// Frontmatter: toml.
@@ -1220,6 +1938,14 @@ pub struct Toml {
     /// Positional info.
     #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     pub position: Option<Position>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// MDX: ESM.
@@ -1241,6 +1967,14 @@ pub struct MdxjsEsm {
     // Custom data on where each slice of `value` came from.
     #[cfg_attr(feature = "serde", serde(rename = "_markdownRsStops"))]
     pub stops: Vec<Stop>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// MDX: expression (flow).
@@ -1262,6 +1996,14 @@ pub struct MdxFlowExpression {
     // Custom data on where each slice of `value` came from.
     #[cfg_attr(feature = "serde", serde(rename = "_markdownRsStops"))]
     pub stops: Vec<Stop>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// MDX: expression (text).
@@ -1283,6 +2025,14 @@ pub struct MdxTextExpression {
     // Custom data on where each slice of `value` came from.
     #[cfg_attr(feature = "serde", serde(rename = "_markdownRsStops"))]
     pub stops: Vec<Stop>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// MDX: JSX element (container).
@@ -1312,6 +2062,14 @@ pub struct MdxJsxFlowElement {
     pub name: Option<String>,
     /// Attributes.
     pub attributes: Vec<AttributeContent>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// MDX: JSX element (text).
@@ -1341,6 +2099,14 @@ pub struct MdxJsxTextElement {
     pub name: Option<String>,
     /// Attributes.
     pub attributes: Vec<AttributeContent>,
+    // Extra.
+    /// Source of the node.
+    ///
+    /// Only set when [`preserve`][crate::ParseOptions::preserve] is on, and
+    /// then not on nodes in containers (such as block quotes) that span
+    /// several lines, as their source includes the prefixes of containers.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub raw: Option<String>,
 }
 
 /// MDX: JSX attribute.
@@ -1358,7 +2124,10 @@ pub struct MdxJsxTextElement {
 pub struct MdxJsxAttribute {
     // Void.
     /// Positional info.
-    // pub position: Option<Position>,
+    ///
+    /// From the start of the name to the end of the value, if any.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub position: Option<Position>,
     /// Key.
     pub name: String,
     /// Value.
@@ -1379,6 +2148,12 @@ pub struct MdxJsxAttribute {
     serde(tag = "type", rename = "mdxJsxExpressionAttribute")
 )]
 pub struct MdxJsxExpressionAttribute {
+    // Void.
+    /// Positional info.
+    ///
+    /// From the opening brace to the closing brace, both including.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    pub position: Option<Position>,
     /// Value.
     pub value: String,
     /// Stops
@@ -1399,11 +2174,12 @@ mod tests {
         let mut node = Node::Text(Text {
             value: "a".into(),
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Text { value: \"a\", position: None }",
+            "Text { value: \"a\", position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1414,7 +2190,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Text { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Text { value: \"a\", position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1424,11 +2200,12 @@ mod tests {
         let mut node = Node::InlineCode(InlineCode {
             value: "a".into(),
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "InlineCode { value: \"a\", position: None }",
+            "InlineCode { value: \"a\", position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1439,7 +2216,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "InlineCode { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "InlineCode { value: \"a\", position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1451,11 +2228,13 @@ mod tests {
             position: None,
             lang: None,
             meta: None,
+            markers: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Code { value: \"a\", position: None, lang: None, meta: None }",
+            "Code { value: \"a\", position: None, lang: None, meta: None, markers: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1466,7 +2245,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Code { value: \"a\", position: Some(1:1-1:2 (0-1)), lang: None, meta: None }",
+            "Code { value: \"a\", position: Some(1:1-1:2 (0-1)), lang: None, meta: None, markers: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1476,11 +2255,12 @@ mod tests {
         let mut node = Node::InlineMath(InlineMath {
             value: "a".into(),
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "InlineMath { value: \"a\", position: None }",
+            "InlineMath { value: \"a\", position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1491,7 +2271,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "InlineMath { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "InlineMath { value: \"a\", position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1502,11 +2282,12 @@ mod tests {
             value: "a".into(),
             position: None,
             meta: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Math { value: \"a\", position: None, meta: None }",
+            "Math { value: \"a\", position: None, meta: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1517,7 +2298,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Math { value: \"a\", position: Some(1:1-1:2 (0-1)), meta: None }",
+            "Math { value: \"a\", position: Some(1:1-1:2 (0-1)), meta: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1527,11 +2308,13 @@ mod tests {
         let mut node = Node::Html(Html {
             value: "a".into(),
             position: None,
+            kind: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Html { value: \"a\", position: None }",
+            "Html { value: \"a\", position: None, kind: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1542,7 +2325,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Html { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Html { value: \"a\", position: Some(1:1-1:2 (0-1)), kind: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1553,11 +2336,12 @@ mod tests {
             value: "a".into(),
             stops: vec![],
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxTextExpression { value: \"a\", position: None, stops: [] }",
+            "MdxTextExpression { value: \"a\", position: None, stops: [], raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1568,7 +2352,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxTextExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "MdxTextExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [], raw: None }",
             "should support `position_set`"
         );
     }
@@ -1579,11 +2363,12 @@ mod tests {
             value: "a".into(),
             stops: vec![],
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxFlowExpression { value: \"a\", position: None, stops: [] }",
+            "MdxFlowExpression { value: \"a\", position: None, stops: [], raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1594,7 +2379,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxFlowExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "MdxFlowExpression { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [], raw: None }",
             "should support `position_set`"
         );
     }
@@ -1605,11 +2390,12 @@ mod tests {
             value: "a".into(),
             stops: vec![],
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxjsEsm { value: \"a\", position: None, stops: [] }",
+            "MdxjsEsm { value: \"a\", position: None, stops: [], raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1620,7 +2406,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxjsEsm { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [] }",
+            "MdxjsEsm { value: \"a\", position: Some(1:1-1:2 (0-1)), stops: [], raw: None }",
             "should support `position_set`"
         );
     }
@@ -1630,11 +2416,12 @@ mod tests {
         let mut node = Node::Toml(Toml {
             value: "a".into(),
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Toml { value: \"a\", position: None }",
+            "Toml { value: \"a\", position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1645,7 +2432,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Toml { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Toml { value: \"a\", position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1655,11 +2442,12 @@ mod tests {
         let mut node = Node::Yaml(Yaml {
             value: "a".into(),
             position: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Yaml { value: \"a\", position: None }",
+            "Yaml { value: \"a\", position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "a", "should support `ToString`");
@@ -1670,7 +2458,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Yaml { value: \"a\", position: Some(1:1-1:2 (0-1)) }",
+            "Yaml { value: \"a\", position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1679,11 +2467,14 @@ mod tests {
 
     #[test]
     fn break_node() {
-        let mut node = Node::Break(Break { position: None });
+        let mut node = Node::Break(Break {
+            position: None,
+            raw: None,
+        });
 
         assert_eq!(
             format!("{:?}", node),
-            "Break { position: None }",
+            "Break { position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1694,18 +2485,22 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Break { position: Some(1:1-1:2 (0-1)) }",
+            "Break { position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
 
     #[test]
     fn thematic_break() {
-        let mut node = Node::ThematicBreak(ThematicBreak { position: None });
+        let mut node = Node::ThematicBreak(ThematicBreak {
+            position: None,
+            markers: None,
+            raw: None,
+        });
 
         assert_eq!(
             format!("{:?}", node),
-            "ThematicBreak { position: None }",
+            "ThematicBreak { position: None, markers: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1716,7 +2511,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "ThematicBreak { position: Some(1:1-1:2 (0-1)) }",
+            "ThematicBreak { position: Some(1:1-1:2 (0-1)), markers: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1727,11 +2522,12 @@ mod tests {
             position: None,
             identifier: "a".into(),
             label: Some("b".into()),
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteReference { position: None, identifier: \"a\", label: Some(\"b\") }",
+            "FootnoteReference { position: None, identifier: \"a\", label: Some(\"b\"), raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1742,7 +2538,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteReference { position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: Some(\"b\") }",
+            "FootnoteReference { position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: Some(\"b\"), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1755,11 +2551,12 @@ mod tests {
             identifier: "b".into(),
             label: Some("c".into()),
             reference_kind: ReferenceKind::Full,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "ImageReference { position: None, alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\") }",
+            "ImageReference { position: None, alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\"), raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1770,7 +2567,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "ImageReference { position: Some(1:1-1:2 (0-1)), alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\") }",
+            "ImageReference { position: Some(1:1-1:2 (0-1)), alt: \"a\", reference_kind: Full, identifier: \"b\", label: Some(\"c\"), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1782,11 +2579,12 @@ mod tests {
             alt: "a".into(),
             url: "b".into(),
             title: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Image { position: None, alt: \"a\", url: \"b\", title: None }",
+            "Image { position: None, alt: \"a\", url: \"b\", title: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1797,7 +2595,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Image { position: Some(1:1-1:2 (0-1)), alt: \"a\", url: \"b\", title: None }",
+            "Image { position: Some(1:1-1:2 (0-1)), alt: \"a\", url: \"b\", title: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1810,11 +2608,12 @@ mod tests {
             label: None,
             url: "b".into(),
             title: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Definition { position: None, url: \"b\", title: None, identifier: \"a\", label: None }",
+            "Definition { position: None, url: \"b\", title: None, identifier: \"a\", label: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1825,7 +2624,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Definition { position: Some(1:1-1:2 (0-1)), url: \"b\", title: None, identifier: \"a\", label: None }",
+            "Definition { position: Some(1:1-1:2 (0-1)), url: \"b\", title: None, identifier: \"a\", label: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1837,11 +2636,12 @@ mod tests {
         let mut node = Node::Root(Root {
             position: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Root { children: [], position: None }",
+            "Root { children: [], position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1856,7 +2656,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Root { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Root { children: [], position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1866,11 +2666,12 @@ mod tests {
         let mut node = Node::Blockquote(Blockquote {
             position: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Blockquote { children: [], position: None }",
+            "Blockquote { children: [], position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1885,7 +2686,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Blockquote { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Blockquote { children: [], position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1895,11 +2696,12 @@ mod tests {
         let mut node = Node::Delete(Delete {
             position: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Delete { children: [], position: None }",
+            "Delete { children: [], position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1914,7 +2716,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Delete { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Delete { children: [], position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -1924,11 +2726,13 @@ mod tests {
         let mut node = Node::Emphasis(Emphasis {
             position: None,
             children: vec![],
+            markers: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Emphasis { children: [], position: None }",
+            "Emphasis { children: [], position: None, markers: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1943,7 +2747,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Emphasis { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Emphasis { children: [], position: Some(1:1-1:2 (0-1)), markers: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1953,11 +2757,13 @@ mod tests {
         let mut node = Node::Strong(Strong {
             position: None,
             children: vec![],
+            markers: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Strong { children: [], position: None }",
+            "Strong { children: [], position: None, markers: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -1972,7 +2778,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Strong { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Strong { children: [], position: Some(1:1-1:2 (0-1)), markers: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -1982,11 +2788,12 @@ mod tests {
         let mut node = Node::Paragraph(Paragraph {
             position: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Paragraph { children: [], position: None }",
+            "Paragraph { children: [], position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2001,9 +2808,26 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Paragraph { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "Paragraph { children: [], position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
+        assert_eq!(node.raw(), None, "should support `raw`");
+        node.raw_set(Some("a".into()));
+        assert_eq!(
+            node.raw_mut(),
+            Some(&mut "a".into()),
+            "should support `raw_set`, `raw_mut`"
+        );
+        assert_eq!(
+            Node::Text(Text {
+                value: "a".into(),
+                position: None,
+                raw: None,
+            })
+            .raw(),
+            None,
+            "should support `raw` on nodes w/o it"
+        );
     }
 
     #[test]
@@ -2011,11 +2835,12 @@ mod tests {
         let mut node = Node::TableRow(TableRow {
             position: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "TableRow { children: [], position: None }",
+            "TableRow { children: [], position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2030,7 +2855,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "TableRow { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "TableRow { children: [], position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -2040,11 +2865,12 @@ mod tests {
         let mut node = Node::TableCell(TableCell {
             position: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "TableCell { children: [], position: None }",
+            "TableCell { children: [], position: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2059,7 +2885,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "TableCell { children: [], position: Some(1:1-1:2 (0-1)) }",
+            "TableCell { children: [], position: Some(1:1-1:2 (0-1)), raw: None }",
             "should support `position_set`"
         );
     }
@@ -2070,11 +2896,13 @@ mod tests {
             position: None,
             depth: 1,
             children: vec![],
+            markers: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Heading { children: [], position: None, depth: 1 }",
+            "Heading { children: [], position: None, depth: 1, markers: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2089,7 +2917,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Heading { children: [], position: Some(1:1-1:2 (0-1)), depth: 1 }",
+            "Heading { children: [], position: Some(1:1-1:2 (0-1)), depth: 1, markers: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -2100,11 +2928,12 @@ mod tests {
             position: None,
             align: vec![],
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Table { children: [], position: None, align: [] }",
+            "Table { children: [], position: None, align: [], raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2119,7 +2948,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Table { children: [], position: Some(1:1-1:2 (0-1)), align: [] }",
+            "Table { children: [], position: Some(1:1-1:2 (0-1)), align: [], raw: None }",
             "should support `position_set`"
         );
     }
@@ -2131,11 +2960,13 @@ mod tests {
             spread: false,
             checked: None,
             children: vec![],
+            markers: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "ListItem { children: [], position: None, spread: false, checked: None }",
+            "ListItem { children: [], position: None, spread: false, checked: None, markers: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2150,7 +2981,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "ListItem { children: [], position: Some(1:1-1:2 (0-1)), spread: false, checked: None }",
+            "ListItem { children: [], position: Some(1:1-1:2 (0-1)), spread: false, checked: None, markers: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -2162,12 +2993,15 @@ mod tests {
             spread: false,
             ordered: false,
             start: None,
+            kind: None,
             children: vec![],
+            markers: None,
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "List { children: [], position: None, ordered: false, start: None, spread: false }",
+            "List { children: [], position: None, ordered: false, start: None, kind: None, spread: false, markers: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2182,7 +3016,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "List { children: [], position: Some(1:1-1:2 (0-1)), ordered: false, start: None, spread: false }",
+            "List { children: [], position: Some(1:1-1:2 (0-1)), ordered: false, start: None, kind: None, spread: false, markers: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -2195,11 +3029,12 @@ mod tests {
             label: None,
             reference_kind: ReferenceKind::Full,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "LinkReference { children: [], position: None, reference_kind: Full, identifier: \"a\", label: None }",
+            "LinkReference { children: [], position: None, reference_kind: Full, identifier: \"a\", label: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2214,7 +3049,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "LinkReference { children: [], position: Some(1:1-1:2 (0-1)), reference_kind: Full, identifier: \"a\", label: None }",
+            "LinkReference { children: [], position: Some(1:1-1:2 (0-1)), reference_kind: Full, identifier: \"a\", label: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -2226,11 +3061,12 @@ mod tests {
             url: "a".into(),
             title: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "Link { children: [], position: None, url: \"a\", title: None }",
+            "Link { children: [], position: None, url: \"a\", title: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2245,7 +3081,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "Link { children: [], position: Some(1:1-1:2 (0-1)), url: \"a\", title: None }",
+            "Link { children: [], position: Some(1:1-1:2 (0-1)), url: \"a\", title: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -2257,11 +3093,12 @@ mod tests {
             identifier: "a".into(),
             label: None,
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteDefinition { children: [], position: None, identifier: \"a\", label: None }",
+            "FootnoteDefinition { children: [], position: None, identifier: \"a\", label: None, raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2276,7 +3113,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "FootnoteDefinition { children: [], position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: None }",
+            "FootnoteDefinition { children: [], position: Some(1:1-1:2 (0-1)), identifier: \"a\", label: None, raw: None }",
             "should support `position_set`"
         );
     }
@@ -2288,11 +3125,12 @@ mod tests {
             name: None,
             attributes: vec![],
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxJsxFlowElement { children: [], position: None, name: None, attributes: [] }",
+            "MdxJsxFlowElement { children: [], position: None, name: None, attributes: [], raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2307,7 +3145,7 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxJsxFlowElement { children: [], position: Some(1:1-1:2 (0-1)), name: None, attributes: [] }",
+            "MdxJsxFlowElement { children: [], position: Some(1:1-1:2 (0-1)), name: None, attributes: [], raw: None }",
             "should support `position_set`"
         );
     }
@@ -2319,11 +3157,12 @@ mod tests {
             name: None,
             attributes: vec![],
             children: vec![],
+            raw: None,
         });
 
         assert_eq!(
             format!("{:?}", node),
-            "MdxJsxTextElement { children: [], position: None, name: None, attributes: [] }",
+            "MdxJsxTextElement { children: [], position: None, name: None, attributes: [], raw: None }",
             "should support `Debug`"
         );
         assert_eq!(node.to_string(), "", "should support `ToString`");
@@ -2338,8 +3177,115 @@ mod tests {
         node.position_set(Some(Position::new(1, 1, 0, 1, 2, 1)));
         assert_eq!(
             format!("{:?}", node),
-            "MdxJsxTextElement { children: [], position: Some(1:1-1:2 (0-1)), name: None, attributes: [] }",
+            "MdxJsxTextElement { children: [], position: Some(1:1-1:2 (0-1)), name: None, attributes: [], raw: None }",
             "should support `position_set`"
         );
     }
+
+    #[test]
+    fn eq_ignore_position() {
+        let options = crate::ParseOptions::gfm();
+        let a = crate::to_mdast(
+            "# a *b*\n\n* c\n* [d](e \"f\")\n\n| g |\n| - |\n| h |\n",
+            &options,
+        )
+        .unwrap();
+        let b = crate::to_mdast(
+            "#   a *b*   #\r\n\r\n\r\n-   c\r\n-   [d](  e  'f'  )\r\n\r\n|g|\r\n|:-:|\r\n|h|",
+            &options,
+        )
+        .unwrap();
+        let c = crate::to_mdast("# a _b_\n\n* c\n* [d](e \"g\")\n", &options).unwrap();
+
+        assert_ne!(a, b, "should differ in position");
+        assert!(
+            !a.eq_ignore_position(&b),
+            "should not be equal if other fields differ (table align)"
+        );
+
+        let b = crate::to_mdast(
+            "#   a *b*   #\r\n\r\n\r\n-   c\r\n-   [d](  e  'f'  )\r\n\r\n|g|\r\n|-|\r\n|h|",
+            &options,
+        )
+        .unwrap();
+
+        assert_ne!(a, b, "should differ in position (2)");
+        assert!(
+            a.eq_ignore_position(&b),
+            "should be equal ignoring position for differently formatted sources"
+        );
+        assert!(
+            b.eq_ignore_position(&a),
+            "should be equal ignoring position (symmetric)"
+        );
+        assert!(
+            !a.eq_ignore_position(&c),
+            "should not be equal if children differ"
+        );
+
+        let mut d = a.clone();
+        d.children_mut().unwrap().pop();
+        assert!(
+            !a.eq_ignore_position(&d),
+            "should not be equal if there are fewer children"
+        );
+
+        assert!(
+            !Node::Break(Break {
+                position: None,
+                raw: None
+            })
+            .eq_ignore_position(&Node::ThematicBreak(ThematicBreak {
+                position: None,
+                markers: None,
+                raw: None,
+            })),
+            "should not be equal if the node types differ"
+        );
+
+        let letters = crate::ParseOptions {
+            ordered_list_letters: true,
+            ..crate::ParseOptions::default()
+        };
+        assert!(
+            !crate::to_mdast("c) d", &letters)
+                .unwrap()
+                .eq_ignore_position(&crate::to_mdast("3) d", &letters).unwrap()),
+            "should not be equal if the kinds of lists differ"
+        );
+
+        let mdx = crate::ParseOptions::mdx();
+        assert!(
+            crate::to_mdast("<a b={1} c>{2}</a>", &mdx)
+                .unwrap()
+                .eq_ignore_position(&crate::to_mdast("\n\n<a  b={1}  c >{2}</a>", &mdx).unwrap()),
+            "should not compare stops"
+        );
+
+        let kind = crate::ParseOptions {
+            mdast_html_kind: true,
+            ..crate::ParseOptions::default()
+        };
+        assert!(
+            !crate::to_mdast("<!--a-->", &kind)
+                .unwrap()
+                .eq_ignore_position(
+                    &crate::to_mdast("<!--a-->", &crate::ParseOptions::default()).unwrap()
+                ),
+            "should not be equal if the kinds of html differ"
+        );
+
+        assert!(
+            crate::to_mdast(
+                "*a*",
+                &crate::ParseOptions {
+                    mdast_markers: true,
+                    ..crate::ParseOptions::default()
+                }
+            )
+            .unwrap()
+            .eq_ignore_position(&crate::to_mdast("_a_", &crate::ParseOptions::default()).unwrap()),
+            "should not compare markers"
+        );
+    }
 }
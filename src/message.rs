@@ -8,8 +8,12 @@ pub struct Message {
     /// Reason for message (should use markdown).
     pub reason: String,
     /// Category of message.
+    ///
+    /// Stable: see [`rules`][] for the values used by `markdown-rs`.
     pub rule_id: Box<String>,
     /// Namespace of message.
+    ///
+    /// Stable: [`rules::SOURCE`][] for messages made by `markdown-rs`.
     pub source: Box<String>,
 }
 
@@ -17,12 +21,15 @@ impl fmt::Display for Message {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Some(ref place) = self.place {
             write!(f, "{}: ", place)?;
-        };
+        }
 
         write!(f, "{} ({}:{})", self.reason, self.source, self.rule_id)
     }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for Message {}
+
 /// Somewhere.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Place {
@@ -44,3 +51,153 @@ impl fmt::Display for Place {
         }
     }
 }
+
+/// Values of [`Message::rule_id`][] for messages made by `markdown-rs`.
+///
+/// These values are stable: they do not change when the wording of reasons
+/// changes, so they can be matched on.
+/// Messages from the MDX parsers passed in options
+/// ([`mdx_expression_parse`][crate::ParseOptions::mdx_expression_parse],
+/// [`mdx_esm_parse`][crate::ParseOptions::mdx_esm_parse]) instead have the
+/// rule and source that those parsers signal.
+pub mod rules {
+    /// Source of messages made by `markdown-rs`.
+    pub const SOURCE: &str = "markdown-rs";
+
+    // MDX: expressions.
+    /// Unexpected end of file in an expression.
+    pub const UNEXPECTED_EOF_IN_EXPRESSION: &str = "unexpected-eof-in-expression";
+    /// Unexpected lazy line in a flow expression in a container.
+    pub const UNEXPECTED_LAZY_IN_EXPRESSION: &str = "unexpected-lazy-in-expression";
+
+    // MDX: JSX.
+    /// Unexpected lazy line in a flow JSX tag in a container.
+    pub const UNEXPECTED_LAZY_IN_JSX: &str = "unexpected-lazy-in-jsx";
+    /// Unexpected character before name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_BEFORE_NAME: &str = "unexpected-character-before-name";
+    /// Unexpected character in name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_IN_NAME: &str = "unexpected-character-in-name";
+    /// Unexpected character after name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_AFTER_NAME: &str = "unexpected-character-after-name";
+    /// Unexpected character before member name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_BEFORE_MEMBER_NAME: &str =
+        "unexpected-character-before-member-name";
+    /// Unexpected character in member name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_IN_MEMBER_NAME: &str = "unexpected-character-in-member-name";
+    /// Unexpected character after member name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_AFTER_MEMBER_NAME: &str =
+        "unexpected-character-after-member-name";
+    /// Unexpected character before local name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_BEFORE_LOCAL_NAME: &str =
+        "unexpected-character-before-local-name";
+    /// Unexpected character in local name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_IN_LOCAL_NAME: &str = "unexpected-character-in-local-name";
+    /// Unexpected character after local name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_AFTER_LOCAL_NAME: &str = "unexpected-character-after-local-name";
+    /// Unexpected character before attribute name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_BEFORE_ATTRIBUTE_NAME: &str =
+        "unexpected-character-before-attribute-name";
+    /// Unexpected character in attribute name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_IN_ATTRIBUTE_NAME: &str =
+        "unexpected-character-in-attribute-name";
+    /// Unexpected character after attribute name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_AFTER_ATTRIBUTE_NAME: &str =
+        "unexpected-character-after-attribute-name";
+    /// Unexpected character before local attribute name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_BEFORE_LOCAL_ATTRIBUTE_NAME: &str =
+        "unexpected-character-before-local-attribute-name";
+    /// Unexpected character in local attribute name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_IN_LOCAL_ATTRIBUTE_NAME: &str =
+        "unexpected-character-in-local-attribute-name";
+    /// Unexpected character after local attribute name in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_AFTER_LOCAL_ATTRIBUTE_NAME: &str =
+        "unexpected-character-after-local-attribute-name";
+    /// Unexpected character before attribute value in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_BEFORE_ATTRIBUTE_VALUE: &str =
+        "unexpected-character-before-attribute-value";
+    /// Unexpected character in attribute value in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_IN_ATTRIBUTE_VALUE: &str =
+        "unexpected-character-in-attribute-value";
+    /// Unexpected character after self-closing slash in a JSX tag.
+    pub const UNEXPECTED_CHARACTER_AFTER_SELF_CLOSING_SLASH: &str =
+        "unexpected-character-after-self-closing-slash";
+    /// Unexpected end of file before name in a JSX tag.
+    pub const UNEXPECTED_EOF_BEFORE_NAME: &str = "unexpected-eof-before-name";
+    /// Unexpected end of file in name in a JSX tag.
+    pub const UNEXPECTED_EOF_IN_NAME: &str = "unexpected-eof-in-name";
+    /// Unexpected end of file after name in a JSX tag.
+    pub const UNEXPECTED_EOF_AFTER_NAME: &str = "unexpected-eof-after-name";
+    /// Unexpected end of file before member name in a JSX tag.
+    pub const UNEXPECTED_EOF_BEFORE_MEMBER_NAME: &str = "unexpected-eof-before-member-name";
+    /// Unexpected end of file in member name in a JSX tag.
+    pub const UNEXPECTED_EOF_IN_MEMBER_NAME: &str = "unexpected-eof-in-member-name";
+    /// Unexpected end of file after member name in a JSX tag.
+    pub const UNEXPECTED_EOF_AFTER_MEMBER_NAME: &str = "unexpected-eof-after-member-name";
+    /// Unexpected end of file before local name in a JSX tag.
+    pub const UNEXPECTED_EOF_BEFORE_LOCAL_NAME: &str = "unexpected-eof-before-local-name";
+    /// Unexpected end of file in local name in a JSX tag.
+    pub const UNEXPECTED_EOF_IN_LOCAL_NAME: &str = "unexpected-eof-in-local-name";
+    /// Unexpected end of file after local name in a JSX tag.
+    pub const UNEXPECTED_EOF_AFTER_LOCAL_NAME: &str = "unexpected-eof-after-local-name";
+    /// Unexpected end of file before attribute name in a JSX tag.
+    pub const UNEXPECTED_EOF_BEFORE_ATTRIBUTE_NAME: &str = "unexpected-eof-before-attribute-name";
+    /// Unexpected end of file in attribute name in a JSX tag.
+    pub const UNEXPECTED_EOF_IN_ATTRIBUTE_NAME: &str = "unexpected-eof-in-attribute-name";
+    /// Unexpected end of file after attribute name in a JSX tag.
+    pub const UNEXPECTED_EOF_AFTER_ATTRIBUTE_NAME: &str = "unexpected-eof-after-attribute-name";
+    /// Unexpected end of file before local attribute name in a JSX tag.
+    pub const UNEXPECTED_EOF_BEFORE_LOCAL_ATTRIBUTE_NAME: &str =
+        "unexpected-eof-before-local-attribute-name";
+    /// Unexpected end of file in local attribute name in a JSX tag.
+    pub const UNEXPECTED_EOF_IN_LOCAL_ATTRIBUTE_NAME: &str =
+        "unexpected-eof-in-local-attribute-name";
+    /// Unexpected end of file after local attribute name in a JSX tag.
+    pub const UNEXPECTED_EOF_AFTER_LOCAL_ATTRIBUTE_NAME: &str =
+        "unexpected-eof-after-local-attribute-name";
+    /// Unexpected end of file before attribute value in a JSX tag.
+    pub const UNEXPECTED_EOF_BEFORE_ATTRIBUTE_VALUE: &str = "unexpected-eof-before-attribute-value";
+    /// Unexpected end of file in attribute value in a JSX tag.
+    pub const UNEXPECTED_EOF_IN_ATTRIBUTE_VALUE: &str = "unexpected-eof-in-attribute-value";
+    /// Unexpected end of file after self-closing slash in a JSX tag.
+    pub const UNEXPECTED_EOF_AFTER_SELF_CLOSING_SLASH: &str =
+        "unexpected-eof-after-self-closing-slash";
+
+    // MDX: mdast.
+    /// Unexpected closing tag, such as one that does not match the opening tag.
+    pub const END_TAG_MISMATCH: &str = "end-tag-mismatch";
+    /// Unexpected attribute on a closing tag.
+    pub const UNEXPECTED_ATTRIBUTE: &str = "unexpected-attribute";
+    /// Unexpected closing tag (`</a>`) without an opening tag.
+    pub const UNEXPECTED_CLOSING_SLASH: &str = "unexpected-closing-slash";
+    /// Unexpected self-closing slash in a closing tag.
+    pub const UNEXPECTED_SELF_CLOSING_SLASH: &str = "unexpected-self-closing-slash";
+
+    // Options.
+    /// Unexpected options that cannot be used together.
+    pub const INVALID_OPTIONS: &str = "invalid-options";
+    /// Unexpected tag or attribute name in `element_overrides`.
+    pub const INVALID_ELEMENT_OVERRIDE: &str = "invalid-element-override";
+    /// Unexpected empty delimiter, in `to_mdast_multi()` and
+    /// `to_html_multi()`.
+    pub const INVALID_DELIMITER: &str = "invalid-delimiter";
+
+    // Compiling.
+    /// Unexpected HTML bigger than `max_output_size`.
+    pub const MAX_OUTPUT_SIZE: &str = "max-output-size";
+    /// Cannot write HTML to output.
+    pub const WRITE_ERROR: &str = "write-error";
+    /// Unexpected MDX node, in `mdast_to_html()`.
+    pub const UNEXPECTED_MDX_NODE: &str = "unexpected-mdx-node";
+    /// Unexpected unbalanced events, in `compile_events()`.
+    pub const UNBALANCED_EVENTS: &str = "unbalanced-events";
+    /// Unexpected content in a void event, in `compile_events()`.
+    pub const INVALID_VOID: &str = "invalid-void";
+    /// Unexpected invalid point of an event, in `compile_events()`.
+    pub const INVALID_POINT: &str = "invalid-point";
+    /// Unexpected structure of events, such as a link without a label, in
+    /// `compile_events()`.
+    pub const INVALID_STRUCTURE: &str = "invalid-structure";
+    /// Unexpected footnote call or definition in a footnote defined in
+    /// `extra_footnote_definitions`.
+    pub const NESTED_FOOTNOTE: &str = "nested-footnote";
+}
@@ -1,13 +1,19 @@
 //! Turn bytes of markdown into events.
 
-use crate::event::{Event, Point};
+use crate::construct::text;
+use crate::event::{Content, Event, Kind, Name, Point};
+use crate::mdast;
 use crate::message;
 use crate::state::{Name as StateName, State};
 use crate::subtokenize::subtokenize;
 use crate::tokenizer::Tokenizer;
 use crate::util::location::Location;
-use crate::ParseOptions;
+#[cfg(feature = "profile")]
+use crate::util::profile::Profiler;
+use crate::{to_html, to_mdast, CompileOptions, ParseOptions};
 use alloc::{string::String, vec, vec::Vec};
+#[cfg(feature = "profile")]
+use core::cell::RefCell;
 
 /// Info needed, in all content types, when parsing markdown.
 ///
@@ -25,6 +31,38 @@ pub struct ParseState<'a> {
     pub definitions: Vec<String>,
     /// Set of defined GFM footnote definition identifiers.
     pub gfm_footnote_definitions: Vec<String>,
+    /// Characters that can start something in text, when there are text
+    /// extensions.
+    pub text_markers: Vec<u8>,
+    /// Collector of statistics.
+    #[cfg(feature = "profile")]
+    pub profiler: RefCell<Profiler>,
+}
+
+impl<'a> ParseState<'a> {
+    /// Create info for `value`.
+    fn new(value: &'a str, options: &'a ParseOptions) -> ParseState<'a> {
+        let bytes = value.as_bytes();
+
+        ParseState {
+            options,
+            bytes,
+            location: if options.mdx_esm_parse.is_some() || options.mdx_expression_parse.is_some() {
+                Some(Location::new(bytes))
+            } else {
+                None
+            },
+            definitions: vec![],
+            gfm_footnote_definitions: vec![],
+            text_markers: if options.text_extensions.is_empty() {
+                vec![]
+            } else {
+                text::markers(options)
+            },
+            #[cfg(feature = "profile")]
+            profiler: RefCell::default(),
+        }
+    }
 }
 
 /// Turn a string of markdown into events.
@@ -34,33 +72,55 @@ pub fn parse<'a>(
     value: &'a str,
     options: &'a ParseOptions,
 ) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
-    let bytes = value.as_bytes();
-
-    let mut parse_state = ParseState {
-        options,
-        bytes,
-        location: if options.mdx_esm_parse.is_some() || options.mdx_expression_parse.is_some() {
-            Some(Location::new(bytes))
-        } else {
-            None
-        },
-        definitions: vec![],
-        gfm_footnote_definitions: vec![],
+    let start = Point {
+        line: 1,
+        column: 1,
+        index: 0,
+        vs: 0,
     };
+    parse_range(value, options, start, value.len(), vec![])
+}
 
+/// Turn a string of markdown into events, with the identifiers of GFM
+/// footnote definitions that are defined elsewhere.
+///
+/// Calls to those footnotes are recognized, as if they were defined in
+/// `value`.
+pub fn parse_with_gfm_footnote_definitions<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    gfm_footnote_definitions: Vec<String>,
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
     let start = Point {
         line: 1,
         column: 1,
         index: 0,
         vs: 0,
     };
+    parse_range(value, options, start, value.len(), gfm_footnote_definitions)
+}
+
+/// Turn a part of a string of markdown, from `start` to `end`, into events.
+///
+/// The part is parsed as a whole document: definitions outside it are not
+/// known.
+/// Points in events are relative to the whole `value`.
+/// `gfm_footnote_definitions` are identifiers of GFM footnote definitions
+/// that are defined elsewhere.
+pub fn parse_range<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    start: Point,
+    end: usize,
+    gfm_footnote_definitions: Vec<String>,
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
+    options.validate()?;
+    let mut parse_state = ParseState::new(value, options);
+    parse_state.gfm_footnote_definitions = gfm_footnote_definitions;
+    let from = start.index;
     let mut tokenizer = Tokenizer::new(start, &parse_state);
 
-    let state = tokenizer.push(
-        (0, 0),
-        (parse_state.bytes.len(), 0),
-        State::Next(StateName::DocumentStart),
-    );
+    let state = tokenizer.push((from, 0), (end, 0), State::Next(StateName::DocumentStart));
     let mut result = tokenizer.flush(state, true)?;
     let mut events = tokenizer.events;
 
@@ -74,6 +134,200 @@ pub fn parse<'a>(
             return Ok((events, parse_state));
         }
 
+        #[cfg(feature = "profile")]
+        parse_state.profiler.borrow_mut().pass();
+
         result = subtokenize(&mut events, &parse_state, None)?;
     }
 }
+
+/// Parsed markdown.
+///
+/// Made by [`parse()`][crate::parse].
+/// Can be compiled to HTML and to a syntax tree, as often as needed,
+/// without parsing again.
+#[derive(Debug)]
+pub struct Parsed<'a> {
+    /// Events.
+    events: Vec<Event>,
+    /// Bytes of the input value.
+    bytes: &'a [u8],
+    /// Configuration used when parsing.
+    options: &'a ParseOptions,
+}
+
+impl<'a> Parsed<'a> {
+    /// Parse `value`.
+    pub(crate) fn new(
+        value: &'a str,
+        options: &'a ParseOptions,
+    ) -> Result<Parsed<'a>, message::Message> {
+        let (events, parse_state) = parse(value, options)?;
+
+        Ok(Parsed {
+            events,
+            bytes: parse_state.bytes,
+            options,
+        })
+    }
+
+    /// Turn into HTML, like [`to_html_with_options()`][crate::to_html_with_options].
+    ///
+    /// [`extra_footnote_definitions`][CompileOptions::extra_footnote_definitions]
+    /// are not used, as calls to them must be known while parsing.
+    ///
+    /// ## Errors
+    ///
+    /// Errors when the HTML would become bigger than
+    /// [`max_output_size`][CompileOptions::max_output_size].
+    pub fn to_html(&self, options: &CompileOptions) -> Result<String, message::Message> {
+        let mut result = String::new();
+        self.to_html_to_writer(options, &mut result)?;
+        Ok(result)
+    }
+
+    /// Turn into HTML, written to `out`, like
+    /// [`to_html_to_writer()`][crate::to_html_to_writer].
+    ///
+    /// [`extra_footnote_definitions`][CompileOptions::extra_footnote_definitions]
+    /// are not used, as calls to them must be known while parsing.
+    ///
+    /// ## Errors
+    ///
+    /// Errors when writing to `out` fails, or when the HTML would become
+    /// bigger than [`max_output_size`][CompileOptions::max_output_size].
+    pub fn to_html_to_writer(
+        &self,
+        options: &CompileOptions,
+        out: &mut impl core::fmt::Write,
+    ) -> Result<(), message::Message> {
+        to_html::compile(&self.events, self.bytes, options, out)
+    }
+
+    /// Turn into a syntax tree, like [`to_mdast()`][crate::to_mdast].
+    ///
+    /// ## Errors
+    ///
+    /// Errors with MDX syntax errors that are found when compiling, such as
+    /// JSX closing tags that do not match their opening tags.
+    pub fn to_mdast(&self) -> Result<mdast::Node, message::Message> {
+        to_mdast::compile(&self.events, self.bytes, self.options)
+    }
+}
+
+/// Turn a string of markdown into the events of its frontmatter, if any.
+///
+/// Only the BOM and frontmatter constructs are tried at the start of the
+/// document.
+/// Tokenizing stops as soon as the closing fence is found, or when it is
+/// clear that there is no frontmatter.
+pub fn parse_frontmatter<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
+    let bytes = value.as_bytes();
+
+    let parse_state = ParseState {
+        options,
+        bytes,
+        location: None,
+        definitions: vec![],
+        gfm_footnote_definitions: vec![],
+        text_markers: vec![],
+        #[cfg(feature = "profile")]
+        profiler: RefCell::default(),
+    };
+
+    let start = Point {
+        line: 1,
+        column: 1,
+        index: 0,
+        vs: 0,
+    };
+    let mut tokenizer = Tokenizer::new(start, &parse_state);
+
+    // Whether there is frontmatter or not, we’re done afterwards.
+    tokenizer.attempt(State::Ok, State::Ok);
+    tokenizer.attempt(
+        State::Next(StateName::FrontmatterStart),
+        State::Next(StateName::FrontmatterStart),
+    );
+
+    let state = tokenizer.push(
+        (0, 0),
+        (parse_state.bytes.len(), 0),
+        State::Next(StateName::BomStart),
+    );
+    tokenizer.flush(state, false)?;
+    let events = tokenizer.events;
+
+    Ok((events, parse_state))
+}
+
+/// Turn a string of markdown into the events of one content type.
+///
+/// Only [string][Content::String] and [text][Content::Text] are supported:
+/// block constructs are not tried, and there are no definitions, so
+/// references are not turned into links.
+///
+/// The events are wrapped in a paragraph, so that they can be compiled like
+/// the phrasing of a document.
+pub fn parse_content<'a>(
+    value: &'a str,
+    options: &'a ParseOptions,
+    content: &Content,
+) -> Result<(Vec<Event>, ParseState<'a>), message::Message> {
+    debug_assert!(
+        matches!(content, Content::String | Content::Text),
+        "expected string or text"
+    );
+
+    options.validate()?;
+    let parse_state = ParseState::new(value, options);
+
+    let start = Point {
+        line: 1,
+        column: 1,
+        index: 0,
+        vs: 0,
+    };
+    let mut tokenizer = Tokenizer::new(start.clone(), &parse_state);
+
+    let state = tokenizer.push(
+        (0, 0),
+        (parse_state.bytes.len(), 0),
+        State::Next(if *content == Content::String {
+            StateName::StringStart
+        } else {
+            StateName::TextStart
+        }),
+    );
+    let mut result = tokenizer.flush(state, true)?;
+    let end = tokenizer.point.clone();
+    let mut content_events = tokenizer.events;
+
+    // Destinations and titles of resources are strings in text: they are
+    // parsed like in a whole document.
+    while !result.done {
+        #[cfg(feature = "profile")]
+        parse_state.profiler.borrow_mut().pass();
+
+        result = subtokenize(&mut content_events, &parse_state, None)?;
+    }
+
+    let mut events = vec![Event {
+        kind: Kind::Enter,
+        name: Name::Paragraph,
+        point: start,
+        link: None,
+    }];
+    events.append(&mut content_events);
+    events.push(Event {
+        kind: Kind::Exit,
+        name: Name::Paragraph,
+        point: end,
+        link: None,
+    });
+
+    Ok((events, parse_state))
+}
@@ -79,6 +79,7 @@ pub enum Name {
     CodeIndentedAtBreak,
     CodeIndentedAfter,
     CodeIndentedFurtherStart,
+    CodeIndentedFurtherLineStart,
     CodeIndentedInside,
     CodeIndentedFurtherBegin,
     CodeIndentedFurtherAfter,
@@ -127,6 +128,9 @@ pub enum Name {
     DocumentFlowInside,
     DocumentFlowEnd,
 
+    ExtensionTextStart,
+    ExtensionTextInside,
+
     FlowStart,
     FlowBeforeGfmTable,
     FlowBeforeCodeIndented,
@@ -320,6 +324,9 @@ pub enum Name {
     LabelEndReferenceCollapsed,
     LabelEndReferenceCollapsedOpen,
 
+    LabelStartFootnoteInlineStart,
+    LabelStartFootnoteInlineOpen,
+
     LabelStartImageStart,
     LabelStartImageOpen,
     LabelStartImageAfter,
@@ -448,6 +455,8 @@ pub enum Name {
 
     TextStart,
     TextBefore,
+    TextBeforeExtension,
+    TextBeforeBuiltin,
     TextBeforeHtml,
     TextBeforeMdxJsx,
     TextBeforeHardBreakEscape,
@@ -508,6 +517,7 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::CodeIndentedAtBreak => construct::code_indented::at_break,
         Name::CodeIndentedAfter => construct::code_indented::after,
         Name::CodeIndentedFurtherStart => construct::code_indented::further_start,
+        Name::CodeIndentedFurtherLineStart => construct::code_indented::further_line_start,
         Name::CodeIndentedInside => construct::code_indented::inside,
         Name::CodeIndentedFurtherBegin => construct::code_indented::further_begin,
         Name::CodeIndentedFurtherAfter => construct::code_indented::further_after,
@@ -564,6 +574,9 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::DocumentFlowEnd => construct::document::flow_end,
         Name::DocumentFlowInside => construct::document::flow_inside,
 
+        Name::ExtensionTextStart => construct::extension_text::start,
+        Name::ExtensionTextInside => construct::extension_text::inside,
+
         Name::FlowStart => construct::flow::start,
         Name::FlowBeforeGfmTable => construct::flow::before_gfm_table,
         Name::FlowBeforeCodeIndented => construct::flow::before_code_indented,
@@ -806,6 +819,9 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
         Name::LabelEndReferenceCollapsed => construct::label_end::reference_collapsed,
         Name::LabelEndReferenceCollapsedOpen => construct::label_end::reference_collapsed_open,
 
+        Name::LabelStartFootnoteInlineStart => construct::label_start_footnote_inline::start,
+        Name::LabelStartFootnoteInlineOpen => construct::label_start_footnote_inline::open,
+
         Name::LabelStartImageStart => construct::label_start_image::start,
         Name::LabelStartImageOpen => construct::label_start_image::open,
         Name::LabelStartImageAfter => construct::label_start_image::after,
@@ -947,6 +963,8 @@ pub fn call(tokenizer: &mut Tokenizer, name: Name) -> State {
 
         Name::TextStart => construct::text::start,
         Name::TextBefore => construct::text::before,
+        Name::TextBeforeExtension => construct::text::before_extension,
+        Name::TextBeforeBuiltin => construct::text::before_builtin,
         Name::TextBeforeHtml => construct::text::before_html,
         Name::TextBeforeMdxJsx => construct::text::before_mdx_jsx,
         Name::TextBeforeHardBreakEscape => construct::text::before_hard_break_escape,
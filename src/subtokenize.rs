@@ -16,6 +16,12 @@
 //! doing so due to definitions, which can occur after references, and thus the
 //! whole document needs to be parsed up to the level of definitions, before
 //! any level that can include references can be parsed.
+//!
+//! The checks on links here are debug assertions: constructs must only link
+//! void events of the same content type, for any input.
+//! If one of them fails, the bug is in the construct that made the link (or
+//! in how an attempt that made it was reverted), not here.
+//! The fuzz targets in `fuzz/` exercise these checks with all constructs on.
 
 use crate::event::{Content, Event, Kind, Name, VOID_EVENTS};
 use crate::message;
@@ -215,17 +221,14 @@ pub fn divide_events(
         // Fix sublinks.
         if let Some(sublink_curr) = &child_events[child_index].link {
             if sublink_curr.previous.is_some() {
-                let old_prev = old_prev.unwrap();
-                let prev_event = &mut child_events[old_prev];
+                let prev_event = &mut child_events[old_prev.unwrap()];
                 // The `index` in `events` where the current link is,
-                // minus one to get the previous link,
                 // minus 2 events (the enter and exit) for each removed
                 // link.
-                let new_link = if slices.is_empty() {
-                    old_prev + link_index + 2
-                } else {
-                    old_prev + link_index - (slices.len() - 1) * 2
-                };
+                // There can be other events between the previous link and
+                // this one (such as whitespace), so this is based on the
+                // current event, not the previous one.
+                let new_link = child_index + link_index - slices.len() * 2;
                 prev_event.link.as_mut().unwrap().next =
                     Some(new_link + acc_before.1 - acc_before.0);
             }
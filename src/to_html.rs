@@ -1,25 +1,37 @@
 //! Turn events into a string of HTML.
 use crate::event::{Event, Kind, Name};
-use crate::mdast::AlignKind;
+use crate::mdast::{AlignKind, ListItem, Node, OrderedListKind, ReferenceKind};
 use crate::util::{
     character_reference::decode as decode_character_reference,
     constant::{SAFE_PROTOCOL_HREF, SAFE_PROTOCOL_SRC},
-    encode::encode,
+    encode::{decode, encode},
+    frontmatter::{self, FrontmatterKind},
     gfm_tagfilter::gfm_tagfilter,
-    infer::{gfm_table_align, list_loose},
+    infer::{
+        exits, footnote_inline_identifiers, gfm_footnote_calls_undefined,
+        gfm_footnote_definition_order, gfm_table_align, list_kind, list_loose,
+    },
+    list_value,
     normalize_identifier::normalize_identifier,
-    sanitize_uri::{sanitize, sanitize_with_protocols},
+    sanitize_uri::{is_path_relative, resolve, sanitize, sanitize_with_protocols},
     skip,
     slice::{Position, Slice},
+    smart_punctuation::smart_punctuation,
+};
+use crate::{
+    message, parser::parse, BooleanAttributeStyle, CompileOptions, ElementOverride,
+    FootnoteNumbering, FrontmatterRendering, ImageInfo, LineEnding, Options, QuoteKind,
+    SelfClosingStyle,
 };
-use crate::{CompileOptions, LineEnding};
 use alloc::{
+    borrow::Cow,
+    boxed::Box,
     format,
     string::{String, ToString},
     vec,
     vec::Vec,
 };
-use core::str;
+use core::{fmt, str};
 
 /// Link, image, or footnote call.
 /// Resource or reference.
@@ -29,6 +41,9 @@ struct Media {
     /// Whether this represents an image (`true`) or a link or definition
     /// (`false`).
     image: bool,
+    /// Whether this represents an inline footnote, which, unlike links, can
+    /// contain links.
+    footnote_inline: bool,
     /// The text between the brackets (`x` in `![x]()` and `[x]()`).
     ///
     /// Not interpreted.
@@ -96,20 +111,40 @@ struct CompileContext<'a> {
     raw_text_inside: bool,
     /// Whether we are in image text.
     image_alt_inside: bool,
+    /// Whether we are in a string (such as a destination or title), where
+    /// punctuation is not made smart.
+    string_inside: bool,
+    /// Whether we are in a title.
+    title_inside: bool,
+    /// Whether we are in the text of a paragraph or setext heading.
+    text_inside: bool,
     /// Marker of character reference.
     character_reference_marker: Option<u8>,
     /// Whether we are expecting the first list item marker.
     list_expect_first_marker: Option<bool>,
-    /// Stack of media (link, image).
+    /// Kind of numbering of the ordered list whose first value is expected.
+    list_kind: Option<OrderedListKind>,
+    /// Stack of media (link, image, footnote call).
     media_stack: Vec<Media>,
     /// Stack of containers.
     tight_stack: Vec<bool>,
+    /// Where events exit, made when the first list is found.
+    list_exits: Vec<usize>,
     /// List of definitions.
     definitions: Vec<Definition>,
     /// List of definitions.
     gfm_footnote_definitions: Vec<(String, String)>,
     gfm_footnote_definition_calls: Vec<(String, usize)>,
     gfm_footnote_definition_stack: Vec<(usize, usize)>,
+    /// Identifiers of called footnotes in definition order, when numbering
+    /// by definition order.
+    gfm_footnote_definition_order: Option<Vec<String>>,
+    /// Identifiers of footnote definitions, when compiling a syntax tree.
+    gfm_footnote_definition_identifiers: Vec<String>,
+    /// Identifiers of inline footnotes, computed when the first one is found.
+    footnote_inline_identifiers: Vec<String>,
+    /// Number of inline footnotes seen.
+    footnote_inline_count: usize,
     /// Whether we are in a GFM table head.
     gfm_table_in_head: bool,
     /// Current GFM table alignment.
@@ -127,6 +162,13 @@ struct CompileContext<'a> {
     // Intermediate results.
     /// Stack of buffers.
     buffers: Vec<String>,
+    /// Last byte of what was already written out of the bottom buffer.
+    written_last_byte: Option<u8>,
+    /// Number of bytes already written out of the bottom buffer.
+    written_size: usize,
+    /// Whether pushing would have made the HTML bigger than
+    /// `max_output_size`.
+    max_output_size_exceeded: bool,
     /// Current event index.
     index: usize,
 }
@@ -147,22 +189,40 @@ impl<'a> CompileContext<'a> {
             raw_flow_seen_data: None,
             raw_flow_fences_count: None,
             raw_text_inside: false,
+            string_inside: false,
+            title_inside: false,
+            text_inside: false,
             character_reference_marker: None,
             list_expect_first_marker: None,
+            list_kind: None,
             media_stack: vec![],
             definitions: vec![],
             gfm_footnote_definitions: vec![],
             gfm_footnote_definition_calls: vec![],
             gfm_footnote_definition_stack: vec![],
+            gfm_footnote_definition_order: if options.gfm_footnote_numbering
+                == FootnoteNumbering::DefinitionOrder
+            {
+                Some(gfm_footnote_definition_order(events, bytes, &[]))
+            } else {
+                None
+            },
+            gfm_footnote_definition_identifiers: vec![],
+            footnote_inline_identifiers: vec![],
+            footnote_inline_count: 0,
             gfm_table_in_head: false,
             gfm_table_align: None,
             gfm_table_column: 0,
             tight_stack: vec![],
+            list_exits: vec![],
             slurp_one_line_ending: false,
             image_alt_inside: false,
             encode_html: true,
             line_ending_default: line_ending,
             buffers: vec![String::new()],
+            written_last_byte: None,
+            written_size: 0,
+            max_output_size_exceeded: false,
             index: 0,
             options,
         }
@@ -179,12 +239,100 @@ impl<'a> CompileContext<'a> {
     }
 
     /// Push a str to the last buffer.
+    ///
+    /// Does nothing if the HTML would become bigger than `max_output_size`.
     fn push(&mut self, value: &str) {
+        if self.max_output_size_exceeded {
+            return;
+        }
+
+        let value = if self.options.strip_control_characters {
+            strip_control_characters(value)
+        } else {
+            Cow::Borrowed(value)
+        };
+        let value = value.as_ref();
+
+        if let Some(max) = self.options.max_output_size {
+            let size = self.written_size
+                + self.buffers.iter().map(String::len).sum::<usize>()
+                + value.len();
+
+            if size > max {
+                self.max_output_size_exceeded = true;
+                return;
+            }
+        }
+
         let last_buf_opt = self.buffers.last_mut();
         let last_buf = last_buf_opt.expect("at least one buffer should exist");
         last_buf.push_str(value);
     }
 
+    /// Push an attribute (` name="value"`).
+    ///
+    /// `value` must already be encoded, except for single quotes.
+    fn push_attribute(&mut self, name: &str, value: &str) {
+        let (quote, value) = if self.options.attribute_quote == QuoteKind::Single {
+            ("'", value.replace('\'', "&#x27;"))
+        } else {
+            ("\"", value.into())
+        };
+        self.push(" ");
+        self.push(name);
+        self.push("=");
+        self.push(quote);
+        self.push(&value);
+        self.push(quote);
+    }
+
+    /// Push the opening tag of an element generated from markdown (`<name>`),
+    /// with the tag name and attributes of `element`, if given.
+    fn push_element_open(&mut self, name: &str, element: Option<&ElementOverride>) {
+        self.push("<");
+        self.push(element.and_then(|d| d.tag_name.as_deref()).unwrap_or(name));
+        if let Some(element) = element {
+            for (name, value) in &element.attributes {
+                self.push_attribute(name, &encode(value, true));
+            }
+        }
+        self.push(">");
+    }
+
+    /// Push the closing tag of an element generated from markdown
+    /// (`</name>`), with the tag name of `element`, if given.
+    fn push_element_close(&mut self, name: &str, element: Option<&ElementOverride>) {
+        self.push("</");
+        self.push(element.and_then(|d| d.tag_name.as_deref()).unwrap_or(name));
+        self.push(">");
+    }
+
+    /// Get the end of a void element (` />` or `>`).
+    fn self_closing(&self) -> &'static str {
+        match self.options.self_closing {
+            SelfClosingStyle::Xhtml => " />",
+            SelfClosingStyle::Html5 => ">",
+        }
+    }
+
+    /// Push the end of a void element (` />` or `>`).
+    fn push_self_closing(&mut self) {
+        let end = self.self_closing();
+        self.push(end);
+    }
+
+    /// Push a boolean attribute (` name=""`).
+    fn push_boolean_attribute(&mut self, name: &str) {
+        match self.options.boolean_attribute_style {
+            BooleanAttributeStyle::Empty => self.push_attribute(name, ""),
+            BooleanAttributeStyle::Repeat => self.push_attribute(name, name),
+            BooleanAttributeStyle::Omit => {
+                self.push(" ");
+                self.push(name);
+            }
+        }
+    }
+
     /// Add a line ending.
     fn line_ending(&mut self) {
         let eol = self.line_ending_default.as_str().to_string();
@@ -195,21 +343,91 @@ impl<'a> CompileContext<'a> {
     fn line_ending_if_needed(&mut self) {
         let last_buf_opt = self.buffers.last();
         let last_buf = last_buf_opt.expect("at least one buffer should exist");
-        let last_byte = last_buf.as_bytes().last();
+        let last_byte = if self.buffers.len() == 1 && last_buf.is_empty() {
+            self.written_last_byte
+        } else {
+            last_buf.as_bytes().last().copied()
+        };
 
         if !matches!(last_byte, None | Some(b'\n' | b'\r')) {
             self.line_ending();
         }
     }
+
+    /// Write what is in the bottom buffer out, if we are not in another
+    /// buffer.
+    fn write_out(&mut self, out: &mut impl fmt::Write) -> Result<(), message::Message> {
+        if self.max_output_size_exceeded {
+            return Err(message::Message {
+                place: None,
+                reason: format!(
+                    "Unexpected HTML bigger than `max_output_size` ({} bytes)",
+                    self.options.max_output_size.unwrap_or_default()
+                ),
+                rule_id: Box::new(message::rules::MAX_OUTPUT_SIZE.into()),
+                source: Box::new(message::rules::SOURCE.into()),
+            });
+        }
+
+        if self.buffers.len() == 1 {
+            let bottom = self.buffers.first_mut().expect("expected 1 buffer");
+
+            if !bottom.is_empty() {
+                out.write_str(bottom).map_err(|_| message::Message {
+                    place: None,
+                    reason: "Cannot write HTML to output".into(),
+                    rule_id: Box::new(message::rules::WRITE_ERROR.into()),
+                    source: Box::new(message::rules::SOURCE.into()),
+                })?;
+                self.written_last_byte = bottom.as_bytes().last().copied();
+                self.written_size += bottom.len();
+                bottom.clear();
+            }
+        }
+
+        Ok(())
+    }
 }
 
-/// Turn events and bytes into a string of HTML.
-pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> String {
+/// Turn events and bytes into HTML, written to `out`.
+///
+/// Output is written whenever we are not in a nested buffer (such as for a
+/// heading or a link label), so the whole document is never held in memory.
+///
+/// Errors when writing to `out` fails, or when the HTML would become bigger
+/// than `max_output_size`.
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    options: &CompileOptions,
+    out: &mut impl fmt::Write,
+) -> Result<(), message::Message> {
+    compile_with_gfm_footnote_definitions(events, bytes, options, vec![], out)
+}
+
+/// Turn events and bytes into HTML, written to `out`, with GFM footnote
+/// definitions that are defined elsewhere.
+///
+/// `gfm_footnote_definitions` are pairs of normalized identifiers and HTML,
+/// such as from [`compile_extra_gfm_footnote_definitions()`][].
+/// They are used for calls to footnotes that are not defined in the
+/// document.
+pub fn compile_with_gfm_footnote_definitions(
+    events: &[Event],
+    bytes: &[u8],
+    options: &CompileOptions,
+    mut gfm_footnote_definitions: Vec<(String, String)>,
+    out: &mut impl fmt::Write,
+) -> Result<(), message::Message> {
+    check_element_overrides(options)?;
+
     let mut index = 0;
     let mut line_ending_inferred = None;
 
     // First, we figure out what the used line ending style is.
     // Stop when we find a line ending.
+    // Only line endings in events are used, as `bytes` can contain other
+    // documents.
     while index < events.len() {
         let event = &events[index];
 
@@ -217,7 +435,7 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
             && (event.name == Name::BlankLineEnding || event.name == Name::LineEnding)
         {
             let slice = Slice::from_position(bytes, &Position::from_exit_event(events, index));
-            line_ending_inferred = Some(slice.as_str().parse().unwrap());
+            line_ending_inferred = LineEnding::detect(slice.as_str());
             break;
         }
 
@@ -229,42 +447,17 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
         line_ending_inferred.unwrap_or_else(|| options.default_line_ending.clone());
 
     let mut context = CompileContext::new(events, bytes, options, line_ending_default);
-    let mut definition_indices = vec![];
-    let mut index = 0;
-    let mut definition_inside = false;
-
-    // Handle all definitions first.
-    // We must do two passes because we need to compile the events in
-    // definitions which come after references already.
-    //
-    // To speed things up, we collect the places we can jump over for the
-    // second pass.
-    //
-    // We don’t need to handle GFM footnote definitions like this, because
-    // unlike normal definitions, what they produce is not used in calls.
-    // It would also get very complex, because footnote definitions can be
-    // nested.
-    while index < events.len() {
-        let event = &events[index];
-
-        if definition_inside {
-            handle(&mut context, index);
-        }
-
-        if event.kind == Kind::Enter {
-            if event.name == Name::Definition {
-                handle(&mut context, index); // Also handle start.
-                definition_inside = true;
-                definition_indices.push((index, index));
-            }
-        } else if event.name == Name::Definition {
-            definition_inside = false;
-            definition_indices.last_mut().unwrap().1 = index;
-        }
 
-        index += 1;
+    if !gfm_footnote_definitions.is_empty() && context.gfm_footnote_definition_order.is_some() {
+        let extra = gfm_footnote_definitions
+            .iter()
+            .map(|d| d.0.clone())
+            .collect::<Vec<_>>();
+        context.gfm_footnote_definition_order =
+            Some(gfm_footnote_definition_order(events, bytes, &extra));
     }
 
+    let definition_indices = handle_definitions(&mut context);
     let mut index = 0;
     let jump_default = (events.len(), events.len());
     let mut definition_index = 0;
@@ -281,27 +474,255 @@ pub fn compile(events: &[Event], bytes: &[u8], options: &CompileOptions) -> Stri
                 .unwrap_or(&jump_default);
         } else {
             handle(&mut context, index);
+            context.write_out(out)?;
             index += 1;
         }
     }
 
     // No section to generate.
     if !context.gfm_footnote_definition_calls.is_empty() {
+        // After the definitions in the document, so that those are found first.
+        context
+            .gfm_footnote_definitions
+            .append(&mut gfm_footnote_definitions);
         generate_footnote_section(&mut context);
     }
 
     debug_assert_eq!(context.buffers.len(), 1, "expected 1 final buffer");
-    context
-        .buffers
-        .first()
-        .expect("expected 1 final buffer")
-        .into()
+    context.write_out(out)
+}
+
+/// Get the normalized identifiers of
+/// [`extra_footnote_definitions`][CompileOptions::extra_footnote_definitions],
+/// which are needed when parsing to know about calls to them.
+pub fn extra_gfm_footnote_definition_identifiers(options: &CompileOptions) -> Vec<String> {
+    options
+        .extra_footnote_definitions
+        .iter()
+        .map(|(identifier, _)| normalize_identifier(identifier).into_owned())
+        .collect()
+}
+
+/// Turn [`extra_footnote_definitions`][CompileOptions::extra_footnote_definitions]
+/// that are called but not defined in a document (`events` and `bytes`) into
+/// pairs of normalized identifiers and HTML.
+///
+/// Each definition is parsed and compiled as a separate document.
+/// That document cannot have footnotes itself, as they would get a section
+/// and numbers of their own.
+/// Definitions that are not used are not compiled.
+pub fn compile_extra_gfm_footnote_definitions(
+    options: &Options,
+    events: &[Event],
+    bytes: &[u8],
+) -> Result<Vec<(String, String)>, message::Message> {
+    let mut undefined = gfm_footnote_calls_undefined(events, bytes);
+    let mut definitions = vec![];
+
+    for (identifier, value) in &options.compile.extra_footnote_definitions {
+        let id = normalize_identifier(identifier).into_owned();
+
+        // Removed once compiled, as the first definition is used.
+        if !undefined.remove(&id) {
+            continue;
+        }
+
+        let (events, parse_state) = parse(value, &options.parse)?;
+
+        if let Some(event) = events.iter().find(|event| {
+            matches!(
+                event.name,
+                Name::GfmFootnoteCall | Name::GfmFootnoteDefinition | Name::FootnoteInline
+            )
+        }) {
+            return Err(message::Message {
+                place: Some(Box::new(message::Place::Point(event.point.to_unist()))),
+                reason: format!(
+                    "Unexpected footnote in footnote `{}` in `extra_footnote_definitions`, expected no footnotes in it",
+                    identifier
+                ),
+                rule_id: Box::new(message::rules::NESTED_FOOTNOTE.into()),
+                source: Box::new(message::rules::SOURCE.into()),
+            });
+        }
+
+        let mut result = String::new();
+        compile(&events, parse_state.bytes, &options.compile, &mut result)?;
+        definitions.push((id, result));
+    }
+
+    Ok(definitions)
+}
+
+/// Check that the tag and attribute names in
+/// [`element_overrides`][CompileOptions::element_overrides] are valid.
+fn check_element_overrides(options: &CompileOptions) -> Result<(), message::Message> {
+    let overrides = &options.element_overrides;
+
+    for element in [
+        overrides.delete.as_ref(),
+        overrides.emphasis.as_ref(),
+        overrides.inline_code.as_ref(),
+        overrides.paragraph.as_ref(),
+        overrides.strong.as_ref(),
+    ]
+    .iter()
+    .flatten()
+    {
+        if let Some(ref tag_name) = element.tag_name {
+            if tag_name.is_empty() || !tag_name.bytes().all(|b| b.is_ascii_alphabetic()) {
+                return Err(element_override_message(
+                    &format!("tag name `{}`", tag_name),
+                    "ASCII letters",
+                ));
+            }
+        }
+
+        for (name, _) in &element.attributes {
+            if name.is_empty()
+                || !name
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b':'))
+            {
+                return Err(element_override_message(
+                    &format!("attribute name `{}`", name),
+                    "ASCII alphanumerics, `-`, `_`, or `:`",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a message for an invalid element override.
+fn element_override_message(found: &str, expected: &str) -> message::Message {
+    message::Message {
+        place: None,
+        reason: format!(
+            "Unexpected {} in `element_overrides`, expected {}",
+            found, expected
+        ),
+        rule_id: Box::new(message::rules::INVALID_ELEMENT_OVERRIDE.into()),
+        source: Box::new(message::rules::SOURCE.into()),
+    }
+}
+
+/// Handle all definitions, returning where they are.
+///
+/// We must do two passes because we need to compile the events in
+/// definitions which come after references already.
+///
+/// To speed things up, we collect the places we can jump over for the
+/// second pass.
+///
+/// We don’t need to handle GFM footnote definitions like this, because
+/// unlike normal definitions, what they produce is not used in calls.
+/// It would also get very complex, because footnote definitions can be
+/// nested.
+fn handle_definitions(context: &mut CompileContext) -> Vec<(usize, usize)> {
+    let mut definition_indices = vec![];
+    let mut index = 0;
+    let mut definition_inside = false;
+
+    while index < context.events.len() {
+        let event = &context.events[index];
+
+        if definition_inside {
+            handle(context, index);
+        }
+
+        if event.kind == Kind::Enter {
+            if event.name == Name::Definition {
+                handle(context, index); // Also handle start.
+                definition_inside = true;
+                definition_indices.push((index, index));
+            }
+        } else if event.name == Name::Definition {
+            definition_inside = false;
+            definition_indices.last_mut().unwrap().1 = index;
+        }
+
+        index += 1;
+    }
+
+    definition_indices
+}
+
+/// Turn the events in each range (`start` and `end`, exclusive) into plain
+/// text.
+///
+/// The text is made like the `alt` of images: tags are not generated, but
+/// the text of links, images, code, and such is, and character references
+/// are decoded.
+pub fn compile_text(events: &[Event], bytes: &[u8], ranges: &[(usize, usize)]) -> Vec<String> {
+    let options = CompileOptions::default();
+    let mut context = CompileContext::new(events, bytes, &options, LineEnding::default());
+    handle_definitions(&mut context);
+
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            // Act as if in an image.
+            context.media_stack.push(Media {
+                image: true,
+                footnote_inline: false,
+                label_id: None,
+                label: None,
+                reference_id: None,
+                destination: None,
+                title: None,
+            });
+            context.image_alt_inside = true;
+            context.buffer();
+
+            let mut index = start;
+            while index < end {
+                handle(&mut context, index);
+                index += 1;
+            }
+
+            let value = context.resume();
+            context.media_stack.pop();
+            context.image_alt_inside = false;
+            decode(&value)
+        })
+        .collect()
 }
 
 /// Handle the event at `index`.
 fn handle(context: &mut CompileContext, index: usize) {
     context.index = index;
 
+    if matches!(
+        context.events[index].name,
+        Name::CodeFencedFenceInfo
+            | Name::CodeFencedFenceMeta
+            | Name::DefinitionDestinationString
+            | Name::DefinitionLabelString
+            | Name::DefinitionTitleString
+            | Name::MathFlowFenceMeta
+            | Name::ReferenceString
+            | Name::ResourceDestinationString
+            | Name::ResourceTitleString
+    ) {
+        context.string_inside = context.events[index].kind == Kind::Enter;
+    }
+
+    if matches!(
+        context.events[index].name,
+        Name::DefinitionTitleString | Name::ResourceTitleString
+    ) {
+        context.title_inside = context.events[index].kind == Kind::Enter;
+    }
+
+    if matches!(
+        context.events[index].name,
+        Name::HeadingSetextText | Name::Paragraph
+    ) {
+        context.text_inside = context.events[index].kind == Kind::Enter;
+    }
+
     if context.events[index].kind == Kind::Enter {
         enter(context);
     } else {
@@ -336,6 +757,8 @@ fn enter(context: &mut CompileContext) {
         Name::Definition => on_enter_definition(context),
         Name::DefinitionDestinationString => on_enter_definition_destination_string(context),
         Name::Emphasis => on_enter_emphasis(context),
+        Name::Extension(name) => on_enter_extension(context, name),
+        Name::FootnoteInline => on_enter_footnote_inline(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmFootnoteDefinition => on_enter_gfm_footnote_definition(context),
         Name::GfmFootnoteCall => on_enter_gfm_footnote_call(context),
@@ -378,6 +801,9 @@ fn exit(context: &mut CompileContext) {
         Name::AutolinkProtocol => on_exit_autolink_protocol(context),
         Name::BlankLineEnding => on_exit_blank_line_ending(context),
         Name::BlockQuote => on_exit_block_quote(context),
+        Name::CharacterEscape | Name::CharacterReference => {
+            on_exit_character_escape_or_reference(context);
+        }
         Name::CharacterReferenceMarker => on_exit_character_reference_marker(context),
         Name::CharacterReferenceMarkerNumeric => {
             on_exit_character_reference_marker_numeric(context);
@@ -396,6 +822,8 @@ fn exit(context: &mut CompileContext) {
         Name::DefinitionLabelString => on_exit_definition_label_string(context),
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
         Name::Emphasis => on_exit_emphasis(context),
+        Name::Extension(name) => on_exit_extension(context, name),
+        Name::FootnoteInline => on_exit_footnote_inline(context),
         Name::Frontmatter => on_exit_frontmatter(context),
         Name::GfmAutolinkLiteralEmail => on_exit_gfm_autolink_literal_email(context),
         Name::GfmAutolinkLiteralMailto => on_exit_gfm_autolink_literal_mailto(context),
@@ -471,7 +899,7 @@ fn on_enter_raw_flow(context: &mut CompileContext) {
     context.raw_flow_fences_count = Some(0);
 
     if context.events[context.index].name == Name::MathFlow {
-        context.push(" class=\"language-math math-display\"");
+        context.push_attribute("class", "language-math math-display");
     }
 }
 
@@ -479,11 +907,14 @@ fn on_enter_raw_flow(context: &mut CompileContext) {
 fn on_enter_raw_text(context: &mut CompileContext) {
     context.raw_text_inside = true;
     if !context.image_alt_inside {
-        context.push("<code");
         if context.events[context.index].name == Name::MathText {
-            context.push(" class=\"language-math math-inline\"");
+            context.push("<code");
+            context.push_attribute("class", "language-math math-inline");
+            context.push(">");
+        } else {
+            let element = context.options.element_overrides.inline_code.as_ref();
+            context.push_element_open("code", element);
         }
-        context.push(">");
     }
     context.buffer();
 }
@@ -493,6 +924,7 @@ fn on_enter_definition(context: &mut CompileContext) {
     context.buffer();
     context.media_stack.push(Media {
         image: false,
+        footnote_inline: false,
         label: None,
         label_id: None,
         reference_id: None,
@@ -510,10 +942,35 @@ fn on_enter_definition_destination_string(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`Emphasis`][Name::Emphasis].
 fn on_enter_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<em>");
+        let element = context.options.element_overrides.emphasis.as_ref();
+        context.push_element_open("em", element);
     }
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`Extension`][Name::Extension].
+fn on_enter_extension(context: &mut CompileContext, name: &str) {
+    if !context.image_alt_inside {
+        if let Some(tag) = context.options.extension_tags.get(name) {
+            context.push("<");
+            context.push(tag);
+            context.push(">");
+        }
+    }
+}
+
+/// Handle [`Enter`][Kind::Enter]:[`FootnoteInline`][Name::FootnoteInline].
+fn on_enter_footnote_inline(context: &mut CompileContext) {
+    context.media_stack.push(Media {
+        image: false,
+        footnote_inline: true,
+        label_id: None,
+        label: None,
+        reference_id: None,
+        destination: None,
+        title: None,
+    });
+}
+
 /// Handle [`Enter`][Kind::Enter]:[`Frontmatter`][Name::Frontmatter].
 fn on_enter_frontmatter(context: &mut CompileContext) {
     context.buffer();
@@ -528,6 +985,7 @@ fn on_enter_gfm_footnote_definition(context: &mut CompileContext) {
 fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
     context.media_stack.push(Media {
         image: false,
+        footnote_inline: false,
         label_id: None,
         label: None,
         reference_id: None,
@@ -539,7 +997,8 @@ fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<del>");
+        let element = context.options.element_overrides.delete.as_ref();
+        context.push_element_open("del", element);
     }
 }
 
@@ -575,9 +1034,9 @@ fn on_enter_gfm_table_cell(context: &mut CompileContext) {
         }
 
         match value {
-            AlignKind::Left => context.push(" align=\"left\""),
-            AlignKind::Right => context.push(" align=\"right\""),
-            AlignKind::Center => context.push(" align=\"center\""),
+            AlignKind::Left => context.push_attribute("align", "left"),
+            AlignKind::Right => context.push_attribute("align", "right"),
+            AlignKind::Center => context.push_attribute("align", "center"),
             AlignKind::None => {}
         }
 
@@ -601,9 +1060,14 @@ fn on_enter_gfm_table_row(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
 fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<input type=\"checkbox\" ");
+        context.push("<input");
+        context.push_attribute("type", "checkbox");
         if !context.options.gfm_task_list_item_checkable {
-            context.push("disabled=\"\" ");
+            context.push_boolean_attribute("disabled");
+        }
+        if context.options.gfm_task_list_item_data_offset {
+            let index = context.events[context.index].point.index;
+            context.push_attribute("data-offset", &index.to_string());
         }
     }
 }
@@ -611,14 +1075,18 @@ fn on_enter_gfm_task_list_item_check(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`HtmlFlow`][Name::HtmlFlow].
 fn on_enter_html_flow(context: &mut CompileContext) {
     context.line_ending_if_needed();
-    if context.options.allow_dangerous_html {
+    if context.options.allow_dangerous_html
+        || (context.options.allow_html_comments && html_is_comment(context))
+    {
         context.encode_html = false;
     }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`HtmlText`][Name::HtmlText].
 fn on_enter_html_text(context: &mut CompileContext) {
-    if context.options.allow_dangerous_html {
+    if context.options.allow_dangerous_html
+        || (context.options.allow_html_comments && html_is_comment(context))
+    {
         context.encode_html = false;
     }
 }
@@ -627,6 +1095,7 @@ fn on_enter_html_text(context: &mut CompileContext) {
 fn on_enter_image(context: &mut CompileContext) {
     context.media_stack.push(Media {
         image: true,
+        footnote_inline: false,
         label_id: None,
         label: None,
         reference_id: None,
@@ -640,6 +1109,7 @@ fn on_enter_image(context: &mut CompileContext) {
 fn on_enter_link(context: &mut CompileContext) {
     context.media_stack.push(Media {
         image: false,
+        footnote_inline: false,
         label_id: None,
         label: None,
         reference_id: None,
@@ -650,7 +1120,11 @@ fn on_enter_link(context: &mut CompileContext) {
 
 /// Handle [`Enter`][Kind::Enter]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
 fn on_enter_list(context: &mut CompileContext) {
-    let loose = list_loose(context.events, context.index, true);
+    if context.list_exits.is_empty() {
+        context.list_exits = exits(context.events);
+    }
+
+    let loose = list_loose(context.events, &context.list_exits, context.index, true);
     context.tight_stack.push(!loose);
     context.line_ending_if_needed();
 
@@ -661,6 +1135,15 @@ fn on_enter_list(context: &mut CompileContext) {
         "<ul"
     });
     context.list_expect_first_marker = Some(true);
+
+    if context.events[context.index].name == Name::ListOrdered {
+        context.list_kind = list_kind(
+            context.events,
+            context.bytes,
+            &context.list_exits,
+            context.index,
+        );
+    }
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`ListItemMarker`][Name::ListItemMarker].
@@ -680,8 +1163,9 @@ fn on_enter_paragraph(context: &mut CompileContext) {
     let tight = context.tight_stack.last().unwrap_or(&false);
 
     if !tight {
+        let element = context.options.element_overrides.paragraph.as_ref();
         context.line_ending_if_needed();
-        context.push("<p>");
+        context.push_element_open("p", element);
     }
 }
 
@@ -702,7 +1186,8 @@ fn on_enter_resource_destination_string(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:[`Strong`][Name::Strong].
 fn on_enter_strong(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<strong>");
+        let element = context.options.element_overrides.strong.as_ref();
+        context.push_element_open("strong", element);
     }
 }
 
@@ -737,7 +1222,8 @@ fn on_exit_autolink_protocol(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:{[`HardBreakEscape`][Name::HardBreakEscape],[`HardBreakTrailing`][Name::HardBreakTrailing]}.
 fn on_exit_break(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("<br />");
+        context.push("<br");
+        context.push_self_closing();
     }
 }
 
@@ -757,6 +1243,18 @@ fn on_exit_block_quote(context: &mut CompileContext) {
     context.push("</blockquote>");
 }
 
+/// Handle [`Exit`][Kind::Exit]:{[`CharacterEscape`][Name::CharacterEscape],[`CharacterReference`][Name::CharacterReference]}.
+fn on_exit_character_escape_or_reference(context: &mut CompileContext) {
+    // Unless titles are not decoded, their values are handled instead.
+    if context.title_inside && !context.options.decode_titles {
+        let slice = Slice::from_position(
+            context.bytes,
+            &Position::from_exit_event(context.events, context.index),
+        );
+        context.push(&encode(slice.as_str(), context.encode_html));
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`CharacterReferenceMarker`][Name::CharacterReferenceMarker].
 fn on_exit_character_reference_marker(context: &mut CompileContext) {
     context.character_reference_marker = Some(b'&');
@@ -778,6 +1276,11 @@ fn on_exit_character_reference_value(context: &mut CompileContext) {
         .character_reference_marker
         .take()
         .expect("expected `character_reference_kind` to be set");
+
+    if context.title_inside && !context.options.decode_titles {
+        return;
+    }
+
     let slice = Slice::from_position(
         context.bytes,
         &Position::from_exit_event(context.events, context.index),
@@ -821,9 +1324,7 @@ fn on_exit_raw_flow_fence(context: &mut CompileContext) {
 /// Note: math (flow) does not support `info`.
 fn on_exit_raw_flow_fence_info(context: &mut CompileContext) {
     let value = context.resume();
-    context.push(" class=\"language-");
-    context.push(&value);
-    context.push("\"");
+    context.push_attribute("class", &format!("language-{}", value));
 }
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFenced`][Name::CodeFenced],[`CodeIndented`][Name::CodeIndented],[`MathFlow`][Name::MathFlow]}.
@@ -913,7 +1414,12 @@ fn on_exit_raw_text(context: &mut CompileContext) {
     context.push(str::from_utf8(&bytes).unwrap());
 
     if !context.image_alt_inside {
-        context.push("</code>");
+        let element = if context.events[context.index].name == Name::CodeText {
+            context.options.element_overrides.inline_code.as_ref()
+        } else {
+            None
+        };
+        context.push_element_close("code", element);
     }
 }
 
@@ -934,14 +1440,28 @@ fn on_exit_drop_slurp(context: &mut CompileContext) {
 
 /// Handle [`Exit`][Kind::Exit]:{[`CodeTextData`][Name::CodeTextData],[`Data`][Name::Data],[`CharacterEscapeValue`][Name::CharacterEscapeValue]}.
 fn on_exit_data(context: &mut CompileContext) {
-    context.push(&encode(
-        Slice::from_position(
-            context.bytes,
-            &Position::from_exit_event(context.events, context.index),
-        )
-        .as_str(),
-        context.encode_html,
-    ));
+    // Titles that are not decoded include whole escapes instead.
+    if context.title_inside
+        && !context.options.decode_titles
+        && context.events[context.index].name == Name::CharacterEscapeValue
+    {
+        return;
+    }
+
+    let position = Position::from_exit_event(context.events, context.index);
+
+    if context.options.smart_punctuation
+        && !context.string_inside
+        && context.events[context.index].name == Name::Data
+    {
+        let value = smart_punctuation(context.bytes, position.start.index, position.end.index);
+        context.push(&encode(&value, context.encode_html));
+    } else {
+        context.push(&encode(
+            Slice::from_position(context.bytes, &position).as_str(),
+            context.encode_html,
+        ));
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Definition`][Name::Definition].
@@ -953,7 +1473,7 @@ fn on_exit_definition(context: &mut CompileContext) {
         normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str());
 
     context.definitions.push(Definition {
-        id,
+        id: id.into_owned(),
         destination: media.destination,
         title: media.title,
     });
@@ -983,13 +1503,30 @@ fn on_exit_definition_title_string(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`Emphasis`][Name::Emphasis].
 fn on_exit_emphasis(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("</em>");
+        let element = context.options.element_overrides.emphasis.as_ref();
+        context.push_element_close("em", element);
+    }
+}
+
+/// Handle [`Exit`][Kind::Exit]:[`Extension`][Name::Extension].
+fn on_exit_extension(context: &mut CompileContext, name: &str) {
+    if !context.image_alt_inside {
+        if let Some(tag) = context.options.extension_tags.get(name) {
+            context.push("</");
+            context.push(tag);
+            context.push(">");
+        }
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Frontmatter`][Name::Frontmatter].
 fn on_exit_frontmatter(context: &mut CompileContext) {
     context.resume();
+    if context.options.frontmatter != FrontmatterRendering::Ignore {
+        let (value, kind, _) =
+            frontmatter::from_events(context.events, context.bytes).expect("expected frontmatter");
+        generate_frontmatter(context, &value, kind);
+    }
     context.slurp_one_line_ending = true;
 }
 
@@ -1063,11 +1600,37 @@ fn on_exit_gfm_autolink_literal_xmpp(context: &mut CompileContext) {
     );
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`FootnoteInline`][Name::FootnoteInline].
+fn on_exit_footnote_inline(context: &mut CompileContext) {
+    let label = context.media_stack.pop().unwrap().label.unwrap();
+
+    if context.footnote_inline_count == 0 {
+        context.footnote_inline_identifiers =
+            footnote_inline_identifiers(context.events, context.bytes);
+    }
+
+    let id = context.footnote_inline_identifiers[context.footnote_inline_count].clone();
+    let element = context.options.element_overrides.paragraph.as_ref();
+    context.footnote_inline_count += 1;
+    context.buffer();
+    context.push_element_open("p", element);
+    context.push(&label);
+    context.push_element_close("p", element);
+    let value = context.resume();
+    context.gfm_footnote_definitions.push((id.clone(), value));
+    generate_footnote_call(context, id);
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`GfmFootnoteCall`][Name::GfmFootnoteCall].
 fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
     let indices = context.media_stack.pop().unwrap().label_id.unwrap();
     let id =
         normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str());
+    generate_footnote_call(context, id.into_owned());
+}
+
+/// Generate a footnote call.
+fn generate_footnote_call(context: &mut CompileContext, id: String) {
     let safe_id = sanitize(&id.to_lowercase());
     let mut call_index = 0;
 
@@ -1093,33 +1656,35 @@ fn on_exit_gfm_footnote_call(context: &mut CompileContext) {
         return;
     }
 
-    context.push("<sup><a href=\"#");
-    if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
-    } else {
-        context.push("user-content-");
-    }
-    context.push("fn-");
-    context.push(&safe_id);
-    context.push("\" id=\"");
-    if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
-    } else {
-        context.push("user-content-");
-    }
-    context.push("fnref-");
-    context.push(&safe_id);
+    let prefix = gfm_footnote_clobber_prefix(context);
+    let namespace = gfm_footnote_id_prefix(context);
+    let mut id = format!("{}{}fnref-{}", prefix, namespace, safe_id);
     if context.gfm_footnote_definition_calls[call_index].1 > 1 {
-        context.push("-");
-        context.push(
+        id.push('-');
+        id.push_str(
             &context.gfm_footnote_definition_calls[call_index]
                 .1
                 .to_string(),
         );
     }
-    context.push("\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">");
 
-    context.push(&(call_index + 1).to_string());
+    context.push("<sup><a");
+    context.push_attribute("href", &format!("#{}{}fn-{}", prefix, namespace, safe_id));
+    context.push_attribute("id", &id);
+    context.push_attribute("data-footnote-ref", "");
+    context.push_attribute("aria-describedby", &format!("{}footnote-label", namespace));
+    context.push(">");
+
+    let number = if let Some(order) = &context.gfm_footnote_definition_order {
+        order
+            .iter()
+            .position(|d| *d == context.gfm_footnote_definition_calls[call_index].0)
+            .expect("expected call to be in definition order")
+    } else {
+        call_index
+    };
+
+    context.push(&(number + 1).to_string());
     context.push("</a></sup>");
 }
 
@@ -1144,7 +1709,8 @@ fn on_exit_gfm_footnote_definition(context: &mut CompileContext) {
     let indices = context.gfm_footnote_definition_stack.pop().unwrap();
     context.tight_stack.pop();
     context.gfm_footnote_definitions.push((
-        normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str()),
+        normalize_identifier(Slice::from_indices(context.bytes, indices.0, indices.1).as_str())
+            .into_owned(),
         value,
     ));
 }
@@ -1152,7 +1718,8 @@ fn on_exit_gfm_footnote_definition(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`GfmStrikethrough`][Name::GfmStrikethrough].
 fn on_exit_gfm_strikethrough(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("</del>");
+        let element = context.options.element_overrides.delete.as_ref();
+        context.push_element_close("del", element);
     }
 }
 
@@ -1215,14 +1782,14 @@ fn on_exit_gfm_table_row(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`GfmTaskListItemCheck`][Name::GfmTaskListItemCheck].
 fn on_exit_gfm_task_list_item_check(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("/>");
+        context.push_self_closing();
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTaskListItemValueChecked`][Name::GfmTaskListItemValueChecked].
 fn on_exit_gfm_task_list_item_value_checked(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("checked=\"\" ");
+        context.push_boolean_attribute("checked");
     }
 }
 
@@ -1248,9 +1815,8 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
         )
         .len();
         context.line_ending_if_needed();
+        let rank = push_heading_open(context, rank);
         context.heading_atx_rank = Some(rank);
-        context.push("<h");
-        context.push(&rank.to_string());
         context.push(">");
     }
 }
@@ -1276,18 +1842,58 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
         .expect("`heading_atx_rank` must be set in headings");
     let position = Position::from_exit_event(context.events, context.index);
     let head = context.bytes[position.start.index];
-    let rank = if head == b'-' { "2" } else { "1" };
+    let rank = shift_heading_rank(
+        if head == b'-' { 2 } else { 1 },
+        context.options.heading_shift,
+    )
+    .to_string();
 
     context.line_ending_if_needed();
     context.push("<h");
-    context.push(rank);
+    context.push(&rank);
     context.push(">");
     context.push(&text);
     context.push("</h");
-    context.push(rank);
+    context.push(&rank);
     context.push(">");
 }
 
+/// Shift the rank of a heading by `shift`, clamped to `1..=6`.
+fn shift_heading_rank(rank: usize, shift: i8) -> usize {
+    shift_heading_depth(rank, shift).min(6)
+}
+
+/// Shift the depth of a heading by `shift`, to at least `1`.
+fn shift_heading_depth(depth: usize, shift: i8) -> usize {
+    let depth = if shift < 0 {
+        depth.saturating_sub(usize::from(shift.unsigned_abs()))
+    } else {
+        depth + usize::from(shift.unsigned_abs())
+    };
+
+    depth.max(1)
+}
+
+/// Push the start of the opening tag of a heading of `depth`, without `>`.
+///
+/// Returns the rank of the element.
+/// Headings deeper than `6` (see `heading_atx_max_depth`) are `<h6>`s with
+/// their depth in `aria-level`.
+/// Headings that are only shifted past `6` by `heading_shift` are not.
+fn push_heading_open(context: &mut CompileContext, depth: usize) -> usize {
+    let level = shift_heading_depth(depth, context.options.heading_shift);
+    let rank = level.min(6);
+
+    context.push("<h");
+    context.push(&rank.to_string());
+
+    if depth > 6 && level > 6 {
+        context.push_attribute("aria-level", &level.to_string());
+    }
+
+    rank
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_exit_html(context: &mut CompileContext) {
     context.encode_html = true;
@@ -1301,13 +1907,12 @@ fn on_exit_html_data(context: &mut CompileContext) {
     );
     let value = slice.as_str();
 
-    let encoded = if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
-        encode(&gfm_tagfilter(value), context.encode_html)
+    if context.options.gfm_tagfilter && context.options.allow_dangerous_html {
+        let value = gfm_tagfilter(value);
+        context.push(&encode(&value, context.encode_html));
     } else {
-        encode(value, context.encode_html)
-    };
-
-    context.push(&encoded);
+        context.push(&encode(value, context.encode_html));
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`Label`][Name::Label].
@@ -1334,6 +1939,20 @@ fn on_exit_line_ending(context: &mut CompileContext) {
     {
         context.slurp_one_line_ending = false;
     } else {
+        if context.options.hard_wrap
+            && context.text_inside
+            && !context.image_alt_inside
+            && !context.string_inside
+            // Not in HTML, and not after existing breaks.
+            && !matches!(
+                context.events[context.index - 2].name,
+                Name::HardBreakEscape | Name::HardBreakTrailing | Name::HtmlTextData
+            )
+        {
+            context.push("<br");
+            context.push_self_closing();
+        }
+
         context.push(&encode(
             Slice::from_position(
                 context.bytes,
@@ -1392,16 +2011,32 @@ fn on_exit_list_item_value(context: &mut CompileContext) {
             context.bytes,
             &Position::from_exit_event(context.events, context.index),
         );
-        let value = slice.as_str().parse::<u32>().ok().unwrap();
+        let kind = context.list_kind.take();
+        let value = list_value::number(kind, slice.as_str()).unwrap();
 
         if value != 1 {
-            context.push(" start=\"");
-            context.push(&value.to_string());
-            context.push("\"");
+            context.push_attribute("start", &value.to_string());
+        }
+
+        if let Some(kind) = kind {
+            push_list_type(context, kind);
         }
     }
 }
 
+/// Push the `type` attribute of an ordered list of `kind`.
+fn push_list_type(context: &mut CompileContext, kind: OrderedListKind) {
+    context.push_attribute(
+        "type",
+        match kind {
+            OrderedListKind::LowerAlpha => "a",
+            OrderedListKind::UpperAlpha => "A",
+            OrderedListKind::LowerRoman => "i",
+            OrderedListKind::UpperRoman => "I",
+        },
+    );
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`Image`][Name::Image],[`Link`][Name::Link]}.
 fn on_exit_media(context: &mut CompileContext) {
     let mut is_in_image = false;
@@ -1443,46 +2078,25 @@ fn on_exit_media(context: &mut CompileContext) {
         None
     };
 
-    if !is_in_image {
+    if is_in_image {
         if media.image {
-            context.push("<img src=\"");
-        } else {
-            context.push("<a href=\"");
-        };
-
+            context.push(&label);
+        }
+    } else {
         let destination = if let Some(index) = definition_index {
             context.definitions[index].destination.as_ref()
         } else {
             media.destination.as_ref()
         };
 
-        if let Some(destination) = destination {
-            let url = if context.options.allow_dangerous_protocol {
-                sanitize(destination)
-            } else {
-                sanitize_with_protocols(
-                    destination,
-                    if media.image {
-                        &SAFE_PROTOCOL_SRC
-                    } else {
-                        &SAFE_PROTOCOL_HREF
-                    },
-                )
-            };
-            context.push(&url);
-        }
+        let url = if let Some(destination) = destination {
+            let destination = resolve_url(context, destination);
+            let destination = destination.as_ref();
 
-        if media.image {
-            context.push("\" alt=\"");
+            sanitize_url(context, destination, media.image)
+        } else {
+            String::new()
         };
-    }
-
-    if media.image {
-        context.push(&label);
-    }
-
-    if !is_in_image {
-        context.push("\"");
 
         let title = if let Some(index) = definition_index {
             context.definitions[index].title.clone()
@@ -1490,17 +2104,18 @@ fn on_exit_media(context: &mut CompileContext) {
             media.title
         };
 
-        if let Some(title) = title {
-            context.push(" title=\"");
-            context.push(&title);
-            context.push("\"");
-        };
-
         if media.image {
-            context.push(" /");
-        }
+            push_image(context, &url, &label, title.as_deref());
+        } else {
+            context.push("<a");
+            context.push_attribute("href", &url);
 
-        context.push(">");
+            if let Some(title) = title {
+                context.push_attribute("title", &title);
+            }
+
+            context.push(">");
+        }
     }
 
     if !media.image {
@@ -1519,7 +2134,8 @@ fn on_exit_paragraph(context: &mut CompileContext) {
     if *tight {
         context.slurp_one_line_ending = true;
     } else {
-        context.push("</p>");
+        let element = context.options.element_overrides.paragraph.as_ref();
+        context.push_element_close("p", element);
     }
 }
 
@@ -1548,30 +2164,91 @@ fn on_exit_resource_title_string(context: &mut CompileContext) {
 /// Handle [`Exit`][Kind::Exit]:[`Strong`][Name::Strong].
 fn on_exit_strong(context: &mut CompileContext) {
     if !context.image_alt_inside {
-        context.push("</strong>");
+        let element = context.options.element_overrides.strong.as_ref();
+        context.push_element_close("strong", element);
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_exit_thematic_break(context: &mut CompileContext) {
     context.line_ending_if_needed();
-    context.push("<hr />");
+    context.push("<hr");
+    context.push_self_closing();
+}
+
+/// Check whether the HTML (flow or text) entered at the current event is one
+/// closed comment, optionally followed by whitespace.
+fn html_is_comment(context: &CompileContext) -> bool {
+    let mut value = String::new();
+    let mut index = context.index + 1;
+
+    loop {
+        let event = &context.events[index];
+
+        if event.kind == Kind::Exit {
+            match event.name {
+                Name::HtmlFlow | Name::HtmlText => break,
+                Name::HtmlFlowData | Name::HtmlTextData | Name::LineEnding => {
+                    let slice = Slice::from_position(
+                        context.bytes,
+                        &Position::from_exit_event(context.events, index),
+                    );
+                    value.push_str(slice.as_str());
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    is_html_comment(&value)
+}
+
+/// Check whether `value` is one closed HTML comment, optionally followed by
+/// whitespace.
+fn is_html_comment(value: &str) -> bool {
+    let bytes = value.as_bytes();
+
+    if !bytes.starts_with(b"<!--") {
+        return false;
+    }
+
+    // Browsers also close comments at `<!-->` and `<!--->`.
+    let end = if bytes[4..].starts_with(b">") {
+        5
+    } else if bytes[4..].starts_with(b"->") {
+        6
+    } else if let Some(position) = bytes[4..].windows(3).position(|d| d == b"-->") {
+        position + 7
+    } else {
+        return false;
+    };
+
+    // Browsers also close comments at `--!>`.
+    !bytes[..end].windows(4).any(|d| d == b"--!>")
+        && bytes[end..].iter().all(u8::is_ascii_whitespace)
 }
 
 /// Generate a footnote section.
 fn generate_footnote_section(context: &mut CompileContext) {
     context.line_ending_if_needed();
-    context.push("<section data-footnotes=\"\" class=\"footnotes\"><");
+    context.push("<section");
+    context.push_attribute("data-footnotes", "");
+    context.push_attribute("class", "footnotes");
+    context.push("><");
     if let Some(ref value) = context.options.gfm_footnote_label_tag_name {
         context.push(&encode(value, context.encode_html));
     } else {
         context.push("h2");
     }
-    context.push(" id=\"footnote-label\" ");
+    let namespace = gfm_footnote_id_prefix(context);
+    context.push_attribute("id", &format!("{}footnote-label", namespace));
     if let Some(ref value) = context.options.gfm_footnote_label_attributes {
+        context.push(" ");
         context.push(value);
     } else {
-        context.push("class=\"sr-only\"");
+        context.push_attribute("class", "sr-only");
     }
     context.push(">");
     if let Some(ref value) = context.options.gfm_footnote_label {
@@ -1589,10 +2266,21 @@ fn generate_footnote_section(context: &mut CompileContext) {
     context.line_ending();
     context.push("<ol>");
 
-    let mut index = 0;
-    while index < context.gfm_footnote_definition_calls.len() {
-        generate_footnote_item(context, index);
-        index += 1;
+    if let Some(order) = context.gfm_footnote_definition_order.take() {
+        for id in &order {
+            let index = context
+                .gfm_footnote_definition_calls
+                .iter()
+                .position(|d| &d.0 == id)
+                .expect("expected definition to be called");
+            generate_footnote_item(context, index);
+        }
+    } else {
+        let mut index = 0;
+        while index < context.gfm_footnote_definition_calls.len() {
+            generate_footnote_item(context, index);
+            index += 1;
+        }
     }
 
     context.line_ending();
@@ -1622,53 +2310,51 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
         "expected definition"
     );
 
-    context.line_ending();
-    context.push("<li id=\"");
-    if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-        context.push(&encode(value, context.encode_html));
+    let prefix = format!(
+        "{}{}",
+        gfm_footnote_clobber_prefix(context),
+        gfm_footnote_id_prefix(context)
+    );
+    let back_label = if let Some(ref value) = context.options.gfm_footnote_back_label {
+        encode(value, context.encode_html)
     } else {
-        context.push("user-content-");
-    }
-    context.push("fn-");
-    context.push(&safe_id);
-    context.push("\">");
+        "Back to content".into()
+    };
+
+    context.line_ending();
+    context.push("<li");
+    context.push_attribute("id", &format!("{}fn-{}", prefix, safe_id));
+    context.push(">");
     context.line_ending();
 
     // Create one or more backreferences.
+    // They are generated in a buffer, as they might go inside the last
+    // paragraph.
     let mut reference_index = 0;
-    let mut backreferences = String::new();
+    context.buffer();
     while reference_index < context.gfm_footnote_definition_calls[index].1 {
+        let mut href = format!("#{}fnref-{}", prefix, safe_id);
         if reference_index != 0 {
-            backreferences.push(' ');
-        }
-        backreferences.push_str("<a href=\"#");
-        if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
-            backreferences.push_str(&encode(value, context.encode_html));
-        } else {
-            backreferences.push_str("user-content-");
-        }
-        backreferences.push_str("fnref-");
-        backreferences.push_str(&safe_id);
-        if reference_index != 0 {
-            backreferences.push('-');
-            backreferences.push_str(&(reference_index + 1).to_string());
+            href.push('-');
+            href.push_str(&(reference_index + 1).to_string());
+            context.push(" ");
         }
-        backreferences.push_str("\" data-footnote-backref=\"\" aria-label=\"");
-        if let Some(ref value) = context.options.gfm_footnote_back_label {
-            backreferences.push_str(&encode(value, context.encode_html));
-        } else {
-            backreferences.push_str("Back to content");
-        }
-        backreferences.push_str("\" class=\"data-footnote-backref\">↩");
+        context.push("<a");
+        context.push_attribute("href", &href);
+        context.push_attribute("data-footnote-backref", "");
+        context.push_attribute("aria-label", &back_label);
+        context.push_attribute("class", "data-footnote-backref");
+        context.push(">↩");
         if reference_index != 0 {
-            backreferences.push_str("<sup>");
-            backreferences.push_str(&(reference_index + 1).to_string());
-            backreferences.push_str("</sup>");
+            context.push("<sup>");
+            context.push(&(reference_index + 1).to_string());
+            context.push("</sup>");
         }
-        backreferences.push_str("</a>");
+        context.push("</a>");
 
         reference_index += 1;
     }
+    let backreferences = context.resume();
 
     let value = context.gfm_footnote_definitions[definition_index].1.clone();
     let bytes = value.as_bytes();
@@ -1695,6 +2381,13 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
         result.push_str(&backreferences);
         result.push_str(str::from_utf8(after).unwrap());
         context.push(&result);
+    } else if byte_index > 0 {
+        // Like GitHub, put them in a new paragraph after other blocks.
+        context.push(&value);
+        context.line_ending_if_needed();
+        context.push("<p>");
+        context.push(&backreferences);
+        context.push("</p>");
     } else {
         context.push(&value);
         context.line_ending_if_needed();
@@ -1704,6 +2397,72 @@ fn generate_footnote_item(context: &mut CompileContext, index: usize) {
     context.push("</li>");
 }
 
+/// Get the prefix to use before footnote IDs.
+///
+/// The prefix is sanitized like the identifier after it, so that the `id`
+/// of an element is exactly the fragment of the `href` that links to it.
+fn gfm_footnote_clobber_prefix(context: &CompileContext) -> String {
+    if let Some(ref value) = context.options.gfm_footnote_clobber_prefix {
+        sanitize(value)
+    } else {
+        "user-content-".into()
+    }
+}
+
+/// Remove control characters (C0, `DEL`, and C1), except for tabs and line
+/// endings.
+fn strip_control_characters(value: &str) -> Cow<'_, str> {
+    let strip = |char: char| char.is_control() && !matches!(char, '\t' | '\n' | '\r');
+
+    if value.contains(strip) {
+        Cow::Owned(value.chars().filter(|char| !strip(*char)).collect())
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// Get the namespace to use in footnote IDs, after the clobber prefix.
+///
+/// Sanitized like the clobber prefix.
+fn gfm_footnote_id_prefix(context: &CompileContext) -> String {
+    if let Some(ref value) = context.options.gfm_footnote_id_prefix {
+        sanitize(value)
+    } else {
+        String::new()
+    }
+}
+
+/// Make a URL safe, and drop it if it has a protocol that is not allowed,
+/// in an image (`src`) or otherwise (`href`).
+fn sanitize_url(context: &CompileContext, url: &str, image: bool) -> String {
+    if context.options.allow_dangerous_protocol {
+        return sanitize(url);
+    }
+
+    let protocols = if image {
+        &context.options.protocol_src
+    } else {
+        &context.options.protocol_href
+    };
+
+    match protocols {
+        Some(protocols) => sanitize_with_protocols(url, protocols),
+        None if image => sanitize_with_protocols(url, &SAFE_PROTOCOL_SRC),
+        None => sanitize_with_protocols(url, &SAFE_PROTOCOL_HREF),
+    }
+}
+
+/// Resolve a URL against `url_base`, if it is given and the URL is
+/// relative to a path.
+///
+/// Autolinks are not resolved: they always have a protocol.
+fn resolve_url<'a>(context: &CompileContext, url: &'a str) -> Cow<'a, str> {
+    match context.options.url_base {
+        Some(ref base) if is_path_relative(url) => Cow::Owned(resolve(base, url)),
+        _ => Cow::Borrowed(url),
+    }
+}
+
 /// Generate an autolink (used by unicode autolinks and GFM autolink literals).
 fn generate_autolink(
     context: &mut CompileContext,
@@ -1715,7 +2474,7 @@ fn generate_autolink(
     let mut index = 0;
 
     while index < context.media_stack.len() {
-        if !context.media_stack[index].image {
+        if !context.media_stack[index].image && !context.media_stack[index].footnote_inline {
             is_in_link = true;
             break;
         }
@@ -1723,21 +2482,17 @@ fn generate_autolink(
     }
 
     if !context.image_alt_inside && (!is_in_link || !is_gfm_literal) {
-        context.push("<a href=\"");
         let url = if let Some(protocol) = protocol {
             format!("{}{}", protocol, value)
         } else {
             value.into()
         };
 
-        let url = if context.options.allow_dangerous_protocol {
-            sanitize(&url)
-        } else {
-            sanitize_with_protocols(&url, &SAFE_PROTOCOL_HREF)
-        };
+        let url = sanitize_url(context, &url, false);
 
-        context.push(&url);
-        context.push("\">");
+        context.push("<a");
+        context.push_attribute("href", &url);
+        context.push(">");
     }
 
     context.push(&encode(value, context.encode_html));
@@ -1746,3 +2501,666 @@ fn generate_autolink(
         context.push("</a>");
     }
 }
+
+/// Generate frontmatter (used by events and mdast).
+fn generate_frontmatter(context: &mut CompileContext, value: &str, kind: FrontmatterKind) {
+    let (language, fence) = match kind {
+        FrontmatterKind::Toml => ("toml", "+++"),
+        FrontmatterKind::Yaml => ("yaml", "---"),
+    };
+
+    match context.options.frontmatter {
+        FrontmatterRendering::Ignore => {}
+        FrontmatterRendering::CodeBlock => {
+            context.line_ending_if_needed();
+            context.push("<pre><code");
+            context.push_attribute("class", &format!("language-{}", language));
+            context.push(">");
+            mdast_raw_flow_value(context, value);
+        }
+        FrontmatterRendering::Comment => {
+            context.line_ending_if_needed();
+            context.push("<!--");
+            context.line_ending();
+            context.push(fence);
+            context.line_ending();
+            if !value.is_empty() {
+                // Browsers close comments at `-->` and `--!>`.
+                context.push(&value.replace('>', "&gt;"));
+                context.line_ending();
+            }
+            context.push(fence);
+            context.line_ending();
+            context.push("-->");
+        }
+    }
+}
+
+/// Turn a syntax tree into HTML.
+///
+/// The tree is compiled with the handlers used for events where possible, so
+/// that the result is the same as compiling the markdown it came from.
+///
+/// Errors when there are MDX nodes and
+/// [`skip_mdx_nodes`][CompileOptions::skip_mdx_nodes] is off, or when the
+/// HTML would become bigger than `max_output_size`.
+pub fn compile_mdast(tree: &Node, options: &CompileOptions) -> Result<String, message::Message> {
+    check_element_overrides(options)?;
+
+    let mut context = CompileContext::new(&[], &[], options, options.default_line_ending.clone());
+    let mut footnote_calls = vec![];
+
+    // Handle all definitions first, as references can come before them.
+    mdast_collect(&mut context, tree, &mut footnote_calls);
+
+    if let Some(order) = context.gfm_footnote_definition_order.as_mut() {
+        let mut index = 0;
+        while index < context.gfm_footnote_definition_identifiers.len() {
+            let id = &context.gfm_footnote_definition_identifiers[index];
+            if footnote_calls.contains(id) && !order.contains(id) {
+                order.push(id.clone());
+            }
+            index += 1;
+        }
+    }
+
+    mdast_node(&mut context, tree)?;
+
+    // No section to generate.
+    if !context.gfm_footnote_definition_calls.is_empty() {
+        generate_footnote_section(&mut context);
+    }
+
+    debug_assert_eq!(context.buffers.len(), 1, "expected 1 final buffer");
+    let mut result = String::new();
+    context.write_out(&mut result)?;
+    Ok(result)
+}
+
+/// Collect definitions, footnote definitions, and footnote calls in `node`.
+fn mdast_collect(context: &mut CompileContext, node: &Node, footnote_calls: &mut Vec<String>) {
+    match node {
+        Node::Definition(definition) => {
+            context.definitions.push(Definition {
+                id: normalize_identifier(&definition.identifier).into_owned(),
+                destination: Some(definition.url.clone()),
+                title: definition
+                    .title
+                    .as_ref()
+                    .map(|d| encode(d, true).into_owned()),
+            });
+        }
+        Node::FootnoteDefinition(definition) => {
+            context
+                .gfm_footnote_definition_identifiers
+                .push(normalize_identifier(&definition.identifier).into_owned());
+        }
+        Node::FootnoteReference(reference) => {
+            footnote_calls.push(normalize_identifier(&reference.identifier).into_owned());
+        }
+        _ => {}
+    }
+
+    if let Some(children) = node.children() {
+        for child in children {
+            mdast_collect(context, child, footnote_calls);
+        }
+    }
+}
+
+/// Compile the children of `node`, which is flow content.
+fn mdast_flow(context: &mut CompileContext, children: &[Node]) -> Result<(), message::Message> {
+    for child in children {
+        mdast_flow_node(context, child)?;
+    }
+
+    Ok(())
+}
+
+/// Compile `node`, which is in flow content.
+fn mdast_flow_node(context: &mut CompileContext, node: &Node) -> Result<(), message::Message> {
+    if let Node::Html(_) = node {
+        context.line_ending_if_needed();
+    }
+
+    mdast_node(context, node)
+}
+
+/// Compile the children of `node`, which is phrasing content.
+fn mdast_phrasing(context: &mut CompileContext, children: &[Node]) -> Result<(), message::Message> {
+    for child in children {
+        mdast_node(context, child)?;
+    }
+
+    Ok(())
+}
+
+/// Compile `node`.
+fn mdast_node(context: &mut CompileContext, node: &Node) -> Result<(), message::Message> {
+    match node {
+        Node::Root(root) => mdast_flow(context, &root.children)?,
+        Node::Blockquote(block_quote) => {
+            on_enter_block_quote(context);
+            mdast_flow(context, &block_quote.children)?;
+            on_exit_block_quote(context);
+        }
+        Node::FootnoteDefinition(definition) => {
+            on_enter_gfm_footnote_definition(context);
+            context.buffer();
+            mdast_flow(context, &definition.children)?;
+            let value = context.resume();
+            context.tight_stack.pop();
+            context.gfm_footnote_definitions.push((
+                normalize_identifier(&definition.identifier).into_owned(),
+                value,
+            ));
+        }
+        Node::List(list) => {
+            let loose = list.spread
+                || list
+                    .children
+                    .iter()
+                    .any(|d| matches!(d, Node::ListItem(item) if item.spread));
+            context.tight_stack.push(!loose);
+            context.line_ending_if_needed();
+            context.push(if list.ordered { "<ol" } else { "<ul" });
+            if let Some(start) = list.start {
+                if list.ordered && start != 1 {
+                    context.push_attribute("start", &start.to_string());
+                }
+            }
+            if let Some(kind) = list.kind {
+                if list.ordered {
+                    push_list_type(context, kind);
+                }
+            }
+            context.push(">");
+            mdast_flow(context, &list.children)?;
+            context.tight_stack.pop();
+            context.line_ending();
+            context.push(if list.ordered { "</ol>" } else { "</ul>" });
+        }
+        Node::ListItem(item) => mdast_list_item(context, item)?,
+        Node::Heading(heading) => {
+            context.line_ending_if_needed();
+            let rank = push_heading_open(context, usize::from(heading.depth));
+            context.push(">");
+            mdast_phrasing(context, &heading.children)?;
+            context.push("</h");
+            context.push(&rank.to_string());
+            context.push(">");
+        }
+        Node::Paragraph(paragraph) => {
+            on_enter_paragraph(context);
+            mdast_phrasing(context, &paragraph.children)?;
+            on_exit_paragraph(context);
+            context.slurp_one_line_ending = false;
+        }
+        Node::ThematicBreak(_) => on_exit_thematic_break(context),
+        Node::Code(code) => {
+            context.line_ending_if_needed();
+            context.push("<pre><code");
+            if let Some(lang) = &code.lang {
+                context.push_attribute("class", &format!("language-{}", encode(lang, true)));
+            }
+            context.push(">");
+            mdast_raw_flow_value(context, &code.value);
+        }
+        Node::Math(math) => {
+            context.line_ending_if_needed();
+            context.push("<pre><code");
+            context.push_attribute("class", "language-math math-display");
+            context.push(">");
+            mdast_raw_flow_value(context, &math.value);
+        }
+        Node::Html(html) => {
+            let value = if context.options.allow_dangerous_html {
+                if context.options.gfm_tagfilter {
+                    gfm_tagfilter(&html.value)
+                } else {
+                    html.value.clone()
+                }
+            } else if context.options.allow_html_comments && is_html_comment(&html.value) {
+                html.value.clone()
+            } else {
+                encode(&html.value, true).into_owned()
+            };
+            context.push(&value);
+        }
+        Node::Table(table) => {
+            context.gfm_table_align = Some(table.align.clone());
+            context.line_ending_if_needed();
+            context.push("<table>");
+
+            let mut index = 0;
+            while index < table.children.len() {
+                if index == 0 {
+                    on_enter_gfm_table_head(context);
+                } else if index == 1 {
+                    context.line_ending_if_needed();
+                    on_enter_gfm_table_body(context);
+                }
+
+                mdast_node(context, &table.children[index])?;
+
+                if index == 0 {
+                    on_exit_gfm_table_head(context);
+                }
+
+                index += 1;
+            }
+
+            if index > 1 {
+                on_exit_gfm_table_body(context);
+            }
+
+            on_exit_gfm_table(context);
+        }
+        Node::TableRow(row) => {
+            // Rows outside tables are not aligned.
+            let outside = context.gfm_table_align.is_none();
+            if outside {
+                context.gfm_table_align = Some(vec![AlignKind::None; row.children.len()]);
+            }
+
+            on_enter_gfm_table_row(context);
+            mdast_phrasing(context, &row.children)?;
+            on_exit_gfm_table_row(context);
+
+            if outside {
+                context.gfm_table_align = None;
+            }
+        }
+        Node::TableCell(cell) => {
+            // Cells outside tables are only their content.
+            if context.gfm_table_align.is_none() {
+                mdast_phrasing(context, &cell.children)?;
+            } else {
+                on_enter_gfm_table_cell(context);
+                mdast_phrasing(context, &cell.children)?;
+                on_exit_gfm_table_cell(context);
+            }
+        }
+        Node::Break(_) => {
+            on_exit_break(context);
+            context.line_ending();
+        }
+        Node::InlineCode(code) => {
+            let element = context.options.element_overrides.inline_code.as_ref();
+            context.push_element_open("code", element);
+            context.push(&encode(&mdast_raw_text_value(&code.value), true));
+            context.push_element_close("code", element);
+        }
+        Node::InlineMath(math) => {
+            context.push("<code");
+            context.push_attribute("class", "language-math math-inline");
+            context.push(">");
+            context.push(&encode(&mdast_raw_text_value(&math.value), true));
+            context.push("</code>");
+        }
+        Node::Delete(delete) => {
+            on_enter_gfm_strikethrough(context);
+            mdast_phrasing(context, &delete.children)?;
+            on_exit_gfm_strikethrough(context);
+        }
+        Node::Emphasis(emphasis) => {
+            on_enter_emphasis(context);
+            mdast_phrasing(context, &emphasis.children)?;
+            on_exit_emphasis(context);
+        }
+        Node::Strong(strong) => {
+            on_enter_strong(context);
+            mdast_phrasing(context, &strong.children)?;
+            on_exit_strong(context);
+        }
+        Node::Text(text) => {
+            let value = if context.options.smart_punctuation {
+                let value = smart_punctuation(text.value.as_bytes(), 0, text.value.len());
+                encode(&value, true).into_owned()
+            } else {
+                encode(&text.value, true).into_owned()
+            };
+
+            if context.options.hard_wrap {
+                let br = format!("<br{}", context.self_closing());
+                context.push(&mdast_hard_wrap(&value, &br));
+            } else {
+                context.push(&value);
+            }
+        }
+        Node::Link(link) => {
+            let title = link.title.as_ref().map(|d| encode(d, true));
+            mdast_link(context, &link.url, title.as_deref(), &link.children)?;
+        }
+        Node::Image(image) => {
+            let title = image.title.as_ref().map(|d| encode(d, true));
+            mdast_image(context, &image.url, title.as_deref(), &image.alt);
+        }
+        Node::LinkReference(reference) => {
+            let id = normalize_identifier(&reference.identifier);
+
+            if let Some(definition) = context.definitions.iter().find(|d| d.id == id) {
+                let url = definition.destination.clone().unwrap_or_default();
+                let title = definition.title.clone();
+                mdast_link(context, &url, title.as_deref(), &reference.children)?;
+            } else {
+                context.push("[");
+                mdast_phrasing(context, &reference.children)?;
+                context.push("]");
+                mdast_reference_suffix(
+                    context,
+                    reference.reference_kind,
+                    reference.label.as_ref().unwrap_or(&reference.identifier),
+                );
+            }
+        }
+        Node::ImageReference(reference) => {
+            let id = normalize_identifier(&reference.identifier);
+
+            if let Some(definition) = context.definitions.iter().find(|d| d.id == id) {
+                let url = definition.destination.clone().unwrap_or_default();
+                let title = definition.title.clone();
+                mdast_image(context, &url, title.as_deref(), &reference.alt);
+            } else {
+                context.push("![");
+                context.push(&encode(&reference.alt, true));
+                context.push("]");
+                mdast_reference_suffix(
+                    context,
+                    reference.reference_kind,
+                    reference.label.as_ref().unwrap_or(&reference.identifier),
+                );
+            }
+        }
+        Node::FootnoteReference(reference) => {
+            let id = normalize_identifier(&reference.identifier);
+
+            if context
+                .gfm_footnote_definition_identifiers
+                .iter()
+                .any(|d| *d == id)
+            {
+                generate_footnote_call(context, id.into_owned());
+            } else {
+                context.push("[^");
+                context.push(&encode(
+                    reference.label.as_ref().unwrap_or(&reference.identifier),
+                    true,
+                ));
+                context.push("]");
+            }
+        }
+        Node::MdxJsxFlowElement(element) if context.options.skip_mdx_nodes => {
+            mdast_flow(context, &element.children)?;
+        }
+        Node::MdxJsxTextElement(element) if context.options.skip_mdx_nodes => {
+            mdast_phrasing(context, &element.children)?;
+        }
+        Node::MdxjsEsm(_) | Node::MdxFlowExpression(_) | Node::MdxTextExpression(_)
+            if context.options.skip_mdx_nodes => {}
+        Node::MdxjsEsm(_)
+        | Node::MdxFlowExpression(_)
+        | Node::MdxTextExpression(_)
+        | Node::MdxJsxFlowElement(_)
+        | Node::MdxJsxTextElement(_) => {
+            let name = match node {
+                Node::MdxjsEsm(_) => "MdxjsEsm",
+                Node::MdxFlowExpression(_) => "MdxFlowExpression",
+                Node::MdxTextExpression(_) => "MdxTextExpression",
+                Node::MdxJsxFlowElement(_) => "MdxJsxFlowElement",
+                _ => "MdxJsxTextElement",
+            };
+
+            return Err(message::Message {
+                place: node
+                    .position()
+                    .map(|d| Box::new(message::Place::Position(d.clone()))),
+                reason: format!(
+                    "Unexpected MDX node `{}`, expected markdown (pass `skip_mdx_nodes: true` to drop MDX)",
+                    name
+                ),
+                rule_id: Box::new(message::rules::UNEXPECTED_MDX_NODE.into()),
+                source: Box::new(message::rules::SOURCE.into()),
+            });
+        }
+        Node::Toml(toml) => generate_frontmatter(context, &toml.value, FrontmatterKind::Toml),
+        Node::Yaml(yaml) => generate_frontmatter(context, &yaml.value, FrontmatterKind::Yaml),
+        // Things that don’t contribute to the document.
+        Node::Definition(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Compile a list item.
+fn mdast_list_item(context: &mut CompileContext, item: &ListItem) -> Result<(), message::Message> {
+    let tight = *context.tight_stack.last().unwrap_or(&false);
+    context.line_ending_if_needed();
+    context.push("<li>");
+
+    let mut index = 0;
+    let mut seen_content = false;
+    while index < item.children.len() {
+        let child = &item.children[index];
+
+        match child {
+            Node::Paragraph(paragraph) if index == 0 && item.checked.is_some() => {
+                on_enter_paragraph(context);
+                mdast_task_list_item_check(context, item, child);
+                mdast_phrasing(context, &paragraph.children)?;
+                on_exit_paragraph(context);
+                context.slurp_one_line_ending = false;
+            }
+            _ => {
+                if index == 0 && item.checked.is_some() {
+                    mdast_task_list_item_check(context, item, child);
+                }
+
+                // Tight paragraphs are not separated from what comes before
+                // them by their tags.
+                if tight && seen_content && matches!(child, Node::Paragraph(_)) {
+                    context.line_ending_if_needed();
+                }
+
+                mdast_flow_node(context, child)?;
+            }
+        }
+
+        // Ignore things that don’t contribute to the document.
+        if !matches!(
+            child,
+            Node::Definition(_) | Node::FootnoteDefinition(_) | Node::Toml(_) | Node::Yaml(_)
+        ) {
+            seen_content = true;
+        }
+
+        index += 1;
+    }
+
+    // Ignore things that don’t contribute to the document.
+    let last = item.children.iter().rev().find(|d| {
+        !matches!(
+            d,
+            Node::Definition(_) | Node::FootnoteDefinition(_) | Node::Toml(_) | Node::Yaml(_)
+        )
+    });
+    let tight_paragraph = tight && matches!(last, Some(Node::Paragraph(_)));
+    let empty_item = last.is_none();
+
+    if !tight_paragraph && !empty_item {
+        context.line_ending_if_needed();
+    }
+
+    context.push("</li>");
+    Ok(())
+}
+
+/// Compile the checkbox of a task list item, before `node`.
+fn mdast_task_list_item_check(context: &mut CompileContext, item: &ListItem, node: &Node) {
+    context.push("<input");
+    context.push_attribute("type", "checkbox");
+    if !context.options.gfm_task_list_item_checkable {
+        context.push_boolean_attribute("disabled");
+    }
+    // The check is not in the tree, but it is followed by one space or tab
+    // before the content.
+    if context.options.gfm_task_list_item_data_offset {
+        if let Some(position) = node.position() {
+            if let Some(offset) = position.start.offset.checked_sub(4) {
+                context.push_attribute("data-offset", &offset.to_string());
+            }
+        }
+    }
+    if item.checked == Some(true) {
+        context.push_boolean_attribute("checked");
+    }
+    context.push_self_closing();
+    context.push(" ");
+}
+
+/// Compile the value of code (flow) or math (flow).
+fn mdast_raw_flow_value(context: &mut CompileContext, value: &str) {
+    if !value.is_empty() {
+        context.push(&encode(value, true));
+        context.line_ending();
+    }
+
+    context.push("</code></pre>");
+}
+
+/// Get the value of code (text) or math (text) as it is in HTML.
+///
+/// Line endings are turned into spaces.
+/// Padding made with spaces is already removed in the tree, but padding made
+/// with line endings is not.
+fn mdast_raw_text_value(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let eol_padding =
+        matches!(bytes.first(), Some(b'\n' | b'\r')) || matches!(bytes.last(), Some(b'\n' | b'\r'));
+    let mut value = value.replace("\r\n", " ").replace(['\r', '\n'], " ");
+
+    if eol_padding
+        && value.len() > 2
+        && value.starts_with(' ')
+        && value.ends_with(' ')
+        && !value.bytes().all(|d| d == b' ')
+    {
+        value.remove(0);
+        value.pop();
+    }
+
+    value
+}
+
+/// Turn line endings in the value of text into breaks, for `hard_wrap`.
+fn mdast_hard_wrap(value: &str, br: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut result = String::with_capacity(value.len());
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        // Once for CRLF.
+        if bytes[index] == b'\r'
+            || (bytes[index] == b'\n' && (index == 0 || bytes[index - 1] != b'\r'))
+        {
+            result.push_str(&value[start..index]);
+            result.push_str(br);
+            start = index;
+        }
+
+        index += 1;
+    }
+
+    result.push_str(&value[start..]);
+    result
+}
+
+/// Compile a link.
+///
+/// `title` must already be encoded.
+fn mdast_link(
+    context: &mut CompileContext,
+    url: &str,
+    title: Option<&str>,
+    children: &[Node],
+) -> Result<(), message::Message> {
+    let url = resolve_url(context, url);
+    let url = url.as_ref();
+    let url = sanitize_url(context, url, false);
+
+    // Links in links (such as GFM autolink literals) are only their content.
+    if context.media_stack.iter().any(|d| !d.image) {
+        return mdast_phrasing(context, children);
+    }
+
+    context.push("<a");
+    context.push_attribute("href", &url);
+    if let Some(title) = title {
+        context.push_attribute("title", title);
+    }
+    context.push(">");
+    context.media_stack.push(Media {
+        image: false,
+        footnote_inline: false,
+        label_id: None,
+        label: None,
+        reference_id: None,
+        destination: None,
+        title: None,
+    });
+    mdast_phrasing(context, children)?;
+    context.media_stack.pop();
+    context.push("</a>");
+    Ok(())
+}
+
+/// Compile an image.
+///
+/// `title` must already be encoded.
+fn mdast_image(context: &mut CompileContext, url: &str, title: Option<&str>, alt: &str) {
+    let url = resolve_url(context, url);
+    let url = url.as_ref();
+    let url = sanitize_url(context, url, true);
+
+    push_image(context, &url, &encode(alt, true), title);
+}
+
+/// Push an image, with encoded `src`, `alt`, and `title`, through
+/// `image_renderer` if there is one.
+fn push_image(context: &mut CompileContext, src: &str, alt: &str, title: Option<&str>) {
+    if let Some(renderer) = &context.options.image_renderer {
+        let info = ImageInfo {
+            src: decode(src),
+            alt: decode(alt),
+            title: title.map(decode),
+        };
+
+        if let Some(html) = renderer(&info) {
+            context.push(&html);
+            return;
+        }
+    }
+
+    context.push("<img");
+    context.push_attribute("src", src);
+    context.push_attribute("alt", alt);
+    if let Some(title) = title {
+        context.push_attribute("title", title);
+    }
+    context.push_self_closing();
+}
+
+/// Compile what comes after the label of a reference that is not defined,
+/// as text.
+fn mdast_reference_suffix(context: &mut CompileContext, kind: ReferenceKind, label: &str) {
+    match kind {
+        ReferenceKind::Shortcut => {}
+        ReferenceKind::Collapsed => context.push("[]"),
+        ReferenceKind::Full => {
+            context.push("[");
+            context.push(&encode(label, true));
+            context.push("]");
+        }
+    }
+}
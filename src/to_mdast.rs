@@ -1,11 +1,13 @@
 //! Turn events into a syntax tree.
 
+use crate::configuration::ParseOptions;
+use crate::construct::text::markers as text_markers;
 use crate::event::{Event, Kind, Name};
 use crate::mdast::{
     AttributeContent, AttributeValue, AttributeValueExpression, Blockquote, Break, Code,
-    Definition, Delete, Emphasis, FootnoteDefinition, FootnoteReference, Heading, Html, Image,
-    ImageReference, InlineCode, InlineMath, Link, LinkReference, List, ListItem, Math,
-    MdxFlowExpression, MdxJsxAttribute, MdxJsxExpressionAttribute, MdxJsxFlowElement,
+    Definition, Delete, Emphasis, FootnoteDefinition, FootnoteReference, Heading, Html, HtmlKind,
+    Image, ImageReference, InlineCode, InlineMath, Link, LinkReference, List, ListItem, Markers,
+    Math, MdxFlowExpression, MdxJsxAttribute, MdxJsxExpressionAttribute, MdxJsxFlowElement,
     MdxJsxTextElement, MdxTextExpression, MdxjsEsm, Node, Paragraph, ReferenceKind, Root, Strong,
     Table, TableCell, TableRow, Text, ThematicBreak, Toml, Yaml,
 };
@@ -15,7 +17,11 @@ use crate::util::{
     character_reference::{
         decode as decode_character_reference, parse as parse_character_reference,
     },
-    infer::{gfm_table_align, list_item_loose, list_loose},
+    constant::HTML_VOID_NAMES,
+    infer::{
+        exits, footnote_inline_identifiers, gfm_table_align, list_item_loose, list_kind, list_loose,
+    },
+    list_value,
     mdx_collect::{collect, Result as CollectResult},
     normalize_identifier::normalize_identifier,
     slice::{Position as SlicePosition, Slice},
@@ -27,7 +33,7 @@ use alloc::{
     vec,
     vec::Vec,
 };
-use core::str;
+use core::{mem, str};
 
 /// A reference to something.
 #[derive(Debug)]
@@ -90,9 +96,14 @@ struct CompileContext<'a> {
     events: &'a [Event],
     /// List of bytes.
     bytes: &'a [u8],
+    /// Configuration.
+    options: &'a ParseOptions,
     // Fields used by handlers to track the things they need to track to
     // compile markdown.
     character_reference_marker: u8,
+    footnote_inline_count: usize,
+    footnote_inline_definitions: Vec<Node>,
+    footnote_inline_identifiers: Vec<String>,
     gfm_table_inside: bool,
     hard_break_after: bool,
     heading_setext_text_after: bool,
@@ -100,16 +111,27 @@ struct CompileContext<'a> {
     jsx_tag: Option<JsxTag>,
     media_reference_stack: Vec<Reference>,
     raw_flow_fence_seen: bool,
+    /// Where events exit, made when the first list is found.
+    list_exits: Vec<usize>,
+    /// Opening JSX tags closed by a tag with the same name, made when the
+    /// first unclosed void element is found.
+    jsx_closed_tags: Option<Vec<usize>>,
+    /// Bytes that can start something in text, when splitting text.
+    text_markers: Vec<u8>,
     // Intermediate results.
     /// Primary tree and buffers.
-    trees: Vec<(Node, Vec<usize>, Vec<usize>)>,
+    ///
+    /// Each is a stack of open nodes, starting at the root, whose nodes are
+    /// added to their parents when they close, and a stack of the events
+    /// that opened them.
+    trees: Vec<(Vec<Node>, Vec<usize>)>,
     /// Current event index.
     index: usize,
 }
 
 impl<'a> CompileContext<'a> {
     /// Create a new compile context.
-    fn new(events: &'a [Event], bytes: &'a [u8]) -> CompileContext<'a> {
+    fn new(events: &'a [Event], bytes: &'a [u8], options: &'a ParseOptions) -> CompileContext<'a> {
         let tree = Node::Root(Root {
             children: vec![],
             position: Some(Position {
@@ -124,12 +146,17 @@ impl<'a> CompileContext<'a> {
                     events[events.len() - 1].point.to_unist()
                 },
             }),
+            raw: None,
         });
 
         CompileContext {
             events,
             bytes,
+            options,
             character_reference_marker: 0,
+            footnote_inline_count: 0,
+            footnote_inline_definitions: vec![],
+            footnote_inline_identifiers: vec![],
             gfm_table_inside: false,
             hard_break_after: false,
             heading_setext_text_after: false,
@@ -137,7 +164,14 @@ impl<'a> CompileContext<'a> {
             jsx_tag: None,
             media_reference_stack: vec![],
             raw_flow_fence_seen: false,
-            trees: vec![(tree, vec![], vec![])],
+            list_exits: vec![],
+            jsx_closed_tags: None,
+            text_markers: if options.mdast_split_text {
+                text_markers(options)
+            } else {
+                vec![]
+            },
+            trees: vec![(vec![tree], vec![])],
             index: 0,
         }
     }
@@ -145,42 +179,43 @@ impl<'a> CompileContext<'a> {
     /// Push a buffer.
     fn buffer(&mut self) {
         self.trees.push((
-            Node::Paragraph(Paragraph {
+            vec![Node::Paragraph(Paragraph {
                 children: vec![],
                 position: None,
-            }),
-            vec![],
+                raw: None,
+            })],
             vec![],
         ));
     }
 
     /// Pop a buffer, returning its value.
     fn resume(&mut self) -> Node {
-        if let Some((node, stack_a, stack_b)) = self.trees.pop() {
+        if let Some((mut nodes, event_stack)) = self.trees.pop() {
             debug_assert_eq!(
-                stack_a.len(),
-                0,
+                nodes.len(),
+                1,
                 "expected stack (nodes in tree) to be drained"
             );
             debug_assert_eq!(
-                stack_b.len(),
+                event_stack.len(),
                 0,
                 "expected stack (opening events) to be drained"
             );
-            node
+            nodes.pop().unwrap()
         } else {
             unreachable!("Cannot resume w/o buffer")
         }
     }
 
     fn tail_mut(&mut self) -> &mut Node {
-        let (tree, stack, _) = self.trees.last_mut().expect("Cannot get tail w/o tree");
-        delve_mut(tree, stack)
+        let (nodes, _) = self.trees.last_mut().expect("Cannot get tail w/o tree");
+        nodes.last_mut().unwrap()
     }
 
     fn tail_penultimate_mut(&mut self) -> &mut Node {
-        let (tree, stack, _) = self.trees.last_mut().expect("Cannot get tail w/o tree");
-        delve_mut(tree, &stack[0..(stack.len() - 1)])
+        let (nodes, _) = self.trees.last_mut().expect("Cannot get tail w/o tree");
+        let index = nodes.len() - 2;
+        &mut nodes[index]
     }
 
     fn tail_push(&mut self, mut child: Node) {
@@ -188,32 +223,58 @@ impl<'a> CompileContext<'a> {
             child.position_set(Some(position_from_event(&self.events[self.index])));
         }
 
-        let (tree, stack, event_stack) = self.trees.last_mut().expect("Cannot get tail w/o tree");
-        let node = delve_mut(tree, stack);
-        let children = node.children_mut().expect("Cannot push to non-parent");
-        let index = children.len();
-        children.push(child);
-        stack.push(index);
+        let (nodes, event_stack) = self.trees.last_mut().expect("Cannot get tail w/o tree");
+        debug_assert!(
+            nodes.last().unwrap().children().is_some(),
+            "Cannot push to non-parent"
+        );
+        nodes.push(child);
         event_stack.push(self.index);
     }
 
     fn tail_push_again(&mut self) {
-        let (tree, stack, event_stack) = self.trees.last_mut().expect("Cannot get tail w/o tree");
-        let node = delve_mut(tree, stack);
-        let children = node.children().expect("Cannot push to non-parent");
-        stack.push(children.len() - 1);
+        let (nodes, event_stack) = self.trees.last_mut().expect("Cannot get tail w/o tree");
+        let children = nodes
+            .last_mut()
+            .unwrap()
+            .children_mut()
+            .expect("Cannot push to non-parent");
+        let child = children.pop().unwrap();
+        nodes.push(child);
         event_stack.push(self.index);
     }
 
     fn tail_pop(&mut self) -> Result<(), message::Message> {
+        self.tail_pop_at(self.events[self.index].point.to_unist())
+    }
+
+    fn tail_pop_at(&mut self, end: Point) -> Result<(), message::Message> {
         let ev = &self.events[self.index];
-        let end = ev.point.to_unist();
-        let (tree, stack, event_stack) = self.trees.last_mut().expect("Cannot get tail w/o tree");
-        let node = delve_mut(tree, stack);
+
+        // Closing something that was opened outside of the current buffer,
+        // such as a closing tag in a label, with its opening tag before it.
+        if self.trees.last().unwrap().1.is_empty() {
+            let left_index = *self
+                .trees
+                .iter()
+                .rev()
+                .find_map(|(_, event_stack)| event_stack.last())
+                .expect("Cannot pop w/o opening event");
+            let left = &self.events[left_index];
+            return on_mismatch_error(self, Some(ev), left);
+        }
+
+        let (nodes, event_stack) = self.trees.last_mut().expect("Cannot get tail w/o tree");
+        let mut node = nodes.pop().unwrap();
         let pos = node.position_mut().expect("Cannot pop manually added node");
         pos.end = end;
+        nodes
+            .last_mut()
+            .unwrap()
+            .children_mut()
+            .expect("Cannot push to non-parent")
+            .push(node);
 
-        stack.pop().unwrap();
         let left_index = event_stack.pop().unwrap();
         let left = &self.events[left_index];
         if left.name != ev.name {
@@ -225,8 +286,12 @@ impl<'a> CompileContext<'a> {
 }
 
 /// Turn events and bytes into a syntax tree.
-pub fn compile(events: &[Event], bytes: &[u8]) -> Result<Node, message::Message> {
-    let mut context = CompileContext::new(events, bytes);
+pub fn compile(
+    events: &[Event],
+    bytes: &[u8],
+    options: &ParseOptions,
+) -> Result<Node, message::Message> {
+    let mut context = CompileContext::new(events, bytes, options);
 
     let mut index = 0;
     while index < events.len() {
@@ -235,16 +300,95 @@ pub fn compile(events: &[Event], bytes: &[u8]) -> Result<Node, message::Message>
     }
 
     debug_assert_eq!(context.trees.len(), 1, "expected 1 final tree");
-    let (tree, _, event_stack) = context.trees.pop().unwrap();
+    let (mut nodes, event_stack) = context.trees.pop().unwrap();
 
     if let Some(index) = event_stack.last() {
         let event = &events[*index];
         on_mismatch_error(&mut context, None, event)?;
     }
 
+    let mut tree = nodes.pop().unwrap();
+
+    if options.preserve {
+        preserve(&mut tree, bytes);
+    }
+
+    // Inline footnotes are hoisted to the root as definitions.
+    tree.children_mut()
+        .unwrap()
+        .append(&mut context.footnote_inline_definitions);
+
     Ok(tree)
 }
 
+/// Push data to a value.
+///
+/// NUL is replaced with a replacement character (`�`), as `CommonMark`
+/// requires, like [`encode`][crate::util::encode::encode] does in HTML.
+fn push_data(target: &mut String, value: &str) {
+    for (index, part) in value.split('\0').enumerate() {
+        if index != 0 {
+            target.push(char::REPLACEMENT_CHARACTER);
+        }
+
+        target.push_str(part);
+    }
+}
+
+/// Set the source of the root and its descendants.
+fn preserve(tree: &mut Node, bytes: &[u8]) {
+    tree.raw_set(Some(str::from_utf8(bytes).unwrap().into()));
+    preserve_children(tree, bytes, false);
+}
+
+/// Set the source of the descendants of `parent`.
+///
+/// Nodes in containers that span several lines are skipped, as their source
+/// includes the prefixes of those containers (such as `> `), which are added
+/// again when serializing the containers.
+fn preserve_children(parent: &mut Node, bytes: &[u8], contained: bool) {
+    let contained = contained
+        || matches!(
+            parent,
+            Node::Blockquote(_)
+                | Node::FootnoteDefinition(_)
+                | Node::List(_)
+                | Node::ListItem(_)
+                | Node::MdxJsxFlowElement(_)
+        );
+
+    if let Some(children) = parent.children_mut() {
+        for child in children {
+            if let Some(position) = child.position() {
+                let value =
+                    Slice::from_indices(bytes, position.start.offset, position.end.offset).as_str();
+
+                if !contained || !value.contains(['\n', '\r']) {
+                    child.raw_set(Some(value.into()));
+                }
+            }
+
+            preserve_children(child, bytes, contained);
+        }
+    }
+}
+
+/// Turn events of one content type, wrapped in a paragraph, into nodes.
+///
+/// Returns the children of the paragraph, followed by the definitions of
+/// inline footnotes.
+pub fn compile_fragment(
+    events: &[Event],
+    bytes: &[u8],
+    options: &ParseOptions,
+) -> Result<Vec<Node>, message::Message> {
+    let mut tree = compile(events, bytes, options)?;
+    let mut children = mem::take(tree.children_mut().expect("expected root"));
+    let mut nodes = mem::take(children[0].children_mut().expect("expected paragraph"));
+    nodes.extend(children.drain(1..));
+    Ok(nodes)
+}
+
 /// Handle the event at `index`.
 fn handle(context: &mut CompileContext, index: usize) -> Result<(), message::Message> {
     context.index = index;
@@ -293,6 +437,7 @@ fn enter(context: &mut CompileContext) -> Result<(), message::Message> {
         Name::CodeText => on_enter_code_text(context),
         Name::Definition => on_enter_definition(context),
         Name::Emphasis => on_enter_emphasis(context),
+        Name::FootnoteInline => on_enter_footnote_inline(context),
         Name::Frontmatter => on_enter_frontmatter(context),
         Name::GfmAutolinkLiteralEmail
         | Name::GfmAutolinkLiteralMailto
@@ -340,22 +485,22 @@ fn enter(context: &mut CompileContext) -> Result<(), message::Message> {
 fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
     match context.events[context.index].name {
         Name::Autolink
-        | Name::BlockQuote
         | Name::CharacterReference
         | Name::Definition
         | Name::Emphasis
-        | Name::GfmFootnoteDefinition
         | Name::GfmStrikethrough
         | Name::GfmTableRow
         | Name::GfmTableCell
         | Name::HeadingAtx
-        | Name::ListOrdered
-        | Name::ListUnordered
         | Name::Paragraph
         | Name::Strong
         | Name::ThematicBreak => {
             on_exit(context)?;
         }
+        Name::BlockQuote | Name::GfmFootnoteDefinition | Name::ListItem => {
+            on_exit_container(context)?;
+        }
+        Name::ListOrdered | Name::ListUnordered => on_exit_list(context)?,
         Name::CharacterEscapeValue
         | Name::CodeFlowChunk
         | Name::CodeTextData
@@ -370,6 +515,7 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
         }
         Name::MdxJsxTagAttributeExpression | Name::MdxJsxTagAttributeValueExpression => {
             on_exit_drop(context);
+            mdx_jsx_tag_attribute_end(context);
         }
         Name::AutolinkProtocol => on_exit_autolink_protocol(context)?,
         Name::AutolinkEmail => on_exit_autolink_email(context)?,
@@ -382,6 +528,7 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
         }
         Name::CharacterReferenceValue => on_exit_character_reference_value(context),
         Name::CodeFencedFenceInfo => on_exit_code_fenced_fence_info(context),
+        Name::CodeFencedFenceSequence => on_exit_code_fenced_fence_sequence(context),
         Name::CodeFencedFenceMeta | Name::MathFlowFenceMeta => on_exit_raw_flow_fence_meta(context),
         Name::CodeFencedFence | Name::MathFlowFence => on_exit_raw_flow_fence(context),
         Name::CodeFenced | Name::MathFlow => on_exit_raw_flow(context)?,
@@ -392,6 +539,8 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
             on_exit_definition_id(context);
         }
         Name::DefinitionTitleString => on_exit_definition_title_string(context),
+        Name::EmphasisSequence | Name::StrongSequence => on_exit_attention_sequence(context),
+        Name::FootnoteInline => on_exit_footnote_inline(context)?,
         Name::Frontmatter => on_exit_frontmatter(context)?,
         Name::GfmAutolinkLiteralEmail
         | Name::GfmAutolinkLiteralMailto
@@ -409,9 +558,9 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
         Name::HeadingSetextUnderlineSequence => on_exit_heading_setext_underline_sequence(context),
         Name::HeadingSetextText => on_exit_heading_setext_text(context),
         Name::HtmlFlow | Name::HtmlText => on_exit_html(context)?,
-        Name::LabelText => on_exit_label_text(context),
+        Name::LabelText => on_exit_label_text(context)?,
         Name::LineEnding => on_exit_line_ending(context)?,
-        Name::ListItem => on_exit_list_item(context)?,
+        Name::ListItemMarker => on_exit_list_item_marker(context),
         Name::ListItemValue => on_exit_list_item_value(context),
         Name::MdxEsm | Name::MdxFlowExpression | Name::MdxTextExpression => {
             on_exit_mdx_esm_or_expression(context)?;
@@ -431,6 +580,7 @@ fn exit(context: &mut CompileContext) -> Result<(), message::Message> {
         Name::ReferenceString => on_exit_reference_string(context),
         Name::ResourceDestinationString => on_exit_resource_destination_string(context),
         Name::ResourceTitleString => on_exit_resource_title_string(context),
+        Name::ThematicBreakSequence => on_exit_thematic_break_sequence(context),
         _ => {}
     }
 
@@ -444,16 +594,27 @@ fn on_enter_buffer(context: &mut CompileContext) {
 
 /// Handle [`Enter`][Kind::Enter]:[`Data`][Name::Data] (and many text things).
 fn on_enter_data(context: &mut CompileContext) {
+    // When splitting text, data directly after data, or data that starts
+    // with a marker, is where the tokenizer did not find a construct, so it
+    // starts a new node.
+    let event = &context.events[context.index];
+    let split = context.options.mdast_split_text
+        && event.name == Name::Data
+        && ((context.index > 0 && context.events[context.index - 1].name == Name::Data)
+            || context
+                .text_markers
+                .contains(&context.bytes[event.point.index]));
     let parent = context.tail_mut();
     let children = parent.children_mut().expect("expected parent");
 
     // Add to stack again.
-    if let Some(Node::Text(_)) = children.last_mut() {
+    if !split && matches!(children.last(), Some(Node::Text(_))) {
         context.tail_push_again();
     } else {
         context.tail_push(Node::Text(Text {
             value: String::new(),
             position: None,
+            raw: None,
         }));
     }
 }
@@ -465,6 +626,7 @@ fn on_enter_autolink(context: &mut CompileContext) {
         title: None,
         children: vec![],
         position: None,
+        raw: None,
     }));
 }
 
@@ -473,6 +635,7 @@ fn on_enter_block_quote(context: &mut CompileContext) {
     context.tail_push(Node::Blockquote(Blockquote {
         children: vec![],
         position: None,
+        raw: None,
     }));
 }
 
@@ -483,6 +646,8 @@ fn on_enter_code_fenced(context: &mut CompileContext) {
         meta: None,
         value: String::new(),
         position: None,
+        markers: None,
+        raw: None,
     }));
 }
 
@@ -497,6 +662,7 @@ fn on_enter_code_text(context: &mut CompileContext) {
     context.tail_push(Node::InlineCode(InlineCode {
         value: String::new(),
         position: None,
+        raw: None,
     }));
     context.buffer();
 }
@@ -506,6 +672,7 @@ fn on_enter_math_text(context: &mut CompileContext) {
     context.tail_push(Node::InlineMath(InlineMath {
         value: String::new(),
         position: None,
+        raw: None,
     }));
     context.buffer();
 }
@@ -523,6 +690,7 @@ fn on_enter_mdx_esm(context: &mut CompileContext) {
         value: result.value,
         position: None,
         stops: result.stops,
+        raw: None,
     }));
     context.buffer();
 }
@@ -540,6 +708,7 @@ fn on_enter_mdx_flow_expression(context: &mut CompileContext) {
         value: result.value,
         position: None,
         stops: result.stops,
+        raw: None,
     }));
     context.buffer();
 }
@@ -557,6 +726,7 @@ fn on_enter_mdx_text_expression(context: &mut CompileContext) {
         value: result.value,
         position: None,
         stops: result.stops,
+        raw: None,
     }));
     context.buffer();
 }
@@ -569,6 +739,7 @@ fn on_enter_definition(context: &mut CompileContext) {
         label: None,
         title: None,
         position: None,
+        raw: None,
     }));
 }
 
@@ -577,9 +748,24 @@ fn on_enter_emphasis(context: &mut CompileContext) {
     context.tail_push(Node::Emphasis(Emphasis {
         children: vec![],
         position: None,
+        markers: None,
+        raw: None,
     }));
 }
 
+/// Handle [`Enter`][Kind::Enter]:[`FootnoteInline`][Name::FootnoteInline].
+fn on_enter_footnote_inline(context: &mut CompileContext) {
+    // Turned into a reference on exit.
+    context.tail_push(Node::FootnoteDefinition(FootnoteDefinition {
+        identifier: String::new(),
+        label: None,
+        children: vec![],
+        position: None,
+        raw: None,
+    }));
+    context.media_reference_stack.push(Reference::new());
+}
+
 /// Handle [`Enter`][Kind::Enter]:{[`GfmAutolinkLiteralEmail`][Name::GfmAutolinkLiteralEmail],[`GfmAutolinkLiteralMailto`][Name::GfmAutolinkLiteralMailto],[`GfmAutolinkLiteralProtocol`][Name::GfmAutolinkLiteralProtocol],[`GfmAutolinkLiteralWww`][Name::GfmAutolinkLiteralWww],[`GfmAutolinkLiteralXmpp`][Name::GfmAutolinkLiteralXmpp]}.
 fn on_enter_gfm_autolink_literal(context: &mut CompileContext) {
     on_enter_autolink(context);
@@ -592,6 +778,7 @@ fn on_enter_gfm_footnote_call(context: &mut CompileContext) {
         identifier: String::new(),
         label: None,
         position: None,
+        raw: None,
     }));
     context.media_reference_stack.push(Reference::new());
 }
@@ -603,6 +790,7 @@ fn on_enter_gfm_footnote_definition(context: &mut CompileContext) {
         label: None,
         children: vec![],
         position: None,
+        raw: None,
     }));
 }
 
@@ -611,6 +799,7 @@ fn on_enter_gfm_strikethrough(context: &mut CompileContext) {
     context.tail_push(Node::Delete(Delete {
         children: vec![],
         position: None,
+        raw: None,
     }));
 }
 
@@ -621,6 +810,7 @@ fn on_enter_gfm_table(context: &mut CompileContext) {
         align,
         children: vec![],
         position: None,
+        raw: None,
     }));
     context.gfm_table_inside = true;
 }
@@ -630,6 +820,7 @@ fn on_enter_gfm_table_row(context: &mut CompileContext) {
     context.tail_push(Node::TableRow(TableRow {
         children: vec![],
         position: None,
+        raw: None,
     }));
 }
 
@@ -638,12 +829,16 @@ fn on_enter_gfm_table_cell(context: &mut CompileContext) {
     context.tail_push(Node::TableCell(TableCell {
         children: vec![],
         position: None,
+        raw: None,
     }));
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`HardBreakEscape`][Name::HardBreakEscape].
 fn on_enter_hard_break(context: &mut CompileContext) {
-    context.tail_push(Node::Break(Break { position: None }));
+    context.tail_push(Node::Break(Break {
+        position: None,
+        raw: None,
+    }));
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`Frontmatter`][Name::Frontmatter].
@@ -654,11 +849,13 @@ fn on_enter_frontmatter(context: &mut CompileContext) {
         Node::Toml(Toml {
             value: String::new(),
             position: None,
+            raw: None,
         })
     } else {
         Node::Yaml(Yaml {
             value: String::new(),
             position: None,
+            raw: None,
         })
     };
 
@@ -692,12 +889,18 @@ fn on_enter_strong(context: &mut CompileContext) {
     context.tail_push(Node::Strong(Strong {
         children: vec![],
         position: None,
+        markers: None,
+        raw: None,
     }));
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`ThematicBreak`][Name::ThematicBreak].
 fn on_enter_thematic_break(context: &mut CompileContext) {
-    context.tail_push(Node::ThematicBreak(ThematicBreak { position: None }));
+    context.tail_push(Node::ThematicBreak(ThematicBreak {
+        position: None,
+        markers: None,
+        raw: None,
+    }));
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`HeadingAtx`][Name::HeadingAtx].
@@ -706,14 +909,20 @@ fn on_enter_heading(context: &mut CompileContext) {
         depth: 0, // Will be set later.
         children: vec![],
         position: None,
+        markers: None,
+        raw: None,
     }));
 }
 
 /// Handle [`Enter`][Kind::Enter]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_enter_html(context: &mut CompileContext) {
+    // One node for the whole span: its data and line endings are buffered
+    // into a single value.
     context.tail_push(Node::Html(Html {
         value: String::new(),
         position: None,
+        kind: None,
+        raw: None,
     }));
     context.buffer();
 }
@@ -725,6 +934,7 @@ fn on_enter_image(context: &mut CompileContext) {
         title: None,
         alt: String::new(),
         position: None,
+        raw: None,
     }));
     context.media_reference_stack.push(Reference::new());
 }
@@ -736,6 +946,7 @@ fn on_enter_link(context: &mut CompileContext) {
         title: None,
         children: vec![],
         position: None,
+        raw: None,
     }));
     context.media_reference_stack.push(Reference::new());
 }
@@ -743,26 +954,45 @@ fn on_enter_link(context: &mut CompileContext) {
 /// Handle [`Enter`][Kind::Enter]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
 fn on_enter_list(context: &mut CompileContext) {
     let ordered = context.events[context.index].name == Name::ListOrdered;
-    let spread = list_loose(context.events, context.index, false);
+    if context.list_exits.is_empty() {
+        context.list_exits = exits(context.events);
+    }
+
+    let spread = list_loose(context.events, &context.list_exits, context.index, false);
+    let kind = if ordered {
+        list_kind(
+            context.events,
+            context.bytes,
+            &context.list_exits,
+            context.index,
+        )
+    } else {
+        None
+    };
 
     context.tail_push(Node::List(List {
         ordered,
         spread,
         start: None,
+        kind,
         children: vec![],
         position: None,
+        markers: None,
+        raw: None,
     }));
 }
 
 /// Handle [`Enter`][Kind::Enter]:[`ListItem`][Name::ListItem].
 fn on_enter_list_item(context: &mut CompileContext) {
-    let spread = list_item_loose(context.events, context.index);
+    let spread = list_item_loose(context.events, &context.list_exits, context.index);
 
     context.tail_push(Node::ListItem(ListItem {
         spread,
         checked: None,
         children: vec![],
         position: None,
+        markers: None,
+        raw: None,
     }));
 }
 
@@ -772,6 +1002,7 @@ fn on_enter_math_flow(context: &mut CompileContext) {
         meta: None,
         value: String::new(),
         position: None,
+        raw: None,
     }));
 }
 
@@ -798,8 +1029,8 @@ fn on_enter_mdx_jsx_tag_closing_marker(
         Err(message::Message {
             place: Some(Box::new(message::Place::Point(event.point.to_unist()))),
             reason: "Unexpected closing slash `/` in tag, expected an open tag first".into(),
-            rule_id: Box::new("unexpected-closing-slash".into()),
-            source: Box::new("markdown-rs".into()),
+            rule_id: Box::new(message::rules::UNEXPECTED_CLOSING_SLASH.into()),
+            source: Box::new(message::rules::SOURCE.into()),
         })
     } else {
         Ok(())
@@ -815,8 +1046,8 @@ fn on_enter_mdx_jsx_tag_any_attribute(
         Err(message::Message {
             place: Some(Box::new(message::Place::Point(event.point.to_unist()))),
             reason: "Unexpected attribute in closing tag, expected the end of the tag".into(),
-            rule_id: Box::new("unexpected-attribute".into()),
-            source: Box::new("markdown-rs".into()),
+            rule_id: Box::new(message::rules::UNEXPECTED_ATTRIBUTE.into()),
+            source: Box::new(message::rules::SOURCE.into()),
         })
     } else {
         Ok(())
@@ -833,6 +1064,10 @@ fn on_enter_mdx_jsx_tag_attribute(context: &mut CompileContext) -> Result<(), me
         .expect("expected tag")
         .attributes
         .push(AttributeContent::Property(MdxJsxAttribute {
+            position: Some(Position {
+                start: context.events[context.index].point.to_unist(),
+                end: context.events[context.index].point.to_unist(),
+            }),
             name: String::new(),
             value: None,
         }));
@@ -859,6 +1094,10 @@ fn on_enter_mdx_jsx_tag_attribute_expression(
         .expect("expected tag")
         .attributes
         .push(AttributeContent::Expression(MdxJsxExpressionAttribute {
+            position: Some(Position {
+                start: context.events[context.index].point.to_unist(),
+                end: context.events[context.index].point.to_unist(),
+            }),
             value,
             stops,
         }));
@@ -907,8 +1146,8 @@ fn on_enter_mdx_jsx_tag_self_closing_marker(
             place: Some(Box::new(message::Place::Point(event.point.to_unist()))),
             reason: "Unexpected self-closing slash `/` in closing tag, expected the end of the tag"
                 .into(),
-            rule_id: Box::new("unexpected-self-closing-slash".into()),
-            source: Box::new("markdown-rs".into()),
+            rule_id: Box::new(message::rules::UNEXPECTED_SELF_CLOSING_SLASH.into()),
+            source: Box::new(message::rules::SOURCE.into()),
         })
     } else {
         Ok(())
@@ -920,6 +1159,7 @@ fn on_enter_paragraph(context: &mut CompileContext) {
     context.tail_push(Node::Paragraph(Paragraph {
         children: vec![],
         position: None,
+        raw: None,
     }));
 }
 
@@ -929,6 +1169,51 @@ fn on_exit(context: &mut CompileContext) -> Result<(), message::Message> {
     Ok(())
 }
 
+/// Handle [`Exit`][Kind::Exit]:{[`BlockQuote`][Name::BlockQuote],[`GfmFootnoteDefinition`][Name::GfmFootnoteDefinition],[`ListItem`][Name::ListItem]}.
+///
+/// Like `mdast-util-from-markdown`, trailing line endings and blank lines
+/// are not part of the container.
+fn on_exit_container(context: &mut CompileContext) -> Result<(), message::Message> {
+    if context.events[context.index].name == Name::ListItem {
+        on_exit_list_item(context);
+    }
+
+    let name = &context.events[context.index].name;
+    let mut index = context.index;
+
+    while index > 1 && context.events[index - 1].kind == Kind::Exit {
+        match context.events[index - 1].name {
+            Name::BlankLineEnding | Name::LineEnding | Name::SpaceOrTab => index -= 2,
+            // Prefixes of block quotes around list items and definitions.
+            Name::BlockQuotePrefix if *name != Name::BlockQuote => {
+                index -= 1;
+                while !(context.events[index].kind == Kind::Enter
+                    && context.events[index].name == Name::BlockQuotePrefix)
+                {
+                    index -= 1;
+                }
+            }
+            _ => break,
+        }
+    }
+
+    context.tail_pop_at(context.events[index].point.to_unist())
+}
+
+/// Handle [`Exit`][Kind::Exit]:{[`ListOrdered`][Name::ListOrdered],[`ListUnordered`][Name::ListUnordered]}.
+///
+/// Like `mdast-util-from-markdown`, lists end where their last item ends.
+fn on_exit_list(context: &mut CompileContext) -> Result<(), message::Message> {
+    let exit = context.events[context.index].point.to_unist();
+    let end = context
+        .tail_mut()
+        .children()
+        .and_then(|children| children.last())
+        .and_then(Node::position)
+        .map_or(exit, |position| position.end.clone());
+    context.tail_pop_at(end)
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`AutolinkProtocol`][Name::AutolinkProtocol].
 fn on_exit_autolink_protocol(context: &mut CompileContext) -> Result<(), message::Message> {
     on_exit_data(context)?;
@@ -960,6 +1245,20 @@ fn on_exit_autolink_email(context: &mut CompileContext) -> Result<(), message::M
     Ok(())
 }
 
+/// Handle [`Exit`][Kind::Exit]:{[`EmphasisSequence`][Name::EmphasisSequence],[`StrongSequence`][Name::StrongSequence]}.
+fn on_exit_attention_sequence(context: &mut CompileContext) {
+    let markers = markers_from_exit_event(context);
+
+    // Only the opening sequence matters.
+    // At the closing sequence, the tail can be something else, such as an
+    // unclosed JSX element, which crashes later.
+    match context.tail_mut() {
+        Node::Emphasis(node) if node.markers.is_none() => node.markers = markers,
+        Node::Strong(node) if node.markers.is_none() => node.markers = markers,
+        _ => {}
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`CharacterReferenceMarker`][Name::CharacterReferenceMarker].
 fn on_exit_character_reference_marker(context: &mut CompileContext) {
     context.character_reference_marker = b'&';
@@ -1004,6 +1303,22 @@ fn on_exit_code_fenced_fence_info(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`CodeFencedFenceSequence`][Name::CodeFencedFenceSequence].
+fn on_exit_code_fenced_fence_sequence(context: &mut CompileContext) {
+    // Closing fence, ignore.
+    if context.raw_flow_fence_seen {
+        return;
+    }
+
+    let markers = markers_from_exit_event(context);
+
+    if let Node::Code(node) = context.tail_mut() {
+        node.markers = markers;
+    } else {
+        unreachable!("expected code on stack");
+    }
+}
+
 /// Handle [`Exit`][Kind::Exit]:{[`CodeFencedFenceMeta`][Name::CodeFencedFenceMeta],[`MathFlowFenceMeta`][Name::MathFlowFenceMeta]}.
 fn on_exit_raw_flow_fence_meta(context: &mut CompileContext) {
     let value = context.resume().to_string();
@@ -1110,7 +1425,7 @@ fn on_exit_data(context: &mut CompileContext) -> Result<(), message::Message> {
         &SlicePosition::from_exit_event(context.events, context.index),
     );
     if let Node::Text(text) = context.tail_mut() {
-        text.value.push_str(value.as_str());
+        push_data(&mut text.value, value.as_str());
     } else {
         unreachable!("expected text on stack");
     }
@@ -1165,6 +1480,40 @@ fn on_exit_drop(context: &mut CompileContext) {
     context.resume();
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`FootnoteInline`][Name::FootnoteInline].
+fn on_exit_footnote_inline(context: &mut CompileContext) -> Result<(), message::Message> {
+    context.media_reference_stack.pop();
+    on_exit(context)?;
+
+    if context.footnote_inline_count == 0 {
+        context.footnote_inline_identifiers =
+            footnote_inline_identifiers(context.events, context.bytes);
+    }
+
+    let identifier = context.footnote_inline_identifiers[context.footnote_inline_count].clone();
+    context.footnote_inline_count += 1;
+
+    let siblings = context.tail_mut().children_mut().unwrap();
+
+    if let Some(Node::FootnoteDefinition(node)) = siblings.last_mut() {
+        let reference = Node::FootnoteReference(FootnoteReference {
+            identifier: identifier.clone(),
+            label: Some(identifier.clone()),
+            position: node.position.clone(),
+            raw: None,
+        });
+        node.identifier.clone_from(&identifier);
+        node.label = Some(identifier);
+        let definition = siblings.pop().unwrap();
+        siblings.push(reference);
+        context.footnote_inline_definitions.push(definition);
+    } else {
+        unreachable!("expected footnote definition on stack")
+    }
+
+    Ok(())
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`Frontmatter`][Name::Frontmatter].
 fn on_exit_frontmatter(context: &mut CompileContext) -> Result<(), message::Message> {
     let value = trim_eol(context.resume().to_string(), true, true);
@@ -1199,7 +1548,7 @@ fn on_exit_gfm_autolink_literal(context: &mut CompileContext) -> Result<(), mess
         if let Some(prefix) = prefix {
             link.url.push_str(prefix);
         }
-        link.url.push_str(value.as_str());
+        push_data(&mut link.url, value.as_str());
     } else {
         unreachable!("expected link on stack");
     }
@@ -1211,6 +1560,27 @@ fn on_exit_gfm_autolink_literal(context: &mut CompileContext) -> Result<(), mess
 
 /// Handle [`Exit`][Kind::Exit]:[`GfmTable`][Name::GfmTable].
 fn on_exit_gfm_table(context: &mut CompileContext) -> Result<(), message::Message> {
+    if context.options.gfm_table_normalize_cells {
+        if let Node::Table(table) = context.tail_mut() {
+            let size = table.align.len();
+
+            // Pad or truncate body rows to the size of the head row, like HTML.
+            for row in table.children.iter_mut().skip(1) {
+                if let Node::TableRow(row) = row {
+                    row.children.resize_with(size, || {
+                        Node::TableCell(TableCell {
+                            children: vec![],
+                            position: None,
+                            raw: None,
+                        })
+                    });
+                }
+            }
+        } else {
+            unreachable!("expected table on stack");
+        }
+    }
+
     on_exit(context)?;
     context.gfm_table_inside = false;
     Ok(())
@@ -1242,15 +1612,18 @@ fn on_exit_heading_atx_sequence(context: &mut CompileContext) {
         &SlicePosition::from_exit_event(context.events, context.index),
     );
 
+    let markers = markers_from_exit_event(context);
+
     if let Node::Heading(node) = context.tail_mut() {
         if node.depth == 0 {
             #[allow(clippy::cast_possible_truncation)]
             let depth = slice.len() as u8;
             node.depth = depth;
+            node.markers = markers;
         }
-    } else {
-        unreachable!("expected heading on stack");
     }
+    // Otherwise, an MDX JSX tag in the heading is not closed, which is
+    // an error when the heading exits.
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`HeadingSetext`][Name::HeadingSetext].
@@ -1270,23 +1643,42 @@ fn on_exit_heading_setext_underline_sequence(context: &mut CompileContext) {
     let position = SlicePosition::from_exit_event(context.events, context.index);
     let head = context.bytes[position.start.index];
     let depth = if head == b'-' { 2 } else { 1 };
+    let markers = markers_from_exit_event(context);
 
     if let Node::Heading(node) = context.tail_mut() {
         node.depth = depth;
-    } else {
-        unreachable!("expected heading on stack");
+        node.markers = markers;
+    }
+    // Otherwise, an MDX JSX tag in the heading is not closed, which is
+    // an error when the heading exits.
+}
+
+/// Turn a node in the label of an image into plain text, like
+/// [`ToString`], but with the alt of nested images, as in HTML.
+fn alt_to_string(node: &Node) -> String {
+    match node {
+        Node::Image(x) => x.alt.clone(),
+        Node::ImageReference(x) => x.alt.clone(),
+        _ => node.children().map_or_else(
+            || node.to_string(),
+            |children| children.iter().map(alt_to_string).collect(),
+        ),
     }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`LabelText`][Name::LabelText].
-fn on_exit_label_text(context: &mut CompileContext) {
+fn on_exit_label_text(context: &mut CompileContext) -> Result<(), message::Message> {
+    // An MDX JSX tag in the label is not closed.
+    let events = context.events;
+    if let Some(index) = context.trees.last().unwrap().1.last() {
+        on_mismatch_error(context, Some(&events[context.index]), &events[*index])?;
+    }
+
     let mut fragment = context.resume();
     let label = fragment.to_string();
     let children = fragment.children_mut().unwrap().split_off(0);
-    let slice = Slice::from_position(
-        context.bytes,
-        &SlicePosition::from_exit_event(context.events, context.index),
-    );
+    let position = SlicePosition::from_exit_event(context.events, context.index);
+    let slice = Slice::from_position(context.bytes, &position);
     let identifier = normalize_identifier(slice.as_str()).to_lowercase();
 
     let reference = context
@@ -1298,10 +1690,22 @@ fn on_exit_label_text(context: &mut CompileContext) {
 
     match context.tail_mut() {
         Node::Link(node) => node.children = children,
-        Node::Image(node) => node.alt = label,
+        Node::Image(node) => node.alt = children.iter().map(alt_to_string).collect(),
         Node::FootnoteReference(_) => {}
+        Node::FootnoteDefinition(node) => {
+            node.children = vec![Node::Paragraph(Paragraph {
+                children,
+                position: Some(Position {
+                    start: position.start.to_unist(),
+                    end: position.end.to_unist(),
+                }),
+                raw: None,
+            })];
+        }
         _ => unreachable!("expected footnote refereence, image, or link on stack"),
     }
+
+    Ok(())
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`LineEnding`][Name::LineEnding].
@@ -1342,9 +1746,17 @@ fn on_exit_line_ending(context: &mut CompileContext) -> Result<(), message::Mess
 /// Handle [`Exit`][Kind::Exit]:{[`HtmlFlow`][Name::HtmlFlow],[`HtmlText`][Name::HtmlText]}.
 fn on_exit_html(context: &mut CompileContext) -> Result<(), message::Message> {
     let value = context.resume().to_string();
+    let kind = if context.options.mdast_html_kind {
+        Some(html_kind(&value))
+    } else {
+        None
+    };
 
     match context.tail_mut() {
-        Node::Html(node) => node.value = value,
+        Node::Html(node) => {
+            node.value = value;
+            node.kind = kind;
+        }
         _ => unreachable!("expected html on stack for value"),
     }
 
@@ -1370,33 +1782,28 @@ fn on_exit_media(context: &mut CompileContext) -> Result<(), message::Message> {
                 node.identifier = reference.identifier;
                 node.label = Some(reference.label);
             }
-            Node::Image(_) => {
-                // Need to swap it with a reference version of the node.
-                if let Some(Node::Image(node)) = siblings.pop() {
-                    siblings.push(Node::ImageReference(ImageReference {
-                        reference_kind: kind,
-                        identifier: reference.identifier,
-                        label: Some(reference.label),
-                        alt: node.alt,
-                        position: node.position,
-                    }));
-                } else {
-                    unreachable!("impossible: it’s an image")
-                }
+            // Need to swap these with a reference version of the node.
+            Node::Image(node) => {
+                let node = Node::ImageReference(ImageReference {
+                    reference_kind: kind,
+                    identifier: reference.identifier,
+                    label: Some(reference.label),
+                    alt: mem::take(&mut node.alt),
+                    position: node.position.take(),
+                    raw: None,
+                });
+                *siblings.last_mut().unwrap() = node;
             }
-            Node::Link(_) => {
-                // Need to swap it with a reference version of the node.
-                if let Some(Node::Link(node)) = siblings.pop() {
-                    siblings.push(Node::LinkReference(LinkReference {
-                        reference_kind: kind,
-                        identifier: reference.identifier,
-                        label: Some(reference.label),
-                        children: node.children,
-                        position: node.position,
-                    }));
-                } else {
-                    unreachable!("impossible: it’s a link")
-                }
+            Node::Link(node) => {
+                let node = Node::LinkReference(LinkReference {
+                    reference_kind: kind,
+                    identifier: reference.identifier,
+                    label: Some(reference.label),
+                    children: mem::take(&mut node.children),
+                    position: node.position.take(),
+                    raw: None,
+                });
+                *siblings.last_mut().unwrap() = node;
             }
             _ => unreachable!("expected footnote reference, image, or link on stack"),
         }
@@ -1406,7 +1813,7 @@ fn on_exit_media(context: &mut CompileContext) -> Result<(), message::Message> {
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ListItem`][Name::ListItem].
-fn on_exit_list_item(context: &mut CompileContext) -> Result<(), message::Message> {
+fn on_exit_list_item(context: &mut CompileContext) {
     if let Node::ListItem(item) = context.tail_mut() {
         if item.checked.is_some() {
             if let Some(Node::Paragraph(paragraph)) = item.children.first_mut() {
@@ -1444,26 +1851,42 @@ fn on_exit_list_item(context: &mut CompileContext) -> Result<(), message::Messag
             }
         }
     }
+}
 
-    on_exit(context)?;
+/// Handle [`Exit`][Kind::Exit]:[`ListItemMarker`][Name::ListItemMarker].
+fn on_exit_list_item_marker(context: &mut CompileContext) {
+    let markers = markers_from_exit_event(context);
 
-    Ok(())
+    if let Node::ListItem(node) = context.tail_mut() {
+        node.markers = markers;
+    } else {
+        unreachable!("expected list item on stack");
+    }
+
+    if let Node::List(node) = context.tail_penultimate_mut() {
+        // The first item defines the markers of the list.
+        if node.markers.is_none() {
+            node.markers = markers;
+        }
+    } else {
+        unreachable!("expected list on stack");
+    }
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`ListItemValue`][Name::ListItemValue].
 fn on_exit_list_item_value(context: &mut CompileContext) {
-    let start = Slice::from_position(
+    let slice = Slice::from_position(
         context.bytes,
         &SlicePosition::from_exit_event(context.events, context.index),
-    )
-    .as_str()
-    .parse()
-    .expect("expected list value up to u8");
+    );
 
     if let Node::List(node) = context.tail_penultimate_mut() {
         debug_assert!(node.ordered, "expected list to be ordered");
         if node.start.is_none() {
-            node.start = Some(start);
+            node.start = Some(
+                list_value::number(node.kind, slice.as_str())
+                    .expect("expected list value up to u32"),
+            );
         }
     } else {
         unreachable!("expected list on stack");
@@ -1501,8 +1924,8 @@ fn on_exit_mdx_jsx_tag(context: &mut CompileContext) -> Result<(), message::Mess
                         tail.start.line,
                         tail.start.column,
                     ),
-                    rule_id: Box::new("end-tag-mismatch".into()),
-                    source: Box::new("markdown-rs".into()),
+                    rule_id: Box::new(message::rules::END_TAG_MISMATCH.into()),
+                    source: Box::new(message::rules::SOURCE.into()),
                 },
             );
         }
@@ -1520,6 +1943,7 @@ fn on_exit_mdx_jsx_tag(context: &mut CompileContext) -> Result<(), message::Mess
                     start: tag.start.clone(),
                     end: tag.end.clone(),
                 }),
+                raw: None,
             })
         } else {
             Node::MdxJsxTextElement(MdxJsxTextElement {
@@ -1530,10 +1954,31 @@ fn on_exit_mdx_jsx_tag(context: &mut CompileContext) -> Result<(), message::Mess
                     start: tag.start.clone(),
                     end: tag.end.clone(),
                 }),
+                raw: None,
             })
         };
 
         context.tail_push(node);
+
+        // Treat unclosed HTML void elements as self-closing, if allowed.
+        if !tag.self_closing
+            && context.options.mdx_allow_unclosed_void_elements
+            && matches!(&tag.name, Some(name) if HTML_VOID_NAMES.contains(&name.as_str()))
+        {
+            if context.jsx_closed_tags.is_none() {
+                context.jsx_closed_tags = Some(jsx_closed_tags(context.events, context.bytes));
+            }
+
+            if context
+                .jsx_closed_tags
+                .as_ref()
+                .unwrap()
+                .binary_search(&context.index)
+                .is_err()
+            {
+                tag.self_closing = true;
+            }
+        }
     }
 
     if tag.self_closing || tag.close {
@@ -1545,6 +1990,67 @@ fn on_exit_mdx_jsx_tag(context: &mut CompileContext) -> Result<(), message::Mess
     Ok(())
 }
 
+/// Get the opening tags that are closed by a tag with the same name, as the
+/// indices of their exit events, in order.
+///
+/// Each closing tag closes the last opening tag that is not closed yet and
+/// not self-closing.
+fn jsx_closed_tags(events: &[Event], bytes: &[u8]) -> Vec<usize> {
+    let mut stack: Vec<(usize, Option<&[u8]>)> = vec![];
+    let mut closed = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter
+            && (event.name == Name::MdxJsxFlowTag || event.name == Name::MdxJsxTextTag)
+        {
+            let mut close = false;
+            let mut self_closing = false;
+            let mut name = None;
+            index += 1;
+
+            while !(events[index].kind == Kind::Exit && events[index].name == event.name) {
+                if events[index].kind == Kind::Enter {
+                    match events[index].name {
+                        Name::MdxJsxTagClosingMarker => close = true,
+                        Name::MdxJsxTagSelfClosingMarker => self_closing = true,
+                        Name::MdxJsxTagNameMember | Name::MdxJsxTagNameLocal => name = None,
+                        Name::MdxJsxTagNamePrimary => {
+                            name = Some(
+                                Slice::from_position(
+                                    bytes,
+                                    &SlicePosition::from_exit_event(events, index + 1),
+                                )
+                                .bytes,
+                            );
+                        }
+                        _ => {}
+                    }
+                }
+
+                index += 1;
+            }
+
+            if close {
+                if let Some((opening, opening_name)) = stack.pop() {
+                    if opening_name.is_some() && opening_name == name {
+                        closed.push(opening);
+                    }
+                }
+            } else if !self_closing {
+                stack.push((index, name));
+            }
+        }
+
+        index += 1;
+    }
+
+    closed.sort_unstable();
+    closed
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`MdxJsxTagClosingMarker`][Name::MdxJsxTagClosingMarker].
 fn on_exit_mdx_jsx_tag_closing_marker(context: &mut CompileContext) {
     context.jsx_tag.as_mut().expect("expected tag").close = true;
@@ -1601,6 +2107,27 @@ fn on_exit_mdx_esm_or_expression(context: &mut CompileContext) -> Result<(), mes
     Ok(())
 }
 
+/// End the position of the current attribute at the current exit.
+///
+/// Attributes end at their name or value, not at the whitespace after them
+/// that is in [`MdxJsxTagAttribute`][Name::MdxJsxTagAttribute].
+fn mdx_jsx_tag_attribute_end(context: &mut CompileContext) {
+    let end = context.events[context.index].point.to_unist();
+    let position = match context
+        .jsx_tag
+        .as_mut()
+        .expect("expected tag")
+        .attributes
+        .last_mut()
+        .expect("expected attribute")
+    {
+        AttributeContent::Expression(attribute) => &mut attribute.position,
+        AttributeContent::Property(attribute) => &mut attribute.position,
+    };
+
+    position.as_mut().expect("expected position").end = end;
+}
+
 /// Handle [`Exit`][Kind::Exit]:[`MdxJsxTagAttributePrimaryName`][Name::MdxJsxTagAttributePrimaryName].
 fn on_exit_mdx_jsx_tag_attribute_primary_name(context: &mut CompileContext) {
     let slice = Slice::from_position(
@@ -1620,6 +2147,8 @@ fn on_exit_mdx_jsx_tag_attribute_primary_name(context: &mut CompileContext) {
     } else {
         unreachable!("expected property")
     }
+
+    mdx_jsx_tag_attribute_end(context);
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`MdxJsxTagAttributeNameLocal`][Name::MdxJsxTagAttributeNameLocal].
@@ -1640,6 +2169,8 @@ fn on_exit_mdx_jsx_tag_attribute_name_local(context: &mut CompileContext) {
     } else {
         unreachable!("expected property")
     }
+
+    mdx_jsx_tag_attribute_end(context);
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`MdxJsxTagAttributeValueLiteral`][Name::MdxJsxTagAttributeValueLiteral].
@@ -1659,6 +2190,8 @@ fn on_exit_mdx_jsx_tag_attribute_value_literal(context: &mut CompileContext) {
     } else {
         unreachable!("expected property")
     }
+
+    mdx_jsx_tag_attribute_end(context);
 }
 
 /// Handle [`Exit`][Kind::Exit]:[`MdxJsxTagSelfClosingMarker`][Name::MdxJsxTagSelfClosingMarker].
@@ -1705,6 +2238,82 @@ fn on_exit_resource_title_string(context: &mut CompileContext) {
     }
 }
 
+/// Handle [`Exit`][Kind::Exit]:[`ThematicBreakSequence`][Name::ThematicBreakSequence].
+fn on_exit_thematic_break_sequence(context: &mut CompileContext) {
+    let markers = markers_from_exit_event(context);
+
+    if let Node::ThematicBreak(node) = context.tail_mut() {
+        // There can be several sequences, separated by whitespace.
+        node.markers = match (node.markers, markers) {
+            (Some(left), Some(right)) => Some(Markers {
+                character: left.character,
+                size: left.size + right.size,
+            }),
+            _ => markers,
+        };
+    } else {
+        unreachable!("expected thematic break on stack");
+    }
+}
+
+/// Get the markers of the sequence at the current exit event.
+///
+/// Returns `None` if `mdast_markers` is off.
+fn markers_from_exit_event(context: &CompileContext) -> Option<Markers> {
+    if context.options.mdast_markers {
+        let position = SlicePosition::from_exit_event(context.events, context.index);
+        Some(Markers {
+            character: context.bytes[position.start.index] as char,
+            size: position.end.index - position.start.index,
+        })
+    } else {
+        None
+    }
+}
+
+/// Get the kind of HTML from its value.
+///
+/// Checks the same prefixes as the tokenizer does when starting HTML, after
+/// the whitespace that HTML (flow) can start with.
+/// When something other than whitespace follows the end of a comment, CDATA,
+/// instruction, or declaration, such as a tag, the HTML is seen as an
+/// element.
+fn html_kind(value: &str) -> HtmlKind {
+    let value = value.trim_start_matches(|d| matches!(d, '\t' | ' '));
+
+    let (kind, rest) = if let Some(rest) = value.strip_prefix("<!--") {
+        // `<!-->` and `<!--->` are complete comments.
+        let rest = rest.strip_prefix('-').unwrap_or(rest);
+        let rest = if let Some(rest) = rest.strip_prefix('>') {
+            Some(rest)
+        } else {
+            rest.find("-->").map(|index| &rest[index + 3..])
+        };
+        (HtmlKind::Comment, rest)
+    } else if let Some(rest) = value.strip_prefix("<![CDATA[") {
+        let rest = rest.find("]]>").map(|index| &rest[index + 3..]);
+        (HtmlKind::Cdata, rest)
+    } else if let Some(rest) = value.strip_prefix("<?") {
+        let rest = rest.find("?>").map(|index| &rest[index + 2..]);
+        (HtmlKind::Instruction, rest)
+    } else if let Some(rest) = value.strip_prefix("<!") {
+        let rest = rest.find('>').map(|index| &rest[index + 1..]);
+        (HtmlKind::Declaration, rest)
+    } else {
+        return HtmlKind::Element;
+    };
+
+    if rest.map_or(false, |rest| {
+        !rest
+            .trim_matches(|d| matches!(d, '\t' | '\n' | '\r' | ' '))
+            .is_empty()
+    }) {
+        HtmlKind::Element
+    } else {
+        kind
+    }
+}
+
 /// Create a position from an event.
 fn position_from_event(event: &Event) -> Position {
     let end = Point::new(event.point.line, event.point.column, event.point.index);
@@ -1714,17 +2323,6 @@ fn position_from_event(event: &Event) -> Position {
     }
 }
 
-/// Resolve the current stack on the tree.
-fn delve_mut<'tree>(mut node: &'tree mut Node, stack: &'tree [usize]) -> &'tree mut Node {
-    let mut stack_index = 0;
-    while stack_index < stack.len() {
-        let index = stack[stack_index];
-        node = &mut node.children_mut().expect("Cannot delve into non-parent")[index];
-        stack_index += 1;
-    }
-    node
-}
-
 /// Remove initial/final EOLs.
 fn trim_eol(value: String, at_start: bool, at_end: bool) -> String {
     let bytes = value.as_bytes();
@@ -1790,8 +2388,8 @@ fn on_mismatch_error(
                     String::new()
                 }
             ),
-            rule_id: Box::new("end-tag-mismatch".into()),
-            source: Box::new("markdown-rs".into()),
+            rule_id: Box::new(message::rules::END_TAG_MISMATCH.into()),
+            source: Box::new(message::rules::SOURCE.into()),
         });
     }
 
@@ -1809,8 +2407,8 @@ fn on_mismatch_error(
                         &right.point.line,
                         &right.point.column,
                     ),
-                    rule_id: Box::new("end-tag-mismatch".into()),
-                    source: Box::new("markdown-rs".into()),
+                    rule_id: Box::new(message::rules::END_TAG_MISMATCH.into()),
+                    source: Box::new(message::rules::SOURCE.into()),
                 }
             );
         }
@@ -12,13 +12,13 @@ use crate::event::{Content, Event, Kind, Link, Name, Point, VOID_EVENTS};
 use crate::message;
 use crate::parser::ParseState;
 use crate::resolve::{call as call_resolve, Name as ResolveName};
-use crate::state::{call, State};
+use crate::state::{call, Name as StateName, State};
 use crate::subtokenize::Subresult;
 
 #[cfg(feature = "log")]
 use crate::util::char::format_byte_opt;
 
-use crate::util::{constant::TAB_SIZE, edit_map::EditMap};
+use crate::util::{char::column_width, constant::TAB_SIZE, edit_map::EditMap};
 use alloc::{boxed::Box, string::String, vec, vec::Vec};
 
 /// Containers.
@@ -104,6 +104,15 @@ pub enum LabelKind {
     ///
     /// Construct: [Label end][crate::construct::label_end].
     GfmUndefinedFootnote,
+    /// Label (footnote inline) start.
+    ///
+    /// ```markdown
+    /// > | a ^[b] c
+    ///       ^^
+    /// ```
+    ///
+    /// Construct: [Label start (footnote inline)][crate::construct::label_start_footnote_inline].
+    FootnoteInline,
 }
 
 /// Label start, looking for an end.
@@ -201,6 +210,11 @@ pub struct TokenizeState<'a> {
     /// a list item.
     /// Used for GFM task list items.
     pub document_at_first_paragraph_of_list_item: bool,
+    /// Whitespace at the start of the current line, as a range of indices,
+    /// and whether it is the whole line.
+    /// Used to check whether list items continue, without looking at the same
+    /// whitespace again for every open list item.
+    pub document_whitespace: Option<(usize, usize, bool)>,
 
     // Couple of very frequent settings for parsing whitespace.
     pub space_or_tab_eol_content: Option<Content>,
@@ -227,6 +241,23 @@ pub struct TokenizeState<'a> {
     /// Used when tokenizing [text content][crate::construct::text].
     pub labels: Vec<Label>,
 
+    // Couple of raw (text) related fields.
+    /// Where the last sequence of each size ends, for code (text) at `0` and
+    /// math (text) at `1`.
+    ///
+    /// Filled while looking for closing sequences.
+    /// Used when tokenizing [text content][crate::construct::text].
+    pub raw_text_ends: [Vec<usize>; 2],
+    /// Whether `raw_text_ends` knows about all sequences, which is the case
+    /// once looking for a closing sequence reached the end.
+    pub raw_text_ends_complete: [bool; 2],
+
+    /// Where runs of data started, before they were merged.
+    ///
+    /// Only filled when `mdast_split_text` is on.
+    /// Used to split data again after resolving.
+    pub data_starts: Vec<Point>,
+
     /// List of defined definition identifiers.
     pub definitions: Vec<String>,
     /// List of defined GFM footnote definition identifiers.
@@ -235,6 +266,11 @@ pub struct TokenizeState<'a> {
     // Last error message provided at an EOF of an expression.
     pub mdx_last_parse_error: Option<(String, String, String)>,
 
+    /// Index of the next text extension to try.
+    ///
+    /// Used when tokenizing [text content][crate::construct::text].
+    pub extension_index: usize,
+
     /// Whether to connect events.
     pub connect: bool,
     /// Marker.
@@ -242,7 +278,7 @@ pub struct TokenizeState<'a> {
     /// Secondary marker.
     pub marker_b: u8,
     /// Several markers.
-    pub markers: &'static [u8],
+    pub markers: &'a [u8],
     /// Whether something was seen.
     pub seen: bool,
     /// Size.
@@ -349,9 +385,12 @@ impl<'a> Tokenizer<'a> {
                 document_child_state: None,
                 document_child: None,
                 document_at_first_paragraph_of_list_item: false,
+                document_whitespace: None,
+                data_starts: vec![],
                 definitions: vec![],
                 gfm_footnote_definitions: vec![],
                 mdx_last_parse_error: None,
+                extension_index: 0,
                 end: 0,
                 label_starts: vec![],
                 label_starts_loose: vec![],
@@ -359,6 +398,8 @@ impl<'a> Tokenizer<'a> {
                 marker_b: 0,
                 markers: &[],
                 labels: vec![],
+                raw_text_ends: [vec![], vec![]],
+                raw_text_ends_complete: [false, false],
                 seen: false,
                 size: 0,
                 size_b: 0,
@@ -477,7 +518,10 @@ impl<'a> Tokenizer<'a> {
                     self.point.line += 1;
                     self.point.column = 1;
 
-                    if self.point.line - self.first_line + 1 > self.column_start.len() {
+                    // Keep jumps aligned with lines, even if no skip was
+                    // defined for earlier lines: an attempt that failed
+                    // after later lines must not find their starts here.
+                    while self.point.line - self.first_line >= self.column_start.len() {
                         self.column_start.push((self.point.index, self.point.vs));
                     }
 
@@ -488,7 +532,7 @@ impl<'a> Tokenizer<'a> {
                     #[cfg(feature = "log")]
                     log::trace!("position: after eol: `{:?}`", self.point);
                 } else {
-                    self.point.column += 1;
+                    self.point.column += column_width(byte);
                 }
             }
         }
@@ -644,7 +688,7 @@ impl<'a> Tokenizer<'a> {
             let defs = &mut value.definitions;
             let fn_defs = &mut value.gfm_footnote_definitions;
             while index < resolvers.len() {
-                if let Some(mut result) = call_resolve(self, resolvers[index])? {
+                if let Some(mut result) = call_resolver(self, resolvers[index])? {
                     fn_defs.append(&mut result.gfm_footnote_definitions);
                     defs.append(&mut result.definitions);
                 }
@@ -755,14 +799,14 @@ fn push_impl(
                     log::trace!("feed:    {} to {:?}", format_byte_opt(byte), name);
 
                     tokenizer.expect(byte);
-                    state = call(tokenizer, name);
-                };
+                    state = call_state(tokenizer, name);
+                }
             }
             State::Retry(name) => {
                 #[cfg(feature = "log")]
                 log::trace!("retry:   `{:?}`", name);
 
-                state = call(tokenizer, name);
+                state = call_state(tokenizer, name);
             }
         }
     }
@@ -772,8 +816,10 @@ fn push_impl(
     if flush {
         debug_assert!(matches!(state, State::Ok | State::Error(_)), "must be ok");
     } else {
+        // Note: a tokenizer can be done before the end, when it only needs to
+        // look at the start of the document (such as for frontmatter).
         debug_assert!(
-            matches!(state, State::Next(_) | State::Error(_)),
+            matches!(state, State::Next(_) | State::Ok | State::Error(_)),
             "must have a next state"
         );
     }
@@ -781,6 +827,43 @@ fn push_impl(
     state
 }
 
+/// Call a state.
+fn call_state(tokenizer: &mut Tokenizer, name: StateName) -> State {
+    #[cfg(feature = "profile")]
+    let start = tokenizer.parse_state.profiler.borrow_mut().start();
+
+    let state = call(tokenizer, name);
+
+    #[cfg(feature = "profile")]
+    tokenizer
+        .parse_state
+        .profiler
+        .borrow_mut()
+        .state(name, start);
+
+    state
+}
+
+/// Call a resolver.
+fn call_resolver(
+    tokenizer: &mut Tokenizer,
+    name: ResolveName,
+) -> Result<Option<Subresult>, message::Message> {
+    #[cfg(feature = "profile")]
+    let start = tokenizer.parse_state.profiler.borrow_mut().start();
+
+    let result = call_resolve(tokenizer, name);
+
+    #[cfg(feature = "profile")]
+    tokenizer
+        .parse_state
+        .profiler
+        .borrow_mut()
+        .resolver(name, start);
+
+    result
+}
+
 /// Figure out how to handle a byte.
 fn byte_action(bytes: &[u8], point: &Point) -> ByteAction {
     if point.index < bytes.len() {
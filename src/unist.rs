@@ -5,14 +5,27 @@
 use alloc::fmt;
 
 /// One place in a source file.
+///
+/// The `offset` counts bytes in the source file, so it can be used to slice
+/// the source.
+/// The `column` counts UTF-16 code units, like in JavaScript (and so
+/// `mdast-util-from-markdown`): most characters are one column, but
+/// characters outside the BMP, such as most emoji, are two.
+/// Combining characters and the parts of emoji sequences are each counted.
+/// Tabs are counted up to the next tab stop.
+/// A byte order mark (BOM) at the start of the document is counted too: it is
+/// not in the syntax tree, but the first character after it is at column `2`
+/// and offset `3`.
+/// Use [`Location`][crate::Location] to turn offsets into points that match.
 #[derive(Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     /// 1-indexed integer representing a line in a source file.
     pub line: usize,
-    /// 1-indexed integer representing a column in a source file.
+    /// 1-indexed integer representing a column (in UTF-16 code units) in a
+    /// source file.
     pub column: usize,
-    /// 0-indexed integer representing a character in a source file.
+    /// 0-indexed integer representing a byte in a source file.
     pub offset: usize,
 }
 
@@ -41,7 +41,7 @@ pub enum Kind {
 /// In most cases, markdown operates on ASCII bytes.
 /// In a few cases, it is unicode aware, so we need to find an actual char.
 pub fn before_index(bytes: &[u8], index: usize) -> Option<char> {
-    let start = if index < 4 { 0 } else { index - 4 };
+    let start = index.saturating_sub(4);
     String::from_utf8_lossy(&bytes[start..index]).chars().last()
 }
 
@@ -154,11 +154,35 @@ pub fn format_byte(byte: u8) -> String {
     }
 }
 
+/// Get how many columns a byte (`u8`) of UTF-8 takes.
+///
+/// Columns count UTF-16 code units, like JavaScript strings do: the first
+/// byte of a character takes `1`, or `2` for a character outside the BMP
+/// (which starts with four bytes), and continuation bytes take `0`.
+pub fn column_width(byte: u8) -> usize {
+    match byte {
+        0x80..=0xBF => 0,
+        0xF0..=0xFF => 2,
+        _ => 1,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloc::string::ToString;
 
+    #[test]
+    fn test_column_width() {
+        let width = |value: &str| value.bytes().map(column_width).sum::<usize>();
+
+        assert_eq!(width("a"), 1, "should count ASCII as 1");
+        assert_eq!(width("é"), 1, "should count 2 bytes as 1");
+        assert_eq!(width("“"), 1, "should count 3 bytes as 1");
+        assert_eq!(width("😀"), 2, "should count 4 bytes as 2");
+        assert_eq!(width("e\u{301}"), 2, "should count combining characters");
+    }
+
     #[test]
     fn test_classify() {
         assert_eq!(
@@ -105,13 +105,16 @@ pub const GFM_HTML_TAGFILTER_NAMES: [&str; 9] = [
 /// [whitespace]: crate::construct::partial_whitespace
 pub const HARD_BREAK_PREFIX_SIZE_MIN: usize = 2;
 
-/// The max number of markers allowed to form a [heading (atx)][heading_atx].
+/// The max number of markers allowed to form a [heading (atx)][heading_atx],
+/// by default.
 ///
 /// This limitation is imposed by HTML, which imposes a max heading rank of
 /// `6`.
+/// It can be changed with
+/// [`heading_atx_max_depth`][crate::ParseOptions::heading_atx_max_depth].
 ///
 /// [heading_atx]: crate::construct::heading_atx
-pub const HEADING_ATX_OPENING_FENCE_SIZE_MAX: usize = 6;
+pub const HEADING_ATX_OPENING_FENCE_SIZE_MAX: u8 = 6;
 
 /// List of HTML tag names that form the **basic** production of
 /// [HTML (flow)][html_flow].
@@ -232,7 +235,32 @@ pub const HTML_RAW_NAMES: [&str; 4] = ["pre", "script", "style", "textarea"];
 /// This is currently the size of `textarea`.
 pub const HTML_RAW_SIZE_MAX: usize = 8;
 
+/// List of HTML tag names of void elements.
+///
+/// Void elements cannot have content, and in HTML they are written without
+/// closing tag.
+/// Used when [`mdx_allow_unclosed_void_elements`][allow] is on, to treat
+/// these MDX JSX tags as self-closing when they are not closed.
+///
+/// ## References
+///
+/// *   [*§ 13.1.2 Elements* in the HTML spec](https://html.spec.whatwg.org/multipage/syntax.html#void-elements)
+///
+/// [allow]: crate::ParseOptions::mdx_allow_unclosed_void_elements
+pub const HTML_VOID_NAMES: [&str; 13] = [
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
 /// To safeguard performance, labels are capped at a large number: `999`.
+///
+/// This applies to the label of [definitions][definition], full references,
+/// and GFM footnotes (the `xxx` in `[xxx]` and `[^xxx]`), and to the text of shortcut and
+/// collapsed references, as it is also their label.
+/// Line endings, and whitespace after them, are not counted.
+/// 999 bytes is fine, 1000 is too many.
+///
+/// [definition]: crate::construct::definition
 pub const LINK_REFERENCE_SIZE_MAX: usize = 999;
 
 /// The max number of decimals allowed to form an (ordered)
@@ -306,7 +334,7 @@ pub const THEMATIC_BREAK_MARKER_COUNT_MIN: usize = 3;
 /// *   [*§ 2.5 Entity and numeric character references* in `CommonMark`](https://spec.commonmark.org/0.31/#entity-and-numeric-character-references)
 ///
 /// [character_reference]: crate::construct::character_reference
-pub const CHARACTER_REFERENCES: [(&str, &str); 2125] = [
+pub static CHARACTER_REFERENCES: [(&str, &str); 2125] = [
     ("AElig", "Æ"),
     ("AMP", "&"),
     ("Aacute", "Á"),
@@ -9,7 +9,7 @@
 //! through another tokenizer and inject the result.
 
 use crate::event::Event;
-use alloc::{vec, vec::Vec};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
 
 /// Shift `previous` and `next` links according to `jumps`.
 ///
@@ -54,17 +54,33 @@ fn shift_links(events: &mut [Event], jumps: &[(usize, usize, usize)]) {
     }
 }
 
+/// Changes at a certain place.
+#[derive(Debug)]
+struct Edit {
+    /// Number of events to remove.
+    remove: usize,
+    /// Events to add before earlier additions, last first.
+    before: Vec<Vec<Event>>,
+    /// Events to add.
+    add: Vec<Event>,
+}
+
 /// Tracks a bunch of edits.
 #[derive(Debug)]
 pub struct EditMap {
-    /// Record of changes.
-    map: Vec<(usize, usize, Vec<Event>)>,
+    /// Record of changes, by where they happen.
+    ///
+    /// A map, not a list, so that finding earlier edits at the same place is
+    /// fast, also when there are thousands of edits.
+    map: BTreeMap<usize, Edit>,
 }
 
 impl EditMap {
     /// Create a new edit map.
     pub fn new() -> EditMap {
-        EditMap { map: vec![] }
+        EditMap {
+            map: BTreeMap::new(),
+        }
     }
     /// Create an edit: a remove and/or add at a certain place.
     pub fn add(&mut self, index: usize, remove: usize, add: Vec<Event>) {
@@ -76,20 +92,35 @@ impl EditMap {
     }
     /// Done, change the events.
     pub fn consume(&mut self, events: &mut Vec<Event>) {
-        self.map
-            .sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
         if self.map.is_empty() {
             return;
         }
 
+        let mut map = core::mem::take(&mut self.map)
+            .into_iter()
+            .map(|(at, mut edit)| {
+                if !edit.before.is_empty() {
+                    let mut add = Vec::with_capacity(
+                        edit.before.iter().map(Vec::len).sum::<usize>() + edit.add.len(),
+                    );
+                    while let Some(mut slice) = edit.before.pop() {
+                        add.append(&mut slice);
+                    }
+                    add.append(&mut edit.add);
+                    edit.add = add;
+                }
+
+                (at, edit.remove, edit.add)
+            })
+            .collect::<Vec<_>>();
+
         // Calculate jumps: where items in the current list move to.
-        let mut jumps = Vec::with_capacity(self.map.len());
+        let mut jumps = Vec::with_capacity(map.len());
         let mut index = 0;
         let mut add_acc = 0;
         let mut remove_acc = 0;
-        while index < self.map.len() {
-            let (at, remove, add) = &self.map[index];
+        while index < map.len() {
+            let (at, remove, add) = &map[index];
             remove_acc += remove;
             add_acc += add.len();
             jumps.push((*at, remove_acc, add_acc));
@@ -99,13 +130,13 @@ impl EditMap {
         shift_links(events, &jumps);
 
         let len_before = events.len();
-        let mut index = self.map.len();
+        let mut index = map.len();
         let mut vecs = Vec::with_capacity(index * 2 + 1);
         while index > 0 {
             index -= 1;
-            vecs.push(events.split_off(self.map[index].0 + self.map[index].1));
-            vecs.push(self.map[index].2.split_off(0));
-            events.truncate(self.map[index].0);
+            vecs.push(events.split_off(map[index].0 + map[index].1));
+            vecs.push(map[index].2.split_off(0));
+            events.truncate(map[index].0);
         }
         vecs.push(events.split_off(0));
 
@@ -114,35 +145,33 @@ impl EditMap {
         while let Some(mut slice) = vecs.pop() {
             events.append(&mut slice);
         }
-
-        self.map.truncate(0);
     }
 }
 
 /// Create an edit.
 fn add_impl(edit_map: &mut EditMap, at: usize, remove: usize, mut add: Vec<Event>, before: bool) {
-    let mut index = 0;
-
     if remove == 0 && add.is_empty() {
         return;
     }
 
-    while index < edit_map.map.len() {
-        if edit_map.map[index].0 == at {
-            edit_map.map[index].1 += remove;
-
-            if before {
-                add.append(&mut edit_map.map[index].2);
-                edit_map.map[index].2 = add;
-            } else {
-                edit_map.map[index].2.append(&mut add);
-            }
+    if let Some(edit) = edit_map.map.get_mut(&at) {
+        edit.remove += remove;
 
-            return;
+        // Prepending would move earlier additions each time, which is slow
+        // when there are many, so they are joined when consuming.
+        if before {
+            edit.before.push(add);
+        } else {
+            edit.add.append(&mut add);
         }
-
-        index += 1;
+    } else {
+        edit_map.map.insert(
+            at,
+            Edit {
+                remove,
+                before: vec![],
+                add,
+            },
+        );
     }
-
-    edit_map.map.push((at, remove, add));
 }
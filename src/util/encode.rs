@@ -1,6 +1,6 @@
 //! Encode HTML.
 
-use alloc::string::String;
+use alloc::{borrow::Cow, string::String};
 
 /// Encode dangerous html characters.
 ///
@@ -20,19 +20,28 @@ use alloc::string::String;
 /// assert_eq!(encode("I <3 🦀"), "I &lt;3 🦀");
 /// ```
 ///
+/// Most text has nothing to encode, in which case `value` is borrowed
+/// instead of copied.
+///
 /// ## References
 ///
 /// *   [`micromark-util-encode` in `micromark`](https://github.com/micromark/micromark/tree/main/packages/micromark-util-encode)
-pub fn encode(value: &str, encode_html: bool) -> String {
-    // It’ll grow a bit bigger for each dangerous character.
-    let mut result = String::with_capacity(value.len());
+pub fn encode(value: &str, encode_html: bool) -> Cow<'_, str> {
     let bytes = value.as_bytes();
-    let mut index = 0;
+    let dangerous =
+        |byte: &u8| *byte == b'\0' || (encode_html && matches!(byte, b'&' | b'"' | b'<' | b'>'));
+    let mut index = if let Some(index) = bytes.iter().position(dangerous) {
+        index
+    } else {
+        return Cow::Borrowed(value);
+    };
+    // It’ll grow a bit bigger for each dangerous character.
+    let mut result = String::with_capacity(value.len() + 8);
     let mut start = 0;
 
     while index < bytes.len() {
         let byte = bytes[index];
-        if matches!(byte, b'\0') || (encode_html && matches!(byte, b'&' | b'"' | b'<' | b'>')) {
+        if dangerous(&byte) {
             result.push_str(&value[start..index]);
             result.push_str(match byte {
                 b'\0' => "�",
@@ -51,5 +60,70 @@ pub fn encode(value: &str, encode_html: bool) -> String {
 
     result.push_str(&value[start..]);
 
+    Cow::Owned(result)
+}
+
+/// Decode the dangerous html characters encoded by [`encode`][].
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::encode;
+///
+/// assert_eq!(decode("I &lt;3 🦀"), "I <3 🦀");
+/// ```
+pub fn decode(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(index) = rest.find('&') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let (character, size) = if rest.starts_with("&amp;") {
+            ('&', 5)
+        } else if rest.starts_with("&quot;") {
+            ('"', 6)
+        } else if rest.starts_with("&lt;") {
+            ('<', 4)
+        } else if rest.starts_with("&gt;") {
+            ('>', 4)
+        } else {
+            ('&', 1)
+        };
+
+        result.push(character);
+        rest = &rest[size..];
+    }
+
+    result.push_str(rest);
+
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode() {
+        assert!(
+            matches!(encode("a b", true), Cow::Borrowed("a b")),
+            "should borrow if there is nothing to encode"
+        );
+        assert!(
+            matches!(encode("a < b", false), Cow::Borrowed("a < b")),
+            "should borrow if there is nothing to encode w/o `encode_html`"
+        );
+        assert_eq!(
+            encode("a < b & \"c\" > d", true),
+            "a &lt; b &amp; &quot;c&quot; &gt; d",
+            "should encode dangerous characters"
+        );
+        assert_eq!(
+            encode("a\0b", false),
+            "a�b",
+            "should replace nul w/o `encode_html`"
+        );
+    }
+}
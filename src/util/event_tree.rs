@@ -0,0 +1,187 @@
+//! Tree view over events.
+
+use crate::event::{Event, Kind, Name, Point};
+use crate::message;
+use crate::util::validate::pair;
+use alloc::{vec, vec::Vec};
+
+/// Read-only tree view over a list of [`Event`][]s.
+///
+/// Events are flat: each [`Enter`][Kind::Enter] is paired with the
+/// [`Exit`][Kind::Exit] that closes it, and everything between them is
+/// inside it.
+/// This view pairs them once, so that walking the tree does not need
+/// tracking depth.
+///
+/// Nodes are represented by the index of their enter event, so that
+/// `events[node]` is the enter event, and [`exit()`][EventTree::exit] gets
+/// the index of the exit event.
+/// Events are not copied.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{event::Name, to_events, EventTree, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let events = to_events("# *a*", &ParseOptions::default())?;
+/// let tree = EventTree::new(&events)?;
+///
+/// let heading = tree.roots().next().unwrap();
+/// assert_eq!(tree.name(heading), &Name::HeadingAtx);
+///
+/// let names = tree.children(heading).map(|node| tree.name(node)).collect::<Vec<_>>();
+/// assert_eq!(names, vec![&Name::HeadingAtxSequence, &Name::SpaceOrTab, &Name::HeadingAtxText]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct EventTree<'a> {
+    /// Events.
+    events: &'a [Event],
+    /// Index of the other event in each pair: the exit for enters, the enter
+    /// for exits.
+    pairs: Vec<usize>,
+    /// Index of the enter of the parent of each node, by enter index.
+    parents: Vec<Option<usize>>,
+}
+
+impl<'a> EventTree<'a> {
+    /// Create a tree view over `events`.
+    ///
+    /// ## Errors
+    ///
+    /// Errors when events are not balanced: each exit must close the last
+    /// entered event, and all entered events must be exited.
+    pub fn new(events: &'a [Event]) -> Result<Self, message::Message> {
+        let pairs = pair(events)?;
+        let mut parents = vec![None; events.len()];
+        let mut index = 0;
+
+        while index < events.len() {
+            if events[index].kind == Kind::Enter {
+                let mut child = index + 1;
+
+                while child < pairs[index] {
+                    parents[child] = Some(index);
+                    child = pairs[child] + 1;
+                }
+            }
+
+            index += 1;
+        }
+
+        Ok(EventTree {
+            events,
+            pairs,
+            parents,
+        })
+    }
+
+    /// Get the events this view is over.
+    #[must_use]
+    pub fn events(&self) -> &'a [Event] {
+        self.events
+    }
+
+    /// Get the nodes that are not in another node.
+    ///
+    /// For events from [`to_events()`][crate::to_events], these are the
+    /// blocks of the document, and the line endings between them.
+    pub fn roots(&self) -> impl Iterator<Item = usize> + '_ {
+        Children {
+            pairs: &self.pairs,
+            index: 0,
+            end: self.events.len(),
+        }
+    }
+
+    /// Get the nodes directly in `node`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `node` is not the index of an enter event.
+    pub fn children(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        Children {
+            pairs: &self.pairs,
+            index: node + 1,
+            end: self.exit(node),
+        }
+    }
+
+    /// Get the node that `node` is directly in.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `node` is not the index of an enter event.
+    #[must_use]
+    pub fn parent(&self, node: usize) -> Option<usize> {
+        self.assert_enter(node);
+        self.parents[node]
+    }
+
+    /// Get the index of the exit event of `node`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `node` is not the index of an enter event.
+    #[must_use]
+    pub fn exit(&self, node: usize) -> usize {
+        self.assert_enter(node);
+        self.pairs[node]
+    }
+
+    /// Get the name of `node`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `node` is not the index of an enter event.
+    #[must_use]
+    pub fn name(&self, node: usize) -> &'a Name {
+        self.assert_enter(node);
+        &self.events[node].name
+    }
+
+    /// Get the start and end points of `node`.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `node` is not the index of an enter event.
+    #[must_use]
+    pub fn span(&self, node: usize) -> (&'a Point, &'a Point) {
+        let exit = self.exit(node);
+        (&self.events[node].point, &self.events[exit].point)
+    }
+
+    /// Check that `node` is the index of an enter event.
+    fn assert_enter(&self, node: usize) {
+        assert!(
+            node < self.events.len() && self.events[node].kind == Kind::Enter,
+            "expected `node` to be the index of an enter event"
+        );
+    }
+}
+
+/// Iterator over sibling nodes.
+struct Children<'a> {
+    /// Index of the other event in each pair.
+    pairs: &'a [usize],
+    /// Index of the next node.
+    index: usize,
+    /// Index to stop at.
+    end: usize,
+}
+
+impl Iterator for Children<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index < self.end {
+            let node = self.index;
+            self.index = self.pairs[node] + 1;
+            Some(node)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! Experimental extension point to add custom constructs in text.
+
+use crate::event::Name;
+use crate::tokenizer::Tokenizer;
+use alloc::{boxed::Box, fmt, vec::Vec};
+
+/// Result of a step of a text extension.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ExtensionState {
+    /// The current byte was consumed and the extension wants to see the next
+    /// one.
+    ///
+    /// Returning this without consuming, or at the end of the input, is
+    /// treated as [`ExtensionState::Nok`].
+    Next,
+    /// The construct is done, successfully.
+    ///
+    /// Returning this when nothing was consumed is treated as
+    /// [`ExtensionState::Nok`].
+    Ok,
+    /// The construct is not successful.
+    ///
+    /// Everything the extension did is discarded and `markdown-rs` tries
+    /// other constructs at the same place.
+    Nok,
+}
+
+/// Limited view of the tokenizer, passed to text extensions.
+///
+/// The extension is called once with the marker that triggered it, and then
+/// again for each next byte, as long as it returns
+/// [`ExtensionState::Next`][].
+pub struct ExtensionTokenizer<'t, 'a> {
+    /// Tokenizer.
+    tokenizer: &'t mut Tokenizer<'a>,
+    /// Whether the current byte was consumed.
+    consumed: bool,
+}
+
+impl<'t, 'a> ExtensionTokenizer<'t, 'a> {
+    /// Create a new facade.
+    pub(crate) fn new(tokenizer: &'t mut Tokenizer<'a>) -> Self {
+        Self {
+            tokenizer,
+            consumed: false,
+        }
+    }
+
+    /// Whether the current byte was consumed.
+    pub(crate) fn consumed(&self) -> bool {
+        self.consumed
+    }
+
+    /// Current byte, or `None` at the end of the input.
+    pub fn current(&self) -> Option<u8> {
+        self.tokenizer.current
+    }
+
+    /// Previous byte.
+    pub fn previous(&self) -> Option<u8> {
+        self.tokenizer.previous
+    }
+
+    /// Consume the current byte.
+    ///
+    /// Can be called once per step.
+    /// Does nothing at the end of the input and at line endings: text
+    /// extensions cannot span lines.
+    pub fn consume(&mut self) {
+        if !self.consumed && !matches!(self.tokenizer.current, None | Some(b'\n')) {
+            self.tokenizer.consume();
+            self.consumed = true;
+        }
+    }
+
+    /// Enter an `Extension` event called `name`.
+    ///
+    /// Events must be balanced and must not be empty.
+    pub fn enter(&mut self, name: &'static str) {
+        self.tokenizer.enter(Name::Extension(name));
+    }
+
+    /// Exit an `Extension` event called `name`.
+    pub fn exit(&mut self, name: &'static str) {
+        self.tokenizer.exit(Name::Extension(name));
+    }
+
+    /// Enter data, which is compiled as text.
+    pub fn enter_data(&mut self) {
+        self.tokenizer.enter(Name::Data);
+    }
+
+    /// Exit data.
+    pub fn exit_data(&mut self) {
+        self.tokenizer.exit(Name::Data);
+    }
+
+    /// Get a number kept between steps.
+    ///
+    /// Starts at `0` for every attempt.
+    pub fn state(&self) -> usize {
+        self.tokenizer.tokenize_state.size
+    }
+
+    /// Set a number kept between steps.
+    pub fn set_state(&mut self, value: usize) {
+        self.tokenizer.tokenize_state.size = value;
+    }
+}
+
+/// Signature of a function that tokenizes a text extension.
+pub type ExtensionTokenize = dyn Fn(&mut ExtensionTokenizer) -> ExtensionState;
+
+/// Custom construct in text.
+///
+/// Can be passed in `text_extensions` in
+/// [`ParseOptions`][crate::configuration::ParseOptions].
+///
+/// Extensions are tried, in order, before the built in constructs, at the
+/// bytes in `markers`.
+/// What they consume is not parsed further.
+pub struct TextExtension {
+    /// Name of the extension.
+    pub name: &'static str,
+    /// Bytes that can start the construct.
+    pub markers: Vec<u8>,
+    /// Function called for each byte.
+    pub tokenize: Box<ExtensionTokenize>,
+}
+
+impl fmt::Debug for TextExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TextExtension")
+            .field("name", &self.name)
+            .field("markers", &self.markers)
+            .field("tokenize", &"[Function]")
+            .finish()
+    }
+}
@@ -0,0 +1,110 @@
+//! Utilities to get frontmatter out of events.
+
+use crate::event::{Event, Kind, Name};
+use crate::unist::Position;
+use crate::util::slice::Slice;
+use alloc::string::String;
+
+/// Kind of frontmatter.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{frontmatter, Constructs, FrontmatterKind, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let options = ParseOptions {
+///     constructs: Constructs {
+///         frontmatter: true,
+///         ..Constructs::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let (_, kind, _) = frontmatter("+++\ntitle = \"Jupyter\"\n+++", &options)?.unwrap();
+///
+/// assert_eq!(kind, FrontmatterKind::Toml);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FrontmatterKind {
+    /// TOML, with `+++` fences.
+    ///
+    /// ```markdown
+    /// > | +++
+    ///     ^^^
+    /// > | title = "Jupyter"
+    ///     ^^^^^^^^^^^^^^^^^
+    /// > | +++
+    ///     ^^^
+    /// ```
+    Toml,
+    /// YAML, with `---` fences.
+    ///
+    /// ```markdown
+    /// > | ---
+    ///     ^^^
+    /// > | title: Venus
+    ///     ^^^^^^^^^^^^
+    /// > | ---
+    ///     ^^^
+    /// ```
+    Yaml,
+}
+
+/// Get the value, kind, and position of frontmatter from events.
+///
+/// The value is the same as what [`to_mdast`][crate::to_mdast] puts in
+/// [`Yaml`][crate::mdast::Yaml] and [`Toml`][crate::mdast::Toml] nodes: the
+/// lines between the fences, without the line endings next to the fences.
+pub fn from_events(events: &[Event], bytes: &[u8]) -> Option<(String, FrontmatterKind, Position)> {
+    let mut index = 0;
+    let mut enter = None;
+    let mut value_start = None;
+    let mut value_end = None;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match (&event.kind, &event.name) {
+            (Kind::Enter, Name::Frontmatter) => enter = Some(index),
+            (Kind::Enter, Name::LineEnding) if value_start.is_some() => {
+                value_end = Some(event.point.index);
+            }
+            (Kind::Exit, Name::LineEnding) if value_start.is_none() => {
+                value_start = Some(event.point.index);
+            }
+            (Kind::Exit, Name::Frontmatter) => {
+                let start = &events[enter.expect("expected frontmatter enter")].point;
+                let kind = if bytes[start.index] == b'+' {
+                    FrontmatterKind::Toml
+                } else {
+                    FrontmatterKind::Yaml
+                };
+                // Empty frontmatter only has the line ending after the
+                // opening fence.
+                let value = match (value_start, value_end) {
+                    (Some(value_start), Some(value_end)) => {
+                        Slice::from_indices(bytes, value_start, value_end).serialize()
+                    }
+                    _ => String::new(),
+                };
+
+                return Some((
+                    value,
+                    kind,
+                    Position {
+                        start: start.to_unist(),
+                        end: event.point.to_unist(),
+                    },
+                ));
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    None
+}
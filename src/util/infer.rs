@@ -3,14 +3,55 @@
 //! Used to share between `to_html` and `to_mdast`.
 
 use crate::event::{Event, Kind, Name};
-use crate::mdast::AlignKind;
-use alloc::{vec, vec::Vec};
+use crate::mdast::{AlignKind, OrderedListKind};
+use crate::util::{
+    list_value,
+    normalize_identifier::normalize_identifier,
+    slice::{Position, Slice},
+};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// Get where the things that events enter exit, by the index of their enter.
+///
+/// Indices of exit events are not used.
+/// Used to skip over the contents of list items when figuring out whether
+/// they are spread, as looking at everything in deeply nested lists would
+/// take quadratic time.
+pub fn exits(events: &[Event]) -> Vec<usize> {
+    let mut exits = vec![0; events.len()];
+    let mut stack = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        if events[index].kind == Kind::Enter {
+            stack.push(index);
+        } else {
+            exits[stack.pop().expect("expected enter")] = index;
+        }
+
+        index += 1;
+    }
+
+    exits
+}
 
 /// Figure out if a list is spread or not.
 ///
 /// When `include_items: true` is passed, infers whether the list as a whole
 /// is “loose”.
-pub fn list_loose(events: &[Event], mut index: usize, include_items: bool) -> bool {
+///
+/// `exits` is the result of [`exits`][exits()].
+pub fn list_loose(
+    events: &[Event],
+    exits: &[usize],
+    mut index: usize,
+    include_items: bool,
+) -> bool {
     let mut balance = 0;
     let name = &events[index].name;
     debug_assert!(
@@ -22,12 +63,18 @@ pub fn list_loose(events: &[Event], mut index: usize, include_items: bool) -> bo
         let event = &events[index];
 
         if event.kind == Kind::Enter {
+            // Skip the contents of items.
+            if balance == 2 {
+                index = exits[index] + 1;
+                continue;
+            }
+
             balance += 1;
 
             if include_items
                 && balance == 2
                 && event.name == Name::ListItem
-                && list_item_loose(events, index)
+                && list_item_loose(events, exits, index)
             {
                 return true;
             }
@@ -89,7 +136,9 @@ pub fn list_loose(events: &[Event], mut index: usize, include_items: bool) -> bo
 }
 
 /// Figure out if an item is spread or not.
-pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
+///
+/// `exits` is the result of [`exits`][exits()].
+pub fn list_item_loose(events: &[Event], exits: &[usize], mut index: usize) -> bool {
     debug_assert!(
         matches!(events[index].name, Name::ListItem),
         "expected list item"
@@ -100,6 +149,12 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
         let event = &events[index];
 
         if event.kind == Kind::Enter {
+            // Skip the contents of children.
+            if balance == 2 {
+                index = exits[index] + 1;
+                continue;
+            }
+
             balance += 1;
         } else {
             balance -= 1;
@@ -125,7 +180,33 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
                     at_prefix = true;
                 }
 
-                if !at_prefix {
+                // Blank lines at the end of the item, which are not between
+                // its children:
+                //
+                // ```markdown
+                // > | - a
+                //   | ␊
+                //     ^
+                //   | ␊
+                //   | ***
+                // ```
+                let mut at_end = true;
+                let mut after = index + 1;
+
+                while after < events.len() {
+                    if events[after].name == Name::ListItem {
+                        break;
+                    }
+
+                    if events[after].name != Name::BlankLineEnding {
+                        at_end = false;
+                        break;
+                    }
+
+                    after += 1;
+                }
+
+                if !at_prefix && !at_end {
                     return true;
                 }
             }
@@ -142,6 +223,44 @@ pub fn list_item_loose(events: &[Event], mut index: usize) -> bool {
     false
 }
 
+/// Figure out the kind of numbering of an ordered list.
+///
+/// Looks at the values of the first two items, so that `v.` followed by
+/// `vi.` is seen as roman numerals.
+///
+/// `exits` is the result of [`exits`][exits()].
+pub fn list_kind(
+    events: &[Event],
+    bytes: &[u8],
+    exits: &[usize],
+    mut index: usize,
+) -> Option<OrderedListKind> {
+    debug_assert_eq!(events[index].name, Name::ListOrdered, "expected list");
+    let mut values = vec![];
+    // Skip the list enter.
+    index += 1;
+
+    while index < events.len() && events[index].kind == Kind::Enter && values.len() < 2 {
+        if events[index].name == Name::ListItem {
+            let mut prefix = index + 1;
+
+            if events[prefix].name == Name::SpaceOrTab {
+                prefix = exits[prefix] + 1;
+            }
+
+            if events[prefix + 1].name == Name::ListItemValue {
+                let position = Position::from_exit_event(events, exits[prefix + 1]);
+                values.push(Slice::from_position(bytes, &position).as_str());
+            }
+        }
+
+        index = exits[index] + 1;
+    }
+
+    let value = values.first().copied().unwrap_or_default();
+    list_value::kind_with_next(value, values.get(1).copied())
+}
+
 /// Figure out the alignment of a GFM table.
 pub fn gfm_table_align(events: &[Event], mut index: usize) -> Vec<AlignKind> {
     debug_assert!(
@@ -190,3 +309,160 @@ pub fn gfm_table_align(events: &[Event], mut index: usize) -> Vec<AlignKind> {
 
     align
 }
+
+/// Figure out the identifiers of inline footnotes.
+///
+/// Inline footnotes are numbered in the order they occur, starting at `1`.
+/// Numbers that are used as identifiers of GFM footnote definitions are
+/// skipped, so that the two don’t clash.
+pub fn footnote_inline_identifiers(events: &[Event], bytes: &[u8]) -> Vec<String> {
+    let mut taken = BTreeSet::new();
+    let mut count = 0;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            if event.name == Name::GfmFootnoteDefinitionLabelString {
+                // We don’t care about virtual spaces, so `as_str` is fine.
+                taken.insert(
+                    normalize_identifier(
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index))
+                            .as_str(),
+                    )
+                    .to_lowercase(),
+                );
+            } else if event.name == Name::FootnoteInline {
+                count += 1;
+            }
+        }
+
+        index += 1;
+    }
+
+    let mut identifiers = vec![];
+    let mut number = 0;
+
+    while identifiers.len() < count {
+        number += 1;
+        let identifier = number.to_string();
+        if !taken.contains(&identifier) {
+            identifiers.push(identifier);
+        }
+    }
+
+    identifiers
+}
+
+/// Figure out the identifiers of called GFM footnotes, in the order they are
+/// defined.
+///
+/// Inline footnotes are defined where they occur.
+/// Footnotes defined elsewhere (`extra`) are defined after the document.
+pub fn gfm_footnote_definition_order(
+    events: &[Event],
+    bytes: &[u8],
+    extra: &[String],
+) -> Vec<String> {
+    let inline_identifiers = footnote_inline_identifiers(events, bytes);
+    let mut inline_index = 0;
+    // Definitions in order, and a set of them for lookups.
+    let mut definitions = vec![];
+    let mut defined = BTreeSet::new();
+    let mut calls = BTreeSet::new();
+    let mut label = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            match event.name {
+                Name::GfmFootnoteDefinitionLabelString => {
+                    // We don’t care about virtual spaces, so `as_str` is fine.
+                    let id = normalize_identifier(
+                        Slice::from_position(bytes, &Position::from_exit_event(events, index))
+                            .as_str(),
+                    );
+
+                    if defined.insert(id.to_string()) {
+                        definitions.push(id.into_owned());
+                    }
+                }
+                Name::LabelText => {
+                    label = Some(Position::from_exit_event(events, index));
+                }
+                Name::GfmFootnoteCall => {
+                    let position = label.take().expect("expected label text before call");
+                    calls.insert(
+                        normalize_identifier(Slice::from_position(bytes, &position).as_str())
+                            .into_owned(),
+                    );
+                }
+                Name::FootnoteInline => {
+                    let id = inline_identifiers[inline_index].clone();
+                    inline_index += 1;
+                    defined.insert(id.clone());
+                    definitions.push(id.clone());
+                    calls.insert(id);
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    for id in extra {
+        if defined.insert(id.clone()) {
+            definitions.push(id.clone());
+        }
+    }
+
+    definitions.retain(|id| calls.contains(id));
+    definitions
+}
+
+/// Figure out the identifiers of GFM footnotes that are called but not
+/// defined in the document.
+pub fn gfm_footnote_calls_undefined(events: &[Event], bytes: &[u8]) -> BTreeSet<String> {
+    let mut definitions = BTreeSet::new();
+    let mut calls = BTreeSet::new();
+    let mut label = None;
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            match event.name {
+                Name::GfmFootnoteDefinitionLabelString => {
+                    // We don’t care about virtual spaces, so `as_str` is fine.
+                    definitions.insert(
+                        normalize_identifier(
+                            Slice::from_position(bytes, &Position::from_exit_event(events, index))
+                                .as_str(),
+                        )
+                        .into_owned(),
+                    );
+                }
+                Name::LabelText => {
+                    label = Some(Position::from_exit_event(events, index));
+                }
+                Name::GfmFootnoteCall => {
+                    let position = label.take().expect("expected label text before call");
+                    calls.insert(
+                        normalize_identifier(Slice::from_position(bytes, &position).as_str())
+                            .into_owned(),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    calls.difference(&definitions).cloned().collect()
+}
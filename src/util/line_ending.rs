@@ -16,6 +16,11 @@ use alloc::{str::FromStr, string::String};
 /// # }
 /// ```
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
 pub enum LineEnding {
     /// Both a carriage return (`\r`) and a line feed (`\n`).
     ///
@@ -49,6 +54,32 @@ pub enum LineEnding {
 
 // xxxxxxxxxxxxxxx
 impl LineEnding {
+    /// Get the first line ending in `value`.
+    ///
+    /// This is the line ending that [`to_html()`][crate::to_html] and such
+    /// use in the HTML they generate, instead of
+    /// [`default_line_ending`][crate::CompileOptions::default_line_ending].
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use markdown::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::detect("a\r\nb\nc"), Some(LineEnding::CarriageReturnLineFeed));
+    /// assert_eq!(LineEnding::detect("a"), None);
+    /// ```
+    #[must_use]
+    pub fn detect(value: &str) -> Option<Self> {
+        let bytes = value.as_bytes();
+        let index = bytes.iter().position(|d| matches!(d, b'\r' | b'\n'))?;
+
+        Some(match bytes[index] {
+            b'\n' => LineEnding::LineFeed,
+            _ if bytes.get(index + 1) == Some(&b'\n') => LineEnding::CarriageReturnLineFeed,
+            _ => LineEnding::CarriageReturn,
+        })
+    }
+
     /// Turn the line ending into a [str].
     #[must_use]
     pub fn as_str(&self) -> &str {
@@ -65,9 +96,9 @@ impl FromStr for LineEnding {
 
     /// Turn a string into a line ending.
     ///
-    /// ## Panics
+    /// ## Errors
     ///
-    /// Panics if `code` is not `\r\n`, `\r`, or `\n`.
+    /// Errors if `s` is not exactly `\r\n`, `\r`, or `\n`.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "\r\n" => Ok(LineEnding::CarriageReturnLineFeed),
@@ -78,6 +109,48 @@ impl FromStr for LineEnding {
     }
 }
 
+/// Turn all line endings in `value` into `line_ending`.
+///
+/// A carriage return followed by a line feed is one line ending; sole
+/// carriage returns and sole line feeds are line endings too.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{normalize_line_endings, LineEnding};
+///
+/// assert_eq!(
+///     normalize_line_endings("a\rb\r\nc\n", &LineEnding::LineFeed),
+///     "a\nb\nc\n"
+/// );
+/// ```
+#[must_use]
+pub fn normalize_line_endings(value: &str, line_ending: &LineEnding) -> String {
+    let bytes = value.as_bytes();
+    let eol = line_ending.as_str();
+    let mut result = String::with_capacity(value.len());
+    let mut start = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        if matches!(bytes[index], b'\r' | b'\n') {
+            result.push_str(&value[start..index]);
+            result.push_str(eol);
+
+            if bytes[index] == b'\r' && bytes.get(index + 1) == Some(&b'\n') {
+                index += 1;
+            }
+
+            start = index + 1;
+        }
+
+        index += 1;
+    }
+
+    result.push_str(&value[start..]);
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,5 +195,84 @@ mod tests {
             Err("Expected CR, LF, or CRLF".into()),
             "should error when parsing a non-eol"
         );
+
+        assert_eq!(
+            "\n\n".parse::<LineEnding>(),
+            Err("Expected CR, LF, or CRLF".into()),
+            "should error when parsing more than one eol"
+        );
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(
+            LineEnding::detect("a\nb\r\nc\rd"),
+            Some(LineEnding::LineFeed),
+            "should detect the first line ending (LF)"
+        );
+        assert_eq!(
+            LineEnding::detect("a\r\nb\nc"),
+            Some(LineEnding::CarriageReturnLineFeed),
+            "should detect the first line ending (CRLF)"
+        );
+        assert_eq!(
+            LineEnding::detect("a\rb\r\nc"),
+            Some(LineEnding::CarriageReturn),
+            "should detect the first line ending (CR)"
+        );
+        assert_eq!(
+            LineEnding::detect("a\r"),
+            Some(LineEnding::CarriageReturn),
+            "should detect a final carriage return"
+        );
+        assert_eq!(
+            LineEnding::detect("a"),
+            None,
+            "should not detect line endings in a document w/o them"
+        );
+        assert_eq!(
+            LineEnding::detect(""),
+            None,
+            "should not detect line endings in an empty document"
+        );
+    }
+
+    #[test]
+    fn test_normalize_line_endings() {
+        assert_eq!(
+            normalize_line_endings("a\rb\r\nc\nd", &LineEnding::LineFeed),
+            "a\nb\nc\nd",
+            "should normalize mixed line endings to LF"
+        );
+        assert_eq!(
+            normalize_line_endings("a\rb\r\nc\nd", &LineEnding::CarriageReturnLineFeed),
+            "a\r\nb\r\nc\r\nd",
+            "should normalize mixed line endings to CRLF"
+        );
+        assert_eq!(
+            normalize_line_endings("a\rb\r\nc\nd", &LineEnding::CarriageReturn),
+            "a\rb\rc\rd",
+            "should normalize mixed line endings to CR"
+        );
+        assert_eq!(
+            normalize_line_endings("\n\r\r\n\r", &LineEnding::CarriageReturnLineFeed),
+            "\r\n\r\n\r\n\r\n",
+            "should treat LF + CR as two line endings, and CR + LF as one"
+        );
+        assert_eq!(
+            normalize_line_endings("a\u{2028}b", &LineEnding::CarriageReturnLineFeed),
+            "a\u{2028}b",
+            "should not normalize other characters"
+        );
+        assert_eq!(
+            normalize_line_endings("a", &LineEnding::CarriageReturnLineFeed),
+            "a",
+            "should support documents w/o line endings"
+        );
+        assert_eq!(
+            normalize_line_endings("", &LineEnding::CarriageReturnLineFeed),
+            "",
+            "should support empty documents"
+        );
     }
 }
@@ -0,0 +1,204 @@
+//! Find GFM autolink literals in plain text.
+
+use crate::event::{Kind, Name, Point};
+use crate::parser::ParseState;
+use crate::state::{Name as StateName, State};
+use crate::tokenizer::Tokenizer;
+use crate::util::slice::{Position, Slice};
+use crate::{Constructs, ParseOptions};
+use alloc::{string::String, vec, vec::Vec};
+
+/// Kind of link found by [`linkify()`][].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LinkSpanKind {
+    /// Email address, `mailto:` is prepended.
+    ///
+    /// ```markdown
+    /// > | a contact@example.com b
+    ///       ^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Email,
+    /// Email with `mailto:` protocol.
+    ///
+    /// ```markdown
+    /// > | a mailto:contact@example.com b
+    ///       ^^^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Mailto,
+    /// URL with `http:` or `https:` protocol.
+    ///
+    /// ```markdown
+    /// > | a https://example.com b
+    ///       ^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Protocol,
+    /// URL starting with `www.`, `http://` is prepended.
+    ///
+    /// ```markdown
+    /// > | a www.example.com b
+    ///       ^^^^^^^^^^^^^^^
+    /// ```
+    Www,
+    /// Jabber address with `xmpp:` protocol.
+    ///
+    /// ```markdown
+    /// > | a xmpp:contact@example.com b
+    ///       ^^^^^^^^^^^^^^^^^^^^^^^^
+    /// ```
+    Xmpp,
+}
+
+/// Link found by [`linkify()`][].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinkSpan {
+    /// Byte index in the text where the link starts.
+    pub start: usize,
+    /// Byte index in the text where the link ends.
+    pub end: usize,
+    /// Kind of link.
+    pub kind: LinkSpanKind,
+    /// URL to link to.
+    ///
+    /// This is the text of the link, with `http://` prepended for
+    /// [`Www`][LinkSpanKind::Www] and `mailto:` for
+    /// [`Email`][LinkSpanKind::Email].
+    /// It is not sanitized.
+    pub href: String,
+}
+
+/// Find links in plain text, like GFM autolink literals.
+///
+/// This uses the same rules as the [`gfm_autolink_literal`][gfm] construct,
+/// including how trailing punctuation and parens are handled, but nothing
+/// else is parsed: the text is not treated as markdown.
+/// The spans match what a full GFM parse of the same text (without other
+/// markdown syntax) in a paragraph finds.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{linkify, LinkSpan, LinkSpanKind};
+///
+/// assert_eq!(
+///     linkify("See www.example.com/a_(b)), or mail a@b.c."),
+///     vec![
+///         LinkSpan {
+///             start: 4,
+///             end: 25,
+///             kind: LinkSpanKind::Www,
+///             href: "http://www.example.com/a_(b)".into()
+///         },
+///         LinkSpan {
+///             start: 36,
+///             end: 41,
+///             kind: LinkSpanKind::Email,
+///             href: "mailto:a@b.c".into()
+///         }
+///     ]
+/// );
+/// ```
+///
+/// [gfm]: crate::Constructs::gfm_autolink_literal
+pub fn linkify(value: &str) -> Vec<LinkSpan> {
+    let options = ParseOptions {
+        constructs: Constructs {
+            attention: false,
+            autolink: false,
+            block_quote: false,
+            character_escape: false,
+            character_reference: false,
+            code_indented: false,
+            code_fenced: false,
+            code_text: false,
+            definition: false,
+            footnote_inline: false,
+            frontmatter: false,
+            gfm_autolink_literal: true,
+            gfm_label_start_footnote: false,
+            gfm_footnote_definition: false,
+            gfm_strikethrough: false,
+            gfm_table: false,
+            gfm_task_list_item: false,
+            hard_break_escape: false,
+            hard_break_trailing: false,
+            heading_atx: false,
+            heading_setext: false,
+            html_flow: false,
+            html_text: false,
+            label_start_image: false,
+            label_start_link: false,
+            label_end: false,
+            list_item: false,
+            math_flow: false,
+            math_text: false,
+            mdx_esm: false,
+            mdx_expression_flow: false,
+            mdx_expression_text: false,
+            mdx_jsx_flow: false,
+            mdx_jsx_text: false,
+            thematic_break: false,
+        },
+        ..ParseOptions::default()
+    };
+    let bytes = value.as_bytes();
+    let parse_state = ParseState {
+        options: &options,
+        bytes,
+        location: None,
+        definitions: vec![],
+        gfm_footnote_definitions: vec![],
+        text_markers: vec![],
+        #[cfg(feature = "profile")]
+        profiler: core::cell::RefCell::default(),
+    };
+    let start = Point {
+        line: 1,
+        column: 1,
+        index: 0,
+        vs: 0,
+    };
+    let mut tokenizer = Tokenizer::new(start, &parse_state);
+    let state = tokenizer.push((0, 0), (bytes.len(), 0), State::Next(StateName::TextStart));
+    tokenizer
+        .flush(state, true)
+        .expect("expected text without MDX to not crash");
+
+    let events = tokenizer.events;
+    let mut spans = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Exit {
+            let kind = match event.name {
+                Name::GfmAutolinkLiteralEmail => Some(LinkSpanKind::Email),
+                Name::GfmAutolinkLiteralMailto => Some(LinkSpanKind::Mailto),
+                Name::GfmAutolinkLiteralProtocol => Some(LinkSpanKind::Protocol),
+                Name::GfmAutolinkLiteralWww => Some(LinkSpanKind::Www),
+                Name::GfmAutolinkLiteralXmpp => Some(LinkSpanKind::Xmpp),
+                _ => None,
+            };
+
+            if let Some(kind) = kind {
+                let position = Position::from_exit_event(&events, index);
+                let mut href = String::from(match kind {
+                    LinkSpanKind::Email => "mailto:",
+                    LinkSpanKind::Www => "http://",
+                    _ => "",
+                });
+                href.push_str(Slice::from_position(bytes, &position).as_str());
+                spans.push(LinkSpan {
+                    start: position.start.index,
+                    end: position.end.index,
+                    kind,
+                    href,
+                });
+            }
+        }
+
+        index += 1;
+    }
+
+    spans
+}
@@ -0,0 +1,257 @@
+//! Utilities to handle the values of ordered list items.
+//!
+//! Values are digits, or, when
+//! [`ordered_list_letters`][crate::ParseOptions::ordered_list_letters] is
+//! on, letters or roman numerals.
+
+use crate::mdast::OrderedListKind;
+use alloc::string::String;
+
+/// Roman numerals, from big to small, with their subtractive forms.
+const ROMAN: [(&str, u32); 13] = [
+    ("m", 1000),
+    ("cm", 900),
+    ("d", 500),
+    ("cd", 400),
+    ("c", 100),
+    ("xc", 90),
+    ("l", 50),
+    ("xl", 40),
+    ("x", 10),
+    ("ix", 9),
+    ("v", 5),
+    ("iv", 4),
+    ("i", 1),
+];
+
+/// Get the kind of numbering of a list from the value of its first item.
+///
+/// Returns `None` for digits.
+/// Single letters are letters, except for `i` and `I`, which are roman
+/// numerals, like other values of several letters.
+pub fn kind(value: &str) -> Option<OrderedListKind> {
+    let bytes = value.as_bytes();
+    let upper = bytes.first().map_or(false, u8::is_ascii_uppercase);
+
+    if !bytes.first().map_or(false, u8::is_ascii_alphabetic) {
+        None
+    } else if bytes.len() == 1 && !matches!(bytes[0], b'i' | b'I') {
+        Some(if upper {
+            OrderedListKind::UpperAlpha
+        } else {
+            OrderedListKind::LowerAlpha
+        })
+    } else if upper {
+        Some(OrderedListKind::UpperRoman)
+    } else {
+        Some(OrderedListKind::LowerRoman)
+    }
+}
+
+/// Get the kind of numbering of a list from the values of its first and,
+/// if there is one, second item.
+///
+/// Like [`kind()`][kind()], but a single letter that is also a roman numeral
+/// (such as `v`) starts a list of roman numerals when the next value only
+/// fits that kind (such as `vi`).
+pub fn kind_with_next(value: &str, next: Option<&str>) -> Option<OrderedListKind> {
+    let kind = kind(value);
+
+    if let Some(next) = next {
+        let roman_kind = match kind {
+            Some(OrderedListKind::LowerAlpha) => Some(OrderedListKind::LowerRoman),
+            Some(OrderedListKind::UpperAlpha) => Some(OrderedListKind::UpperRoman),
+            _ => None,
+        };
+
+        if roman_kind.is_some()
+            && !compatible(kind, next)
+            && compatible(roman_kind, value)
+            && compatible(roman_kind, next)
+        {
+            return roman_kind;
+        }
+    }
+
+    kind
+}
+
+/// Check whether an item with `value` can be in a list of `kind`.
+///
+/// Single letters such as `i`, `v`, and `x` can be in both kinds of lists:
+/// `h.` is followed by `i.`, and `iv.` by `v.`.
+pub fn compatible(kind: Option<OrderedListKind>, value: &str) -> bool {
+    let bytes = value.as_bytes();
+
+    match kind {
+        None => bytes.iter().all(u8::is_ascii_digit),
+        Some(OrderedListKind::LowerAlpha) => bytes.len() == 1 && bytes[0].is_ascii_lowercase(),
+        Some(OrderedListKind::UpperAlpha) => bytes.len() == 1 && bytes[0].is_ascii_uppercase(),
+        Some(OrderedListKind::LowerRoman) => {
+            bytes.iter().all(u8::is_ascii_lowercase) && roman(value).is_some()
+        }
+        Some(OrderedListKind::UpperRoman) => {
+            bytes.iter().all(u8::is_ascii_uppercase) && roman(value).is_some()
+        }
+    }
+}
+
+/// Get the number of an item with `value` in a list of `kind`.
+///
+/// Returns `None` if `value` is not valid for `kind`.
+pub fn number(kind: Option<OrderedListKind>, value: &str) -> Option<u32> {
+    if !compatible(kind, value) {
+        return None;
+    }
+
+    match kind {
+        None => value.parse().ok(),
+        Some(OrderedListKind::LowerAlpha | OrderedListKind::UpperAlpha) => {
+            Some(u32::from(value.as_bytes()[0].to_ascii_lowercase() - b'a') + 1)
+        }
+        Some(OrderedListKind::LowerRoman | OrderedListKind::UpperRoman) => roman(value),
+    }
+}
+
+/// Get the number of a roman numeral.
+///
+/// The numeral must be all lowercase or all uppercase, and written in the
+/// usual (shortest) form: `iv`, not `iiii`.
+/// That way words such as `dim` or `civil` are not numerals.
+pub fn roman(value: &str) -> Option<u32> {
+    let bytes = value.as_bytes();
+
+    if bytes.is_empty()
+        || !(bytes.iter().all(u8::is_ascii_lowercase) || bytes.iter().all(u8::is_ascii_uppercase))
+    {
+        return None;
+    }
+
+    let lower = value.to_ascii_lowercase();
+    let mut rest = lower.as_str();
+    let mut total = 0;
+
+    for (numeral, number) in ROMAN {
+        while let Some(after) = rest.strip_prefix(numeral) {
+            rest = after;
+            total += number;
+        }
+    }
+
+    if rest.is_empty() && to_roman(total) == lower {
+        Some(total)
+    } else {
+        None
+    }
+}
+
+/// Turn a number into a lowercase roman numeral, in the usual form.
+fn to_roman(mut number: u32) -> String {
+    let mut result = String::new();
+
+    for (numeral, value) in ROMAN {
+        while number >= value {
+            result.push_str(numeral);
+            number -= value;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(kind("1"), None, "should support digits");
+        assert_eq!(
+            kind("a"),
+            Some(OrderedListKind::LowerAlpha),
+            "should support lowercase letters"
+        );
+        assert_eq!(
+            kind("C"),
+            Some(OrderedListKind::UpperAlpha),
+            "should support uppercase letters, also if they could be numerals"
+        );
+        assert_eq!(
+            kind("i"),
+            Some(OrderedListKind::LowerRoman),
+            "should support `i` as a roman numeral"
+        );
+        assert_eq!(
+            kind("IV"),
+            Some(OrderedListKind::UpperRoman),
+            "should support several letters as roman numerals"
+        );
+    }
+
+    #[test]
+    fn test_kind_with_next() {
+        assert_eq!(
+            kind_with_next("v", None),
+            Some(OrderedListKind::LowerAlpha),
+            "should support a single value"
+        );
+        assert_eq!(
+            kind_with_next("v", Some("w")),
+            Some(OrderedListKind::LowerAlpha),
+            "should support letters followed by letters"
+        );
+        assert_eq!(
+            kind_with_next("V", Some("VI")),
+            Some(OrderedListKind::UpperRoman),
+            "should support letters followed by roman numerals"
+        );
+        assert_eq!(
+            kind_with_next("b", Some("ii")),
+            Some(OrderedListKind::LowerAlpha),
+            "should not support letters that are not numerals followed by roman numerals"
+        );
+        assert_eq!(
+            kind_with_next("v", Some("VI")),
+            Some(OrderedListKind::LowerAlpha),
+            "should not support other cases"
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number(None, "12"), Some(12), "should support digits");
+        assert_eq!(
+            number(Some(OrderedListKind::LowerAlpha), "c"),
+            Some(3),
+            "should support letters"
+        );
+        assert_eq!(
+            number(Some(OrderedListKind::LowerAlpha), "i"),
+            Some(9),
+            "should support `i` as a letter"
+        );
+        assert_eq!(
+            number(Some(OrderedListKind::UpperRoman), "V"),
+            Some(5),
+            "should support `V` as a roman numeral"
+        );
+        assert_eq!(
+            number(Some(OrderedListKind::LowerAlpha), "A"),
+            None,
+            "should not support other cases"
+        );
+    }
+
+    #[test]
+    fn test_roman() {
+        assert_eq!(roman("i"), Some(1), "should support `i`");
+        assert_eq!(roman("iv"), Some(4), "should support subtraction");
+        assert_eq!(roman("XIV"), Some(14), "should support uppercase");
+        assert_eq!(roman("mcmxc"), Some(1990), "should support big numbers");
+        assert_eq!(roman("iiii"), None, "should not support long forms");
+        assert_eq!(roman("dim"), None, "should not support invalid numerals");
+        assert_eq!(roman("Iv"), None, "should not support mixed case");
+        assert_eq!(roman("a"), None, "should not support other letters");
+        assert_eq!(roman(""), None, "should not support empty values");
+    }
+}
@@ -5,6 +5,7 @@
 //!   slices in a whole document, and byte indices into that whole document.
 
 use crate::unist::Point;
+use crate::util::char::column_width;
 use alloc::{vec, vec::Vec};
 
 /// Each stop represents a new slice, which contains the byte index into the
@@ -17,6 +18,9 @@ pub struct Location {
     /// List, where each index is a line number (0-based), and each value is
     /// the byte index *after* where the line ends.
     indices: Vec<usize>,
+    /// List of byte indices *after* non-ASCII characters, and how many bytes
+    /// more than columns there are up to there.
+    surplus: Vec<(usize, usize)>,
 }
 
 impl Location {
@@ -26,9 +30,23 @@ impl Location {
     #[must_use]
     pub fn new(bytes: &[u8]) -> Self {
         let mut index = 0;
-        let mut location_index = Self { indices: vec![] };
+        let mut surplus = 0;
+        let mut location_index = Self {
+            indices: vec![],
+            surplus: vec![],
+        };
 
         while index < bytes.len() {
+            if bytes[index] >= 0xC0 {
+                let size = match bytes[index] {
+                    0xC0..=0xDF => 2,
+                    0xE0..=0xEF => 3,
+                    _ => 4,
+                };
+                surplus += size - column_width(bytes[index]);
+                location_index.surplus.push((index + size, surplus));
+            }
+
             if bytes[index] == b'\r' {
                 if index + 1 < bytes.len() && bytes[index + 1] == b'\n' {
                     location_index.indices.push(index + 2);
@@ -71,13 +89,27 @@ impl Location {
                 } else {
                     0
                 };
-                return Some(Point::new(index + 1, offset + 1 - previous, offset));
+                return Some(Point::new(
+                    index + 1,
+                    offset + 1 - previous - (self.surplus_at(offset) - self.surplus_at(previous)),
+                    offset,
+                ));
             }
         }
 
         None
     }
 
+    /// Get how many bytes more than columns there are before `offset`.
+    fn surplus_at(&self, offset: usize) -> usize {
+        let index = self.surplus.partition_point(|d| d.0 <= offset);
+        if index == 0 {
+            0
+        } else {
+            self.surplus[index - 1].1
+        }
+    }
+
     /// Like `to_point`, but takes a relative offset from a certain string
     /// instead of an absolute offset into the whole document.
     ///
@@ -192,6 +224,42 @@ mod tests {
             "should support some points (4)"
         );
     }
+
+    #[test]
+    fn test_location_bom() {
+        let location = Location::new("\u{FEFF}a\nb".as_bytes());
+        assert_eq!(
+            location.to_point(3), // `a`
+            Some(Point::new(1, 2, 3)),
+            "should count a BOM as one column, but its bytes in offsets (1)"
+        );
+        assert_eq!(
+            location.to_point(5), // `b`
+            Some(Point::new(2, 1, 5)),
+            "should count a BOM as one column, but its bytes in offsets (2)"
+        );
+    }
+
+    #[test]
+    fn test_location_unicode() {
+        let location = Location::new("é “a” 😀 b\n中文 c".as_bytes());
+        assert_eq!(
+            location.to_point(6), // `a`
+            Some(Point::new(1, 4, 6)),
+            "should count non-ASCII characters as one column"
+        );
+        assert_eq!(
+            location.to_point(16), // `b`
+            Some(Point::new(1, 10, 16)),
+            "should count characters outside the BMP as two columns"
+        );
+        assert_eq!(
+            location.to_point(25), // `c`
+            Some(Point::new(2, 4, 25)),
+            "should count columns from the start of the line"
+        );
+    }
+
     #[test]
     fn test_empty() {
         let location = Location::new("".as_bytes());
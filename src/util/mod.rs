@@ -5,15 +5,27 @@ pub mod character_reference;
 pub mod constant;
 pub mod edit_map;
 pub mod encode;
+pub mod event_tree;
+pub mod extension;
+pub mod frontmatter;
 pub mod gfm_tagfilter;
 pub mod identifier;
 pub mod infer;
 pub mod line_ending;
+pub mod linkify;
+pub mod list_value;
 pub mod location;
 pub mod mdx;
 pub mod mdx_collect;
+pub mod multi;
 pub mod normalize_identifier;
+#[cfg(feature = "profile")]
+pub mod profile;
 pub mod sanitize_uri;
 pub mod skip;
 pub mod slice;
+pub mod smart_punctuation;
+pub mod toc;
 pub mod unicode;
+pub mod unified_json;
+pub mod validate;
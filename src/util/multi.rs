@@ -0,0 +1,138 @@
+//! Utilities to split one value into several documents.
+
+use crate::event::{Event, Kind, Name, Point};
+use crate::message;
+use crate::parser::parse_range;
+use crate::util::location::Location;
+use crate::ParseOptions;
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// Line that is exactly the delimiter.
+struct Delimiter {
+    /// Index where the line starts.
+    start: usize,
+    /// Index after the line, including its line ending (if any).
+    end: usize,
+}
+
+/// Get the documents in `value`, separated by lines that are exactly
+/// `delimiter`.
+///
+/// Each document is a start point and an end index into `value`.
+/// A document includes the line ending before the delimiter that ends it.
+/// Delimiters in fenced code, math (flow), and frontmatter do not split, as
+/// they are content of those constructs.
+/// That is found by parsing each document up to the delimiter after the
+/// candidate, so checking is linear, unless a fence contains many delimiters.
+///
+/// Errors when `delimiter` is empty, as that would match every blank line.
+pub fn split(
+    value: &str,
+    delimiter: &str,
+    options: &ParseOptions,
+) -> Result<Vec<(Point, usize)>, message::Message> {
+    if delimiter.is_empty() {
+        return Err(message::Message {
+            place: None,
+            reason: "Unexpected empty delimiter, expected at least one character, as an empty delimiter matches every blank line".into(),
+            rule_id: Box::new(message::rules::INVALID_DELIMITER.into()),
+            source: Box::new(message::rules::SOURCE.into()),
+        });
+    }
+
+    let delimiters = find(value.as_bytes(), delimiter.as_bytes());
+    let location = Location::new(value.as_bytes());
+    let mut documents = vec![];
+    let mut start = point(&location, 0);
+    let mut index = 0;
+
+    while index < delimiters.len() {
+        let current = &delimiters[index];
+        let end = delimiters
+            .get(index + 1)
+            .map_or(value.len(), |next| next.start);
+        let (events, _) = parse_range(value, options, start.clone(), end, vec![])?;
+
+        if !inside(&events, current.start) {
+            documents.push((start, current.start));
+            start = point(&location, current.end);
+        }
+
+        index += 1;
+    }
+
+    documents.push((start, value.len()));
+    Ok(documents)
+}
+
+/// Find lines that are exactly `delimiter`.
+fn find(bytes: &[u8], delimiter: &[u8]) -> Vec<Delimiter> {
+    let mut delimiters = vec![];
+    let mut start = 0;
+
+    while start <= bytes.len() {
+        let mut index = start;
+
+        while index < bytes.len() && !matches!(bytes[index], b'\n' | b'\r') {
+            index += 1;
+        }
+
+        let mut end = index;
+
+        if end < bytes.len() {
+            end += if bytes[end] == b'\r' && end + 1 < bytes.len() && bytes[end + 1] == b'\n' {
+                2
+            } else {
+                1
+            };
+        }
+
+        if &bytes[start..index] == delimiter {
+            delimiters.push(Delimiter { start, end });
+        }
+
+        // Last line.
+        if end == index {
+            break;
+        }
+
+        start = end;
+    }
+
+    delimiters
+}
+
+/// Check whether the line at `index` is in fenced code, math (flow), or
+/// frontmatter.
+///
+/// The line that opens such a construct is not in it.
+fn inside(events: &[Event], index: usize) -> bool {
+    let mut open = None;
+
+    for event in events {
+        if matches!(
+            event.name,
+            Name::CodeFenced | Name::MathFlow | Name::Frontmatter
+        ) {
+            if event.kind == Kind::Enter {
+                open = Some(event.point.index);
+            } else if matches!(open, Some(start) if start < index) && event.point.index > index {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Get the point at `index`.
+fn point(location: &Location, index: usize) -> Point {
+    let point = location.to_point(index).unwrap();
+
+    Point {
+        line: point.line,
+        column: point.column,
+        index,
+        vs: 0,
+    }
+}
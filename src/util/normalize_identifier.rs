@@ -1,6 +1,6 @@
 //! Normalize identifiers.
 
-use alloc::string::String;
+use alloc::{borrow::Cow, string::String};
 
 /// Normalize an identifier, as found in [references][label_end] and
 /// [definitions][definition], so it can be compared when matching.
@@ -23,6 +23,18 @@ use alloc::string::String;
 /// If we’d inverse the steps, for `ẞ`, we’d first uppercase without a
 /// change, and then lowercase to `ß`, which would not match `ss`.
 ///
+/// Case folding is full, not simple, so one character can match several:
+/// `İ` (U+0130) matches `i̇` (U+0069 U+0307), and `ﬁ` (U+FB01) matches `fi`
+/// (U+0066 U+0069).
+///
+/// No unicode normalization is performed, as in `micromark`, `cmark`, and
+/// GitHub, so canonically equivalent identifiers that are written
+/// differently do not match: `é` (U+00E9) does not match `é` (U+0065 U+0301).
+///
+/// Identifiers are often already normalized (such as `#123` or `API`), in
+/// which case they are borrowed instead of allocated.
+/// ASCII identifiers are allocated once.
+///
 /// ## Examples
 ///
 /// ```rust ignore
@@ -41,7 +53,11 @@ use alloc::string::String;
 ///
 /// [definition]: crate::construct::definition
 /// [label_end]: crate::construct::label_end
-pub fn normalize_identifier(value: &str) -> String {
+pub fn normalize_identifier(value: &str) -> Cow<'_, str> {
+    if is_normalized(value) {
+        return Cow::Borrowed(value);
+    }
+
     // Note: it’ll grow a bit smaller for consecutive whitespace.
     let mut result = String::with_capacity(value.len());
     let bytes = value.as_bytes();
@@ -59,7 +75,7 @@ pub fn normalize_identifier(value: &str) -> String {
         }
         // First non-whitespace we see after whitespace.
         else if in_whitespace {
-            if start != 0 {
+            if !result.is_empty() {
                 result.push(' ');
             }
 
@@ -74,5 +90,75 @@ pub fn normalize_identifier(value: &str) -> String {
         result.push_str(&value[start..]);
     }
 
-    result.to_lowercase().to_uppercase()
+    // For ASCII, lower- and then uppercasing is the same as uppercasing.
+    if result.is_ascii() {
+        result.make_ascii_uppercase();
+        Cow::Owned(result)
+    } else {
+        Cow::Owned(result.to_lowercase().to_uppercase())
+    }
+}
+
+/// Check whether `value` is already normalized: ASCII without lowercase
+/// letters, with whitespace only as single spaces between other characters.
+fn is_normalized(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b' ' if index == 0 || index == bytes.len() - 1 || bytes[index - 1] == b' ' => {
+                return false;
+            }
+            b'\t' | b'\n' | b'\r' | b'a'..=b'z' | 0x80..=0xFF => return false,
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_identifier() {
+        assert!(
+            matches!(normalize_identifier("#123"), Cow::Borrowed("#123")),
+            "should borrow normalized identifiers"
+        );
+        assert!(
+            matches!(normalize_identifier("A B"), Cow::Borrowed("A B")),
+            "should borrow normalized identifiers w/ spaces"
+        );
+        assert_eq!(
+            normalize_identifier("  a\t\r\n b  "),
+            "A B",
+            "should collapse and trim whitespace"
+        );
+        assert_eq!(
+            normalize_identifier("a  b"),
+            "A B",
+            "should collapse consecutive spaces"
+        );
+        assert_eq!(
+            normalize_identifier("Привет"),
+            "ПРИВЕТ",
+            "should case fold non-ASCII"
+        );
+        assert_eq!(
+            normalize_identifier("ẞ"),
+            normalize_identifier("ss"),
+            "should case fold fully"
+        );
+        assert_eq!(normalize_identifier(""), "", "should support empty values");
+        assert_eq!(
+            normalize_identifier(" "),
+            "",
+            "should support whitespace only values"
+        );
+    }
 }
@@ -0,0 +1,233 @@
+//! Collect statistics about how a document is parsed.
+//!
+//! Only available with the `profile` feature.
+
+use crate::event::{Event, Kind, Name as EventName};
+use crate::resolve::Name as ResolveName;
+use crate::state::Name as StateName;
+use alloc::{format, string::String, vec, vec::Vec};
+use std::time::{Duration, Instant};
+
+/// Statistics about how a document was turned into HTML.
+///
+/// Can be gotten with [`to_html_with_profile()`][crate::to_html_with_profile].
+#[derive(Clone, Debug, Default)]
+pub struct Profile {
+    /// Time taken per state of the state machine, such as `AttentionStart`.
+    ///
+    /// The name of a state starts with the name of its construct.
+    /// `count` is the number of steps taken in that state.
+    /// `time` is exclusive: it does not include time spent in nested
+    /// tokenizers, such as the flow tokenizer that the document tokenizer
+    /// feeds.
+    pub states: Vec<ProfileEntry>,
+    /// Events per name, such as `Emphasis`.
+    ///
+    /// `count` is the number of enter events in the result of parsing.
+    /// `time` is always zero.
+    pub events: Vec<ProfileEntry>,
+    /// Time taken per resolver, such as `Attention`.
+    ///
+    /// `count` is the number of times the resolver ran, which is once per
+    /// tokenizer that registered it.
+    /// `time` is exclusive, like for `states`.
+    pub resolvers: Vec<ProfileEntry>,
+    /// Number of times content in other content was tokenized.
+    pub passes: usize,
+    /// Time taken to parse.
+    pub parse_time: Duration,
+    /// Time taken to compile.
+    pub compile_time: Duration,
+}
+
+/// Statistics of one thing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProfileEntry {
+    /// Name.
+    pub name: String,
+    /// How often it occurred.
+    pub count: usize,
+    /// Time spent.
+    pub time: Duration,
+}
+
+/// Collector of statistics, shared by the tokenizers of a document.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    /// Steps and time per state, indexed by state name.
+    states: Vec<Option<(StateName, usize, Duration)>>,
+    /// Runs and time per resolver, indexed by resolver name.
+    resolvers: Vec<Option<(ResolveName, usize, Duration)>>,
+    /// Number of subtokenize passes.
+    passes: usize,
+    /// Time spent in nested calls, for each running call.
+    nested: Vec<Duration>,
+}
+
+impl Profiler {
+    /// Start timing a call.
+    pub fn start(&mut self) -> Instant {
+        self.nested.push(Duration::ZERO);
+        Instant::now()
+    }
+
+    /// Stop timing a call of state `name`, started at `start`.
+    pub fn state(&mut self, name: StateName, start: Instant) {
+        let time = self.stop(start);
+        add(&mut self.states, name, name as usize, time);
+    }
+
+    /// Stop timing a call of resolver `name`, started at `start`.
+    pub fn resolver(&mut self, name: ResolveName, start: Instant) {
+        let time = self.stop(start);
+        add(&mut self.resolvers, name, name as usize, time);
+    }
+
+    /// Count a subtokenize pass.
+    pub fn pass(&mut self) {
+        self.passes += 1;
+    }
+
+    /// Turn into a profile, with the `events` that parsing resulted in.
+    pub fn into_profile(
+        self,
+        events: &[Event],
+        parse_time: Duration,
+        compile_time: Duration,
+    ) -> Profile {
+        let mut counts: Vec<(&EventName, usize)> = vec![];
+
+        for event in events {
+            if event.kind == Kind::Enter {
+                if let Some(entry) = counts.iter_mut().find(|d| d.0 == &event.name) {
+                    entry.1 += 1;
+                } else {
+                    counts.push((&event.name, 1));
+                }
+            }
+        }
+
+        let mut states = entries(self.states);
+        let mut events = counts
+            .into_iter()
+            .map(|(name, count)| ProfileEntry {
+                name: format!("{:?}", name),
+                count,
+                time: Duration::ZERO,
+            })
+            .collect::<Vec<_>>();
+        let mut resolvers = entries(self.resolvers);
+
+        states.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        events.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+        resolvers.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+        Profile {
+            states,
+            events,
+            resolvers,
+            passes: self.passes,
+            parse_time,
+            compile_time,
+        }
+    }
+
+    /// Stop timing a call, started at `start`, and get the time spent in it
+    /// minus the time spent in nested calls.
+    fn stop(&mut self, start: Instant) -> Duration {
+        let time = start.elapsed();
+        let nested = self.nested.pop().expect("expected `start` before `stop`");
+
+        if let Some(parent) = self.nested.last_mut() {
+            *parent += time;
+        }
+
+        time.saturating_sub(nested)
+    }
+}
+
+/// Add a call to the entry of `name` at `index`.
+fn add<T>(list: &mut Vec<Option<(T, usize, Duration)>>, name: T, index: usize, time: Duration) {
+    if index >= list.len() {
+        list.resize_with(index + 1, || None);
+    }
+
+    if let Some(entry) = &mut list[index] {
+        entry.1 += 1;
+        entry.2 += time;
+    } else {
+        list[index] = Some((name, 1, time));
+    }
+}
+
+/// Turn entries into public entries.
+fn entries<T: core::fmt::Debug>(list: Vec<Option<(T, usize, Duration)>>) -> Vec<ProfileEntry> {
+    list.into_iter()
+        .flatten()
+        .map(|(name, count, time)| ProfileEntry {
+            name: format!("{:?}", name),
+            count,
+            time,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::Point;
+
+    #[test]
+    fn profiler() {
+        let mut profiler = Profiler::default();
+        let outer = profiler.start();
+        let inner = profiler.start();
+        profiler.state(StateName::AttentionInside, inner);
+        profiler.state(StateName::AttentionStart, outer);
+        let start = profiler.start();
+        profiler.resolver(ResolveName::Attention, start);
+        profiler.pass();
+
+        let point = Point {
+            line: 1,
+            column: 1,
+            index: 0,
+            vs: 0,
+        };
+        let event = |kind, name| Event {
+            kind,
+            name,
+            point: point.clone(),
+            link: None,
+        };
+        let events = vec![
+            event(Kind::Enter, EventName::Emphasis),
+            event(Kind::Exit, EventName::Emphasis),
+            event(Kind::Enter, EventName::Data),
+            event(Kind::Exit, EventName::Data),
+        ];
+        let profile = profiler.into_profile(&events, Duration::ZERO, Duration::ZERO);
+        let names = |list: &[ProfileEntry]| {
+            list.iter()
+                .map(|d| (d.name.clone(), d.count))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(&profile.states),
+            vec![("AttentionInside".into(), 1), ("AttentionStart".into(), 1)],
+            "should collect states, sorted by name"
+        );
+        assert_eq!(
+            names(&profile.events),
+            vec![("Data".into(), 1), ("Emphasis".into(), 1)],
+            "should count events"
+        );
+        assert_eq!(
+            names(&profile.resolvers),
+            vec![("Attention".into(), 1)],
+            "should collect resolvers"
+        );
+        assert_eq!(profile.passes, 1, "should count passes");
+    }
+}
@@ -24,14 +24,14 @@ use alloc::{format, string::String, vec::Vec};
 /// *   [`micromark-util-sanitize-uri` in `micromark`](https://github.com/micromark/micromark/tree/main/packages/micromark-util-sanitize-uri)
 #[must_use]
 pub fn sanitize(value: &str) -> String {
-    encode(&normalize(value), true)
+    encode(&normalize(value), true).into_owned()
 }
 
 /// Make a value safe for injection as a URL, and check protocols.
 ///
 /// This first uses [`sanitize`][].
-/// Then, a vec of (lowercase) allowed protocols can be given, in which case
-/// the URL is ignored or kept.
+/// Then, a list of allowed protocols can be given (compared
+/// case-insensitively), in which case the URL is ignored or kept.
 ///
 /// For example, `&["http", "https", "irc", "ircs", "mailto", "xmpp"]`
 /// can be used for `a[href]`, or `&["http", "https"]` for `img[src]`.
@@ -51,7 +51,7 @@ pub fn sanitize(value: &str) -> String {
 /// ## References
 ///
 /// *   [`micromark-util-sanitize-uri` in `micromark`](https://github.com/micromark/micromark/tree/main/packages/micromark-util-sanitize-uri)
-pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
+pub fn sanitize_with_protocols(value: &str, protocols: &[impl AsRef<str>]) -> String {
     let value = sanitize(value);
 
     let end = value.find(|c| matches!(c, '?' | '#' | '/'));
@@ -69,8 +69,11 @@ pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     // If there is no protocol, it’s relative, and fine.
     if let Some(colon) = colon {
         // If it is a protocol, it should be allowed.
-        let protocol = value[0..colon].to_lowercase();
-        if !protocols.contains(&protocol.as_str()) {
+        let protocol = &value[0..colon];
+        if !protocols
+            .iter()
+            .any(|d| d.as_ref().eq_ignore_ascii_case(protocol))
+        {
             return String::new();
         }
     }
@@ -78,6 +81,238 @@ pub fn sanitize_with_protocols(value: &str, protocols: &[&str]) -> String {
     value
 }
 
+/// Resolve a URL reference against a base URL.
+///
+/// This follows the algorithm in
+/// [§ 5.2 of RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2):
+/// the components of `value` that are missing are taken from `base`, and
+/// dot segments (`.` and `..`) are removed from the path.
+/// Nothing is encoded or decoded: use [`sanitize`][] on the result.
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sanitize_uri::resolve;
+///
+/// assert_eq!(resolve("https://a.b/c/d.md", "../e.png"), "https://a.b/e.png");
+/// assert_eq!(resolve("https://a.b/c/d.md", "?e"), "https://a.b/c/d.md?e");
+/// assert_eq!(resolve("https://a.b/c/d.md", "mailto:e@f.g"), "mailto:e@f.g");
+/// ```
+///
+/// ## References
+///
+/// *   [*§ 5.2 Relative Resolution* in `RFC 3986`](https://www.rfc-editor.org/rfc/rfc3986#section-5.2)
+pub fn resolve(base: &str, value: &str) -> String {
+    let base = Uri::parse(base);
+    let reference = Uri::parse(value);
+
+    let target = if reference.scheme.is_some() {
+        Uri {
+            path: remove_dot_segments(reference.path),
+            ..reference.into()
+        }
+    } else if reference.authority.is_some() {
+        Uri {
+            scheme: base.scheme,
+            path: remove_dot_segments(reference.path),
+            ..reference.into()
+        }
+    } else if reference.path.is_empty() {
+        Uri {
+            scheme: base.scheme,
+            authority: base.authority,
+            path: base.path.into(),
+            query: reference.query.or(base.query),
+            fragment: reference.fragment,
+        }
+    } else {
+        let path = if reference.path.starts_with('/') {
+            reference.path.into()
+        } else if base.authority.is_some() && base.path.is_empty() {
+            format!("/{}", reference.path)
+        } else {
+            let end = base.path.rfind('/').map_or(0, |index| index + 1);
+            format!("{}{}", &base.path[0..end], reference.path)
+        };
+
+        Uri {
+            scheme: base.scheme,
+            authority: base.authority,
+            path: remove_dot_segments(&path),
+            query: reference.query,
+            fragment: reference.fragment,
+        }
+    };
+
+    target.serialize()
+}
+
+/// Check if a URL reference is relative to a path, so that it would change
+/// when resolved against a base.
+///
+/// That is, it has no scheme (such as `https:` or `mailto:`), is not
+/// protocol-relative (`//`), and is not only a fragment (`#`).
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::sanitize_uri::is_path_relative;
+///
+/// assert!(is_path_relative("./a.md"));
+/// assert!(is_path_relative("?a"));
+/// assert!(!is_path_relative("#a"));
+/// assert!(!is_path_relative("//a.b/c"));
+/// assert!(!is_path_relative("data:,a"));
+/// ```
+pub fn is_path_relative(value: &str) -> bool {
+    !value.starts_with('#') && !value.starts_with("//") && Uri::parse(value).scheme.is_none()
+}
+
+/// Components of a URL.
+struct Uri<'a, P> {
+    /// Scheme, without `:`.
+    scheme: Option<&'a str>,
+    /// Authority, without `//`.
+    authority: Option<&'a str>,
+    /// Path.
+    path: P,
+    /// Query, without `?`.
+    query: Option<&'a str>,
+    /// Fragment, without `#`.
+    fragment: Option<&'a str>,
+}
+
+impl<'a> Uri<'a, &'a str> {
+    /// Split a URL into its components.
+    ///
+    /// See [appendix B of RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#appendix-B).
+    fn parse(value: &'a str) -> Self {
+        let mut rest = value;
+        let mut fragment = None;
+        let mut query = None;
+        let mut authority = None;
+
+        if let Some(index) = rest.find('#') {
+            fragment = Some(&rest[(index + 1)..]);
+            rest = &rest[0..index];
+        }
+
+        if let Some(index) = rest.find('?') {
+            query = Some(&rest[(index + 1)..]);
+            rest = &rest[0..index];
+        }
+
+        let scheme = rest.find(':').and_then(|index| {
+            let scheme = &rest[0..index];
+            let mut bytes = scheme.bytes();
+
+            if matches!(bytes.next(), Some(byte) if byte.is_ascii_alphabetic())
+                && bytes
+                    .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.'))
+            {
+                rest = &rest[(index + 1)..];
+                Some(scheme)
+            } else {
+                None
+            }
+        });
+
+        if let Some(after) = rest.strip_prefix("//") {
+            let index = after.find('/').unwrap_or(after.len());
+            authority = Some(&after[0..index]);
+            rest = &after[index..];
+        }
+
+        Uri {
+            scheme,
+            authority,
+            path: rest,
+            query,
+            fragment,
+        }
+    }
+}
+
+impl<'a> From<Uri<'a, &'a str>> for Uri<'a, String> {
+    fn from(uri: Uri<'a, &'a str>) -> Self {
+        Uri {
+            scheme: uri.scheme,
+            authority: uri.authority,
+            path: uri.path.into(),
+            query: uri.query,
+            fragment: uri.fragment,
+        }
+    }
+}
+
+impl Uri<'_, String> {
+    /// Join components into a URL.
+    ///
+    /// See [§ 5.3 of RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.3).
+    fn serialize(&self) -> String {
+        let mut result = String::new();
+
+        if let Some(scheme) = self.scheme {
+            result.push_str(scheme);
+            result.push(':');
+        }
+
+        if let Some(authority) = self.authority {
+            result.push_str("//");
+            result.push_str(authority);
+        }
+
+        result.push_str(&self.path);
+
+        if let Some(query) = self.query {
+            result.push('?');
+            result.push_str(query);
+        }
+
+        if let Some(fragment) = self.fragment {
+            result.push('#');
+            result.push_str(fragment);
+        }
+
+        result
+    }
+}
+
+/// Remove `.` and `..` segments from a path.
+///
+/// See [§ 5.2.4 of RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2.4).
+fn remove_dot_segments(path: &str) -> String {
+    let mut input = path;
+    let mut output = String::with_capacity(path.len());
+
+    while !input.is_empty() {
+        if let Some(rest) = input
+            .strip_prefix("../")
+            .or_else(|| input.strip_prefix("./"))
+        {
+            input = rest;
+        } else if input.starts_with("/./") {
+            input = &input[2..];
+        } else if input == "/." {
+            input = "/";
+        } else if input.starts_with("/../") || input == "/.." {
+            input = if input.len() == 3 { "/" } else { &input[3..] };
+            output.truncate(output.rfind('/').unwrap_or(0));
+        } else if input == "." || input == ".." {
+            input = "";
+        } else {
+            let start = usize::from(input.starts_with('/'));
+            let end = input[start..]
+                .find('/')
+                .map_or(input.len(), |index| index + start);
+            output.push_str(&input[0..end]);
+            input = &input[end..];
+        }
+    }
+
+    output
+}
+
 /// Normalize a URL (such as used in [definitions][definition],
 /// [references][label_end]).
 ///
@@ -120,6 +355,15 @@ fn normalize(value: &str) -> String {
             continue;
         }
 
+        // NUL is replaced with a replacement character, like in `encode`.
+        if char == '\0' {
+            result.push_str(&chars[start..index].iter().collect::<String>());
+            result.push_str("%EF%BF%BD");
+            start = index + 1;
+            index += 1;
+            continue;
+        }
+
         // Note: Rust already takes care of lone surrogates.
         // Non-ascii or not allowed ascii.
         if char >= '\u{0080}'
@@ -146,3 +390,102 @@ fn normalize(value: &str) -> String {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let base = "http://a/b/c/d;p?q";
+        // Examples from § 5.4 of RFC 3986.
+        let cases = [
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("g;x", "http://a/b/c/g;x"),
+            ("g;x?y#s", "http://a/b/c/g;x?y#s"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../", "http://a/"),
+            ("../../g", "http://a/g"),
+            ("../../../g", "http://a/g"),
+            ("../../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            (".g", "http://a/b/c/.g"),
+            ("g..", "http://a/b/c/g.."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("./g/.", "http://a/b/c/g/"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g;x=1/./y", "http://a/b/c/g;x=1/y"),
+            ("g;x=1/../y", "http://a/b/c/y"),
+            ("g?y/./x", "http://a/b/c/g?y/./x"),
+            ("g?y/../x", "http://a/b/c/g?y/../x"),
+            ("g#s/./x", "http://a/b/c/g#s/./x"),
+            ("g#s/../x", "http://a/b/c/g#s/../x"),
+            ("http:g", "http:g"),
+        ];
+
+        for (value, expected) in cases {
+            assert_eq!(
+                resolve(base, value),
+                expected,
+                "should resolve `{}` against `{}`",
+                value,
+                base
+            );
+        }
+    }
+
+    #[test]
+    fn test_resolve_authority() {
+        assert_eq!(
+            resolve("https://example.com", "a/b.md"),
+            "https://example.com/a/b.md",
+            "should add a slash when the base has an authority but no path"
+        );
+    }
+
+    #[test]
+    fn test_is_path_relative() {
+        assert!(is_path_relative("a"), "should support a bare name");
+        assert!(is_path_relative("../a"), "should support a parent");
+        assert!(is_path_relative("?a"), "should support a query");
+        assert!(is_path_relative(""), "should support empty");
+        assert!(
+            is_path_relative("a/b:c"),
+            "should support a colon after a slash"
+        );
+        assert!(!is_path_relative("#a"), "should not support a fragment");
+        assert!(
+            !is_path_relative("//a/b"),
+            "should not support protocol-relative"
+        );
+        assert!(
+            !is_path_relative("mailto:a@b.c"),
+            "should not support a scheme"
+        );
+        assert!(
+            !is_path_relative("A+b.c-d:e"),
+            "should not support a scheme w/ `+`, `.`, `-`"
+        );
+    }
+}
@@ -72,10 +72,11 @@ impl<'a> Slice<'a> {
 
         // If we have virtual spaces before, it means we are past the actual
         // character at that index, and those virtual spaces.
+        // The tab spans to the next tab stop, which depends on where it is.
         if before > 0 {
-            before = TAB_SIZE - before;
+            before = (TAB_SIZE - position.start.column % TAB_SIZE) % TAB_SIZE + 1;
             start += 1;
-        };
+        }
 
         // If we have virtual spaces after, it means that character is included,
         // and one less virtual space.
@@ -110,7 +111,7 @@ impl<'a> Slice<'a> {
     /// Turn the slice into a `&str`.
     ///
     /// > 👉 **Note**: cannot represent virtual spaces.
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'a str {
         str::from_utf8(self.bytes).unwrap()
     }
 
@@ -0,0 +1,104 @@
+//! Turn ASCII punctuation into typographic punctuation.
+
+use crate::util::char::{before_index, classify_opt, kind_after_index, Kind};
+use alloc::string::String;
+
+/// Turn straight quotes, dashes, and dots into typographic punctuation.
+///
+/// Data is the part of `bytes` between `start` and `end`.
+/// The characters around quotes, also those outside data, decide whether
+/// they open or close, like the delimiter runs of attention:
+///
+/// *   `"` opens (`“`) when followed by a word and not preceded by one,
+///     closes (`”`) the other way around, or when both are words;
+///     otherwise it is kept
+/// *   `'` works the same (`‘` and `’`), but it is an apostrophe (`’`)
+///     between words (`it’s`) and before digits (`’90s`)
+///
+/// Sequences of hyphens are turned into dashes: `--` into an en dash (`–`),
+/// `---` into an em dash (`—`), and longer sequences into em dashes if
+/// possible, en dashes otherwise, or em dashes followed by en dashes.
+/// Sequences of three dots (`...`) are turned into an ellipsis (`…`).
+///
+/// ## Examples
+///
+/// ```rust ignore
+/// use markdown::util::smart_punctuation::smart_punctuation;
+///
+/// let bytes = "\"It's 1--2...\"".as_bytes();
+/// assert_eq!(smart_punctuation(bytes, 0, bytes.len()), "“It’s 1–2…”");
+/// ```
+///
+/// ## References
+///
+/// *   [`inlines.c` in `cmark`](https://github.com/commonmark/cmark/blob/master/src/inlines.c)
+pub fn smart_punctuation(bytes: &[u8], start: usize, end: usize) -> String {
+    let value = core::str::from_utf8(&bytes[start..end]).unwrap();
+    let mut result = String::with_capacity(value.len());
+    let mut index = start;
+    let mut last = start;
+
+    while index < end {
+        let byte = bytes[index];
+
+        match byte {
+            b'"' | b'\'' => {
+                result.push_str(&value[(last - start)..(index - start)]);
+                let before = classify_opt(before_index(bytes, index));
+                let after = kind_after_index(bytes, index + 1);
+                let open = after != Kind::Whitespace
+                    && (after != Kind::Punctuation || before != Kind::Other);
+                let close = before != Kind::Whitespace
+                    && (before != Kind::Punctuation || after != Kind::Other);
+                let digit = index + 1 < bytes.len() && bytes[index + 1].is_ascii_digit();
+
+                result.push(match (byte, open, close) {
+                    (b'"', true, false) => '“',
+                    (b'"', _, true) => '”',
+                    (b'\'', true, false) if !digit => '‘',
+                    (b'\'', true, _) | (b'\'', _, true) => '’',
+                    _ => char::from(byte),
+                });
+
+                index += 1;
+                last = index;
+            }
+            b'-' | b'.' => {
+                let mut size = 1;
+
+                while index + size < end && bytes[index + size] == byte {
+                    size += 1;
+                }
+
+                if size > 1 {
+                    result.push_str(&value[(last - start)..(index - start)]);
+
+                    if byte == b'.' {
+                        result.push_str(&"…".repeat(size / 3));
+                        result.push_str(&".".repeat(size % 3));
+                    } else {
+                        let (em, en) = if size % 3 == 0 {
+                            (size / 3, 0)
+                        } else if size % 2 == 0 {
+                            (0, size / 2)
+                        } else if size % 3 == 2 {
+                            ((size - 2) / 3, 1)
+                        } else {
+                            ((size - 4) / 3, 2)
+                        };
+                        result.push_str(&"—".repeat(em));
+                        result.push_str(&"–".repeat(en));
+                    }
+
+                    last = index + size;
+                }
+
+                index += size;
+            }
+            _ => index += 1,
+        }
+    }
+
+    result.push_str(&value[(last - start)..]);
+    result
+}
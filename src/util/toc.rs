@@ -0,0 +1,104 @@
+//! Utilities to get a table of contents out of events.
+
+use crate::event::{Event, Kind, Name};
+use crate::to_html::compile_text;
+use crate::unist::Position;
+use alloc::{string::String, vec, vec::Vec};
+
+/// Heading in a table of contents.
+///
+/// ## Examples
+///
+/// ```
+/// use markdown::{toc, ParseOptions};
+/// # fn main() -> Result<(), markdown::message::Message> {
+///
+/// let entries = toc("# Venus\n\n## *Atmosphere* &amp; `clouds`", &ParseOptions::default())?;
+///
+/// assert_eq!(entries[1].depth, 2);
+/// assert_eq!(entries[1].text, "Atmosphere & clouds");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TocEntry {
+    /// Rank of the heading (between `1` and `6`, both including, unless
+    /// `heading_atx_max_depth` allows deeper headings).
+    pub depth: u8,
+    /// Plain text content of the heading.
+    ///
+    /// Made like the `alt` of images: markers of attention and code are
+    /// dropped, the text of links and images is kept, and character
+    /// references are decoded.
+    pub text: String,
+    /// Position of the whole heading, markers included.
+    pub position: Position,
+}
+
+/// Get the headings (ATX and setext) from events, in document order.
+///
+/// Headings in containers (block quotes, lists, footnote definitions) are
+/// included.
+pub fn from_events(events: &[Event], bytes: &[u8]) -> Vec<TocEntry> {
+    let mut headings = vec![];
+    let mut ranges = vec![];
+    let mut index = 0;
+    let mut enter = 0;
+    let mut depth = 0;
+    let mut range = None;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        match (&event.kind, &event.name) {
+            (Kind::Enter, Name::HeadingAtx | Name::HeadingSetext) => {
+                enter = index;
+                depth = 0;
+                range = None;
+            }
+            (Kind::Exit, Name::HeadingAtxSequence) if depth == 0 => {
+                #[allow(clippy::cast_possible_truncation)]
+                let size = (event.point.index - events[index - 1].point.index) as u8;
+                depth = size;
+            }
+            (Kind::Exit, Name::HeadingSetextUnderlineSequence) => {
+                depth = if bytes[events[index - 1].point.index] == b'-' {
+                    2
+                } else {
+                    1
+                };
+            }
+            (Kind::Enter, Name::HeadingAtxText | Name::HeadingSetextText) => {
+                range = Some((index + 1, index + 1));
+            }
+            (Kind::Exit, Name::HeadingAtxText | Name::HeadingSetextText) => {
+                range = range.map(|d: (usize, usize)| (d.0, index));
+            }
+            (Kind::Exit, Name::HeadingAtx | Name::HeadingSetext) => {
+                headings.push((
+                    depth,
+                    Position {
+                        start: events[enter].point.to_unist(),
+                        end: event.point.to_unist(),
+                    },
+                ));
+                ranges.push(range.unwrap_or((index, index)));
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    let texts = compile_text(events, bytes, &ranges);
+
+    headings
+        .into_iter()
+        .zip(texts)
+        .map(|((depth, position), text)| TocEntry {
+            depth,
+            text,
+            position,
+        })
+        .collect()
+}
@@ -0,0 +1,368 @@
+//! Turn a syntax tree into JSON in the shape of `mdast-util-from-markdown`.
+//!
+//! Fields are in the order that `mdast-util-from-markdown` (and the
+//! `mdast-util-gfm-*`, `mdast-util-math`, `mdast-util-frontmatter`, and
+//! `mdast-util-mdx-*` extensions) adds them, and JSON is written like
+//! `JSON.stringify` does.
+//! That includes `position`, which is added when a node is entered: before
+//! `label`, `identifier`, and `referenceType` on references, and not at all on
+//! expression attributes.
+//! Fields that only exist in markdown-rs (such as `raw`, `markers`, `kind`,
+//! and stops) are not written.
+
+use crate::mdast::{AlignKind, AttributeContent, AttributeValue, Node, ReferenceKind};
+use crate::unist::{Point, Position};
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Serialize a syntax tree.
+pub fn serialize(tree: &Node) -> String {
+    let mut buffer = String::new();
+    node(&mut buffer, tree);
+    buffer
+}
+
+/// Serialize a node.
+fn node(buffer: &mut String, node: &Node) {
+    match node {
+        Node::Root(x) => {
+            open(buffer, "root");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::Blockquote(x) => {
+            open(buffer, "blockquote");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::FootnoteDefinition(x) => {
+            open(buffer, "footnoteDefinition");
+            field_string(buffer, "identifier", &x.identifier);
+            field_string_opt(buffer, "label", x.label.as_deref());
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::MdxJsxFlowElement(x) => {
+            open(buffer, "mdxJsxFlowElement");
+            field_string_opt(buffer, "name", x.name.as_deref());
+            attributes(buffer, &x.attributes);
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::MdxJsxTextElement(x) => {
+            open(buffer, "mdxJsxTextElement");
+            field_string_opt(buffer, "name", x.name.as_deref());
+            attributes(buffer, &x.attributes);
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::List(x) => {
+            open(buffer, "list");
+            field(buffer, "ordered");
+            boolean(buffer, x.ordered);
+            field(buffer, "start");
+            match x.start {
+                Some(start) => number(buffer, start as usize),
+                None => buffer.push_str("null"),
+            }
+            field(buffer, "spread");
+            boolean(buffer, x.spread);
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::ListItem(x) => {
+            open(buffer, "listItem");
+            field(buffer, "spread");
+            boolean(buffer, x.spread);
+            field(buffer, "checked");
+            match x.checked {
+                Some(checked) => boolean(buffer, checked),
+                None => buffer.push_str("null"),
+            }
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::MdxjsEsm(x) => literal(buffer, "mdxjsEsm", &x.value, x.position.as_ref()),
+        Node::Toml(x) => literal(buffer, "toml", &x.value, x.position.as_ref()),
+        Node::Yaml(x) => literal(buffer, "yaml", &x.value, x.position.as_ref()),
+        Node::Break(x) => {
+            open(buffer, "break");
+            position(buffer, x.position.as_ref());
+        }
+        Node::InlineCode(x) => literal(buffer, "inlineCode", &x.value, x.position.as_ref()),
+        Node::InlineMath(x) => literal(buffer, "inlineMath", &x.value, x.position.as_ref()),
+        Node::Delete(x) => {
+            open(buffer, "delete");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::Emphasis(x) => {
+            open(buffer, "emphasis");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::MdxTextExpression(x) => {
+            literal(buffer, "mdxTextExpression", &x.value, x.position.as_ref());
+        }
+        Node::FootnoteReference(x) => {
+            open(buffer, "footnoteReference");
+            field_string(buffer, "identifier", &x.identifier);
+            field_string_opt(buffer, "label", x.label.as_deref());
+            position(buffer, x.position.as_ref());
+        }
+        Node::Html(x) => literal(buffer, "html", &x.value, x.position.as_ref()),
+        Node::Image(x) => {
+            open(buffer, "image");
+            field_string_opt(buffer, "title", x.title.as_deref());
+            field_string(buffer, "url", &x.url);
+            field_string(buffer, "alt", &x.alt);
+            position(buffer, x.position.as_ref());
+        }
+        Node::ImageReference(x) => {
+            open(buffer, "imageReference");
+            field_string(buffer, "alt", &x.alt);
+            position(buffer, x.position.as_ref());
+            field_string_opt(buffer, "label", x.label.as_deref());
+            field_string(buffer, "identifier", &x.identifier);
+            reference_kind(buffer, x.reference_kind);
+        }
+        Node::Link(x) => {
+            open(buffer, "link");
+            field_string_opt(buffer, "title", x.title.as_deref());
+            field_string(buffer, "url", &x.url);
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::LinkReference(x) => {
+            open(buffer, "linkReference");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+            field_string_opt(buffer, "label", x.label.as_deref());
+            field_string(buffer, "identifier", &x.identifier);
+            reference_kind(buffer, x.reference_kind);
+        }
+        Node::Strong(x) => {
+            open(buffer, "strong");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::Text(x) => literal(buffer, "text", &x.value, x.position.as_ref()),
+        Node::Code(x) => {
+            open(buffer, "code");
+            field_string_opt(buffer, "lang", x.lang.as_deref());
+            field_string_opt(buffer, "meta", x.meta.as_deref());
+            field_string(buffer, "value", &x.value);
+            position(buffer, x.position.as_ref());
+        }
+        Node::Math(x) => {
+            open(buffer, "math");
+            field_string_opt(buffer, "meta", x.meta.as_deref());
+            field_string(buffer, "value", &x.value);
+            position(buffer, x.position.as_ref());
+        }
+        Node::MdxFlowExpression(x) => {
+            literal(buffer, "mdxFlowExpression", &x.value, x.position.as_ref());
+        }
+        Node::Heading(x) => {
+            open(buffer, "heading");
+            field(buffer, "depth");
+            number(buffer, x.depth.into());
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::Table(x) => {
+            open(buffer, "table");
+            field(buffer, "align");
+            buffer.push('[');
+            for (index, align) in x.align.iter().enumerate() {
+                if index > 0 {
+                    buffer.push(',');
+                }
+                buffer.push_str(match align {
+                    AlignKind::Left => "\"left\"",
+                    AlignKind::Right => "\"right\"",
+                    AlignKind::Center => "\"center\"",
+                    AlignKind::None => "null",
+                });
+            }
+            buffer.push(']');
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::ThematicBreak(x) => {
+            open(buffer, "thematicBreak");
+            position(buffer, x.position.as_ref());
+        }
+        Node::TableRow(x) => {
+            open(buffer, "tableRow");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::TableCell(x) => {
+            open(buffer, "tableCell");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+        Node::Definition(x) => {
+            open(buffer, "definition");
+            field_string(buffer, "identifier", &x.identifier);
+            field_string_opt(buffer, "label", x.label.as_deref());
+            field_string_opt(buffer, "title", x.title.as_deref());
+            field_string(buffer, "url", &x.url);
+            position(buffer, x.position.as_ref());
+        }
+        Node::Paragraph(x) => {
+            open(buffer, "paragraph");
+            children(buffer, &x.children);
+            position(buffer, x.position.as_ref());
+        }
+    }
+
+    buffer.push('}');
+}
+
+/// Serialize a node with just a value.
+fn literal(buffer: &mut String, kind: &str, value: &str, at: Option<&Position>) {
+    open(buffer, kind);
+    field_string(buffer, "value", value);
+    position(buffer, at);
+}
+
+/// Serialize the start of an object, with its type.
+fn open(buffer: &mut String, kind: &str) {
+    buffer.push_str("{\"type\":");
+    string(buffer, kind);
+}
+
+/// Serialize the key of a field (not the first).
+fn field(buffer: &mut String, key: &str) {
+    buffer.push(',');
+    string(buffer, key);
+    buffer.push(':');
+}
+
+/// Serialize a string field.
+fn field_string(buffer: &mut String, key: &str, value: &str) {
+    field(buffer, key);
+    string(buffer, value);
+}
+
+/// Serialize a string field that is `null` when missing.
+fn field_string_opt(buffer: &mut String, key: &str, value: Option<&str>) {
+    field(buffer, key);
+    match value {
+        Some(value) => string(buffer, value),
+        None => buffer.push_str("null"),
+    }
+}
+
+/// Serialize children.
+fn children(buffer: &mut String, children: &[Node]) {
+    field(buffer, "children");
+    buffer.push('[');
+    for (index, child) in children.iter().enumerate() {
+        if index > 0 {
+            buffer.push(',');
+        }
+        node(buffer, child);
+    }
+    buffer.push(']');
+}
+
+/// Serialize the kind of a reference.
+fn reference_kind(buffer: &mut String, kind: ReferenceKind) {
+    field_string(
+        buffer,
+        "referenceType",
+        match kind {
+            ReferenceKind::Shortcut => "shortcut",
+            ReferenceKind::Collapsed => "collapsed",
+            ReferenceKind::Full => "full",
+        },
+    );
+}
+
+/// Serialize MDX JSX attributes.
+fn attributes(buffer: &mut String, attributes: &[AttributeContent]) {
+    field(buffer, "attributes");
+    buffer.push('[');
+    for (index, attribute) in attributes.iter().enumerate() {
+        if index > 0 {
+            buffer.push(',');
+        }
+        match attribute {
+            // Expression attributes do not get positions in JS.
+            AttributeContent::Expression(x) => {
+                literal(buffer, "mdxJsxExpressionAttribute", &x.value, None);
+            }
+            AttributeContent::Property(x) => {
+                open(buffer, "mdxJsxAttribute");
+                field_string(buffer, "name", &x.name);
+                field(buffer, "value");
+                match &x.value {
+                    Some(AttributeValue::Literal(value)) => string(buffer, value),
+                    Some(AttributeValue::Expression(value)) => {
+                        literal(buffer, "mdxJsxAttributeValueExpression", &value.value, None);
+                        buffer.push('}');
+                    }
+                    None => buffer.push_str("null"),
+                }
+                position(buffer, x.position.as_ref());
+            }
+        }
+        buffer.push('}');
+    }
+    buffer.push(']');
+}
+
+/// Serialize a position, if there is one.
+fn position(buffer: &mut String, position: Option<&Position>) {
+    if let Some(position) = position {
+        field(buffer, "position");
+        buffer.push_str("{\"start\":");
+        point(buffer, &position.start);
+        buffer.push_str(",\"end\":");
+        point(buffer, &position.end);
+        buffer.push('}');
+    }
+}
+
+/// Serialize a point.
+fn point(buffer: &mut String, point: &Point) {
+    buffer.push_str("{\"line\":");
+    number(buffer, point.line);
+    buffer.push_str(",\"column\":");
+    number(buffer, point.column);
+    buffer.push_str(",\"offset\":");
+    number(buffer, point.offset);
+    buffer.push('}');
+}
+
+/// Serialize a boolean.
+fn boolean(buffer: &mut String, value: bool) {
+    buffer.push_str(if value { "true" } else { "false" });
+}
+
+/// Serialize a number.
+fn number(buffer: &mut String, value: usize) {
+    write!(buffer, "{}", value).unwrap();
+}
+
+/// Serialize a string, escaped like `JSON.stringify` does.
+fn string(buffer: &mut String, value: &str) {
+    buffer.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => buffer.push_str("\\\""),
+            '\\' => buffer.push_str("\\\\"),
+            '\u{8}' => buffer.push_str("\\b"),
+            '\u{c}' => buffer.push_str("\\f"),
+            '\n' => buffer.push_str("\\n"),
+            '\r' => buffer.push_str("\\r"),
+            '\t' => buffer.push_str("\\t"),
+            '\0'..='\u{1f}' => write!(buffer, "\\u{:04x}", char as u32).unwrap(),
+            _ => buffer.push(char),
+        }
+    }
+    buffer.push('"');
+}
@@ -0,0 +1,526 @@
+//! Check that events can be compiled.
+
+use crate::event::{Event, Kind, Name, VOID_EVENTS};
+use crate::message::{self, rules};
+use crate::util::{character_reference, list_value, normalize_identifier::normalize_identifier};
+use alloc::{boxed::Box, format, string::String, vec, vec::Vec};
+
+/// Check that `events` are valid for `value`.
+///
+/// Events must be balanced: each exit must close the last entered event.
+/// Void events must be empty: their exit must directly follow their enter.
+/// Points must refer to character boundaries in `value`, and exits must not
+/// be before their enters.
+///
+/// Constructs must be directly in the constructs they belong to (such as a
+/// [`Resource`][Name::Resource] in a [`Link`][Name::Link]), must have the
+/// events in them that compilers use (such as a [`Label`][Name::Label] in a
+/// link), in order, and at most once when compilers use only one; and code,
+/// definitions, headings, and tables cannot be nested.
+/// Values of character references and list items must be valid, and
+/// references and GFM footnote calls must be defined.
+///
+/// Does not check links between events, which compilers do not use.
+pub fn validate(events: &[Event], value: &str) -> Result<(), message::Message> {
+    pair(events)?;
+
+    let mut stack: Vec<Open> = vec![];
+    let mut definitions: Vec<String> = vec![];
+    let mut gfm_footnote_definitions: Vec<String> = vec![];
+    let mut references: Vec<(usize, String)> = vec![];
+    let mut gfm_footnote_calls: Vec<(usize, String)> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+        let point = &event.point;
+
+        if point.index > value.len() || !value.is_char_boundary(point.index) {
+            return Err(error(
+                event,
+                format!(
+                    "Unexpected point `{}` of `{:?}`, expected a character boundary in the value",
+                    point.index, event.name
+                ),
+                rules::INVALID_POINT,
+            ));
+        }
+
+        if event.kind == Kind::Enter {
+            if VOID_EVENTS.contains(&event.name) {
+                let next = events.get(index + 1);
+
+                if !matches!(next, Some(next) if next.kind == Kind::Exit && next.name == event.name)
+                {
+                    return Err(error(
+                        event,
+                        format!(
+                            "Unexpected content in `{:?}`, expected void event to be exited directly",
+                            event.name
+                        ),
+                        rules::INVALID_VOID,
+                    ));
+                }
+            }
+
+            let parents = parents(&event.name);
+
+            if !parents.is_empty()
+                && !matches!(stack.last(), Some(open) if parents.contains(open.name))
+            {
+                return Err(error(
+                    event,
+                    format!(
+                        "Unexpected `{:?}`, expected it directly in {}",
+                        event.name,
+                        names(parents)
+                    ),
+                    rules::INVALID_STRUCTURE,
+                ));
+            }
+
+            let excluded = excluded(&event.name);
+
+            if let Some(open) = stack.iter().find(|open| excluded.contains(open.name)) {
+                return Err(error(
+                    event,
+                    format!(
+                        "Unexpected `{:?}` in `{:?}`, expected it outside of {}",
+                        event.name,
+                        open.name,
+                        names(excluded)
+                    ),
+                    rules::INVALID_STRUCTURE,
+                ));
+            }
+
+            if let Some(parent) = stack.last_mut() {
+                if unique(&event.name) {
+                    if parent.unique.contains(&&event.name) {
+                        return Err(error(
+                            event,
+                            format!(
+                                "Unexpected second `{:?}` in `{:?}`, expected one",
+                                event.name, parent.name
+                            ),
+                            rules::INVALID_STRUCTURE,
+                        ));
+                    }
+
+                    parent.unique.push(&event.name);
+                }
+
+                if children(parent.name).get(parent.children) == Some(&event.name) {
+                    parent.children += 1;
+                }
+            }
+
+            stack.push(Open {
+                name: &event.name,
+                enter: index,
+                children: 0,
+                unique: vec![],
+            });
+        } else if let Some(open) = stack.pop() {
+            let start = events[open.enter].point.index;
+
+            if point.index < start {
+                return Err(error(
+                    event,
+                    format!(
+                        "Unexpected point `{}` of `{:?}`, expected an exit to not be before its enter (at `{}`)",
+                        point.index, event.name, start
+                    ),
+                    rules::INVALID_POINT,
+                ));
+            }
+
+            if let Some(child) = children(open.name).get(open.children) {
+                return Err(error(
+                    event,
+                    format!(
+                        "Unexpected end of `{:?}`, expected a `{:?}` in it",
+                        event.name, child
+                    ),
+                    rules::INVALID_STRUCTURE,
+                ));
+            }
+
+            let slice = &value[start..point.index];
+
+            match event.name {
+                Name::CharacterReferenceValue
+                    if !character_reference_valid(
+                        slice,
+                        character_reference_marker(events, open.enter),
+                    ) =>
+                {
+                    return Err(error(
+                        event,
+                        format!(
+                            "Unexpected character reference value `{}`, expected a valid value",
+                            slice
+                        ),
+                        rules::INVALID_STRUCTURE,
+                    ));
+                }
+                Name::ListItemValue
+                    if list_value::number(list_value::kind(slice), slice).is_none() =>
+                {
+                    return Err(error(
+                        event,
+                        format!(
+                            "Unexpected list item value `{}`, expected a valid value",
+                            slice
+                        ),
+                        rules::INVALID_STRUCTURE,
+                    ));
+                }
+                Name::DefinitionLabelString => {
+                    definitions.push(normalize_identifier(slice).into_owned());
+                }
+                Name::GfmFootnoteDefinitionLabelString => {
+                    gfm_footnote_definitions.push(normalize_identifier(slice).into_owned());
+                }
+                Name::Image | Name::Link => {
+                    if let Some(id) = reference_id(events, open.enter, index, value) {
+                        references.push((index, id));
+                    }
+                }
+                Name::GfmFootnoteCall => {
+                    let id = reference_id(events, open.enter, index, value);
+                    gfm_footnote_calls.push((index, id.unwrap_or_default()));
+                }
+                _ => {}
+            }
+        }
+
+        index += 1;
+    }
+
+    for (index, id) in references {
+        if !definitions.contains(&id) {
+            return Err(undefined(&events[index], &id));
+        }
+    }
+
+    for (index, id) in gfm_footnote_calls {
+        if !gfm_footnote_definitions.contains(&id) {
+            return Err(undefined(&events[index], &id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Pair the enters and exits in `events`.
+///
+/// Returns, for each event, the index of the other event in its pair: the
+/// exit for enters, the enter for exits.
+///
+/// ## Errors
+///
+/// Errors when events are not balanced: each exit must close the last
+/// entered event, and all entered events must be exited.
+pub fn pair(events: &[Event]) -> Result<Vec<usize>, message::Message> {
+    let mut pairs = vec![0; events.len()];
+    let mut stack: Vec<usize> = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            stack.push(index);
+        } else {
+            match stack.pop() {
+                Some(enter) if events[enter].name == event.name => {
+                    pairs[enter] = index;
+                    pairs[index] = enter;
+                }
+                _ => {
+                    return Err(error(
+                        event,
+                        format!(
+                            "Unexpected exit of `{:?}`, expected an exit of the last entered event",
+                            event.name
+                        ),
+                        rules::UNBALANCED_EVENTS,
+                    ));
+                }
+            }
+        }
+
+        index += 1;
+    }
+
+    if let Some(enter) = stack.pop() {
+        return Err(message::Message {
+            place: None,
+            reason: format!(
+                "Unexpected end of events, expected an exit of `{:?}`",
+                events[enter].name
+            ),
+            rule_id: Box::new(rules::UNBALANCED_EVENTS.into()),
+            source: Box::new(rules::SOURCE.into()),
+        });
+    }
+
+    Ok(pairs)
+}
+
+/// Entered event.
+struct Open<'a> {
+    /// Name.
+    name: &'a Name,
+    /// Index of the enter event.
+    enter: usize,
+    /// Number of required children seen.
+    children: usize,
+    /// Children seen that can occur once.
+    unique: Vec<&'a Name>,
+}
+
+/// Get the constructs that `name` must be directly in, if any.
+///
+/// Compilers keep the state that `name` changes in these constructs.
+fn parents(name: &Name) -> &'static [Name] {
+    match name {
+        Name::CharacterReferenceMarker
+        | Name::CharacterReferenceMarkerHexadecimal
+        | Name::CharacterReferenceMarkerNumeric
+        | Name::CharacterReferenceValue => &[Name::CharacterReference],
+        Name::CodeFencedFence => &[Name::CodeFenced],
+        Name::CodeFencedFenceInfo | Name::CodeFencedFenceMeta => &[Name::CodeFencedFence],
+        Name::CodeFlowChunk => &[Name::CodeFenced, Name::CodeIndented],
+        Name::DefinitionDestination | Name::DefinitionLabel | Name::DefinitionTitle => {
+            &[Name::Definition]
+        }
+        Name::DefinitionDestinationLiteral | Name::DefinitionDestinationRaw => {
+            &[Name::DefinitionDestination]
+        }
+        Name::DefinitionDestinationString => &[
+            Name::DefinitionDestinationLiteral,
+            Name::DefinitionDestinationRaw,
+        ],
+        Name::DefinitionLabelString => &[Name::DefinitionLabel],
+        Name::DefinitionTitleString => &[Name::DefinitionTitle],
+        Name::GfmFootnoteDefinitionPrefix => &[Name::GfmFootnoteDefinition],
+        Name::GfmFootnoteDefinitionLabel => &[Name::GfmFootnoteDefinitionPrefix],
+        Name::GfmFootnoteDefinitionLabelString => &[Name::GfmFootnoteDefinitionLabel],
+        Name::GfmTableBody | Name::GfmTableHead => &[Name::GfmTable],
+        Name::GfmTableCell => &[Name::GfmTableRow],
+        Name::GfmTableDelimiterRow => &[Name::GfmTableHead],
+        Name::GfmTableRow => &[Name::GfmTableBody, Name::GfmTableHead],
+        Name::HeadingAtxSequence | Name::HeadingAtxText => &[Name::HeadingAtx],
+        Name::HeadingSetextText | Name::HeadingSetextUnderline => &[Name::HeadingSetext],
+        Name::HeadingSetextUnderlineSequence => &[Name::HeadingSetextUnderline],
+        Name::Label => &[
+            Name::FootnoteInline,
+            Name::GfmFootnoteCall,
+            Name::Image,
+            Name::Link,
+        ],
+        Name::LabelText => &[Name::Label],
+        Name::ListItem => &[Name::ListOrdered, Name::ListUnordered],
+        Name::ListItemPrefix => &[Name::ListItem],
+        Name::ListItemMarker | Name::ListItemValue => &[Name::ListItemPrefix],
+        Name::MathFlowChunk | Name::MathFlowFence => &[Name::MathFlow],
+        Name::MathFlowFenceMeta => &[Name::MathFlowFence],
+        Name::Reference | Name::Resource => &[Name::Image, Name::Link],
+        Name::ReferenceString => &[Name::Reference],
+        Name::ResourceDestination | Name::ResourceTitle => &[Name::Resource],
+        Name::ResourceDestinationLiteral | Name::ResourceDestinationRaw => {
+            &[Name::ResourceDestination]
+        }
+        Name::ResourceDestinationString => &[
+            Name::ResourceDestinationLiteral,
+            Name::ResourceDestinationRaw,
+        ],
+        Name::ResourceTitleString => &[Name::ResourceTitle],
+        _ => &[],
+    }
+}
+
+/// Get the constructs that `name` must not be in, at any depth.
+///
+/// Compilers keep the state of these constructs in one place, so they cannot
+/// be nested.
+fn excluded(name: &Name) -> &'static [Name] {
+    match name {
+        Name::CodeFenced | Name::CodeIndented | Name::MathFlow => {
+            &[Name::CodeFenced, Name::CodeIndented, Name::MathFlow]
+        }
+        Name::Definition => &[Name::Definition],
+        Name::GfmTable => &[Name::GfmTable],
+        Name::HeadingAtx | Name::HeadingSetext => &[Name::HeadingAtx, Name::HeadingSetext],
+        _ => &[],
+    }
+}
+
+/// Check whether `name` can occur only once in its parent.
+fn unique(name: &Name) -> bool {
+    matches!(
+        name,
+        Name::CharacterReferenceValue
+            | Name::DefinitionDestination
+            | Name::DefinitionDestinationString
+            | Name::DefinitionLabel
+            | Name::DefinitionLabelString
+            | Name::DefinitionTitle
+            | Name::DefinitionTitleString
+            | Name::GfmFootnoteDefinitionLabel
+            | Name::GfmFootnoteDefinitionLabelString
+            | Name::GfmFootnoteDefinitionPrefix
+            | Name::HeadingSetextText
+            | Name::HeadingSetextUnderline
+            | Name::HeadingSetextUnderlineSequence
+            | Name::Label
+            | Name::LabelText
+            | Name::ListItemPrefix
+            | Name::ListItemValue
+            | Name::Reference
+            | Name::ReferenceString
+            | Name::Resource
+            | Name::ResourceDestination
+            | Name::ResourceDestinationString
+            | Name::ResourceTitle
+            | Name::ResourceTitleString
+    )
+}
+
+/// Get the constructs that must be directly in `name`, in order.
+fn children(name: &Name) -> &'static [Name] {
+    match name {
+        Name::CharacterReference => &[
+            Name::CharacterReferenceMarker,
+            Name::CharacterReferenceValue,
+        ],
+        Name::CodeFenced => &[Name::CodeFencedFence],
+        Name::GfmTable => &[Name::GfmTableHead],
+        Name::GfmTableHead => &[Name::GfmTableRow, Name::GfmTableDelimiterRow],
+        Name::ListItem => &[Name::ListItemPrefix],
+        Name::ListItemPrefix => &[Name::ListItemMarker],
+        Name::Definition => &[Name::DefinitionLabel],
+        Name::DefinitionLabel => &[Name::DefinitionLabelString],
+        Name::FootnoteInline | Name::GfmFootnoteCall | Name::Image | Name::Link => &[Name::Label],
+        Name::Frontmatter => &[Name::FrontmatterFence, Name::FrontmatterFence],
+        Name::GfmFootnoteDefinition => &[Name::GfmFootnoteDefinitionPrefix],
+        Name::GfmFootnoteDefinitionPrefix => &[Name::GfmFootnoteDefinitionLabel],
+        Name::GfmFootnoteDefinitionLabel => &[Name::GfmFootnoteDefinitionLabelString],
+        Name::HeadingAtx => &[Name::HeadingAtxSequence],
+        Name::HeadingSetext => &[Name::HeadingSetextText, Name::HeadingSetextUnderline],
+        Name::HeadingSetextUnderline => &[Name::HeadingSetextUnderlineSequence],
+        Name::Label => &[Name::LabelText],
+        Name::MathFlow => &[Name::MathFlowFence],
+        _ => &[],
+    }
+}
+
+/// Get the marker of the character reference value entered at `index`: the
+/// last marker before it.
+fn character_reference_marker(events: &[Event], mut index: usize) -> u8 {
+    while index > 0 {
+        index -= 1;
+
+        match events[index].name {
+            Name::CharacterReferenceMarkerHexadecimal => return b'x',
+            Name::CharacterReferenceMarkerNumeric => return b'#',
+            Name::CharacterReferenceMarker | Name::CharacterReference => break,
+            _ => {}
+        }
+    }
+
+    b'&'
+}
+
+/// Check whether `value` is a valid value of a character reference.
+fn character_reference_valid(value: &str, marker: u8) -> bool {
+    !value.is_empty()
+        && value.len() <= character_reference::value_max(marker)
+        && value
+            .as_bytes()
+            .iter()
+            .all(character_reference::value_test(marker))
+        && (marker != b'&' || character_reference::decode_named(value, true).is_some())
+}
+
+/// Get the identifier of the media or GFM footnote call from `enter` to
+/// `exit`.
+///
+/// Like compilers, uses the reference string, or otherwise the label text.
+/// Media with a resource do not have an identifier.
+fn reference_id(events: &[Event], enter: usize, exit: usize, value: &str) -> Option<String> {
+    let mut parents: Vec<&Name> = vec![];
+    let mut label = None;
+    let mut reference = None;
+    let mut index = enter + 1;
+
+    while index < exit {
+        let event = &events[index];
+
+        if event.kind == Kind::Enter {
+            match (parents.last(), &event.name) {
+                (None, Name::Resource) => return None,
+                (Some(Name::Label), Name::LabelText) => label = Some(index),
+                (Some(Name::Reference), Name::ReferenceString) => reference = Some(index),
+                _ => {}
+            }
+
+            parents.push(&event.name);
+        } else {
+            parents.pop();
+        }
+
+        index += 1;
+    }
+
+    reference.or(label).map(|index| {
+        let start = events[index].point.index;
+        let mut end = index + 1;
+        let mut depth = 0;
+
+        // Find the exit.
+        while events[end].kind == Kind::Enter || depth > 0 {
+            if events[end].kind == Kind::Enter {
+                depth += 1;
+            } else {
+                depth -= 1;
+            }
+            end += 1;
+        }
+
+        normalize_identifier(&value[start..events[end].point.index]).into_owned()
+    })
+}
+
+/// Create a list of names.
+fn names(names: &[Name]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{:?}`", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Create an error for an undefined reference or GFM footnote call.
+fn undefined(event: &Event, id: &str) -> message::Message {
+    error(
+        event,
+        format!(
+            "Unexpected `{:?}` to `{}`, expected a definition with that identifier",
+            event.name, id
+        ),
+        rules::INVALID_STRUCTURE,
+    )
+}
+
+/// Create an error at `event`.
+fn error(event: &Event, reason: String, rule_id: &str) -> message::Message {
+    message::Message {
+        place: Some(Box::new(message::Place::Point(event.point.to_unist()))),
+        reason,
+        rule_id: Box::new(rule_id.into()),
+        source: Box::new(rules::SOURCE.into()),
+    }
+}
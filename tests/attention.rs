@@ -844,37 +844,106 @@ fn attention() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::Emphasis(Emphasis {
                         children: vec![Node::Text(Text {
                             value: "alpha".into(),
-                            position: Some(Position::new(1, 4, 3, 1, 9, 8))
+                            position: Some(Position::new(1, 4, 3, 1, 9, 8)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 3, 2, 1, 10, 9))
+                        position: Some(Position::new(1, 3, 2, 1, 10, 9)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b ".into(),
-                        position: Some(Position::new(1, 10, 9, 1, 13, 12))
+                        position: Some(Position::new(1, 10, 9, 1, 13, 12)),
+                        raw: None,
                     }),
                     Node::Strong(Strong {
                         children: vec![Node::Text(Text {
                             value: "bravo".into(),
-                            position: Some(Position::new(1, 15, 14, 1, 20, 19))
+                            position: Some(Position::new(1, 15, 14, 1, 20, 19)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 13, 12, 1, 22, 21))
+                        position: Some(Position::new(1, 13, 12, 1, 22, 21)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " c.".into(),
-                        position: Some(Position::new(1, 22, 21, 1, 25, 24))
+                        position: Some(Position::new(1, 22, 21, 1, 25, 24)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 25, 24))
+                position: Some(Position::new(1, 1, 0, 1, 25, 24)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 25, 24))
+            position: Some(Position::new(1, 1, 0, 1, 25, 24)),
+            raw: None,
         }),
         "should support attention as `Emphasis`, `Strong`s in mdast"
     );
 
     Ok(())
 }
+
+#[test]
+fn attention_relaxed_underscore() -> Result<(), message::Message> {
+    let relaxed = Options {
+        parse: ParseOptions {
+            relaxed_underscore: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("Pass *_internal_ to f(_x_)."),
+        "<p>Pass *<em>internal</em> to f(<em>x</em>).</p>",
+        "should support underscore attention next to punctuation by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("Pass *_internal_ to f(_x_).", &relaxed)?,
+        "<p>Pass *_internal_ to f(_x_).</p>",
+        "should not support underscore attention next to punctuation w/ `relaxed_underscore`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "Call __init__() and _private(), compare SOME_CONSTANT_NAME to OTHER_NAME, then run (_setup_).",
+            &relaxed
+        )?,
+        "<p>Call __init__() and _private(), compare SOME_CONSTANT_NAME to OTHER_NAME, then run (_setup_).</p>",
+        "should not support underscore attention in identifier-heavy prose w/ `relaxed_underscore`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a _b_ __c__ ___d___ e", &relaxed)?,
+        "<p>a <em>b</em> <strong>c</strong> <em><strong>d</strong></em> e</p>",
+        "should support underscore attention surrounded by whitespace w/ `relaxed_underscore`"
+    );
+
+    assert_eq!(
+        to_html_with_options("_a_\n_b c_", &relaxed)?,
+        "<p><em>a</em>\n<em>b c</em></p>",
+        "should support underscore attention at the start and end of lines w/ `relaxed_underscore`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a _b_, c", &relaxed)?,
+        "<p>a _b_, c</p>",
+        "should not support underscore attention followed by punctuation w/ `relaxed_underscore`"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a* (*b*) *_c_*", &relaxed)?,
+        "<p><em>a</em> (<em>b</em>) <em>_c_</em></p>",
+        "should not affect asterisks w/ `relaxed_underscore`"
+    );
+
+    Ok(())
+}
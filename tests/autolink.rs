@@ -83,6 +83,36 @@ fn autolink() -> Result<(), message::Message> {
         "should not support protocol autolinks w/ spaces"
     );
 
+    assert_eq!(
+        to_html("<http://[2001:db8::1]:8080/path>"),
+        "<p><a href=\"http://%5B2001:db8::1%5D:8080/path\">http://[2001:db8::1]:8080/path</a></p>",
+        "should support protocol autolinks w/ IPv6 hosts"
+    );
+
+    assert_eq!(
+        to_html("<https://[::1]>"),
+        "<p><a href=\"https://%5B::1%5D\">https://[::1]</a></p>",
+        "should support protocol autolinks w/ short IPv6 hosts"
+    );
+
+    assert_eq!(
+        to_html("<http://a.b/c^d`e>"),
+        "<p><a href=\"http://a.b/c%5Ed%60e\">http://a.b/c^d`e</a></p>",
+        "should support protocol autolinks w/ carets and grave accents"
+    );
+
+    assert_eq!(
+        to_html("<http://[2001:db8::1] /path>"),
+        "<p>&lt;http://[2001:db8::1] /path&gt;</p>",
+        "should not support protocol autolinks w/ IPv6 hosts and spaces"
+    );
+
+    assert_eq!(
+        to_html("<http://[::1]\t>"),
+        "<p>&lt;http://[::1]\t&gt;</p>",
+        "should not support protocol autolinks w/ tabs"
+    );
+
     assert_eq!(
         to_html("<http://example.com/\\[\\>"),
         "<p><a href=\"http://example.com/%5C%5B%5C\">http://example.com/\\[\\</a></p>",
@@ -285,38 +315,47 @@ fn autolink() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "https://alpha.com".into(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "https://alpha.com".into(),
-                            position: Some(Position::new(1, 4, 3, 1, 21, 20))
+                            position: Some(Position::new(1, 4, 3, 1, 21, 20)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 3, 2, 1, 22, 21))
+                        position: Some(Position::new(1, 3, 2, 1, 22, 21)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b ".into(),
-                        position: Some(Position::new(1, 22, 21, 1, 25, 24))
+                        position: Some(Position::new(1, 22, 21, 1, 25, 24)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "mailto:bravo@charlie.com".into(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "bravo@charlie.com".into(),
-                            position: Some(Position::new(1, 26, 25, 1, 43, 42))
+                            position: Some(Position::new(1, 26, 25, 1, 43, 42)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 25, 24, 1, 44, 43))
+                        position: Some(Position::new(1, 25, 24, 1, 44, 43)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " c.".into(),
-                        position: Some(Position::new(1, 44, 43, 1, 47, 46))
+                        position: Some(Position::new(1, 44, 43, 1, 47, 46)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 47, 46))
+                position: Some(Position::new(1, 1, 0, 1, 47, 46)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 47, 46))
+            position: Some(Position::new(1, 1, 0, 1, 47, 46)),
+            raw: None,
         }),
         "should support autolinks as `Link`s in mdast"
     );
@@ -225,13 +225,17 @@ fn block_quote() -> Result<(), message::Message> {
                 children: vec![Node::Paragraph(Paragraph {
                     children: vec![Node::Text(Text {
                         value: "a".into(),
-                        position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                        position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                        raw: None,
                     }),],
-                    position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                    position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 4, 3))
+            position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+            raw: None,
         }),
         "should support block quotes as `BlockQuote`s in mdast"
     );
@@ -109,14 +109,61 @@ fn character_escape() -> Result<(), message::Message> {
             children: vec![Node::Paragraph(Paragraph {
                 children: vec![Node::Text(Text {
                     value: "a * b".into(),
-                    position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                    position: Some(Position::new(1, 1, 0, 1, 7, 6)),
+                    raw: None,
                 }),],
-                position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                position: Some(Position::new(1, 1, 0, 1, 7, 6)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 7, 6))
+            position: Some(Position::new(1, 1, 0, 1, 7, 6)),
+            raw: None,
         }),
         "should support character escapes as `Text`s in mdast"
     );
 
+    let context = Options {
+        parse: ParseOptions {
+            character_escape_requires_punctuation_context: true,
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html("C:\\Users\\name\\(x).md"),
+        "<p>C:\\Users\\name(x).md</p>",
+        "should support character escapes after alphanumerics by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("C:\\Users\\name\\(x).md", &context)?,
+        "<p>C:\\Users\\name\\(x).md</p>",
+        "should not support character escapes after alphanumerics w/ `character_escape_requires_punctuation_context`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\*b\\_c 1\\.", &context)?,
+        "<p>a\\*b\\_c 1\\.</p>",
+        "should not support character escapes after letters or digits w/ `character_escape_requires_punctuation_context`"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\*a* (\\*b*) c:\\(d)", &context)?,
+        "<p>*a* (*b*) c:(d)</p>",
+        "should support character escapes after whitespace and punctuation w/ `character_escape_requires_punctuation_context`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b\\.c \"d\\\"e\")", &context)?,
+        "<p><a href=\"b%5C.c\" title=\"d\\&quot;e\">a</a></p>",
+        "should not support character escapes after alphanumerics in strings w/ `character_escape_requires_punctuation_context`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\\nb", &context)?,
+        "<p>a<br />\nb</p>",
+        "should support hard break (escape) after alphanumerics w/ `character_escape_requires_punctuation_context`"
+    );
+
     Ok(())
 }
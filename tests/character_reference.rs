@@ -196,6 +196,36 @@ fn character_reference() -> Result<(), message::Message> {
         "should not support the other characters inside a hexademical"
     );
 
+    assert_eq!(
+        to_html("[a](b&#40;c&#41;d) [a](b\\(c\\)d)"),
+        "<p><a href=\"b(c)d\">a</a> <a href=\"b(c)d\">a</a></p>",
+        "should treat parens as character references in destinations like escaped parens"
+    );
+
+    assert_eq!(
+        to_html("[a](b&#32;c) [a](<b c>)"),
+        "<p><a href=\"b%20c\">a</a> <a href=\"b%20c\">a</a></p>",
+        "should encode spaces as character references in destinations like literal spaces"
+    );
+
+    assert_eq!(
+        to_html("[a](b&#91;c&#93;d) [a](b\\[c\\]d)"),
+        "<p><a href=\"b%5Bc%5Dd\">a</a> <a href=\"b%5Bc%5Dd\">a</a></p>",
+        "should encode brackets as character references in destinations like escaped brackets"
+    );
+
+    assert_eq!(
+        to_html("[a](b&eacute;c) [a](béc)"),
+        "<p><a href=\"b%C3%A9c\">a</a> <a href=\"b%C3%A9c\">a</a></p>",
+        "should encode non-ASCII character references in destinations like literal characters"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n[a]: b&#41;c"),
+        "<p><a href=\"b)c\">a</a></p>\n",
+        "should treat parens as character references in definition destinations like escaped parens"
+    );
+
     assert_eq!(
         to_html_with_options(
             "&amp;",
@@ -220,11 +250,14 @@ fn character_reference() -> Result<(), message::Message> {
             children: vec![Node::Paragraph(Paragraph {
                 children: vec![Node::Text(Text {
                     value: "\u{a0} & © Æ Ď\n¾ ℋ ⅆ\n∲ ≧̸\n# Ӓ Ϡ �\n\" ആ ಫ".into(),
-                    position: Some(Position::new(1, 1, 0, 5, 23, 158))
+                    position: Some(Position::new(1, 1, 0, 5, 23, 158)),
+                    raw: None,
                 }),],
-                position: Some(Position::new(1, 1, 0, 5, 23, 158))
+                position: Some(Position::new(1, 1, 0, 5, 23, 158)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 5, 23, 158))
+            position: Some(Position::new(1, 1, 0, 5, 23, 158)),
+            raw: None,
         }),
         "should support character references as `Text`s in mdast"
     );
@@ -6,260 +6,293 @@ use markdown::{
 };
 use pretty_assertions::assert_eq;
 
-#[test]
-fn code_fenced() -> Result<(), message::Message> {
-    assert_eq!(
-        to_html("```\n<\n >\n```"),
+/// Fixtures: a document with line feeds, the HTML it turns into, and what
+/// the fixture checks.
+///
+/// Each fixture is also checked with carriage returns and with carriage
+/// return + line feeds instead of line feeds.
+const CODE_FENCED: [(&str, &str, &str); 51] = [
+    (
+        "```\n<\n >\n```",
         "<pre><code>&lt;\n &gt;\n</code></pre>",
-        "should support fenced code w/ grave accents"
-    );
-
-    assert_eq!(
-        to_html("~~~\n<\n >\n~~~"),
+        "should support fenced code w/ grave accents",
+    ),
+    (
+        "~~~\n<\n >\n~~~",
         "<pre><code>&lt;\n &gt;\n</code></pre>",
-        "should support fenced code w/ tildes"
-    );
-
-    assert_eq!(
-        to_html("``\nfoo\n``"),
+        "should support fenced code w/ tildes",
+    ),
+    (
+        "``\nfoo\n``",
         "<p><code>foo</code></p>",
-        "should not support fenced code w/ less than three markers"
-    );
-
-    assert_eq!(
-        to_html("```\naaa\n~~~\n```"),
+        "should not support fenced code w/ less than three markers",
+    ),
+    (
+        "```\naaa\n~~~\n```",
         "<pre><code>aaa\n~~~\n</code></pre>",
-        "should not support a tilde closing sequence for a grave accent opening sequence"
-    );
-
-    assert_eq!(
-        to_html("~~~\naaa\n```\n~~~"),
+        "should not support a tilde closing sequence for a grave accent opening sequence",
+    ),
+    (
+        "~~~\naaa\n```\n~~~",
         "<pre><code>aaa\n```\n</code></pre>",
-        "should not support a grave accent closing sequence for a tilde opening sequence"
-    );
-
-    assert_eq!(
-        to_html("````\naaa\n```\n``````"),
+        "should not support a grave accent closing sequence for a tilde opening sequence",
+    ),
+    (
+        "````\naaa\n```\n``````",
         "<pre><code>aaa\n```\n</code></pre>",
-        "should support a closing sequence longer, but not shorter than, the opening"
-    );
-
-    assert_eq!(
-        to_html("~~~~\naaa\n~~~\n~~~~"),
+        "should support a closing sequence longer, but not shorter than, the opening",
+    ),
+    (
+        "~~~~\naaa\n~~~\n~~~~",
         "<pre><code>aaa\n~~~\n</code></pre>",
-        "should support a closing sequence equal to, but not shorter than, the opening"
-    );
-
-    assert_eq!(
-        to_html("```"),
+        "should support a closing sequence equal to, but not shorter than, the opening",
+    ),
+    (
+        "```",
         "<pre><code></code></pre>\n",
-        "should support an eof right after an opening sequence"
-    );
-
-    assert_eq!(
-        to_html("`````\n\n```\naaa\n"),
+        "should support an eof right after an opening sequence",
+    ),
+    (
+        "`````\n\n```\naaa\n",
         "<pre><code>\n```\naaa\n</code></pre>\n",
-        "should support an eof somewhere in content"
-    );
-
-    assert_eq!(
-        to_html("> ```\n> aaa\n\nbbb"),
+        "should support an eof somewhere in content",
+    ),
+    (
+        "> ```\n> aaa\n\nbbb",
         "<blockquote>\n<pre><code>aaa\n</code></pre>\n</blockquote>\n<p>bbb</p>",
-        "should support no closing sequence in a block quote"
-    );
-
-    assert_eq!(
-        to_html("```\n\n  \n```"),
+        "should support no closing sequence in a block quote",
+    ),
+    (
+        "```\n\n  \n```",
         "<pre><code>\n  \n</code></pre>",
-        "should support blank lines in fenced code"
-    );
-
-    assert_eq!(
-        to_html("```\n```"),
+        "should support blank lines in fenced code",
+    ),
+    (
+        "```\n```",
         "<pre><code></code></pre>",
-        "should support empty fenced code"
-    );
-
-    assert_eq!(
-      to_html(" ```\n aaa\naaa\n```"),
-      "<pre><code>aaa\naaa\n</code></pre>",
-      "should remove up to one space from the content if the opening sequence is indented w/ 1 space"
-    );
-
-    assert_eq!(
-      to_html("  ```\naaa\n  aaa\naaa\n  ```"),
-      "<pre><code>aaa\naaa\naaa\n</code></pre>",
-      "should remove up to two space from the content if the opening sequence is indented w/ 2 spaces"
-    );
-
-    assert_eq!(
-      to_html("   ```\n   aaa\n    aaa\n  aaa\n   ```"),
-      "<pre><code>aaa\n aaa\naaa\n</code></pre>",
-      "should remove up to three space from the content if the opening sequence is indented w/ 3 spaces"
-    );
-
-    assert_eq!(
-        to_html("    ```\n    aaa\n    ```"),
+        "should support empty fenced code",
+    ),
+    (
+        " ```\n aaa\naaa\n```",
+        "<pre><code>aaa\naaa\n</code></pre>",
+        "should remove up to one space from the content if the opening sequence is indented w/ 1 space",
+    ),
+    (
+        "  ```\naaa\n  aaa\naaa\n  ```",
+        "<pre><code>aaa\naaa\naaa\n</code></pre>",
+        "should remove up to two space from the content if the opening sequence is indented w/ 2 spaces",
+    ),
+    (
+        "   ```\n   aaa\n    aaa\n  aaa\n   ```",
+        "<pre><code>aaa\n aaa\naaa\n</code></pre>",
+        "should remove up to three space from the content if the opening sequence is indented w/ 3 spaces",
+    ),
+    (
+        "    ```\n    aaa\n    ```",
         "<pre><code>```\naaa\n```\n</code></pre>",
-        "should not support indenteding the opening sequence w/ 4 spaces"
-    );
-
-    assert_eq!(
-        to_html("```\naaa\n  ```"),
+        "should not support indenteding the opening sequence w/ 4 spaces",
+    ),
+    (
+        "```\naaa\n  ```",
         "<pre><code>aaa\n</code></pre>",
-        "should support an indented closing sequence"
-    );
-
-    assert_eq!(
-        to_html("   ```\naaa\n  ```"),
+        "should support an indented closing sequence",
+    ),
+    (
+        "   ```\naaa\n  ```",
         "<pre><code>aaa\n</code></pre>",
-        "should support a differently indented closing sequence than the opening sequence"
-    );
-
-    assert_eq!(
-        to_html("```\naaa\n    ```\n"),
+        "should support a differently indented closing sequence than the opening sequence",
+    ),
+    (
+        "```\naaa\n    ```\n",
         "<pre><code>aaa\n    ```\n</code></pre>\n",
-        "should not support an indented closing sequence w/ 4 spaces"
-    );
-
-    assert_eq!(
-        to_html("``` ```\naaa"),
+        "should not support an indented closing sequence w/ 4 spaces",
+    ),
+    (
+        "``` ```\naaa",
         "<p><code> </code>\naaa</p>",
-        "should not support grave accents in the opening fence after the opening sequence"
-    );
-
-    assert_eq!(
-        to_html("~~~~~~\naaa\n~~~ ~~\n"),
+        "should not support grave accents in the opening fence after the opening sequence",
+    ),
+    (
+        "~~~~~~\naaa\n~~~ ~~\n",
         "<pre><code>aaa\n~~~ ~~\n</code></pre>\n",
-        "should not support spaces in the closing sequence"
-    );
-
-    assert_eq!(
-        to_html("foo\n```\nbar\n```\nbaz"),
+        "should not support spaces in the closing sequence",
+    ),
+    (
+        "foo\n```\nbar\n```\nbaz",
         "<p>foo</p>\n<pre><code>bar\n</code></pre>\n<p>baz</p>",
-        "should support interrupting paragraphs"
-    );
-
-    assert_eq!(
-        to_html("foo\n---\n~~~\nbar\n~~~\n# baz"),
+        "should support interrupting paragraphs",
+    ),
+    (
+        "foo\n---\n~~~\nbar\n~~~\n# baz",
         "<h2>foo</h2>\n<pre><code>bar\n</code></pre>\n<h1>baz</h1>",
-        "should support interrupting other content"
-    );
-
-    assert_eq!(
-        to_html("```ruby\ndef foo(x)\n  return 3\nend\n```"),
+        "should support interrupting other content",
+    ),
+    (
+        "```ruby\ndef foo(x)\n  return 3\nend\n```",
         "<pre><code class=\"language-ruby\">def foo(x)\n  return 3\nend\n</code></pre>",
-        "should support the info string as a `language-` class (1)"
-    );
-
-    assert_eq!(
-        to_html("````;\n````"),
+        "should support the info string as a `language-` class (1)",
+    ),
+    (
+        "````;\n````",
         "<pre><code class=\"language-;\"></code></pre>",
-        "should support the info string as a `language-` class (2)"
-    );
-
-    assert_eq!(
-        to_html("~~~~    ruby startline=3 $%@#$\ndef foo(x)\n  return 3\nend\n~~~~~~~"),
+        "should support the info string as a `language-` class (2)",
+    ),
+    (
+        "~~~~    ruby startline=3 $%@#$\ndef foo(x)\n  return 3\nend\n~~~~~~~",
         "<pre><code class=\"language-ruby\">def foo(x)\n  return 3\nend\n</code></pre>",
-        "should support the info string as a `language-` class, but not the meta string"
-    );
-
-    assert_eq!(
-        to_html("``` aa ```\nfoo"),
+        "should support the info string as a `language-` class, but not the meta string",
+    ),
+    (
+        "``` aa ```\nfoo",
         "<p><code>aa</code>\nfoo</p>",
-        "should not support grave accents in the meta string"
-    );
-
-    assert_eq!(
-        to_html("~~~ aa ``` ~~~\nfoo\n~~~"),
+        "should not support grave accents in the meta string",
+    ),
+    (
+        "~~~ aa ``` ~~~\nfoo\n~~~",
         "<pre><code class=\"language-aa\">foo\n</code></pre>",
-        "should support grave accents and tildes in the meta string of tilde fenced code"
-    );
-
-    assert_eq!(
-        to_html("```\n``` aaa\n```"),
+        "should support grave accents and tildes in the meta string of tilde fenced code",
+    ),
+    (
+        "```\n``` aaa\n```",
         "<pre><code>``` aaa\n</code></pre>",
-        "should not support info string on closing sequences"
-    );
-
+        "should not support info string on closing sequences",
+    ),
     // Our own:
-    assert_eq!(
-        to_html("```  "),
+    (
+        "```  ",
         "<pre><code></code></pre>\n",
-        "should support an eof after whitespace, after the start fence sequence"
-    );
-
-    assert_eq!(
-        to_html("```  js\nalert(1)\n```"),
+        "should support an eof after whitespace, after the start fence sequence",
+    ),
+    (
+        "```  js\nalert(1)\n```",
         "<pre><code class=\"language-js\">alert(1)\n</code></pre>",
-        "should support whitespace between the sequence and the info string"
-    );
-
-    assert_eq!(
-        to_html("```js"),
+        "should support whitespace between the sequence and the info string",
+    ),
+    (
+        "```js",
         "<pre><code class=\"language-js\"></code></pre>\n",
-        "should support an eof after the info string"
-    );
-
-    assert_eq!(
-        to_html("```  js \nalert(1)\n```"),
+        "should support an eof after the info string",
+    ),
+    (
+        "```  js \nalert(1)\n```",
         "<pre><code class=\"language-js\">alert(1)\n</code></pre>",
-        "should support whitespace after the info string"
-    );
-
-    assert_eq!(
-        to_html("```\n  "),
+        "should support whitespace after the info string",
+    ),
+    (
+        "```\n  ",
         "<pre><code>  \n</code></pre>\n",
-        "should support an eof after whitespace in content"
-    );
-
-    assert_eq!(
-        to_html("  ```\n "),
+        "should support an eof after whitespace in content",
+    ),
+    (
+        "  ```\n ",
         "<pre><code></code></pre>\n",
-        "should support an eof in the prefix, in content"
-    );
-
-    assert_eq!(
-        to_html("```j\\+s&copy;"),
+        "should support an eof in the prefix, in content",
+    ),
+    (
+        "```j\\+s&copy;",
         "<pre><code class=\"language-j+s©\"></code></pre>\n",
-        "should support character escapes and character references in info strings"
-    );
-
-    assert_eq!(
-        to_html("```a\\&b\0c"),
+        "should support character escapes and character references in info strings",
+    ),
+    (
+        "```a\\&b\0c",
         "<pre><code class=\"language-a&amp;b�c\"></code></pre>\n",
-        "should encode dangerous characters in languages"
-    );
-
-    assert_eq!(
-      to_html("   ```\naaa\n    ```"),
-      "<pre><code>aaa\n ```\n</code></pre>\n",
-      "should not support a closing sequence w/ too much indent, regardless of opening sequence (1)"
-    );
-
-    assert_eq!(
-        to_html("> ```\n>\n>\n>\n\na"),
+        "should encode dangerous characters in languages",
+    ),
+    (
+        "   ```\naaa\n    ```",
+        "<pre><code>aaa\n ```\n</code></pre>\n",
+        "should not support a closing sequence w/ too much indent, regardless of opening sequence (1)",
+    ),
+    (
+        "> ```\n>\n>\n>\n\na",
         "<blockquote>\n<pre><code>\n\n\n</code></pre>\n</blockquote>\n<p>a</p>",
-        "should not support a closing sequence w/ too much indent, regardless of opening sequence (2)"
-    );
-
-    assert_eq!(
-        to_html("> ```a\nb"),
+        "should not support a closing sequence w/ too much indent, regardless of opening sequence (2)",
+    ),
+    (
+        "> ```a\nb",
         "<blockquote>\n<pre><code class=\"language-a\"></code></pre>\n</blockquote>\n<p>b</p>",
-        "should not support lazyness (1)"
-    );
-
-    assert_eq!(
-        to_html("> a\n```b"),
+        "should not support lazyness (1)",
+    ),
+    (
+        "> a\n```b",
         "<blockquote>\n<p>a</p>\n</blockquote>\n<pre><code class=\"language-b\"></code></pre>\n",
-        "should not support lazyness (2)"
-    );
-
-    assert_eq!(
-        to_html("> ```a\n```"),
+        "should not support lazyness (2)",
+    ),
+    (
+        "> ```a\n```",
         "<blockquote>\n<pre><code class=\"language-a\"></code></pre>\n</blockquote>\n<pre><code></code></pre>\n",
-        "should not support lazyness (3)"
-    );
+        "should not support lazyness (3)",
+    ),
+    (
+        ">\t```\n>\tcode\n>\t```",
+        "<blockquote>\n<pre><code>code\n</code></pre>\n</blockquote>",
+        "should strip the indent of a fence after a tab in a block quote",
+    ),
+    (
+        "> \t```\n> \tcode\n> \t```",
+        "<blockquote>\n<pre><code>code\n</code></pre>\n</blockquote>",
+        "should strip the indent of a fence after a space and a tab in a block quote (1)",
+    ),
+    (
+        ">  \t```\n>  \tcode\n>  \t```",
+        "<blockquote>\n<pre><code>code\n</code></pre>\n</blockquote>",
+        "should strip the indent of a fence after a space and a tab in a block quote (2)",
+    ),
+    (
+        "> \t```\n>\t\tcode\n> \t```",
+        "<blockquote>\n<pre><code>\tcode\n</code></pre>\n</blockquote>",
+        "should strip the indent of a fence from a partial tab in a block quote",
+    ),
+    (
+        ">\t```\n>  a\n>    b\n>\t```",
+        "<blockquote>\n<pre><code>a\n b\n</code></pre>\n</blockquote>",
+        "should strip the indent of a fence after a tab from spaces in a block quote",
+    ),
+    (
+        ">  ```\n>   a\n> b\n>  ```",
+        "<blockquote>\n<pre><code> a\nb\n</code></pre>\n</blockquote>",
+        "should strip the indent of an indented fence in a block quote (1)",
+    ),
+    (
+        ">   ```\n>    a\n> b\n>   ```",
+        "<blockquote>\n<pre><code> a\nb\n</code></pre>\n</blockquote>",
+        "should strip the indent of an indented fence in a block quote (2)",
+    ),
+    (
+        ">    ```\n>     a\n> b\n>    ```",
+        "<blockquote>\n<pre><code> a\nb\n</code></pre>\n</blockquote>",
+        "should strip the indent of an indented fence in a block quote (3)",
+    ),
+    (
+        ">\t ```\n>\t  a\n> b\n>\t ```",
+        "<blockquote>\n<pre><code> a\nb\n</code></pre>\n</blockquote>",
+        "should strip the indent of an indented fence after a tab in a block quote",
+    ),
+];
+
+/// Replace the line feeds in `value` and `expected` with `eol`.
+///
+/// The expected HTML only uses `eol` if there are line endings in `value`, as
+/// the first line ending in a document is used for generated line endings.
+fn with_eol(value: &str, expected: &str, eol: &str) -> (String, String) {
+    let expected = if value.contains('\n') {
+        expected.replace('\n', eol)
+    } else {
+        expected.into()
+    };
+    (value.replace('\n', eol), expected)
+}
+
+#[test]
+fn code_fenced() -> Result<(), message::Message> {
+    for (value, expected, message) in CODE_FENCED {
+        assert_eq!(to_html(value), expected, "{}", message);
+
+        for eol in ["\r\n", "\r"] {
+            let (value, expected) = with_eol(value, expected, eol);
+            assert_eq!(to_html(&value), expected, "{} (w/ {:?})", message, eol);
+        }
+    }
 
     assert_eq!(
         to_html_with_options(
@@ -289,9 +322,12 @@ fn code_fenced() -> Result<(), message::Message> {
                 lang: Some("js".into()),
                 meta: Some("extra".into()),
                 value: "console.log(1)\nconsole.log(2)".into(),
-                position: Some(Position::new(1, 1, 0, 4, 4, 45))
+                position: Some(Position::new(1, 1, 0, 4, 4, 45)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 4, 4, 45))
+            position: Some(Position::new(1, 1, 0, 4, 4, 45)),
+            raw: None,
         }),
         "should support code (fenced) as `Code`s in mdast"
     );
@@ -303,9 +339,12 @@ fn code_fenced() -> Result<(), message::Message> {
                 lang: None,
                 meta: None,
                 value: "asd".into(),
-                position: Some(Position::new(1, 1, 0, 2, 4, 7))
+                position: Some(Position::new(1, 1, 0, 2, 4, 7)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 4, 7))
+            position: Some(Position::new(1, 1, 0, 2, 4, 7)),
+            raw: None,
         }),
         "should support code (fenced) w/o closing fence in mdast"
     );
@@ -317,9 +356,12 @@ fn code_fenced() -> Result<(), message::Message> {
                 lang: None,
                 meta: None,
                 value: "asd".into(),
-                position: Some(Position::new(1, 1, 0, 3, 4, 11))
+                position: Some(Position::new(1, 1, 0, 3, 4, 11)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 4, 11))
+            position: Some(Position::new(1, 1, 0, 3, 4, 11)),
+            raw: None,
         }),
         "should support code (fenced) w/o CR line endings"
     );
@@ -331,9 +373,12 @@ fn code_fenced() -> Result<(), message::Message> {
                 lang: None,
                 meta: None,
                 value: "asd".into(),
-                position: Some(Position::new(1, 1, 0, 3, 4, 13))
+                position: Some(Position::new(1, 1, 0, 3, 4, 13)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 4, 13))
+            position: Some(Position::new(1, 1, 0, 3, 4, 13)),
+            raw: None,
         }),
         "should support code (fenced) w/o CR+LF line endings"
     );
@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Code, Node, Root},
+    mdast::{Blockquote, Code, List, ListItem, Node, Root},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -122,6 +122,36 @@ fn code_indented() -> Result<(), message::Message> {
         "should not support lazyness (7)"
     );
 
+    assert_eq!(
+        to_html(">     a\n>     b"),
+        "<blockquote>\n<pre><code>a\nb\n</code></pre>\n</blockquote>",
+        "should support continued code (indented) in a block quote opened on the same line"
+    );
+
+    assert_eq!(
+        to_html("    a\n>\n>     b"),
+        "<pre><code>a\n</code></pre>\n<blockquote>\n<pre><code>b\n</code></pre>\n</blockquote>",
+        "should not continue code (indented) into a block quote opened on a blank line"
+    );
+
+    assert_eq!(
+        to_html("    a\n-\n      b"),
+        "<pre><code>a\n</code></pre>\n<ul>\n<li>\n<pre><code>b\n</code></pre>\n</li>\n</ul>",
+        "should not continue code (indented) into a list item opened on a blank line"
+    );
+
+    assert_eq!(
+        to_html(">\t  a\n>\t   b"),
+        "<blockquote>\n<pre><code>a\n b\n</code></pre>\n</blockquote>",
+        "should support continued code (indented) after a tab in a block quote"
+    );
+
+    assert_eq!(
+        to_html("-     a\n      b"),
+        "<ul>\n<li>\n<pre><code>a\nb\n</code></pre>\n</li>\n</ul>",
+        "should support continued code (indented) in a list item opened on the same line"
+    );
+
     let off = Options {
         parse: ParseOptions {
             constructs: Constructs {
@@ -212,12 +242,89 @@ fn code_indented() -> Result<(), message::Message> {
                 lang: None,
                 meta: None,
                 value: "console.log(1)\nconsole.log(2)".into(),
-                position: Some(Position::new(1, 1, 0, 2, 19, 34))
+                position: Some(Position::new(1, 1, 0, 2, 19, 34)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 1, 35))
+            position: Some(Position::new(1, 1, 0, 3, 1, 35)),
+            raw: None,
         }),
         "should support code (indented) as `Code`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("    a\n>\n>     b", &Default::default())?,
+        Node::Root(Root {
+            children: vec![
+                Node::Code(Code {
+                    lang: None,
+                    meta: None,
+                    value: "a".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 6, 5)),
+                    markers: None,
+                    raw: None,
+                }),
+                Node::Blockquote(Blockquote {
+                    children: vec![Node::Code(Code {
+                        lang: None,
+                        meta: None,
+                        value: "b".into(),
+                        position: Some(Position::new(3, 3, 10, 3, 8, 15)),
+                        markers: None,
+                        raw: None,
+                    })],
+                    position: Some(Position::new(2, 1, 6, 3, 8, 15)),
+                    raw: None,
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 3, 8, 15)),
+            raw: None,
+        }),
+        "should not continue code (indented) into a block quote opened on a blank line in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("    a\n-\n      b", &Default::default())?,
+        Node::Root(Root {
+            children: vec![
+                Node::Code(Code {
+                    lang: None,
+                    meta: None,
+                    value: "a".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 6, 5)),
+                    markers: None,
+                    raw: None,
+                }),
+                Node::List(List {
+                    children: vec![Node::ListItem(ListItem {
+                        children: vec![Node::Code(Code {
+                            lang: None,
+                            meta: None,
+                            value: "b".into(),
+                            position: Some(Position::new(3, 3, 10, 3, 8, 15)),
+                            markers: None,
+                            raw: None,
+                        })],
+                        position: Some(Position::new(2, 1, 6, 3, 8, 15)),
+                        spread: false,
+                        checked: None,
+                        markers: None,
+                        raw: None,
+                    })],
+                    position: Some(Position::new(2, 1, 6, 3, 8, 15)),
+                    ordered: false,
+                    start: None,
+                    kind: None,
+                    spread: false,
+                    markers: None,
+                    raw: None,
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 3, 8, 15)),
+            raw: None,
+        }),
+        "should not continue code (indented) into a list item opened on a blank line in mdast"
+    );
+
     Ok(())
 }
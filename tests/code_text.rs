@@ -162,6 +162,36 @@ fn code_text() -> Result<(), message::Message> {
         "should support an escaped initial grave accent"
     );
 
+    assert_eq!(
+        to_html("```a`` `b`\nc"),
+        "<p>```a`` <code>b</code>\nc</p>",
+        "should support code after sequences that are not closed"
+    );
+
+    assert_eq!(
+        to_html("`a ``b`` ```c `` d`"),
+        "<p><code>a ``b`` ```c `` d</code></p>",
+        "should support code around sequences of other sizes"
+    );
+
+    assert_eq!(
+        to_html("```a ``b`` c ``d"),
+        "<p>```a <code>b</code> c ``d</p>",
+        "should not support code when closing sequences only exist before it"
+    );
+
+    assert_eq!(
+        to_html("`` `a` `b`"),
+        "<p>`` <code>a</code> <code>b</code></p>",
+        "should support code after closed code of the same size, after sequences that are not closed"
+    );
+
+    assert_eq!(
+        to_html("``` ``a`` ``b``"),
+        "<p>``` <code>a</code> <code>b</code></p>",
+        "should support code after closed code of the same size, after bigger sequences that are not closed"
+    );
+
     assert_eq!(
         to_html_with_options(
             "`a`",
@@ -187,20 +217,25 @@ fn code_text() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::InlineCode(InlineCode {
                         value: "alpha".into(),
-                        position: Some(Position::new(1, 3, 2, 1, 10, 9))
+                        position: Some(Position::new(1, 3, 2, 1, 10, 9)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b.".into(),
-                        position: Some(Position::new(1, 10, 9, 1, 13, 12))
+                        position: Some(Position::new(1, 10, 9, 1, 13, 12)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+            raw: None,
         }),
         "should support code (text) as `InlineCode`s in mdast"
     );
@@ -211,11 +246,14 @@ fn code_text() -> Result<(), message::Message> {
             children: vec![Node::Paragraph(Paragraph {
                 children: vec![Node::InlineCode(InlineCode {
                     value: " alpha".into(),
-                    position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                    position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                    raw: None,
                 }),],
-                position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 11, 10))
+            position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+            raw: None,
         }),
         "should strip one space from each side of `InlineCode` if the value starts and ends with space"
     );
@@ -226,11 +264,14 @@ fn code_text() -> Result<(), message::Message> {
             children: vec![Node::Paragraph(Paragraph {
                 children: vec![Node::InlineCode(InlineCode {
                     value: "   ".into(),
-                    position: Some(Position::new(1, 1, 0, 1, 6, 5))
+                    position: Some(Position::new(1, 1, 0, 1, 6, 5)),
+                    raw: None,
                 }),],
-                position: Some(Position::new(1, 1, 0, 1, 6, 5))
+                position: Some(Position::new(1, 1, 0, 1, 6, 5)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 6, 5))
+            position: Some(Position::new(1, 1, 0, 1, 6, 5)),
+            raw: None,
         }),
         "should not strip any whitespace if `InlineCode` is all whitespace"
     );
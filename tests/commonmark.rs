@@ -1,14 +1,20 @@
 //! `CommonMark` test suite.
 
 // > 👉 **Important**: this module is generated by `generate/src/main.rs`.
-// > It is generate from the latest CommonMark website.
+// > It is generate from the CommonMark spec, version 0.31.2.
 
-use markdown::{message, to_html_with_options, CompileOptions, Options};
+use markdown::{commonmark_version, message, to_html_with_options, CompileOptions, Options};
 use pretty_assertions::assert_eq;
 
 #[rustfmt::skip]
 #[test]
 fn commonmark() -> Result<(), message::Message> {
+    assert_eq!(
+        commonmark_version(),
+        "0.31.2",
+        "should be tested against the same version of the spec"
+    );
+
     let danger = Options {
         compile: CompileOptions {
             allow_dangerous_html: true,
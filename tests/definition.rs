@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{Definition, Node, Root},
+    mdast::{Blockquote, Definition, List, ListItem, Node, Root},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -374,6 +374,12 @@ fn definition() -> Result<(), message::Message> {
         "should support whitespace before a destination"
     );
 
+    assert_eq!(
+        to_html("[a b]: c\n\n[a\nB] [ab]"),
+        "<p><a href=\"c\">a\nB</a> [ab]</p>",
+        "should collapse whitespace in labels to a space, not remove it"
+    );
+
     // See: <https://github.com/commonmark/commonmark.js/issues/192>
     assert_eq!(
         to_html("[x]: <> \"\"\n[][x]"),
@@ -515,12 +521,61 @@ fn definition() -> Result<(), message::Message> {
                 identifier: "a".into(),
                 label: Some("a".into()),
                 title: Some("c".into()),
-                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+            raw: None,
         }),
         "should support definitions as `Definition`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("> [a]: b\n\n- [c]: d", &Default::default())?,
+        Node::Root(Root {
+            children: vec![
+                Node::Blockquote(Blockquote {
+                    children: vec![Node::Definition(Definition {
+                        url: "b".into(),
+                        identifier: "a".into(),
+                        label: Some("a".into()),
+                        title: None,
+                        position: Some(Position::new(1, 3, 2, 1, 9, 8)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 9, 8)),
+                    raw: None,
+                }),
+                Node::List(List {
+                    children: vec![Node::ListItem(ListItem {
+                        children: vec![Node::Definition(Definition {
+                            url: "d".into(),
+                            identifier: "c".into(),
+                            label: Some("c".into()),
+                            title: None,
+                            position: Some(Position::new(3, 3, 12, 3, 9, 18)),
+                            raw: None,
+                        })],
+                        position: Some(Position::new(3, 1, 10, 3, 9, 18)),
+                        spread: false,
+                        checked: None,
+                        markers: None,
+                        raw: None,
+                    })],
+                    position: Some(Position::new(3, 1, 10, 3, 9, 18)),
+                    ordered: false,
+                    start: None,
+                    kind: None,
+                    spread: false,
+                    markers: None,
+                    raw: None,
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 3, 9, 18)),
+            raw: None,
+        }),
+        "should support definitions in block quotes and list items as `Definition`s in mdast"
+    );
+
     Ok(())
 }
@@ -0,0 +1,267 @@
+use markdown::{
+    compile_events,
+    event::{Event, Kind, Name, Point},
+    message, to_events, CompileOptions, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Turn issue numbers (`#123`) in data into links to `url` followed by the
+/// number.
+///
+/// The URLs do not exist in the document, so they are appended to the value,
+/// and the events for destinations point into that injected text.
+fn linkify_issues(events: Vec<Event>, value: &str, url: &str) -> (Vec<Event>, String) {
+    let bytes = value.as_bytes();
+    let mut injected = String::from(value);
+    let mut result = vec![];
+    let mut index = 0;
+
+    while index < events.len() {
+        let enter = &events[index];
+
+        if enter.kind == Kind::Enter && enter.name == Name::Data {
+            let exit = &events[index + 1];
+            let mut start = enter.point.clone();
+            let mut cursor = start.index;
+
+            while cursor < exit.point.index {
+                let mut end = cursor + 1;
+
+                while end < exit.point.index && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+
+                if bytes[cursor] == b'#' && end > cursor + 1 {
+                    let hash = point(&enter.point, cursor);
+                    let after = point(&enter.point, end);
+                    let url_start = injected_point(injected.len());
+                    injected.push_str(url);
+                    injected.push_str(&value[cursor + 1..end]);
+                    let url_end = injected_point(injected.len());
+
+                    if hash.index > start.index {
+                        result.push(event(Kind::Enter, Name::Data, &start));
+                        result.push(event(Kind::Exit, Name::Data, &hash));
+                    }
+
+                    result.push(event(Kind::Enter, Name::Link, &hash));
+                    result.push(event(Kind::Enter, Name::Label, &hash));
+                    result.push(event(Kind::Enter, Name::LabelText, &hash));
+                    result.push(event(Kind::Enter, Name::Data, &hash));
+                    result.push(event(Kind::Exit, Name::Data, &after));
+                    result.push(event(Kind::Exit, Name::LabelText, &after));
+                    result.push(event(Kind::Exit, Name::Label, &after));
+                    result.push(event(Kind::Enter, Name::Resource, &url_start));
+                    result.push(event(Kind::Enter, Name::ResourceDestination, &url_start));
+                    result.push(event(Kind::Enter, Name::ResourceDestinationRaw, &url_start));
+                    result.push(event(
+                        Kind::Enter,
+                        Name::ResourceDestinationString,
+                        &url_start,
+                    ));
+                    result.push(event(Kind::Enter, Name::Data, &url_start));
+                    result.push(event(Kind::Exit, Name::Data, &url_end));
+                    result.push(event(Kind::Exit, Name::ResourceDestinationString, &url_end));
+                    result.push(event(Kind::Exit, Name::ResourceDestinationRaw, &url_end));
+                    result.push(event(Kind::Exit, Name::ResourceDestination, &url_end));
+                    result.push(event(Kind::Exit, Name::Resource, &url_end));
+                    result.push(event(Kind::Exit, Name::Link, &after));
+
+                    start = after;
+                    cursor = end;
+                } else {
+                    cursor += 1;
+                }
+            }
+
+            if exit.point.index > start.index {
+                result.push(event(Kind::Enter, Name::Data, &start));
+                result.push(exit.clone());
+            }
+
+            index += 2;
+        } else {
+            result.push(enter.clone());
+            index += 1;
+        }
+    }
+
+    (result, injected)
+}
+
+/// Create a point at `index` in injected text.
+///
+/// Injected text is not part of the document, so its line and column only
+/// show up in messages.
+fn injected_point(index: usize) -> Point {
+    Point {
+        line: 1,
+        column: index + 1,
+        index,
+        vs: 0,
+    }
+}
+
+/// Create a point at `index` on the same line as `point`.
+fn point(point: &Point, index: usize) -> Point {
+    Point {
+        line: point.line,
+        column: point.column + index - point.index,
+        index,
+        vs: 0,
+    }
+}
+
+/// Create an event.
+fn event(kind: Kind, name: Name, point: &Point) -> Event {
+    Event {
+        kind,
+        name,
+        point: point.clone(),
+        link: None,
+    }
+}
+
+#[test]
+fn events() -> Result<(), message::Message> {
+    let parse = ParseOptions::default();
+    let compile = CompileOptions::default();
+
+    assert_eq!(
+        compile_events(&to_events("*a* b", &parse)?, "*a* b", &compile)?,
+        "<p><em>a</em> b</p>",
+        "should compile events like `to_html`"
+    );
+
+    let value = "Fixes #123 and #45, not #a.\n\n# Title #6\n\n`#7`";
+    let (events, injected) = linkify_issues(
+        to_events(value, &parse)?,
+        value,
+        "https://github.com/wooorm/markdown-rs/issues/",
+    );
+
+    assert_eq!(
+        compile_events(&events, &injected, &compile)?,
+        "<p>Fixes <a href=\"https://github.com/wooorm/markdown-rs/issues/123\">#123</a> and <a href=\"https://github.com/wooorm/markdown-rs/issues/45\">#45</a>, not #a.</p>\n<h1>Title <a href=\"https://github.com/wooorm/markdown-rs/issues/6\">#6</a></h1>\n<p><code>#7</code></p>",
+        "should support changed events (issue numbers)"
+    );
+
+    let mut events = to_events("a", &parse)?;
+    events.pop();
+
+    assert_eq!(
+        compile_events(&events, "a", &compile)
+            .unwrap_err()
+            .to_string(),
+        "Unexpected end of events, expected an exit of `Paragraph` (markdown-rs:unbalanced-events)",
+        "should error on unclosed events"
+    );
+
+    let mut events = to_events("*a*", &parse)?;
+    events.remove(events.len() - 2);
+
+    assert_eq!(
+        compile_events(&events, "*a*", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:4: Unexpected exit of `Paragraph`, expected an exit of the last entered event (markdown-rs:unbalanced-events)",
+        "should error on unbalanced events"
+    );
+
+    let mut events = to_events("a", &parse)?;
+    events.insert(2, event(Kind::Enter, Name::Emphasis, &events[1].point));
+    events.insert(3, event(Kind::Exit, Name::Emphasis, &events[2].point));
+
+    assert_eq!(
+        compile_events(&events, "a", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:1: Unexpected content in `Data`, expected void event to be exited directly (markdown-rs:invalid-void)",
+        "should error on content in void events"
+    );
+
+    assert_eq!(
+        compile_events(&to_events("a", &parse)?, "", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:2: Unexpected point `1` of `Data`, expected a character boundary in the value (markdown-rs:invalid-point)",
+        "should error on points outside of the value"
+    );
+
+    assert_eq!(
+        compile_events(&to_events("a", &parse)?, "é", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:2: Unexpected point `1` of `Data`, expected a character boundary in the value (markdown-rs:invalid-point)",
+        "should error on points in characters"
+    );
+
+    let mut events = to_events("ab", &parse)?;
+    events[1].point = point(&events[1].point, 2);
+    events[2].point = point(&events[1].point, 1);
+
+    assert_eq!(
+        compile_events(&events, "ab", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:2: Unexpected point `1` of `Data`, expected an exit to not be before its enter (at `2`) (markdown-rs:invalid-point)",
+        "should error on exits before enters"
+    );
+
+    let mut events = to_events("*a*", &parse)?;
+    events.retain(|event| event.name != Name::EmphasisSequence);
+    for event in &mut events {
+        if event.name == Name::Emphasis {
+            event.name = Name::Link;
+        }
+    }
+
+    assert_eq!(
+        compile_events(&events, "*a*", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:4: Unexpected end of `Link`, expected a `Label` in it (markdown-rs:invalid-structure)",
+        "should error on links without label"
+    );
+
+    let mut events = to_events("[a]\n\n[a]: b", &parse)?;
+    let start = events
+        .iter()
+        .position(|event| event.name == Name::Definition)
+        .unwrap();
+    let end = events
+        .iter()
+        .rposition(|event| event.name == Name::Definition)
+        .unwrap();
+    events.drain(start..=end);
+
+    assert_eq!(
+        compile_events(&events, "[a]\n\n[a]: b", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:4: Unexpected `Link` to `A`, expected a definition with that identifier (markdown-rs:invalid-structure)",
+        "should error on references without definition"
+    );
+
+    let mut events = to_events("[a]: b", &parse)?;
+    let copy = events.clone();
+    events.splice(1..1, copy);
+
+    assert_eq!(
+        compile_events(&events, "[a]: b", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:1: Unexpected `Definition` in `Definition`, expected it outside of `Definition` (markdown-rs:invalid-structure)",
+        "should error on definitions in definitions"
+    );
+
+    assert_eq!(
+        compile_events(&to_events("&amp;", &parse)?, "&xyz;", &compile)
+            .unwrap_err()
+            .to_string(),
+        "1:5: Unexpected character reference value `xyz`, expected a valid value (markdown-rs:invalid-structure)",
+        "should error on invalid character references"
+    );
+
+    Ok(())
+}
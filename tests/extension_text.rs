@@ -0,0 +1,226 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    message, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, ExtensionState, ExtensionTokenizer, Options, ParseOptions, TextExtension,
+};
+use pretty_assertions::assert_eq;
+
+/// Highlight (`==a==`).
+fn highlight(tokenizer: &mut ExtensionTokenizer) -> ExtensionState {
+    match (tokenizer.state(), tokenizer.current()) {
+        (0, Some(b'=')) => {
+            tokenizer.enter("highlight");
+            tokenizer.enter("highlightSequence");
+            tokenizer.consume();
+            tokenizer.set_state(1);
+            ExtensionState::Next
+        }
+        (1, Some(b'=')) => {
+            tokenizer.consume();
+            tokenizer.exit("highlightSequence");
+            tokenizer.set_state(2);
+            ExtensionState::Next
+        }
+        (2, Some(byte)) if byte != b'=' && !byte.is_ascii_whitespace() => {
+            tokenizer.enter_data();
+            tokenizer.consume();
+            tokenizer.set_state(3);
+            ExtensionState::Next
+        }
+        (3, Some(b'=')) => {
+            tokenizer.exit_data();
+            tokenizer.enter("highlightSequence");
+            tokenizer.consume();
+            tokenizer.set_state(4);
+            ExtensionState::Next
+        }
+        (3, Some(_)) => {
+            tokenizer.consume();
+            ExtensionState::Next
+        }
+        (4, Some(b'=')) => {
+            tokenizer.consume();
+            tokenizer.exit("highlightSequence");
+            tokenizer.exit("highlight");
+            ExtensionState::Ok
+        }
+        _ => ExtensionState::Nok,
+    }
+}
+
+/// Extension that never matches.
+fn never(tokenizer: &mut ExtensionTokenizer) -> ExtensionState {
+    tokenizer.enter("never");
+    tokenizer.consume();
+    ExtensionState::Nok
+}
+
+/// Extension that is done right away.
+fn empty(_tokenizer: &mut ExtensionTokenizer) -> ExtensionState {
+    ExtensionState::Ok
+}
+
+fn parse_options(extensions: Vec<TextExtension>) -> ParseOptions {
+    ParseOptions {
+        text_extensions: extensions,
+        ..ParseOptions::default()
+    }
+}
+
+fn highlight_extension() -> TextExtension {
+    TextExtension {
+        name: "highlight",
+        markers: vec![b'='],
+        tokenize: Box::new(highlight),
+    }
+}
+
+fn options() -> Options {
+    Options {
+        parse: parse_options(vec![highlight_extension()]),
+        compile: CompileOptions {
+            extension_tags: [("highlight".into(), "mark".into())].into(),
+            ..CompileOptions::default()
+        },
+    }
+}
+
+#[test]
+fn extension_text() -> Result<(), message::Message> {
+    assert_eq!(
+        to_html_with_options("a ==b== c", &Options::default())?,
+        "<p>a ==b== c</p>",
+        "should not support extensions by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b== c", &options())?,
+        "<p>a <mark>b</mark> c</p>",
+        "should support a text extension"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ==b== c",
+            &Options {
+                parse: parse_options(vec![highlight_extension()]),
+                ..Options::default()
+            }
+        )?,
+        "<p>a b c</p>",
+        "should compile only data if there is no tag for an extension"
+    );
+
+    assert_eq!(
+        to_html_with_options("==a b==", &options())?,
+        "<p><mark>a b</mark></p>",
+        "should support data with spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ==b", &options())?,
+        "<p>a ==b</p>",
+        "should not support an unclosed extension"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ====", &options())?,
+        "<p>a ====</p>",
+        "should not support an extension the callback rejects"
+    );
+
+    assert_eq!(
+        to_html_with_options("a = b, c=d", &options())?,
+        "<p>a = b, c=d</p>",
+        "should support markers as data"
+    );
+
+    assert_eq!(
+        to_html_with_options("==a\nb==", &options())?,
+        "<p>==a\nb==</p>",
+        "should not support line endings in extensions"
+    );
+
+    assert_eq!(
+        to_html_with_options("*==a==* ==b== *c*", &options())?,
+        "<p><em><mark>a</mark></em> <mark>b</mark> <em>c</em></p>",
+        "should support extensions in and next to other constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options("==*a* &amp;==", &options())?,
+        "<p><mark>*a* &amp;amp;</mark></p>",
+        "should not parse the contents of extensions further"
+    );
+
+    assert_eq!(
+        to_html_with_options("![==a==](b)", &options())?,
+        "<p><img src=\"b\" alt=\"a\" /></p>",
+        "should not generate tags in image alt"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ==b== *c*",
+            &Options {
+                parse: parse_options(vec![
+                    TextExtension {
+                        name: "never",
+                        markers: vec![b'=', b'*'],
+                        tokenize: Box::new(never),
+                    },
+                    highlight_extension()
+                ]),
+                compile: CompileOptions {
+                    extension_tags: [("highlight".into(), "mark".into())].into(),
+                    ..CompileOptions::default()
+                },
+            }
+        )?,
+        "<p>a <mark>b</mark> <em>c</em></p>",
+        "should try other extensions and then built in constructs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a =b *c*",
+            &Options {
+                parse: parse_options(vec![TextExtension {
+                    name: "empty",
+                    markers: vec![b'=', b'*'],
+                    tokenize: Box::new(empty),
+                }]),
+                ..Options::default()
+            }
+        )?,
+        "<p>a =b <em>c</em></p>",
+        "should not support extensions that are done w/o consuming anything"
+    );
+
+    assert_eq!(
+        to_mdast("a ==b== c", &parse_options(vec![highlight_extension()]))?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a b c".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 10, 9)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9)),
+            raw: None,
+        }),
+        "should keep only the data of extensions in mdast"
+    );
+
+    assert_eq!(
+        format!("{:?}", highlight_extension()),
+        "TextExtension { name: \"highlight\", markers: [61], tokenize: \"[Function]\" }",
+        "should support `Debug` on text extensions"
+    );
+
+    Ok(())
+}
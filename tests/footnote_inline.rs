@@ -0,0 +1,287 @@
+use markdown::{
+    mdast::{FootnoteDefinition, FootnoteReference, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn footnote_inline() -> Result<(), message::Message> {
+    let inline = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                footnote_inline: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html("a^[b] c"),
+        "<p>a^[b] c</p>",
+        "should ignore inline footnotes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b] c", &Options::gfm())?,
+        "<p>a^[b] c</p>",
+        "should ignore inline footnotes in GFM"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b *c*] d", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> d</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <em>c</em> <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a^[b]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        footnote_inline: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support inline footnotes without GFM"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ^ [b] ^[c", &inline)?,
+        "<p>a ^ [b] ^[c</p>",
+        "should not support a caret without bracket, or an unclosed inline footnote"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b [c] d] e", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> e</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b [c] d <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support balanced brackets in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b `]` c] d", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> d</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <code>]</code> c <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support code (text) with a closing bracket in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b [c](d) e] f^[g www.h.com]", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> f<sup><a href=\"#user-content-fn-2\" id=\"user-content-fnref-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"d\">c</a> e <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-2\">
+<p>g <a href=\"http://www.h.com\">www.h.com</a> <a href=\"#user-content-fnref-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support links and autolink literals in inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a ^[b] c](d)", &inline)?,
+        "<p>[a <sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> c](d)</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should not support inline footnotes in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b ^[c] d]", &inline)?,
+        "<p>a^[b <sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> d]</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>c <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should not support inline footnotes in inline footnotes (the deepest wins)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b](c)", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup>(c)</p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should not support a resource after an inline footnote"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| c^[d] | e |", &inline)?,
+        "<table>
+<thead>
+<tr>
+<th>a</th>
+<th>b</th>
+</tr>
+</thead>
+<tbody>
+<tr>
+<td>c<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></td>
+<td>e</td>
+</tr>
+</tbody>
+</table>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>d <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support inline footnotes in table cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("a^[b]\n\n[c]: d", &inline)?,
+        to_html_with_options("a^[b]", &inline)?,
+        "should not match inline footnotes to definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^1] b^[c] d[^3]\n\n[^1]: e\n[^3]: f", &inline)?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> b<sup><a href=\"#user-content-fn-2\" id=\"user-content-fnref-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup> d<sup><a href=\"#user-content-fn-3\" id=\"user-content-fnref-3\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">3</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>e <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-2\">
+<p>c <a href=\"#user-content-fnref-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+<li id=\"user-content-fn-3\">
+<p>f <a href=\"#user-content-fnref-3\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should number inline footnotes with GFM footnotes, skipping identifiers of definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a^[b]](c)", &inline)?,
+        "<p><img src=\"c\" alt=\"a\" /></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"user-content-fn-1\">
+<p>b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should not output a call in an image alt"
+    );
+
+    assert_eq!(
+        to_mdast("a^[b]\n\nc", &inline.parse)?,
+        Node::Root(Root {
+            children: vec![
+                Node::Paragraph(Paragraph {
+                    children: vec![
+                        Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 1, 0, 1, 2, 1)),
+                            raw: None,
+                        }),
+                        Node::FootnoteReference(FootnoteReference {
+                            identifier: "1".into(),
+                            label: Some("1".into()),
+                            position: Some(Position::new(1, 2, 1, 1, 6, 5)),
+                            raw: None,
+                        })
+                    ],
+                    position: Some(Position::new(1, 1, 0, 1, 6, 5)),
+                    raw: None,
+                }),
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "c".into(),
+                        position: Some(Position::new(3, 1, 7, 3, 2, 8)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(3, 1, 7, 3, 2, 8)),
+                    raw: None,
+                }),
+                Node::FootnoteDefinition(FootnoteDefinition {
+                    identifier: "1".into(),
+                    label: Some("1".into()),
+                    children: vec![Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "b".into(),
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4)),
+                            raw: None,
+                        })],
+                        position: Some(Position::new(1, 4, 3, 1, 5, 4)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(1, 2, 1, 1, 6, 5)),
+                    raw: None,
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 3, 2, 8)),
+            raw: None,
+        }),
+        "should support inline footnotes as hoisted `FootnoteDefinition`s and `FootnoteReference`s in mdast"
+    );
+
+    Ok(())
+}
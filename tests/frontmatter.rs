@@ -1,8 +1,8 @@
 use markdown::{
     mdast::{Node, Root, Toml, Yaml},
-    message, to_html, to_html_with_options, to_mdast,
+    mdast_to_html, message, to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Constructs, Options, ParseOptions,
+    CompileOptions, Constructs, FrontmatterKind, FrontmatterRendering, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -144,9 +144,11 @@ fn frontmatter() -> Result<(), message::Message> {
         Node::Root(Root {
             children: vec![Node::Yaml(Yaml {
                 value: "a: b".into(),
-                position: Some(Position::new(1, 1, 0, 3, 4, 12))
+                position: Some(Position::new(1, 1, 0, 3, 4, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 4, 12))
+            position: Some(Position::new(1, 1, 0, 3, 4, 12)),
+            raw: None,
         }),
         "should support yaml as `Yaml`s in mdast"
     );
@@ -156,12 +158,223 @@ fn frontmatter() -> Result<(), message::Message> {
         Node::Root(Root {
             children: vec![Node::Toml(Toml {
                 value: "title = \"Jupyter\"".into(),
-                position: Some(Position::new(1, 1, 0, 3, 4, 25))
+                position: Some(Position::new(1, 1, 0, 3, 4, 25)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 4, 25))
+            position: Some(Position::new(1, 1, 0, 3, 4, 25)),
+            raw: None,
         }),
         "should support toml as `Toml`s in mdast"
     );
 
     Ok(())
 }
+
+#[test]
+fn frontmatter_only() -> Result<(), message::Message> {
+    let parse = ParseOptions {
+        constructs: Constructs {
+            frontmatter: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        markdown::frontmatter("---\ntitle: Jupyter\n---", &ParseOptions::default())?,
+        None,
+        "should not support frontmatter by default"
+    );
+
+    assert_eq!(
+        markdown::frontmatter("---\na: b\n---\n# Venus", &parse)?,
+        Some((
+            "a: b".into(),
+            FrontmatterKind::Yaml,
+            Position::new(1, 1, 0, 3, 4, 12)
+        )),
+        "should support yaml"
+    );
+
+    assert_eq!(
+        markdown::frontmatter("+++\ntitle = \"Jupyter\"\n+++", &parse)?,
+        Some((
+            "title = \"Jupyter\"".into(),
+            FrontmatterKind::Toml,
+            Position::new(1, 1, 0, 3, 4, 25)
+        )),
+        "should support toml"
+    );
+
+    let fixtures = [
+        "---\n---",
+        "---\na: b\n---",
+        "---\na: b\n---\n",
+        "+++\na = \"b\"\n+++\n\n# c",
+        "---\na\n\nb\n \t\nc\n---",
+        "--- \t\n---\t ",
+        "---\r\na: b\r\n---\r\nc",
+        "---\ra: b\r---",
+        "\u{FEFF}---\na: b\n---",
+        "\u{FEFF}+++\na = 1\n+++\n",
+        "\u{FEFF}# a",
+        "---",
+        "---\n",
+        "---\ntitle: Neptune",
+        "---\n+++",
+        "+++\n---",
+        "--\n---",
+        "----\n---",
+        "---\n----",
+        "---\n--",
+        "--- a\n---",
+        "---\n--- b",
+        " ---\n---",
+        "# a\n---\n---",
+        "> ---\n> ---",
+        "",
+    ];
+
+    for fixture in fixtures {
+        let expected = match &to_mdast(fixture, &parse)? {
+            Node::Root(root) => match root.children.first() {
+                Some(Node::Yaml(node)) => Some((
+                    node.value.clone(),
+                    FrontmatterKind::Yaml,
+                    node.position.clone().unwrap(),
+                )),
+                Some(Node::Toml(node)) => Some((
+                    node.value.clone(),
+                    FrontmatterKind::Toml,
+                    node.position.clone().unwrap(),
+                )),
+                _ => None,
+            },
+            _ => unreachable!("expected root"),
+        };
+
+        assert_eq!(
+            markdown::frontmatter(fixture, &parse)?,
+            expected,
+            "should match `to_mdast` for `{:?}`",
+            fixture
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn frontmatter_rendering() -> Result<(), message::Message> {
+    let code_block = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                frontmatter: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            frontmatter: FrontmatterRendering::CodeBlock,
+            ..Default::default()
+        },
+    };
+    let comment = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                frontmatter: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        compile: CompileOptions {
+            frontmatter: FrontmatterRendering::Comment,
+            ..Default::default()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("---\ntitle: Jupyter\n---\n\n# a", &code_block)?,
+        "<pre><code class=\"language-yaml\">title: Jupyter\n</code></pre>\n<h1>a</h1>",
+        "should support `FrontmatterRendering::CodeBlock` (yaml)"
+    );
+
+    assert_eq!(
+        to_html_with_options("+++\ntitle = \"<Jupyter>\"\n+++\n# a", &code_block)?,
+        "<pre><code class=\"language-toml\">title = &quot;&lt;Jupyter&gt;&quot;\n</code></pre>\n<h1>a</h1>",
+        "should support `FrontmatterRendering::CodeBlock` (toml)"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\n---", &code_block)?,
+        "<pre><code class=\"language-yaml\"></code></pre>",
+        "should support `FrontmatterRendering::CodeBlock` (empty)"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\na\n\nb\n---", &code_block)?,
+        "<pre><code class=\"language-yaml\">a\n\nb\n</code></pre>",
+        "should support blank lines w/ `FrontmatterRendering::CodeBlock`"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\ntitle: Jupyter\n---\n\n# a", &comment)?,
+        "<!--\n---\ntitle: Jupyter\n---\n-->\n<h1>a</h1>",
+        "should support `FrontmatterRendering::Comment` (yaml)"
+    );
+
+    assert_eq!(
+        to_html_with_options("+++\ntitle = \"Jupyter\"\n+++", &comment)?,
+        "<!--\n+++\ntitle = \"Jupyter\"\n+++\n-->",
+        "should support `FrontmatterRendering::Comment` (toml)"
+    );
+
+    assert_eq!(
+        to_html_with_options("+++\n+++", &comment)?,
+        "<!--\n+++\n+++\n-->",
+        "should support `FrontmatterRendering::Comment` (empty)"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\na: -->\n---", &comment)?,
+        "<!--\n---\na: --&gt;\n---\n-->",
+        "should not end the comment early w/ `FrontmatterRendering::Comment`"
+    );
+
+    assert_eq!(
+        to_html_with_options("---\na: --!><script>alert(1)</script>\n---", &comment)?,
+        "<!--\n---\na: --!&gt;<script&gt;alert(1)</script&gt;\n---\n-->",
+        "should not end the comment early w/ `--!>` w/ `FrontmatterRendering::Comment`"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n---\nb: c\n---", &code_block)?,
+        "<h1>a</h1>\n<hr />\n<h2>b: c</h2>",
+        "should not affect what looks like frontmatter but is not at the start"
+    );
+
+    assert_eq!(
+        to_html_with_options("\n---\nb: c\n---", &comment)?,
+        "<hr />\n<h2>b: c</h2>",
+        "should not affect what looks like frontmatter after a blank line"
+    );
+
+    for fixture in [
+        "---\ntitle: Jupyter\n---\n\n# a",
+        "+++\ntitle = \"Jupyter\"\n+++",
+        "---\n---",
+        "---\na: -->\n---",
+        "---\na: --!><b>\n---",
+    ] {
+        for options in [&code_block, &comment] {
+            assert_eq!(
+                mdast_to_html(&to_mdast(fixture, &options.parse)?, &options.compile)?,
+                to_html_with_options(fixture, options)?,
+                "should match `to_html_with_options` w/ `mdast_to_html` for `{:?}`",
+                fixture
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,6 @@
-use markdown::{mdast, message, to_html, to_html_with_options, to_mdast, Options};
+use markdown::{
+    mdast, message, to_html, to_html_with_options, to_mdast, Constructs, Options, ParseOptions,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -129,5 +131,117 @@ fn fuzz() -> Result<(), message::Message> {
         "12: mdx: handle invalid mdx without panic (GH-26)"
     );
 
+    let frontmatter = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                frontmatter: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("+++\na\n> b", &frontmatter),
+        Ok("<p>+++\na</p>\n<blockquote>\n<p>b</p>\n</blockquote>".into()),
+        "13-a: containers after unclosed frontmatter"
+    );
+
+    assert_eq!(
+        to_html_with_options("+++\n\n> a", &frontmatter),
+        Ok("<p>+++</p>\n<blockquote>\n<p>a</p>\n</blockquote>".into()),
+        "13-b: containers after unclosed frontmatter and a blank line"
+    );
+
+    assert_eq!(
+        to_html_with_options("+++\r\n---\r\n* ", &frontmatter),
+        Ok("<h2>+++</h2>\r\n<ul>\r\n<li></li>\r\n</ul>".into()),
+        "13-c: list items after unclosed frontmatter (CRLF)"
+    );
+
+    assert!(
+        matches!(
+            to_mdast("+++\n<a>\n>", &frontmatter.parse),
+            Ok(mdast::Node::Root(_))
+        ),
+        "13-d: block quotes after unclosed frontmatter (mdast)"
+    );
+
+    assert_eq!(
+        to_mdast("a<b>\n-", &ParseOptions::mdx())
+            .err()
+            .unwrap()
+            .reason,
+        "Expected a closing tag for `<b>` (1:2) before the end of `HeadingSetext`",
+        "14-a: mdx: unclosed jsx in heading (setext)"
+    );
+
+    assert_eq!(
+        to_mdast("# a<b> #", &ParseOptions::mdx())
+            .err()
+            .unwrap()
+            .reason,
+        "Expected a closing tag for `<b>` (1:4) before the end of `HeadingAtx`",
+        "14-b: mdx: unclosed jsx in heading (atx)"
+    );
+
+    assert_eq!(
+        to_mdast("[<>]()", &ParseOptions::mdx())
+            .err()
+            .unwrap()
+            .reason,
+        "Expected a closing tag for `<>` (1:2) before the end of `LabelText`",
+        "14-c: mdx: unclosed jsx in label"
+    );
+
+    assert_eq!(
+        to_mdast("<a>[b</a>](c)", &ParseOptions::mdx())
+            .err()
+            .unwrap()
+            .reason,
+        "Expected the closing tag `</a>` either before the start of `Link` (1:4), or another opening tag after that start",
+        "14-d: mdx: closing jsx in link label, opened before it"
+    );
+
+    assert_eq!(
+        to_mdast("<a>![b</a>](c)", &ParseOptions::mdx())
+            .err()
+            .unwrap()
+            .reason,
+        "Expected the closing tag `</a>` either before the start of `Image` (1:4), or another opening tag after that start",
+        "14-e: mdx: closing jsx in image label, opened before it"
+    );
+
+    assert_eq!(
+        to_mdast(
+            "<a>^[b</a>]",
+            &ParseOptions {
+                constructs: Constructs {
+                    footnote_inline: true,
+                    ..Constructs::mdx()
+                },
+                ..ParseOptions::mdx()
+            }
+        )
+        .err()
+        .unwrap()
+        .reason,
+        "Expected the closing tag `</a>` either before the start of `FootnoteInline` (1:4), or another opening tag after that start",
+        "14-f: mdx: closing jsx in inline footnote, opened before it"
+    );
+
+    assert_eq!(
+        to_html(">     a\n\n    b"),
+        "<blockquote>\n<pre><code>a\n</code></pre>\n</blockquote>\n<pre><code>b\n</code></pre>",
+        "15-a: indented code in block quote, blank line, indented code"
+    );
+
+    assert_eq!(
+        to_html(">     )\n\n\t"),
+        "<blockquote>\n<pre><code>)\n</code></pre>\n</blockquote>\n",
+        "15-b: indented code in block quote, blank line, whitespace-only line"
+    );
+
     Ok(())
 }
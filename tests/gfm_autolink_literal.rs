@@ -4,10 +4,11 @@
 // Remove when solved.
 
 use markdown::{
+    linkify,
     mdast::{Link, Node, Paragraph, Root, Text},
-    message, to_html, to_html_with_options, to_mdast,
+    message, sanitize, to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Options, ParseOptions,
+    CompileOptions, LinkSpan, LinkSpanKind, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -214,6 +215,24 @@ fn gfm_autolink_literal() -> Result<(), message::Message> {
         "should support email links after Unicode punctuation"
     );
 
+    assert_eq!(
+        to_html_with_options("user+tag@example.com <user+tag@example.com>", &Options::gfm())?,
+        "<p><a href=\"mailto:user+tag@example.com\">user+tag@example.com</a> <a href=\"mailto:user+tag@example.com\">user+tag@example.com</a></p>",
+        "should not encode `+` in emails, like autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a.b_c-d@example.com <a.b_c-d@example.com>", &Options::gfm())?,
+        "<p><a href=\"mailto:a.b_c-d@example.com\">a.b_c-d@example.com</a> <a href=\"mailto:a.b_c-d@example.com\">a.b_c-d@example.com</a></p>",
+        "should not encode `.`, `_`, or `-` in emails, like autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a%b@example.com <a%b@example.com>", &Options::gfm())?,
+        "<p>a%<a href=\"mailto:b@example.com\">b@example.com</a> <a href=\"mailto:a%25b@example.com\">a%b@example.com</a></p>",
+        "should not include `%` in emails, but encode it in autolinks"
+    );
+
     assert_eq!(
         to_html_with_options(
             "http&#x3A;//user:password@host:port/path?key=value#fragment",
@@ -2749,80 +2768,388 @@ www.a/~
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "https://alpha.com".into(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "https://alpha.com".into(),
-                            position: Some(Position::new(1, 3, 2, 1, 20, 19))
+                            position: Some(Position::new(1, 3, 2, 1, 20, 19)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 3, 2, 1, 20, 19))
+                        position: Some(Position::new(1, 3, 2, 1, 20, 19)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b ".into(),
-                        position: Some(Position::new(1, 20, 19, 1, 23, 22))
+                        position: Some(Position::new(1, 20, 19, 1, 23, 22)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "mailto:bravo@charlie.com".into(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "bravo@charlie.com".into(),
-                            position: Some(Position::new(1, 23, 22, 1, 40, 39))
+                            position: Some(Position::new(1, 23, 22, 1, 40, 39)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 23, 22, 1, 40, 39))
+                        position: Some(Position::new(1, 23, 22, 1, 40, 39)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " c ".into(),
-                        position: Some(Position::new(1, 40, 39, 1, 43, 42))
+                        position: Some(Position::new(1, 40, 39, 1, 43, 42)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "http://www.delta.com".into(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "www.delta.com".into(),
-                            position: Some(Position::new(1, 43, 42, 1, 56, 55))
+                            position: Some(Position::new(1, 43, 42, 1, 56, 55)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 43, 42, 1, 56, 55))
+                        position: Some(Position::new(1, 43, 42, 1, 56, 55)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " d ".into(),
-                        position: Some(Position::new(1, 56, 55, 1, 59, 58))
+                        position: Some(Position::new(1, 56, 55, 1, 59, 58)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "xmpp:echo@foxtrot.com".into(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "xmpp:echo@foxtrot.com".into(),
-                            position: Some(Position::new(1, 59, 58, 1, 80, 79))
+                            position: Some(Position::new(1, 59, 58, 1, 80, 79)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 59, 58, 1, 80, 79))
+                        position: Some(Position::new(1, 59, 58, 1, 80, 79)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " e ".into(),
-                        position: Some(Position::new(1, 80, 79, 1, 83, 82))
+                        position: Some(Position::new(1, 80, 79, 1, 83, 82)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "mailto:golf@hotel.com".into(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "mailto:golf@hotel.com".into(),
-                            position: Some(Position::new(1, 83, 82, 1, 104, 103))
+                            position: Some(Position::new(1, 83, 82, 1, 104, 103)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 83, 82, 1, 104, 103))
+                        position: Some(Position::new(1, 83, 82, 1, 104, 103)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " f.".into(),
-                        position: Some(Position::new(1, 104, 103, 1, 107, 106))
+                        position: Some(Position::new(1, 104, 103, 1, 107, 106)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 107, 106))
+                position: Some(Position::new(1, 1, 0, 1, 107, 106)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 107, 106))
+            position: Some(Position::new(1, 1, 0, 1, 107, 106)),
+            raw: None,
         }),
         "should support GFM autolink literals as `Link`s in mdast"
     );
 
+    assert_eq!(
+        to_html_with_options("| see www.example.com |\n| - |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>see <a href=\"http://www.example.com\">www.example.com</a></th>\n</tr>\n</thead>\n</table>",
+        "should support www autolinks in table cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n|www.example.com|", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><a href=\"http://www.example.com\">www.example.com</a></td>\n</tr>\n</tbody>\n</table>",
+        "should support www autolinks next to pipes in table cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n|https://a.b/c|user@example.com|", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><a href=\"https://a.b/c\">https://a.b/c</a></td>\n<td><a href=\"mailto:user@example.com\">user@example.com</a></td>\n</tr>\n</tbody>\n</table>",
+        "should support protocol and email autolinks next to pipes in table cells"
+    );
+
+    assert_eq!(
+        to_html_with_options("# visit www.example.com", &Options::gfm())?,
+        "<h1>visit <a href=\"http://www.example.com\">www.example.com</a></h1>",
+        "should support www autolinks in ATX headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("# visit www.example.com ##", &Options::gfm())?,
+        "<h1>visit <a href=\"http://www.example.com\">www.example.com</a></h1>",
+        "should support www autolinks before a closing sequence in ATX headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a user@example.com #", &Options::gfm())?,
+        "<h1>a <a href=\"mailto:user@example.com\">user@example.com</a></h1>",
+        "should support email autolinks before a closing sequence in ATX headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("# www.example.com#", &Options::gfm())?,
+        "<h1><a href=\"http://www.example.com#\">www.example.com#</a></h1>",
+        "should include a number sign directly after a www autolink in ATX headings (not a closing sequence)"
+    );
+
+    assert_eq!(
+        to_html_with_options("visit https://example.com\n===", &Options::gfm())?,
+        "<h1>visit <a href=\"https://example.com\">https://example.com</a></h1>",
+        "should support protocol autolinks in setext headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("*visit www.example.com now*", &Options::gfm())?,
+        "<p><em>visit <a href=\"http://www.example.com\">www.example.com</a> now</em></p>",
+        "should support www autolinks in the middle of emphasis"
+    );
+
+    assert_eq!(
+        to_html_with_options("*www.example.com now*", &Options::gfm())?,
+        "<p><em><a href=\"http://www.example.com\">www.example.com</a> now</em></p>",
+        "should support www autolinks at the start of emphasis"
+    );
+
+    assert_eq!(
+        to_html_with_options("*visit www.example.com*", &Options::gfm())?,
+        "<p><em>visit <a href=\"http://www.example.com\">www.example.com</a></em></p>",
+        "should support www autolinks at the end of emphasis (next to the closing marker)"
+    );
+
+    assert_eq!(
+        to_html_with_options("*visit www.example.com.*", &Options::gfm())?,
+        "<p><em>visit <a href=\"http://www.example.com\">www.example.com</a>.</em></p>",
+        "should support www autolinks before trailing punctuation and the closing marker of emphasis"
+    );
+
+    assert_eq!(
+        to_html_with_options("_visit https://example.com_", &Options::gfm())?,
+        "<p><em>visit <a href=\"https://example.com\">https://example.com</a></em></p>",
+        "should support protocol autolinks next to the closing marker of emphasis (underscore)"
+    );
+
+    assert_eq!(
+        to_html_with_options("**see user@example.com now**", &Options::gfm())?,
+        "<p><strong>see <a href=\"mailto:user@example.com\">user@example.com</a> now</strong></p>",
+        "should support email autolinks in the middle of strong"
+    );
+
+    assert_eq!(
+        to_html_with_options("**user@example.com**", &Options::gfm())?,
+        "<p><strong><a href=\"mailto:user@example.com\">user@example.com</a></strong></p>",
+        "should support email autolinks next to both markers of strong"
+    );
+
+    assert_eq!(
+        to_html_with_options("__www.example.com__", &Options::gfm())?,
+        "<p><strong><a href=\"http://www.example.com\">www.example.com</a></strong></p>",
+        "should support www autolinks next to both markers of strong (underscore)"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~visit www.example.com~~", &Options::gfm())?,
+        "<p><del>visit <a href=\"http://www.example.com\">www.example.com</a></del></p>",
+        "should support www autolinks next to the closing marker of strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("~https://example.com now~", &Options::gfm())?,
+        "<p><del><a href=\"https://example.com\">https://example.com</a> now</del></p>",
+        "should support protocol autolinks at the start of strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("***www.example.com***", &Options::gfm())?,
+        "<p><em><strong><a href=\"http://www.example.com\">www.example.com</a></strong></em></p>",
+        "should support www autolinks in emphasis and strong"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a www.example.com* b", &Options::gfm())?,
+        "<p><em>a <a href=\"http://www.example.com\">www.example.com</a></em> b</p>",
+        "should support www autolinks before the closing marker of emphasis, followed by more text"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn gfm_autolink_literal_linkify() -> Result<(), message::Message> {
+    let options = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            allow_dangerous_protocol: true,
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        linkify("a b c"),
+        vec![],
+        "should find nothing in text without links"
+    );
+
+    assert_eq!(
+        linkify("a https://example.com b"),
+        vec![LinkSpan {
+            start: 2,
+            end: 21,
+            kind: LinkSpanKind::Protocol,
+            href: "https://example.com".into()
+        }],
+        "should find protocol links"
+    );
+
+    assert_eq!(
+        linkify("a www.example.com b"),
+        vec![LinkSpan {
+            start: 2,
+            end: 17,
+            kind: LinkSpanKind::Www,
+            href: "http://www.example.com".into()
+        }],
+        "should find www links, with `http://` prepended"
+    );
+
+    assert_eq!(
+        linkify("a user@example.com b"),
+        vec![LinkSpan {
+            start: 2,
+            end: 18,
+            kind: LinkSpanKind::Email,
+            href: "mailto:user@example.com".into()
+        }],
+        "should find email links, with `mailto:` prepended"
+    );
+
+    assert_eq!(
+        linkify("mailto:a@b.c xmpp:a@b.c/d"),
+        vec![
+            LinkSpan {
+                start: 0,
+                end: 12,
+                kind: LinkSpanKind::Mailto,
+                href: "mailto:a@b.c".into()
+            },
+            LinkSpan {
+                start: 13,
+                end: 25,
+                kind: LinkSpanKind::Xmpp,
+                href: "xmpp:a@b.c/d".into()
+            }
+        ],
+        "should find `mailto:` and `xmpp:` links"
+    );
+
+    assert_eq!(
+        linkify("*www.a.com* _www.b.com_ [www.c.com](d)"),
+        vec![
+            LinkSpan {
+                start: 1,
+                end: 10,
+                kind: LinkSpanKind::Www,
+                href: "http://www.a.com".into()
+            },
+            LinkSpan {
+                start: 13,
+                end: 22,
+                kind: LinkSpanKind::Www,
+                href: "http://www.b.com".into()
+            },
+            LinkSpan {
+                start: 25,
+                end: 34,
+                kind: LinkSpanKind::Www,
+                href: "http://www.c.com".into()
+            }
+        ],
+        "should not treat the text as markdown"
+    );
+
+    // Plain text, without other markdown syntax, must give the same links
+    // as a full parse.
+    let fixtures = [
+        "https://example.com",
+        "www.example.com",
+        "user@example.com",
+        "www.a.)",
+        "www.a b",
+        "www.a/b c",
+        "http://點看.com",
+        "www.點看.com",
+        "點看@example.com",
+        "example@點看.com",
+        "www.a.com/點看",
+        "www.-a.b",
+        "www.$",
+        "www.a..b.c",
+        "https://a.bc/d/e/).",
+        "https://a.bc/d/e/.)",
+        "https://a.bc).",
+        "https://a.bc).d",
+        "https://a.bc/))d",
+        "ftp://a/b/c.txt",
+        "，https://example.com",
+        "a www.example.com&xxx;b c",
+        "a www.example.com&xxx;. b",
+        "a www.example.com&xxx. b",
+        "a www.example.com& b",
+        "mailto:scyther@pokemon.com.",
+        "mmmmailto:scyther@pokemon.com",
+        "mailto:scyther@pokemon.com/mailto:beedrill@pokemon.com",
+        "xmpp:scyther@pokemon.com/message.",
+        "Email me at:scyther@pokemon.com",
+        "a@b.c-, a@b.c., a@b.c+",
+        "See www.example.com/a(b)c), https://x.y?z=1!, and me@home.org.",
+    ];
+
+    for fixture in fixtures {
+        assert_eq!(
+            linkify_to_html(fixture),
+            to_html_with_options(fixture, &options)?,
+            "should match a full parse for `{}`",
+            fixture
+        );
+    }
+
     Ok(())
 }
+
+/// Turn the links found in plain text into HTML, like a full parse would.
+fn linkify_to_html(value: &str) -> String {
+    let mut result = String::from("<p>");
+    let mut index = 0;
+
+    for span in linkify(value) {
+        result.push_str(&encode(&value[index..span.start]));
+        result.push_str("<a href=\"");
+        result.push_str(&sanitize(&span.href));
+        result.push_str("\">");
+        result.push_str(&encode(&value[span.start..span.end]));
+        result.push_str("</a>");
+        index = span.end;
+    }
+
+    result.push_str(&encode(&value[index..]));
+    result.push_str("</p>");
+    result
+}
+
+fn encode(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
@@ -1,8 +1,11 @@
 use markdown::{
-    mdast::{FootnoteDefinition, FootnoteReference, Node, Paragraph, Root, Text},
+    mdast::{
+        Definition, FootnoteDefinition, FootnoteReference, LinkReference, Node, Paragraph,
+        ReferenceKind, Root, Text,
+    },
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
-    CompileOptions, Options, ParseOptions,
+    CompileOptions, Constructs, FootnoteNumbering, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -121,6 +124,162 @@ fn gfm_footnote() -> Result<(), message::Message> {
         "should support `options.gfm_footnote_clobber_prefix`"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "[^a]\n\n[^a]: b",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_clobber_prefix: Some("x y\"-".into()),
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p><sup><a href=\"#x%20y%22-fn-a\" id=\"x%20y%22-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"x%20y%22-fn-a\">
+<p>b <a href=\"#x%20y%22-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should sanitize `options.gfm_footnote_clobber_prefix` like labels"
+    );
+
+    for prefix in ["a b-", "ü-", "%-", "100%-", "%20-", "\"'-", "<&>-", "#-"] {
+        let result = to_html_with_options(
+            "[^a-b] [^a-b] [^ü] [^%]\n\n[^a-b]: c\n[^ü]: d\n[^%]: e",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_clobber_prefix: Some(prefix.into()),
+                    ..CompileOptions::gfm()
+                },
+            },
+        )?;
+        let ids = result
+            .split(" id=\"")
+            .skip(1)
+            .map(|d| d.split('"').next().unwrap())
+            .collect::<Vec<_>>();
+        let hrefs = result
+            .split(" href=\"#")
+            .skip(1)
+            .map(|d| d.split('"').next().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            hrefs.len(),
+            8,
+            "should have links for prefix `{}` (calls and backreferences)",
+            prefix
+        );
+
+        for href in hrefs {
+            assert!(
+                ids.contains(&href),
+                "should link to an element w/ the exact id for prefix `{}` (`{}` in {:?})",
+                prefix,
+                href,
+                ids
+            );
+        }
+
+        for id in ids {
+            assert!(
+                !id.contains(|d: char| d.is_whitespace() || !d.is_ascii()),
+                "should not have whitespace or non-ASCII in ids for prefix `{}` (`{}`)",
+                prefix,
+                id
+            );
+        }
+    }
+
+    assert_eq!(
+        to_html_with_options(
+            "[^a]\n\n[^a]: b",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_clobber_prefix: Some("".into()),
+                    gfm_footnote_id_prefix: Some("c d-".into()),
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p><sup><a href=\"#c%20d-fn-a\" id=\"c%20d-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"c%20d-footnote-label\">1</a></sup></p>
+<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"c%20d-footnote-label\" class=\"sr-only\">Footnotes</h2>
+<ol>
+<li id=\"c%20d-fn-a\">
+<p>b <a href=\"#c%20d-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
+</li>
+</ol>
+</section>
+",
+        "should support `options.gfm_footnote_id_prefix` (sanitized)"
+    );
+
+    let documents = ["comment-1-", "comment-2-"]
+        .iter()
+        .map(|namespace| {
+            to_html_with_options(
+                "a[^1] b[^x] c[^1]\n\n[^1]: d\n[^x]: e",
+                &Options {
+                    parse: ParseOptions::gfm(),
+                    compile: CompileOptions {
+                        gfm_footnote_id_prefix: Some((*namespace).into()),
+                        ..CompileOptions::gfm()
+                    },
+                },
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let attributes = |result: &str, name: &str| {
+        result
+            .split(&format!(" {}=\"", name))
+            .skip(1)
+            .map(|d| {
+                d.split('"')
+                    .next()
+                    .unwrap()
+                    .trim_start_matches('#')
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+    };
+    let ids = documents
+        .iter()
+        .map(|d| attributes(d, "id"))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        ids[0].len(),
+        6,
+        "should have ids for calls, definitions, and the label"
+    );
+
+    for id in &ids[0] {
+        assert!(
+            !ids[1].contains(id),
+            "should not have ids in two documents w/ different `gfm_footnote_id_prefix` (`{}`)",
+            id
+        );
+    }
+
+    for (document, ids) in documents.iter().zip(&ids) {
+        for name in ["href", "aria-describedby"] {
+            for target in attributes(document, name) {
+                assert!(
+                    ids.contains(&target),
+                    "should link to an id in the same document w/ `gfm_footnote_id_prefix` (`{}` in {:?})",
+                    target,
+                    ids
+                );
+            }
+        }
+    }
+
     assert_eq!(
         to_html_with_options("A paragraph.\n\n[^a]: whatevs", &Options::gfm())?,
         "<p>A paragraph.</p>\n",
@@ -315,7 +474,7 @@ b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back
 <blockquote>
 <p>b</p>
 </blockquote>
-<a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a>
+<p><a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>
 </li>
 </ol>
 </section>
@@ -524,15 +683,15 @@ j], [^ l], [^m ]</p>
 <ol>
 <li id="user-content-fn-a">
 <h1>b</h1>
-<a href="#user-content-fnref-a" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-a" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-w">
 <h1>w</h1>
-<a href="#user-content-fnref-w" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-w" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-x">
 <h1>x</h1>
-<a href="#user-content-fnref-x" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a> <a href="#user-content-fnref-x-2" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩<sup>2</sup></a>
+<p><a href="#user-content-fnref-x" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a> <a href="#user-content-fnref-x-2" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩<sup>2</sup></a></p>
 </li>
 </ol>
 </section>
@@ -756,7 +915,7 @@ Lazy!
 <ol>
 <li id="user-content-fn-1">
 <hr />
-<a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-2">
 <p>Paragraph. <a href="#user-content-fnref-2" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
@@ -828,27 +987,27 @@ indented delta <a href="#user-content-fnref-2" data-footnote-backref="" aria-lab
 <blockquote>
 <p>golf</p>
 </blockquote>
-<a href="#user-content-fnref-5" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-5" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-6">
 <blockquote>
 <p>hotel</p>
 </blockquote>
-<a href="#user-content-fnref-6" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-6" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-7">
 <blockquote>
 <p>india</p>
 </blockquote>
-<a href="#user-content-fnref-7" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-7" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-8">
 <h1>juliett</h1>
-<a href="#user-content-fnref-8" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-8" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-9">
 <hr />
-<a href="#user-content-fnref-9" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-9" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-10">
 <ul>
@@ -862,7 +1021,7 @@ indented delta <a href="#user-content-fnref-2" data-footnote-backref="" aria-lab
 </ul>
 </li>
 </ul>
-<a href="#user-content-fnref-10" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-10" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 </ol>
 </section>
@@ -1062,6 +1221,64 @@ indented delta <a href="#user-content-fnref-2" data-footnote-backref="" aria-lab
         "should match interrupt like GitHub"
     );
 
+    assert_eq!(
+        to_html_with_options("> quote\n[^a]: note\n\ntext[^a]", &Options::gfm())?,
+        r##"<blockquote>
+<p>quote</p>
+</blockquote>
+<p>text<sup><a href="#user-content-fn-a" id="user-content-fnref-a" data-footnote-ref="" aria-describedby="footnote-label">1</a></sup></p>
+<section data-footnotes="" class="footnotes"><h2 id="footnote-label" class="sr-only">Footnotes</h2>
+<ol>
+<li id="user-content-fn-a">
+<p>note <a href="#user-content-fnref-a" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
+</li>
+</ol>
+</section>
+"##,
+        "should not support a definition as lazy continuation of a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("* item\n[^a]: note\n\ntext[^a]", &Options::gfm())?,
+        r##"<ul>
+<li>item</li>
+</ul>
+<p>text<sup><a href="#user-content-fn-a" id="user-content-fnref-a" data-footnote-ref="" aria-describedby="footnote-label">1</a></sup></p>
+<section data-footnotes="" class="footnotes"><h2 id="footnote-label" class="sr-only">Footnotes</h2>
+<ol>
+<li id="user-content-fn-a">
+<p>note <a href="#user-content-fnref-a" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
+</li>
+</ol>
+</section>
+"##,
+        "should not support a definition as lazy continuation of a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("para\n[^a]: note\n\ntext[^a]", &Options::gfm())?,
+        r##"<p>para</p>
+<p>text<sup><a href="#user-content-fn-a" id="user-content-fnref-a" data-footnote-ref="" aria-describedby="footnote-label">1</a></sup></p>
+<section data-footnotes="" class="footnotes"><h2 id="footnote-label" class="sr-only">Footnotes</h2>
+<ol>
+<li id="user-content-fn-a">
+<p>note <a href="#user-content-fnref-a" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
+</li>
+</ol>
+</section>
+"##,
+        "should support a definition interrupting a paragraph (like GitHub)"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [^a]: note\n[^b]: other\n\ntext[^a][^b]", &Options::gfm())?,
+        to_html_with_options(
+            "> [^a]: note\n\n[^b]: other\n\ntext[^a][^b]",
+            &Options::gfm()
+        )?,
+        "should not support a definition as lazy continuation of a definition in a block quote"
+    );
+
     assert_eq!(
         to_html_with_options(
             r###"What are these[^1], [^2][], and [this][^3].
@@ -1192,7 +1409,7 @@ indented delta <a href="#user-content-fnref-2" data-footnote-backref="" aria-lab
 <li id="user-content-fn-1">
 <p>Paragraph</p>
 <h1>Heading</h1>
-<a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-2">
 <p>Paragraph</p>
@@ -1201,21 +1418,21 @@ indented delta <a href="#user-content-fnref-2" data-footnote-backref="" aria-lab
 
 more code
 </code></pre>
-<a href="#user-content-fnref-2" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-2" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-3">
 <p>Paragraph</p>
 <blockquote>
 <p>block quote</p>
 </blockquote>
-<a href="#user-content-fnref-3" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-3" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-4">
 <p>Paragraph</p>
 <ul>
 <li>list</li>
 </ul>
-<a href="#user-content-fnref-4" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-4" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 </ol>
 </section>
@@ -1345,7 +1562,7 @@ more code
 <li id="user-content-fn-1">
 <p>Paragraph</p>
 <h1>Heading</h1>
-<a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-2">
 <p>Paragraph</p>
@@ -1353,21 +1570,21 @@ more code
 
 more code
 </code></pre>
-<a href="#user-content-fnref-2" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-2" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-3">
 <p>Paragraph</p>
 <blockquote>
 <p>block quote</p>
 </blockquote>
-<a href="#user-content-fnref-3" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-3" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-4">
 <p>Paragraph</p>
 <ul>
 <li>list</li>
 </ul>
-<a href="#user-content-fnref-4" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-4" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 </ol>
 </section>
@@ -1568,7 +1785,7 @@ multi-paragraph list items. <a href="#user-content-fnref-longnote" data-footnote
 <p>a</p>
 <pre><code>8
 </code></pre>
-<a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a>
+<p><a href="#user-content-fnref-1" data-footnote-backref="" aria-label="Back to content" class="data-footnote-backref">↩</a></p>
 </li>
 <li id="user-content-fn-2">
 <p>a</p>
@@ -1621,6 +1838,56 @@ multi-paragraph list items. <a href="#user-content-fnref-longnote" data-footnote
         "should match references and definitions like GitHub"
     );
 
+    let reverse = "a[^c] b[^b] c[^a] d[^c]\n\n[^a]: A\n[^b]: B\n[^c]: C\n[^d]: D";
+
+    assert_eq!(
+        to_html_with_options(reverse, &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-c\" id=\"user-content-fnref-c\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> b<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup> c<sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">3</a></sup> d<sup><a href=\"#user-content-fn-c\" id=\"user-content-fnref-c-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-c\">\n<p>C <a href=\"#user-content-fnref-c\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-c-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>\n</li>\n<li id=\"user-content-fn-b\">\n<p>B <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-a\">\n<p>A <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should number footnotes in call order by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            reverse,
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_numbering: FootnoteNumbering::DefinitionOrder,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p>a<sup><a href=\"#user-content-fn-c\" id=\"user-content-fnref-c\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">3</a></sup> b<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup> c<sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> d<sup><a href=\"#user-content-fn-c\" id=\"user-content-fnref-c-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">3</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>A <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-b\">\n<p>B <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-c\">\n<p>C <a href=\"#user-content-fnref-c\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-c-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>\n</li>\n</ol>\n</section>\n",
+        "should support `gfm_footnote_numbering: FootnoteNumbering::DefinitionOrder`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b] c^[d] e[^f]\n\n[^f]: g\n[^b]: h",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        footnote_inline: true,
+                        ..Constructs::gfm()
+                    },
+                    ..ParseOptions::gfm()
+                },
+                compile: CompileOptions {
+                    gfm_footnote_numbering: FootnoteNumbering::DefinitionOrder,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">3</a></sup> c<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> e<sup><a href=\"#user-content-fn-f\" id=\"user-content-fnref-f\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<p>d <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-f\">\n<p>g <a href=\"#user-content-fnref-f\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-b\">\n<p>h <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should number inline footnotes where they occur w/ `FootnoteNumbering::DefinitionOrder`"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a[^b] | c[^b] |\n| - | - |\n\n[^b]: d", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></th>\n<th>c<sup><a href=\"#user-content-fn-b\" id=\"user-content-fnref-b-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></th>\n</tr>\n</thead>\n</table>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-b\">\n<p>d <a href=\"#user-content-fnref-b\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-b-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>\n</li>\n</ol>\n</section>\n",
+        "should support calls in table cells, each w/ a unique `id` and backreference"
+    );
+
     assert_eq!(
         to_mdast("[^a]: b\n\tc\n\nd [^a] e.", &ParseOptions::gfm())?,
         Node::Root(Root {
@@ -1629,37 +1896,135 @@ multi-paragraph list items. <a href="#user-content-fnref-longnote" data-footnote
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: "b\nc".into(),
-                            position: Some(Position::new(1, 7, 6, 2, 6, 10))
+                            position: Some(Position::new(1, 7, 6, 2, 6, 10)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(1, 7, 6, 2, 6, 10))
+                        position: Some(Position::new(1, 7, 6, 2, 6, 10)),
+                        raw: None,
                     })],
                     identifier: "a".into(),
                     label: Some("a".into()),
-                    position: Some(Position::new(1, 1, 0, 3, 1, 11))
+                    position: Some(Position::new(1, 1, 0, 2, 6, 10)),
+                    raw: None,
                 }),
                 Node::Paragraph(Paragraph {
                     children: vec![
                         Node::Text(Text {
                             value: "d ".into(),
-                            position: Some(Position::new(4, 1, 12, 4, 3, 14))
+                            position: Some(Position::new(4, 1, 12, 4, 3, 14)),
+                            raw: None,
                         }),
                         Node::FootnoteReference(FootnoteReference {
                             identifier: "a".into(),
                             label: Some("a".into()),
-                            position: Some(Position::new(4, 3, 14, 4, 7, 18))
+                            position: Some(Position::new(4, 3, 14, 4, 7, 18)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " e.".into(),
-                            position: Some(Position::new(4, 7, 18, 4, 10, 21))
+                            position: Some(Position::new(4, 7, 18, 4, 10, 21)),
+                            raw: None,
                         })
                     ],
-                    position: Some(Position::new(4, 1, 12, 4, 10, 21))
+                    position: Some(Position::new(4, 1, 12, 4, 10, 21)),
+                    raw: None,
                 })
             ],
-            position: Some(Position::new(1, 1, 0, 4, 10, 21))
+            position: Some(Position::new(1, 1, 0, 4, 10, 21)),
+            raw: None,
         }),
         "should support GFM footnotes as `FootnoteDefinition`, `FootnoteReference`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("[^a]: b [c]\n\n    [c]: d\n\n[^a]", &ParseOptions::gfm())?,
+        Node::Root(Root {
+            children: vec![
+                Node::FootnoteDefinition(FootnoteDefinition {
+                    children: vec![
+                        Node::Paragraph(Paragraph {
+                            children: vec![
+                                Node::Text(Text {
+                                    value: "b ".into(),
+                                    position: Some(Position::new(1, 7, 6, 1, 9, 8)),
+                                    raw: None,
+                                }),
+                                Node::LinkReference(LinkReference {
+                                    children: vec![Node::Text(Text {
+                                        value: "c".into(),
+                                        position: Some(Position::new(1, 10, 9, 1, 11, 10)),
+                                        raw: None,
+                                    })],
+                                    reference_kind: ReferenceKind::Shortcut,
+                                    identifier: "c".into(),
+                                    label: Some("c".into()),
+                                    position: Some(Position::new(1, 9, 8, 1, 12, 11)),
+                                    raw: None,
+                                })
+                            ],
+                            position: Some(Position::new(1, 7, 6, 1, 12, 11)),
+                            raw: None,
+                        }),
+                        Node::Definition(Definition {
+                            url: "d".into(),
+                            title: None,
+                            identifier: "c".into(),
+                            label: Some("c".into()),
+                            position: Some(Position::new(3, 5, 17, 3, 11, 23)),
+                            raw: None,
+                        })
+                    ],
+                    identifier: "a".into(),
+                    label: Some("a".into()),
+                    position: Some(Position::new(1, 1, 0, 3, 11, 23)),
+                    raw: None,
+                }),
+                Node::Paragraph(Paragraph {
+                    children: vec![Node::FootnoteReference(FootnoteReference {
+                        identifier: "a".into(),
+                        label: Some("a".into()),
+                        position: Some(Position::new(5, 1, 25, 5, 5, 29)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(5, 1, 25, 5, 5, 29)),
+                    raw: None,
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 5, 5, 29)),
+            raw: None,
+        }),
+        "should support definitions in GFM footnote definitions as `Definition`s in mdast"
+    );
+
+    assert_eq!(
+        to_html_with_options("[^a]: b [c]\n    [c]: d\n\n[^a]", &Options::gfm())?,
+        "<p><sup><a href=\"#user-content-fn-a\" id=\"user-content-fnref-a\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-a\">\n<p>b [c]\n[c]: d <a href=\"#user-content-fnref-a\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should not support definitions interrupting a paragraph in GFM footnote definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^1]\n\n[^1]: b\n\n    ```js\n    c\n    ```", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<p>b</p>\n<pre><code class=\"language-js\">c\n</code></pre>\n<p><a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should put backreferences in a new paragraph after code, like GitHub"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^1]\n\n[^1]:\n    - b\n    - c", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<ul>\n<li>b</li>\n<li>c</li>\n</ul>\n<p><a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should put backreferences in a new paragraph after lists, like GitHub"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^1]\n\n[^1]: > b", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<blockquote>\n<p>b</p>\n</blockquote>\n<p><a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should put backreferences in a new paragraph after block quotes, like GitHub"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^1]\n\n[^1]: b\n\n    c", &Options::gfm())?,
+        "<p>a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<p>b</p>\n<p>c <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should put backreferences in the last paragraph, like GitHub"
+    );
+
     Ok(())
 }
@@ -7,7 +7,7 @@ use markdown::{
     mdast::{Delete, Node, Paragraph, Root, Text},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
-    Options, ParseOptions,
+    GfmStrikethroughAllowed, Options, ParseOptions,
 };
 use pretty_assertions::assert_eq;
 
@@ -49,6 +49,54 @@ fn gfm_strikethrough() -> Result<(), message::Message> {
         "should support nested strikethrough"
     );
 
+    assert_eq!(
+        to_html_with_options("**a ~~b** c~~", &Options::gfm())?,
+        "<p><strong>a ~~b</strong> c~~</p>",
+        "should not let strikethrough cross strong (1)"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~a **b~~ c**", &Options::gfm())?,
+        "<p><del>a **b</del> c**</p>",
+        "should not let strong cross strikethrough (1)"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a ~~b* c~~", &Options::gfm())?,
+        "<p><em>a ~~b</em> c~~</p>",
+        "should not let strikethrough cross emphasis"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~a *b~~ c*", &Options::gfm())?,
+        "<p><del>a *b</del> c*</p>",
+        "should not let emphasis cross strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options("__a ~~b__ c~~", &Options::gfm())?,
+        "<p><strong>a ~~b</strong> c~~</p>",
+        "should not let strikethrough cross strong (2)"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~a __b~~ c__", &Options::gfm())?,
+        "<p><del>a __b</del> c__</p>",
+        "should not let strong cross strikethrough (2)"
+    );
+
+    assert_eq!(
+        to_html_with_options("**a ~~b~~ c**", &Options::gfm())?,
+        "<p><strong>a <del>b</del> c</strong></p>",
+        "should support strikethrough in strong"
+    );
+
+    assert_eq!(
+        to_html_with_options("~~a **b** c~~", &Options::gfm())?,
+        "<p><del>a <strong>b</strong> c</del></p>",
+        "should support strong in strikethrough"
+    );
+
     assert_eq!(
         to_html_with_options("a ~-1~ b", &Options::gfm())?,
         "<p>a <del>-1</del> b</p>",
@@ -405,6 +453,88 @@ u ~**xxx**~ zzz
         "should support strikethrough w/ one tilde if `singleTilde: true`"
     );
 
+    assert_eq!(
+        to_html_with_options("a ~b~ ~~c~~ ~~~d~~~ e", &Options::gfm())?,
+        "<p>a <del>b</del> <del>c</del> ~~~d~~~ e</p>",
+        "should support strikethrough w/ one or two tildes by default (`GfmStrikethroughAllowed::Both`)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ~b~ ~~c~~ ~~~d~~~ e",
+            &Options {
+                parse: ParseOptions {
+                    gfm_strikethrough_allowed: GfmStrikethroughAllowed::SingleOnly,
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p>a <del>b</del> ~~c~~ ~~~d~~~ e</p>",
+        "should support strikethrough w/ only one tilde w/ `GfmStrikethroughAllowed::SingleOnly`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ~b~ ~~c~~ ~~~d~~~ e",
+            &Options {
+                parse: ParseOptions {
+                    gfm_strikethrough_allowed: GfmStrikethroughAllowed::DoubleOnly,
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p>a ~b~ <del>c</del> ~~~d~~~ e</p>",
+        "should support strikethrough w/ only two tildes w/ `GfmStrikethroughAllowed::DoubleOnly`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ~b~ ~~c~~ ~~~d~~~ e",
+            &Options {
+                parse: ParseOptions {
+                    gfm_strikethrough_single_tilde: false,
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p>a ~b~ <del>c</del> ~~~d~~~ e</p>",
+        "should map `gfm_strikethrough_single_tilde: false` to `GfmStrikethroughAllowed::DoubleOnly`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a ~b~ ~~c~~ ~~~d~~~ e",
+            &Options {
+                parse: ParseOptions {
+                    gfm_strikethrough_single_tilde: false,
+                    gfm_strikethrough_allowed: GfmStrikethroughAllowed::SingleOnly,
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p>a ~b~ ~~c~~ ~~~d~~~ e</p>",
+        "should not support strikethrough w/ `gfm_strikethrough_single_tilde: false` and `GfmStrikethroughAllowed::SingleOnly`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "~~a~ b~ *~~c~~*",
+            &Options {
+                parse: ParseOptions {
+                    gfm_strikethrough_allowed: GfmStrikethroughAllowed::SingleOnly,
+                    ..ParseOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p>~~a~ b~ <em>~~c~~</em></p>",
+        "should not match sequences of different sizes w/ `GfmStrikethroughAllowed::SingleOnly`"
+    );
+
     assert_eq!(
         to_mdast("a ~~alpha~~ b.", &ParseOptions::gfm())?,
         Node::Root(Root {
@@ -412,23 +542,29 @@ u ~**xxx**~ zzz
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::Delete(Delete {
                         children: vec![Node::Text(Text {
                             value: "alpha".into(),
-                            position: Some(Position::new(1, 5, 4, 1, 10, 9))
+                            position: Some(Position::new(1, 5, 4, 1, 10, 9)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 3, 2, 1, 12, 11))
+                        position: Some(Position::new(1, 3, 2, 1, 12, 11)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b.".into(),
-                        position: Some(Position::new(1, 12, 11, 1, 15, 14))
+                        position: Some(Position::new(1, 12, 11, 1, 15, 14)),
+                        raw: None,
                     }),
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 15, 14))
+                position: Some(Position::new(1, 1, 0, 1, 15, 14)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 15, 14))
+            position: Some(Position::new(1, 1, 0, 1, 15, 14)),
+            raw: None,
         }),
         "should support GFM strikethrough as `Delete`s in mdast"
     );
@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{AlignKind, InlineCode, Node, Root, Table, TableCell, TableRow, Text},
+    mdast::{AlignKind, InlineCode, InlineMath, Node, Root, Table, TableCell, TableRow, Text},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -1856,88 +1856,113 @@ normal escape: <a href="https://github.com/github/cmark-gfm/issues/277">https://
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "none".into(),
-                                    position: Some(Position::new(1, 3, 2, 1, 7, 6))
+                                    position: Some(Position::new(1, 3, 2, 1, 7, 6)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(1, 1, 0, 1, 8, 7))
+                                position: Some(Position::new(1, 1, 0, 1, 8, 7)),
+                                raw: None,
                             }),
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "left".into(),
-                                    position: Some(Position::new(1, 10, 9, 1, 14, 13))
+                                    position: Some(Position::new(1, 10, 9, 1, 14, 13)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(1, 8, 7, 1, 15, 14))
+                                position: Some(Position::new(1, 8, 7, 1, 15, 14)),
+                                raw: None,
                             }),
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "right".into(),
-                                    position: Some(Position::new(1, 17, 16, 1, 22, 21))
+                                    position: Some(Position::new(1, 17, 16, 1, 22, 21)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(1, 15, 14, 1, 23, 22))
+                                position: Some(Position::new(1, 15, 14, 1, 23, 22)),
+                                raw: None,
                             }),
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "center".into(),
-                                    position: Some(Position::new(1, 25, 24, 1, 31, 30))
+                                    position: Some(Position::new(1, 25, 24, 1, 31, 30)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(1, 23, 22, 1, 33, 32))
+                                position: Some(Position::new(1, 23, 22, 1, 33, 32)),
+                                raw: None,
                             }),
                         ],
-                        position: Some(Position::new(1, 1, 0, 1, 33, 32))
+                        position: Some(Position::new(1, 1, 0, 1, 33, 32)),
+                        raw: None,
                     }),
                     Node::TableRow(TableRow {
                         children: vec![Node::TableCell(TableCell {
                             children: vec![Node::Text(Text {
                                 value: "a".into(),
-                                position: Some(Position::new(3, 3, 57, 3, 4, 58))
+                                position: Some(Position::new(3, 3, 57, 3, 4, 58)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(3, 1, 55, 3, 6, 60))
+                            position: Some(Position::new(3, 1, 55, 3, 6, 60)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(3, 1, 55, 3, 6, 60))
+                        position: Some(Position::new(3, 1, 55, 3, 6, 60)),
+                        raw: None,
                     }),
                     Node::TableRow(TableRow {
                         children: vec![
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "b".into(),
-                                    position: Some(Position::new(4, 3, 63, 4, 4, 64))
+                                    position: Some(Position::new(4, 3, 63, 4, 4, 64)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(4, 1, 61, 4, 5, 65))
+                                position: Some(Position::new(4, 1, 61, 4, 5, 65)),
+                                raw: None,
                             }),
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "c".into(),
-                                    position: Some(Position::new(4, 7, 67, 4, 8, 68))
+                                    position: Some(Position::new(4, 7, 67, 4, 8, 68)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(4, 5, 65, 4, 9, 69))
+                                position: Some(Position::new(4, 5, 65, 4, 9, 69)),
+                                raw: None,
                             }),
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "d".into(),
-                                    position: Some(Position::new(4, 11, 71, 4, 12, 72))
+                                    position: Some(Position::new(4, 11, 71, 4, 12, 72)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(4, 9, 69, 4, 13, 73))
+                                position: Some(Position::new(4, 9, 69, 4, 13, 73)),
+                                raw: None,
                             }),
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "e".into(),
-                                    position: Some(Position::new(4, 15, 75, 4, 16, 76))
+                                    position: Some(Position::new(4, 15, 75, 4, 16, 76)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(4, 13, 73, 4, 17, 77))
+                                position: Some(Position::new(4, 13, 73, 4, 17, 77)),
+                                raw: None,
                             }),
                             Node::TableCell(TableCell {
                                 children: vec![Node::Text(Text {
                                     value: "f".into(),
-                                    position: Some(Position::new(4, 19, 79, 4, 20, 80))
+                                    position: Some(Position::new(4, 19, 79, 4, 20, 80)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(4, 17, 77, 4, 22, 82))
+                                position: Some(Position::new(4, 17, 77, 4, 22, 82)),
+                                raw: None,
                             }),
                         ],
-                        position: Some(Position::new(4, 1, 61, 4, 22, 82))
+                        position: Some(Position::new(4, 1, 61, 4, 22, 82)),
+                        raw: None,
                     }),
                 ],
-                position: Some(Position::new(1, 1, 0, 4, 22, 82))
+                position: Some(Position::new(1, 1, 0, 4, 22, 82)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 4, 22, 82))
+            position: Some(Position::new(1, 1, 0, 4, 22, 82)),
+            raw: None,
         }),
         "should support GFM tables as `Table`, `TableRow`, `TableCell`s in mdast"
     );
@@ -1951,18 +1976,357 @@ normal escape: <a href="https://github.com/github/cmark-gfm/issues/277">https://
                     children: vec![Node::TableCell(TableCell {
                         children: vec![Node::InlineCode(InlineCode {
                             value: "a|b".into(),
-                            position: Some(Position::new(1, 3, 2, 1, 9, 8))
+                            position: Some(Position::new(1, 3, 2, 1, 9, 8)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                        position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                        raw: None,
                     }),],
-                    position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                    position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                    raw: None,
                 }),],
-                position: Some(Position::new(1, 1, 0, 2, 6, 16))
+                position: Some(Position::new(1, 1, 0, 2, 6, 16)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 6, 16))
+            position: Some(Position::new(1, 1, 0, 2, 6, 16)),
+            raw: None,
         }),
         "should support weird pipe escapes in code in tables"
     );
 
     Ok(())
 }
+
+#[test]
+fn gfm_table_normalize_cells() -> Result<(), message::Message> {
+    let options = ParseOptions {
+        gfm_table_normalize_cells: true,
+        ..ParseOptions::gfm()
+    };
+
+    assert_eq!(
+        to_mdast("| a | b |\n| - | - |\n| c |", &options)?,
+        Node::Root(Root {
+            children: vec![Node::Table(Table {
+                align: vec![AlignKind::None, AlignKind::None],
+                children: vec![
+                    Node::TableRow(TableRow {
+                        children: vec![
+                            Node::TableCell(TableCell {
+                                children: vec![Node::Text(Text {
+                                    value: "a".into(),
+                                    position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                                    raw: None,
+                                }),],
+                                position: Some(Position::new(1, 1, 0, 1, 5, 4)),
+                                raw: None,
+                            }),
+                            Node::TableCell(TableCell {
+                                children: vec![Node::Text(Text {
+                                    value: "b".into(),
+                                    position: Some(Position::new(1, 7, 6, 1, 8, 7)),
+                                    raw: None,
+                                }),],
+                                position: Some(Position::new(1, 5, 4, 1, 10, 9)),
+                                raw: None,
+                            }),
+                        ],
+                        position: Some(Position::new(1, 1, 0, 1, 10, 9)),
+                        raw: None,
+                    }),
+                    Node::TableRow(TableRow {
+                        children: vec![
+                            Node::TableCell(TableCell {
+                                children: vec![Node::Text(Text {
+                                    value: "c".into(),
+                                    position: Some(Position::new(3, 3, 22, 3, 4, 23)),
+                                    raw: None,
+                                }),],
+                                position: Some(Position::new(3, 1, 20, 3, 6, 25)),
+                                raw: None,
+                            }),
+                            Node::TableCell(TableCell {
+                                children: vec![],
+                                position: None,
+                                raw: None,
+                            }),
+                        ],
+                        position: Some(Position::new(3, 1, 20, 3, 6, 25)),
+                        raw: None,
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 3, 6, 25)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 6, 25)),
+            raw: None,
+        }),
+        "should pad short rows w/ empty cells w/o position w/ `gfm_table_normalize_cells`"
+    );
+
+    for value in [
+        "| a | b |\n| - | - |\n| c |",
+        "| a | b |\n| - | - |\n| c | d | e |",
+        "| a | b |\n| - | - |\n|",
+        "| a | b | c |\n| - | - | - |\n| d |\n| e | f | g | h |\n| |\n| i | j |",
+        "| a |\n| - |\n| b | c |",
+    ] {
+        let html = to_html_with_options(value, &Options::gfm())?;
+        let html_cells = html
+            .split("<tr>")
+            .skip(1)
+            .map(|row| row.matches("<th>").count() + row.matches("<td>").count())
+            .collect::<Vec<_>>();
+        let cells = |options: &ParseOptions| -> Result<Vec<usize>, message::Message> {
+            let tree = to_mdast(value, options)?;
+            Ok(tree.children().unwrap()[0]
+                .children()
+                .unwrap()
+                .iter()
+                .map(|row| row.children().unwrap().len())
+                .collect())
+        };
+
+        assert_eq!(
+            cells(&options)?,
+            html_cells,
+            "should have as many cells in mdast as in HTML w/ `gfm_table_normalize_cells` ({:?})",
+            value
+        );
+
+        assert_eq!(
+            cells(&ParseOptions::gfm())?.len(),
+            html_cells.len(),
+            "should have as many rows in mdast as in HTML w/o `gfm_table_normalize_cells` ({:?})",
+            value
+        );
+    }
+
+    assert_ne!(
+        to_mdast("| a | b |\n| - | - |\n| c |", &ParseOptions::gfm())?,
+        to_mdast("| a | b |\n| - | - |\n| c |", &options)?,
+        "should not normalize cells by default"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn gfm_table_many_columns() -> Result<(), message::Message> {
+    let size = 300;
+    let mut delimiter = "| :-- ".repeat(size - 1);
+    delimiter.push_str("| --: |");
+    let value = format!(
+        "{}|\n{}\n{}|",
+        "| a ".repeat(size),
+        delimiter,
+        "| b ".repeat(size)
+    );
+
+    let tree = to_mdast(&value, &ParseOptions::gfm())?;
+    let align = match &tree.children().unwrap()[0] {
+        Node::Table(table) => table.align.clone(),
+        _ => unreachable!("expected table"),
+    };
+
+    assert_eq!(align.len(), size, "should support more than 255 columns");
+
+    assert_eq!(
+        (align[255], align[256], align[size - 1]),
+        (AlignKind::Left, AlignKind::Left, AlignKind::Right),
+        "should support alignment of columns after the 255th"
+    );
+
+    let html = to_html_with_options(&value, &Options::gfm())?;
+
+    assert_eq!(
+        (
+            html.matches("<th align=\"left\">").count(),
+            html.matches("<td align=\"right\">").count()
+        ),
+        (size - 1, 1),
+        "should support alignment of columns after the 255th in HTML"
+    );
+
+    assert!(
+        html.ends_with(
+            "<td align=\"left\">b</td>\n<td align=\"right\">b</td>\n</tr>\n</tbody>\n</table>"
+        ),
+        "should align the last column in HTML"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn gfm_table_escaped_backslash() -> Result<(), message::Message> {
+    /// Get the number of cells in each row.
+    fn cells(value: &str) -> Result<Vec<usize>, message::Message> {
+        let tree = to_mdast(value, &ParseOptions::gfm())?;
+        match &tree.children().unwrap()[0] {
+            Node::Table(table) => Ok(table
+                .children
+                .iter()
+                .map(|row| row.children().unwrap().len())
+                .collect()),
+            _ => unreachable!("expected table"),
+        }
+    }
+
+    assert_eq!(
+        to_html_with_options("| \\\\ | b |\n| - | - |\n| 1 | 2 |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>\\</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>1</td>\n<td>2</td>\n</tr>\n</tbody>\n</table>",
+        "should support a cell w/ just an escaped backslash"
+    );
+
+    assert_eq!(
+        cells("| \\\\ | b |\n| - | - |\n| 1 | 2 |")?,
+        vec![2, 2],
+        "should support a cell w/ just an escaped backslash (mdast)"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a\\\\| b |\n| - | - |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a\\</th>\n<th>b</th>\n</tr>\n</thead>\n</table>",
+        "should not escape a pipe after an escaped backslash (`\\\\|`)"
+    );
+
+    assert_eq!(
+        cells("| a\\\\| b |\n| - | - |")?,
+        vec![2],
+        "should not escape a pipe after an escaped backslash (`\\\\|`, mdast)"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a\\\\\\| b |\n| - |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a\\| b</th>\n</tr>\n</thead>\n</table>",
+        "should escape a pipe after an escaped backslash and a backslash (`\\\\\\|`)"
+    );
+
+    assert_eq!(
+        cells("| a\\\\\\| b |\n| - |")?,
+        vec![1],
+        "should escape a pipe after an escaped backslash and a backslash (`\\\\\\|`, mdast)"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a\\ | b |\n| - | - |\n| 1\\ | 2\\\\ |", &Options::gfm())?,
+        "<table>\n<thead>\n<tr>\n<th>a\\</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>1\\</td>\n<td>2\\</td>\n</tr>\n</tbody>\n</table>",
+        "should support cells ending in backslashes"
+    );
+
+    assert_eq!(
+        cells("| a\\ | b |\n| - | - |\n| 1\\ | 2\\\\ |")?,
+        vec![2, 2],
+        "should support cells ending in backslashes (mdast)"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn gfm_table_pipes_in_code_and_math() -> Result<(), message::Message> {
+    let math = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_flow: true,
+                math_text: true,
+                ..Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+
+    /// Get the children of the cells in the last row.
+    fn last_row(value: &str, options: &ParseOptions) -> Result<Vec<Vec<Node>>, message::Message> {
+        let tree = to_mdast(value, options)?;
+        match &tree.children().unwrap()[0] {
+            Node::Table(table) => Ok(table
+                .children
+                .last()
+                .unwrap()
+                .children()
+                .unwrap()
+                .iter()
+                .map(|cell| cell.children().unwrap().clone())
+                .collect()),
+            _ => unreachable!("expected table"),
+        }
+    }
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| $b\\|c$ |", &math)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><code class=\"language-math math-inline\">b|c</code></td>\n</tr>\n</tbody>\n</table>",
+        "should support escaped pipes in math (text) in tables"
+    );
+
+    assert_eq!(
+        last_row("| a |\n| - |\n| $b\\|c$ |", &math.parse)?,
+        vec![vec![Node::InlineMath(InlineMath {
+            value: "b|c".into(),
+            position: Some(Position::new(3, 3, 14, 3, 9, 20)),
+            raw: None,
+        })]],
+        "should support escaped pipes in math (text) in tables (mdast)"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| $$b\\|c\\|d$$ |", &math)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><code class=\"language-math math-inline\">b|c|d</code></td>\n</tr>\n</tbody>\n</table>",
+        "should support several escaped pipes in math (text) w/ a bigger sequence in tables"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n| `b\\|c` |", &math)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td><code>b|c</code></td>\n</tr>\n</tbody>\n</table>",
+        "should support escaped pipes in code (text) in tables, w/ math on"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a | b |\n| - | - |\n| $c|d$ | `e|f` |", &math)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n<th>b</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>$c</td>\n<td>d$</td>\n</tr>\n</tbody>\n</table>",
+        "should split cells at unescaped pipes in math (text) and code (text)"
+    );
+
+    assert_eq!(
+        last_row("| a | b |\n| - | - |\n| $c|d$ | `e|f` |", &math.parse)?,
+        vec![
+            vec![Node::Text(Text {
+                value: "$c".into(),
+                position: Some(Position::new(3, 3, 22, 3, 5, 24)),
+                raw: None,
+            })],
+            vec![Node::Text(Text {
+                value: "d$".into(),
+                position: Some(Position::new(3, 6, 25, 3, 8, 27)),
+                raw: None,
+            })],
+            vec![Node::Text(Text {
+                value: "`e".into(),
+                position: Some(Position::new(3, 11, 30, 3, 13, 32)),
+                raw: None,
+            })],
+            vec![Node::Text(Text {
+                value: "f`".into(),
+                position: Some(Position::new(3, 14, 33, 3, 16, 35)),
+                raw: None,
+            })],
+        ],
+        "should split cells at unescaped pipes in math (text) and code (text) (mdast)"
+    );
+
+    assert_eq!(
+        to_html_with_options("$a\\|b$", &math)?,
+        "<p><code class=\"language-math math-inline\">a\\|b</code></p>",
+        "should not support escaped pipes in math (text) outside tables"
+    );
+
+    assert_eq!(
+        to_html_with_options("| a |\n| - |\n$$\nb|c\n$$", &math)?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n</table>\n<pre><code class=\"language-math math-display\">b|c\n</code></pre>",
+        "should support math (flow) interrupting tables, w/o splitting at its pipes"
+    );
+
+    Ok(())
+}
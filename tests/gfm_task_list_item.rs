@@ -41,6 +41,37 @@ fn gfm_task_list_item() -> Result<(), message::Message> {
         "should support option for enabled (checkable) task list item checks"
     );
 
+    assert_eq!(
+        to_html_with_options(
+            "* [ ] a\n  * [x] b\n  * [ ] c\n* [x] d",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_task_list_item_data_offset: true,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" data-offset=\"2\" /> a\n<ul>\n<li><input type=\"checkbox\" disabled=\"\" data-offset=\"12\" checked=\"\" /> b</li>\n<li><input type=\"checkbox\" disabled=\"\" data-offset=\"22\" /> c</li>\n</ul>\n</li>\n<li><input type=\"checkbox\" disabled=\"\" data-offset=\"30\" checked=\"\" /> d</li>\n</ul>",
+        "should support option for offsets on task list item checks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "> * [x] a\n> * [ ] b",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_task_list_item_checkable: true,
+                    gfm_task_list_item_data_offset: true,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<blockquote>\n<ul>\n<li><input type=\"checkbox\" data-offset=\"4\" checked=\"\" /> a</li>\n<li><input type=\"checkbox\" data-offset=\"14\" /> b</li>\n</ul>\n</blockquote>",
+        "should support option for offsets on checkable task list item checks"
+    );
+
     assert_eq!(
         to_html_with_options("*\n    [x]", &Options::gfm())?,
         "<ul>\n<li>[x]</li>\n</ul>",
@@ -261,6 +292,7 @@ Text.</li>
                 ordered: false,
                 spread: false,
                 start: None,
+                kind: None,
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: Some(true),
@@ -268,11 +300,15 @@ Text.</li>
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "a".into(),
-                                position: Some(Position::new(1, 7, 6, 1, 8, 7))
+                                position: Some(Position::new(1, 7, 6, 1, 8, 7)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(1, 7, 6, 1, 8, 7))
+                            position: Some(Position::new(1, 7, 6, 1, 8, 7)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(1, 1, 0, 1, 8, 7))
+                        position: Some(Position::new(1, 1, 0, 1, 8, 7)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         checked: Some(false),
@@ -280,11 +316,15 @@ Text.</li>
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "b".into(),
-                                position: Some(Position::new(2, 7, 14, 2, 8, 15))
+                                position: Some(Position::new(2, 7, 14, 2, 8, 15)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(2, 7, 14, 2, 8, 15))
+                            position: Some(Position::new(2, 7, 14, 2, 8, 15)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(2, 1, 8, 2, 8, 15))
+                        position: Some(Position::new(2, 1, 8, 2, 8, 15)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         checked: None,
@@ -292,16 +332,23 @@ Text.</li>
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "c".into(),
-                                position: Some(Position::new(3, 3, 18, 3, 4, 19))
+                                position: Some(Position::new(3, 3, 18, 3, 4, 19)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(3, 3, 18, 3, 4, 19))
+                            position: Some(Position::new(3, 3, 18, 3, 4, 19)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(3, 1, 16, 3, 4, 19))
+                        position: Some(Position::new(3, 1, 16, 3, 4, 19)),
+                        markers: None,
+                        raw: None,
                     }),
                 ],
-                position: Some(Position::new(1, 1, 0, 3, 4, 19))
+                position: Some(Position::new(1, 1, 0, 3, 4, 19)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 4, 19))
+            position: Some(Position::new(1, 1, 0, 3, 4, 19)),
+            raw: None,
         }),
         "should support task list items as `checked` fields on `ListItem`s in mdast"
     );
@@ -316,6 +363,7 @@ Text.</li>
                 ordered: false,
                 spread: false,
                 start: None,
+                kind: None,
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: Some(true),
@@ -323,11 +371,15 @@ Text.</li>
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "a".into(),
-                                position: Some(Position::new(2, 1, 7, 2, 4, 10))
+                                position: Some(Position::new(2, 1, 7, 2, 4, 10)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(2, 1, 7, 2, 4, 10))
+                            position: Some(Position::new(2, 1, 7, 2, 4, 10)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(1, 1, 0, 2, 4, 10))
+                        position: Some(Position::new(1, 1, 0, 2, 4, 10)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         checked: Some(false),
@@ -335,11 +387,15 @@ Text.</li>
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "  b".into(),
-                                position: Some(Position::new(3, 7, 17, 3, 10, 20))
+                                position: Some(Position::new(3, 7, 17, 3, 10, 20)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(3, 7, 17, 3, 10, 20))
+                            position: Some(Position::new(3, 7, 17, 3, 10, 20)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(3, 1, 11, 3, 10, 20))
+                        position: Some(Position::new(3, 1, 11, 3, 10, 20)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         checked: Some(true),
@@ -348,18 +404,27 @@ Text.</li>
                             children: vec![Node::Emphasis(Emphasis {
                                 children: vec![Node::Text(Text {
                                     value: "c".into(),
-                                    position: Some(Position::new(5, 2, 30, 5, 3, 31))
+                                    position: Some(Position::new(5, 2, 30, 5, 3, 31)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(5, 1, 29, 5, 4, 32))
+                                position: Some(Position::new(5, 1, 29, 5, 4, 32)),
+                                markers: None,
+                                raw: None,
                             })],
-                            position: Some(Position::new(5, 1, 29, 5, 4, 32))
+                            position: Some(Position::new(5, 1, 29, 5, 4, 32)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(4, 1, 21, 5, 4, 32))
+                        position: Some(Position::new(4, 1, 21, 5, 4, 32)),
+                        markers: None,
+                        raw: None,
                     }),
                 ],
-                position: Some(Position::new(1, 1, 0, 5, 4, 32))
+                position: Some(Position::new(1, 1, 0, 5, 4, 32)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 5, 4, 32))
+            position: Some(Position::new(1, 1, 0, 5, 4, 32)),
+            raw: None,
         }),
         "should handle lots of whitespace after checkbox, and non-text"
     );
@@ -69,22 +69,105 @@ fn hard_break_escape() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1)),
+                        raw: None,
                     }),
                     Node::Break(Break {
-                        position: Some(Position::new(1, 2, 1, 2, 1, 3))
+                        position: Some(Position::new(1, 2, 1, 2, 1, 3)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: "b.".into(),
-                        position: Some(Position::new(2, 1, 3, 2, 3, 5))
+                        position: Some(Position::new(2, 1, 3, 2, 3, 5)),
+                        raw: None,
                     }),
                 ],
-                position: Some(Position::new(1, 1, 0, 2, 3, 5))
+                position: Some(Position::new(1, 1, 0, 2, 3, 5)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 3, 5))
+            position: Some(Position::new(1, 1, 0, 2, 3, 5)),
+            raw: None,
         }),
         "should support hard break (escape) as `Break`s in mdast"
     );
 
+    assert_eq!(
+        to_html("a\\\r\nb"),
+        "<p>a<br />\r\nb</p>",
+        "should support a hard break (escape) before a CRLF"
+    );
+
+    assert_eq!(
+        to_html("a\\"),
+        "<p>a\\</p>",
+        "should not support a hard break (escape) at the end of the document"
+    );
+
+    assert_eq!(
+        to_html("a\\\r\n"),
+        "<p>a\\</p>\r\n",
+        "should not support a hard break (escape) before a CRLF at the end of the document"
+    );
+
+    assert_eq!(
+        to_html("a\\ \nb"),
+        "<p>a\\\nb</p>",
+        "should not support a hard break (escape) w/ whitespace before the line ending"
+    );
+
+    assert_eq!(
+        to_mdast("a\\", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\\".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+            raw: None,
+        }),
+        "should not support a hard break (escape) at the end of the document in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("a\\\r\n", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\\".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 1, 4)),
+            raw: None,
+        }),
+        "should not support a hard break (escape) before a CRLF at the end of the document in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("a\\ \nb", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\\\nb".into(),
+                    position: Some(Position::new(1, 1, 0, 2, 2, 5)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 2, 5)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 2, 5)),
+            raw: None,
+        }),
+        "should not support a hard break (escape) w/ whitespace before the line ending in mdast"
+    );
+
     Ok(())
 }
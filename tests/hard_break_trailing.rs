@@ -135,19 +135,24 @@ fn hard_break_trailing() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 2, 1))
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1)),
+                        raw: None,
                     }),
                     Node::Break(Break {
-                        position: Some(Position::new(1, 2, 1, 2, 1, 4))
+                        position: Some(Position::new(1, 2, 1, 2, 1, 4)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: "b.".into(),
-                        position: Some(Position::new(2, 1, 4, 2, 3, 6))
+                        position: Some(Position::new(2, 1, 4, 2, 3, 6)),
+                        raw: None,
                     }),
                 ],
-                position: Some(Position::new(1, 1, 0, 2, 3, 6))
+                position: Some(Position::new(1, 1, 0, 2, 3, 6)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 3, 6))
+            position: Some(Position::new(1, 1, 0, 2, 3, 6)),
+            raw: None,
         }),
         "should support hard break (trailing) as `Break`s in mdast"
     );
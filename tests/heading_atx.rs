@@ -206,6 +206,48 @@ fn heading_atx() -> Result<(), message::Message> {
         "should not support lazyness (2)"
     );
 
+    assert_eq!(
+        to_html("# foo\t#"),
+        "<h1>foo</h1>",
+        "should support a tab before a closing sequence"
+    );
+
+    assert_eq!(
+        to_html("# foo \t #"),
+        "<h1>foo</h1>",
+        "should support spaces and tabs before a closing sequence"
+    );
+
+    assert_eq!(
+        to_html("### foo\t###\t\t"),
+        "<h3>foo</h3>",
+        "should support trailing tabs after a closing sequence"
+    );
+
+    assert_eq!(
+        to_html("## foo\t## \t"),
+        "<h2>foo</h2>",
+        "should support trailing spaces and tabs after a closing sequence"
+    );
+
+    assert_eq!(
+        to_html("#\t#"),
+        "<h1></h1>",
+        "should support a tab before a closing sequence in an empty heading"
+    );
+
+    assert_eq!(
+        to_html("# foo\t#b"),
+        "<h1>foo\t#b</h1>",
+        "should not support other content after a closing sequence after a tab"
+    );
+
+    assert_eq!(
+        to_html("# foo\\\t#"),
+        "<h1>foo\\</h1>",
+        "should support a closing sequence after a backslash and a tab"
+    );
+
     assert_eq!(
         to_html_with_options(
             "# a",
@@ -231,14 +273,38 @@ fn heading_atx() -> Result<(), message::Message> {
                 depth: 2,
                 children: vec![Node::Text(Text {
                     value: "alpha".into(),
-                    position: Some(Position::new(1, 4, 3, 1, 9, 8))
+                    position: Some(Position::new(1, 4, 3, 1, 9, 8)),
+                    raw: None,
                 }),],
-                position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 11, 10))
+            position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+            raw: None,
         }),
         "should support heading (atx) as `Heading`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("# alpha\t#\t", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                depth: 1,
+                children: vec![Node::Text(Text {
+                    value: "alpha".into(),
+                    position: Some(Position::new(1, 3, 2, 1, 8, 7)),
+                    raw: None,
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 13, 10)),
+                markers: None,
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 13, 10)),
+            raw: None,
+        }),
+        "should not include a closing sequence after a tab in `Heading`s in mdast"
+    );
+
     Ok(())
 }
@@ -297,6 +297,36 @@ fn heading_setext() -> Result<(), message::Message> {
         "should support turning off setext underlines"
     );
 
+    assert_eq!(
+        to_html("a\n  b\n===\n"),
+        "<h1>a\nb</h1>\n",
+        "should support an indented continuation line"
+    );
+
+    assert_eq!(
+        to_html("a\n  b\n==="),
+        "<h1>a\nb</h1>",
+        "should support an indented continuation line, w/o final line ending"
+    );
+
+    assert_eq!(
+        to_html("a\n\tb\n==="),
+        "<h1>a\nb</h1>",
+        "should support a tab-indented continuation line"
+    );
+
+    assert_eq!(
+        to_html("a\n  b\n   ---"),
+        "<h2>a\nb</h2>",
+        "should support an indented continuation line and an underline indented by 3 spaces"
+    );
+
+    assert_eq!(
+        to_html("a\n  b\n    ---"),
+        "<p>a\nb\n---</p>",
+        "should not support an indented continuation line and an underline indented by 4 spaces"
+    );
+
     assert_eq!(
         to_mdast("alpha\nbravo\n==", &Default::default())?,
         Node::Root(Root {
@@ -304,11 +334,15 @@ fn heading_setext() -> Result<(), message::Message> {
                 depth: 1,
                 children: vec![Node::Text(Text {
                     value: "alpha\nbravo".into(),
-                    position: Some(Position::new(1, 1, 0, 2, 6, 11))
+                    position: Some(Position::new(1, 1, 0, 2, 6, 11)),
+                    raw: None,
                 }),],
-                position: Some(Position::new(1, 1, 0, 3, 3, 14))
+                position: Some(Position::new(1, 1, 0, 3, 3, 14)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 3, 14))
+            position: Some(Position::new(1, 1, 0, 3, 3, 14)),
+            raw: None,
         }),
         "should support heading (atx) as `Heading`s in mdast"
     );
@@ -0,0 +1,190 @@
+use markdown::{
+    mdast::{List, ListItem, Node, Paragraph, Root, Text, ThematicBreak},
+    message, to_html_with_options, to_mdast,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn heading_setext_off() -> Result<(), message::Message> {
+    let off = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                heading_setext: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a\n---", &off)?,
+        "<p>a</p>\n<hr />",
+        "should support a thematic break after a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n---\nc", &off)?,
+        "<p>a\nb</p>\n<hr />\n<p>c</p>",
+        "should support a thematic break after a multiline paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n===", &off)?,
+        "<p>a\n===</p>",
+        "should support an equals sequence as paragraph continuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n-", &off)?,
+        "<p>a\n-</p>",
+        "should support a single dash as paragraph continuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n  ---  ", &off)?,
+        "<p>a</p>\n<hr />",
+        "should support an indented thematic break after a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  ---", &off)?,
+        "<ul>\n<li>a\n<hr />\n</li>\n</ul>",
+        "should support a thematic break in a list item, like `***`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  ---", &off)?,
+        to_html_with_options("* a\n  ***", &off)?,
+        "should treat `---` in a list item the same as `***`"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  ---\n  b", &off)?,
+        "<ul>\n<li>a\n<hr />\nb</li>\n</ul>",
+        "should support content after a thematic break in a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  ---\n* b", &off)?,
+        "<ul>\n<li>a\n<hr />\n</li>\n<li>b</li>\n</ul>",
+        "should support a next list item after a thematic break in a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("1. a\n   ---", &off)?,
+        "<ol>\n<li>a\n<hr />\n</li>\n</ol>",
+        "should support a thematic break in an ordered list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n---", &off)?,
+        "<ul>\n<li>a</li>\n</ul>\n<hr />",
+        "should not support a lazy thematic break in a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n ---", &off)?,
+        "<ul>\n<li>a</li>\n</ul>\n<hr />",
+        "should not support an underindented thematic break in a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  b\n---", &off)?,
+        "<ul>\n<li>a\nb</li>\n</ul>\n<hr />",
+        "should not support a lazy thematic break after a multiline paragraph in a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("* a\n  ===", &off)?,
+        "<ul>\n<li>a\n===</li>\n</ul>",
+        "should support an equals sequence as paragraph continuation in a list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  - b\n    ---", &off)?,
+        "<ul>\n<li>a\n<ul>\n<li>b\n<hr />\n</li>\n</ul>\n</li>\n</ul>",
+        "should support a thematic break in a nested list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  - b\n  ---", &off)?,
+        "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n<hr />\n</li>\n</ul>",
+        "should support a thematic break closing a nested list item"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> ---", &off)?,
+        "<blockquote>\n<p>a</p>\n<hr />\n</blockquote>",
+        "should support a thematic break in a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n---", &off)?,
+        "<blockquote>\n<p>a</p>\n</blockquote>\n<hr />",
+        "should not support a lazy thematic break in a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n===", &off)?,
+        "<blockquote>\n<p>a\n===</p>\n</blockquote>",
+        "should support a lazy equals sequence in a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("> * a\n>   ---", &off)?,
+        "<blockquote>\n<ul>\n<li>a\n<hr />\n</li>\n</ul>\n</blockquote>",
+        "should support a thematic break in a list item in a block quote"
+    );
+
+    assert_eq!(
+        to_html_with_options("> * a\n> ---", &off)?,
+        "<blockquote>\n<ul>\n<li>a</li>\n</ul>\n<hr />\n</blockquote>",
+        "should support a thematic break closing a list item in a block quote"
+    );
+
+    assert_eq!(
+        to_mdast("* a\n  ---", &off.parse)?,
+        Node::Root(Root {
+            children: vec![Node::List(List {
+                ordered: false,
+                spread: false,
+                start: None,
+                kind: None,
+                children: vec![Node::ListItem(ListItem {
+                    checked: None,
+                    spread: false,
+                    children: vec![
+                        Node::Paragraph(Paragraph {
+                            children: vec![Node::Text(Text {
+                                value: "a".into(),
+                                position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                                raw: None,
+                            })],
+                            position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                            raw: None,
+                        }),
+                        Node::ThematicBreak(ThematicBreak {
+                            position: Some(Position::new(2, 3, 6, 2, 6, 9)),
+                            markers: None,
+                            raw: None,
+                        })
+                    ],
+                    position: Some(Position::new(1, 1, 0, 2, 6, 9)),
+                    markers: None,
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 6, 9)),
+                markers: None,
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 6, 9)),
+            raw: None,
+        }),
+        "should support a thematic break in a list item in mdast"
+    );
+
+    Ok(())
+}
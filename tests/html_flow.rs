@@ -52,9 +52,12 @@ fn html_flow() -> Result<(), message::Message> {
         Node::Root(Root {
             children: vec![Node::Html(Html {
                 value: "<div>\nstuff\n</div>".into(),
-                position: Some(Position::new(1, 1, 0, 3, 7, 18))
+                position: Some(Position::new(1, 1, 0, 3, 7, 18)),
+                kind: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 7, 18))
+            position: Some(Position::new(1, 1, 0, 3, 7, 18)),
+            raw: None,
         }),
         "should support HTML (flow) as `Html`s in mdast"
     );
@@ -340,6 +343,80 @@ fn html_flow_2_comment() -> Result<(), message::Message> {
         "should not support lazyness (2)"
     );
 
+    let comments = Options {
+        compile: CompileOptions {
+            allow_html_comments: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("<!-- toc -->"),
+        "&lt;!-- toc --&gt;",
+        "should encode comments by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- toc -->\n\na", &comments)?,
+        "<!-- toc -->\n<p>a</p>",
+        "should support comments w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- Foo\n\nbar\n   baz -->\nokay", &comments)?,
+        "<!-- Foo\n\nbar\n   baz -->\n<p>okay</p>",
+        "should support multiline comments w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> <!-- a\n> b -->", &comments)?,
+        "<blockquote>\n<!-- a\nb -->\n</blockquote>",
+        "should support comments in containers w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- a -- b -->", &comments)?,
+        "<!-- a -- b -->",
+        "should support comments w/ two dashes inside w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- a -->  ", &comments)?,
+        "<!-- a -->  ",
+        "should support whitespace after comments w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- a", &comments)?,
+        "&lt;!-- a",
+        "should encode unclosed comments w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- foo -->*bar*", &comments)?,
+        "&lt;!-- foo --&gt;*bar*",
+        "should encode comments followed by other things w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!--><script>alert(1)</script>", &comments)?,
+        "&lt;!--&gt;&lt;script&gt;alert(1)&lt;/script&gt;",
+        "should encode comments that browsers close early (`<!-->`) w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<!-- a --!><script>alert(1)</script> -->", &comments)?,
+        "&lt;!-- a --!&gt;&lt;script&gt;alert(1)&lt;/script&gt; --&gt;",
+        "should encode comments that browsers close early (`--!>`) w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>\n<!-- a -->", &comments)?,
+        "&lt;div&gt;\n&lt;!-- a --&gt;",
+        "should encode other HTML w/ `allow_html_comments`"
+    );
+
     Ok(())
 }
 
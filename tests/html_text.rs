@@ -110,6 +110,50 @@ fn html_text() -> Result<(), message::Message> {
         "should support comments w/ two dashes inside"
     );
 
+    let comments = Options {
+        compile: CompileOptions {
+            allow_html_comments: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("a <!-- b --> c <i>d</i>", &comments)?,
+        "<p>a <!-- b --> c &lt;i&gt;d&lt;/i&gt;</p>",
+        "should support comments, but not other HTML, w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("foo <!-- this is a\ncomment - with hyphen -->", &comments)?,
+        "<p>foo <!-- this is a\ncomment - with hyphen --></p>",
+        "should support multiline comments w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <!-- b", &comments)?,
+        "<p>a &lt;!-- b</p>",
+        "should encode unclosed comments w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <!--><script>alert(1)</script>", &comments)?,
+        "<p>a <!-->&lt;script&gt;alert(1)&lt;/script&gt;</p>",
+        "should encode HTML after comments that browsers close early w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <!-- b --!><script>alert(1)</script> -->", &comments)?,
+        "<p>a &lt;!-- b --!&gt;&lt;script&gt;alert(1)&lt;/script&gt; --&gt;</p>",
+        "should encode comments that browsers close early (`--!>`) w/ `allow_html_comments`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a <?b?> <!C> <![CDATA[d]]>", &comments)?,
+        "<p>a &lt;?b?&gt; &lt;!C&gt; &lt;![CDATA[d]]&gt;</p>",
+        "should encode instructions, declarations, and cdata w/ `allow_html_comments`"
+    );
+
     assert_eq!(
         to_html_with_options("foo <!--> foo -->", &danger)?,
         "<p>foo <!--> foo --&gt;</p>",
@@ -451,31 +495,164 @@ to_html_with_options("<x> a", &danger)?,
                 children: vec![
                     Node::Text(Text {
                         value: "alpha ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                        position: Some(Position::new(1, 1, 0, 1, 7, 6)),
+                        raw: None,
                     }),
                     Node::Html(Html {
                         value: "<i>".into(),
-                        position: Some(Position::new(1, 7, 6, 1, 10, 9))
+                        position: Some(Position::new(1, 7, 6, 1, 10, 9)),
+                        kind: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: "bravo".into(),
-                        position: Some(Position::new(1, 10, 9, 1, 15, 14))
+                        position: Some(Position::new(1, 10, 9, 1, 15, 14)),
+                        raw: None,
                     }),
                     Node::Html(Html {
                         value: "</b>".into(),
-                        position: Some(Position::new(1, 15, 14, 1, 19, 18))
+                        position: Some(Position::new(1, 15, 14, 1, 19, 18)),
+                        kind: None,
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " charlie.".into(),
-                        position: Some(Position::new(1, 19, 18, 1, 28, 27))
+                        position: Some(Position::new(1, 19, 18, 1, 28, 27)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 28, 27))
+                position: Some(Position::new(1, 1, 0, 1, 28, 27)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 28, 27))
+            position: Some(Position::new(1, 1, 0, 1, 28, 27)),
+            raw: None,
         }),
         "should support HTML (text) as `Html`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("a <b\nc=\"d\"> e", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
+                    }),
+                    Node::Html(Html {
+                        value: "<b\nc=\"d\">".into(),
+                        position: Some(Position::new(1, 3, 2, 2, 7, 11)),
+                        kind: None,
+                        raw: None,
+                    }),
+                    Node::Text(Text {
+                        value: " e".into(),
+                        position: Some(Position::new(2, 7, 11, 2, 9, 13)),
+                        raw: None,
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 2, 9, 13)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 9, 13)),
+            raw: None,
+        }),
+        "should support a line ending in HTML (text) as one `Html` in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("a <!--b\nc--> d", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
+                    }),
+                    Node::Html(Html {
+                        value: "<!--b\nc-->".into(),
+                        position: Some(Position::new(1, 3, 2, 2, 5, 12)),
+                        kind: None,
+                        raw: None,
+                    }),
+                    Node::Text(Text {
+                        value: " d".into(),
+                        position: Some(Position::new(2, 5, 12, 2, 7, 14)),
+                        raw: None,
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 2, 7, 14)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 7, 14)),
+            raw: None,
+        }),
+        "should support a line ending in an HTML (text) comment as one `Html` in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("a <![CDATA[b\nc]]> d", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
+                    }),
+                    Node::Html(Html {
+                        value: "<![CDATA[b\nc]]>".into(),
+                        position: Some(Position::new(1, 3, 2, 2, 5, 17)),
+                        kind: None,
+                        raw: None,
+                    }),
+                    Node::Text(Text {
+                        value: " d".into(),
+                        position: Some(Position::new(2, 5, 17, 2, 7, 19)),
+                        raw: None,
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 2, 7, 19)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 7, 19)),
+            raw: None,
+        }),
+        "should support a line ending in HTML (text) CDATA as one `Html` in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("a <b\n  c=\"d\"\n  e> f", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
+                    }),
+                    Node::Html(Html {
+                        value: "<b\nc=\"d\"\ne>".into(),
+                        position: Some(Position::new(1, 3, 2, 3, 5, 17)),
+                        kind: None,
+                        raw: None,
+                    }),
+                    Node::Text(Text {
+                        value: " f".into(),
+                        position: Some(Position::new(3, 5, 17, 3, 7, 19)),
+                        raw: None,
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 3, 7, 19)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 7, 19)),
+            raw: None,
+        }),
+        "should support several line endings in HTML (text) as one `Html` in mdast, w/o the indent of lines"
+    );
+
     Ok(())
 }
@@ -235,6 +235,75 @@ fn image() -> Result<(), message::Message> {
         "should allow non-http protocols w/ `allowDangerousProtocol`"
     );
 
+    assert_eq!(
+        to_html("[![*alt*](img.png)](url)"),
+        "<p><a href=\"url\"><img src=\"img.png\" alt=\"alt\" /></a></p>",
+        "should support emphasis in the alt of an image in a link"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![**a** ~~b~~ `c`](i)](u)", &Options::gfm())?,
+        "<p><a href=\"u\"><img src=\"i\" alt=\"a b c\" /></a></p>",
+        "should support strong, strikethrough, and code in the alt of an image in a link"
+    );
+
+    assert_eq!(
+        to_html("[a ![*b* `c`](i) d](u)"),
+        "<p><a href=\"u\">a <img src=\"i\" alt=\"b c\" /> d</a></p>",
+        "should support attention and code in the alt of an image between text in a link"
+    );
+
+    assert_eq!(
+        to_html("*[![_a_ **b**](i)](u)*"),
+        "<p><em><a href=\"u\"><img src=\"i\" alt=\"a b\" /></a></em></p>",
+        "should support attention in the alt of an image in a link in emphasis"
+    );
+
+    assert_eq!(
+        to_html("[![a [*b*](x)](i)](u)"),
+        "<p>[<img src=\"i\" alt=\"a b\" />](u)</p>",
+        "should support a link in the alt of an image, which prevents an outer link"
+    );
+
+    assert_eq!(
+        to_html("[![a&b <c> \"d\"](i)](u)"),
+        "<p><a href=\"u\"><img src=\"i\" alt=\"a&amp;b &lt;c&gt; &quot;d&quot;\" /></a></p>",
+        "should encode the alt of an image in a link once"
+    );
+
+    assert_eq!(
+        to_html("[![a &amp; &lt;b&gt; &quot;c&quot; &#x26;](i)](u)"),
+        "<p><a href=\"u\"><img src=\"i\" alt=\"a &amp; &lt;b&gt; &quot;c&quot; &amp;\" /></a></p>",
+        "should encode character references in the alt of an image in a link once"
+    );
+
+    assert_eq!(
+        to_html("[![a&b][i]][u]\n\n[i]: x\n[u]: y"),
+        "<p><a href=\"y\"><img src=\"x\" alt=\"a&amp;b\" /></a></p>\n",
+        "should encode the alt of an image (reference) in a link (reference) once"
+    );
+
+    assert_eq!(
+        to_html("![a ![b&c](i) &lt;d&gt;](j)"),
+        "<p><img src=\"j\" alt=\"a b&amp;c &lt;d&gt;\" /></p>",
+        "should encode the alt of an image in the alt of an image once"
+    );
+
+    assert_eq!(
+        to_mdast("![a ![b&c](i) &lt;d&gt;](j)", &Default::default())?
+            .children()
+            .and_then(|d| d[0].children())
+            .map(|d| d[0].clone()),
+        Some(Node::Image(Image {
+            alt: "a b&c <d>".into(),
+            url: "j".into(),
+            title: None,
+            position: Some(Position::new(1, 1, 0, 1, 28, 27)),
+            raw: None,
+        })),
+        "should include the alt of an image in the alt of an image in mdast"
+    );
+
     assert_eq!(
         to_mdast(
             "a ![alpha]() b ![bravo](charlie 'delta') c.",
@@ -245,32 +314,39 @@ fn image() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::Image(Image {
                         alt: "alpha".into(),
                         url: String::new(),
                         title: None,
-                        position: Some(Position::new(1, 3, 2, 1, 13, 12))
+                        position: Some(Position::new(1, 3, 2, 1, 13, 12)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b ".into(),
-                        position: Some(Position::new(1, 13, 12, 1, 16, 15))
+                        position: Some(Position::new(1, 13, 12, 1, 16, 15)),
+                        raw: None,
                     }),
                     Node::Image(Image {
                         alt: "bravo".into(),
                         url: "charlie".into(),
                         title: Some("delta".into()),
-                        position: Some(Position::new(1, 16, 15, 1, 41, 40))
+                        position: Some(Position::new(1, 16, 15, 1, 41, 40)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " c.".into(),
-                        position: Some(Position::new(1, 41, 40, 1, 44, 43))
+                        position: Some(Position::new(1, 41, 40, 1, 44, 43)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 44, 43))
+                position: Some(Position::new(1, 1, 0, 1, 44, 43)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 44, 43))
+            position: Some(Position::new(1, 1, 0, 1, 44, 43)),
+            raw: None,
         }),
         "should support image (resource) as `Image`s in mdast"
     );
@@ -287,52 +363,62 @@ fn image() -> Result<(), message::Message> {
                     label: Some("x".into()),
                     url: "y".into(),
                     title: None,
-                    position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                    position: Some(Position::new(1, 1, 0, 1, 7, 6)),
+                    raw: None,
                 }),
                 Node::Paragraph(Paragraph {
                     children: vec![
                         Node::Text(Text {
                             value: "a ".into(),
-                            position: Some(Position::new(3, 1, 8, 3, 3, 10))
+                            position: Some(Position::new(3, 1, 8, 3, 3, 10)),
+                            raw: None,
                         }),
                         Node::ImageReference(ImageReference {
                             reference_kind: ReferenceKind::Shortcut,
                             identifier: "x".into(),
                             label: Some("x".into()),
                             alt: "x".into(),
-                            position: Some(Position::new(3, 3, 10, 3, 7, 14))
+                            position: Some(Position::new(3, 3, 10, 3, 7, 14)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " b ".into(),
-                            position: Some(Position::new(3, 7, 14, 3, 10, 17))
+                            position: Some(Position::new(3, 7, 14, 3, 10, 17)),
+                            raw: None,
                         }),
                         Node::ImageReference(ImageReference {
                             reference_kind: ReferenceKind::Collapsed,
                             identifier: "x".into(),
                             label: Some("x".into()),
                             alt: "x".into(),
-                            position: Some(Position::new(3, 10, 17, 3, 16, 23))
+                            position: Some(Position::new(3, 10, 17, 3, 16, 23)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " c ".into(),
-                            position: Some(Position::new(3, 16, 23, 3, 19, 26))
+                            position: Some(Position::new(3, 16, 23, 3, 19, 26)),
+                            raw: None,
                         }),
                         Node::ImageReference(ImageReference {
                             reference_kind: ReferenceKind::Full,
                             identifier: "x".into(),
                             label: Some("x".into()),
                             alt: "d".into(),
-                            position: Some(Position::new(3, 19, 26, 3, 26, 33))
+                            position: Some(Position::new(3, 19, 26, 3, 26, 33)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " e.".into(),
-                            position: Some(Position::new(3, 26, 33, 3, 29, 36))
+                            position: Some(Position::new(3, 26, 33, 3, 29, 36)),
+                            raw: None,
                         }),
                     ],
-                    position: Some(Position::new(3, 1, 8, 3, 29, 36))
+                    position: Some(Position::new(3, 1, 8, 3, 29, 36)),
+                    raw: None,
                 }),
             ],
-            position: Some(Position::new(1, 1, 0, 3, 29, 36))
+            position: Some(Position::new(1, 1, 0, 3, 29, 36)),
+            raw: None,
         }),
         "should support image (reference) as `ImageReference`s in mdast"
     );
@@ -101,6 +101,30 @@ fn link_reference() -> Result<(), message::Message> {
         "should match references to definitions w/ unicode case-folding"
     );
 
+    assert_eq!(
+        to_html("[\u{130}]: /url\n\n[i\u{307}] [I\u{307}]"),
+        "<p><a href=\"/url\">i\u{307}</a> <a href=\"/url\">I\u{307}</a></p>",
+        "should match references to definitions w/ full unicode case-folding (`İ` and `i̇`)"
+    );
+
+    assert_eq!(
+        to_html("[\u{fb01}]: /url\n\n[fi] [FI]"),
+        "<p><a href=\"/url\">fi</a> <a href=\"/url\">FI</a></p>",
+        "should match references to definitions w/ full unicode case-folding (`ﬁ` and `fi`)"
+    );
+
+    assert_eq!(
+        to_html("[\u{1e9e}]: /url\n\n[ss] [\u{df}]"),
+        "<p><a href=\"/url\">ss</a> <a href=\"/url\">\u{df}</a></p>",
+        "should match references to definitions w/ full unicode case-folding (`ẞ`, `ss`, and `ß`)"
+    );
+
+    assert_eq!(
+        to_html("[\u{e9}]: /url\n\n[e\u{301}] [\u{c9}]"),
+        "<p>[e\u{301}] <a href=\"/url\">\u{c9}</a></p>",
+        "should not match canonically equivalent references to definitions (`é` and `é`), like `micromark` and `cmark`"
+    );
+
     assert_eq!(
         to_html("[Foo\n  bar]: /url\n\n[Baz][Foo bar]"),
         "<p><a href=\"/url\">Baz</a></p>",
@@ -434,13 +458,15 @@ fn link_reference() -> Result<(), message::Message> {
                     label: Some("x".into()),
                     url: "y".into(),
                     title: None,
-                    position: Some(Position::new(1, 1, 0, 1, 7, 6))
+                    position: Some(Position::new(1, 1, 0, 1, 7, 6)),
+                    raw: None,
                 }),
                 Node::Paragraph(Paragraph {
                     children: vec![
                         Node::Text(Text {
                             value: "a ".into(),
-                            position: Some(Position::new(3, 1, 8, 3, 3, 10))
+                            position: Some(Position::new(3, 1, 8, 3, 3, 10)),
+                            raw: None,
                         }),
                         Node::LinkReference(LinkReference {
                             reference_kind: ReferenceKind::Shortcut,
@@ -448,13 +474,16 @@ fn link_reference() -> Result<(), message::Message> {
                             label: Some("x".into()),
                             children: vec![Node::Text(Text {
                                 value: "x".into(),
-                                position: Some(Position::new(3, 4, 11, 3, 5, 12))
+                                position: Some(Position::new(3, 4, 11, 3, 5, 12)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(3, 3, 10, 3, 6, 13))
+                            position: Some(Position::new(3, 3, 10, 3, 6, 13)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " b ".into(),
-                            position: Some(Position::new(3, 6, 13, 3, 9, 16))
+                            position: Some(Position::new(3, 6, 13, 3, 9, 16)),
+                            raw: None,
                         }),
                         Node::LinkReference(LinkReference {
                             reference_kind: ReferenceKind::Collapsed,
@@ -462,13 +491,16 @@ fn link_reference() -> Result<(), message::Message> {
                             label: Some("x".into()),
                             children: vec![Node::Text(Text {
                                 value: "x".into(),
-                                position: Some(Position::new(3, 10, 17, 3, 11, 18))
+                                position: Some(Position::new(3, 10, 17, 3, 11, 18)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(3, 9, 16, 3, 14, 21))
+                            position: Some(Position::new(3, 9, 16, 3, 14, 21)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " c ".into(),
-                            position: Some(Position::new(3, 14, 21, 3, 17, 24))
+                            position: Some(Position::new(3, 14, 21, 3, 17, 24)),
+                            raw: None,
                         }),
                         Node::LinkReference(LinkReference {
                             reference_kind: ReferenceKind::Full,
@@ -476,19 +508,24 @@ fn link_reference() -> Result<(), message::Message> {
                             label: Some("x".into()),
                             children: vec![Node::Text(Text {
                                 value: "d".into(),
-                                position: Some(Position::new(3, 18, 25, 3, 19, 26))
+                                position: Some(Position::new(3, 18, 25, 3, 19, 26)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(3, 17, 24, 3, 23, 30))
+                            position: Some(Position::new(3, 17, 24, 3, 23, 30)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " e.".into(),
-                            position: Some(Position::new(3, 23, 30, 3, 26, 33))
+                            position: Some(Position::new(3, 23, 30, 3, 26, 33)),
+                            raw: None,
                         }),
                     ],
-                    position: Some(Position::new(3, 1, 8, 3, 26, 33))
+                    position: Some(Position::new(3, 1, 8, 3, 26, 33)),
+                    raw: None,
                 }),
             ],
-            position: Some(Position::new(1, 1, 0, 3, 26, 33))
+            position: Some(Position::new(1, 1, 0, 3, 26, 33)),
+            raw: None,
         }),
         "should support link (reference) as `LinkReference`s in mdast"
     );
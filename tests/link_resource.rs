@@ -453,6 +453,18 @@ fn link_resource() -> Result<(), message::Message> {
         "should support an eol at the start of a title"
     );
 
+    assert_eq!(
+        to_html("[a](b \"c \nd\")"),
+        "<p><a href=\"b\" title=\"c\nd\">a</a></p>",
+        "should support whitespace before an eol in a title"
+    );
+
+    assert_eq!(
+        to_html("[a](b \"c  \nd\")"),
+        "<p><a href=\"b\" title=\"c\nd\">a</a></p>",
+        "should not support hard breaks in a title"
+    );
+
     assert_eq!(
         to_html("[a](b( \"c\")"),
         "<p>[a](b( &quot;c&quot;)</p>",
@@ -475,38 +487,47 @@ fn link_resource() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: String::new(),
                         title: None,
                         children: vec![Node::Text(Text {
                             value: "alpha".into(),
-                            position: Some(Position::new(1, 4, 3, 1, 9, 8))
+                            position: Some(Position::new(1, 4, 3, 1, 9, 8)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 3, 2, 1, 12, 11))
+                        position: Some(Position::new(1, 3, 2, 1, 12, 11)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b ".into(),
-                        position: Some(Position::new(1, 12, 11, 1, 15, 14))
+                        position: Some(Position::new(1, 12, 11, 1, 15, 14)),
+                        raw: None,
                     }),
                     Node::Link(Link {
                         url: "charlie".into(),
                         title: Some("delta".into()),
                         children: vec![Node::Text(Text {
                             value: "bravo".into(),
-                            position: Some(Position::new(1, 16, 15, 1, 21, 20))
+                            position: Some(Position::new(1, 16, 15, 1, 21, 20)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 15, 14, 1, 39, 38))
+                        position: Some(Position::new(1, 15, 14, 1, 39, 38)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " c.".into(),
-                        position: Some(Position::new(1, 39, 38, 1, 42, 41))
+                        position: Some(Position::new(1, 39, 38, 1, 42, 41)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 42, 41))
+                position: Some(Position::new(1, 1, 0, 1, 42, 41)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 42, 41))
+            position: Some(Position::new(1, 1, 0, 1, 42, 41)),
+            raw: None,
         }),
         "should support link (resource) as `Link`s in mdast"
     );
@@ -521,14 +542,18 @@ fn link_resource() -> Result<(), message::Message> {
                         url: "image".into(),
                         title: None,
                         position: Some(Position::new(1, 2, 1, 1, 16, 15)),
+                        raw: None,
                     }),],
                     url: "url".into(),
                     title: None,
                     position: Some(Position::new(1, 1, 0, 1, 22, 21)),
+                    raw: None,
                 }),],
                 position: Some(Position::new(1, 1, 0, 1, 22, 21)),
+                raw: None,
             }),],
-            position: Some(Position::new(1, 1, 0, 1, 22, 21))
+            position: Some(Position::new(1, 1, 0, 1, 22, 21)),
+            raw: None,
         }),
         "should support nested links in mdast"
     );
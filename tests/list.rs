@@ -1,5 +1,5 @@
 use markdown::{
-    mdast::{List, ListItem, Node, Paragraph, Root, Text},
+    mdast::{List, ListItem, Node, Paragraph, Root, Text, ThematicBreak},
     message, to_html, to_html_with_options, to_mdast,
     unist::Position,
     CompileOptions, Constructs, Options, ParseOptions,
@@ -96,6 +96,18 @@ fn list() -> Result<(), message::Message> {
         "should not support ordered item values over 10 digits"
     );
 
+    assert_eq!(
+        to_html("999999999. ok"),
+        "<ol start=\"999999999\">\n<li>ok</li>\n</ol>",
+        "should support the biggest ordered item value"
+    );
+
+    assert_eq!(
+        to_html("4294967297. not ok"),
+        "<p>4294967297. not ok</p>",
+        "should not support ordered item values that do not fit in 32 bits"
+    );
+
     assert_eq!(
         to_html("0. ok"),
         "<ol start=\"0\">\n<li>ok</li>\n</ol>",
@@ -180,6 +192,42 @@ fn list() -> Result<(), message::Message> {
         "should support empty only items"
     );
 
+    assert_eq!(
+        to_html("1.\n   a"),
+        "<ol>\n<li>a</li>\n</ol>",
+        "should support content indented by the width of the marker and a space after an ordered item w/ a blank first line"
+    );
+
+    assert_eq!(
+        to_html("-\n a"),
+        "<ul>\n<li></li>\n</ul>\n<p>a</p>",
+        "should not support content indented by less than the width of the marker and a space after an item w/ a blank first line"
+    );
+
+    assert_eq!(
+        to_html("10.\n    a"),
+        "<ol start=\"10\">\n<li>a</li>\n</ol>",
+        "should support content indented by the width of a wide marker and a space after an item w/ a blank first line"
+    );
+
+    assert_eq!(
+        to_html("10.\n   a"),
+        "<ol start=\"10\">\n<li></li>\n</ol>\n<p>a</p>",
+        "should not support content indented by less than the width of a wide marker and a space after an item w/ a blank first line"
+    );
+
+    assert_eq!(
+        to_html("1.  \n   a"),
+        "<ol>\n<li>a</li>\n</ol>",
+        "should ignore trailing whitespace on the blank first line of an item"
+    );
+
+    assert_eq!(
+        to_html("1.\n       a"),
+        "<ol>\n<li>\n<pre><code>a\n</code></pre>\n</li>\n</ol>",
+        "should support indented code after a blank first line of an item"
+    );
+
     assert_eq!(
         to_html("- foo\n-\n- bar"),
         "<ul>\n<li>foo</li>\n<li></li>\n<li>bar</li>\n</ul>",
@@ -204,6 +252,36 @@ fn list() -> Result<(), message::Message> {
         "should support a single empty item"
     );
 
+    assert_eq!(
+        to_html("-   \n- a"),
+        "<ul>\n<li></li>\n<li>a</li>\n</ul>",
+        "should support a first blank item (whitespace only)"
+    );
+
+    assert_eq!(
+        to_html("1.   \n2. a"),
+        "<ol>\n<li></li>\n<li>a</li>\n</ol>",
+        "should support a first blank item (whitespace only, ordered)"
+    );
+
+    assert_eq!(
+        to_html("1. a\n2.  \n3. b"),
+        "<ol>\n<li>a</li>\n<li></li>\n<li>b</li>\n</ol>",
+        "should support blank continued items (ordered)"
+    );
+
+    assert_eq!(
+        to_html("- a\n-   "),
+        "<ul>\n<li>a</li>\n<li></li>\n</ul>",
+        "should support a blank item at the end of the document"
+    );
+
+    assert_eq!(
+        to_html("-   "),
+        "<ul>\n<li></li>\n</ul>",
+        "should support a single blank item"
+    );
+
     assert_eq!(
         to_html("foo\n*\n\nfoo\n1."),
         "<p>foo\n*</p>\n<p>foo\n1.</p>",
@@ -589,6 +667,72 @@ fn list() -> Result<(), message::Message> {
         "should support turning off lists"
     );
 
+    assert_eq!(
+        to_html("- a\n- b\n\n***"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n<hr />",
+        "should support tight lists w/ a blank line before a thematic break"
+    );
+
+    assert_eq!(
+        to_html("- a\n- b\n\n\n***"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n<hr />",
+        "should support tight lists w/ blank lines before a thematic break"
+    );
+
+    assert_eq!(
+        to_html("- a\n- b\n\n\n# c"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n<h1>c</h1>",
+        "should support tight lists w/ blank lines before a heading"
+    );
+
+    assert_eq!(
+        to_html("1. a\n2. b\n\n\nc"),
+        "<ol>\n<li>a</li>\n<li>b</li>\n</ol>\n<p>c</p>",
+        "should support tight lists w/ blank lines before a paragraph"
+    );
+
+    assert_eq!(
+        to_html("- a\n- b\n\n\n"),
+        "<ul>\n<li>a</li>\n<li>b</li>\n</ul>\n",
+        "should support tight lists w/ blank lines before the end"
+    );
+
+    assert_eq!(
+        to_html("- a\n  - b\n\n\nc"),
+        "<ul>\n<li>a\n<ul>\n<li>b</li>\n</ul>\n</li>\n</ul>\n<p>c</p>",
+        "should support tight nested lists w/ blank lines after them"
+    );
+
+    assert_eq!(
+        to_html("- a\n\n\n  b"),
+        "<ul>\n<li>\n<p>a</p>\n<p>b</p>\n</li>\n</ul>",
+        "should support loose lists w/ blank lines between children"
+    );
+
+    assert_eq!(
+        to_html("- ```\n  a\n  ```"),
+        "<ul>\n<li>\n<pre><code>a\n</code></pre>\n</li>\n</ul>",
+        "should support items w/ only code (fenced) in unordered lists"
+    );
+
+    assert_eq!(
+        to_html("1. ```\n   a\n   ```"),
+        "<ol>\n<li>\n<pre><code>a\n</code></pre>\n</li>\n</ol>",
+        "should support items w/ only code (fenced) in ordered lists"
+    );
+
+    assert_eq!(
+        to_html("-     a\n1.     b"),
+        "<ul>\n<li>\n<pre><code>a\n</code></pre>\n</li>\n</ul>\n<ol>\n<li>\n<pre><code>b\n</code></pre>\n</li>\n</ol>",
+        "should support items w/ only code (indented)"
+    );
+
+    assert_eq!(
+        to_html("- ```\n  a\n\n\n  ```\n- b"),
+        "<ul>\n<li>\n<pre><code>a\n\n\n</code></pre>\n</li>\n<li>b</li>\n</ul>",
+        "should support items w/ only code (fenced) w/ blank lines inside it"
+    );
+
     assert_eq!(
         to_mdast("* a", &Default::default())?,
         Node::Root(Root {
@@ -596,25 +740,67 @@ fn list() -> Result<(), message::Message> {
                 ordered: false,
                 spread: false,
                 start: None,
+                kind: None,
                 children: vec![Node::ListItem(ListItem {
                     checked: None,
                     spread: false,
                     children: vec![Node::Paragraph(Paragraph {
                         children: vec![Node::Text(Text {
                             value: "a".into(),
-                            position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                            position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                            raw: None,
                         }),],
-                        position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                        position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                        raw: None,
                     })],
-                    position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                    position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                    markers: None,
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 4, 3))
+            position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+            raw: None,
         }),
         "should support lists, list items as `List`, `ListItem`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("1.\n   a", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::List(List {
+                ordered: true,
+                spread: false,
+                start: Some(1),
+                kind: None,
+                children: vec![Node::ListItem(ListItem {
+                    checked: None,
+                    spread: false,
+                    children: vec![Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(2, 4, 6, 2, 5, 7)),
+                            raw: None,
+                        }),],
+                        position: Some(Position::new(2, 4, 6, 2, 5, 7)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(1, 1, 0, 2, 5, 7)),
+                    markers: None,
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 5, 7)),
+                markers: None,
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 5, 7)),
+            raw: None,
+        }),
+        "should support an item w/ a blank first line in mdast"
+    );
+
     assert_eq!(
         to_mdast("3. a\n4. b", &Default::default())?,
         Node::Root(Root {
@@ -622,6 +808,7 @@ fn list() -> Result<(), message::Message> {
                 ordered: true,
                 spread: false,
                 start: Some(3),
+                kind: None,
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: None,
@@ -629,11 +816,15 @@ fn list() -> Result<(), message::Message> {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "a".into(),
-                                position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                                position: Some(Position::new(1, 4, 3, 1, 5, 4)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(1, 4, 3, 1, 5, 4))
+                            position: Some(Position::new(1, 4, 3, 1, 5, 4)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(1, 1, 0, 1, 5, 4))
+                        position: Some(Position::new(1, 1, 0, 1, 5, 4)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         checked: None,
@@ -641,20 +832,79 @@ fn list() -> Result<(), message::Message> {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "b".into(),
-                                position: Some(Position::new(2, 4, 8, 2, 5, 9))
+                                position: Some(Position::new(2, 4, 8, 2, 5, 9)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(2, 4, 8, 2, 5, 9))
+                            position: Some(Position::new(2, 4, 8, 2, 5, 9)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(2, 1, 5, 2, 5, 9))
+                        position: Some(Position::new(2, 1, 5, 2, 5, 9)),
+                        markers: None,
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 2, 5, 9))
+                position: Some(Position::new(1, 1, 0, 2, 5, 9)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 5, 9))
+            position: Some(Position::new(1, 1, 0, 2, 5, 9)),
+            raw: None,
         }),
         "should support `start` fields on `List` w/ `ordered: true` in mdast"
     );
 
+    assert_eq!(
+        to_mdast("999999999. a", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::List(List {
+                ordered: true,
+                spread: false,
+                start: Some(999_999_999),
+                kind: None,
+                children: vec![Node::ListItem(ListItem {
+                    checked: None,
+                    spread: false,
+                    children: vec![Node::Paragraph(Paragraph {
+                        children: vec![Node::Text(Text {
+                            value: "a".into(),
+                            position: Some(Position::new(1, 12, 11, 1, 13, 12)),
+                            raw: None,
+                        }),],
+                        position: Some(Position::new(1, 12, 11, 1, 13, 12)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                    markers: None,
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                markers: None,
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+            raw: None,
+        }),
+        "should support the biggest `start` on `List` in mdast"
+    );
+
+    assert_eq!(
+        to_mdast("4294967297. a", &Default::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "4294967297. a".into(),
+                    position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+                    raw: None,
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+            raw: None,
+        }),
+        "should not support values that do not fit in 32 bits in mdast, like in HTML"
+    );
+
     assert_eq!(
         to_mdast("* a\n\n  b\n* c", &Default::default())?,
         Node::Root(Root {
@@ -662,6 +912,7 @@ fn list() -> Result<(), message::Message> {
                 ordered: false,
                 spread: false,
                 start: None,
+                kind: None,
                 children: vec![
                     Node::ListItem(ListItem {
                         checked: None,
@@ -670,19 +921,25 @@ fn list() -> Result<(), message::Message> {
                             Node::Paragraph(Paragraph {
                                 children: vec![Node::Text(Text {
                                     value: "a".into(),
-                                    position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                                    position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(1, 3, 2, 1, 4, 3))
+                                position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                                raw: None,
                             }),
                             Node::Paragraph(Paragraph {
                                 children: vec![Node::Text(Text {
                                     value: "b".into(),
-                                    position: Some(Position::new(3, 3, 7, 3, 4, 8))
+                                    position: Some(Position::new(3, 3, 7, 3, 4, 8)),
+                                    raw: None,
                                 }),],
-                                position: Some(Position::new(3, 3, 7, 3, 4, 8))
+                                position: Some(Position::new(3, 3, 7, 3, 4, 8)),
+                                raw: None,
                             })
                         ],
-                        position: Some(Position::new(1, 1, 0, 3, 4, 8))
+                        position: Some(Position::new(1, 1, 0, 3, 4, 8)),
+                        markers: None,
+                        raw: None,
                     }),
                     Node::ListItem(ListItem {
                         checked: None,
@@ -690,19 +947,67 @@ fn list() -> Result<(), message::Message> {
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "c".into(),
-                                position: Some(Position::new(4, 3, 11, 4, 4, 12))
+                                position: Some(Position::new(4, 3, 11, 4, 4, 12)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(4, 3, 11, 4, 4, 12))
+                            position: Some(Position::new(4, 3, 11, 4, 4, 12)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(4, 1, 9, 4, 4, 12))
+                        position: Some(Position::new(4, 1, 9, 4, 4, 12)),
+                        markers: None,
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 4, 4, 12))
+                position: Some(Position::new(1, 1, 0, 4, 4, 12)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 4, 4, 12))
+            position: Some(Position::new(1, 1, 0, 4, 4, 12)),
+            raw: None,
         }),
         "should support `spread` fields on `List`, `ListItem`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("- a\n\n\n***", &Default::default())?,
+        Node::Root(Root {
+            children: vec![
+                Node::List(List {
+                    ordered: false,
+                    spread: false,
+                    start: None,
+                    kind: None,
+                    children: vec![Node::ListItem(ListItem {
+                        checked: None,
+                        spread: false,
+                        children: vec![Node::Paragraph(Paragraph {
+                            children: vec![Node::Text(Text {
+                                value: "a".into(),
+                                position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                                raw: None,
+                            }),],
+                            position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                            raw: None,
+                        })],
+                        position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                        markers: None,
+                        raw: None,
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                    markers: None,
+                    raw: None,
+                }),
+                Node::ThematicBreak(ThematicBreak {
+                    position: Some(Position::new(4, 1, 6, 4, 4, 9)),
+                    markers: None,
+                    raw: None,
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 4, 4, 9)),
+            raw: None,
+        }),
+        "should not infer `spread` from blank lines at the end of a `ListItem` in mdast"
+    );
+
     Ok(())
 }
@@ -261,9 +261,11 @@ fn math_flow() -> Result<(), message::Message> {
             children: vec![Node::Math(Math {
                 meta: Some("extra".into()),
                 value: "abc\ndef".into(),
-                position: Some(Position::new(1, 1, 0, 4, 3, 18))
+                position: Some(Position::new(1, 1, 0, 4, 3, 18)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 4, 3, 18))
+            position: Some(Position::new(1, 1, 0, 4, 3, 18)),
+            raw: None,
         }),
         "should support math (flow) as `Math`s in mdast"
     );
@@ -19,6 +19,18 @@ fn math_text() -> Result<(), message::Message> {
         },
         ..Default::default()
     };
+    let double = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                math_text: true,
+                math_flow: true,
+                ..Default::default()
+            },
+            math_text_single_dollar: false,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
 
     assert_eq!(
         to_html("$a$"),
@@ -207,6 +219,72 @@ fn math_text() -> Result<(), message::Message> {
         "should support an escaped initial dollar"
     );
 
+    assert_eq!(
+        to_html_with_options("costs $5 and $10", &math)?,
+        "<p>costs <code class=\"language-math math-inline\">5 and </code>10</p>",
+        "should support math (text) between prices w/ a single dollar"
+    );
+
+    assert_eq!(
+        to_html_with_options("costs $5 and $10", &double)?,
+        "<p>costs $5 and $10</p>",
+        "should not support math (text) between prices w/ `math_text_single_dollar: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("costs $5 and $$10", &math)?,
+        "<p>costs $5 and $$10</p>",
+        "should not support math (text) w/ different sequence sizes"
+    );
+
+    assert_eq!(
+        to_html_with_options("costs $5 and $$10", &double)?,
+        "<p>costs $5 and $$10</p>",
+        "should not support math (text) w/ different sequence sizes w/ `math_text_single_dollar: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$ $a$ $b$", &math)?,
+        "<p>$$ <code class=\"language-math math-inline\">a</code> <code class=\"language-math math-inline\">b</code></p>",
+        "should support math (text) after closed math of the same size, after sequences that are not closed"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$$ $$a$$ $$b$$", &double)?,
+        "<p>$$$ <code class=\"language-math math-inline\">a</code> <code class=\"language-math math-inline\">b</code></p>",
+        "should support math (text) after closed math of the same size, after bigger sequences that are not closed"
+    );
+
+    assert_eq!(
+        to_html_with_options("a $$ b", &double)?,
+        "<p>a $$ b</p>",
+        "should not support a lone double dollar as empty math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a $$$$ b", &double)?,
+        "<p>a $$$$ b</p>",
+        "should not support four dollars as empty math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a $$$$ b", &math)?,
+        "<p>a $$$$ b</p>",
+        "should not support four dollars as empty math (text) w/ a single dollar"
+    );
+
+    assert_eq!(
+        to_html_with_options("$$5 and $$10", &double)?,
+        "<p><code class=\"language-math math-inline\">5 and </code>10</p>",
+        "should support math (text) between double dollar prices w/ `math_text_single_dollar: false`"
+    );
+
+    assert_eq!(
+        to_html_with_options("costs \\$5 and \\$10", &math)?,
+        "<p>costs $5 and $10</p>",
+        "should support escaped dollars in prices"
+    );
+
     assert_eq!(
         to_mdast("a $alpha$ b.", &math.parse)?,
         Node::Root(Root {
@@ -214,20 +292,25 @@ fn math_text() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::InlineMath(InlineMath {
                         value: "alpha".into(),
-                        position: Some(Position::new(1, 3, 2, 1, 10, 9))
+                        position: Some(Position::new(1, 3, 2, 1, 10, 9)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b.".into(),
-                        position: Some(Position::new(1, 10, 9, 1, 13, 12))
+                        position: Some(Position::new(1, 10, 9, 1, 13, 12)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+            raw: None,
         }),
         "should support math (text) as `InlineMath`s in mdast"
     );
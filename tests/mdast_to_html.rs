@@ -0,0 +1,259 @@
+use markdown::{
+    mdast::{
+        FootnoteReference, ImageReference, LinkReference, Node, Paragraph, ReferenceKind, Root,
+        Text,
+    },
+    mdast_to_html, message, to_html_with_options, to_mdast, BooleanAttributeStyle, CompileOptions,
+    Constructs, FootnoteNumbering, Options, ParseOptions, QuoteKind,
+};
+use pretty_assertions::assert_eq;
+
+/// Documents that compile to the same HTML from markdown and from mdast.
+const CORPUS: [&str; 44] = [
+    "",
+    "a",
+    "a\nb",
+    "a  \nb\\\nc",
+    "# a *b* c #\n\nd\n===\n\ne\n---",
+    "***\n- - -",
+    "> a\n> b\n>\n> > c",
+    "> a\nb",
+    "* a\n* b\n\n* c",
+    "* a\n\n  b\n* c",
+    "1. a\n2. b",
+    "3. a\n1. b",
+    "* a\n  * b\n    * c",
+    "*\n* a\n*",
+    "* # a\n  b",
+    "* a\n  > b",
+    "* [x] a\n* [ ] b\n\n* [X] c",
+    "* [x] a\n\n  b",
+    "    a\n\n\n    b",
+    "```js\na\n\n```",
+    "~~~\n~~~",
+    "```a&b\n<c>\n```",
+    "`a` `` ` `` `  b  ` ``\nc\n``",
+    "*a* _b_ **c** __d__ ~e~ ~~f~~",
+    "&amp; &copy; &#35; \\* a<b & \"c\"",
+    "[a](b 'c') [d](<e f>) [g](javascript:alert(1))",
+    "![a *b*](c \"d\") ![e](data:image/png;base64,x)",
+    "[a]\n\n[a]: b 'c'\n\n[A][] [x][a] [b]\n\n![a] ![c][a]",
+    "[a]: b\n[a]: c\n\n[a]",
+    "<https://a.b> <c@d.e> www.f.g https://h.i j@k.l",
+    "[www.a.com](b) [https://c.d](e)",
+    "a[^b] c[^d] e[^b]\n\n[^b]: f\n\n[^d]: g\n    h\n\n    i",
+    "a[^1]\n\n[^1]: b\n\n    ```\n    c\n    ```",
+    "a[^x]\n\n[^y]: b",
+    "[^a]: b\n\nc[^a]",
+    "| a | b |\n| :- | -: |\n| c |\n| d | e | f |",
+    "| a |\n| - |",
+    "| `a\\|b` |\n| :-: |\n| c |",
+    "$a$ $$\nb\n$$",
+    "$$\na\n$$",
+    "<div>\n*a*\n</div>\n\n<b>c</b> <!--d--> <xmp>",
+    "<!--a-->\n\n<!--b--> c",
+    "* <div>\n\n  a",
+    "- a\n\n\n- b\n\n      c",
+];
+
+#[test]
+fn mdast_to_html_corpus() -> Result<(), message::Message> {
+    let parse = || ParseOptions {
+        constructs: Constructs {
+            math_flow: true,
+            math_text: true,
+            ..Constructs::gfm()
+        },
+        ..ParseOptions::gfm()
+    };
+    let configurations = [
+        CompileOptions::default(),
+        CompileOptions {
+            allow_dangerous_html: true,
+            allow_dangerous_protocol: true,
+            ..CompileOptions::gfm()
+        },
+        CompileOptions {
+            allow_html_comments: true,
+            attribute_quote: QuoteKind::Single,
+            boolean_attribute_style: BooleanAttributeStyle::Omit,
+            gfm_footnote_clobber_prefix: Some("x y".into()),
+            gfm_footnote_id_prefix: Some("z-".into()),
+            gfm_footnote_numbering: FootnoteNumbering::DefinitionOrder,
+            gfm_task_list_item_checkable: true,
+            gfm_task_list_item_data_offset: true,
+            heading_shift: 1,
+            ..CompileOptions::gfm()
+        },
+    ];
+
+    for compile in configurations {
+        let options = Options {
+            parse: parse(),
+            compile,
+        };
+
+        for value in CORPUS {
+            assert_eq!(
+                mdast_to_html(&to_mdast(value, &options.parse)?, &options.compile)?.trim_end(),
+                to_html_with_options(value, &options)?.trim_end(),
+                "should compile the tree of `{:?}` like the markdown ({:?})",
+                value,
+                options.compile
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn mdast_to_html_tree() -> Result<(), message::Message> {
+    let text = |value: &str| {
+        Node::Text(Text {
+            value: value.into(),
+            position: None,
+            raw: None,
+        })
+    };
+
+    let paragraph = |children: Vec<Node>| {
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children,
+                position: None,
+                raw: None,
+            })],
+            position: None,
+            raw: None,
+        })
+    };
+
+    assert_eq!(
+        mdast_to_html(&paragraph(vec![text("a < b")]), &CompileOptions::default())?,
+        "<p>a &lt; b</p>",
+        "should support a tree without positions"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &paragraph(vec![
+                Node::LinkReference(LinkReference {
+                    children: vec![text("a")],
+                    position: None,
+                    reference_kind: ReferenceKind::Shortcut,
+                    identifier: "a".into(),
+                    label: Some("a".into()),
+                    raw: None,
+                }),
+                Node::ImageReference(ImageReference {
+                    alt: "b".into(),
+                    position: None,
+                    reference_kind: ReferenceKind::Full,
+                    identifier: "c".into(),
+                    label: Some("C".into()),
+                    raw: None,
+                }),
+                Node::LinkReference(LinkReference {
+                    children: vec![text("d")],
+                    position: None,
+                    reference_kind: ReferenceKind::Collapsed,
+                    identifier: "d".into(),
+                    label: Some("d".into()),
+                    raw: None,
+                }),
+                Node::FootnoteReference(FootnoteReference {
+                    position: None,
+                    identifier: "e".into(),
+                    label: Some("E".into()),
+                    raw: None,
+                }),
+            ]),
+            &CompileOptions::default()
+        )?,
+        "<p>[a]![b][C][d][][^E]</p>",
+        "should turn references without definitions into text"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast(
+                "---\na: b\n---\n# c",
+                &ParseOptions {
+                    constructs: Constructs {
+                        frontmatter: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                }
+            )?,
+            &CompileOptions::default()
+        )?,
+        "<h1>c</h1>",
+        "should drop frontmatter"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast("a *b*", &ParseOptions::default())?,
+            &CompileOptions {
+                max_output_size: Some(8),
+                ..CompileOptions::default()
+            }
+        )
+        .unwrap_err()
+        .rule_id
+        .as_str(),
+        "max-output-size",
+        "should support `max_output_size`"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn mdast_to_html_mdx() -> Result<(), message::Message> {
+    let tree = to_mdast(
+        "<A>\n  *c* {d}\n</A>\n\n{e}\n\nf <G>h</G>",
+        &ParseOptions::mdx(),
+    )?;
+
+    let error = mdast_to_html(&tree, &CompileOptions::default()).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "1:1-3:5: Unexpected MDX node `MdxJsxFlowElement`, expected markdown (pass `skip_mdx_nodes: true` to drop MDX) (markdown-rs:unexpected-mdx-node)",
+        "should error on MDX nodes by default"
+    );
+
+    assert_eq!(
+        error.rule_id.as_str(),
+        markdown::message::rules::UNEXPECTED_MDX_NODE,
+        "should error with a rule id for MDX nodes"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast("a {b}", &ParseOptions::mdx())?,
+            &CompileOptions::default()
+        )
+        .unwrap_err()
+        .to_string(),
+        "1:3-1:6: Unexpected MDX node `MdxTextExpression`, expected markdown (pass `skip_mdx_nodes: true` to drop MDX) (markdown-rs:unexpected-mdx-node)",
+        "should error on MDX nodes in phrasing"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &tree,
+            &CompileOptions {
+                skip_mdx_nodes: true,
+                ..CompileOptions::default()
+            }
+        )?,
+        "<p><em>c</em> </p>\n<p>f h</p>",
+        "should skip MDX nodes w/ `skip_mdx_nodes`"
+    );
+
+    Ok(())
+}
@@ -122,6 +122,39 @@ fn mdx_esm() -> Result<(), message::Message> {
         "should support blank lines in import/exports"
     );
 
+    assert_eq!(
+        to_html_with_options("export const x = {\n}\n\nexport const y = 1", &swc)?,
+        "",
+        "should support multiline exports followed by a blank line and another export"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "export const x = {\n\n  a: 1,\n\n\n  b: 2\n}\n\nexport const y = 1\n\nz",
+            &swc
+        )?,
+        "<p>z</p>",
+        "should support multiline exports w/ blank lines in braces"
+    );
+
+    assert_eq!(
+        to_html_with_options("export const x = {\n\n  a: 1 +\n\n}\n\nb", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
+        "5:1: Could not parse esm with swc: Expression expected (mdx:swc)",
+        "should crash on invalid exports w/ blank lines in braces, at the right place"
+    );
+
+    assert_eq!(
+        to_html_with_options("export const x = {\n\n  a: 1,\n\nb", &swc)
+            .err()
+            .unwrap()
+            .to_string(),
+        "5:2: Could not parse esm with swc: Unexpected eof (mdx:swc)",
+        "should crash on unclosed exports w/ blank lines at the end of the document"
+    );
+
     assert_eq!(
         to_html_with_options("import a from 'b'\n*md*?", &swc)
             .err()
@@ -259,12 +292,37 @@ fn mdx_esm() -> Result<(), message::Message> {
             children: vec![Node::MdxjsEsm(MdxjsEsm {
                 value: "import a from 'b'\nexport {a}".into(),
                 position: Some(Position::new(1, 1, 0, 2, 11, 28)),
-                stops: vec![(0, 0), (17, 17), (18, 18)]
+                stops: vec![(0, 0), (17, 17), (18, 18)],
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 11, 28))
+            position: Some(Position::new(1, 1, 0, 2, 11, 28)),
+            raw: None,
         }),
         "should support mdx esm as `MdxjsEsm`s in mdast"
     );
 
+    assert_eq!(
+        to_mdast("export const x = {\n\n}\n\nexport const y = 1", &swc.parse)?,
+        Node::Root(Root {
+            children: vec![
+                Node::MdxjsEsm(MdxjsEsm {
+                    value: "export const x = {\n\n}".into(),
+                    position: Some(Position::new(1, 1, 0, 3, 2, 21)),
+                    stops: vec![(0, 0), (18, 18), (19, 19), (20, 20)],
+                    raw: None,
+                }),
+                Node::MdxjsEsm(MdxjsEsm {
+                    value: "export const y = 1".into(),
+                    position: Some(Position::new(5, 1, 23, 5, 19, 41)),
+                    stops: vec![(0, 23)],
+                    raw: None,
+                })
+            ],
+            position: Some(Position::new(1, 1, 0, 5, 19, 41)),
+            raw: None,
+        }),
+        "should support blank lines in braces, and blank lines between mdx esm, in mdast"
+    );
+
     Ok(())
 }
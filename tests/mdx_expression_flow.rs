@@ -71,7 +71,7 @@ fn mdx_expression_flow_agnostic() -> Result<(), message::Message> {
 
     assert_eq!(
         to_html_with_options("{a", &mdx).err().unwrap().to_string(),
-        "1:3: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:3: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash if no closing brace is found (1)"
     );
 
@@ -80,7 +80,7 @@ fn mdx_expression_flow_agnostic() -> Result<(), message::Message> {
             .err()
             .unwrap()
             .to_string(),
-        "1:9: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:9: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash if no closing brace is found (2)"
     );
 
@@ -112,7 +112,7 @@ fn mdx_expression_flow_agnostic() -> Result<(), message::Message> {
         to_html_with_options("> {a\nb}", &mdx)
             .err()
             .unwrap().to_string(),
-        "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-expression)",
         "should not support lazyness (1)"
     );
 
@@ -132,7 +132,7 @@ fn mdx_expression_flow_agnostic() -> Result<(), message::Message> {
         to_html_with_options("> {\n> a\nb}", &mdx)
             .err()
             .unwrap().to_string(),
-        "3:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "3:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-expression)",
         "should not support lazyness (4)"
     );
 
@@ -142,9 +142,11 @@ fn mdx_expression_flow_agnostic() -> Result<(), message::Message> {
             children: vec![Node::MdxFlowExpression(MdxFlowExpression {
                 value: "alpha +\nbravo".into(),
                 position: Some(Position::new(1, 1, 0, 2, 7, 15)),
-                stops: vec![(0, 1), (7, 8), (8, 9)]
+                stops: vec![(0, 1), (7, 8), (8, 9)],
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 7, 15))
+            position: Some(Position::new(1, 1, 0, 2, 7, 15)),
+            raw: None,
         }),
         "should support mdx expressions (flow) as `MdxFlowExpression`s in mdast"
     );
@@ -155,9 +157,11 @@ fn mdx_expression_flow_agnostic() -> Result<(), message::Message> {
             children: vec![Node::MdxFlowExpression(MdxFlowExpression {
                 value: "`\n  a\n`".into(),
                 position: Some(Position::new(1, 3, 2, 3, 5, 15)),
-                stops: vec![(0, 3), (1, 4), (2, 7), (5, 10), (6, 13)]
+                stops: vec![(0, 3), (1, 4), (2, 7), (5, 10), (6, 13)],
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 5, 15))
+            position: Some(Position::new(1, 1, 0, 3, 5, 15)),
+            raw: None,
         }),
         "should support indent in `MdxFlowExpression` in mdast"
     );
@@ -165,6 +169,101 @@ fn mdx_expression_flow_agnostic() -> Result<(), message::Message> {
     Ok(())
 }
 
+/// Get the values of all expressions (flow and text), in tree order.
+fn values(value: &str) -> Result<Vec<String>, message::Message> {
+    fn visit(node: &Node, list: &mut Vec<String>) {
+        match node {
+            Node::MdxFlowExpression(node) => list.push(node.value.clone()),
+            Node::MdxTextExpression(node) => list.push(node.value.clone()),
+            _ => {}
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                visit(child, list);
+            }
+        }
+    }
+
+    let mut list = vec![];
+    visit(&to_mdast(value, &ParseOptions::mdx())?, &mut list);
+    Ok(list)
+}
+
+/// Note: values match `mdast-util-mdx-expression`: the source between the
+/// braces, without container prefixes, and without up to two spaces of
+/// indent.
+#[test]
+fn mdx_expression_values() -> Result<(), message::Message> {
+    assert_eq!(
+        values("{ a }")?,
+        vec![" a "],
+        "should keep whitespace inside braces (flow)"
+    );
+
+    assert_eq!(
+        values("x { a } y")?,
+        vec![" a "],
+        "should keep whitespace inside braces (text)"
+    );
+
+    assert_eq!(
+        values("{a}\n{b}")?,
+        vec!["a", "b"],
+        "should not include line endings between adjacent expressions (flow)"
+    );
+
+    assert_eq!(
+        values("x {a}\n{b} y")?,
+        vec!["a", "b"],
+        "should not include line endings between adjacent expressions (text)"
+    );
+
+    assert_eq!(
+        values("{\n  a\n}")?,
+        vec!["\na\n"],
+        "should keep line endings inside braces"
+    );
+
+    assert_eq!(
+        values("> {a\n>   b\n> c}")?,
+        vec!["a\nb\nc"],
+        "should strip block quote prefixes (flow)"
+    );
+
+    assert_eq!(
+        values("> x {a\n>  b} y")?,
+        vec!["a\nb"],
+        "should strip block quote prefixes (text)"
+    );
+
+    assert_eq!(
+        values("> {`\n>     a\n> `}")?,
+        vec!["`\n  a\n`"],
+        "should strip at most two spaces of indent after block quote prefixes"
+    );
+
+    assert_eq!(
+        values("* {a\n    b}")?,
+        vec!["a\nb"],
+        "should strip list item indent (flow)"
+    );
+
+    assert_eq!(
+        values("* x {a\n   b} y")?,
+        vec!["a\nb"],
+        "should strip list item indent (text)"
+    );
+
+    assert_eq!(
+        values("- > {a\n  >  b}")?,
+        vec!["a\nb"],
+        "should strip prefixes of nested containers"
+    );
+
+    Ok(())
+}
+
 /// Note: these tests are also in `micromark/micromark-extension-mdx-expression`
 /// at `tests/index.js`.
 #[test]
@@ -193,7 +292,7 @@ fn mdx_expression_flow_gnostic() -> Result<(), message::Message> {
 
     assert_eq!(
         to_html_with_options("{a", &swc).err().unwrap().to_string(),
-        "1:3: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:3: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash if no closing brace is found (1)"
     );
 
@@ -248,9 +347,11 @@ fn mdx_expression_flow_gnostic() -> Result<(), message::Message> {
             children: vec![Node::MdxFlowExpression(MdxFlowExpression {
                 value: "`\n  `".into(),
                 position: Some(Position::new(1, 1, 0, 2, 7, 6)),
-                stops: vec![(0, 1), (1, 2), (2, 3)]
+                stops: vec![(0, 1), (1, 2), (2, 3)],
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 7, 6))
+            position: Some(Position::new(1, 1, 0, 2, 7, 6)),
+            raw: None,
         }),
         "should use correct positional info when tabs are used (1, indent)"
     );
@@ -261,9 +362,11 @@ fn mdx_expression_flow_gnostic() -> Result<(), message::Message> {
             children: vec![Node::MdxFlowExpression(MdxFlowExpression {
                 value: "`\nalpha\t`".into(),
                 position: Some(Position::new(1, 1, 0, 2, 11, 11)),
-                stops: vec![(0, 1), (1, 2), (2, 3)]
+                stops: vec![(0, 1), (1, 2), (2, 3)],
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 11, 11))
+            position: Some(Position::new(1, 1, 0, 2, 11, 11)),
+            raw: None,
         }),
         "should use correct positional info when tabs are used (2, content)"
     );
@@ -276,30 +379,37 @@ fn mdx_expression_flow_gnostic() -> Result<(), message::Message> {
                     children: vec![
                         Node::Text(Text {
                             value: "aaa ".into(),
-                            position: Some(Position::new(1, 4, 3, 1, 8, 7))
+                            position: Some(Position::new(1, 4, 3, 1, 8, 7)),
+                            raw: None,
                         }),
                         Node::MdxJsxTextElement(MdxJsxTextElement {
                             children: vec![],
                             name: Some("b".into()),
                             attributes: vec![AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 11, 10, 3, 6, 29)),
                                 name: "c".into(),
                                 value: Some(AttributeValue::Expression(AttributeValueExpression {
                                     value: "`\n   d\n`".into(),
                                     stops: vec![(0, 13), (1, 14), (2, 19), (6, 23), (7, 27)]
                                 }))
                             })],
-                            position: Some(Position::new(1, 8, 7, 3, 9, 32))
+                            position: Some(Position::new(1, 8, 7, 3, 9, 32)),
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " eee".into(),
-                            position: Some(Position::new(3, 9, 32, 3, 13, 36))
+                            position: Some(Position::new(3, 9, 32, 3, 13, 36)),
+                            raw: None,
                         })
                     ],
-                    position: Some(Position::new(1, 3, 2, 3, 13, 36))
+                    position: Some(Position::new(1, 3, 2, 3, 13, 36)),
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 3, 13, 36))
+                position: Some(Position::new(1, 1, 0, 3, 13, 36)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 13, 36))
+            position: Some(Position::new(1, 1, 0, 3, 13, 36)),
+            raw: None,
         }),
         "should support template strings in JSX (text) in block quotes"
     );
@@ -312,19 +422,24 @@ fn mdx_expression_flow_gnostic() -> Result<(), message::Message> {
                     children: vec![
                         Node::Text(Text {
                             value: "ab ".into(),
-                            position: Some(Position::new(1, 3, 2, 1, 6, 5))
+                            position: Some(Position::new(1, 3, 2, 1, 6, 5)),
+                            raw: None,
                         }),
                         Node::MdxTextExpression(MdxTextExpression {
                             value: "`\n`".into(),
                             stops: vec![(0, 6), (1, 7), (2, 10)],
-                            position: Some(Position::new(1, 6, 5, 2, 7, 12))
+                            position: Some(Position::new(1, 6, 5, 2, 7, 12)),
+                            raw: None,
                         })
                     ],
-                    position: Some(Position::new(1, 3, 2, 2, 7, 12))
+                    position: Some(Position::new(1, 3, 2, 2, 7, 12)),
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 2, 7, 12))
+                position: Some(Position::new(1, 1, 0, 2, 7, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 2, 7, 12))
+            position: Some(Position::new(1, 1, 0, 2, 7, 12)),
+            raw: None,
         }),
         "should use correct positional when there are virtual spaces due to a block quote"
     );
@@ -351,11 +466,14 @@ fn mdx_expression_flow_gnostic() -> Result<(), message::Message> {
                         (22, 38),
                         (28, 44),
                         (29, 47)
-                    ]
+                    ],
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 6, 5, 49))
+                position: Some(Position::new(1, 1, 0, 6, 5, 49)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 6, 5, 49))
+            position: Some(Position::new(1, 1, 0, 6, 5, 49)),
+            raw: None,
         }),
         "should keep the correct number of spaces in a blockquote (flow)"
     );
@@ -382,11 +500,14 @@ fn mdx_expression_flow_gnostic() -> Result<(), message::Message> {
                         (22, 38),
                         (28, 44),
                         (29, 47)
-                    ]
+                    ],
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 6, 5, 49))
+                position: Some(Position::new(1, 1, 0, 6, 5, 49)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 6, 5, 49))
+            position: Some(Position::new(1, 1, 0, 6, 5, 49)),
+            raw: None,
         }),
         "should keep the correct number of spaces in a blockquote (flow)"
     );
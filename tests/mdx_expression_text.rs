@@ -186,7 +186,7 @@ fn mdx_expression_text_agnostic() -> Result<(), message::Message> {
             .err()
             .unwrap()
             .to_string(),
-        "1:7: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:7: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash if no closing brace is found (1)"
     );
 
@@ -194,7 +194,7 @@ fn mdx_expression_text_agnostic() -> Result<(), message::Message> {
         to_html_with_options("a {b { c } d", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:13: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:13: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash if no closing brace is found (2)"
     );
 
@@ -223,21 +223,26 @@ fn mdx_expression_text_agnostic() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::MdxTextExpression(MdxTextExpression {
                         value: "alpha".into(),
                         position: Some(Position::new(1, 3, 2, 1, 10, 9)),
-                        stops: vec![(0, 3)]
+                        stops: vec![(0, 3)],
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " b.".into(),
-                        position: Some(Position::new(1, 10, 9, 1, 13, 12))
+                        position: Some(Position::new(1, 10, 9, 1, 13, 12)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+            raw: None,
         }),
         "should support mdx expressions (text) as `MdxTextExpression`s in mdast"
     );
@@ -285,7 +290,7 @@ fn mdx_expression_text_gnostic() -> Result<(), message::Message> {
             .err()
             .unwrap()
             .to_string(),
-        "1:7: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:7: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash if no closing brace is found (1)"
     );
 
@@ -340,6 +345,7 @@ fn mdx_expression_text_gnostic() -> Result<(), message::Message> {
                         Node::Text(Text {
                             value: "alpha ".into(),
                             position: Some(Position::new(1, 3, 2, 1, 9, 8)),
+                            raw: None,
                         }),
                         Node::MdxTextExpression(MdxTextExpression {
                             value: "`\nbravo\ncharlie\ndelta\n echo\n`".into(),
@@ -356,18 +362,23 @@ fn mdx_expression_text_gnostic() -> Result<(), message::Message> {
                                 (22, 44),
                                 (27, 49),
                                 (28, 52)
-                            ]
+                            ],
+                            raw: None,
                         }),
                         Node::Text(Text {
                             value: " foxtrot.".into(),
                             position: Some(Position::new(6, 5, 54, 6, 14, 63)),
+                            raw: None,
                         }),
                     ],
-                    position: Some(Position::new(1, 3, 2, 6, 14, 63))
+                    position: Some(Position::new(1, 3, 2, 6, 14, 63)),
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 6, 14, 63))
+                position: Some(Position::new(1, 1, 0, 6, 14, 63)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 6, 14, 63))
+            position: Some(Position::new(1, 1, 0, 6, 14, 63)),
+            raw: None,
         }),
         "should keep the correct number of spaces in a blockquote (text)"
     );
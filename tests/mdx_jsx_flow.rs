@@ -1,6 +1,9 @@
 mod test_utils;
 use markdown::{
-    mdast::{List, ListItem, MdxJsxFlowElement, Node, Paragraph, Root, Text},
+    mdast::{
+        AttributeContent, AttributeValue, List, ListItem, MdxJsxAttribute, MdxJsxFlowElement, Node,
+        Paragraph, Root, Text,
+    },
     message, to_html_with_options, to_mdast,
     unist::Position,
     Constructs, Options, ParseOptions,
@@ -131,13 +134,13 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
         to_html_with_options("* <!a>\n1. b", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`) (markdown-rs:unexpected-character)",
+        "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`) (markdown-rs:unexpected-character-before-name)",
         "should handle crash in containers gracefully"
     );
 
     assert_eq!(
          to_html_with_options("> <X\n/>", &mdx).err().unwrap().to_string(),
-        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-jsx)",
         "should not support lazy flow (1)"
     );
 
@@ -145,7 +148,7 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
         to_html_with_options("> a\n> <X\n/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "3:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "3:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-jsx)",
         "should not support lazy flow (2)"
     );
 
@@ -153,7 +156,7 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
         to_html_with_options("> <a b='\nc'/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-jsx)",
         "should not support lazy flow (3)"
     );
 
@@ -161,7 +164,7 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
         to_html_with_options("> <a b='c\n'/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-jsx)",
         "should not support lazy flow (4)"
     );
 
@@ -169,7 +172,7 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
         to_html_with_options("> <a b='c\nd'/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "2:1: Unexpected lazy line in jsx in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-jsx)",
         "should not support lazy flow (5)"
     );
 
@@ -177,7 +180,7 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
         to_html_with_options("> <a b={c\nd}/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-expression)",
         "should not support lazy flow (6)"
     );
 
@@ -185,7 +188,7 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
         to_html_with_options("> <a {b\nc}/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy)",
+        "2:1: Unexpected lazy line in expression in container, expected line to be prefixed with `>` when in a block quote, whitespace when in a list, etc (markdown-rs:unexpected-lazy-in-expression)",
         "should not support lazy flow (7)"
     );
 
@@ -205,27 +208,92 @@ fn mdx_jsx_flow_essence() -> Result<(), message::Message> {
                     ordered: false,
                     spread: false,
                     start: None,
+                    kind: None,
                     children: vec![Node::ListItem(ListItem {
                         checked: None,
                         spread: false,
                         children: vec![Node::Paragraph(Paragraph {
                             children: vec![Node::Text(Text {
                                 value: "a".into(),
-                                position: Some(Position::new(2, 5, 7, 2, 6, 8))
+                                position: Some(Position::new(2, 5, 7, 2, 6, 8)),
+                                raw: None,
                             }),],
-                            position: Some(Position::new(2, 5, 7, 2, 6, 8))
+                            position: Some(Position::new(2, 5, 7, 2, 6, 8)),
+                            raw: None,
                         })],
-                        position: Some(Position::new(2, 1, 3, 2, 6, 8))
+                        position: Some(Position::new(2, 1, 3, 2, 6, 8)),
+                        markers: None,
+                        raw: None,
                     })],
-                    position: Some(Position::new(2, 1, 3, 2, 6, 8))
+                    position: Some(Position::new(2, 1, 3, 2, 6, 8)),
+                    markers: None,
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 3, 4, 12))
+                position: Some(Position::new(1, 1, 0, 3, 4, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 3, 4, 12))
+            position: Some(Position::new(1, 1, 0, 3, 4, 12)),
+            raw: None,
         }),
         "should support mdx jsx (flow) as `MdxJsxFlowElement`s in mdast"
     );
 
+    let void = ParseOptions {
+        mdx_allow_unclosed_void_elements: true,
+        ..ParseOptions::mdx()
+    };
+
+    assert_eq!(
+        to_mdast("<img src=\"x\">", &void)?,
+        Node::Root(Root {
+            children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                name: Some("img".into()),
+                attributes: vec![AttributeContent::Property(MdxJsxAttribute {
+                    position: Some(Position::new(1, 6, 5, 1, 13, 12)),
+                    name: "src".into(),
+                    value: Some(AttributeValue::Literal("x".into())),
+                })],
+                children: vec![],
+                position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+            raw: None,
+        }),
+        "should support unclosed void elements (flow) w/ `mdx_allow_unclosed_void_elements`"
+    );
+
+    assert_eq!(
+        to_mdast("<p>\n  <hr>\n</p>", &void)?,
+        Node::Root(Root {
+            children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                name: Some("p".into()),
+                attributes: vec![],
+                children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                    name: Some("hr".into()),
+                    attributes: vec![],
+                    children: vec![],
+                    position: Some(Position::new(2, 3, 6, 2, 7, 10)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 3, 5, 15)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 3, 5, 15)),
+            raw: None,
+        }),
+        "should support unclosed void elements (flow) in elements w/ `mdx_allow_unclosed_void_elements`"
+    );
+
+    assert_eq!(
+        to_mdast("<div>\n  <img>\n</span>", &void)
+            .err()
+            .unwrap()
+            .to_string(),
+        "3:1-3:8: Unexpected closing tag `</span>`, expected corresponding closing tag for `<div>` (1:1) (markdown-rs:end-tag-mismatch)",
+        "should still crash on mismatched tags w/ `mdx_allow_unclosed_void_elements`"
+    );
+
     Ok(())
 }
 
@@ -341,3 +409,90 @@ fn mdx_jsx_flow_interleaving_with_expressions() -> Result<(), message::Message>
 
     Ok(())
 }
+
+/// Get the source of each attribute of all JSX elements, in tree order.
+fn attributes(value: &str) -> Result<Vec<&str>, message::Message> {
+    fn visit<'a>(value: &'a str, node: &Node, list: &mut Vec<&'a str>) {
+        let attributes = match node {
+            Node::MdxJsxFlowElement(node) => &node.attributes[..],
+            Node::MdxJsxTextElement(node) => &node.attributes[..],
+            _ => &[],
+        };
+
+        for attribute in attributes {
+            let position = match attribute {
+                AttributeContent::Expression(attribute) => &attribute.position,
+                AttributeContent::Property(attribute) => &attribute.position,
+            };
+            let position = position.as_ref().unwrap();
+            list.push(&value[position.start.offset..position.end.offset]);
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                visit(value, child, list);
+            }
+        }
+    }
+
+    let mut list = vec![];
+    visit(value, &to_mdast(value, &ParseOptions::mdx())?, &mut list);
+    Ok(list)
+}
+
+#[test]
+fn mdx_jsx_attribute_position() -> Result<(), message::Message> {
+    assert_eq!(
+        attributes("<a b c:d e='&amp;' f=\"g\" h={i} {...j} />")?,
+        vec!["b", "c:d", "e='&amp;'", "f=\"g\"", "h={i}", "{...j}"],
+        "should support positions of attributes (flow)"
+    );
+
+    assert_eq!(
+        attributes("x <a b c='d' e={f} {...g} /> y")?,
+        vec!["b", "c='d'", "e={f}", "{...g}"],
+        "should support positions of attributes (text)"
+    );
+
+    assert_eq!(
+        attributes("<a\n  b\n  c='d\ne'\n  f={\n    g\n  }\n  {...h}\n/>")?,
+        vec!["b", "c='d\ne'", "f={\n    g\n  }", "{...h}"],
+        "should support positions of attributes across lines (flow)"
+    );
+
+    assert_eq!(
+        attributes("x <a\nb c={\nd} e='f\ng'\n/> y")?,
+        vec!["b", "c={\nd}", "e='f\ng'"],
+        "should support positions of attributes across lines (text)"
+    );
+
+    assert_eq!(
+        attributes("> <a\n> b='c\n> d' e={f}\n> />")?,
+        vec!["b='c\n> d'", "e={f}"],
+        "should support positions of attributes in containers"
+    );
+
+    let tree = to_mdast("<a\n  b='c' />", &ParseOptions::mdx())?;
+
+    assert_eq!(
+        tree,
+        Node::Root(Root {
+            children: vec![Node::MdxJsxFlowElement(MdxJsxFlowElement {
+                name: Some("a".into()),
+                attributes: vec![AttributeContent::Property(MdxJsxAttribute {
+                    position: Some(Position::new(2, 3, 5, 2, 8, 10)),
+                    name: "b".into(),
+                    value: Some(AttributeValue::Literal("c".into())),
+                })],
+                children: vec![],
+                position: Some(Position::new(1, 1, 0, 2, 11, 13)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 11, 13)),
+            raw: None,
+        }),
+        "should support positions of attributes as `MdxJsxAttribute`s in mdast"
+    );
+
+    Ok(())
+}
@@ -56,16 +56,19 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                 Node::MdxFlowExpression(MdxFlowExpression {
                     value: "1".into(),
                     position: Some(Position::new(1, 1, 0, 1, 4, 3)),
-                    stops: vec![(0, 1)]
+                    stops: vec![(0, 1)],
+                    raw: None,
                 }),
                 Node::MdxJsxFlowElement(MdxJsxFlowElement {
                     name: Some("a".into()),
                     attributes: vec![],
                     children: vec![],
-                    position: Some(Position::new(1, 4, 3, 1, 8, 7))
+                    position: Some(Position::new(1, 4, 3, 1, 8, 7)),
+                    raw: None,
                 })
             ],
-            position: Some(Position::new(1, 1, 0, 1, 8, 7))
+            position: Some(Position::new(1, 1, 0, 1, 8, 7)),
+            raw: None,
         }),
         "should support mdx jsx (text) with expression child"
     );
@@ -79,11 +82,14 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                 children: vec![Node::MdxFlowExpression(MdxFlowExpression {
                     value: "1".into(),
                     position: Some(Position::new(1, 4, 3, 1, 7, 6)),
-                    stops: vec![(0, 4)]
+                    stops: vec![(0, 4)],
+                    raw: None,
                 })],
-                position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                raw: None,
             }),],
-            position: Some(Position::new(1, 1, 0, 1, 11, 10))
+            position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+            raw: None,
         }),
         "should support mdx jsx (text) with expression child"
     );
@@ -95,22 +101,27 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::MdxJsxTextElement(MdxJsxTextElement {
                         name: Some("b".into()),
                         attributes: vec![],
                         children: vec![],
-                        position: Some(Position::new(1, 3, 2, 1, 8, 7))
+                        position: Some(Position::new(1, 3, 2, 1, 8, 7)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " c.".into(),
-                        position: Some(Position::new(1, 8, 7, 1, 11, 10))
+                        position: Some(Position::new(1, 8, 7, 1, 11, 10)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 11, 10))
+            position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+            raw: None,
         }),
         "should support mdx jsx (text) as `MdxJsxTextElement`s in mdast (self-closing)"
     );
@@ -122,7 +133,8 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                 children: vec![
                     Node::Text(Text {
                         value: "a ".into(),
-                        position: Some(Position::new(1, 1, 0, 1, 3, 2))
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
                     }),
                     Node::MdxJsxTextElement(MdxJsxTextElement {
                         name: Some("b".into()),
@@ -132,22 +144,29 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                                 children: vec![
                                     Node::Text(Text {
                                         value: "c".into(),
-                                        position: Some(Position::new(1, 7, 6, 1, 8, 7))
+                                        position: Some(Position::new(1, 7, 6, 1, 8, 7)),
+                                        raw: None,
                                     }),
                                 ],
-                                position: Some(Position::new(1, 6, 5, 1, 9, 8))
+                                position: Some(Position::new(1, 6, 5, 1, 9, 8)),
+                                markers: None,
+                                raw: None,
                             }),
                         ],
-                        position: Some(Position::new(1, 3, 2, 1, 13, 12))
+                        position: Some(Position::new(1, 3, 2, 1, 13, 12)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: " d.".into(),
-                        position: Some(Position::new(1, 13, 12, 1, 16, 15))
+                        position: Some(Position::new(1, 13, 12, 1, 16, 15)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 16, 15))
+                position: Some(Position::new(1, 1, 0, 1, 16, 15)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 16, 15))
+            position: Some(Position::new(1, 1, 0, 1, 16, 15)),
+            raw: None,
         }),
         "should support mdx jsx (text) as `MdxJsxTextElement`s in mdast (matched open and close tags)"
     );
@@ -161,16 +180,20 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a:b".into()),
                         attributes: vec![],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 8, 7))
+                        position: Some(Position::new(1, 1, 0, 1, 8, 7)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 8, 7, 1, 9, 8))
+                        position: Some(Position::new(1, 8, 7, 1, 9, 8)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 9, 8))
+                position: Some(Position::new(1, 1, 0, 1, 9, 8)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 9, 8))
+            position: Some(Position::new(1, 1, 0, 1, 9, 8)),
+            raw: None,
         }),
         "should support mdx jsx (text) as `MdxJsxTextElement`s in mdast (namespace in tag name)"
     );
@@ -184,16 +207,20 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a.b.c".into()),
                         attributes: vec![],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 10, 9))
+                        position: Some(Position::new(1, 1, 0, 1, 10, 9)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 10, 9, 1, 11, 10))
+                        position: Some(Position::new(1, 10, 9, 1, 11, 10)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 11, 10))
+                position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 11, 10))
+            position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+            raw: None,
         }),
         "should support mdx jsx (text) as `MdxJsxTextElement`s in mdast (members in tag name)"
     );
@@ -206,20 +233,25 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                     Node::MdxJsxTextElement(MdxJsxTextElement {
                         name: Some("a".into()),
                         attributes: vec![AttributeContent::Expression(MdxJsxExpressionAttribute {
+                            position: Some(Position::new(1, 4, 3, 1, 10, 9)),
                             value: "...b".into(),
                             stops: vec![(0, 4)]
                         })],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                        position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 13, 12, 1, 14, 13))
+                        position: Some(Position::new(1, 13, 12, 1, 14, 13)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 14, 13))
+                position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 14, 13))
+            position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+            raw: None,
         }),
         "should support mdx jsx (text) as `MdxJsxTextElement`s in mdast (attribute expression)"
     );
@@ -233,25 +265,31 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a".into()),
                         attributes: vec![
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 4, 3, 1, 5, 4)),
                                 name: "b".into(),
                                 value: None,
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 6, 5, 1, 9, 8)),
                                 name: "c:d".into(),
                                 value: None,
                             })
                         ],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 12, 11))
+                        position: Some(Position::new(1, 1, 0, 1, 12, 11)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 12, 11, 1, 13, 12))
+                        position: Some(Position::new(1, 12, 11, 1, 13, 12)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 13, 12))
+                position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 13, 12))
+            position: Some(Position::new(1, 1, 0, 1, 13, 12)),
+            raw: None,
         }),
         "should support mdx jsx (text) as `MdxJsxTextElement`s in mdast (property names)"
     );
@@ -265,14 +303,17 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a".into()),
                         attributes: vec![
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 4, 3, 1, 9, 8)),
                                 name: "b".into(),
                                 value: Some(AttributeValue::Literal("c".into())),
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 10, 9, 1, 15, 14)),
                                 name: "d".into(),
                                 value: Some(AttributeValue::Literal("e".into())),
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 16, 15, 1, 21, 20)),
                                 name: "f".into(),
                                 value: Some(AttributeValue::Expression(AttributeValueExpression {
                                     value: "g".into(),
@@ -281,16 +322,20 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                             }),
                         ],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 24, 23))
+                        position: Some(Position::new(1, 1, 0, 1, 24, 23)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 24, 23, 1, 25, 24))
+                        position: Some(Position::new(1, 24, 23, 1, 25, 24)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 25, 24))
+                position: Some(Position::new(1, 1, 0, 1, 25, 24)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 25, 24))
+            position: Some(Position::new(1, 1, 0, 1, 25, 24)),
+            raw: None,
         }),
         "should support mdx jsx (text) as `MdxJsxTextElement`s in mdast (attribute values)"
     );
@@ -304,21 +349,26 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a".into()),
                         attributes: vec![
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 4, 3, 1, 117, 116)),
                                 name: "b".into(),
                                 value: Some(AttributeValue::Literal("\u{a0} & © Æ &Dcaron; ¾ &HilbertSpace; &DifferentialD; &ClockwiseContourIntegral; &ngE;".into())),
                             }),
                         ],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 120, 119))
+                        position: Some(Position::new(1, 1, 0, 1, 120, 119)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 120, 119, 1, 121, 120))
+                        position: Some(Position::new(1, 120, 119, 1, 121, 120)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 121, 120))
+                position: Some(Position::new(1, 1, 0, 1, 121, 120)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 121, 120))
+            position: Some(Position::new(1, 1, 0, 1, 121, 120)),
+            raw: None,
         }),
         "should support character references (HTML 4, named) in JSX attribute values"
     );
@@ -335,25 +385,31 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a".into()),
                         attributes: vec![
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 4, 3, 1, 33, 32)),
                                 name: "b".into(),
                                 value: Some(AttributeValue::Literal("# Ӓ Ϡ �".into())),
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 34, 33, 1, 60, 59)),
                                 name: "c".into(),
                                 value: Some(AttributeValue::Literal("\" ആ ಫ".into())),
                             }),
                         ],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 63, 62))
+                        position: Some(Position::new(1, 1, 0, 1, 63, 62)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 63, 62, 1, 64, 63))
+                        position: Some(Position::new(1, 63, 62, 1, 64, 63)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 64, 63))
+                position: Some(Position::new(1, 1, 0, 1, 64, 63)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 64, 63))
+            position: Some(Position::new(1, 1, 0, 1, 64, 63)),
+            raw: None,
         }),
         "should support character references (numeric) in JSX attribute values"
     );
@@ -367,21 +423,26 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a".into()),
                         attributes: vec![
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 4, 3, 1, 75, 74)),
                                 name: "b".into(),
                                 value: Some(AttributeValue::Literal("&nbsp &x; &#; &#x; &#987654321; &#abcdef0; &ThisIsNotDefined; &hi?;".into())),
                             })
                         ],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 78, 77))
+                        position: Some(Position::new(1, 1, 0, 1, 78, 77)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 78, 77, 1, 79, 78))
+                        position: Some(Position::new(1, 78, 77, 1, 79, 78)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 79, 78))
+                position: Some(Position::new(1, 1, 0, 1, 79, 78)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 79, 78))
+            position: Some(Position::new(1, 1, 0, 1, 79, 78)),
+            raw: None,
         }),
         "should not support things that look like character references but aren’t"
     );
@@ -395,29 +456,36 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a".into()),
                         attributes: vec![
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 4, 5, 1, 5, 6)),
                                 name: "b".into(),
                                 value: None,
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 7, 10, 1, 8, 11)),
                                 name: "c".into(),
                                 value: None,
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(1, 10, 15, 1, 11, 16)),
                                 name: "d".into(),
                                 value: None,
                             })
                         ],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 1, 22, 21))
+                        position: Some(Position::new(1, 1, 0, 1, 14, 21)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(1, 22, 21, 1, 23, 22))
+                        position: Some(Position::new(1, 14, 21, 1, 15, 22)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 1, 23, 22))
+                position: Some(Position::new(1, 1, 0, 1, 15, 22)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 23, 22))
+            position: Some(Position::new(1, 1, 0, 1, 15, 22)),
+            raw: None,
         }),
         "should support unicode whitespace in a lot of places"
     );
@@ -431,29 +499,36 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
                         name: Some("a".into()),
                         attributes: vec![
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(2, 1, 3, 2, 2, 4)),
                                 name: "b".into(),
                                 value: None,
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(3, 1, 6, 3, 2, 7)),
                                 name: "c".into(),
                                 value: None,
                             }),
                             AttributeContent::Property(MdxJsxAttribute {
+                                position: Some(Position::new(4, 2, 9, 4, 3, 10)),
                                 name: "d".into(),
                                 value: None,
                             })
                         ],
                         children: vec![],
-                        position: Some(Position::new(1, 1, 0, 5, 3, 13))
+                        position: Some(Position::new(1, 1, 0, 5, 3, 13)),
+                        raw: None,
                     }),
                     Node::Text(Text {
                         value: ".".into(),
-                        position: Some(Position::new(5, 3, 13, 5, 4, 14))
+                        position: Some(Position::new(5, 3, 13, 5, 4, 14)),
+                        raw: None,
                     })
                 ],
-                position: Some(Position::new(1, 1, 0, 5, 4, 14))
+                position: Some(Position::new(1, 1, 0, 5, 4, 14)),
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 5, 4, 14))
+            position: Some(Position::new(1, 1, 0, 5, 4, 14)),
+            raw: None,
         }),
         "should support line endings in a lot of places"
     );
@@ -535,6 +610,96 @@ fn mdx_jsx_text_core() -> Result<(), message::Message> {
         "should crash on unclosed jsx after closed jsx"
     );
 
+    let void = ParseOptions {
+        mdx_allow_unclosed_void_elements: true,
+        ..ParseOptions::mdx()
+    };
+
+    assert_eq!(
+        to_mdast("a <br> b", &mdx.parse).err().unwrap().to_string(),
+        "1:9: Expected a closing tag for `<br>` (1:3) before the end of `Paragraph` (markdown-rs:end-tag-mismatch)",
+        "should crash on unclosed void elements by default"
+    );
+
+    assert_eq!(
+        to_mdast("a <br> b", &void)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
+                    }),
+                    Node::MdxJsxTextElement(MdxJsxTextElement {
+                        name: Some("br".into()),
+                        attributes: vec![],
+                        children: vec![],
+                        position: Some(Position::new(1, 3, 2, 1, 7, 6)),
+                        raw: None,
+                    }),
+                    Node::Text(Text {
+                        value: " b".into(),
+                        position: Some(Position::new(1, 7, 6, 1, 9, 8)),
+                        raw: None,
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 9, 8)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 9, 8)),
+            raw: None,
+        }),
+        "should support unclosed void elements w/ `mdx_allow_unclosed_void_elements`"
+    );
+
+    assert_eq!(
+        to_mdast("a <br></br> b", &void)?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "a ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                        raw: None,
+                    }),
+                    Node::MdxJsxTextElement(MdxJsxTextElement {
+                        name: Some("br".into()),
+                        attributes: vec![],
+                        children: vec![],
+                        position: Some(Position::new(1, 3, 2, 1, 12, 11)),
+                        raw: None,
+                    }),
+                    Node::Text(Text {
+                        value: " b".into(),
+                        position: Some(Position::new(1, 12, 11, 1, 14, 13)),
+                        raw: None,
+                    }),
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+            raw: None,
+        }),
+        "should support closed void elements w/ `mdx_allow_unclosed_void_elements`"
+    );
+
+    assert_eq!(
+        to_mdast("a <br> <span> b", &void)
+            .err()
+            .unwrap()
+            .to_string(),
+        "1:16: Expected a closing tag for `<span>` (1:8) before the end of `Paragraph` (markdown-rs:end-tag-mismatch)",
+        "should still crash on other unclosed elements w/ `mdx_allow_unclosed_void_elements`"
+    );
+
+    assert_eq!(
+        to_mdast("a <Br> b", &void).err().unwrap().to_string(),
+        "1:9: Expected a closing tag for `<Br>` (1:3) before the end of `Paragraph` (markdown-rs:end-tag-mismatch)",
+        "should still crash on unclosed components w/ `mdx_allow_unclosed_void_elements`"
+    );
+
     Ok(())
 }
 
@@ -719,7 +884,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <!> b", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`) (markdown-rs:unexpected-character)",
+        "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`) (markdown-rs:unexpected-character-before-name)",
         "should crash on a nonconforming start identifier"
     );
 
@@ -727,7 +892,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a </(> b.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:5: Unexpected character `(` (U+0028) before name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character)",
+        "1:5: Unexpected character `(` (U+0028) before name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character-before-name)",
         "should crash on a nonconforming start identifier in a closing tag"
     );
 
@@ -741,7 +906,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <© /> b.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:4: Unexpected character U+00A9 before name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character)",
+        "1:4: Unexpected character U+00A9 before name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character-before-name)",
         "should crash on non-conforming non-ascii identifier start characters"
     );
 
@@ -749,7 +914,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <!--b-->", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`) (markdown-rs:unexpected-character)",
+        "1:4: Unexpected character `!` (U+0021) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a comment in MDX, use `{/* text */}`) (markdown-rs:unexpected-character-before-name)",
         "should crash nicely on what might be a comment"
     );
 
@@ -757,7 +922,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <// b\nc/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:5: Unexpected character `/` (U+002F) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: JS comments in JSX tags are not supported in MDX) (markdown-rs:unexpected-character)",
+        "1:5: Unexpected character `/` (U+002F) before name, expected a character that can start a name, such as a letter, `$`, or `_` (note: JS comments in JSX tags are not supported in MDX) (markdown-rs:unexpected-character-before-name)",
         "should crash nicely on JS line comments inside tags (1)"
     );
 
@@ -765,7 +930,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <b// c\nd/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:6: Unexpected character `/` (U+002F) after self-closing slash, expected `>` to end the tag (note: JS comments in JSX tags are not supported in MDX) (markdown-rs:unexpected-character)",
+        "1:6: Unexpected character `/` (U+002F) after self-closing slash, expected `>` to end the tag (note: JS comments in JSX tags are not supported in MDX) (markdown-rs:unexpected-character-after-self-closing-slash)",
         "should crash nicely JS line comments inside tags (2)"
     );
 
@@ -773,7 +938,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a </*b*/c>", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:5: Unexpected character `*` (U+002A) before name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character)",
+        "1:5: Unexpected character `*` (U+002A) before name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character-before-name)",
         "should crash nicely JS multiline comments inside tags (1)"
     );
 
@@ -781,7 +946,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <b/*c*/>", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:6: Unexpected character `*` (U+002A) after self-closing slash, expected `>` to end the tag (markdown-rs:unexpected-character)",
+        "1:6: Unexpected character `*` (U+002A) after self-closing slash, expected `>` to end the tag (markdown-rs:unexpected-character-after-self-closing-slash)",
         "should crash nicely JS multiline comments inside tags (2)"
     );
 
@@ -795,7 +960,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a¬ /> b.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:5: Unexpected character U+00AC in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:5: Unexpected character U+00AC in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-in-name)",
         "should crash on non-conforming non-ascii identifier continuation characters"
     );
 
@@ -803,7 +968,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <b@c.d>", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:5: Unexpected character `@` (U+0040) in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character)",
+        "1:5: Unexpected character `@` (U+0040) in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character-in-name)",
         "should crash nicely on what might be an email link"
     );
 
@@ -817,7 +982,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a?> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:5: Unexpected character `?` (U+003F) in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:5: Unexpected character `?` (U+003F) in name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-in-name)",
         "should crash on nonconforming identifier continuation characters"
     );
 
@@ -831,7 +996,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
          to_html_with_options("a <b.c@d.e>", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:7: Unexpected character `@` (U+0040) in member name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character)",
+        "1:7: Unexpected character `@` (U+0040) in member name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character-in-member-name)",
         "should crash nicely on what might be an email link in member names"
     );
 
@@ -845,7 +1010,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a:+> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:6: Unexpected character `+` (U+002B) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character)",
+        "1:6: Unexpected character `+` (U+002B) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character-before-local-name)",
         "should crash on a nonconforming character to start a local name"
     );
 
@@ -853,7 +1018,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <http://example.com>", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:9: Unexpected character `/` (U+002F) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character)",
+        "1:9: Unexpected character `/` (U+002F) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (note: to create a link in MDX, use `[text](url)`) (markdown-rs:unexpected-character-before-local-name)",
         "should crash nicely on what might be a protocol in local names"
     );
 
@@ -861,7 +1026,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <http: >", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:10: Unexpected character `>` (U+003E) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character)",
+        "1:10: Unexpected character `>` (U+003E) before local name, expected a character that can start a name, such as a letter, `$`, or `_` (markdown-rs:unexpected-character-before-local-name)",
         "should crash nicely on what might be a protocol in local names"
     );
 
@@ -869,7 +1034,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a:b|> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:7: Unexpected character `|` (U+007C) in local name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:7: Unexpected character `|` (U+007C) in local name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-in-local-name)",
         "should crash on a nonconforming character in a local name"
     );
 
@@ -877,7 +1042,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a..> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:6: Unexpected character `.` (U+002E) before member name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:6: Unexpected character `.` (U+002E) before member name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-before-member-name)",
         "should crash on a nonconforming character to start a member name"
     );
 
@@ -885,7 +1050,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a.b,> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:7: Unexpected character `,` (U+002C) in member name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:7: Unexpected character `,` (U+002C) in member name, expected a name character such as letters, digits, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-in-member-name)",
         "should crash on a nonconforming character in a member name"
     );
 
@@ -893,7 +1058,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a:b .> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:8: Unexpected character `.` (U+002E) after local name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:8: Unexpected character `.` (U+002E) after local name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-after-local-name)",
         "should crash on a nonconforming character after a local name"
     );
 
@@ -901,7 +1066,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a.b :> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:8: Unexpected character `:` (U+003A) after member name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:8: Unexpected character `:` (U+003A) after member name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-after-member-name)",
         "should crash on a nonconforming character after a member name"
     );
 
@@ -909,7 +1074,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a => c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:6: Unexpected character `=` (U+003D) after name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:6: Unexpected character `=` (U+003D) after name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-after-name)",
         "should crash on a nonconforming character after name"
     );
 
@@ -971,7 +1136,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <b {...p}~>c</b>.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:12: Unexpected character `~` (U+007E) before attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:12: Unexpected character `~` (U+007E) before attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-before-attribute-name)",
         "should crash on a nonconforming character before an attribute name"
     );
 
@@ -979,7 +1144,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <b {...", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:10: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:10: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash on a missing closing brace in attribute expression"
     );
 
@@ -987,7 +1152,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b@> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:7: Unexpected character `@` (U+0040) in attribute name, expected an attribute name character such as letters, digits, `$`, or `_`; `=` to initialize a value; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:7: Unexpected character `@` (U+0040) in attribute name, expected an attribute name character such as letters, digits, `$`, or `_`; `=` to initialize a value; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-in-attribute-name)",
         "should crash on a nonconforming character in attribute name"
     );
 
@@ -1007,7 +1172,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b 1> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:8: Unexpected character `1` (U+0031) after attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:8: Unexpected character `1` (U+0031) after attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag (markdown-rs:unexpected-character-after-attribute-name)",
         "should crash on a nonconforming character after an attribute name"
     );
 
@@ -1015,7 +1180,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b:#> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:8: Unexpected character `#` (U+0023) before local attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:8: Unexpected character `#` (U+0023) before local attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag (markdown-rs:unexpected-character-before-local-attribute-name)",
         "should crash on a nonconforming character to start a local attribute name"
     );
 
@@ -1023,7 +1188,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b:c%> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:9: Unexpected character `%` (U+0025) in local attribute name, expected an attribute name character such as letters, digits, `$`, or `_`; `=` to initialize a value; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:9: Unexpected character `%` (U+0025) in local attribute name, expected an attribute name character such as letters, digits, `$`, or `_`; `=` to initialize a value; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-in-local-attribute-name)",
         "should crash on a nonconforming character in a local attribute name"
     );
 
@@ -1031,7 +1196,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b:c ^> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:10: Unexpected character `^` (U+005E) after local attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:10: Unexpected character `^` (U+005E) after local attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; `=` to initialize a value; or the end of the tag (markdown-rs:unexpected-character-after-local-attribute-name)",
         "should crash on a nonconforming character after a local attribute name"
     );
 
@@ -1051,7 +1216,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b=``> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:8: Unexpected character `` ` `` (U+0060) before attribute value, expected a character that can start an attribute value, such as `\"`, `'`, or `{` (markdown-rs:unexpected-character)",
+        "1:8: Unexpected character `` ` `` (U+0060) before attribute value, expected a character that can start an attribute value, such as `\"`, `'`, or `{` (markdown-rs:unexpected-character-before-attribute-value)",
         "should crash on a nonconforming character before an attribute value"
     );
 
@@ -1059,7 +1224,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b=<c />> d.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:8: Unexpected character `<` (U+003C) before attribute value, expected a character that can start an attribute value, such as `\"`, `'`, or `{` (note: to use an element or fragment as a prop value in MDX, use `{<element />}`) (markdown-rs:unexpected-character)",
+        "1:8: Unexpected character `<` (U+003C) before attribute value, expected a character that can start an attribute value, such as `\"`, `'`, or `{` (note: to use an element or fragment as a prop value in MDX, use `{<element />}`) (markdown-rs:unexpected-character-before-attribute-value)",
         "should crash nicely on what might be a fragment, element as prop value"
     );
 
@@ -1067,7 +1232,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b=\"> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:13: Unexpected end of file in attribute value, expected a corresponding closing quote `\"` (U+0022) (markdown-rs:unexpected-eof)",
+        "1:13: Unexpected end of file in attribute value, expected a corresponding closing quote `\"` (U+0022) (markdown-rs:unexpected-eof-in-attribute-value)",
         "should crash on a missing closing quote in double quoted attribute value"
     );
 
@@ -1075,7 +1240,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b=\"> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:13: Unexpected end of file in attribute value, expected a corresponding closing quote `\"` (U+0022) (markdown-rs:unexpected-eof)",
+        "1:13: Unexpected end of file in attribute value, expected a corresponding closing quote `\"` (U+0022) (markdown-rs:unexpected-eof-in-attribute-value)",
         "should crash on a missing closing quote in single quoted attribute value"
     );
 
@@ -1083,7 +1248,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b={> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:13: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof)",
+        "1:13: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
         "should crash on a missing closing brace in an attribute value expression"
     );
 
@@ -1091,7 +1256,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a b=\"\"*> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:10: Unexpected character `*` (U+002A) before attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character)",
+        "1:10: Unexpected character `*` (U+002A) before attribute name, expected a character that can start an attribute name, such as a letter, `$`, or `_`; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-before-attribute-name)",
         "should crash on a nonconforming character after an attribute value"
     );
 
@@ -1111,7 +1276,7 @@ fn mdx_jsx_text_complete() -> Result<(), message::Message> {
         to_html_with_options("a <a/b> c.", &mdx)
             .err()
             .unwrap().to_string(),
-        "1:6: Unexpected character `b` (U+0062) after self-closing slash, expected `>` to end the tag (markdown-rs:unexpected-character)",
+        "1:6: Unexpected character `b` (U+0062) after self-closing slash, expected `>` to end the tag (markdown-rs:unexpected-character-after-self-closing-slash)",
         "should crash on a nonconforming character after a self-closing slash"
     );
 
@@ -0,0 +1,80 @@
+use markdown::{
+    message, to_html_with_options, BooleanAttributeStyle, CompileOptions, Options, ParseOptions,
+    QuoteKind,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn attribute_style() -> Result<(), message::Message> {
+    let doc =
+        "* [x] a [b](c 'd\"e') ![f'g](h \"i'j\")[^k]\n* [ ] l\n\n```m\nn\n```\n\n[^k]: o [^k]";
+
+    assert_eq!(
+        to_html_with_options(doc, &Options::gfm())?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> a <a href=\"c\" title=\"d&quot;e\">b</a> <img src=\"h\" alt=\"f'g\" title=\"i'j\" /><sup><a href=\"#user-content-fn-k\" id=\"user-content-fnref-k\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></li>\n<li><input type=\"checkbox\" disabled=\"\" /> l</li>\n</ul>\n<pre><code class=\"language-m\">n\n</code></pre>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-k\">\n<p>o <sup><a href=\"#user-content-fn-k\" id=\"user-content-fnref-k-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> <a href=\"#user-content-fnref-k\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-k-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>\n</li>\n</ol>\n</section>\n",
+        "should use double quotes and empty boolean attributes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            doc,
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    attribute_quote: QuoteKind::Single,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<ul>\n<li><input type='checkbox' disabled='' checked='' /> a <a href='c' title='d&quot;e'>b</a> <img src='h' alt='f&#x27;g' title='i&#x27;j' /><sup><a href='#user-content-fn-k' id='user-content-fnref-k' data-footnote-ref='' aria-describedby='footnote-label'>1</a></sup></li>\n<li><input type='checkbox' disabled='' /> l</li>\n</ul>\n<pre><code class='language-m'>n\n</code></pre>\n<section data-footnotes='' class='footnotes'><h2 id='footnote-label' class='sr-only'>Footnotes</h2>\n<ol>\n<li id='user-content-fn-k'>\n<p>o <sup><a href='#user-content-fn-k' id='user-content-fnref-k-2' data-footnote-ref='' aria-describedby='footnote-label'>1</a></sup> <a href='#user-content-fnref-k' data-footnote-backref='' aria-label='Back to content' class='data-footnote-backref'>↩</a> <a href='#user-content-fnref-k-2' data-footnote-backref='' aria-label='Back to content' class='data-footnote-backref'>↩<sup>2</sup></a></p>\n</li>\n</ol>\n</section>\n",
+        "should support `attribute_quote: QuoteKind::Single`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            doc,
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    boolean_attribute_style: BooleanAttributeStyle::Repeat,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"disabled\" checked=\"checked\" /> a <a href=\"c\" title=\"d&quot;e\">b</a> <img src=\"h\" alt=\"f'g\" title=\"i'j\" /><sup><a href=\"#user-content-fn-k\" id=\"user-content-fnref-k\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></li>\n<li><input type=\"checkbox\" disabled=\"disabled\" /> l</li>\n</ul>\n<pre><code class=\"language-m\">n\n</code></pre>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-k\">\n<p>o <sup><a href=\"#user-content-fn-k\" id=\"user-content-fnref-k-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup> <a href=\"#user-content-fnref-k\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-k-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>\n</li>\n</ol>\n</section>\n",
+        "should support `boolean_attribute_style: BooleanAttributeStyle::Repeat`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            doc,
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    attribute_quote: QuoteKind::Single,
+                    boolean_attribute_style: BooleanAttributeStyle::Omit,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<ul>\n<li><input type='checkbox' disabled checked /> a <a href='c' title='d&quot;e'>b</a> <img src='h' alt='f&#x27;g' title='i&#x27;j' /><sup><a href='#user-content-fn-k' id='user-content-fnref-k' data-footnote-ref='' aria-describedby='footnote-label'>1</a></sup></li>\n<li><input type='checkbox' disabled /> l</li>\n</ul>\n<pre><code class='language-m'>n\n</code></pre>\n<section data-footnotes='' class='footnotes'><h2 id='footnote-label' class='sr-only'>Footnotes</h2>\n<ol>\n<li id='user-content-fn-k'>\n<p>o <sup><a href='#user-content-fn-k' id='user-content-fnref-k-2' data-footnote-ref='' aria-describedby='footnote-label'>1</a></sup> <a href='#user-content-fnref-k' data-footnote-backref='' aria-label='Back to content' class='data-footnote-backref'>↩</a> <a href='#user-content-fnref-k-2' data-footnote-backref='' aria-label='Back to content' class='data-footnote-backref'>↩<sup>2</sup></a></p>\n</li>\n</ol>\n</section>\n",
+        "should support `boolean_attribute_style: BooleanAttributeStyle::Omit`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](b'c) <https://d'e>",
+            &Options {
+                compile: CompileOptions {
+                    attribute_quote: QuoteKind::Single,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href='b&#x27;c'>a</a> <a href='https://d&#x27;e'>https://d'e</a></p>",
+        "should encode single quotes in urls w/ `attribute_quote: QuoteKind::Single`"
+    );
+
+    Ok(())
+}
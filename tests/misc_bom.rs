@@ -1,8 +1,13 @@
-use markdown::to_html;
+use markdown::{
+    mdast::{Heading, Node, Paragraph, Root, Text},
+    message, to_html, to_mdast,
+    unist::Position,
+    Location, ParseOptions,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
-fn bom() {
+fn bom() -> Result<(), message::Message> {
     assert_eq!(to_html("\u{FEFF}"), "", "should ignore just a bom");
 
     assert_eq!(
@@ -10,4 +15,66 @@ fn bom() {
         "<h1>hea\u{FEFF}ding</h1>",
         "should ignore a bom"
     );
+
+    assert_eq!(
+        to_mdast("\u{FEFF}", &ParseOptions::default())?,
+        Node::Root(Root {
+            children: vec![],
+            position: Some(Position::new(1, 1, 0, 1, 2, 3)),
+            raw: None,
+        }),
+        "should count just a bom as one column, and its bytes in offsets"
+    );
+
+    assert_eq!(
+        to_mdast("\u{FEFF}# a", &ParseOptions::default())?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                depth: 1,
+                children: vec![Node::Text(Text {
+                    value: "a".into(),
+                    position: Some(Position::new(1, 4, 5, 1, 5, 6)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 2, 3, 1, 5, 6)),
+                markers: None,
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 5, 6)),
+            raw: None,
+        }),
+        "should count a bom as one column, and its bytes in offsets"
+    );
+
+    assert_eq!(
+        to_mdast("\u{FEFF}a\nb", &ParseOptions::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![Node::Text(Text {
+                    value: "a\nb".into(),
+                    position: Some(Position::new(1, 2, 3, 2, 2, 6)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 2, 3, 2, 2, 6)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 2, 2, 6)),
+            raw: None,
+        }),
+        "should not count a bom in columns on later lines"
+    );
+
+    let value = "\u{FEFF}a";
+    let location = Location::new(value.as_bytes());
+
+    if let Node::Root(root) = &to_mdast(value, &ParseOptions::default())? {
+        let start = &root.children[0].position().unwrap().start;
+        assert_eq!(
+            location.to_point(start.offset).as_ref(),
+            Some(start),
+            "should match `Location` when there is a bom"
+        );
+    }
+
+    Ok(())
 }
@@ -0,0 +1,122 @@
+use markdown::{
+    mdast::{Emphasis, Node, Paragraph, Root, Text},
+    message, to_mdast,
+    unist::Position,
+    Location, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the position of the first emphasis.
+fn emphasis(value: &str) -> Result<Position, message::Message> {
+    find(value, |d| matches!(d, Node::Emphasis(_)))
+}
+
+/// Get the position of the first node that passes `test`.
+fn find(value: &str, test: fn(&Node) -> bool) -> Result<Position, message::Message> {
+    let tree = to_mdast(value, &ParseOptions::gfm())?;
+    let mut stack = vec![&tree];
+
+    while let Some(node) = stack.pop() {
+        if test(node) {
+            return Ok(node.position().unwrap().clone());
+        }
+
+        if let Some(children) = node.children() {
+            stack.extend(children.iter().rev());
+        }
+    }
+
+    unreachable!("expected node")
+}
+
+#[test]
+fn column() -> Result<(), message::Message> {
+    assert_eq!(
+        to_mdast("“quote” *em*", &ParseOptions::default())?,
+        Node::Root(Root {
+            children: vec![Node::Paragraph(Paragraph {
+                children: vec![
+                    Node::Text(Text {
+                        value: "“quote” ".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 9, 12)),
+                        raw: None,
+                    }),
+                    Node::Emphasis(Emphasis {
+                        children: vec![Node::Text(Text {
+                            value: "em".into(),
+                            position: Some(Position::new(1, 10, 13, 1, 12, 15)),
+                            raw: None,
+                        })],
+                        position: Some(Position::new(1, 9, 12, 1, 13, 16)),
+                        markers: None,
+                        raw: None,
+                    })
+                ],
+                position: Some(Position::new(1, 1, 0, 1, 13, 16)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 13, 16)),
+            raw: None,
+        }),
+        "should count characters in the BMP as one column, and their bytes in offsets"
+    );
+
+    assert_eq!(
+        emphasis("中文 *a*")?,
+        Position::new(1, 4, 7, 1, 7, 10),
+        "should count CJK as one column"
+    );
+
+    assert_eq!(
+        emphasis("😀 *a*")?,
+        Position::new(1, 4, 5, 1, 7, 8),
+        "should count characters outside the BMP as two columns"
+    );
+
+    assert_eq!(
+        emphasis("👩\u{200D}👩\u{200D}👧 *a*")?,
+        Position::new(1, 10, 19, 1, 13, 22),
+        "should count each character in an emoji ZWJ sequence"
+    );
+
+    assert_eq!(
+        emphasis("e\u{301} *a*")?,
+        Position::new(1, 4, 4, 1, 7, 7),
+        "should count combining characters"
+    );
+
+    assert_eq!(
+        emphasis("😀\n😀 *a*")?,
+        Position::new(2, 4, 10, 2, 7, 13),
+        "should count columns from the start of the line"
+    );
+
+    assert_eq!(
+        emphasis("> 😀\n> *a*")?,
+        Position::new(2, 3, 9, 2, 6, 12),
+        "should count columns in containers"
+    );
+
+    assert_eq!(
+        find("a\u{3000} www.b.com *c*", |d| matches!(d, Node::Link(_)))?,
+        Position::new(1, 4, 5, 1, 13, 14),
+        "should count columns in autolink literals"
+    );
+
+    assert_eq!(
+        emphasis("a\u{3000} www.b.com *c*")?,
+        Position::new(1, 14, 15, 1, 17, 18),
+        "should count columns after autolink literals"
+    );
+
+    let value = "é “a” 😀\n中文 *b*";
+    let location = Location::new(value.as_bytes());
+    let position = emphasis(value)?;
+    assert_eq!(
+        location.to_point(position.start.offset).as_ref(),
+        Some(&position.start),
+        "should match `Location`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use markdown::{mdast::Node, message, to_mdast, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Get the kind and start and end offsets of all containers (block quotes,
+/// footnote definitions, lists, list items), in tree order.
+fn containers(value: &str) -> Result<Vec<(&'static str, usize, usize)>, message::Message> {
+    fn visit(node: &Node, results: &mut Vec<(&'static str, usize, usize)>) {
+        let kind = match node {
+            Node::Blockquote(_) => Some("blockquote"),
+            Node::FootnoteDefinition(_) => Some("footnoteDefinition"),
+            Node::List(_) => Some("list"),
+            Node::ListItem(_) => Some("listItem"),
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            let position = node.position().unwrap();
+            results.push((kind, position.start.offset, position.end.offset));
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                visit(child, results);
+            }
+        }
+    }
+
+    let mut results = vec![];
+    visit(&to_mdast(value, &ParseOptions::gfm())?, &mut results);
+    Ok(results)
+}
+
+#[test]
+fn container_positions() -> Result<(), message::Message> {
+    assert_eq!(
+        containers("* a\n* b\n\nc")?,
+        vec![("list", 0, 7), ("listItem", 0, 3), ("listItem", 4, 7)],
+        "should not include trailing blank lines in tight lists (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("* a\n\n* b\n\nc")?,
+        vec![("list", 0, 8), ("listItem", 0, 3), ("listItem", 5, 8)],
+        "should not include trailing blank lines in loose lists (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("1. a\n\n   b\n\n\n2. c\n")?,
+        vec![("list", 0, 17), ("listItem", 0, 10), ("listItem", 13, 17)],
+        "should not include several trailing blank lines in list items (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("* a\n  * b\n\n  c\n\nd")?,
+        vec![
+            ("list", 0, 14),
+            ("listItem", 0, 14),
+            ("list", 6, 9),
+            ("listItem", 6, 9)
+        ],
+        "should not include trailing blank lines in nested lists (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("* a\n  \n\n")?,
+        vec![("list", 0, 3), ("listItem", 0, 3)],
+        "should not include trailing whitespace-only lines (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("*\n\na")?,
+        vec![("list", 0, 1), ("listItem", 0, 1)],
+        "should end empty list items after their marker (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("> a\n>\n\nb")?,
+        vec![("blockquote", 0, 5)],
+        "should include trailing empty block quote lines, but not blank lines (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("> * a\n>\n> b")?,
+        vec![("blockquote", 0, 11), ("list", 2, 5), ("listItem", 2, 5)],
+        "should not include trailing blank lines in lists in block quotes (like `mdast-util-from-markdown`)"
+    );
+
+    assert_eq!(
+        containers("[^a]: b\n\n    c\n\nd")?,
+        vec![("footnoteDefinition", 0, 14)],
+        "should not include trailing blank lines in footnote definitions (like `mdast-util-from-markdown`)"
+    );
+
+    Ok(())
+}
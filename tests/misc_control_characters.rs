@@ -0,0 +1,55 @@
+use markdown::{message, to_html, to_html_with_options, CompileOptions, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn control_characters() -> Result<(), message::Message> {
+    let strip = Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            strip_control_characters: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a\u{1}b\u{1f}c\u{7f}d\u{85}e\u{9f}f"),
+        "<p>a\u{1}b\u{1f}c\u{7f}d\u{85}e\u{9f}f</p>",
+        "should keep control characters by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\u{1}b\u{1f}c\u{7f}d\u{85}e\u{9f}f", &strip)?,
+        "<p>abcdef</p>",
+        "should strip C0, `DEL`, and C1 w/ `strip_control_characters`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\0b", &strip)?,
+        "<p>a�b</p>",
+        "should still replace NUL w/ `strip_control_characters`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\u{1}\tb\r\nc\u{1}\rd\r\n\r\n    e\u{1}\tf", &strip)?,
+        "<p>a\tb\r\nc\rd</p>\r\n<pre><code>e\tf\r\n</code></pre>",
+        "should keep tabs and line endings w/ `strip_control_characters`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "`a\u{2}b` [c\u{3}](<d\u{4}e> \"f\u{5}g\") ![h\u{6}](i)\n\n```j\u{7}\nk\u{8}\n```",
+            &strip
+        )?,
+        "<p><code>ab</code> <a href=\"de\" title=\"fg\">c</a> <img src=\"i\" alt=\"h\" /></p>\n<pre><code class=\"language-j\">k\n</code></pre>",
+        "should strip control characters everywhere w/ `strip_control_characters`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div \u{1b}>\u{1b}[31m</div>", &strip)?,
+        "<div >[31m</div>",
+        "should strip control characters in HTML w/ `strip_control_characters`"
+    );
+
+    Ok(())
+}
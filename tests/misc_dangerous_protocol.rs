@@ -1,4 +1,7 @@
-use markdown::to_html;
+use markdown::{
+    mdast_to_html, message, to_html, to_html_with_options, to_mdast, CompileOptions, Options,
+    ParseOptions,
+};
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -195,3 +198,119 @@ fn dangerous_protocol_link() {
         "should allow a colon in a path"
     );
 }
+
+#[test]
+fn dangerous_protocol_allow_list() -> Result<(), message::Message> {
+    let tel = Options {
+        compile: CompileOptions {
+            protocol_href: Some(vec!["http".into(), "https".into(), "TEL".into()]),
+            ..CompileOptions::default()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html_with_options("[a](tel:123)", &tel)?,
+        "<p><a href=\"tel:123\">a</a></p>",
+        "should allow protocols in `protocol_href`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](Tel:123) [b](TEL:456)", &tel)?,
+        "<p><a href=\"Tel:123\">a</a> <a href=\"TEL:456\">b</a></p>",
+        "should compare protocols case-insensitively"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](javascript:alert(1)) [b](JAVASCRIPT:alert(1))", &tel)?,
+        "<p><a href=\"\">a</a> <a href=\"\">b</a></p>",
+        "should still drop protocols not in `protocol_href`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](mailto:b@c.com)", &tel)?,
+        "<p><a href=\"\">a</a></p>",
+        "should replace the default list w/ `protocol_href`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](./b) [c](#d)", &tel)?,
+        "<p><a href=\"./b\">a</a> <a href=\"#d\">c</a></p>",
+        "should allow relative URLs w/ `protocol_href`"
+    );
+
+    assert_eq!(
+        to_html_with_options("<tel:123> <javascript:alert(1)>", &tel)?,
+        "<p><a href=\"tel:123\">tel:123</a> <a href=\"\">javascript:alert(1)</a></p>",
+        "should use `protocol_href` for autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a] [b]\n\n[a]: tel:123\n[b]: javascript:alert(1)", &tel)?,
+        "<p><a href=\"tel:123\">a</a> <a href=\"\">b</a></p>\n",
+        "should use `protocol_href` for references to definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](tel:123)", &tel)?,
+        "<p><img src=\"\" alt=\"a\" /></p>",
+        "should not use `protocol_href` for images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a](data:image/png;base64,b) ![c](http://d.com/e.png)",
+            &Options {
+                compile: CompileOptions {
+                    protocol_src: Some(vec!["https".into(), "data".into()]),
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><img src=\"data:image/png;base64,b\" alt=\"a\" /> <img src=\"\" alt=\"c\" /></p>",
+        "should use `protocol_src` for images"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](mailto:b@c.com) <mailto:d@e.com> f@g.com",
+            &Options {
+                compile: CompileOptions {
+                    protocol_href: Some(vec!["http".into(), "https".into()]),
+                    ..CompileOptions::default()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p><a href=\"\">a</a> <a href=\"\">mailto:d@e.com</a> <a href=\"\">f@g.com</a></p>",
+        "should support blocking default protocols, also in GFM autolink literals"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[a](javascript:alert(1))",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    protocol_href: Some(vec![]),
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"javascript:alert(1)\">a</a></p>",
+        "should ignore `protocol_href` w/ `allow_dangerous_protocol`"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast("[a](tel:123) ![b](tel:456)", &ParseOptions::default())?,
+            &tel.compile
+        )?,
+        "<p><a href=\"tel:123\">a</a> <img src=\"\" alt=\"b\" /></p>",
+        "should use `protocol_href` and `protocol_src` when compiling a syntax tree"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use markdown::{
+    mdast_to_html, message, to_html, to_html_with_options, to_mdast, CompileOptions, Options,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn decode_titles() -> Result<(), message::Message> {
+    let raw = Options {
+        compile: CompileOptions {
+            decode_titles: false,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("[a](b \"c &copy; &#169; &#xA9; d\")"),
+        "<p><a href=\"b\" title=\"c © © © d\">a</a></p>",
+        "should decode character references in titles by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c &copy; d\")", &raw)?,
+        "<p><a href=\"b\" title=\"c &amp;copy; d\">a</a></p>",
+        "should keep named character references w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c &#169; &#xA9; d\")", &raw)?,
+        "<p><a href=\"b\" title=\"c &amp;#169; &amp;#xA9; d\">a</a></p>",
+        "should keep numeric character references w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        to_html("[a](b \"c &nope; d\")"),
+        "<p><a href=\"b\" title=\"c &amp;nope; d\">a</a></p>",
+        "should not decode invalid character references by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c &nope; d\")", &raw)?,
+        "<p><a href=\"b\" title=\"c &amp;nope; d\">a</a></p>",
+        "should not decode invalid character references w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        to_html("[a](b \"c \\\" \\* d\")"),
+        "<p><a href=\"b\" title=\"c &quot; * d\">a</a></p>",
+        "should decode character escapes in titles by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c \\\" \\* d\")", &raw)?,
+        "<p><a href=\"b\" title=\"c \\&quot; \\* d\">a</a></p>",
+        "should keep character escapes w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b \"c &amp; d\")", &raw)?,
+        "<p><img src=\"b\" alt=\"a\" title=\"c &amp;amp; d\" /></p>",
+        "should keep character references in image titles w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a] ![b][a]\n\n[a]: c 'd &copy; e'", &raw)?,
+        "<p><a href=\"c\" title=\"d &amp;copy; e\">a</a> <img src=\"c\" alt=\"b\" title=\"d &amp;copy; e\" /></p>\n",
+        "should keep character references in definition titles w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        to_html_with_options("> [a](b \"c &copy;\n> \\\" d\")", &raw)?,
+        "<blockquote>\n<p><a href=\"b\" title=\"c &amp;copy;\n\\&quot; d\">a</a></p>\n</blockquote>",
+        "should support multiline titles in containers w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a &copy; \\*](b&copy; \"c\")", &raw)?,
+        "<p><a href=\"b%C2%A9\" title=\"c\">a © *</a></p>",
+        "should decode character references outside titles w/o `decode_titles`"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast("[a](b \"c &copy; d\")", &ParseOptions::default())?,
+            &raw.compile
+        )?,
+        "<p><a href=\"b\" title=\"c © d\">a</a></p>",
+        "should not affect syntax trees, where titles are already decoded"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use markdown::{
+    mdast::{Blockquote, List, ListItem, Node, Text},
+    message, to_mdast, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Depth of trees that would overflow the stack if handled recursively.
+const DEPTH: usize = 100_000;
+
+#[test]
+fn deep_drop() {
+    let mut node = Node::Text(Text {
+        value: "a".into(),
+        position: None,
+        raw: None,
+    });
+
+    for _ in 0..DEPTH {
+        node = Node::Blockquote(Blockquote {
+            children: vec![node],
+            position: None,
+            raw: None,
+        });
+    }
+
+    drop(node);
+
+    let mut node = Node::Text(Text {
+        value: "a".into(),
+        position: None,
+        raw: None,
+    });
+
+    for _ in 0..DEPTH {
+        node = Node::List(List {
+            children: vec![Node::ListItem(ListItem {
+                children: vec![node],
+                position: None,
+                spread: false,
+                checked: None,
+                markers: None,
+                raw: None,
+            })],
+            position: None,
+            ordered: false,
+            start: None,
+            kind: None,
+            spread: false,
+            markers: None,
+            raw: None,
+        });
+    }
+
+    drop(node);
+}
+
+#[test]
+fn deep_to_mdast() -> Result<(), message::Message> {
+    let tree = to_mdast(&format!("{}a", ">".repeat(DEPTH)), &ParseOptions::default())?;
+    let mut node = &tree.children().unwrap()[0];
+    let mut depth = 0;
+
+    while let Node::Blockquote(quote) = node {
+        node = &quote.children[0];
+        depth += 1;
+    }
+
+    assert_eq!(depth, DEPTH, "should build very deep trees");
+
+    drop(tree);
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+use markdown::{
+    mdast_to_html, message, to_html_with_options, to_mdast, CompileOptions, ElementOverride,
+    ElementOverrides, Options, ParseOptions, QuoteKind,
+};
+use pretty_assertions::assert_eq;
+
+fn element(tag_name: Option<&str>, attributes: &[(&str, &str)]) -> Option<ElementOverride> {
+    Some(ElementOverride {
+        tag_name: tag_name.map(Into::into),
+        attributes: attributes
+            .iter()
+            .map(|(name, value)| ((*name).into(), (*value).into()))
+            .collect(),
+    })
+}
+
+fn overrides(element_overrides: ElementOverrides) -> Options {
+    Options {
+        parse: ParseOptions {
+            constructs: markdown::Constructs {
+                math_text: true,
+                ..markdown::Constructs::gfm()
+            },
+            ..ParseOptions::gfm()
+        },
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            element_overrides,
+            ..CompileOptions::gfm()
+        },
+    }
+}
+
+#[test]
+fn element_overrides() -> Result<(), message::Message> {
+    let strike = overrides(ElementOverrides {
+        delete: element(Some("s"), &[("class", "strike")]),
+        ..ElementOverrides::default()
+    });
+
+    assert_eq!(
+        to_html_with_options("~a~", &Options::gfm())?,
+        "<p><del>a</del></p>",
+        "should use `del` by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("~a~ <del>b</del>", &strike)?,
+        "<p><s class=\"strike\">a</s> <del>b</del></p>",
+        "should override strikethrough, but not `del` in HTML (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("<del>\na\n</del>\n\n~b~", &strike)?,
+        "<del>\na\n</del>\n<p><s class=\"strike\">b</s></p>",
+        "should override strikethrough, but not `del` in HTML (flow)"
+    );
+
+    let themed = overrides(ElementOverrides {
+        emphasis: element(Some("i"), &[("class", "em")]),
+        strong: element(Some("b"), &[("class", "strong")]),
+        ..ElementOverrides::default()
+    });
+
+    assert_eq!(
+        to_html_with_options("***a** b* **c *d***", &themed)?,
+        "<p><i class=\"em\"><b class=\"strong\">a</b> b</i> <b class=\"strong\">c <i class=\"em\">d</i></b></p>",
+        "should keep nesting of emphasis and strong w/ overrides"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a* **b**",
+            &overrides(ElementOverrides {
+                emphasis: element(None, &[("class", "x"), ("data-y", "z")]),
+                ..ElementOverrides::default()
+            })
+        )?,
+        "<p><em class=\"x\" data-y=\"z\">a</em> <strong>b</strong></p>",
+        "should support only attributes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "`a` $b$",
+            &overrides(ElementOverrides {
+                inline_code: element(Some("kbd"), &[]),
+                ..ElementOverrides::default()
+            })
+        )?,
+        "<p><kbd>a</kbd> <code class=\"language-math math-inline\">b</code></p>",
+        "should override code (text), but not math (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\n\n* b\n\n> c",
+            &overrides(ElementOverrides {
+                paragraph: element(None, &[("class", "p")]),
+                ..ElementOverrides::default()
+            })
+        )?,
+        "<p class=\"p\">a</p>\n<ul>\n<li>b</li>\n</ul>\n<blockquote>\n<p class=\"p\">c</p>\n</blockquote>",
+        "should override paragraphs, not in tight lists"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a^[b]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: markdown::Constructs {
+                        footnote_inline: true,
+                        ..markdown::Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    element_overrides: ElementOverrides {
+                        paragraph: element(None, &[("class", "p")]),
+                        ..ElementOverrides::default()
+                    },
+                    ..CompileOptions::default()
+                }
+            }
+        )?,
+        "<p class=\"p\">a<sup><a href=\"#user-content-fn-1\" id=\"user-content-fnref-1\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-1\">\n<p class=\"p\">b <a href=\"#user-content-fnref-1\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should override paragraphs of inline footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("![*a* ~b~](c)", &themed)?,
+        "<p><img src=\"c\" alt=\"a b\" /></p>",
+        "should not add elements in image descriptions"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "*a*",
+            &overrides(ElementOverrides {
+                emphasis: element(None, &[("title", "\"b\" & 'c' <d>")]),
+                ..ElementOverrides::default()
+            })
+        )?,
+        "<p><em title=\"&quot;b&quot; &amp; 'c' &lt;d&gt;\">a</em></p>",
+        "should encode attribute values"
+    );
+
+    let mut single = overrides(ElementOverrides {
+        emphasis: element(None, &[("title", "'b'")]),
+        ..ElementOverrides::default()
+    });
+    single.compile.attribute_quote = QuoteKind::Single;
+
+    assert_eq!(
+        to_html_with_options("*a*", &single)?,
+        "<p><em title='&#x27;b&#x27;'>a</em></p>",
+        "should support `attribute_quote`"
+    );
+
+    let error = to_html_with_options(
+        "a",
+        &overrides(ElementOverrides {
+            strong: element(Some("b onclick=x"), &[]),
+            ..ElementOverrides::default()
+        }),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        (error.rule_id.as_str(), error.reason.as_str()),
+        (
+            "invalid-element-override",
+            "Unexpected tag name `b onclick=x` in `element_overrides`, expected ASCII letters"
+        ),
+        "should error on invalid tag names"
+    );
+
+    let error = to_html_with_options(
+        "a",
+        &overrides(ElementOverrides {
+            paragraph: element(None, &[("a\"", "b")]),
+            ..ElementOverrides::default()
+        }),
+    )
+    .unwrap_err();
+
+    assert_eq!(
+        error.rule_id.as_str(),
+        "invalid-element-override",
+        "should error on invalid attribute names"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast("*a* **b** ~c~ `d`", &themed.parse)?,
+            &CompileOptions {
+                element_overrides: ElementOverrides {
+                    delete: element(Some("s"), &[]),
+                    inline_code: element(Some("kbd"), &[]),
+                    paragraph: element(None, &[("class", "p")]),
+                    ..themed.compile.element_overrides.clone()
+                },
+                ..CompileOptions::default()
+            }
+        )?,
+        "<p class=\"p\"><i class=\"em\">a</i> <b class=\"strong\">b</b> <s>c</s> <kbd>d</kbd></p>",
+        "should support overrides in mdast"
+    );
+
+    Ok(())
+}
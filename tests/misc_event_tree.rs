@@ -0,0 +1,127 @@
+use markdown::{
+    event::{Kind, Name},
+    message, to_events, EventTree, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Serialize the nodes in a tree view, as `Name(Child, Child)`.
+fn serialize(tree: &EventTree, nodes: &[usize]) -> String {
+    nodes
+        .iter()
+        .map(|node| {
+            let children = tree.children(*node).collect::<Vec<_>>();
+            let name = format!("{:?}", tree.name(*node));
+
+            if children.is_empty() {
+                name
+            } else {
+                format!("{}({})", name, serialize(tree, &children))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Find the first node called `name`.
+fn find(tree: &EventTree, name: &Name) -> usize {
+    tree.events()
+        .iter()
+        .enumerate()
+        .position(|(index, event)| event.kind == Kind::Enter && tree.name(index) == name)
+        .unwrap()
+}
+
+#[test]
+fn event_tree() -> Result<(), message::Message> {
+    let events = to_events("> # *a **b***", &ParseOptions::default())?;
+    let tree = EventTree::new(&events)?;
+
+    assert_eq!(
+        serialize(&tree, &tree.roots().collect::<Vec<_>>()),
+        "BlockQuote(BlockQuotePrefix(BlockQuoteMarker, SpaceOrTab), HeadingAtx(HeadingAtxSequence, SpaceOrTab, HeadingAtxText(Emphasis(EmphasisSequence, EmphasisText(Data, Strong(StrongSequence, StrongText(Data), StrongSequence)), EmphasisSequence))))",
+        "should support nested emphasis in headings in block quotes"
+    );
+
+    let strong = find(&tree, &Name::Strong);
+    let mut ancestors = vec![];
+    let mut node = tree.parent(strong);
+    while let Some(parent) = node {
+        ancestors.push(tree.name(parent));
+        node = tree.parent(parent);
+    }
+
+    assert_eq!(
+        ancestors,
+        vec![
+            &Name::EmphasisText,
+            &Name::Emphasis,
+            &Name::HeadingAtxText,
+            &Name::HeadingAtx,
+            &Name::BlockQuote
+        ],
+        "should support getting parents"
+    );
+
+    assert_eq!(
+        tree.parent(find(&tree, &Name::BlockQuote)),
+        None,
+        "should not have parents for roots"
+    );
+
+    let (start, end) = tree.span(strong);
+    assert_eq!(
+        (start.index, end.index),
+        (7, 12),
+        "should support getting the span of a node"
+    );
+
+    assert_eq!(
+        tree.events()[tree.exit(strong)].kind,
+        Kind::Exit,
+        "should support getting the exit of a node"
+    );
+
+    let events = to_events("a\n\n> b\n\n* c", &ParseOptions::default())?;
+    let tree = EventTree::new(&events)?;
+
+    assert_eq!(
+        tree.roots().map(|node| tree.name(node)).collect::<Vec<_>>(),
+        vec![
+            &Name::Paragraph,
+            &Name::LineEnding,
+            &Name::BlankLineEnding,
+            &Name::BlockQuote,
+            &Name::LineEnding,
+            &Name::BlankLineEnding,
+            &Name::ListUnordered
+        ],
+        "should support several roots"
+    );
+
+    let events = EventTree::new(&[])?;
+    assert_eq!(
+        events.roots().count(),
+        0,
+        "should support an empty list of events"
+    );
+
+    let mut events = to_events("*a*", &ParseOptions::default())?;
+    events.remove(1);
+
+    assert_eq!(
+        EventTree::new(&events).err().unwrap().reason,
+        "Unexpected exit of `Emphasis`, expected an exit of the last entered event",
+        "should error on an exit w/o enter"
+    );
+
+    let mut events = to_events("*a*", &ParseOptions::default())?;
+    events.pop();
+
+    assert_eq!(
+        EventTree::new(&events).err().unwrap().reason,
+        "Unexpected end of events, expected an exit of `Paragraph`",
+        "should error on an enter w/o exit"
+    );
+
+    Ok(())
+}
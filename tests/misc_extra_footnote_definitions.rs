@@ -0,0 +1,142 @@
+use markdown::{
+    message, to_html_multi, to_html_with_options, CompileOptions, Constructs, FootnoteNumbering,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn extra_footnote_definitions() -> Result<(), message::Message> {
+    let glossary = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            extra_footnote_definitions: vec![
+                ("Glossary".into(), "A *shared* note.".into()),
+                ("term".into(), "> Quoted.".into()),
+                ("local".into(), "Not used.".into()),
+            ],
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options("a[^term]", &Options::gfm())?,
+        "<p>a[^term]</p>",
+        "should not support calls to undefined footnotes by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "Text[^glossary], more[^local], and[^term].\n\nAgain[^GLOSSARY].\n\n[^local]: Local note.",
+            &glossary
+        )?,
+        "<p>Text<sup><a href=\"#user-content-fn-glossary\" id=\"user-content-fnref-glossary\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup>, more<sup><a href=\"#user-content-fn-local\" id=\"user-content-fnref-local\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup>, and<sup><a href=\"#user-content-fn-term\" id=\"user-content-fnref-term\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">3</a></sup>.</p>\n<p>Again<sup><a href=\"#user-content-fn-glossary\" id=\"user-content-fnref-glossary-2\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup>.</p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-glossary\">\n<p>A <em>shared</em> note. <a href=\"#user-content-fnref-glossary\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a> <a href=\"#user-content-fnref-glossary-2\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩<sup>2</sup></a></p>\n</li>\n<li id=\"user-content-fn-local\">\n<p>Local note. <a href=\"#user-content-fnref-local\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-term\">\n<blockquote>\n<p>Quoted.</p>\n</blockquote>\n<p><a href=\"#user-content-fnref-term\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should support footnotes defined elsewhere, numbered by call, w/ definitions in the document first"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^term] b[^local]\n\n[^local]: c",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    gfm_footnote_numbering: FootnoteNumbering::DefinitionOrder,
+                    ..glossary.compile.clone()
+                }
+            }
+        )?,
+        "<p>a<sup><a href=\"#user-content-fn-term\" id=\"user-content-fnref-term\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">2</a></sup> b<sup><a href=\"#user-content-fn-local\" id=\"user-content-fnref-local\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-local\">\n<p>c <a href=\"#user-content-fnref-local\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n<li id=\"user-content-fn-term\">\n<blockquote>\n<p>Quoted.</p>\n</blockquote>\n<p><a href=\"#user-content-fnref-term\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should number footnotes defined elsewhere after the document w/ `FootnoteNumbering::DefinitionOrder`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a", &glossary)?,
+        "<p>a</p>",
+        "should not generate a section for footnotes defined elsewhere that are not called"
+    );
+
+    assert_eq!(
+        to_html_multi("a[^term]\n\u{1e}\nb[^term]", "\u{1e}", &glossary)?
+            .iter()
+            .map(|d| d.contains("<p>Quoted.</p>"))
+            .collect::<Vec<_>>(),
+        vec![true, true],
+        "should support footnotes defined elsewhere in `to_html_multi`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b]",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        gfm_footnote_definition: true,
+                        gfm_label_start_footnote: true,
+                        ..Constructs::mdx()
+                    },
+                    ..ParseOptions::mdx()
+                },
+                compile: CompileOptions {
+                    extra_footnote_definitions: vec![("b".into(), "{c".into())],
+                    ..CompileOptions::default()
+                }
+            }
+        )
+        .unwrap_err()
+        .to_string(),
+        "1:3: Unexpected end of file in expression, expected a corresponding closing brace for `{` (markdown-rs:unexpected-eof-in-expression)",
+        "should crash on errors in footnotes defined elsewhere"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b]",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    extra_footnote_definitions: vec![("b".into(), "c[^d]\n\n[^d]: e".into())],
+                    ..CompileOptions::gfm()
+                }
+            }
+        )
+        .unwrap_err()
+        .to_string(),
+        "1:2: Unexpected footnote in footnote `b` in `extra_footnote_definitions`, expected no footnotes in it (markdown-rs:nested-footnote)",
+        "should crash on footnotes in footnotes defined elsewhere"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "no footnotes here",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    extra_footnote_definitions: vec![
+                        ("a".into(), "x".into()),
+                        ("unused".into(), "[^z]: nested".into())
+                    ],
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "<p>no footnotes here</p>",
+        "should not compile footnotes defined elsewhere that are not called"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a[^b]\n\n[^b]: c",
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    extra_footnote_definitions: vec![("b".into(), "[^z]: nested".into())],
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?
+        .contains("<p>c <a"),
+        true,
+        "should not compile footnotes defined elsewhere that are defined in the document"
+    );
+
+    Ok(())
+}
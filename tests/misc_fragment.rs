@@ -0,0 +1,157 @@
+use markdown::{
+    mdast::{Emphasis, Image, Link, Node, Text},
+    message, parse_string_fragment, parse_text_fragment,
+    unist::Position,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn text_fragment() -> Result<(), message::Message> {
+    assert_eq!(
+        parse_text_fragment("a *b* c", &ParseOptions::default())?,
+        vec![
+            Node::Text(Text {
+                value: "a ".into(),
+                position: Some(Position::new(1, 1, 0, 1, 3, 2)),
+                raw: None,
+            }),
+            Node::Emphasis(Emphasis {
+                children: vec![Node::Text(Text {
+                    value: "b".into(),
+                    position: Some(Position::new(1, 4, 3, 1, 5, 4)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 3, 2, 1, 6, 5)),
+                markers: None,
+                raw: None,
+            }),
+            Node::Text(Text {
+                value: " c".into(),
+                position: Some(Position::new(1, 6, 5, 1, 8, 7)),
+                raw: None,
+            })
+        ],
+        "should support emphasis"
+    );
+
+    assert_eq!(
+        parse_text_fragment("a &amp; &#35; b", &ParseOptions::default())?,
+        vec![Node::Text(Text {
+            value: "a & # b".into(),
+            position: Some(Position::new(1, 1, 0, 1, 16, 15)),
+            raw: None,
+        })],
+        "should support character references"
+    );
+
+    assert_eq!(
+        parse_text_fragment("<https://a.b>", &ParseOptions::default())?,
+        vec![Node::Link(Link {
+            children: vec![Node::Text(Text {
+                value: "https://a.b".into(),
+                position: Some(Position::new(1, 2, 1, 1, 13, 12)),
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+            url: "https://a.b".into(),
+            title: None,
+            raw: None,
+        })],
+        "should support autolinks"
+    );
+
+    assert_eq!(
+        parse_text_fragment("# a\n\n- b\n> c\n\n    d\n***", &ParseOptions::default())?,
+        vec![Node::Text(Text {
+            value: "# a\n\n- b\n> c\n\nd\n***".into(),
+            position: Some(Position::new(1, 1, 0, 7, 4, 23)),
+            raw: None,
+        })],
+        "should not support block constructs, also not across blank lines (but drop initial whitespace, like paragraphs)"
+    );
+
+    assert_eq!(
+        parse_text_fragment("[a]\n\n[a]: b", &ParseOptions::default())?,
+        vec![Node::Text(Text {
+            value: "[a]\n\n[a]: b".into(),
+            position: Some(Position::new(1, 1, 0, 3, 7, 11)),
+            raw: None,
+        })],
+        "should not support definitions (or references)"
+    );
+
+    assert_eq!(
+        parse_text_fragment("[a](b&amp;c \"t&amp;\")", &ParseOptions::default())?,
+        vec![Node::Link(Link {
+            children: vec![Node::Text(Text {
+                value: "a".into(),
+                position: Some(Position::new(1, 2, 1, 1, 3, 2)),
+                raw: None,
+            })],
+            url: "b&c".into(),
+            title: Some("t&".into()),
+            position: Some(Position::new(1, 1, 0, 1, 22, 21)),
+            raw: None,
+        })],
+        "should decode character references in destinations and titles"
+    );
+
+    assert_eq!(
+        parse_text_fragment("![x](y\\)z)", &ParseOptions::default())?,
+        vec![Node::Image(Image {
+            alt: "x".into(),
+            url: "y)z".into(),
+            title: None,
+            position: Some(Position::new(1, 1, 0, 1, 11, 10)),
+            raw: None,
+        })],
+        "should decode character escapes in destinations"
+    );
+
+    assert_eq!(
+        parse_text_fragment("[a\\*][b&amp;]", &ParseOptions::default())?,
+        vec![Node::Text(Text {
+            value: "[a*][b&]".into(),
+            position: Some(Position::new(1, 1, 0, 1, 14, 13)),
+            raw: None,
+        })],
+        "should decode escapes and character references in references (which are not supported)"
+    );
+
+    assert_eq!(
+        parse_text_fragment("~a~ www.b.com", &ParseOptions::gfm())?
+            .iter()
+            .map(|d| match d {
+                Node::Delete(_) => "delete",
+                Node::Link(_) => "link",
+                _ => "other",
+            })
+            .collect::<Vec<_>>(),
+        vec!["delete", "other", "link"],
+        "should support constructs turned on in `options`"
+    );
+
+    assert_eq!(
+        parse_text_fragment("", &ParseOptions::default())?,
+        vec![],
+        "should support an empty fragment"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn string_fragment() -> Result<(), message::Message> {
+    assert_eq!(
+        parse_string_fragment("*a* &amp; \\*b\\* <c> `d`\n\n# e", &ParseOptions::default())?,
+        vec![Node::Text(Text {
+            value: "*a* & *b* <c> `d`\n\n# e".into(),
+            position: Some(Position::new(1, 1, 0, 3, 4, 28)),
+            raw: None,
+        })],
+        "should support character escapes and references, and nothing else"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use markdown::{message, to_html_with_options, Constructs, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Options with only the GFM constructs turned on by `gfm` in `CommonMark`.
+fn only(gfm: fn(&mut Constructs)) -> Options {
+    let mut constructs = Constructs::default();
+    gfm(&mut constructs);
+
+    Options {
+        parse: ParseOptions {
+            constructs,
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    }
+}
+
+/// Compile GFM constructs one at a time, each with default compile options,
+/// to check that none depend on another, or on compile options, to form
+/// valid HTML.
+#[test]
+fn gfm_constructs() -> Result<(), message::Message> {
+    let value = "| a |\n| - |\n| b |\n\n* [x] c\n\n~d~ www.e.com [^f]\n\n[^f]: g";
+
+    assert_eq!(
+        to_html_with_options(value, &only(|d| d.gfm_autolink_literal = true))?,
+        "<p>| a |\n| - |\n| b |</p>\n<ul>\n<li>[x] c</li>\n</ul>\n<p>~d~ <a href=\"http://www.e.com\">www.e.com</a> <a href=\"g\">^f</a></p>\n",
+        "should support only autolink literals"
+    );
+
+    assert_eq!(
+        to_html_with_options(value, &only(|d| d.gfm_strikethrough = true))?,
+        "<p>| a |\n| - |\n| b |</p>\n<ul>\n<li>[x] c</li>\n</ul>\n<p><del>d</del> www.e.com <a href=\"g\">^f</a></p>\n",
+        "should support only strikethrough"
+    );
+
+    assert_eq!(
+        to_html_with_options(value, &only(|d| d.gfm_table = true))?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b</td>\n</tr>\n</tbody>\n</table>\n<ul>\n<li>[x] c</li>\n</ul>\n<p>~d~ www.e.com <a href=\"g\">^f</a></p>\n",
+        "should support only tables"
+    );
+
+    assert_eq!(
+        to_html_with_options(value, &only(|d| d.gfm_task_list_item = true))?,
+        "<p>| a |\n| - |\n| b |</p>\n<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> c</li>\n</ul>\n<p>~d~ www.e.com <a href=\"g\">^f</a></p>\n",
+        "should support only task list items"
+    );
+
+    assert_eq!(
+        to_html_with_options(value, &only(|d| d.gfm_label_start_footnote = true))?,
+        "<p>| a |\n| - |\n| b |</p>\n<ul>\n<li>[x] c</li>\n</ul>\n<p>~d~ www.e.com <a href=\"g\">^f</a></p>\n",
+        "should support only footnote calls (which need definitions to form)"
+    );
+
+    assert_eq!(
+        to_html_with_options(value, &only(|d| d.gfm_footnote_definition = true))?,
+        "<p>| a |\n| - |\n| b |</p>\n<ul>\n<li>[x] c</li>\n</ul>\n<p>~d~ www.e.com [^f]</p>\n",
+        "should support only footnote definitions (which, uncalled, are not shown)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            value,
+            &only(|d| {
+                d.gfm_label_start_footnote = true;
+                d.gfm_footnote_definition = true;
+            })
+        )?,
+        "<p>| a |\n| - |\n| b |</p>\n<ul>\n<li>[x] c</li>\n</ul>\n<p>~d~ www.e.com <sup><a href=\"#user-content-fn-f\" id=\"user-content-fnref-f\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-f\">\n<p>g <a href=\"#user-content-fnref-f\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n",
+        "should support only footnotes"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a | b |\n|| - | - ||\n| c | d |",
+            &only(|d| d.gfm_table = true)
+        )?,
+        "<p>| a | b |\n|| - | - ||\n| c | d |</p>",
+        "should not form tables w/ extra pipes in the delimiter row"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,142 @@
+use markdown::{
+    mdast_to_html, message, to_html, to_html_with_options, to_mdast, CompileOptions, Options,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn hard_wrap() -> Result<(), message::Message> {
+    let wrap = Options {
+        compile: CompileOptions {
+            hard_wrap: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a\nb"),
+        "<p>a\nb</p>",
+        "should keep line endings by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\nc", &wrap)?,
+        "<p>a<br />\nb<br />\nc</p>",
+        "should turn line endings in paragraphs into breaks w/ `hard_wrap`"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\r\nb", &wrap)?,
+        "<p>a<br />\r\nb</p>",
+        "should support CRLF"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a\n> b\nc", &wrap)?,
+        "<blockquote>\n<p>a<br />\nb<br />\nc</p>\n</blockquote>",
+        "should support paragraphs in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  b\n\n- c\n  d", &wrap)?,
+        "<ul>\n<li>\n<p>a<br />\nb</p>\n</li>\n<li>\n<p>c<br />\nd</p>\n</li>\n</ul>",
+        "should support paragraphs in list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n  b", &wrap)?,
+        "<ul>\n<li>a<br />\nb</li>\n</ul>",
+        "should support paragraphs in tight list items"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb\n=", &wrap)?,
+        "<h1>a<br />\nb</h1>",
+        "should support headings (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n\nb", &wrap)?,
+        "<p>a</p>\n<p>b</p>",
+        "should not affect line endings between paragraphs"
+    );
+
+    assert_eq!(
+        to_html_with_options("*a\nb* [c\nd](e)", &wrap)?,
+        "<p><em>a<br />\nb</em> <a href=\"e\">c<br />\nd</a></p>",
+        "should support line endings in phrasing"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\\nb", &wrap)?,
+        "<p>a<br />\nb</p>",
+        "should not add a break after a hard break (escape)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a  \nb", &wrap)?,
+        "<p>a<br />\nb</p>",
+        "should not add a break after a hard break (trailing)"
+    );
+
+    assert_eq!(
+        to_html_with_options("`a\nb`", &wrap)?,
+        "<p><code>a b</code></p>",
+        "should not affect line endings in code (text)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\nb\n```\n\n    c\n    d", &wrap)?,
+        "<pre><code>a\nb\n</code></pre>\n<pre><code>c\nd\n</code></pre>",
+        "should not affect line endings in code (fenced, indented)"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>\na\n</div>\n\n<a\nb> c",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    hard_wrap: true,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<div>\na\n</div>\n<p><a\nb> c</p>",
+        "should not affect line endings in HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](b \"c\nd\") ![e\nf](g)", &wrap)?,
+        "<p><a href=\"b\" title=\"c\nd\">a</a> <img src=\"g\" alt=\"e\nf\" /></p>",
+        "should not affect line endings in titles and alt"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "| a |\n| - |\n| b |\nc",
+            &Options {
+                compile: CompileOptions {
+                    hard_wrap: true,
+                    ..CompileOptions::gfm()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<table>\n<thead>\n<tr>\n<th>a</th>\n</tr>\n</thead>\n<tbody>\n<tr>\n<td>b</td>\n</tr>\n<tr>\n<td>c</td>\n</tr>\n</tbody>\n</table>",
+        "should not affect tables"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast("> a\n> b\\\nc\n\nd\ne\n=", &ParseOptions::default())?,
+            &wrap.compile
+        )?,
+        "<blockquote>\n<p>a<br />\nb<br />\nc</p>\n</blockquote>\n<h1>d<br />\ne</h1>",
+        "should support `hard_wrap` when compiling syntax trees"
+    );
+
+    Ok(())
+}
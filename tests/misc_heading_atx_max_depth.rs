@@ -0,0 +1,136 @@
+use markdown::{
+    mdast::{Heading, Node, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn heading_atx_max_depth() -> Result<(), message::Message> {
+    let deep = Options {
+        parse: ParseOptions {
+            heading_atx_max_depth: 8,
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("####### a"),
+        "<p>####### a</p>",
+        "should not support seven markers by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("###### a", &deep)?,
+        "<h6>a</h6>",
+        "should not add `aria-level` to headings of rank 6"
+    );
+
+    assert_eq!(
+        to_html_with_options("####### a", &deep)?,
+        "<h6 aria-level=\"7\">a</h6>",
+        "should support seven markers if allowed"
+    );
+
+    assert_eq!(
+        to_html_with_options("######## a ########", &deep)?,
+        "<h6 aria-level=\"8\">a</h6>",
+        "should support closed deeper headings"
+    );
+
+    assert_eq!(
+        to_html_with_options("######### a", &deep)?,
+        "<p>######### a</p>",
+        "should not support more markers than allowed"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "### a",
+            &Options {
+                parse: ParseOptions {
+                    heading_atx_max_depth: 2,
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>### a</p>",
+        "should support lowering the maximum"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "####### a",
+            &Options {
+                parse: ParseOptions {
+                    heading_atx_max_depth: 8,
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    heading_shift: 1,
+                    ..CompileOptions::default()
+                }
+            }
+        )?,
+        "<h6 aria-level=\"8\">a</h6>",
+        "should shift deeper headings"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "####### a",
+            &Options {
+                parse: ParseOptions {
+                    heading_atx_max_depth: 8,
+                    ..ParseOptions::default()
+                },
+                compile: CompileOptions {
+                    heading_shift: -1,
+                    ..CompileOptions::default()
+                }
+            }
+        )?,
+        "<h6>a</h6>",
+        "should not add `aria-level` when shifted to rank 6"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "##### a",
+            &Options {
+                compile: CompileOptions {
+                    heading_shift: 3,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<h6>a</h6>",
+        "should still clamp regular headings shifted past rank 6"
+    );
+
+    assert_eq!(
+        to_mdast("####### a", &deep.parse)?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                depth: 7,
+                children: vec![Node::Text(Text {
+                    value: "a".into(),
+                    position: Some(Position::new(1, 9, 8, 1, 10, 9)),
+                    raw: None,
+                }),],
+                position: Some(Position::new(1, 1, 0, 1, 10, 9)),
+                markers: None,
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 10, 9)),
+            raw: None,
+        }),
+        "should support deeper headings in mdast"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use markdown::{
+    mdast::{Heading, Node, Root, Text},
+    message, to_html_with_options, to_mdast,
+    unist::Position,
+    CompileOptions, Options,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn heading_shift() -> Result<(), message::Message> {
+    let shift = |heading_shift: i8| Options {
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            heading_shift,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("# a\n## b\n### c\n#### d\n##### e\n###### f", &shift(0))?,
+        "<h1>a</h1>\n<h2>b</h2>\n<h3>c</h3>\n<h4>d</h4>\n<h5>e</h5>\n<h6>f</h6>",
+        "should not shift headings by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n## b\n### c\n#### d\n##### e\n###### f", &shift(2))?,
+        "<h3>a</h3>\n<h4>b</h4>\n<h5>c</h5>\n<h6>d</h6>\n<h6>e</h6>\n<h6>f</h6>",
+        "should shift headings (atx) down, clamped at 6"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-", &shift(2))?,
+        "<h3>a</h3>\n<h4>b</h4>",
+        "should shift headings (setext) down"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-", &shift(5))?,
+        "<h6>a</h6>\n<h6>b</h6>",
+        "should clamp shifted headings (setext) at 6"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a\n## b\n### c\n#### d\n##### e\n###### f", &shift(-2))?,
+        "<h1>a</h1>\n<h1>b</h1>\n<h1>c</h1>\n<h2>d</h2>\n<h3>e</h3>\n<h4>f</h4>",
+        "should shift headings (atx) up, clamped at 1"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n=\n\nb\n-", &shift(-1))?,
+        "<h1>a</h1>\n<h1>b</h1>",
+        "should shift headings (setext) up, clamped at 1"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a #", &shift(i8::MAX))?,
+        "<h6>a</h6>",
+        "should support the maximum shift"
+    );
+
+    assert_eq!(
+        to_html_with_options("###### a", &shift(i8::MIN))?,
+        "<h1>a</h1>",
+        "should support the minimum shift"
+    );
+
+    assert_eq!(
+        to_html_with_options("<h1>a</h1>\n\n# b", &shift(2))?,
+        "<h1>a</h1>\n<h3>b</h3>",
+        "should not shift headings in HTML (flow)"
+    );
+
+    assert_eq!(
+        to_html_with_options("```html\n<h1>a</h1>\n```\n\n    # b", &shift(2))?,
+        "<pre><code class=\"language-html\">&lt;h1&gt;a&lt;/h1&gt;\n</code></pre>\n<pre><code># b\n</code></pre>",
+        "should not shift headings in code"
+    );
+
+    assert_eq!(
+        to_html_with_options("> # a\n\n* b\n  -", &shift(1))?,
+        "<blockquote>\n<h2>a</h2>\n</blockquote>\n<ul>\n<li>\n<h3>b</h3>\n</li>\n</ul>",
+        "should shift headings in containers"
+    );
+
+    assert_eq!(
+        to_mdast("# a", &shift(2).parse)?,
+        Node::Root(Root {
+            children: vec![Node::Heading(Heading {
+                depth: 1,
+                children: vec![Node::Text(Text {
+                    value: "a".into(),
+                    position: Some(Position::new(1, 3, 2, 1, 4, 3)),
+                    raw: None,
+                })],
+                position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                markers: None,
+                raw: None,
+            })],
+            position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+            raw: None,
+        }),
+        "should not shift headings in mdast"
+    );
+
+    Ok(())
+}
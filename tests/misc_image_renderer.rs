@@ -0,0 +1,104 @@
+use markdown::{
+    mdast_to_html, message, to_html, to_html_with_options, to_mdast, CompileOptions, ImageInfo,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use std::sync::Arc;
+
+/// Turn `a.png?dark=b.png` into a `<picture>` w/ a dark source.
+fn picture(image: &ImageInfo) -> Option<String> {
+    let (src, dark) = image.src.split_once("?dark=")?;
+    Some(format!(
+        "<picture><source srcset=\"{}\" media=\"(prefers-color-scheme: dark)\" /><img src=\"{}\" alt=\"{}\"{} /></picture>",
+        dark,
+        src,
+        image.alt.replace('&', "&amp;").replace('"', "&quot;"),
+        image
+            .title
+            .as_ref()
+            .map(|d| format!(" title=\"{}\"", d.replace('&', "&amp;").replace('"', "&quot;")))
+            .unwrap_or_default()
+    ))
+}
+
+#[test]
+fn image_renderer() -> Result<(), message::Message> {
+    let options = Options {
+        compile: CompileOptions {
+            image_renderer: Some(Arc::new(picture)),
+            ..CompileOptions::default()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html("![a](b.png?dark=c.png)"),
+        "<p><img src=\"b.png?dark=c.png\" alt=\"a\" /></p>",
+        "should not use a renderer by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b.png?dark=c.png)", &options)?,
+        "<p><picture><source srcset=\"c.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"b.png\" alt=\"a\" /></picture></p>",
+        "should support a renderer"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](b.png)", &options)?,
+        "<p><img src=\"b.png\" alt=\"a\" /></p>",
+        "should compile images as normal if the renderer returns `None`"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a &amp; \"b\"](c.png?dark=d&amp;e.png \"f & <g>\")", &options)?,
+        "<p><picture><source srcset=\"d&e.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"c.png\" alt=\"a &amp; &quot;b&quot;\" title=\"f &amp; <g>\" /></picture></p>",
+        "should pass decoded values (and leave escaping to the renderer)"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a][b] ![b]\n\n[b]: c.png?dark=d.png \"e\"", &options)?,
+        "<p><picture><source srcset=\"d.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"c.png\" alt=\"a\" title=\"e\" /></picture> <picture><source srcset=\"d.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"c.png\" alt=\"b\" title=\"e\" /></picture></p>\n",
+        "should support references"
+    );
+
+    assert_eq!(
+        to_html_with_options("www.a.com ![b](c.png?dark=d.png)", &options)?,
+        "<p><a href=\"http://www.a.com\">www.a.com</a> <picture><source srcset=\"d.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"c.png\" alt=\"b\" /></picture></p>",
+        "should support images next to autolink literals"
+    );
+
+    assert_eq!(
+        to_html_with_options("[![a](b.png?dark=c.png)](d)", &options)?,
+        "<p><a href=\"d\"><picture><source srcset=\"c.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"b.png\" alt=\"a\" /></picture></a></p>",
+        "should support images in links"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![a ![b](c.png?dark=d.png) *e*](f.png?dark=g.png)",
+            &options
+        )?,
+        "<p><picture><source srcset=\"g.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"f.png\" alt=\"a b e\" /></picture></p>",
+        "should not use the renderer for images in the description of images"
+    );
+
+    assert_eq!(
+        to_html_with_options("![a](javascript:alert(1)?dark=b.png)", &options)?,
+        "<p><img src=\"\" alt=\"a\" /></p>",
+        "should pass sanitized URLs"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast(
+                "![a](b.png?dark=c.png \"d\") ![e]\n\n[e]: f.png?dark=g.png",
+                &ParseOptions::default()
+            )?,
+            &options.compile
+        )?,
+        "<p><picture><source srcset=\"c.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"b.png\" alt=\"a\" title=\"d\" /></picture> <picture><source srcset=\"g.png\" media=\"(prefers-color-scheme: dark)\" /><img src=\"f.png\" alt=\"e\" /></picture></p>",
+        "should use the renderer when compiling a syntax tree"
+    );
+
+    Ok(())
+}
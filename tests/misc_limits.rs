@@ -0,0 +1,195 @@
+use markdown::{
+    limits::{
+        AUTOLINK_DOMAIN_SIZE_MAX, AUTOLINK_SCHEME_SIZE_MAX, CHARACTER_REFERENCE_DECIMAL_SIZE_MAX,
+        CHARACTER_REFERENCE_HEXADECIMAL_SIZE_MAX, CHARACTER_REFERENCE_NAMED_SIZE_MAX,
+        HEADING_ATX_OPENING_FENCE_SIZE_MAX, LINK_REFERENCE_SIZE_MAX, LIST_ITEM_VALUE_SIZE_MAX,
+        RESOURCE_DESTINATION_BALANCE_MAX,
+    },
+    mdast::Node,
+    message, to_html, to_html_with_options, to_mdast, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn limits() -> Result<(), message::Message> {
+    let scheme = "a".repeat(AUTOLINK_SCHEME_SIZE_MAX);
+    assert_eq!(
+        to_html(&format!("<{}:b>", scheme)),
+        format!("<p><a href=\"\">{}:b</a></p>", scheme),
+        "should support `AUTOLINK_SCHEME_SIZE_MAX` characters in a scheme"
+    );
+
+    assert_eq!(
+        to_html(&format!("<{}a:b>", scheme)),
+        format!("<p>&lt;{}a:b&gt;</p>", scheme),
+        "should not support more than `AUTOLINK_SCHEME_SIZE_MAX` characters in a scheme"
+    );
+
+    let domain = "b".repeat(AUTOLINK_DOMAIN_SIZE_MAX);
+    assert_eq!(
+        to_html(&format!("<a@{}>", domain)),
+        format!("<p><a href=\"mailto:a@{}\">a@{}</a></p>", domain, domain),
+        "should support `AUTOLINK_DOMAIN_SIZE_MAX` characters in an email domain"
+    );
+
+    assert_eq!(
+        to_html(&format!("<a@{}b>", domain)),
+        format!("<p>&lt;a@{}b&gt;</p>", domain),
+        "should not support more than `AUTOLINK_DOMAIN_SIZE_MAX` characters in an email domain"
+    );
+
+    let decimal = format!("{:0>1$}", 65, CHARACTER_REFERENCE_DECIMAL_SIZE_MAX);
+    assert_eq!(
+        to_html(&format!("&#{};", decimal)),
+        "<p>A</p>",
+        "should support `CHARACTER_REFERENCE_DECIMAL_SIZE_MAX` digits in a decimal character reference"
+    );
+
+    assert_eq!(
+        to_html(&format!("&#0{};", decimal)),
+        format!("<p>&amp;#0{};</p>", decimal),
+        "should not support more than `CHARACTER_REFERENCE_DECIMAL_SIZE_MAX` digits in a decimal character reference"
+    );
+
+    let hexadecimal = format!("{:0>1$}", 41, CHARACTER_REFERENCE_HEXADECIMAL_SIZE_MAX);
+    assert_eq!(
+        to_html(&format!("&#x{};", hexadecimal)),
+        "<p>A</p>",
+        "should support `CHARACTER_REFERENCE_HEXADECIMAL_SIZE_MAX` digits in a hexadecimal character reference"
+    );
+
+    assert_eq!(
+        to_html(&format!("&#x0{};", hexadecimal)),
+        format!("<p>&amp;#x0{};</p>", hexadecimal),
+        "should not support more than `CHARACTER_REFERENCE_HEXADECIMAL_SIZE_MAX` digits in a hexadecimal character reference"
+    );
+
+    assert_eq!(
+        "CounterClockwiseContourIntegral".len(),
+        CHARACTER_REFERENCE_NAMED_SIZE_MAX,
+        "should be the size of the longest named character reference"
+    );
+
+    assert_eq!(
+        to_html("&CounterClockwiseContourIntegral;"),
+        "<p>∳</p>",
+        "should support `CHARACTER_REFERENCE_NAMED_SIZE_MAX` characters in a named character reference"
+    );
+
+    let sequence = "#".repeat(HEADING_ATX_OPENING_FENCE_SIZE_MAX.into());
+    assert_eq!(
+        to_html(&format!("{} a", sequence)),
+        format!("<h{0}>a</h{0}>", HEADING_ATX_OPENING_FENCE_SIZE_MAX),
+        "should support `HEADING_ATX_OPENING_FENCE_SIZE_MAX` markers in a heading (atx)"
+    );
+
+    assert_eq!(
+        to_html(&format!("#{} a", sequence)),
+        format!("<p>#{} a</p>", sequence),
+        "should not support more than `HEADING_ATX_OPENING_FENCE_SIZE_MAX` markers in a heading (atx)"
+    );
+
+    let value = "1".repeat(LIST_ITEM_VALUE_SIZE_MAX - 1);
+    assert_eq!(
+        to_html(&format!("{}. a", value)),
+        format!("<ol start=\"{}\">\n<li>a</li>\n</ol>", value),
+        "should support less than `LIST_ITEM_VALUE_SIZE_MAX` digits in a list item value"
+    );
+
+    assert_eq!(
+        to_html(&format!("1{}. a", value)),
+        format!("<p>1{}. a</p>", value),
+        "should not support `LIST_ITEM_VALUE_SIZE_MAX` digits in a list item value"
+    );
+
+    let open = "(".repeat(RESOURCE_DESTINATION_BALANCE_MAX);
+    let close = ")".repeat(RESOURCE_DESTINATION_BALANCE_MAX);
+    assert_eq!(
+        to_html(&format!("[a]({}b{})", open, close)),
+        format!("<p><a href=\"{}b{}\">a</a></p>", open, close),
+        "should support `RESOURCE_DESTINATION_BALANCE_MAX` unclosed parens in a destination"
+    );
+
+    assert_eq!(
+        to_html(&format!("[a](({}b{}))", open, close)),
+        format!("<p>[a](({}b{}))</p>", open, close),
+        "should not support more than `RESOURCE_DESTINATION_BALANCE_MAX` unclosed parens in a destination"
+    );
+
+    let label = format!("a{}b", " ".repeat(LINK_REFERENCE_SIZE_MAX - 2));
+    assert_eq!(
+        to_html(&format!("[{}]: c\n\n[a b]", label)),
+        "<p><a href=\"c\">a b</a></p>",
+        "should support `LINK_REFERENCE_SIZE_MAX` bytes in the label of a definition"
+    );
+
+    assert_eq!(
+        to_html(&format!("[ {}]: c\n\n[a b]", label)),
+        format!("<p>[ {}]: c</p>\n<p>[a b]</p>", label),
+        "should not support more than `LINK_REFERENCE_SIZE_MAX` bytes in the label of a definition"
+    );
+
+    assert_eq!(
+        to_html(&format!("[a b]: c\n\n[d][{}]", label)),
+        format!("<p><a href=\"c\">d</a></p>"),
+        "should support `LINK_REFERENCE_SIZE_MAX` bytes in the label of a full reference"
+    );
+
+    assert_eq!(
+        to_html(&format!("[a b]: c\n\n[d][ {}]", label)),
+        format!("<p>[d][ {}]</p>", label),
+        "should not support more than `LINK_REFERENCE_SIZE_MAX` bytes in the label of a full reference"
+    );
+
+    assert_eq!(
+        to_html(&format!("[a b]: c\n\n[{}]", label)),
+        format!("<p><a href=\"c\">{}</a></p>", label),
+        "should support `LINK_REFERENCE_SIZE_MAX` bytes in a shortcut reference"
+    );
+
+    assert_eq!(
+        to_html(&format!("[a b]: c\n\n[ {}]", label)),
+        format!("<p>[ {}]</p>", label),
+        "should not support more than `LINK_REFERENCE_SIZE_MAX` bytes in a shortcut reference"
+    );
+
+    assert_eq!(
+        to_html(&format!("[a b]: c\n\n[ {}][]", label)),
+        format!("<p>[ {}][]</p>", label),
+        "should not support more than `LINK_REFERENCE_SIZE_MAX` bytes in a collapsed reference"
+    );
+
+    assert_eq!(
+        to_html(&format!(
+            "[a b]: c\n\n[a\n{}b]",
+            " ".repeat(LINK_REFERENCE_SIZE_MAX)
+        )),
+        "<p><a href=\"c\">a\nb</a></p>",
+        "should not count line endings and initial whitespace in a shortcut reference"
+    );
+
+    let tree = to_mdast(
+        &format!("[a b]: c\n\n[ {}]", label),
+        &ParseOptions::default(),
+    )?;
+    let paragraph = &tree.children().unwrap()[1];
+    assert!(
+        matches!(paragraph.children().unwrap().as_slice(), [Node::Text(_)]),
+        "should not support more than `LINK_REFERENCE_SIZE_MAX` bytes in a shortcut reference (mdast)"
+    );
+
+    let label = "a".repeat(LINK_REFERENCE_SIZE_MAX);
+    assert_eq!(
+        to_html_with_options(&format!("[^{0}]: b\n\n[^{0}]", label), &Options::gfm())?,
+        format!("<p><sup><a href=\"#user-content-fn-{0}\" id=\"user-content-fnref-{0}\" data-footnote-ref=\"\" aria-describedby=\"footnote-label\">1</a></sup></p>\n<section data-footnotes=\"\" class=\"footnotes\"><h2 id=\"footnote-label\" class=\"sr-only\">Footnotes</h2>\n<ol>\n<li id=\"user-content-fn-{0}\">\n<p>b <a href=\"#user-content-fnref-{0}\" data-footnote-backref=\"\" aria-label=\"Back to content\" class=\"data-footnote-backref\">↩</a></p>\n</li>\n</ol>\n</section>\n", label),
+        "should support `LINK_REFERENCE_SIZE_MAX` bytes in the label of a footnote, excluding the caret"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("[^a{0}]: b\n\n[^a{0}]", label), &Options::gfm())?,
+        format!("<p>[^a{0}]: b</p>\n<p>[^a{0}]</p>", label),
+        "should not support more than `LINK_REFERENCE_SIZE_MAX` bytes in the label of a footnote"
+    );
+
+    Ok(())
+}
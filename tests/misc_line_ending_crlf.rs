@@ -0,0 +1,74 @@
+use markdown::{
+    mdast::{Code, Heading, Node, Table},
+    message, to_html, to_mdast, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn line_ending_crlf() -> Result<(), message::Message> {
+    let code = |value: &str| -> Result<(Option<String>, Option<String>), message::Message> {
+        let tree = to_mdast(value, &ParseOptions::default())?;
+        match &tree.children().unwrap()[0] {
+            Node::Code(Code { lang, meta, .. }) => Ok((lang.clone(), meta.clone())),
+            _ => unreachable!("expected code"),
+        }
+    };
+
+    assert_eq!(
+        to_html("```rust\r\nfn main() {}\r\n```"),
+        "<pre><code class=\"language-rust\">fn main() {}\r\n</code></pre>",
+        "should not include a carriage return in the info string (html)"
+    );
+
+    assert_eq!(
+        code("```rust\r\nfn main() {}\r\n```")?,
+        (Some("rust".into()), None),
+        "should not include a carriage return in the info string (mdast)"
+    );
+
+    assert_eq!(
+        code("~~~rust a b \r\nc\r\n~~~")?,
+        (Some("rust".into()), Some("a b ".into())),
+        "should not include a carriage return in the meta string"
+    );
+
+    assert_eq!(
+        code("```rust\rfn main() {}\r```")?,
+        (Some("rust".into()), None),
+        "should not include a lone carriage return in the info string"
+    );
+
+    let tree = to_mdast("# a #\r\nb\r\n=\r\n", &ParseOptions::default())?;
+    let headings = tree
+        .children()
+        .unwrap()
+        .iter()
+        .map(|node| match node {
+            Node::Heading(Heading { children, .. }) => children[0].to_string(),
+            _ => unreachable!("expected heading"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        headings,
+        vec!["a", "b"],
+        "should not include a carriage return in heading text"
+    );
+
+    let tree = to_mdast("| a |\r\n| - |\r\n| b |\r\n", &ParseOptions::gfm())?;
+    let cells = match &tree.children().unwrap()[0] {
+        Node::Table(Table { children, .. }) => children
+            .iter()
+            .map(|row| row.children().unwrap()[0].to_string())
+            .collect::<Vec<_>>(),
+        _ => unreachable!("expected table"),
+    };
+
+    assert_eq!(
+        cells,
+        vec!["a", "b"],
+        "should not include a carriage return in table cells"
+    );
+
+    Ok(())
+}
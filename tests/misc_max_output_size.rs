@@ -0,0 +1,117 @@
+use markdown::{
+    compile_events, message, to_events, to_html_to_writer, to_html_with_options, CompileOptions,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+fn max(size: usize) -> Options {
+    Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            max_output_size: Some(size),
+            ..CompileOptions::default()
+        },
+    }
+}
+
+#[test]
+fn max_output_size() -> Result<(), message::Message> {
+    assert_eq!(
+        to_html_with_options("*a*", &max(17))?,
+        "<p><em>a</em></p>",
+        "should support HTML as big as `max_output_size`"
+    );
+
+    let error = to_html_with_options("*a*", &max(16)).unwrap_err();
+
+    assert_eq!(
+        (error.source.as_str(), error.rule_id.as_str()),
+        ("markdown-rs", "max-output-size"),
+        "should error when HTML is bigger than `max_output_size`"
+    );
+
+    assert_eq!(
+        error.reason, "Unexpected HTML bigger than `max_output_size` (16 bytes)",
+        "should include the maximum in the reason"
+    );
+
+    let mut value = String::from("[a]: <");
+    value.push_str(&"b".repeat(1000));
+    value.push_str(">\n\n");
+    value.push_str(&"![a][a] ".repeat(1000));
+
+    assert!(
+        to_html_with_options(&value, &Options::gfm())?.len() > 1_000_000,
+        "should make big HTML from references to a long definition (baseline)"
+    );
+
+    assert_eq!(
+        to_html_with_options(&value, &max(256 * 1024))
+            .unwrap_err()
+            .rule_id
+            .as_str(),
+        "max-output-size",
+        "should error for big HTML from references to a long definition"
+    );
+
+    let cap = 4096;
+    let mut result = String::with_capacity(cap);
+
+    assert_eq!(
+        to_html_to_writer(&value, &max(cap), &mut result)
+            .unwrap_err()
+            .rule_id
+            .as_str(),
+        "max-output-size",
+        "should error when writing"
+    );
+
+    assert!(
+        result.len() <= cap && result.capacity() == cap,
+        "should not write more than `max_output_size`"
+    );
+
+    let alt = format!("![{}](b)", "a".repeat(1000));
+
+    assert_eq!(
+        to_html_with_options(&format!("[{}]", alt), &max(2048))?,
+        format!("<p>[<img src=\"b\" alt=\"{}\" />]</p>", "a".repeat(1000)),
+        "should support an image with long alt text below the maximum"
+    );
+
+    assert_eq!(
+        to_html_with_options(&format!("[{}]\n\n{}", alt, alt), &max(2048))
+            .unwrap_err()
+            .rule_id
+            .as_str(),
+        "max-output-size",
+        "should count all blocks"
+    );
+
+    assert_eq!(
+        to_html_with_options("a[^b]\n\n[^b]: c", &max(64))
+            .unwrap_err()
+            .rule_id
+            .as_str(),
+        "max-output-size",
+        "should count the footnote section"
+    );
+
+    assert_eq!(
+        compile_events(
+            &to_events("*a*", &ParseOptions::default())?,
+            "*a*",
+            &CompileOptions {
+                max_output_size: Some(8),
+                ..CompileOptions::default()
+            }
+        )
+        .unwrap_err()
+        .rule_id
+        .as_str(),
+        "max-output-size",
+        "should support `max_output_size` in `compile_events`"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+use markdown::{
+    mdast::{HtmlKind, Node},
+    message, to_mdast, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the values and kinds of all HTML nodes, in tree order.
+fn html(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<(String, Option<HtmlKind>)>, message::Message> {
+    fn visit(node: &Node, list: &mut Vec<(String, Option<HtmlKind>)>) {
+        if let Node::Html(node) = node {
+            list.push((node.value.clone(), node.kind));
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                visit(child, list);
+            }
+        }
+    }
+
+    let mut list = vec![];
+    visit(&to_mdast(value, options)?, &mut list);
+    Ok(list)
+}
+
+fn h(value: &str, kind: HtmlKind) -> (String, Option<HtmlKind>) {
+    (value.into(), Some(kind))
+}
+
+#[test]
+fn mdast_html_kind() -> Result<(), message::Message> {
+    let on = ParseOptions {
+        mdast_html_kind: true,
+        ..ParseOptions::default()
+    };
+
+    assert_eq!(
+        html("<!-- a -->", &ParseOptions::default())?,
+        vec![("<!-- a -->".into(), None)],
+        "should not add kinds by default"
+    );
+
+    assert_eq!(
+        html("<!-- a -->", &on)?,
+        vec![h("<!-- a -->", HtmlKind::Comment)],
+        "should support comments (flow)"
+    );
+
+    assert_eq!(
+        html("<!--\na\n-->", &on)?,
+        vec![h("<!--\na\n-->", HtmlKind::Comment)],
+        "should support multiline comments (flow)"
+    );
+
+    assert_eq!(
+        html("   <!-- prettier-ignore -->", &on)?,
+        vec![h("   <!-- prettier-ignore -->", HtmlKind::Comment)],
+        "should support indented comments (flow)"
+    );
+
+    assert_eq!(
+        html("<![CDATA[a]]>", &on)?,
+        vec![h("<![CDATA[a]]>", HtmlKind::Cdata)],
+        "should support CDATA (flow)"
+    );
+
+    assert_eq!(
+        html("<?a?>", &on)?,
+        vec![h("<?a?>", HtmlKind::Instruction)],
+        "should support instructions (flow)"
+    );
+
+    assert_eq!(
+        html("<!doctype html>", &on)?,
+        vec![h("<!doctype html>", HtmlKind::Declaration)],
+        "should support declarations (flow)"
+    );
+
+    assert_eq!(
+        html("<div>\n<!-- a -->\n</div>", &on)?,
+        vec![h("<div>\n<!-- a -->\n</div>", HtmlKind::Element)],
+        "should support elements (flow, basic), w/ comments inside them"
+    );
+
+    assert_eq!(
+        html("<x-y>", &on)?,
+        vec![h("<x-y>", HtmlKind::Element)],
+        "should support elements (flow, complete)"
+    );
+
+    assert_eq!(
+        html("<script>\n<!-- a -->\n</script>", &on)?,
+        vec![h("<script>\n<!-- a -->\n</script>", HtmlKind::Element)],
+        "should support elements (flow, raw)"
+    );
+
+    assert_eq!(
+        html("<!-- a --><div>x</div>", &on)?,
+        vec![h("<!-- a --><div>x</div>", HtmlKind::Element)],
+        "should use element for comments followed by more HTML (flow)"
+    );
+
+    assert_eq!(
+        html("<?a?> <!b>", &on)?,
+        vec![h("<?a?> <!b>", HtmlKind::Element)],
+        "should use element for instructions followed by more HTML (flow)"
+    );
+
+    assert_eq!(
+        html("<!-- a -->  ", &on)?,
+        vec![h("<!-- a -->  ", HtmlKind::Comment)],
+        "should support whitespace after comments (flow)"
+    );
+
+    assert_eq!(
+        html("<!--> a", &on)?,
+        vec![h("<!--> a", HtmlKind::Element)],
+        "should support empty comments followed by more (flow)"
+    );
+
+    assert_eq!(
+        html("<!-- a -->\n\n<div>", &on)?,
+        vec![
+            h("<!-- a -->", HtmlKind::Comment),
+            h("<div>", HtmlKind::Element)
+        ],
+        "should support separate comments and elements (flow)"
+    );
+
+    assert_eq!(
+        html("a <!-- b --><i>c</i> <?d?> <!e> <![CDATA[f]]>", &on)?,
+        vec![
+            h("<!-- b -->", HtmlKind::Comment),
+            h("<i>", HtmlKind::Element),
+            h("</i>", HtmlKind::Element),
+            h("<?d?>", HtmlKind::Instruction),
+            h("<!e>", HtmlKind::Declaration),
+            h("<![CDATA[f]]>", HtmlKind::Cdata)
+        ],
+        "should support HTML (text)"
+    );
+
+    assert_eq!(
+        html("a <!--\nb\n--> c", &on)?,
+        vec![h("<!--\nb\n-->", HtmlKind::Comment)],
+        "should support multiline comments (text)"
+    );
+
+    assert_eq!(
+        html("> <!-- a -->\n\n* <!-- b -->", &on)?,
+        vec![
+            h("<!-- a -->", HtmlKind::Comment),
+            h("<!-- b -->", HtmlKind::Comment)
+        ],
+        "should support comments in containers"
+    );
+
+    Ok(())
+}
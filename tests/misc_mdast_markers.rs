@@ -0,0 +1,108 @@
+use markdown::{
+    mdast::{Markers, Node},
+    message, to_mdast, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the markers of all nodes that can have them, in tree order.
+fn markers(value: &str, options: &ParseOptions) -> Result<Vec<Option<Markers>>, message::Message> {
+    fn visit(node: &Node, list: &mut Vec<Option<Markers>>) {
+        match node {
+            Node::Code(node) => list.push(node.markers),
+            Node::Emphasis(node) => list.push(node.markers),
+            Node::Heading(node) => list.push(node.markers),
+            Node::List(node) => list.push(node.markers),
+            Node::ListItem(node) => list.push(node.markers),
+            Node::Strong(node) => list.push(node.markers),
+            Node::ThematicBreak(node) => list.push(node.markers),
+            _ => {}
+        }
+
+        if let Some(children) = node.children() {
+            for child in children {
+                visit(child, list);
+            }
+        }
+    }
+
+    let mut list = vec![];
+    visit(&to_mdast(value, options)?, &mut list);
+    Ok(list)
+}
+
+fn m(character: char, size: usize) -> Option<Markers> {
+    Some(Markers { character, size })
+}
+
+#[test]
+fn mdast_markers() -> Result<(), message::Message> {
+    let on = ParseOptions {
+        mdast_markers: true,
+        ..ParseOptions::default()
+    };
+
+    assert_eq!(
+        markers(
+            "*a* _b_ **c** __d__\n\n# e\n\n---",
+            &ParseOptions::default()
+        )?,
+        vec![None, None, None, None, None, None],
+        "should not add markers by default"
+    );
+
+    assert_eq!(
+        markers("*a* _b_ **c** __d__ ***e***", &on)?,
+        vec![
+            m('*', 1),
+            m('_', 1),
+            m('*', 2),
+            m('_', 2),
+            m('*', 1),
+            m('*', 2)
+        ],
+        "should support markers on emphasis and strong"
+    );
+
+    assert_eq!(
+        markers("# a\n\n### b ###\n\nc\n===\n\nd\n-", &on)?,
+        vec![m('#', 1), m('#', 3), m('=', 3), m('-', 1)],
+        "should support markers on headings"
+    );
+
+    assert_eq!(
+        markers("***\n\n- - -\n\n_____", &on)?,
+        vec![m('*', 3), m('-', 3), m('_', 5)],
+        "should support markers on thematic breaks"
+    );
+
+    assert_eq!(
+        markers("~~~~js\na\n~~~~\n\n```\nb\n`````\n\n    c", &on)?,
+        vec![m('~', 4), m('`', 3), None],
+        "should support markers on fenced code, but not indented code"
+    );
+
+    assert_eq!(
+        markers("- a\n- b\n\n* c\n  + d\n\n1. e\n\n2) f", &on)?,
+        vec![
+            m('-', 1),
+            m('-', 1),
+            m('-', 1),
+            m('*', 1),
+            m('*', 1),
+            m('+', 1),
+            m('+', 1),
+            m('.', 1),
+            m('.', 1),
+            m(')', 1),
+            m(')', 1)
+        ],
+        "should support markers on lists and list items"
+    );
+
+    assert!(
+        to_mdast("*a*", &on)?.eq_ignore_position(&to_mdast("_a_", &ParseOptions::default())?),
+        "should ignore markers when comparing w/o positional info"
+    );
+
+    Ok(())
+}
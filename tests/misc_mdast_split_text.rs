@@ -0,0 +1,145 @@
+use markdown::{
+    mdast::Node, message, to_html_with_options, to_mdast, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the value and offsets of all phrasing nodes in the first paragraph,
+/// with `Text` nodes as their value and other nodes as their kind.
+fn phrasing(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<Vec<(String, usize, usize)>, message::Message> {
+    let tree = to_mdast(value, options)?;
+    let paragraph = tree
+        .children()
+        .unwrap()
+        .iter()
+        .find(|node| matches!(node, Node::Paragraph(_)))
+        .unwrap();
+
+    Ok(paragraph
+        .children()
+        .unwrap()
+        .iter()
+        .map(|node| {
+            let position = node.position().unwrap();
+            let value = match node {
+                Node::Text(node) => node.value.clone(),
+                Node::Html(_) => "[html]".into(),
+                Node::Link(_) => "[link]".into(),
+                _ => "[other]".into(),
+            };
+            (value, position.start.offset, position.end.offset)
+        })
+        .collect())
+}
+
+fn t(value: &str, start: usize, end: usize) -> (String, usize, usize) {
+    (value.into(), start, end)
+}
+
+#[test]
+fn mdast_split_text() -> Result<(), message::Message> {
+    let no_html = ParseOptions {
+        constructs: Constructs {
+            html_text: false,
+            ..Constructs::default()
+        },
+        ..ParseOptions::default()
+    };
+    let no_html_split = ParseOptions {
+        constructs: Constructs {
+            html_text: false,
+            ..Constructs::default()
+        },
+        mdast_split_text: true,
+        ..ParseOptions::default()
+    };
+    let no_autolink_split = ParseOptions {
+        constructs: Constructs {
+            autolink: false,
+            html_text: false,
+            ..Constructs::default()
+        },
+        mdast_split_text: true,
+        ..ParseOptions::default()
+    };
+
+    assert_eq!(
+        phrasing("a <b> c", &no_html)?,
+        vec![t("a <b> c", 0, 7)],
+        "should merge text by default"
+    );
+
+    assert_eq!(
+        phrasing("a <b> c", &no_html_split)?,
+        vec![t("a ", 0, 2), t("<b> c", 2, 7)],
+        "should split text where disabled html (text) would start"
+    );
+
+    assert_eq!(
+        phrasing(
+            "a <b> c",
+            &ParseOptions {
+                mdast_split_text: true,
+                ..ParseOptions::default()
+            }
+        )?,
+        vec![t("a ", 0, 2), t("[html]", 2, 5), t(" c", 5, 7)],
+        "should not affect constructs that are found"
+    );
+
+    assert_eq!(
+        phrasing("a <https://example.com> b", &no_autolink_split)?,
+        vec![
+            t("a ", 0, 2),
+            t("<", 2, 3),
+            t("https://example.com> b", 3, 25)
+        ],
+        "should split text where disabled autolinks would start"
+    );
+
+    assert_eq!(
+        phrasing("a <b>\n<c> d", &no_html_split)?,
+        vec![t("a ", 0, 2), t("<b>\n", 2, 6), t("<c> d", 6, 11)],
+        "should split text after line endings"
+    );
+
+    assert_eq!(
+        phrasing("a *b c", &no_html_split)?,
+        vec![t("a ", 0, 2), t("*", 2, 3), t("b c", 3, 6)],
+        "should split text around unmatched attention"
+    );
+
+    assert_eq!(
+        phrasing("a &amp; \\* b", &no_html_split)?,
+        vec![t("a & * b", 0, 12)],
+        "should merge character references and escapes with text"
+    );
+
+    assert_eq!(
+        phrasing(
+            "a.b_c-d@example.com",
+            &ParseOptions {
+                mdast_split_text: true,
+                ..ParseOptions::gfm()
+            }
+        )?,
+        vec![t("[link]", 0, 19)],
+        "should support GFM autolink literals spanning several runs of data"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a <b> *c\n<d> [e] <https://f> &amp;",
+            &Options {
+                parse: no_autolink_split,
+                ..Options::default()
+            }
+        )?,
+        "<p>a &lt;b&gt; *c\n&lt;d&gt; [e] &lt;https://f&gt; &amp;</p>",
+        "should not affect HTML"
+    );
+
+    Ok(())
+}
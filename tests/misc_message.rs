@@ -0,0 +1,97 @@
+use markdown::{message::rules, to_html_with_options, to_mdast, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+fn rule(value: &str) -> String {
+    let error = to_mdast(value, &ParseOptions::mdx()).unwrap_err();
+    assert_eq!(error.source.as_str(), rules::SOURCE);
+    *error.rule_id
+}
+
+#[test]
+fn message() {
+    assert_eq!(
+        rule("<a b@c />"),
+        rules::UNEXPECTED_CHARACTER_IN_ATTRIBUTE_NAME,
+        "should use a rule for an unexpected character in an attribute name"
+    );
+
+    assert_eq!(
+        rule("<a b=\"c"),
+        rules::UNEXPECTED_EOF_IN_ATTRIBUTE_VALUE,
+        "should use a rule for an unexpected end of file in an attribute value"
+    );
+
+    assert_eq!(
+        rule("<!a>"),
+        rules::UNEXPECTED_CHARACTER_BEFORE_NAME,
+        "should use a rule for an unexpected character before a name"
+    );
+
+    assert_eq!(
+        rule("<a/b>"),
+        rules::UNEXPECTED_CHARACTER_AFTER_SELF_CLOSING_SLASH,
+        "should use a rule for an unexpected character after a self-closing slash"
+    );
+
+    assert_eq!(
+        rule("{a"),
+        rules::UNEXPECTED_EOF_IN_EXPRESSION,
+        "should use a rule for an unexpected end of file in an expression"
+    );
+
+    assert_eq!(
+        rule("> {a\nb}"),
+        rules::UNEXPECTED_LAZY_IN_EXPRESSION,
+        "should use a rule for a lazy line in an expression"
+    );
+
+    assert_eq!(
+        rule("> <a\nb/>"),
+        rules::UNEXPECTED_LAZY_IN_JSX,
+        "should use a rule for a lazy line in a JSX tag"
+    );
+
+    assert_eq!(
+        rule("</a>"),
+        rules::UNEXPECTED_CLOSING_SLASH,
+        "should use a rule for a closing tag w/o opening tag"
+    );
+
+    assert_eq!(
+        rule("<a>\n\n</b>"),
+        rules::END_TAG_MISMATCH,
+        "should use a rule for a mismatched closing tag"
+    );
+
+    let mdx = Options {
+        parse: ParseOptions::mdx(),
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html_with_options("<a b=\"c", &mdx)
+            .unwrap_err()
+            .to_string(),
+        "1:8: Unexpected end of file in attribute value, expected a corresponding closing quote `\"` (U+0022) (markdown-rs:unexpected-eof-in-attribute-value)",
+        "should include the rule when displaying"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn message_error() {
+    let mdx = Options {
+        parse: ParseOptions::mdx(),
+        ..Default::default()
+    };
+    let error: Box<dyn std::error::Error> =
+        Box::new(to_html_with_options("<a b@c />", &mdx).unwrap_err());
+
+    assert_eq!(
+        error.to_string(),
+        "1:5: Unexpected character `@` (U+0040) in attribute name, expected an attribute name character such as letters, digits, `$`, or `_`; `=` to initialize a value; whitespace before attributes; or the end of the tag (markdown-rs:unexpected-character-in-attribute-name)",
+        "should implement `Error`"
+    );
+
+    assert!(error.source().is_none(), "should not have a source error");
+}
@@ -0,0 +1,230 @@
+use markdown::{
+    mdast::{Node, Paragraph, Root, Text},
+    message, to_html_multi, to_mdast_multi,
+    unist::Position,
+    Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn multi() -> Result<(), message::Message> {
+    let frontmatter = Options {
+        parse: ParseOptions {
+            constructs: Constructs {
+                frontmatter: true,
+                ..Constructs::default()
+            },
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html_multi("a\n\u{1e}\nb", "\u{1e}", &Options::default())?,
+        vec!["<p>a</p>\n", "<p>b</p>"],
+        "should split on a delimiter line"
+    );
+
+    assert_eq!(
+        to_html_multi("a\n\nb", "", &Options::default())
+            .unwrap_err()
+            .to_string(),
+        "Unexpected empty delimiter, expected at least one character, as an empty delimiter matches every blank line (markdown-rs:invalid-delimiter)",
+        "should error on an empty delimiter"
+    );
+
+    assert_eq!(
+        to_mdast_multi("a\n\nb", "", &ParseOptions::default())
+            .unwrap_err()
+            .to_string(),
+        "Unexpected empty delimiter, expected at least one character, as an empty delimiter matches every blank line (markdown-rs:invalid-delimiter)",
+        "should error on an empty delimiter in `to_mdast_multi`"
+    );
+
+    assert_eq!(
+        to_html_multi("a\n***\nb", "***", &Options::default())?,
+        vec!["<p>a</p>\n", "<p>b</p>"],
+        "should support a delimiter that is markdown syntax"
+    );
+
+    assert_eq!(
+        to_html_multi("a\n\u{1e}", "\u{1e}", &Options::default())?,
+        vec!["<p>a</p>\n", ""],
+        "should make an empty document after a delimiter at the end"
+    );
+
+    assert_eq!(
+        to_html_multi("a\n\u{1e}\n", "\u{1e}", &Options::default())?,
+        vec!["<p>a</p>\n", ""],
+        "should make an empty document after a delimiter and a line ending at the end"
+    );
+
+    assert_eq!(
+        to_html_multi("\u{1e}\n\u{1e}\na", "\u{1e}", &Options::default())?,
+        vec!["", "", "<p>a</p>"],
+        "should make empty documents for consecutive delimiters"
+    );
+
+    assert_eq!(
+        to_html_multi("a", "\u{1e}", &Options::default())?,
+        vec!["<p>a</p>"],
+        "should make one document without delimiters"
+    );
+
+    assert_eq!(
+        to_html_multi("a\u{1e}\n \u{1e}\nb", "\u{1e}", &Options::default())?,
+        vec!["<p>a\u{1e}\n\u{1e}\nb</p>"],
+        "should not split on lines that contain more than a delimiter"
+    );
+
+    assert_eq!(
+        to_html_multi("```\n\u{1e}\n```\n\u{1e}\nb", "\u{1e}", &Options::default())?,
+        vec!["<pre><code>\u{1e}\n</code></pre>\n", "<p>b</p>"],
+        "should not split on a delimiter in fenced code"
+    );
+
+    assert_eq!(
+        to_html_multi("```\n\u{1e}\nb", "\u{1e}", &Options::default())?,
+        vec!["<pre><code>\u{1e}\nb\n</code></pre>\n"],
+        "should not split on a delimiter in unclosed fenced code"
+    );
+
+    assert_eq!(
+        to_html_multi("> ```\n\u{1e}\nb", "\u{1e}", &Options::default())?,
+        vec![
+            "<blockquote>\n<pre><code>\n</code></pre>\n</blockquote>",
+            "<p>b</p>"
+        ],
+        "should split on a delimiter after fenced code that is closed by its container"
+    );
+
+    assert_eq!(
+        to_html_multi(
+            "```\n\u{1e}\n```\n\u{1e}\n```\n\u{1e}\n```",
+            "\u{1e}",
+            &Options::default()
+        )?,
+        vec![
+            "<pre><code>\u{1e}\n</code></pre>\n",
+            "<pre><code>\u{1e}\n</code></pre>"
+        ],
+        "should support several fences with delimiters"
+    );
+
+    assert_eq!(
+        to_html_multi("a\n```\n", "```", &Options::default())?,
+        vec!["<p>a</p>\n", ""],
+        "should split on a delimiter that opens fenced code"
+    );
+
+    assert_eq!(
+        to_html_multi("    \u{1e}\n\u{1e}\nb", "\u{1e}", &Options::default())?,
+        vec!["<pre><code>\u{1e}\n</code></pre>\n", "<p>b</p>"],
+        "should split on a delimiter after indented code"
+    );
+
+    assert_eq!(
+        to_html_multi(
+            "+++\n\u{1e}\n+++\n\u{1e}\n+++\na\n+++\nb",
+            "\u{1e}",
+            &frontmatter
+        )?,
+        vec!["", "<p>b</p>"],
+        "should not split on a delimiter in frontmatter, and support frontmatter in each document"
+    );
+
+    assert_eq!(
+        to_html_multi("[a]: b\n\u{1e}\n[a]", "\u{1e}", &Options::default())?,
+        vec!["", "<p>[a]</p>"],
+        "should not share definitions between documents"
+    );
+
+    assert_eq!(
+        to_html_multi("a\r\n\u{1e}\r\nb\r\nc", "\u{1e}", &Options::default())?,
+        vec!["<p>a</p>\r\n", "<p>b\r\nc</p>"],
+        "should support CRLF"
+    );
+
+    assert_eq!(
+        to_html_multi(
+            "a\r\n\r\n\u{1e}\nb\n\n> c\nd",
+            "\u{1e}",
+            &Options::default()
+        )?,
+        vec![
+            "<p>a</p>\r\n",
+            "<p>b</p>\n<blockquote>\n<p>c\nd</p>\n</blockquote>"
+        ],
+        "should use the line endings of each document (CRLF, LF)"
+    );
+
+    assert_eq!(
+        to_html_multi("a\n\n\u{1e}\r\n> b\r\nc", "\u{1e}", &Options::default())?,
+        vec![
+            "<p>a</p>\n",
+            "<blockquote>\r\n<p>b\r\nc</p>\r\n</blockquote>"
+        ],
+        "should use the line endings of each document (LF, CRLF)"
+    );
+
+    assert_eq!(
+        to_mdast_multi("a\n\u{1e}\nb", "\u{1e}", &ParseOptions::default())?,
+        vec![
+            Node::Root(Root {
+                children: vec![Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "a".into(),
+                        position: Some(Position::new(1, 1, 0, 1, 2, 1)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(1, 1, 0, 1, 2, 1)),
+                    raw: None
+                })],
+                position: Some(Position::new(1, 1, 0, 2, 1, 2)),
+                raw: None
+            }),
+            Node::Root(Root {
+                children: vec![Node::Paragraph(Paragraph {
+                    children: vec![Node::Text(Text {
+                        value: "b".into(),
+                        position: Some(Position::new(3, 1, 4, 3, 2, 5)),
+                        raw: None,
+                    })],
+                    position: Some(Position::new(3, 1, 4, 3, 2, 5)),
+                    raw: None
+                })],
+                position: Some(Position::new(3, 1, 4, 3, 2, 5)),
+                raw: None
+            })
+        ],
+        "should support positions relative to the whole value"
+    );
+
+    assert_eq!(
+        to_mdast_multi("a\n\u{1e}", "\u{1e}", &ParseOptions::default())?[1],
+        Node::Root(Root {
+            children: vec![],
+            position: Some(Position::new(2, 2, 3, 2, 2, 3)),
+            raw: None
+        }),
+        "should place an empty document after its delimiter"
+    );
+
+    assert_eq!(
+        to_mdast_multi(
+            "a\n\u{1e}\nb",
+            "\u{1e}",
+            &ParseOptions {
+                preserve: true,
+                ..ParseOptions::default()
+            }
+        )?
+        .iter()
+        .map(|node| node.raw().cloned())
+        .collect::<Vec<_>>(),
+        vec![Some("a\n".into()), Some("b".into())],
+        "should preserve the source of each document"
+    );
+
+    Ok(())
+}
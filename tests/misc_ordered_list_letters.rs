@@ -0,0 +1,209 @@
+use markdown::{
+    mdast::{Node, OrderedListKind},
+    message, to_html, to_html_with_options, to_mdast, CompileOptions, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+/// Get the start and kind of the first list.
+fn list(
+    value: &str,
+    options: &ParseOptions,
+) -> Result<(Option<u32>, Option<OrderedListKind>), message::Message> {
+    let tree = to_mdast(value, options)?;
+
+    if let Node::List(list) = &tree.children().unwrap()[0] {
+        Ok((list.start, list.kind))
+    } else {
+        unreachable!("expected list")
+    }
+}
+
+#[test]
+fn ordered_list_letters() -> Result<(), message::Message> {
+    let letters = Options {
+        parse: ParseOptions {
+            ordered_list_letters: true,
+            ..ParseOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("a. b"),
+        "<p>a. b</p>",
+        "should not support letters by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("a. x\nb. y", &letters)?,
+        "<ol type=\"a\">\n<li>x</li>\n<li>y</li>\n</ol>",
+        "should support lowercase letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("c.  x", &letters)?,
+        "<ol start=\"3\" type=\"a\">\n<li>x</li>\n</ol>",
+        "should support a start"
+    );
+
+    assert_eq!(
+        to_html_with_options("C)  x", &letters)?,
+        "<ol start=\"3\" type=\"A\">\n<li>x</li>\n</ol>",
+        "should support uppercase letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("i. a\nii. b\niii. c", &letters)?,
+        "<ol type=\"i\">\n<li>a</li>\n<li>b</li>\n<li>c</li>\n</ol>",
+        "should support lowercase roman numerals"
+    );
+
+    assert_eq!(
+        to_html_with_options("XIV) a", &letters)?,
+        "<ol start=\"14\" type=\"I\">\n<li>a</li>\n</ol>",
+        "should support uppercase roman numerals"
+    );
+
+    assert_eq!(
+        to_html_with_options("h. a\ni. b", &letters)?,
+        "<ol start=\"8\" type=\"a\">\n<li>a</li>\n<li>b</li>\n</ol>",
+        "should support `i` as a letter in lists of letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a) x\n1) y", &letters)?,
+        "<ol type=\"a\">\n<li>x</li>\n</ol>\n<ol>\n<li>y</li>\n</ol>",
+        "should not support digits in lists of letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("a) x\n\nA) y", &letters)?,
+        "<ol type=\"a\">\n<li>x</li>\n</ol>\n<ol type=\"A\">\n<li>y</li>\n</ol>",
+        "should not support other cases in lists of letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("B. Russell", &letters)?,
+        "<p>B. Russell</p>",
+        "should not support a single uppercase letter, a dot, and one space (initials)"
+    );
+
+    assert_eq!(
+        to_html_with_options("B.  Russell", &letters)?,
+        "<ol start=\"2\" type=\"A\">\n<li>Russell</li>\n</ol>",
+        "should support a single uppercase letter, a dot, and two spaces"
+    );
+
+    assert_eq!(
+        to_html_with_options("B.\tRussell", &letters)?,
+        "<ol start=\"2\" type=\"A\">\n<li>Russell</li>\n</ol>",
+        "should support a single uppercase letter, a dot, and a tab"
+    );
+
+    assert_eq!(
+        to_html_with_options("c. x", &letters)?,
+        "<p>c. x</p>",
+        "should not support a single lowercase letter, a dot, and one space, without other items"
+    );
+
+    assert_eq!(
+        to_html_with_options("i. e. this", &letters)?,
+        "<p>i. e. this</p>",
+        "should not support abbreviations (`i. e.`)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a. x\n\n> b\n\nc. y", &letters)?,
+        "<p>a. x</p>\n<blockquote>\n<p>b</p>\n</blockquote>\n<p>c. y</p>",
+        "should not support a single lowercase letter, a dot, and one space, when the next item is not adjacent"
+    );
+
+    assert_eq!(
+        to_html_with_options("a. x\n\n   more\n\nb. y", &letters)?,
+        "<ol type=\"a\">\n<li>\n<p>x</p>\n<p>more</p>\n</li>\n<li>\n<p>y</p>\n</li>\n</ol>",
+        "should support a single lowercase letter, a dot, and one space, before and after other items"
+    );
+
+    assert_eq!(
+        to_html_with_options("> a. x\n> b. y", &letters)?,
+        "<blockquote>\n<ol type=\"a\">\n<li>x</li>\n<li>y</li>\n</ol>\n</blockquote>",
+        "should support a single lowercase letter, a dot, and one space, in block quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("v. x\nvi. y", &letters)?,
+        "<ol start=\"5\" type=\"i\">\n<li>x</li>\n<li>y</li>\n</ol>",
+        "should support a single letter followed by a roman numeral as roman numerals"
+    );
+
+    assert_eq!(
+        to_html_with_options("v. x\nw. y", &letters)?,
+        "<ol start=\"22\" type=\"a\">\n<li>x</li>\n<li>y</li>\n</ol>",
+        "should support a single letter followed by a letter as letters"
+    );
+
+    assert_eq!(
+        to_html_with_options("dim. x", &letters)?,
+        "<p>dim. x</p>",
+        "should not support several letters that are not roman numerals"
+    );
+
+    assert_eq!(
+        to_html_with_options("iiii. x", &letters)?,
+        "<p>iiii. x</p>",
+        "should not support roman numerals in a long form"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\nb. c", &letters)?,
+        "<p>a\nb. c</p>",
+        "should not support letters other than `a` interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\na) b\n\nc\ni) d", &letters)?,
+        "<p>a</p>\n<ol type=\"a\">\n<li>b</li>\n</ol>\n<p>c</p>\n<ol type=\"i\">\n<li>d</li>\n</ol>",
+        "should support `a` and `i` interrupting a paragraph"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a) [x] b",
+            &Options {
+                parse: ParseOptions {
+                    ordered_list_letters: true,
+                    ..ParseOptions::gfm()
+                },
+                compile: CompileOptions::gfm()
+            }
+        )?,
+        "<ol type=\"a\">\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> b</li>\n</ol>",
+        "should support tasks in lists of letters"
+    );
+
+    assert_eq!(
+        list("1. a", &letters.parse)?,
+        (Some(1), None),
+        "should not add a kind to lists of digits (mdast)"
+    );
+
+    assert_eq!(
+        list("c) a", &letters.parse)?,
+        (Some(3), Some(OrderedListKind::LowerAlpha)),
+        "should support letters (mdast)"
+    );
+
+    assert_eq!(
+        list("V) a\nVI) b", &letters.parse)?,
+        (Some(5), Some(OrderedListKind::UpperRoman)),
+        "should support a single letter followed by a roman numeral as roman numerals (mdast)"
+    );
+
+    assert_eq!(
+        list("IV) a", &letters.parse)?,
+        (Some(4), Some(OrderedListKind::UpperRoman)),
+        "should support roman numerals (mdast)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+mod test_utils;
+use markdown::{
+    message, parse, to_html_to_writer, to_html_with_options, to_mdast, CompileOptions, Options,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+use test_utils::swc::{parse_esm, parse_expression};
+
+#[test]
+fn parse_once() -> Result<(), message::Message> {
+    let value = "# a *b*\n\n| c |\n| - |\n| [d][] |\n\n[d]: e \"f\"\n\n[^g]\n\n[^g]: h";
+    let options = Options::gfm();
+    let parsed = parse(value, &options.parse)?;
+
+    assert_eq!(
+        parsed.to_html(&options.compile)?,
+        to_html_with_options(value, &options)?,
+        "should compile to HTML like `to_html_with_options`"
+    );
+
+    assert_eq!(
+        parsed.to_mdast()?,
+        to_mdast(value, &options.parse)?,
+        "should compile to mdast like `to_mdast`"
+    );
+
+    assert_eq!(
+        parsed.to_html(&options.compile)?,
+        parsed.to_html(&options.compile)?,
+        "should compile to HTML more than once"
+    );
+
+    assert_eq!(
+        parsed.to_mdast()?,
+        parsed.to_mdast()?,
+        "should compile to mdast more than once"
+    );
+
+    assert_eq!(
+        parsed.to_html(&CompileOptions {
+            heading_shift: 1,
+            ..CompileOptions::gfm()
+        })?,
+        to_html_with_options(
+            value,
+            &Options {
+                parse: ParseOptions::gfm(),
+                compile: CompileOptions {
+                    heading_shift: 1,
+                    ..CompileOptions::gfm()
+                }
+            }
+        )?,
+        "should compile to HTML with different compile options"
+    );
+
+    let mut a = String::new();
+    let mut b = String::new();
+    parsed.to_html_to_writer(&options.compile, &mut a)?;
+    to_html_to_writer(value, &options, &mut b)?;
+    assert_eq!(a, b, "should write HTML like `to_html_to_writer`");
+
+    let mdx = ParseOptions {
+        mdx_esm_parse: Some(Box::new(parse_esm)),
+        mdx_expression_parse: Some(Box::new(parse_expression)),
+        ..ParseOptions::mdx()
+    };
+
+    assert_eq!(
+        parse("<a>\n</b>", &mdx)
+            .and_then(|d| d.to_mdast())
+            .unwrap_err()
+            .to_string(),
+        "2:1-2:5: Unexpected closing tag `</b>`, expected corresponding closing tag for `<a>` (1:1) (markdown-rs:end-tag-mismatch)",
+        "should error when compiling mismatched JSX tags to mdast"
+    );
+
+    assert_eq!(
+        parse("{a(}", &mdx).unwrap_err().to_string(),
+        "1:5: Could not parse expression with swc: Unexpected eof (mdx:swc)",
+        "should error when parsing invalid MDX"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use markdown::{message, to_html_with_options, to_mdast, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+use std::time::{Duration, Instant};
+
+/// Repetitions in each input.
+const SIZE: usize = 5000;
+
+/// Time each input may take, generous enough for debug builds on slow
+/// machines, while quadratic behavior takes much longer.
+const LIMIT: Duration = Duration::from_secs(10);
+
+#[test]
+fn pathological() -> Result<(), message::Message> {
+    let cases = [
+        ("unclosed brackets", "[".repeat(SIZE)),
+        ("unclosed images", "![".repeat(SIZE)),
+        (
+            "nested brackets",
+            format!("{}a{}", "[".repeat(SIZE), "]".repeat(SIZE)),
+        ),
+        ("emphasis runs", "*a_ ".repeat(SIZE)),
+        (
+            "nested emphasis",
+            format!("{}a{}", "*".repeat(SIZE), "*".repeat(SIZE)),
+        ),
+        (
+            "backtick runs",
+            (1..(SIZE / 10)).map(|d| "`".repeat(d) + " a ").collect(),
+        ),
+        (
+            "code after lists",
+            format!("{}\n    code", "* a\n".repeat(SIZE)),
+        ),
+        (
+            "nested lists",
+            (0..(SIZE / 20))
+                .map(|d| format!("{}* a\n", "  ".repeat(d)))
+                .collect(),
+        ),
+        (
+            "table cells",
+            format!("{}\n{}\n", "a|".repeat(SIZE), "-|".repeat(SIZE)),
+        ),
+        (
+            "link destination parens",
+            format!("[a]({}b", "(".repeat(SIZE)),
+        ),
+    ];
+
+    for (name, value) in &cases {
+        let start = Instant::now();
+        to_html_with_options(value, &Options::gfm())?;
+        to_mdast(value, &ParseOptions::gfm())?;
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed < LIMIT,
+            "should handle {} in linear time (took {:?})",
+            name,
+            elapsed
+        );
+    }
+
+    assert_eq!(
+        to_html_with_options(
+            &format!("{}a{}", "[".repeat(SIZE), "]".repeat(SIZE)),
+            &Options::default()
+        )?,
+        format!("<p>{}a{}</p>", "[".repeat(SIZE), "]".repeat(SIZE)),
+        "should not make links of nested brackets w/o definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            &format!("{}a{}", "*".repeat(SIZE), "*".repeat(SIZE)),
+            &Options::default()
+        )?,
+        format!(
+            "<p>{}a{}</p>",
+            "<strong>".repeat(SIZE / 2),
+            "</strong>".repeat(SIZE / 2)
+        ),
+        "should support deeply nested strong"
+    );
+
+    Ok(())
+}
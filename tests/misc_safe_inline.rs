@@ -0,0 +1,93 @@
+use markdown::{message, to_html_with_options, Options};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn safe_inline() -> Result<(), message::Message> {
+    let options = Options::safe_inline();
+
+    assert_eq!(
+        to_html_with_options("*a* **b** `c` [d](https://e.com)", &options)?,
+        "<p><em>a</em> <strong>b</strong> <code>c</code> <a href=\"https://e.com\">d</a></p>",
+        "should support emphasis, strong, code (text), and links"
+    );
+
+    assert_eq!(
+        to_html_with_options("# a", &options)?,
+        "<p># a</p>",
+        "should not support headings (atx)"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\n===", &options)?,
+        "<p>a\n===</p>",
+        "should not support headings (setext)"
+    );
+
+    assert_eq!(
+        to_html_with_options("- a\n1. b\n> c\n***", &options)?,
+        "<p>- a\n1. b\n&gt; c\n***</p>",
+        "should not support lists, block quotes, or thematic breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("```\na\n```\n\n    b", &options)?,
+        "<p><code>a</code></p>\n<p>b</p>",
+        "should not support code (fenced, indented), as code (text) instead"
+    );
+
+    assert_eq!(
+        to_html_with_options("<div>a</div> <b>c</b>", &options)?,
+        "<p>&lt;div&gt;a&lt;/div&gt; &lt;b&gt;c&lt;/b&gt;</p>",
+        "should not support HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://a.com> b@c.com www.d.com", &options)?,
+        "<p>&lt;https://a.com&gt; b@c.com www.d.com</p>",
+        "should not support autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a]\n\n[a]: https://b.com", &options)?,
+        "<p>[a]</p>\n<p>[a]: https://b.com</p>",
+        "should not support definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("a ![b *c*](https://d.com/e.png \"f\") g", &options)?,
+        "<p>a b c g</p>",
+        "should show images as their alt text"
+    );
+
+    assert_eq!(
+        to_html_with_options("![<b>&amp;</b>](a.png)", &options)?,
+        "<p>&lt;b&gt;&amp;&lt;/b&gt;</p>",
+        "should encode the alt text of images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](http://b.com) [c](/d)", &options)?,
+        "<p><a href=\"http://b.com\">a</a> <a href=\"/d\">c</a></p>",
+        "should support `http:` and relative links"
+    );
+
+    assert_eq!(
+        to_html_with_options("[a](mailto:b@c.com) [d](javascript:alert(1))", &options)?,
+        "<p><a href=\"\">a</a> <a href=\"\">d</a></p>",
+        "should not support other protocols in links"
+    );
+
+    assert_eq!(
+        to_html_with_options("a\\\nb  \nc", &options)?,
+        "<p>a\\\nb\nc</p>",
+        "should not support hard breaks"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\*a\\* &amp; b\u{1}", &options)?,
+        "<p>*a* &amp; b</p>",
+        "should support escapes and references, and remove control characters"
+    );
+
+    Ok(())
+}
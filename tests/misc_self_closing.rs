@@ -0,0 +1,70 @@
+use markdown::{
+    mdast_to_html, message, to_html_with_options, to_mdast, CompileOptions, Options, ParseOptions,
+    SelfClosingStyle,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn self_closing() -> Result<(), message::Message> {
+    let doc =
+        "* [x] a\\\nb  \nc ![d](e \"f\")\n* [ ] g\n\n***\n\nh <br/> <hr />\n\n<img src=\"i\" />";
+    let xhtml = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            ..CompileOptions::gfm()
+        },
+    };
+    let html5 = Options {
+        parse: ParseOptions::gfm(),
+        compile: CompileOptions {
+            allow_dangerous_html: true,
+            self_closing: SelfClosingStyle::Html5,
+            ..CompileOptions::gfm()
+        },
+    };
+
+    assert_eq!(
+        to_html_with_options(doc, &xhtml)?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> a<br />\nb<br />\nc <img src=\"e\" alt=\"d\" title=\"f\" /></li>\n<li><input type=\"checkbox\" disabled=\"\" /> g</li>\n</ul>\n<hr />\n<p>h <br/> <hr /></p>\n<img src=\"i\" />",
+        "should use XHTML style for void elements by default"
+    );
+
+    assert_eq!(
+        to_html_with_options(doc, &html5)?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\"> a<br>\nb<br>\nc <img src=\"e\" alt=\"d\" title=\"f\"></li>\n<li><input type=\"checkbox\" disabled=\"\"> g</li>\n</ul>\n<hr>\n<p>h <br/> <hr /></p>\n<img src=\"i\" />",
+        "should support `self_closing: SelfClosingStyle::Html5`, w/o affecting HTML"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a\nb",
+            &Options {
+                compile: CompileOptions {
+                    hard_wrap: true,
+                    self_closing: SelfClosingStyle::Html5,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p>a<br>\nb</p>",
+        "should support `self_closing: SelfClosingStyle::Html5` w/ `hard_wrap`"
+    );
+
+    let tree = to_mdast(doc, &ParseOptions::gfm())?;
+
+    assert_eq!(
+        mdast_to_html(&tree, &xhtml.compile)?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\" /> a<br />\nb<br />\nc <img src=\"e\" alt=\"d\" title=\"f\" /></li>\n<li><input type=\"checkbox\" disabled=\"\" /> g</li>\n</ul>\n<hr />\n<p>h <br/> <hr /></p>\n<img src=\"i\" />",
+        "should use XHTML style for void elements by default (mdast)"
+    );
+
+    assert_eq!(
+        mdast_to_html(&tree, &html5.compile)?,
+        "<ul>\n<li><input type=\"checkbox\" disabled=\"\" checked=\"\"> a<br>\nb<br>\nc <img src=\"e\" alt=\"d\" title=\"f\"></li>\n<li><input type=\"checkbox\" disabled=\"\"> g</li>\n</ul>\n<hr>\n<p>h <br/> <hr /></p>\n<img src=\"i\" />",
+        "should support `self_closing: SelfClosingStyle::Html5`, w/o affecting HTML (mdast)"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use markdown::{
+    mdast_to_html, message, to_html, to_html_with_options, to_mdast, CompileOptions, Constructs,
+    Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn smart_punctuation() -> Result<(), message::Message> {
+    let smart = Options {
+        compile: CompileOptions {
+            smart_punctuation: true,
+            ..CompileOptions::default()
+        },
+        ..Options::default()
+    };
+
+    assert_eq!(
+        to_html("\"a\" 'b' c--d---e..."),
+        "<p>&quot;a&quot; 'b' c--d---e...</p>",
+        "should not support smart punctuation by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("\"a\" and 'b'", &smart)?,
+        "<p>“a” and ‘b’</p>",
+        "should support quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("it's, don't, and the dogs' bones", &smart)?,
+        "<p>it’s, don’t, and the dogs’ bones</p>",
+        "should support apostrophes"
+    );
+
+    assert_eq!(
+        to_html_with_options("'90s and rock 'n' roll", &smart)?,
+        "<p>’90s and rock ‘n’ roll</p>",
+        "should support apostrophes before digits"
+    );
+
+    assert_eq!(
+        to_html_with_options("\"a 'b' c\"", &smart)?,
+        "<p>“a ‘b’ c”</p>",
+        "should support nested quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("(\"a\"), \"b,\" [\"c\"]. \"d\"!", &smart)?,
+        "<p>(“a”), “b,” [“c”]. “d”!</p>",
+        "should support quotes next to punctuation"
+    );
+
+    assert_eq!(
+        to_html_with_options("\"*a*\" *\"b\"* \"[c](d)\"", &smart)?,
+        "<p>“<em>a</em>” <em>“b”</em> “<a href=\"d\">c</a>”</p>",
+        "should support quotes next to markers"
+    );
+
+    assert_eq!(
+        to_html_with_options("a \" b ' c", &smart)?,
+        "<p>a &quot; b ' c</p>",
+        "should not support quotes surrounded by whitespace"
+    );
+
+    assert_eq!(
+        to_html_with_options("\\\"a\\\" \\'b\\'", &smart)?,
+        "<p>&quot;a&quot; 'b'</p>",
+        "should not support escaped quotes"
+    );
+
+    assert_eq!(
+        to_html_with_options("pages 1--2, 1999--2001, a---b", &smart)?,
+        "<p>pages 1–2, 1999–2001, a—b</p>",
+        "should support en dashes in numeric ranges and em dashes"
+    );
+
+    assert_eq!(
+        to_html_with_options("a-b a----b a-----b a------b a-------b", &smart)?,
+        "<p>a-b a––b a—–b a——b a—––b</p>",
+        "should support longer sequences of hyphens"
+    );
+
+    assert_eq!(
+        to_html_with_options("Wait... what.... no..", &smart)?,
+        "<p>Wait… what…. no..</p>",
+        "should support ellipses"
+    );
+
+    assert_eq!(
+        to_html_with_options("The end...", &smart)?,
+        "<p>The end…</p>",
+        "should support an ellipsis at the end of a sentence"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "`\"a\" -- ...` <b title=\"c--d\">'e'</b> <https://f.g/--...> [\"h\"](i--j \"k--l\")\n\n```m--n\n\"o\"\n```",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    smart_punctuation: true,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><code>&quot;a&quot; -- ...</code> <b title=\"c--d\">‘e’</b> <a href=\"https://f.g/--...\">https://f.g/--...</a> <a href=\"i--j\" title=\"k--l\">“h”</a></p>\n<pre><code class=\"language-m--n\">&quot;o&quot;\n</code></pre>",
+        "should not support smart punctuation in code, HTML, autolinks, destinations, titles, or info"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "$\"a\"--b$ www.c.com/d--e...",
+            &Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        math_text: true,
+                        ..Constructs::gfm()
+                    },
+                    ..ParseOptions::gfm()
+                },
+                compile: CompileOptions {
+                    smart_punctuation: true,
+                    ..CompileOptions::default()
+                },
+            }
+        )?,
+        "<p><code class=\"language-math math-inline\">&quot;a&quot;--b</code> <a href=\"http://www.c.com/d--e\">www.c.com/d--e</a>…</p>",
+        "should not support smart punctuation in math or autolink literals"
+    );
+
+    assert_eq!(
+        to_html_with_options("[\"a\"][b--c]\n\n[b--c]: d", &smart)?,
+        "<p><a href=\"d\">“a”</a></p>\n",
+        "should support smart punctuation in references"
+    );
+
+    assert_eq!(
+        to_html_with_options("![\"a\"--b](c)", &smart)?,
+        "<p><img src=\"c\" alt=\"“a”–b\" /></p>",
+        "should support smart punctuation in alt"
+    );
+
+    assert_eq!(
+        format!("{:?}", to_mdast("\"a\"--b", &ParseOptions::default())?),
+        "Root { children: [Paragraph { children: [Text { value: \"\\\"a\\\"--b\", position: Some(1:1-1:7 (0-6)), raw: None }], position: Some(1:1-1:7 (0-6)), raw: None }], position: Some(1:1-1:7 (0-6)), raw: None }",
+        "should keep the source text in mdast"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast("\"a\" it's--b...", &ParseOptions::default())?,
+            &smart.compile
+        )?,
+        "<p>“a” it’s–b…</p>",
+        "should support smart punctuation when compiling a syntax tree"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use markdown::{message, to_html, toc, unist::Position, Constructs, ParseOptions, TocEntry};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn misc_toc() -> Result<(), message::Message> {
+    let texts = |value: &str| -> Result<Vec<(u8, String)>, message::Message> {
+        Ok(toc(value, &ParseOptions::gfm())?
+            .into_iter()
+            .map(|d| (d.depth, d.text))
+            .collect())
+    };
+
+    assert_eq!(
+        toc("# a\n\nb\n\nc\n-", &ParseOptions::default())?,
+        vec![
+            TocEntry {
+                depth: 1,
+                text: "a".into(),
+                position: Position::new(1, 1, 0, 1, 4, 3)
+            },
+            TocEntry {
+                depth: 2,
+                text: "c".into(),
+                position: Position::new(5, 1, 8, 6, 2, 11)
+            }
+        ],
+        "should support ATX and setext headings"
+    );
+
+    assert_eq!(
+        toc("a\n\n    # b\n\n```\n# c\n```", &ParseOptions::default())?,
+        vec![],
+        "should not support things that are not headings"
+    );
+
+    assert_eq!(
+        texts("###### a\n####### b\n\nc\nd\n===")?,
+        vec![(6, "a".into()), (1, "c\nd".into())],
+        "should support depths and setext headings over several lines"
+    );
+
+    assert_eq!(
+        texts("# a *b* **c** ~~d~~ `e` $f$")?,
+        vec![(1, "a b c d e $f$".into())],
+        "should drop attention and code markers"
+    );
+
+    assert_eq!(
+        texts("# a ***b _c_*** `` `d` ``")?,
+        vec![(1, "a b c `d`".into())],
+        "should support nested formatting"
+    );
+
+    assert_eq!(
+        texts("# &amp; &copy; &#x26; \\* &lt;a&gt; \"b\"")?,
+        vec![(1, "& © & * <a> \"b\"".into())],
+        "should decode character references and escapes"
+    );
+
+    assert_eq!(
+        texts("# [a](b) ![c *d*](e) [f] <https://g.h> www.i.j\n\n[f]: k")?,
+        vec![(1, "a c d f https://g.h www.i.j".into())],
+        "should keep the text of links, images, references, and autolinks"
+    );
+
+    assert_eq!(
+        texts("# a[^b]\n\n[^b]: c")?,
+        vec![(1, "a".into())],
+        "should drop footnote calls"
+    );
+
+    assert_eq!(
+        texts("# a <b>c</b>")?,
+        vec![(1, "a <b>c</b>".into())],
+        "should keep HTML as text"
+    );
+
+    assert_eq!(
+        texts("# a\n\n## a\n\n# a")?,
+        vec![(1, "a".into()), (2, "a".into()), (1, "a".into())],
+        "should support duplicate headings"
+    );
+
+    assert_eq!(
+        texts("#\n\n# #\n\nb\n=")?,
+        vec![(1, "".into()), (1, "".into()), (1, "b".into())],
+        "should support empty headings"
+    );
+
+    assert_eq!(
+        texts("> # a\n>\n> > b\n> > -\n\n* # c\n\n  1. d\n     ===\n\n[^e]: # f")?,
+        vec![
+            (1, "a".into()),
+            (2, "b".into()),
+            (1, "c".into()),
+            (1, "d".into()),
+            (1, "f".into())
+        ],
+        "should support headings in containers"
+    );
+
+    assert_eq!(
+        toc("> ## a", &ParseOptions::default())?[0].position,
+        Position::new(1, 3, 2, 1, 7, 6),
+        "should support positions of headings in containers"
+    );
+
+    assert_eq!(
+        to_html("![a *b* &amp; `c`](d)"),
+        "<p><img src=\"d\" alt=\"a b &amp; c\" /></p>",
+        "should make text like the `alt` of images (1)"
+    );
+
+    assert_eq!(
+        texts("# a *b* &amp; `c`")?,
+        vec![(1, "a b & c".into())],
+        "should make text like the `alt` of images (2)"
+    );
+
+    assert_eq!(
+        toc(
+            "---\n# a\n---\n# b",
+            &ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    ..Constructs::default()
+                },
+                ..ParseOptions::default()
+            }
+        )?
+        .into_iter()
+        .map(|d| d.text)
+        .collect::<Vec<_>>(),
+        vec!["b"],
+        "should not support headings in frontmatter"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,163 @@
+// Note: these fixtures are written by hand, after what `JSON.stringify`
+// gives for trees from `mdast-util-from-markdown` and its extensions.
+// They are not generated by JS, and they only cover a sample of documents:
+// compatibility with `unified` over the full fixture corpus is unverified.
+use markdown::{
+    mdast::{AttributeContent, Node},
+    mdast_to_unified_json, message, to_mdast, Constructs, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn unified_json() -> Result<(), message::Message> {
+    assert_eq!(
+        mdast_to_unified_json(&to_mdast("a", &ParseOptions::default())?),
+        r#"{"type":"root","children":[{"type":"paragraph","children":[{"type":"text","value":"a","position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":2,"offset":1}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":2,"offset":1}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":2,"offset":1}}}"#,
+        "should serialize like `JSON.stringify`, w/ `offset` in positions"
+    );
+
+    assert_eq!(
+        mdast_to_unified_json(&to_mdast("", &ParseOptions::default())?),
+        r#"{"type":"root","children":[],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":1,"offset":0}}}"#,
+        "should serialize an empty document"
+    );
+
+    assert_eq!(
+        mdast_to_unified_json(&to_mdast("[A]: b\n\n[a] ![c][A]", &ParseOptions::default())?),
+        r#"{"type":"root","children":[{"type":"definition","identifier":"a","label":"A","title":null,"url":"b","position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":7,"offset":6}}},{"type":"paragraph","children":[{"type":"linkReference","children":[{"type":"text","value":"a","position":{"start":{"line":3,"column":2,"offset":9},"end":{"line":3,"column":3,"offset":10}}}],"position":{"start":{"line":3,"column":1,"offset":8},"end":{"line":3,"column":4,"offset":11}},"label":"a","identifier":"a","referenceType":"shortcut"},{"type":"text","value":" ","position":{"start":{"line":3,"column":4,"offset":11},"end":{"line":3,"column":5,"offset":12}}},{"type":"imageReference","alt":"c","position":{"start":{"line":3,"column":5,"offset":12},"end":{"line":3,"column":12,"offset":19}},"label":"A","identifier":"a","referenceType":"full"}],"position":{"start":{"line":3,"column":1,"offset":8},"end":{"line":3,"column":12,"offset":19}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":3,"column":12,"offset":19}}}"#,
+        "should serialize `position` before `label`, `identifier`, and `referenceType` in references, like `mdast-util-from-markdown`"
+    );
+
+    assert_eq!(
+        mdast_to_unified_json(&to_mdast("[d](e) ![f](g \"h\")", &ParseOptions::default())?),
+        r#"{"type":"root","children":[{"type":"paragraph","children":[{"type":"link","title":null,"url":"e","children":[{"type":"text","value":"d","position":{"start":{"line":1,"column":2,"offset":1},"end":{"line":1,"column":3,"offset":2}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":7,"offset":6}}},{"type":"text","value":" ","position":{"start":{"line":1,"column":7,"offset":6},"end":{"line":1,"column":8,"offset":7}}},{"type":"image","title":"h","url":"g","alt":"f","position":{"start":{"line":1,"column":8,"offset":7},"end":{"line":1,"column":19,"offset":18}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":19,"offset":18}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":19,"offset":18}}}"#,
+        "should serialize `position` last in resources, like `mdast-util-from-markdown`"
+    );
+
+    assert_eq!(
+        mdast_to_unified_json(&to_mdast("[^a]\n\n[^a]: b", &ParseOptions::gfm())?),
+        r#"{"type":"root","children":[{"type":"paragraph","children":[{"type":"footnoteReference","identifier":"a","label":"a","position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":5,"offset":4}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":5,"offset":4}}},{"type":"footnoteDefinition","identifier":"a","label":"a","children":[{"type":"paragraph","children":[{"type":"text","value":"b","position":{"start":{"line":3,"column":7,"offset":12},"end":{"line":3,"column":8,"offset":13}}}],"position":{"start":{"line":3,"column":7,"offset":12},"end":{"line":3,"column":8,"offset":13}}}],"position":{"start":{"line":3,"column":1,"offset":6},"end":{"line":3,"column":8,"offset":13}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":3,"column":8,"offset":13}}}"#,
+        "should serialize `position` last in footnotes, like `mdast-util-gfm-footnote`"
+    );
+
+    assert_eq!(
+        mdast_to_unified_json(&to_mdast("<a b {...c} />", &ParseOptions::mdx())?),
+        r#"{"type":"root","children":[{"type":"mdxJsxFlowElement","name":"a","attributes":[{"type":"mdxJsxAttribute","name":"b","value":null,"position":{"start":{"line":1,"column":4,"offset":3},"end":{"line":1,"column":5,"offset":4}}},{"type":"mdxJsxExpressionAttribute","value":"...c"}],"children":[],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":15,"offset":14}}}],"position":{"start":{"line":1,"column":1,"offset":0},"end":{"line":1,"column":15,"offset":14}}}"#,
+        "should serialize `position` on attributes but not on expression attributes, like `mdast-util-mdx-jsx`"
+    );
+
+    assert_eq!(
+        json("* a\n\n  b\n* c", &ParseOptions::default())?,
+        r#"{"type":"root","children":[{"type":"list","ordered":false,"start":null,"spread":false,"children":[{"type":"listItem","spread":true,"checked":null,"children":[{"type":"paragraph","children":[{"type":"text","value":"a"}]},{"type":"paragraph","children":[{"type":"text","value":"b"}]}]},{"type":"listItem","spread":false,"checked":null,"children":[{"type":"paragraph","children":[{"type":"text","value":"c"}]}]}]}]}"#,
+        "should serialize `start: null` and `checked: null` in unordered lists"
+    );
+
+    assert_eq!(
+        json("3. [x] a\n4. [ ] b", &ParseOptions::gfm())?,
+        r#"{"type":"root","children":[{"type":"list","ordered":true,"start":3,"spread":false,"children":[{"type":"listItem","spread":false,"checked":true,"children":[{"type":"paragraph","children":[{"type":"text","value":"a"}]}]},{"type":"listItem","spread":false,"checked":false,"children":[{"type":"paragraph","children":[{"type":"text","value":"b"}]}]}]}]}"#,
+        "should serialize `start` and `checked` in ordered lists w/ tasks"
+    );
+
+    assert_eq!(
+        json(
+            "| a | b | c | d |\n| - | :- | -: | :-: |",
+            &ParseOptions::gfm()
+        )?,
+        r#"{"type":"root","children":[{"type":"table","align":[null,"left","right","center"],"children":[{"type":"tableRow","children":[{"type":"tableCell","children":[{"type":"text","value":"a"}]},{"type":"tableCell","children":[{"type":"text","value":"b"}]},{"type":"tableCell","children":[{"type":"text","value":"c"}]},{"type":"tableCell","children":[{"type":"text","value":"d"}]}]}]}]}"#,
+        "should serialize `null` for columns that are not aligned"
+    );
+
+    assert_eq!(
+        json(
+            "# a\n***\n```js b\nc\n```\n\n    d\n\n<e>",
+            &ParseOptions::default()
+        )?,
+        r#"{"type":"root","children":[{"type":"heading","depth":1,"children":[{"type":"text","value":"a"}]},{"type":"thematicBreak"},{"type":"code","lang":"js","meta":"b","value":"c"},{"type":"code","lang":null,"meta":null,"value":"d"},{"type":"html","value":"<e>"}]}"#,
+        "should serialize flow"
+    );
+
+    assert_eq!(
+        json(
+            "`a` b\\\nc *d* **e** ~f~ [g](h) ![i](j \"k\")",
+            &ParseOptions::gfm()
+        )?,
+        r#"{"type":"root","children":[{"type":"paragraph","children":[{"type":"inlineCode","value":"a"},{"type":"text","value":" b"},{"type":"break"},{"type":"text","value":"c "},{"type":"emphasis","children":[{"type":"text","value":"d"}]},{"type":"text","value":" "},{"type":"strong","children":[{"type":"text","value":"e"}]},{"type":"text","value":" "},{"type":"delete","children":[{"type":"text","value":"f"}]},{"type":"text","value":" "},{"type":"link","title":null,"url":"h","children":[{"type":"text","value":"g"}]},{"type":"text","value":" "},{"type":"image","title":"k","url":"j","alt":"i"}]}]}"#,
+        "should serialize phrasing"
+    );
+
+    assert_eq!(
+        json(
+            "[A]: b\n\n[a] [A][] [c][a] ![d][a]",
+            &ParseOptions::default()
+        )?,
+        r#"{"type":"root","children":[{"type":"definition","identifier":"a","label":"A","title":null,"url":"b"},{"type":"paragraph","children":[{"type":"linkReference","children":[{"type":"text","value":"a"}],"label":"a","identifier":"a","referenceType":"shortcut"},{"type":"text","value":" "},{"type":"linkReference","children":[{"type":"text","value":"A"}],"label":"A","identifier":"a","referenceType":"collapsed"},{"type":"text","value":" "},{"type":"linkReference","children":[{"type":"text","value":"c"}],"label":"a","identifier":"a","referenceType":"full"},{"type":"text","value":" "},{"type":"imageReference","alt":"d","label":"a","identifier":"a","referenceType":"full"}]}]}"#,
+        "should serialize definitions and references"
+    );
+
+    assert_eq!(
+        json("[^a]\n\n[^a]: b", &ParseOptions::gfm())?,
+        r#"{"type":"root","children":[{"type":"paragraph","children":[{"type":"footnoteReference","identifier":"a","label":"a"}]},{"type":"footnoteDefinition","identifier":"a","label":"a","children":[{"type":"paragraph","children":[{"type":"text","value":"b"}]}]}]}"#,
+        "should serialize footnotes"
+    );
+
+    assert_eq!(
+        json(
+            "---\na: b\n---\n$$c\nd\n$$\n\n$e$",
+            &ParseOptions {
+                constructs: Constructs {
+                    frontmatter: true,
+                    math_flow: true,
+                    math_text: true,
+                    ..Constructs::default()
+                },
+                ..ParseOptions::default()
+            }
+        )?,
+        r#"{"type":"root","children":[{"type":"yaml","value":"a: b"},{"type":"math","meta":"c","value":"d"},{"type":"paragraph","children":[{"type":"inlineMath","value":"e"}]}]}"#,
+        "should serialize frontmatter and math (w/o `data`)"
+    );
+
+    assert_eq!(
+        json("<a b c=\"d\" {...e} />\n\n<>{f}</>", &ParseOptions::mdx())?,
+        r#"{"type":"root","children":[{"type":"mdxJsxFlowElement","name":"a","attributes":[{"type":"mdxJsxAttribute","name":"b","value":null},{"type":"mdxJsxAttribute","name":"c","value":"d"},{"type":"mdxJsxExpressionAttribute","value":"...e"}],"children":[]},{"type":"mdxJsxFlowElement","name":null,"attributes":[],"children":[{"type":"mdxFlowExpression","value":"f"}]}]}"#,
+        "should serialize MDX (w/o `data`)"
+    );
+
+    assert_eq!(
+        json("a\\\\\"\u{1}\u{7f}é", &ParseOptions::default())?,
+        "{\"type\":\"root\",\"children\":[{\"type\":\"paragraph\",\"children\":[{\"type\":\"text\",\"value\":\"a\\\\\\\"\\u0001\u{7f}é\"}]}]}",
+        "should escape strings like `JSON.stringify`"
+    );
+
+    Ok(())
+}
+
+/// Turn markdown into unified JSON, without positions.
+fn json(value: &str, options: &ParseOptions) -> Result<String, message::Message> {
+    let mut tree = to_mdast(value, options)?;
+    remove_position(&mut tree);
+    Ok(mdast_to_unified_json(&tree))
+}
+
+fn remove_position(node: &mut Node) {
+    if let Some(children) = node.children_mut() {
+        for child in children {
+            remove_position(child);
+        }
+    }
+
+    node.position_set(None);
+
+    let attributes = match node {
+        Node::MdxJsxFlowElement(node) => &mut node.attributes,
+        Node::MdxJsxTextElement(node) => &mut node.attributes,
+        _ => return,
+    };
+
+    for attribute in attributes {
+        match attribute {
+            AttributeContent::Expression(attribute) => attribute.position = None,
+            AttributeContent::Property(attribute) => attribute.position = None,
+        }
+    }
+}
@@ -1,8 +1,8 @@
-use markdown::to_html;
+use markdown::{message, to_html, to_html_with_options, CompileOptions, Options};
 use pretty_assertions::assert_eq;
 
 #[test]
-fn url() {
+fn url() -> Result<(), message::Message> {
     assert_eq!(
         to_html("<https://%>"),
         "<p><a href=\"https://%25\">https://%</a></p>",
@@ -144,4 +144,62 @@ fn url() {
         format!("<p><a href=\"{}\"></a></p>", ascii_out),
         "should support ascii characters"
     );
+
+    let danger = Options {
+        compile: CompileOptions {
+            allow_dangerous_protocol: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    assert_eq!(
+        to_html("[](a&#9;b)"),
+        "<p><a href=\"a%09b\"></a></p>",
+        "should percent-encode a tab from a numeric character reference in a destination"
+    );
+
+    assert_eq!(
+        to_html("[](<a&#10;b>)"),
+        "<p><a href=\"a%0Ab\"></a></p>",
+        "should percent-encode a line feed from a numeric character reference in a destination"
+    );
+
+    assert_eq!(
+        to_html("[](a&Tab;b) [](a&NewLine;b)"),
+        "<p><a href=\"a%09b\"></a> <a href=\"a%0Ab\"></a></p>",
+        "should percent-encode whitespace from named character references in a destination"
+    );
+
+    assert_eq!(
+        to_html("[](a&#13;b&#x1;c&#x7f;d)"),
+        "<p><a href=\"a%0Db%EF%BF%BDc%EF%BF%BDd\"></a></p>",
+        "should percent-encode control characters from character references in a destination"
+    );
+
+    assert_eq!(
+        to_html("![](a&#9;b)"),
+        "<p><img src=\"a%09b\" alt=\"\" /></p>",
+        "should percent-encode whitespace from character references in an image destination"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n[a]: b&#9;c&#10;d"),
+        "<p><a href=\"b%09c%0Ad\">a</a></p>\n",
+        "should percent-encode whitespace from character references in a definition destination"
+    );
+
+    assert_eq!(
+        to_html_with_options("[](a&#9;b) [](<a&#10;b>) [c]\n\n[c]: d&Tab;e", &danger)?,
+        "<p><a href=\"a%09b\"></a> <a href=\"a%0Ab\"></a> <a href=\"d%09e\">c</a></p>\n",
+        "should percent-encode whitespace from character references in destinations (`allow_dangerous_protocol`)"
+    );
+
+    assert_eq!(
+        to_html_with_options("[](a&#9;b)", &danger)?,
+        to_html_with_options("[](<a\tb>)", &danger)?,
+        "should percent-encode character references like literal bytes (`allow_dangerous_protocol`)"
+    );
+
+    Ok(())
 }
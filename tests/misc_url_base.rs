@@ -0,0 +1,156 @@
+use markdown::{
+    mdast_to_html, message, to_html, to_html_with_options, to_mdast, CompileOptions, Options,
+    ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn url_base() -> Result<(), message::Message> {
+    let options = Options {
+        compile: CompileOptions {
+            url_base: Some("https://example.com/a/b/c.md?d#e".into()),
+            ..CompileOptions::default()
+        },
+        ..Options::gfm()
+    };
+
+    assert_eq!(
+        to_html("[x](./f.md)"),
+        "<p><a href=\"./f.md\">x</a></p>",
+        "should not resolve URLs by default"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](./f.md)", &options)?,
+        "<p><a href=\"https://example.com/a/b/f.md\">x</a></p>",
+        "should resolve `./`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](../f.md)", &options)?,
+        "<p><a href=\"https://example.com/a/f.md\">x</a></p>",
+        "should resolve `../`"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](../../../../f.md)", &options)?,
+        "<p><a href=\"https://example.com/f.md\">x</a></p>",
+        "should not resolve `../` beyond the root"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](f.md)", &options)?,
+        "<p><a href=\"https://example.com/a/b/f.md\">x</a></p>",
+        "should resolve bare names"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](/f.md)", &options)?,
+        "<p><a href=\"https://example.com/f.md\">x</a></p>",
+        "should resolve absolute paths"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](?f)", &options)?,
+        "<p><a href=\"https://example.com/a/b/c.md?f\">x</a></p>",
+        "should resolve queries"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](#f)", &options)?,
+        "<p><a href=\"#f\">x</a></p>",
+        "should not resolve fragments"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](//f.com/g)", &options)?,
+        "<p><a href=\"//f.com/g\">x</a></p>",
+        "should not resolve protocol-relative URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](http://f.com/../g)", &options)?,
+        "<p><a href=\"http://f.com/../g\">x</a></p>",
+        "should not change absolute `http:` URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](mailto:f@g.com)", &options)?,
+        "<p><a href=\"mailto:f@g.com\">x</a></p>",
+        "should not change `mailto:` URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "![x](data:image/png;base64,f)",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_protocol: true,
+                    ..options.compile.clone()
+                },
+                ..Options::gfm()
+            }
+        )?,
+        "<p><img src=\"data:image/png;base64,f\" alt=\"x\" /></p>",
+        "should not change `data:` URLs"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x](javascript:alert(1))", &options)?,
+        "<p><a href=\"\">x</a></p>",
+        "should still sanitize dangerous protocols"
+    );
+
+    assert_eq!(
+        to_html_with_options("![x](images/logo.png \"y\")", &options)?,
+        "<p><img src=\"https://example.com/a/b/images/logo.png\" alt=\"x\" title=\"y\" /></p>",
+        "should resolve images"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x] ![y][x]\n\n[x]: ../f g.md", &options)?,
+        "<p>[x] ![y][x]</p>\n<p>[x]: ../f g.md</p>",
+        "should not affect invalid definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("[x] ![y][x]\n\n[x]: <../f g.md>", &options)?,
+        "<p><a href=\"https://example.com/a/f%20g.md\">x</a> <img src=\"https://example.com/a/f%20g.md\" alt=\"y\" /></p>\n",
+        "should resolve definitions, then sanitize"
+    );
+
+    assert_eq!(
+        to_html_with_options("<https://f.com/../g> www.h.com/../i j@k.com", &options)?,
+        "<p><a href=\"https://f.com/../g\">https://f.com/../g</a> <a href=\"http://www.h.com/../i\">www.h.com/../i</a> <a href=\"mailto:j@k.com\">j@k.com</a></p>",
+        "should not change autolinks"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "[x](f.md)",
+            &Options {
+                compile: CompileOptions {
+                    url_base: Some("https://example.com".into()),
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<p><a href=\"https://example.com/f.md\">x</a></p>",
+        "should support a base without path"
+    );
+
+    assert_eq!(
+        mdast_to_html(
+            &to_mdast(
+                "[x](../f.md) ![y](g.png) [z] www.h.com\n\n[z]: ?i",
+                &ParseOptions::gfm()
+            )?,
+            &options.compile
+        )?,
+        "<p><a href=\"https://example.com/a/f.md\">x</a> <img src=\"https://example.com/a/b/g.png\" alt=\"y\" /> <a href=\"https://example.com/a/b/c.md?i\">z</a> <a href=\"http://www.h.com\">www.h.com</a></p>",
+        "should resolve when compiling a syntax tree"
+    );
+
+    Ok(())
+}
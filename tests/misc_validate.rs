@@ -0,0 +1,88 @@
+use markdown::{
+    message, to_html_with_options, to_mdast, CompileOptions, Constructs, Options, ParseOptions,
+};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn validate() -> Result<(), message::Message> {
+    let jsx_and_html = ParseOptions {
+        constructs: Constructs {
+            html_flow: true,
+            ..Constructs::mdx()
+        },
+        ..ParseOptions::mdx()
+    };
+
+    assert_eq!(
+        (
+            ParseOptions::default().validate(),
+            ParseOptions::gfm().validate(),
+            ParseOptions::mdx().validate(),
+            Options::gfm().validate()
+        ),
+        (Ok(()), Ok(()), Ok(()), Ok(())),
+        "should support the presets"
+    );
+
+    assert_eq!(
+        jsx_and_html
+            .validate()
+            .map_err(|d| (d.to_string(), d.rule_id.to_string())),
+        Err((
+            "Unexpected `mdx_jsx_flow` together with `html_flow`, expected only one of them, as HTML takes precedence over JSX; turn `html_flow` off (markdown-rs:invalid-options)".into(),
+            "invalid-options".into()
+        )),
+        "should not support MDX JSX (flow) w/ HTML (flow)"
+    );
+
+    assert_eq!(
+        ParseOptions {
+            constructs: Constructs {
+                html_text: true,
+                ..Constructs::mdx()
+            },
+            ..ParseOptions::mdx()
+        }
+        .validate()
+        .map_err(|d| d.to_string()),
+        Err("Unexpected `mdx_jsx_text` together with `html_text`, expected only one of them, as HTML takes precedence over JSX; turn `html_text` off (markdown-rs:invalid-options)".into()),
+        "should not support MDX JSX (text) w/ HTML (text)"
+    );
+
+    assert_eq!(
+        ParseOptions {
+            constructs: Constructs {
+                html_flow: true,
+                html_text: true,
+                mdx_jsx_flow: false,
+                mdx_jsx_text: false,
+                ..Constructs::mdx()
+            },
+            ..ParseOptions::mdx()
+        }
+        .validate(),
+        Ok(()),
+        "should support other MDX constructs w/ HTML"
+    );
+
+    assert_eq!(
+        to_mdast("a", &jsx_and_html).map_err(|d| d.rule_id.to_string()),
+        Err("invalid-options".into()),
+        "should validate in `to_mdast`"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "a",
+            &Options {
+                parse: jsx_and_html,
+                compile: CompileOptions::default()
+            }
+        )
+        .map_err(|d| d.rule_id.to_string()),
+        Err("invalid-options".into()),
+        "should validate in `to_html_with_options`"
+    );
+
+    Ok(())
+}
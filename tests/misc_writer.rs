@@ -0,0 +1,107 @@
+use core::fmt;
+use markdown::{message, to_html_to_writer, to_html_with_options, Options, ParseOptions};
+use pretty_assertions::assert_eq;
+
+/// Writer that keeps each chunk it is given.
+#[derive(Default)]
+struct Chunks(Vec<String>);
+
+impl fmt::Write for Chunks {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.0.push(value.into());
+        Ok(())
+    }
+}
+
+/// Writer that fails once more than `limit` bytes are written.
+struct Limited {
+    limit: usize,
+    size: usize,
+}
+
+impl fmt::Write for Limited {
+    fn write_str(&mut self, value: &str) -> fmt::Result {
+        self.size += value.len();
+        if self.size > self.limit {
+            Err(fmt::Error)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[test]
+fn writer() -> Result<(), message::Message> {
+    let gfm = Options::gfm();
+    let mdx = Options {
+        parse: ParseOptions::mdx(),
+        ..Default::default()
+    };
+    let corpus = [
+        "# a\n\nb *c* d",
+        "a\n===\n\nb\n---",
+        "* a\n* b\n\n  c\n* d",
+        "1. a\n2. b\n\n\n***",
+        "> a\n> - b\n>\n>   c",
+        "[a]\n\n[a]: b \"c\"",
+        "![a *b*](c)",
+        "```js\na\n```\n\n    b",
+        "| a | b |\n| :- | -: |\n| c | d |",
+        "a[^b]\n\n[^b]: c\n\n    d",
+        "- [ ] a\n- [x] b",
+        "a\r\nb\r\n\r\nc",
+        "",
+    ];
+
+    for value in corpus {
+        let mut chunks = Chunks::default();
+        to_html_to_writer(value, &gfm, &mut chunks)?;
+
+        assert_eq!(
+            chunks.0.concat(),
+            to_html_with_options(value, &gfm)?,
+            "should write the same as `to_html_with_options` for {:?}",
+            value
+        );
+    }
+
+    let mut chunks = Chunks::default();
+    to_html_to_writer("# a\n\nb\n\n* c", &gfm, &mut chunks)?;
+
+    assert_eq!(
+        chunks.0[..5].to_vec(),
+        vec!["<h1>", "a", "</h1>", "\n", "<p>"],
+        "should write incrementally"
+    );
+
+    assert_eq!(
+        to_html_to_writer("# a\n\nb", &gfm, &mut Limited { limit: 12, size: 0 })
+            .unwrap_err()
+            .to_string(),
+        "Cannot write HTML to output (markdown-rs:write-error)",
+        "should crash if writing fails"
+    );
+
+    assert_eq!(
+        to_html_to_writer(
+            "# a\n\nb",
+            &gfm,
+            &mut Limited {
+                limit: 100,
+                size: 0
+            }
+        ),
+        Ok(()),
+        "should not crash if writing does not fail"
+    );
+
+    assert_eq!(
+        to_html_to_writer("<!a>", &mdx, &mut Chunks::default())
+            .unwrap_err()
+            .to_string(),
+        to_html_with_options("<!a>", &mdx).unwrap_err().to_string(),
+        "should crash on MDX syntax errors"
+    );
+
+    Ok(())
+}
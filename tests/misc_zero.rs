@@ -1,7 +1,8 @@
 use markdown::{
-    mdast::{Node, Root},
-    message, to_html, to_mdast,
+    mdast::{Code, InlineCode, Link, Node, Paragraph, Root, Text},
+    message, to_html, to_html_with_options, to_mdast,
     unist::Position,
+    CompileOptions, Options,
 };
 use pretty_assertions::assert_eq;
 
@@ -15,6 +16,51 @@ fn zero() -> Result<(), message::Message> {
         "should replace `\\0` w/ a replacement characters (`�`)"
     );
 
+    assert_eq!(
+        to_html("`a\0b`"),
+        "<p><code>a�b</code></p>",
+        "should replace NUL in code (text)"
+    );
+
+    assert_eq!(
+        to_html("```a\0b\nc\0d\n```"),
+        "<pre><code class=\"language-a�b\">c�d\n</code></pre>",
+        "should replace NUL in code (fenced)"
+    );
+
+    assert_eq!(
+        to_html("[a\0b](c\0d \"e\0f\")"),
+        "<p><a href=\"c%EF%BF%BDd\" title=\"e�f\">a�b</a></p>",
+        "should replace NUL in links"
+    );
+
+    assert_eq!(
+        to_html("[a]\n\n[a]: <b\0c> 'd\0e'"),
+        "<p><a href=\"b%EF%BF%BDc\" title=\"d�e\">a</a></p>\n",
+        "should replace NUL in definitions"
+    );
+
+    assert_eq!(
+        to_html_with_options("www.a\0b.com", &Options::gfm())?,
+        "<p><a href=\"http://www.a%EF%BF%BDb.com\">www.a�b.com</a></p>",
+        "should replace NUL in autolink literals"
+    );
+
+    assert_eq!(
+        to_html_with_options(
+            "<div>a\0b</div>",
+            &Options {
+                compile: CompileOptions {
+                    allow_dangerous_html: true,
+                    ..CompileOptions::default()
+                },
+                ..Options::default()
+            }
+        )?,
+        "<div>a�b</div>",
+        "should replace NUL in HTML"
+    );
+
     assert_eq!(
         to_html("&#0;"),
         "<p>�</p>",
@@ -33,10 +79,65 @@ fn zero() -> Result<(), message::Message> {
         to_mdast("", &Default::default())?,
         Node::Root(Root {
             children: vec![],
-            position: Some(Position::new(1, 1, 0, 1, 1, 0))
+            position: Some(Position::new(1, 1, 0, 1, 1, 0)),
+            raw: None,
         }),
         "should support no markdown (ast)"
     );
 
+    assert_eq!(
+        to_mdast(
+            "a\0b `c\0d` [e\0f](g\0h \"i\0j\")\n\n```k\0l\nm\0n\n```",
+            &Default::default()
+        )?,
+        Node::Root(Root {
+            children: vec![
+                Node::Paragraph(Paragraph {
+                    children: vec![
+                        Node::Text(Text {
+                            value: "a�b ".into(),
+                            position: Some(Position::new(1, 1, 0, 1, 5, 4)),
+                            raw: None,
+                        }),
+                        Node::InlineCode(InlineCode {
+                            value: "c�d".into(),
+                            position: Some(Position::new(1, 5, 4, 1, 10, 9)),
+                            raw: None,
+                        }),
+                        Node::Text(Text {
+                            value: " ".into(),
+                            position: Some(Position::new(1, 10, 9, 1, 11, 10)),
+                            raw: None,
+                        }),
+                        Node::Link(Link {
+                            children: vec![Node::Text(Text {
+                                value: "e�f".into(),
+                                position: Some(Position::new(1, 12, 11, 1, 15, 14)),
+                                raw: None,
+                            })],
+                            url: "g�h".into(),
+                            title: Some("i�j".into()),
+                            position: Some(Position::new(1, 11, 10, 1, 27, 26)),
+                            raw: None,
+                        }),
+                    ],
+                    position: Some(Position::new(1, 1, 0, 1, 27, 26)),
+                    raw: None,
+                }),
+                Node::Code(Code {
+                    value: "m�n".into(),
+                    lang: Some("k�l".into()),
+                    meta: None,
+                    markers: None,
+                    position: Some(Position::new(3, 1, 28, 5, 4, 42)),
+                    raw: None,
+                }),
+            ],
+            position: Some(Position::new(1, 1, 0, 5, 4, 42)),
+            raw: None,
+        }),
+        "should replace NUL w/ a replacement character (ast)"
+    );
+
     Ok(())
 }
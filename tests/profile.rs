@@ -0,0 +1,80 @@
+#![cfg(feature = "profile")]
+
+use markdown::{message, to_html_with_options, to_html_with_profile, Options, Profile};
+use pretty_assertions::assert_eq;
+
+fn count(profile: &Profile, name: &str) -> usize {
+    profile
+        .events
+        .iter()
+        .find(|d| d.name == name)
+        .map_or(0, |d| d.count)
+}
+
+#[test]
+fn profile() -> Result<(), message::Message> {
+    let value = "# a\n\n*b* **c** [d](e)\n\n| f |\n| - |\n| g |\n";
+    let (result, profile) = to_html_with_profile(value, &Options::gfm())?;
+
+    assert_eq!(
+        result,
+        to_html_with_options(value, &Options::gfm())?,
+        "should compile like `to_html_with_options`"
+    );
+
+    assert_eq!(count(&profile, "Emphasis"), 1, "should count emphasis");
+    assert_eq!(count(&profile, "Strong"), 1, "should count strong");
+    assert_eq!(count(&profile, "Link"), 1, "should count links");
+    assert_eq!(
+        count(&profile, "GfmTableCell"),
+        2,
+        "should count table cells"
+    );
+
+    assert!(
+        profile
+            .states
+            .iter()
+            .any(|d| d.name == "AttentionStart" && d.count > 0),
+        "should track states"
+    );
+
+    let names = profile
+        .resolvers
+        .iter()
+        .map(|d| d.name.as_str())
+        .collect::<Vec<_>>();
+
+    for name in ["Attention", "GfmTable", "HeadingAtx", "Label"] {
+        assert!(
+            names.contains(&name),
+            "should track the `{}` resolver",
+            name
+        );
+    }
+
+    assert!(
+        profile.resolvers.iter().all(|d| d.count > 0),
+        "should count resolver runs"
+    );
+
+    assert!(profile.passes > 0, "should count passes");
+
+    let (_, bigger) = to_html_with_profile(&value.repeat(10), &Options::gfm())?;
+
+    assert_eq!(
+        count(&bigger, "Emphasis"),
+        10,
+        "should count events across a whole document"
+    );
+
+    let (_, empty) = to_html_with_profile("", &Options::default())?;
+
+    assert_eq!(
+        empty.events.len(),
+        0,
+        "should not create events for nothing"
+    );
+
+    Ok(())
+}
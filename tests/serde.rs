@@ -1,4 +1,8 @@
-use markdown::{mdast::Node, message::Message, Constructs, ParseOptions};
+use markdown::{
+    mdast::{AttributeContent, Node},
+    message::Message,
+    Constructs, ParseOptions,
+};
 use test_utils::swc::{parse_esm, parse_expression};
 mod test_utils;
 
@@ -680,6 +684,86 @@ fn serde_paragraph() -> Result<(), Error> {
     )
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn serde_options() -> Result<(), Error> {
+    use markdown::{CompileOptions, LineEnding, Options};
+    use pretty_assertions::assert_eq;
+
+    let options = Options {
+        compile: CompileOptions {
+            default_line_ending: LineEnding::CarriageReturnLineFeed,
+            gfm_footnote_label: Some("Notes".into()),
+            protocol_href: Some(vec!["https".into(), "tel".into()]),
+            ..CompileOptions::gfm()
+        },
+        ..Options::gfm()
+    };
+    let value = serde_json::to_value(&options).map_err(Error::Serde)?;
+
+    assert_eq!(
+        (
+            &value["parse"]["constructs"]["gfmTable"],
+            &value["compile"]["defaultLineEnding"],
+            &value["compile"]["gfmFootnoteLabel"],
+            &value["compile"]["attributeQuote"]
+        ),
+        (
+            &serde_json::json!(true),
+            &serde_json::json!("carriageReturnLineFeed"),
+            &serde_json::json!("Notes"),
+            &serde_json::json!("double")
+        ),
+        "should serialize options w/ camelcase names"
+    );
+
+    let result: Options = serde_json::from_value(value).map_err(Error::Serde)?;
+
+    assert_eq!(
+        format!("{:?}", result),
+        format!("{:?}", options),
+        "should round-trip options"
+    );
+
+    let result: Options = serde_json::from_str(r#"{"parse": {"constructs": {"gfmTable": true}}}"#)
+        .map_err(Error::Serde)?;
+
+    assert_eq!(
+        format!("{:?}", result),
+        format!(
+            "{:?}",
+            Options {
+                parse: ParseOptions {
+                    constructs: Constructs {
+                        gfm_table: true,
+                        ..Constructs::default()
+                    },
+                    ..ParseOptions::default()
+                },
+                ..Options::default()
+            }
+        ),
+        "should use defaults for missing fields"
+    );
+
+    let result: ParseOptions = serde_json::from_value(
+        serde_json::to_value(ParseOptions {
+            mdx_expression_parse: Some(Box::new(parse_expression)),
+            mdx_esm_parse: Some(Box::new(parse_esm)),
+            ..ParseOptions::mdx()
+        })
+        .map_err(Error::Serde)?,
+    )
+    .map_err(Error::Serde)?;
+
+    assert!(
+        result.mdx_expression_parse.is_none() && result.mdx_esm_parse.is_none(),
+        "should skip functions"
+    );
+
+    Ok(())
+}
+
 /// Assert serde of Mdast constructs.
 ///
 /// Refer below links for the MDAST JSON construct types.
@@ -723,4 +807,17 @@ fn remove_position(node: &mut Node) {
     }
 
     node.position_set(None);
+
+    let attributes = match node {
+        Node::MdxJsxFlowElement(node) => &mut node.attributes,
+        Node::MdxJsxTextElement(node) => &mut node.attributes,
+        _ => return,
+    };
+
+    for attribute in attributes {
+        match attribute {
+            AttributeContent::Expression(attribute) => attribute.position = None,
+            AttributeContent::Property(attribute) => attribute.position = None,
+        }
+    }
 }
@@ -192,9 +192,12 @@ fn thematic_break() -> Result<(), message::Message> {
         to_mdast("***", &Default::default())?,
         Node::Root(Root {
             children: vec![Node::ThematicBreak(ThematicBreak {
-                position: Some(Position::new(1, 1, 0, 1, 4, 3))
+                position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+                markers: None,
+                raw: None,
             })],
-            position: Some(Position::new(1, 1, 0, 1, 4, 3))
+            position: Some(Position::new(1, 1, 0, 1, 4, 3)),
+            raw: None,
         }),
         "should support thematic breaks as `ThematicBreak`s in mdast"
     );